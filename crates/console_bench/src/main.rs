@@ -0,0 +1,57 @@
+use console_bench::runner::{map_file, read_all_test_file_data};
+use rs_html_parser::{Parser, ParserOptions};
+use rs_html_parser_tokenizer::TokenizerOptions;
+use std::time::Instant;
+
+/** Parses a single file, which can be larger than the memory, through a memory map. */
+fn parse_mapped_file(path: &str, options: &ParserOptions) {
+    let map = map_file(path).unwrap();
+    let start = Instant::now();
+    let count = Parser::from_bytes(&map, options).count();
+
+    println!(
+        "{} bytes, {} tokens in {:?}",
+        map.len(),
+        count,
+        start.elapsed()
+    );
+}
+
+fn main() {
+    println!("RS Console bench!");
+
+    let options = ParserOptions {
+        xml_mode: false,
+        whitespace: None,
+        coalesce_text: None,
+        limits: None,
+        lazy_attributes: false,
+        tokenizer_options: TokenizerOptions {
+            xml_mode: None,
+            decode_entities: None,
+            ignore_whitespace_between_tags: Some(true),
+        },
+    };
+
+    if let Some(path) = std::env::args().nth(1) {
+        parse_mapped_file(&path, &options);
+        return;
+    }
+
+    let test_data = read_all_test_file_data("./test_data/");
+
+    println!("Files loaded");
+    println!("Running");
+
+    for i in 0..10 {
+        println!("{}", i);
+
+        for test_data in &test_data {
+            let tokenizer = Parser::new(test_data, &options);
+
+            for _token in tokenizer {}
+        }
+    }
+
+    println!("The end");
+}
@@ -1,3 +1,5 @@
+use memmap2::Mmap;
+use std::fs::File;
 use std::path::PathBuf;
 use std::{fs, io};
 
@@ -9,7 +11,7 @@ fn get_files_in_folder(path: &str) -> io::Result<Vec<PathBuf>> {
     Ok(all)
 }
 
-pub fn read_all_test_file_data(path: &str) -> Vec<String> {
+fn get_test_files(path: &str) -> Vec<String> {
     let mut test_files: Vec<String> = Vec::new();
     match get_files_in_folder(path) {
         Ok(files) => {
@@ -43,12 +45,33 @@ pub fn read_all_test_file_data(path: &str) -> Vec<String> {
         Err(e) => println!("Error: {}", e),
     };
 
-    let mut test_data: Vec<String> = Vec::new();
-
     test_files.sort();
+    test_files
+}
+
+pub fn read_all_test_file_data(path: &str) -> Vec<String> {
+    let mut test_data: Vec<String> = Vec::new();
 
-    for entry in test_files {
+    for entry in get_test_files(path) {
         test_data.push(fs::read_to_string(entry).unwrap())
     }
     test_data
 }
+
+/**
+ * Maps the file into memory instead of reading it, so files of any size can be parsed with
+ * `Parser::from_bytes` without copying them.
+ */
+pub fn map_file(path: &str) -> io::Result<Mmap> {
+    let file = File::open(path)?;
+
+    // SAFETY: the map is only read, and the files are not modified while they are parsed
+    unsafe { Mmap::map(&file) }
+}
+
+pub fn map_all_test_files(path: &str) -> Vec<Mmap> {
+    get_test_files(path)
+        .iter()
+        .map(|entry| map_file(entry).unwrap())
+        .collect()
+}
@@ -1,7 +1,12 @@
 #[cfg(test)]
 mod tests {
-    use console_bench::runner::read_all_test_file_data;
-    use rs_html_parser::{Parser, ParserOptions};
+    use console_bench::runner::{map_all_test_files, read_all_test_file_data};
+    #[cfg(feature = "parallel")]
+    use rs_html_parser::DEFAULT_CHUNK_SIZE;
+    use rs_html_parser::{
+        format, minify, validate_stream, ArticleOptions, Document, FormatOptions, Limits,
+        MinifyOptions, NodeData, NodeId, Parser, ParserOptions, WhitespaceMode,
+    };
     use rs_html_parser_tokenizer::TokenizerOptions;
     use rs_html_parser_tokens::Token;
 
@@ -23,6 +28,10 @@ mod tests {
 
         let options = ParserOptions {
             xml_mode: false,
+            whitespace: None,
+            coalesce_text: None,
+            limits: None,
+            lazy_attributes: false,
             tokenizer_options: TokenizerOptions {
                 xml_mode: None,
                 decode_entities: None,
@@ -36,4 +45,276 @@ mod tests {
             insta::assert_debug_snapshot!(result);
         }
     }
+
+    #[test]
+    fn mapped_files_match_read_files() {
+        let test_data = read_all_test_file_data("./../../test_data/");
+        let mapped = map_all_test_files("./../../test_data/");
+        let options = ParserOptions::default();
+
+        assert_eq!(test_data.len(), mapped.len());
+
+        for (test_data, mapped) in test_data.iter().zip(&mapped) {
+            assert_eq!(
+                format!("{:?}", parser(test_data, &options)),
+                format!(
+                    "{:?}",
+                    Parser::from_bytes(mapped, &options).collect::<Vec<_>>()
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn all_html_files_are_well_formed() {
+        let test_data = read_all_test_file_data("./../../test_data/");
+
+        let all_options = [
+            ParserOptions::default(),
+            ParserOptions {
+                xml_mode: true,
+                ..Default::default()
+            },
+            ParserOptions {
+                whitespace: Some(WhitespaceMode::Trim),
+                coalesce_text: Some(false),
+                ..Default::default()
+            },
+            ParserOptions {
+                limits: Some(Limits {
+                    max_depth: Some(8),
+                    max_tokens: Some(500),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+        ];
+
+        for options in &all_options {
+            for test_data in &test_data {
+                let result = validate_stream(Parser::new(test_data, options), options.xml_mode);
+
+                assert_eq!(result, Ok(()));
+            }
+        }
+    }
+
+    #[test]
+    fn lazy_attributes_match_eager_attributes() {
+        let test_data = read_all_test_file_data("./../../test_data/");
+
+        let all_options = |lazy_attributes| {
+            [
+                ParserOptions {
+                    lazy_attributes,
+                    ..Default::default()
+                },
+                ParserOptions {
+                    xml_mode: true,
+                    lazy_attributes,
+                    ..Default::default()
+                },
+                ParserOptions {
+                    whitespace: Some(WhitespaceMode::Trim),
+                    lazy_attributes,
+                    ..Default::default()
+                },
+                ParserOptions {
+                    limits: Some(Limits {
+                        max_attributes: Some(2),
+                        max_attribute_length: Some(16),
+                        ..Default::default()
+                    }),
+                    lazy_attributes,
+                    ..Default::default()
+                },
+            ]
+        };
+
+        for (eager, lazy) in all_options(false).iter().zip(&all_options(true)) {
+            for test_data in &test_data {
+                let mut lazy_tokens = Parser::new(test_data, lazy);
+
+                for eager_token in Parser::new(test_data, eager) {
+                    let lazy_token = lazy_tokens.next().unwrap();
+
+                    assert_eq!(eager_token.data, lazy_token.data);
+                    assert_eq!(eager_token.kind, lazy_token.kind);
+                    assert_eq!(eager_token.is_implied, lazy_token.is_implied);
+                    assert!(lazy_token.attrs.is_none());
+                    assert_eq!(
+                        eager_token.attrs,
+                        lazy_token.attributes().map(|attrs| attrs.into_owned())
+                    );
+
+                    for name in eager_token.attrs.iter().flat_map(|attrs| attrs.keys()) {
+                        assert_eq!(eager_token.attribute(name), lazy_token.attribute(name));
+                    }
+                    assert_eq!(lazy_token.attribute("data-missing"), None);
+                }
+
+                assert!(lazy_tokens.next().is_none());
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "parallel")]
+    fn parallel_tokenization_matches_sequential_parsing() {
+        let test_data = read_all_test_file_data("./../../test_data/");
+        let all_options = [
+            ParserOptions::default(),
+            ParserOptions {
+                xml_mode: true,
+                ..Default::default()
+            },
+            ParserOptions {
+                whitespace: Some(WhitespaceMode::Trim),
+                coalesce_text: Some(false),
+                ..Default::default()
+            },
+        ];
+
+        for options in &all_options {
+            for test_data in &test_data {
+                let expected = format!("{:?}", parser(test_data, options));
+                let parallel = Parser::from_bytes_parallel(test_data.as_bytes(), options, 1024);
+
+                assert_eq!(format!("{:?}", parallel.collect::<Vec<_>>()), expected);
+            }
+        }
+
+        // One large document in chunks of the default size
+        let document = test_data.concat();
+        let options = ParserOptions::default();
+
+        assert_eq!(
+            format!(
+                "{:?}",
+                Parser::from_bytes_parallel(document.as_bytes(), &options, DEFAULT_CHUNK_SIZE)
+                    .collect::<Vec<_>>()
+            ),
+            format!("{:?}", parser(&document, &options))
+        );
+    }
+
+    fn element_names(html: &str) -> Vec<String> {
+        let document = Document::parse(html, &ParserOptions::default());
+
+        document
+            .descendants(document.root())
+            .filter_map(|id| document.name(id).map(|name| name.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn formatting_is_idempotent_and_keeps_elements() {
+        let test_data = read_all_test_file_data("./../../test_data/");
+        let options = FormatOptions::default();
+
+        for test_data in &test_data {
+            let formatted = format(test_data, &options);
+
+            assert_eq!(format(&formatted, &options), formatted);
+            assert_eq!(element_names(&formatted), element_names(test_data));
+        }
+    }
+
+    /** Appends the text, whitespace is collapsed unless it is preformatted. */
+    fn flush_text(text: &mut String, is_preformatted: bool, output: &mut String) {
+        if is_preformatted {
+            output.push_str(text);
+        } else {
+            output.push_str(&text.split_ascii_whitespace().collect::<Vec<_>>().join(" "));
+        }
+        text.clear();
+    }
+
+    /**
+     * The tree as text without comments. Whitespace in text is collapsed, except in preformatted
+     * elements, and an attribute with its name as value is the same as an empty one.
+     */
+    fn normalized(
+        document: &Document,
+        id: NodeId,
+        is_preformatted: bool,
+        text: &mut String,
+        output: &mut String,
+    ) {
+        let mut is_child_preformatted = is_preformatted;
+
+        match &document[id].data {
+            NodeData::Element { name, attrs } => {
+                flush_text(text, is_preformatted, output);
+                output.push_str(&format!("<{}", name));
+                for (key, value) in attrs.iter() {
+                    let value = value.as_ref().map_or("", |(value, _)| value);
+                    let value = if value.eq_ignore_ascii_case(key) { "" } else { value };
+
+                    output.push_str(&format!(" {}={:?}", key.to_ascii_lowercase(), value));
+                }
+                output.push('>');
+                is_child_preformatted |= [
+                    "pre", "textarea", "listing", "script", "style", "xmp", "iframe", "noembed",
+                    "noframes", "plaintext",
+                ]
+                .iter()
+                .any(|preformatted| name.as_str().eq_ignore_ascii_case(preformatted));
+            }
+            NodeData::Text(data) => text.push_str(data),
+            NodeData::ProcessingInstruction(data) => {
+                flush_text(text, is_preformatted, output);
+                output.push_str(data);
+            }
+            NodeData::Document | NodeData::Comment(_) => {}
+        }
+        for child in &document[id].children {
+            normalized(document, *child, is_child_preformatted, text, output);
+        }
+        if let Some(name) = document.name(id) {
+            flush_text(text, is_child_preformatted, output);
+            output.push_str(&format!("</{}>", name));
+        }
+    }
+
+    #[test]
+    fn minified_documents_parse_to_the_same_tree() {
+        let test_data = read_all_test_file_data("./../../test_data/");
+        let tree = |html: &str| {
+            let document = Document::parse(html, &ParserOptions::default());
+            let mut text = String::new();
+            let mut output = String::new();
+
+            normalized(&document, document.root(), false, &mut text, &mut output);
+            flush_text(&mut text, false, &mut output);
+            output
+        };
+
+        for test_data in &test_data {
+            let minified = minify(test_data, &MinifyOptions::default());
+
+            assert!(minified.len() < test_data.len());
+            assert_eq!(tree(&minified), tree(test_data));
+        }
+    }
+
+    #[test]
+    fn extracts_articles() {
+        let test_data = read_all_test_file_data("./../../test_data/");
+
+        for test_data in &test_data {
+            let document = Document::parse(test_data, &ParserOptions::default());
+            let article = document.article(&ArticleOptions::default()).unwrap();
+
+            let extraction = format!(
+                "title: {}\nbyline: {}\nlead image: {}\n\n{}",
+                article.title.unwrap_or_default(),
+                article.byline.unwrap_or_default(),
+                article.lead_image.unwrap_or_default(),
+                article.content.inner_text(article.content.root())
+            );
+
+            insta::assert_snapshot!(extraction);
+        }
+    }
 }
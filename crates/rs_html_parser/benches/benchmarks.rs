@@ -1,6 +1,7 @@
-use criterion::{criterion_group, criterion_main, Criterion};
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
 use rs_html_parser::{Parser, ParserOptions};
-use rs_html_parser_tokenizer::TokenizerOptions;
+use rs_html_parser_tokenizer::{Tokenizer, TokenizerOptions};
+use rs_html_parser_tokens::TokenKind;
 use std::path::PathBuf;
 use std::time::Duration;
 use std::{fs, io};
@@ -59,11 +60,35 @@ pub fn read_all_test_file_data(path: &str) -> Vec<String> {
 
 fn benchmark_test_data(c: &mut Criterion) {
     let test_data = read_all_test_file_data("./../../test_data/");
+    let total_bytes: usize = test_data.iter().map(|data| data.len()).sum();
 
-    c.bench_function("benchmarks", |b| {
+    let mut group = c.benchmark_group("test_data");
+    group.throughput(Throughput::Bytes(total_bytes as u64));
+
+    group.bench_function("tokenizer", |b| {
+        b.iter(|| {
+            let options = TokenizerOptions {
+                xml_mode: None,
+                decode_entities: None,
+                ignore_whitespace_between_tags: Some(true),
+            };
+
+            for test_data in &test_data {
+                let tokenizer = Tokenizer::new(test_data.as_bytes(), &options);
+
+                for _token in tokenizer {}
+            }
+        });
+    });
+
+    group.bench_function("parser", |b| {
         b.iter(|| {
             let options = ParserOptions {
                 xml_mode: false,
+                whitespace: None,
+                coalesce_text: None,
+                limits: None,
+                lazy_attributes: false,
                 tokenizer_options: TokenizerOptions {
                     xml_mode: None,
                     decode_entities: None,
@@ -78,6 +103,48 @@ fn benchmark_test_data(c: &mut Criterion) {
             }
         });
     });
+
+    // Reads only `href` of links, the other attributes are never decoded
+    group.bench_function("parser_lazy_attributes", |b| {
+        b.iter(|| {
+            let options = ParserOptions {
+                lazy_attributes: true,
+                tokenizer_options: TokenizerOptions {
+                    ignore_whitespace_between_tags: Some(true),
+                    ..Default::default()
+                },
+                ..Default::default()
+            };
+
+            for test_data in &test_data {
+                for token in Parser::new(test_data, &options) {
+                    if token.kind == TokenKind::OpenTag && &*token.data == "a" {
+                        black_box(token.attribute("href"));
+                    }
+                }
+            }
+        });
+    });
+
+    // All of the test data as one large document, tokenized on multiple threads
+    #[cfg(feature = "parallel")]
+    group.bench_function("parser_parallel", |b| {
+        let document = test_data.concat();
+
+        b.iter(|| {
+            let options = ParserOptions {
+                tokenizer_options: TokenizerOptions {
+                    ignore_whitespace_between_tags: Some(true),
+                    ..Default::default()
+                },
+                ..Default::default()
+            };
+
+            for _token in Parser::new_parallel(&document, &options) {}
+        });
+    });
+
+    group.finish();
 }
 
 criterion_group!(
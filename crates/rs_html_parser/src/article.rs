@@ -0,0 +1,885 @@
+use crate::tree::{Document, NodeData, NodeId};
+use crate::whitespace::collapse_and_trim_whitespace;
+use rs_html_parser_tokens::LocalName;
+
+#[derive(Default)]
+pub struct ArticleOptions {
+    /**
+     * The length of text that an article is expected to have. A shorter article is extracted
+     * again with fewer heuristics, and the longest extraction is returned.
+     *
+     * @default 500
+     */
+    pub min_text_length: Option<usize>,
+}
+
+/** The main content of a document, see `Document::article`. */
+#[derive(Debug, Clone)]
+pub struct Article {
+    pub title: Option<String>,
+    pub byline: Option<String>,
+    /** The URL of the image for the article, as it is in the document. */
+    pub lead_image: Option<String>,
+    /**
+     * A copy of the elements of the article, without what was left out as not being content, eg.
+     * sharing buttons or lists of links.
+     */
+    pub content: Document,
+}
+
+/** Class and id words of elements that are unlikely to be content, eg. `sidebar`. */
+const UNLIKELY: &[&str] = &[
+    "-ad-",
+    "ai2html",
+    "banner",
+    "breadcrumbs",
+    "combx",
+    "comment",
+    "community",
+    "cover-wrap",
+    "disqus",
+    "extra",
+    "footer",
+    "gdpr",
+    "header",
+    "legends",
+    "menu",
+    "related",
+    "remark",
+    "replies",
+    "rss",
+    "shoutbox",
+    "sidebar",
+    "skyscraper",
+    "social",
+    "sponsor",
+    "supplemental",
+    "ad-break",
+    "agegate",
+    "pagination",
+    "pager",
+    "popup",
+    "yom-remote",
+];
+/** Words that keep an element with an unlikely word, eg. `main-sidebar`. */
+const MAYBE: &[&str] = &[
+    "and", "article", "body", "column", "content", "main", "shadow",
+];
+const UNLIKELY_ROLES: &[&str] = &[
+    "menu",
+    "menubar",
+    "complementary",
+    "navigation",
+    "alert",
+    "alertdialog",
+    "dialog",
+];
+const POSITIVE: &[&str] = &[
+    "article",
+    "body",
+    "content",
+    "entry",
+    "hentry",
+    "h-entry",
+    "main",
+    "page",
+    "pagination",
+    "post",
+    "text",
+    "blog",
+    "story",
+];
+const NEGATIVE: &[&str] = &[
+    "-ad-",
+    "hidden",
+    "banner",
+    "combx",
+    "comment",
+    "com-",
+    "contact",
+    "footer",
+    "footnote",
+    "gdpr",
+    "masthead",
+    "media",
+    "meta",
+    "outbrain",
+    "promo",
+    "related",
+    "scroll",
+    "share",
+    "shoutbox",
+    "sidebar",
+    "skyscraper",
+    "sponsor",
+    "shopping",
+    "tags",
+    "widget",
+];
+const BYLINE: &[&str] = &["byline", "author", "dateline", "writtenby", "p-author"];
+/** Separators between the title of the article and the name of the site in `<title>`. */
+const TITLE_SEPARATORS: &[&str] = &[" | ", " - ", " – ", " — ", " \\ ", " / ", " > ", " » "];
+/** Paragraphs with less text are not scored. */
+const MIN_PARAGRAPH_LENGTH: usize = 25;
+
+/** The heuristics of an extraction, they are turned off one by one when the article is short. */
+#[derive(Copy, Clone)]
+struct Flags {
+    strip_unlikely: bool,
+    weight_classes: bool,
+    clean_conditionally: bool,
+}
+
+/** The content of an element, without what is removed. */
+#[derive(Default, Copy, Clone)]
+struct Stats {
+    /** The length of the text with collapsed whitespace. */
+    text_length: usize,
+    /** The length of the text in links. */
+    link_length: usize,
+    commas: usize,
+    paragraphs: usize,
+    images: usize,
+    list_items: usize,
+    inputs: usize,
+    /** Whether there is a block in the element, otherwise a `div` is scored as a paragraph. */
+    has_block: bool,
+}
+
+struct Extractor<'a> {
+    document: &'a Document,
+    flags: Flags,
+    /** Whether the node is left out before scoring, as it is hidden or unlikely to be content. */
+    removed: Vec<bool>,
+    stats: Vec<Stats>,
+    scores: Vec<Option<f64>>,
+    /** Whether the node is in a `figure`, where images are content. */
+    in_figure: Vec<bool>,
+}
+
+fn contains_any(text: &str, words: &[&str]) -> bool {
+    words.iter().any(|word| text.contains(word))
+}
+
+fn word_count(text: &str) -> usize {
+    text.split_whitespace().count()
+}
+
+/** The `class` and `id` of the element in lowercase. */
+fn class_and_id(document: &Document, id: NodeId) -> String {
+    format!(
+        "{} {}",
+        document.attribute(id, "class").unwrap_or(""),
+        document.attribute(id, "id").unwrap_or("")
+    )
+    .to_ascii_lowercase()
+}
+
+/** Whether the element is not rendered, so never content. */
+fn is_hidden(document: &Document, id: NodeId, name: &LocalName) -> bool {
+    let style = document
+        .attribute(id, "style")
+        .unwrap_or("")
+        .to_ascii_lowercase()
+        .replace(' ', "");
+
+    matches!(
+        name.element(),
+        LocalName::Head
+            | LocalName::Script
+            | LocalName::Style
+            | LocalName::Noscript
+            | LocalName::Template
+            | LocalName::Link
+            | LocalName::Meta
+    ) || document.attribute(id, "hidden").is_some()
+        || document
+            .attribute(id, "aria-hidden")
+            .is_some_and(|value| value.trim().eq_ignore_ascii_case("true"))
+        || style.contains("display:none")
+        || style.contains("visibility:hidden")
+}
+
+/** The `content` of the first `meta` with one of the names, in the order of the names. */
+fn meta<'a>(metas: &[(String, &'a str)], names: &[&str]) -> Option<&'a str> {
+    names.iter().find_map(|name| {
+        metas
+            .iter()
+            .find(|(key, content)| key == name && !content.trim().is_empty())
+            .map(|(_, content)| content.trim())
+    })
+}
+
+impl<'a> Extractor<'a> {
+    fn new(document: &'a Document, flags: Flags) -> Extractor<'a> {
+        let mut extractor = Extractor {
+            document,
+            flags,
+            removed: vec![false; document.len()],
+            stats: vec![Stats::default(); document.len()],
+            scores: vec![None; document.len()],
+            in_figure: vec![false; document.len()],
+        };
+
+        extractor.remove_unlikely();
+        extractor.count();
+        extractor.find_figures();
+        extractor
+    }
+
+    /** Marks the nodes in a `figure`, the parents of the nodes come before them. */
+    fn find_figures(&mut self) {
+        let document = self.document;
+
+        for id in document.nodes() {
+            let Some(parent) = document[id].parent else {
+                continue;
+            };
+
+            self.in_figure[id.index()] =
+                self.in_figure[parent.index()] || document.is_element(parent, &LocalName::Figure);
+        }
+    }
+
+    fn remove_unlikely(&mut self) {
+        let document = self.document;
+        // Whether the node is in a table or in code, where nothing is unlikely
+        let mut is_protected = vec![false; document.len()];
+
+        for id in document.nodes() {
+            let Some(name) = document.name(id) else {
+                continue;
+            };
+            let parent = document[id].parent.unwrap();
+
+            is_protected[id.index()] = is_protected[parent.index()]
+                || matches!(name.element(), LocalName::Table | LocalName::Code);
+
+            if self.removed[parent.index()] || is_hidden(document, id, name) {
+                self.removed[id.index()] = true;
+                continue;
+            }
+            if !self.flags.strip_unlikely
+                || is_protected[parent.index()]
+                || matches!(name.element(), LocalName::Body | LocalName::A)
+            {
+                continue;
+            }
+
+            let class_and_id = class_and_id(document, id);
+            let role = document.attribute(id, "role").unwrap_or("");
+
+            self.removed[id.index()] = (contains_any(&class_and_id, UNLIKELY)
+                && !contains_any(&class_and_id, MAYBE))
+                || UNLIKELY_ROLES.contains(&role.trim().to_ascii_lowercase().as_str());
+        }
+    }
+
+    /** Counts the content of the elements, from the last node up to the root. */
+    fn count(&mut self) {
+        let document = self.document;
+
+        for id in document.nodes().rev() {
+            if self.removed[id.index()] {
+                continue;
+            }
+
+            let mut stats = self.stats[id.index()];
+            let name = match &document[id].data {
+                NodeData::Text(text) => {
+                    stats.text_length = text
+                        .split_ascii_whitespace()
+                        .map(|word| word.chars().count() + 1)
+                        .sum();
+                    stats.commas = text
+                        .chars()
+                        .filter(|c| matches!(c, ',' | '\u{60c}' | '\u{fe50}' | '\u{ff0c}'))
+                        .count();
+                    None
+                }
+                NodeData::Element { name, .. } => Some(name.element()),
+                _ => continue,
+            };
+
+            if name == Some(LocalName::A) {
+                stats.link_length = stats.text_length;
+            }
+            self.stats[id.index()] = stats;
+
+            let Some(parent) = document[id].parent else {
+                continue;
+            };
+            let totals = &mut self.stats[parent.index()];
+
+            totals.text_length += stats.text_length;
+            totals.link_length += stats.link_length;
+            totals.commas += stats.commas;
+            totals.paragraphs += stats.paragraphs;
+            totals.images += stats.images;
+            totals.list_items += stats.list_items;
+            totals.inputs += stats.inputs;
+            totals.has_block |= stats.has_block;
+
+            match name {
+                Some(LocalName::P) => totals.paragraphs += 1,
+                Some(LocalName::Img) => totals.images += 1,
+                Some(LocalName::Li) => totals.list_items += 1,
+                Some(LocalName::Input | LocalName::Select | LocalName::Textarea) => {
+                    totals.inputs += 1
+                }
+                _ => {}
+            }
+            if matches!(
+                name,
+                Some(
+                    LocalName::Blockquote
+                        | LocalName::Dl
+                        | LocalName::Div
+                        | LocalName::Img
+                        | LocalName::Ol
+                        | LocalName::P
+                        | LocalName::Pre
+                        | LocalName::Table
+                        | LocalName::Ul
+                )
+            ) {
+                totals.has_block = true;
+            }
+        }
+    }
+
+    fn link_density(&self, id: NodeId) -> f64 {
+        let stats = &self.stats[id.index()];
+
+        if stats.text_length == 0 {
+            0.0
+        } else {
+            stats.link_length as f64 / stats.text_length as f64
+        }
+    }
+
+    fn class_weight(&self, id: NodeId) -> f64 {
+        if !self.flags.weight_classes {
+            return 0.0;
+        }
+
+        let mut weight = 0.0;
+
+        for name in ["class", "id"] {
+            let value = self
+                .document
+                .attribute(id, name)
+                .unwrap_or("")
+                .to_ascii_lowercase();
+
+            if contains_any(&value, NEGATIVE) {
+                weight -= 25.0;
+            }
+            if contains_any(&value, POSITIVE) {
+                weight += 25.0;
+            }
+        }
+        weight
+    }
+
+    fn score(&mut self, id: NodeId) -> f64 {
+        if let Some(score) = self.scores[id.index()] {
+            return score;
+        }
+
+        use LocalName::*;
+        let score = match self.document.name(id).map(LocalName::element) {
+            Some(Div) => 5.0,
+            Some(Pre | Td | Blockquote) => 3.0,
+            Some(Address | Ol | Ul | Dl | Dd | Dt | Li | Form) => -3.0,
+            Some(H1 | H2 | H3 | H4 | H5 | H6 | Th) => -5.0,
+            _ => 0.0,
+        } + self.class_weight(id);
+
+        self.scores[id.index()] = Some(score);
+        score
+    }
+
+    /** Scores the paragraphs, and adds their scores to the elements they are in. */
+    fn score_paragraphs(&mut self) {
+        let document = self.document;
+
+        for id in document.nodes() {
+            let Some(name) = document.name(id) else {
+                continue;
+            };
+            let stats = self.stats[id.index()];
+
+            use LocalName::*;
+            let is_paragraph = match name.element() {
+                Section | H2 | H3 | H4 | H5 | H6 | P | Td | Pre => true,
+                Div => !stats.has_block,
+                _ => false,
+            };
+
+            if !is_paragraph || self.removed[id.index()] || stats.text_length < MIN_PARAGRAPH_LENGTH
+            {
+                continue;
+            }
+
+            let score = 1.0 + stats.commas as f64 + (stats.text_length / 100).min(3) as f64;
+            let mut ancestor = document[id].parent;
+
+            for level in 0..5 {
+                let Some(id) = ancestor.filter(|id| {
+                    document
+                        .name(*id)
+                        .is_some_and(|name| name.element() != Html)
+                }) else {
+                    break;
+                };
+                let divider = match level {
+                    0 => 1.0,
+                    1 => 2.0,
+                    level => level as f64 * 3.0,
+                };
+                let ancestor_score = self.score(id);
+
+                self.scores[id.index()] = Some(ancestor_score + score / divider);
+                ancestor = document[id].parent;
+            }
+        }
+
+        // Links are not content, so the scores are reduced by the text in links
+        for id in document.nodes() {
+            if let Some(score) = self.scores[id.index()] {
+                self.scores[id.index()] = Some(score * (1.0 - self.link_density(id)));
+            }
+        }
+    }
+
+    fn ancestors(&self, id: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        std::iter::successors(self.document[id].parent, |id| self.document[*id].parent)
+            .filter(|id| self.document.name(*id).is_some())
+    }
+
+    fn is_body(&self, id: NodeId) -> bool {
+        self.document
+            .name(id)
+            .is_none_or(|name| matches!(name.element(), LocalName::Body | LocalName::Html))
+    }
+
+    /** The element with the best score, or an element around it that has more of the content. */
+    fn top_candidate(&mut self) -> Option<NodeId> {
+        let mut candidates: Vec<(NodeId, f64)> = self
+            .document
+            .nodes()
+            .filter_map(|id| Some((id, self.scores[id.index()]?)))
+            .collect();
+
+        candidates.sort_by(|a, b| b.1.total_cmp(&a.1));
+        candidates.truncate(5);
+
+        let &(mut top, top_score) = candidates.first()?;
+
+        if self.is_body(top) {
+            return None;
+        }
+
+        // Content that is split into several candidates is in an element around them
+        let alternatives: Vec<Vec<NodeId>> = candidates[1..]
+            .iter()
+            .filter(|(_, score)| *score / top_score >= 0.75)
+            .map(|(id, _)| self.ancestors(*id).collect())
+            .collect();
+
+        if alternatives.len() >= 3 {
+            let ancestor = self
+                .ancestors(top)
+                .take_while(|id| !self.is_body(*id))
+                .find(|id| {
+                    alternatives
+                        .iter()
+                        .filter(|ancestors| ancestors.contains(id))
+                        .count()
+                        >= 3
+                });
+
+            if let Some(ancestor) = ancestor {
+                top = ancestor;
+                self.score(top);
+            }
+        }
+
+        // A parent with a better score has more of the content
+        let mut last_score = self.scores[top.index()].unwrap_or(0.0);
+        let threshold = last_score / 3.0;
+        let parents: Vec<_> = self
+            .ancestors(top)
+            .take_while(|id| !self.is_body(*id))
+            .collect();
+
+        for parent in parents {
+            let Some(score) = self.scores[parent.index()] else {
+                continue;
+            };
+
+            if score < threshold {
+                break;
+            }
+            if score > last_score {
+                top = parent;
+                break;
+            }
+            last_score = score;
+        }
+
+        while let Some(parent) = self.document[top].parent.filter(|id| !self.is_body(*id)) {
+            if self.document.element_children(parent).count() != 1 {
+                break;
+            }
+            top = parent;
+            self.score(top);
+        }
+
+        Some(top)
+    }
+
+    /** The top candidate and the siblings of it that are content too. */
+    fn content(&mut self, top: NodeId) -> Vec<NodeId> {
+        let document = self.document;
+        let Some(parent) = document[top].parent else {
+            return vec![top];
+        };
+        let top_score = self.scores[top.index()].unwrap_or(0.0);
+        let threshold = (top_score * 0.2).max(10.0);
+        let top_class = document.attribute(top, "class").unwrap_or("");
+
+        document
+            .element_children(parent)
+            .filter(|sibling| {
+                if *sibling == top {
+                    return true;
+                }
+                if self.removed[sibling.index()] {
+                    return false;
+                }
+
+                let bonus = if !top_class.is_empty()
+                    && document.attribute(*sibling, "class") == Some(top_class)
+                {
+                    top_score * 0.2
+                } else {
+                    0.0
+                };
+
+                if self.scores[sibling.index()].is_some_and(|score| score + bonus >= threshold) {
+                    return true;
+                }
+                if !document.is_element(*sibling, &LocalName::P) {
+                    return false;
+                }
+
+                let length = self.stats[sibling.index()].text_length;
+                let link_density = self.link_density(*sibling);
+                let text = document.text_content(*sibling);
+                let text = collapse_and_trim_whitespace(&text);
+
+                (length > 80 && link_density < 0.25)
+                    || (length > 0
+                        && length <= 80
+                        && link_density == 0.0
+                        && (text.ends_with('.') || text.contains(". ")))
+            })
+            .collect()
+    }
+
+    /** Whether the element in the article is left out, as it is not part of the content. */
+    fn is_junk(&self, id: NodeId) -> bool {
+        let document = self.document;
+        let Some(name) = document.name(id) else {
+            return self.removed[id.index()];
+        };
+        let stats = &self.stats[id.index()];
+        let weight = self.class_weight(id);
+
+        use LocalName::*;
+        match name.element() {
+            _ if self.removed[id.index()] => true,
+            Object | Embed | Footer | Aside | Nav | Iframe | Input | Textarea | Select | Button => {
+                true
+            }
+            H1 | H2 => weight < 0.0,
+            _ if class_and_id(document, id).contains("share") && stats.text_length < 500 => true,
+            Form | Fieldset | Table | Ul | Div if self.flags.clean_conditionally => {
+                self.is_conditionally_junk(id, &name.element(), weight)
+            }
+            _ => false,
+        }
+    }
+
+    fn is_conditionally_junk(&self, id: NodeId, name: &LocalName, weight: f64) -> bool {
+        let stats = &self.stats[id.index()];
+
+        if *name == LocalName::Table && self.is_data_table(id) {
+            return false;
+        }
+        if weight + self.scores[id.index()].unwrap_or(0.0) < 0.0 {
+            return true;
+        }
+        if stats.commas >= 10 {
+            return false;
+        }
+
+        let is_list = *name == LocalName::Ul;
+        let is_in_figure = self.in_figure[id.index()];
+        let paragraphs = stats.paragraphs as f64;
+        let images = stats.images;
+        let link_density = self.link_density(id);
+
+        (images > 1 && paragraphs / (images as f64) < 0.5 && !is_in_figure)
+            || (!is_list && stats.list_items.saturating_sub(100) > stats.paragraphs)
+            || (stats.inputs as f64 > (paragraphs / 3.0).floor())
+            || (!is_list
+                && stats.text_length < MIN_PARAGRAPH_LENGTH
+                && (images == 0 || images > 2)
+                && !is_in_figure)
+            || (!is_list && weight < 25.0 && link_density > 0.2)
+            || (weight >= 25.0 && link_density > 0.5)
+    }
+
+    /** Whether the table has data, not only the layout of the page. */
+    fn is_data_table(&self, id: NodeId) -> bool {
+        let document = self.document;
+
+        if document.attribute(id, "role") == Some("presentation") {
+            return false;
+        }
+        document.descendants(id).any(|id| {
+            document.name(id).is_some_and(|name| {
+                matches!(
+                    name.element(),
+                    LocalName::Caption | LocalName::Thead | LocalName::Th | LocalName::Tfoot
+                )
+            })
+        })
+    }
+
+    fn extract(&mut self, byline: Option<NodeId>) -> Document {
+        let document = self.document;
+
+        if let Some(byline) = byline {
+            self.removed[byline.index()] = true;
+        }
+        self.score_paragraphs();
+
+        let mut content = Document::from_tokens(std::iter::empty());
+        let root = content.root();
+
+        match self.top_candidate() {
+            Some(top) => {
+                let nodes = self.content(top);
+
+                for id in nodes {
+                    // Only what is in the elements of the article is cleaned, not the elements
+                    content
+                        .append_copy(root, document, id, &|node| node != id && self.is_junk(node));
+                }
+            }
+            None => {
+                let body = document
+                    .descendants(document.root())
+                    .find(|id| document.is_element(*id, &LocalName::Body))
+                    .unwrap_or(document.root());
+
+                for id in &document[body].children {
+                    content.append_copy(root, document, *id, &|node| self.is_junk(node));
+                }
+            }
+        }
+        content
+    }
+}
+
+impl Document {
+    fn metas(&self) -> Vec<(String, &str)> {
+        self.descendants(self.root())
+            .filter(|id| self.is_element(*id, &LocalName::Meta))
+            .filter_map(|id| {
+                let key = self
+                    .attribute(id, "property")
+                    .or_else(|| self.attribute(id, "name"))?;
+
+                Some((
+                    key.trim().to_ascii_lowercase(),
+                    self.attribute(id, "content")?,
+                ))
+            })
+            .collect()
+    }
+
+    fn article_title(&self, metas: &[(String, &str)]) -> Option<String> {
+        let title = meta(
+            metas,
+            &[
+                "dc:title",
+                "dcterm:title",
+                "og:title",
+                "title",
+                "twitter:title",
+            ],
+        );
+
+        if let Some(title) = title {
+            return Some(collapse_and_trim_whitespace(title).into_owned());
+        }
+
+        let title = self
+            .descendants(self.root())
+            .find(|id| self.is_element(*id, &LocalName::Title))
+            .map(|id| collapse_and_trim_whitespace(&self.text_content(id)).into_owned())
+            .filter(|title| !title.is_empty())?;
+        let separator = TITLE_SEPARATORS
+            .iter()
+            .filter_map(|separator| Some((title.rfind(separator)?, *separator)))
+            .max();
+
+        let Some((index, separator)) = separator else {
+            return Some(title);
+        };
+        let is_hierarchical = matches!(separator, " \\ " | " / " | " > " | " » ");
+        // Without the name of the site after the last separator, or before the first one
+        let mut article_title = title[..index].trim().to_string();
+
+        if word_count(&article_title) < 3 {
+            let first = TITLE_SEPARATORS
+                .iter()
+                .filter_map(|separator| Some(title.find(separator)? + separator.len()))
+                .min()
+                .unwrap();
+
+            article_title = title[first..].trim().to_string();
+        }
+        if word_count(&article_title) <= 4 && !is_hierarchical {
+            return Some(title);
+        }
+        Some(article_title)
+    }
+
+    /** The element with the author of the article, eg. `<span class=byline>By A. Writer</span>`. */
+    fn byline_element(&self, removed: &[bool]) -> Option<(NodeId, String)> {
+        self.nodes().find_map(|id| {
+            if removed[id.index()] || self.name(id).is_none() {
+                return None;
+            }
+
+            let is_byline = self
+                .attribute(id, "rel")
+                .is_some_and(|rel| rel.eq_ignore_ascii_case("author"))
+                || self
+                    .attribute(id, "itemprop")
+                    .is_some_and(|itemprop| itemprop.to_ascii_lowercase().contains("author"))
+                || contains_any(&class_and_id(self, id), BYLINE);
+
+            if !is_byline {
+                return None;
+            }
+
+            let text = collapse_and_trim_whitespace(&self.text_content(id)).into_owned();
+
+            (!text.is_empty() && text.chars().count() < 100).then_some((id, text))
+        })
+    }
+
+    /**
+     * Extracts the main content of the document, as Mozilla's Readability does. Paragraphs are
+     * scored by their text and commas, the scores are added to the elements they are in and then
+     * reduced by the density of links in them, with class and id names as hints. The element with
+     * the best score, and siblings of it that look like content, are the article. Returns `None`
+     * when the document has no text.
+     */
+    pub fn article(&self, options: &ArticleOptions) -> Option<Article> {
+        let min_text_length = options.min_text_length.unwrap_or(500);
+        let metas = self.metas();
+        let meta_byline = meta(
+            &metas,
+            &[
+                "dc:creator",
+                "dcterm:creator",
+                "author",
+                "parsely-author",
+                "article:author",
+            ],
+        )
+        .filter(|author| !author.starts_with("http"))
+        .map(|author| collapse_and_trim_whitespace(author).into_owned());
+        let all_flags = [
+            (true, true, true),
+            (false, true, true),
+            (false, false, true),
+            (false, false, false),
+        ];
+        let mut best: Option<(usize, Document, Option<String>)> = None;
+
+        for (strip_unlikely, weight_classes, clean_conditionally) in all_flags {
+            let mut extractor = Extractor::new(
+                self,
+                Flags {
+                    strip_unlikely,
+                    weight_classes,
+                    clean_conditionally,
+                },
+            );
+            let byline_element = match meta_byline {
+                Some(_) => None,
+                None => self.byline_element(&extractor.removed),
+            };
+            let content = extractor.extract(byline_element.as_ref().map(|(id, _)| *id));
+            let length = content.inner_text(content.root()).chars().count();
+            let byline = meta_byline
+                .clone()
+                .or(byline_element.map(|(_, byline)| byline));
+
+            if best.as_ref().is_none_or(|(best, ..)| length > *best) {
+                best = Some((length, content, byline));
+            }
+            if length >= min_text_length {
+                break;
+            }
+        }
+
+        let (length, content, byline) = best?;
+
+        if length == 0 {
+            return None;
+        }
+
+        let lead_image = meta(
+            &metas,
+            &[
+                "og:image",
+                "og:image:url",
+                "og:image:secure_url",
+                "twitter:image",
+                "twitter:image:src",
+            ],
+        )
+        .map(str::to_string)
+        .or_else(|| {
+            content.descendants(content.root()).find_map(|id| {
+                let src = content.attribute(id, "src").map(str::trim)?;
+                let is_small = ["width", "height"].iter().any(|name| {
+                    content
+                        .attribute(id, name)
+                        .and_then(|size| size.trim().trim_end_matches("px").parse::<u32>().ok())
+                        .is_some_and(|size| size < 100)
+                });
+
+                (content.is_element(id, &LocalName::Img) && !src.is_empty() && !is_small)
+                    .then(|| src.to_string())
+            })
+        });
+
+        Some(Article {
+            title: self.article_title(&metas),
+            byline,
+            lead_image,
+            content,
+        })
+    }
+}
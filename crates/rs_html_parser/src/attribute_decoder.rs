@@ -0,0 +1,93 @@
+use crate::attributes::{slice, AttributesBuilder};
+use crate::{Limits, ParserOptions};
+use rs_html_parser_tokenizer::{Tokenizer, TokenizerOptions};
+use rs_html_parser_tokenizer_tokens::{QuoteType, TokenizerTokenLocation};
+use rs_html_parser_tokens::{AttributeDecoder, Attributes};
+use unicase::UniCase;
+
+/**
+ * Decodes the raw attributes of lazily parsed tags. The source is tokenized again as a tag with
+ * the options of the original parser, so the attributes are the same as in the eager mode.
+ */
+pub struct LazyAttributeDecoder {
+    limits: Limits,
+    tokenizer_options: TokenizerOptions,
+}
+
+impl LazyAttributeDecoder {
+    pub fn new(options: &ParserOptions) -> LazyAttributeDecoder {
+        LazyAttributeDecoder {
+            limits: options.limits.unwrap_or_default(),
+            tokenizer_options: TokenizerOptions {
+                xml_mode: options.tokenizer_options.xml_mode,
+                decode_entities: options.tokenizer_options.decode_entities,
+                ignore_whitespace_between_tags: None,
+            },
+        }
+    }
+
+    fn tag(source: &[u8]) -> Vec<u8> {
+        let mut tag = Vec::with_capacity(source.len() + 3);
+
+        tag.extend_from_slice(b"<a");
+        tag.extend_from_slice(source);
+        tag.push(b'>');
+
+        tag
+    }
+}
+
+impl AttributeDecoder for LazyAttributeDecoder {
+    fn decode(&self, source: &[u8]) -> Option<Attributes> {
+        let tag = LazyAttributeDecoder::tag(source);
+        let mut attributes = AttributesBuilder::default();
+
+        for tokenizer_token in Tokenizer::new(&tag, &self.tokenizer_options) {
+            if tokenizer_token.location == TokenizerTokenLocation::OpenTagEnd {
+                break;
+            }
+            attributes.on_token(&tokenizer_token, &tag, &self.limits);
+        }
+
+        if attributes.attribs.is_empty() {
+            None
+        } else {
+            Some(attributes.attribs)
+        }
+    }
+
+    fn decode_attribute(&self, source: &[u8], name: &str) -> Option<Option<(Box<str>, QuoteType)>> {
+        // The limits depend on the attributes before, so all of them are decoded
+        if self.limits.max_attributes.is_some() || self.limits.max_attribute_length.is_some() {
+            return self.decode(source)?.remove(name);
+        }
+
+        // Otherwise the first attribute with the name wins, the others are skipped
+        let tag = LazyAttributeDecoder::tag(source);
+        let name = UniCase::new(name);
+        let mut attributes = AttributesBuilder::default();
+        let mut is_match = false;
+
+        for tokenizer_token in Tokenizer::new(&tag, &self.tokenizer_options) {
+            match tokenizer_token.location {
+                TokenizerTokenLocation::OpenTagEnd => break,
+                TokenizerTokenLocation::AttrName => {
+                    is_match = UniCase::new(&*slice(&tag, &tokenizer_token)) == name;
+                }
+                _ => {}
+            }
+            if is_match {
+                attributes.on_token(&tokenizer_token, &tag, &self.limits);
+            }
+            if is_match && tokenizer_token.location == TokenizerTokenLocation::AttrEnd {
+                return attributes
+                    .attribs
+                    .into_iter()
+                    .next()
+                    .map(|(_, value)| value);
+            }
+        }
+
+        None
+    }
+}
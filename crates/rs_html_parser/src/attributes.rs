@@ -0,0 +1,109 @@
+use crate::limits::truncate;
+use crate::{Limit, Limits};
+use rs_html_parser_tokenizer_tokens::{TokenizerToken, TokenizerTokenLocation};
+use rs_html_parser_tokens::Attributes;
+use std::borrow::Cow;
+use std::mem::take;
+
+/**
+ * Collects the attributes of a tag from the attribute tokens of the tokenizer, applying the
+ * attribute limits. Both the parser and the lazy attribute decoder use it, so they agree.
+ */
+#[derive(Default)]
+pub struct AttributesBuilder<'a> {
+    pub attribs: Attributes,
+    name: Cow<'a, str>,
+    value: Option<String>,
+    // Whether the value was cut off, the rest of it is dropped
+    is_value_truncated: bool,
+}
+
+pub fn slice<'a>(buffer: &'a [u8], tokenizer_token: &TokenizerToken) -> Cow<'a, str> {
+    String::from_utf8_lossy(&buffer[tokenizer_token.start..tokenizer_token.end])
+}
+
+impl<'a> AttributesBuilder<'a> {
+    pub fn clear(&mut self) {
+        self.attribs.clear();
+        self.name = Default::default();
+        self.value = None;
+        self.is_value_truncated = false;
+    }
+
+    /**
+     * Handles an `AttrName`, `AttrData`, `AttrEntity` or `AttrEnd` token of `buffer`. Returns the
+     * limit that was exceeded by it, if any.
+     */
+    pub fn on_token(
+        &mut self,
+        tokenizer_token: &TokenizerToken,
+        buffer: &'a [u8],
+        limits: &Limits,
+    ) -> Option<Limit> {
+        match tokenizer_token.location {
+            TokenizerTokenLocation::AttrName => {
+                self.name = slice(buffer, tokenizer_token);
+                None
+            }
+            TokenizerTokenLocation::AttrData => {
+                self.push_value(&slice(buffer, tokenizer_token), limits)
+            }
+            TokenizerTokenLocation::AttrEntity => {
+                let c = char::from_u32(tokenizer_token.code).unwrap_or(char::REPLACEMENT_CHARACTER);
+
+                self.push_value(c.encode_utf8(&mut [0; 4]), limits)
+            }
+            TokenizerTokenLocation::AttrEnd => self.on_end(tokenizer_token, limits),
+            _ => None,
+        }
+    }
+
+    fn push_value(&mut self, value: &str, limits: &Limits) -> Option<Limit> {
+        if self.is_value_truncated {
+            return None;
+        }
+
+        let existing_len = self.value.as_ref().map_or(0, |v| v.len());
+        let mut exceeded = None;
+        let value = match limits.max_attribute_length {
+            Some(max) if existing_len + value.len() > max => {
+                exceeded = Some(Limit::AttributeLength);
+                self.is_value_truncated = true;
+                truncate(value, max.saturating_sub(existing_len))
+            }
+            _ => value,
+        };
+
+        self.value.get_or_insert_with(String::new).push_str(value);
+
+        exceeded
+    }
+
+    fn on_end(&mut self, tokenizer_token: &TokenizerToken, limits: &Limits) -> Option<Limit> {
+        let value = self.value.take();
+        self.is_value_truncated = false;
+
+        if self.attribs.contains_key(&*self.name) {
+            return None;
+        }
+        if limits
+            .max_attributes
+            .is_some_and(|max| self.attribs.len() >= max)
+        {
+            return Some(Limit::Attributes);
+        }
+        if limits
+            .max_attribute_length
+            .is_some_and(|max| self.name.len() > max)
+        {
+            return Some(Limit::AttributeLength);
+        }
+
+        let value = value.map(|value| (value.into_boxed_str(), tokenizer_token.quote));
+
+        self.attribs
+            .insert(take(&mut self.name).into_owned(), value);
+
+        None
+    }
+}
@@ -1,144 +1,285 @@
-macro_rules! match_ignore_ascii_case {
-    ($input:expr, $($pattern:expr),* $(,)?) => {
-        {
-            let input_len = $input.len();
-            $(
-                if input_len == $pattern.len() && $input.eq_ignore_ascii_case($pattern) {
-                    // Return true if a match is found
-                    return true;
-                }
-            )*
-            // Return false if no match is found
-            false
-        }
+use rs_html_parser_tokens::LocalName;
+use rs_html_parser_tokens::LocalName::*;
+
+/** Matches the known element of the name, ignoring ASCII case. */
+macro_rules! match_element {
+    ($name:expr, $($pattern:pat_param)|+) => {
+        matches!($name.as_element(), $($pattern)|+)
     };
 }
 
-pub fn is_form_tag(tag_name: &str) -> bool {
-    match_ignore_ascii_case!(
-        tag_name, "input", "option", "optgroup", "select", "button", "datalist", "textarea"
+pub fn is_form_tag(tag_name: &LocalName) -> bool {
+    match_element!(
+        tag_name,
+        Input | Option | Optgroup | Select | Button | Datalist | Textarea
     )
 }
 
-pub fn is_p_tag(tag_name: &str) -> bool {
-    "p".eq_ignore_ascii_case(tag_name)
+pub fn is_p_tag(tag_name: &LocalName) -> bool {
+    match_element!(tag_name, P)
 }
 
-pub fn is_table_section_tags(tag_name: &str) -> bool {
-    match_ignore_ascii_case!(tag_name, "thead", "tbody")
+pub fn is_table_section_tags(tag_name: &LocalName) -> bool {
+    match_element!(tag_name, Thead | Tbody)
 }
 
-pub fn is_dd_dt_tags(tag_name: &str) -> bool {
-    match_ignore_ascii_case!(tag_name, "dd", "dt")
+pub fn is_dd_dt_tags(tag_name: &LocalName) -> bool {
+    match_element!(tag_name, Dd | Dt)
 }
 
-pub fn is_rtp_tags(tag_name: &str) -> bool {
-    match_ignore_ascii_case!(tag_name, "rt", "rp")
+pub fn is_rtp_tags(tag_name: &LocalName) -> bool {
+    match_element!(tag_name, Rt | Rp)
 }
 
-pub fn is_void_elements(tag_name: &str) -> bool {
-    match_ignore_ascii_case!(
-        tag_name, "area", "base", "basefont", "br", "col", "command", "embed", "frame", "hr",
-        "img", "input", "isindex", "keygen", "link", "meta", "param", "source", "track", "wbr"
+pub fn is_void_elements(tag_name: &LocalName) -> bool {
+    match_element!(
+        tag_name,
+        Area | Base
+            | Basefont
+            | Br
+            | Col
+            | Command
+            | Embed
+            | Frame
+            | Hr
+            | Img
+            | Input
+            | Isindex
+            | Keygen
+            | Link
+            | Meta
+            | Param
+            | Source
+            | Track
+            | Wbr
     )
 }
 
-pub fn is_tr_th_td(tag_name: &str) -> bool {
-    match_ignore_ascii_case!(tag_name, "tr", "th", "td")
+pub fn is_tr_th_td(tag_name: &LocalName) -> bool {
+    match_element!(tag_name, Tr | Th | Td)
 }
 
-pub fn is_th(tag_name: &str) -> bool {
-    tag_name.eq_ignore_ascii_case("th")
+pub fn is_th(tag_name: &LocalName) -> bool {
+    match_element!(tag_name, Th)
 }
 
-pub fn is_thead_th_td(tag_name: &str) -> bool {
-    match_ignore_ascii_case!(tag_name, "thead", "th", "td")
+pub fn is_thead_th_td(tag_name: &LocalName) -> bool {
+    match_element!(tag_name, Thead | Th | Td)
 }
 
-pub fn is_head_link_script(tag_name: &str) -> bool {
-    match_ignore_ascii_case!(tag_name, "head", "link", "script")
+pub fn is_head_link_script(tag_name: &LocalName) -> bool {
+    match_element!(tag_name, Head | Link | Script)
 }
 
-pub fn is_li(tag_name: &str) -> bool {
-    tag_name.eq_ignore_ascii_case("li")
+pub fn is_li(tag_name: &LocalName) -> bool {
+    match_element!(tag_name, Li)
 }
 
-pub fn is_option(tag_name: &str) -> bool {
-    tag_name.eq_ignore_ascii_case("option")
+pub fn is_option(tag_name: &LocalName) -> bool {
+    match_element!(tag_name, Option)
 }
 
-pub fn is_opt_group(tag_name: &str) -> bool {
-    match_ignore_ascii_case!(tag_name, "optgroup", "option")
+pub fn is_opt_group(tag_name: &LocalName) -> bool {
+    match_element!(tag_name, Optgroup | Option)
 }
 
-pub fn open_implies_close(tag_name: &str) -> Option<fn(tag_name: &str) -> bool> {
+pub fn open_implies_close(tag_name: &LocalName) -> std::option::Option<fn(&LocalName) -> bool> {
     match tag_name {
-        "tr" => Some(is_tr_th_td),
-        "th" => Some(is_th),
-        "td" => Some(is_thead_th_td),
-        "body" => Some(is_head_link_script),
-        "li" => Some(is_li),
-        "p" => Some(is_p_tag),
-        "h1" => Some(is_p_tag),
-        "h2" => Some(is_p_tag),
-        "h3" => Some(is_p_tag),
-        "h4" => Some(is_p_tag),
-        "h5" => Some(is_p_tag),
-        "h6" => Some(is_p_tag),
-        "select" => Some(is_form_tag),
-        "input" => Some(is_form_tag),
-        "output" => Some(is_form_tag),
-        "button" => Some(is_form_tag),
-        "datalist" => Some(is_form_tag),
-        "textarea" => Some(is_form_tag),
-        "option" => Some(is_option),
-        "optgroup" => Some(is_opt_group),
-        "dd" => Some(is_dd_dt_tags),
-        "dt" => Some(is_dd_dt_tags),
-        "address" => Some(is_p_tag),
-        "article" => Some(is_p_tag),
-        "aside" => Some(is_p_tag),
-        "blockquote" => Some(is_p_tag),
-        "details" => Some(is_p_tag),
-        "div" => Some(is_p_tag),
-        "dl" => Some(is_p_tag),
-        "fieldset" => Some(is_p_tag),
-        "figcaption" => Some(is_p_tag),
-        "figure" => Some(is_p_tag),
-        "footer" => Some(is_p_tag),
-        "form" => Some(is_p_tag),
-        "header" => Some(is_p_tag),
-        "hr" => Some(is_p_tag),
-        "main" => Some(is_p_tag),
-        "nav" => Some(is_p_tag),
-        "ol" => Some(is_p_tag),
-        "pre" => Some(is_p_tag),
-        "section" => Some(is_p_tag),
-        "table" => Some(is_p_tag),
-        "ul" => Some(is_p_tag),
-        "rt" => Some(is_rtp_tags),
-        "rp" => Some(is_rtp_tags),
-        "tbody" => Some(is_table_section_tags),
-        "tfoot" => Some(is_table_section_tags),
+        Tr => Some(is_tr_th_td),
+        Th => Some(is_th),
+        Td => Some(is_thead_th_td),
+        Body => Some(is_head_link_script),
+        Li => Some(is_li),
+        P => Some(is_p_tag),
+        H1 => Some(is_p_tag),
+        H2 => Some(is_p_tag),
+        H3 => Some(is_p_tag),
+        H4 => Some(is_p_tag),
+        H5 => Some(is_p_tag),
+        H6 => Some(is_p_tag),
+        Select => Some(is_form_tag),
+        Input => Some(is_form_tag),
+        Output => Some(is_form_tag),
+        Button => Some(is_form_tag),
+        Datalist => Some(is_form_tag),
+        Textarea => Some(is_form_tag),
+        Option => Some(is_option),
+        Optgroup => Some(is_opt_group),
+        Dd => Some(is_dd_dt_tags),
+        Dt => Some(is_dd_dt_tags),
+        Address => Some(is_p_tag),
+        Article => Some(is_p_tag),
+        Aside => Some(is_p_tag),
+        Blockquote => Some(is_p_tag),
+        Details => Some(is_p_tag),
+        Div => Some(is_p_tag),
+        Dl => Some(is_p_tag),
+        Fieldset => Some(is_p_tag),
+        Figcaption => Some(is_p_tag),
+        Figure => Some(is_p_tag),
+        Footer => Some(is_p_tag),
+        Form => Some(is_p_tag),
+        Header => Some(is_p_tag),
+        Hr => Some(is_p_tag),
+        Main => Some(is_p_tag),
+        Nav => Some(is_p_tag),
+        Ol => Some(is_p_tag),
+        Pre => Some(is_p_tag),
+        Section => Some(is_p_tag),
+        Table => Some(is_p_tag),
+        Ul => Some(is_p_tag),
+        Rt => Some(is_rtp_tags),
+        Rp => Some(is_rtp_tags),
+        Tbody => Some(is_table_section_tags),
+        Tfoot => Some(is_table_section_tags),
         _ => None,
     }
 }
 
-pub fn is_foreign_context_elements(tag_name: &str) -> bool {
-    match_ignore_ascii_case!(tag_name, "math", "svg")
+pub fn is_rcdata_elements(tag_name: &LocalName) -> bool {
+    match_element!(tag_name, Title | Textarea)
+}
+
+pub fn is_rawtext_elements(tag_name: &LocalName) -> bool {
+    match_element!(tag_name, Style | Xmp | Iframe | Noembed | Noframes)
+}
+
+pub fn is_script(tag_name: &LocalName) -> bool {
+    match_element!(tag_name, Script)
 }
 
-pub fn is_html_integration_elements(tag_name: &str) -> bool {
-    match_ignore_ascii_case!(
+pub fn is_plaintext(tag_name: &LocalName) -> bool {
+    match_element!(tag_name, Plaintext)
+}
+
+pub fn is_whitespace_preserving_elements(tag_name: &LocalName) -> bool {
+    match_element!(tag_name, Pre | Textarea | Listing)
+}
+
+pub fn is_foreign_context_elements(tag_name: &LocalName) -> bool {
+    match_element!(tag_name, Math | Svg)
+}
+
+pub fn is_html_integration_elements(tag_name: &LocalName) -> bool {
+    match_element!(
         tag_name,
-        "mi",
-        "mo",
-        "mn",
-        "ms",
-        "mtext",
-        "annotation-xml",
-        "foreignobject",
-        "desc",
-        "title"
+        Mi | Mo | Mn | Ms | Mtext | AnnotationXml | ForeignObject | Desc | Title
     )
 }
+
+/** Elements whose end tag may be omitted, so closing them implicitly is not an error. */
+pub fn has_optional_end_tag(tag_name: &LocalName) -> bool {
+    match_element!(
+        tag_name,
+        Html | Head
+            | Body
+            | Li
+            | Dt
+            | Dd
+            | P
+            | Rt
+            | Rp
+            | Optgroup
+            | Option
+            | Colgroup
+            | Caption
+            | Thead
+            | Tbody
+            | Tfoot
+            | Tr
+            | Td
+            | Th
+    )
+}
+
+/**
+ * Elements that start on a new line when they are rendered or formatted: the block-level
+ * elements, the table parts, list items and the elements of the document head.
+ */
+pub fn is_block_elements(tag_name: &LocalName) -> bool {
+    match_element!(
+        tag_name,
+        Html | Head
+            | Body
+            | Address
+            | Article
+            | Aside
+            | Base
+            | Blockquote
+            | Caption
+            | Center
+            | Col
+            | Colgroup
+            | Dd
+            | Details
+            | Dialog
+            | Dir
+            | Div
+            | Dl
+            | Dt
+            | Fieldset
+            | Figcaption
+            | Figure
+            | Footer
+            | Form
+            | Frame
+            | Frameset
+            | H1
+            | H2
+            | H3
+            | H4
+            | H5
+            | H6
+            | Header
+            | Hgroup
+            | Hr
+            | Legend
+            | Li
+            | Link
+            | Listing
+            | Main
+            | Menu
+            | Meta
+            | Nav
+            | Noframes
+            | Noscript
+            | Ol
+            | Optgroup
+            | Option
+            | P
+            | Plaintext
+            | Pre
+            | Script
+            | Search
+            | Section
+            | Style
+            | Summary
+            | Table
+            | Tbody
+            | Td
+            | Template
+            | Tfoot
+            | Th
+            | Thead
+            | Title
+            | Tr
+            | Ul
+            | Xmp
+    )
+}
+
+/** Elements of the document metadata and scripting, they are not rendered. */
+pub fn is_metadata_elements(tag_name: &LocalName) -> bool {
+    match_element!(
+        tag_name,
+        Base | Link | Meta | Noframes | Noscript | Script | Style | Template | Title
+    )
+}
+
+/** Elements whose content is rendered as it is written, so it must not be reformatted. */
+pub fn is_preformatted_elements(tag_name: &LocalName) -> bool {
+    is_whitespace_preserving_elements(tag_name)
+        || is_rawtext_elements(tag_name)
+        || is_script(tag_name)
+        || is_plaintext(tag_name)
+}
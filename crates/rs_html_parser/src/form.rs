@@ -0,0 +1,586 @@
+use crate::element_info::is_form_tag;
+use crate::tree::{Document, NodeId};
+use crate::whitespace::{collapse_and_trim_whitespace, is_html_whitespace, strip_leading_newline};
+use rs_html_parser_tokens::LocalName;
+use std::collections::HashMap;
+use std::fmt::Write;
+
+const INPUT_TYPES: [&str; 22] = [
+    "hidden",
+    "text",
+    "search",
+    "tel",
+    "url",
+    "email",
+    "password",
+    "date",
+    "month",
+    "week",
+    "time",
+    "datetime-local",
+    "number",
+    "range",
+    "color",
+    "checkbox",
+    "radio",
+    "file",
+    "submit",
+    "image",
+    "reset",
+    "button",
+];
+
+/** The `method` of a form, `get` when it is missing or invalid. */
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum FormMethod {
+    #[default]
+    Get,
+    Post,
+    Dialog,
+}
+
+/** The `enctype` of a form, `application/x-www-form-urlencoded` when it is missing or invalid. */
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum FormEnctype {
+    #[default]
+    UrlEncoded,
+    /** `multipart/form-data` */
+    Multipart,
+    /** `text/plain` */
+    TextPlain,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelectOption {
+    pub id: NodeId,
+    /** The `value`, or the text with whitespace collapsed. */
+    pub value: String,
+    /** The `label`, or the text with whitespace collapsed. */
+    pub label: String,
+    pub is_selected: bool,
+    /** Whether the option or its `optgroup` is `disabled`. */
+    pub is_disabled: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ControlKind {
+    /** An `input` with its type in ASCII lowercase, `text` when the type is missing or unknown. */
+    Input(String),
+    Select {
+        is_multiple: bool,
+        options: Vec<SelectOption>,
+    },
+    Textarea,
+    /** A `button` with its type, `submit`, `reset` or `button`. */
+    Button(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Control {
+    pub id: NodeId,
+    pub kind: ControlKind,
+    /** The `name`, a control without one is not submitted. */
+    pub name: Option<String>,
+    /**
+     * The value, the `value` attribute sanitized and with the default of the input type. It is
+     * the content of a `textarea` and the value of the first selected option of a `select`.
+     */
+    pub value: String,
+    /** Whether a checkbox or a radio button is `checked`, only the last of a radio group is. */
+    pub is_checked: bool,
+    /** Whether the control is `disabled`, or is in a disabled `fieldset` but not in its legend. */
+    pub is_disabled: bool,
+}
+
+/** A `form` with the controls that it owns, in document order. */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Form {
+    pub id: NodeId,
+    /** The `action`, the form is submitted to the URL of the document without it. */
+    pub action: Option<String>,
+    pub method: FormMethod,
+    pub enctype: FormEnctype,
+    pub controls: Vec<Control>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FormValue {
+    Text(String),
+    /** A file input, there is no file chosen so the file name is empty. */
+    File,
+}
+
+/** The entries that a form submits, as names and values. */
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FormData {
+    pub entries: Vec<(String, FormValue)>,
+}
+
+/** Replaces each line break, `\r\n`, `\r` or `\n`, with `newline`. */
+fn normalize_line_breaks(text: &str, newline: &str) -> String {
+    text.replace("\r\n", "\n")
+        .replace('\r', "\n")
+        .replace('\n', newline)
+}
+
+/** Percent-encodes as `application/x-www-form-urlencoded`, a space is written as `+`. */
+fn push_url_encoded(encoded: &mut String, text: &str) {
+    for byte in text.bytes() {
+        match byte {
+            b' ' => encoded.push('+'),
+            b'*' | b'-' | b'.' | b'_' => encoded.push(byte as char),
+            _ if byte.is_ascii_alphanumeric() => encoded.push(byte as char),
+            _ => {
+                let _ = write!(encoded, "%{:02X}", byte);
+            }
+        }
+    }
+}
+
+/** Escapes a name in a `Content-Disposition` header of `multipart/form-data`. */
+fn escape_multipart_name(name: &str) -> String {
+    normalize_line_breaks(name, "\r\n")
+        .replace('\n', "%0A")
+        .replace('\r', "%0D")
+        .replace('"', "%22")
+}
+
+/** The value of an input with the sanitization and the default value of its type. */
+fn input_value(document: &Document, id: NodeId, input_type: &str) -> String {
+    let value = document.attribute(id, "value");
+    let number = |name: &str| {
+        document
+            .attribute(id, name)
+            .and_then(|value| value.trim().parse::<f64>().ok())
+            .filter(|value| value.is_finite())
+    };
+    let value_or_empty = value.unwrap_or("");
+
+    match input_type {
+        "text" | "search" | "tel" | "password" => value_or_empty.replace(['\r', '\n'], ""),
+        "url" | "email" => value_or_empty
+            .replace(['\r', '\n'], "")
+            .trim_matches(is_html_whitespace)
+            .to_string(),
+        "number" if number("value").is_none() => String::new(),
+        "range" => {
+            let min = number("min").unwrap_or(0.0);
+            let max = number("max").unwrap_or(100.0).max(min);
+            let value = number("value").unwrap_or(min + (max - min) / 2.0);
+
+            value.clamp(min, max).to_string()
+        }
+        "color" => match value {
+            Some(color)
+                if color.len() == 7
+                    && color.starts_with('#')
+                    && color[1..].bytes().all(|byte| byte.is_ascii_hexdigit()) =>
+            {
+                color.to_ascii_lowercase()
+            }
+            _ => "#000000".to_string(),
+        },
+        "checkbox" | "radio" => value.unwrap_or("on").to_string(),
+        "file" => String::new(),
+        _ => value_or_empty.to_string(),
+    }
+}
+
+impl Control {
+    /** Whether the control submits its form: a submit button or a `submit` or `image` input. */
+    pub fn is_submit_button(&self) -> bool {
+        match &self.kind {
+            ControlKind::Input(input_type) => input_type == "submit" || input_type == "image",
+            ControlKind::Button(button_type) => button_type == "submit",
+            _ => false,
+        }
+    }
+}
+
+impl Form {
+    /**
+     * The entries that the form submits, as a browser constructs them. `submitter` is the submit
+     * button that the form is submitted with, no other button is submitted. Disabled controls,
+     * controls without a name and checkboxes and radio buttons that are not checked are skipped.
+     */
+    pub fn form_data(&self, submitter: Option<NodeId>) -> FormData {
+        let mut entries = Vec::new();
+
+        for control in &self.controls {
+            let is_button = match &control.kind {
+                ControlKind::Input(input_type) => {
+                    matches!(input_type.as_str(), "submit" | "image" | "reset" | "button")
+                }
+                ControlKind::Button(_) => true,
+                _ => false,
+            };
+
+            if control.is_disabled
+                || is_button && (Some(control.id) != submitter || !control.is_submit_button())
+            {
+                continue;
+            }
+
+            let name = control.name.as_deref().unwrap_or("");
+
+            // An image button submits the coordinates of the click
+            if control.kind == ControlKind::Input("image".to_string()) {
+                let prefix = if name.is_empty() {
+                    String::new()
+                } else {
+                    format!("{}.", name)
+                };
+
+                for coordinate in ["x", "y"] {
+                    entries.push((
+                        format!("{}{}", prefix, coordinate),
+                        FormValue::Text("0".to_string()),
+                    ));
+                }
+                continue;
+            }
+            if name.is_empty() {
+                continue;
+            }
+
+            match &control.kind {
+                ControlKind::Select { options, .. } => {
+                    for option in options {
+                        if option.is_selected && !option.is_disabled {
+                            entries.push((name.to_string(), FormValue::Text(option.value.clone())));
+                        }
+                    }
+                }
+                ControlKind::Input(input_type) => {
+                    let value = match input_type.as_str() {
+                        "checkbox" | "radio" if !control.is_checked => continue,
+                        "file" => FormValue::File,
+                        "hidden"
+                            if control.value.is_empty()
+                                && name.eq_ignore_ascii_case("_charset_") =>
+                        {
+                            FormValue::Text("UTF-8".to_string())
+                        }
+                        _ => FormValue::Text(control.value.clone()),
+                    };
+
+                    entries.push((name.to_string(), value));
+                }
+                _ => entries.push((name.to_string(), FormValue::Text(control.value.clone()))),
+            }
+        }
+
+        FormData { entries }
+    }
+}
+
+impl FormData {
+    /** The entries as `application/x-www-form-urlencoded`, the body of a post or the query. */
+    pub fn to_url_encoded(&self) -> String {
+        let mut encoded = String::new();
+
+        for (name, value) in &self.entries {
+            if !encoded.is_empty() {
+                encoded.push('&');
+            }
+            push_url_encoded(&mut encoded, &normalize_line_breaks(name, "\r\n"));
+            encoded.push('=');
+            if let FormValue::Text(value) = value {
+                push_url_encoded(&mut encoded, &normalize_line_breaks(value, "\r\n"));
+            }
+        }
+        encoded
+    }
+
+    /** The entries as `multipart/form-data`, with the parts separated by the boundary. */
+    pub fn to_multipart(&self, boundary: &str) -> String {
+        let mut encoded = String::new();
+
+        for (name, value) in &self.entries {
+            let _ = write!(
+                encoded,
+                "--{}\r\nContent-Disposition: form-data; name=\"{}\"",
+                boundary,
+                escape_multipart_name(name)
+            );
+            match value {
+                FormValue::Text(value) => {
+                    let _ = write!(
+                        encoded,
+                        "\r\n\r\n{}\r\n",
+                        normalize_line_breaks(value, "\r\n")
+                    );
+                }
+                FormValue::File => encoded.push_str(
+                    "; filename=\"\"\r\nContent-Type: application/octet-stream\r\n\r\n\r\n",
+                ),
+            }
+        }
+        let _ = write!(encoded, "--{}--\r\n", boundary);
+        encoded
+    }
+
+    /** The entries as `text/plain`, a `name=value` line for each. */
+    pub fn to_text_plain(&self) -> String {
+        let mut encoded = String::new();
+
+        for (name, value) in &self.entries {
+            let value = match value {
+                FormValue::Text(value) => value.as_str(),
+                FormValue::File => "",
+            };
+            let _ = write!(
+                encoded,
+                "{}={}\r\n",
+                normalize_line_breaks(name, "\r\n"),
+                normalize_line_breaks(value, "\r\n")
+            );
+        }
+        encoded
+    }
+}
+
+impl Document {
+    /**
+     * The forms of the document with their controls. A control belongs to the form that its
+     * `form` attribute names by id, or else to the nearest form that it is in. Controls in a
+     * `datalist` are not in a form.
+     */
+    pub fn forms(&self) -> Vec<Form> {
+        let mut forms = Vec::new();
+        let mut form_indexes = HashMap::new();
+        let mut ids = HashMap::new();
+
+        for id in self.descendants(self.root()) {
+            if let Some(element_id) = self.attribute(id, "id") {
+                ids.entry(element_id).or_insert(id);
+            }
+            if self.is_element(id, &LocalName::Form) {
+                form_indexes.insert(id, forms.len());
+                forms.push(self.form(id));
+            }
+        }
+
+        for id in self.descendants(self.root()) {
+            if !self.name(id).is_some_and(is_form_tag) || self.is_in(id, &LocalName::Datalist) {
+                continue;
+            }
+
+            let owner = match self.attribute(id, "form") {
+                Some(form) => ids.get(form).copied(),
+                None => self
+                    .ancestors(id)
+                    .find(|ancestor| self.is_element(*ancestor, &LocalName::Form)),
+            };
+
+            if let Some(index) = owner.and_then(|owner| form_indexes.get(&owner)) {
+                if let Some(control) = self.control(id) {
+                    forms[*index].controls.push(control);
+                }
+            }
+        }
+
+        for form in &mut forms {
+            uncheck_radio_groups(&mut form.controls);
+        }
+        forms
+    }
+
+    fn form(&self, id: NodeId) -> Form {
+        let method = match self.attribute(id, "method") {
+            Some(method) if method.trim().eq_ignore_ascii_case("post") => FormMethod::Post,
+            Some(method) if method.trim().eq_ignore_ascii_case("dialog") => FormMethod::Dialog,
+            _ => FormMethod::Get,
+        };
+        let enctype = match self.attribute(id, "enctype") {
+            Some(enctype) if enctype.trim().eq_ignore_ascii_case("multipart/form-data") => {
+                FormEnctype::Multipart
+            }
+            Some(enctype) if enctype.trim().eq_ignore_ascii_case("text/plain") => {
+                FormEnctype::TextPlain
+            }
+            _ => FormEnctype::UrlEncoded,
+        };
+
+        Form {
+            id,
+            action: self.attribute(id, "action").map(str::to_string),
+            method,
+            enctype,
+            controls: Vec::new(),
+        }
+    }
+
+    fn control(&self, id: NodeId) -> Option<Control> {
+        let mut is_checked = false;
+        let (kind, value) = match self.name(id)?.element() {
+            LocalName::Input => {
+                let input_type = self
+                    .attribute(id, "type")
+                    .map(|input_type| input_type.trim().to_ascii_lowercase())
+                    .filter(|input_type| INPUT_TYPES.contains(&input_type.as_str()))
+                    .unwrap_or_else(|| "text".to_string());
+
+                is_checked = matches!(input_type.as_str(), "checkbox" | "radio")
+                    && self.attribute(id, "checked").is_some();
+                let value = input_value(self, id, &input_type);
+
+                (ControlKind::Input(input_type), value)
+            }
+            LocalName::Select => {
+                let is_multiple = self.attribute(id, "multiple").is_some();
+                let options = self.select_options(id, is_multiple);
+                let value = options
+                    .iter()
+                    .find(|option| option.is_selected)
+                    .map(|option| option.value.clone())
+                    .unwrap_or_default();
+
+                (
+                    ControlKind::Select {
+                        is_multiple,
+                        options,
+                    },
+                    value,
+                )
+            }
+            LocalName::Textarea => {
+                let text = self.text_content(id);
+                let value = normalize_line_breaks(strip_leading_newline(&text), "\n");
+
+                (ControlKind::Textarea, value)
+            }
+            LocalName::Button => {
+                let button_type = match self.attribute(id, "type") {
+                    Some(button_type) if button_type.trim().eq_ignore_ascii_case("reset") => {
+                        "reset"
+                    }
+                    Some(button_type) if button_type.trim().eq_ignore_ascii_case("button") => {
+                        "button"
+                    }
+                    _ => "submit",
+                };
+                let value = self.attribute(id, "value").unwrap_or("").to_string();
+
+                (ControlKind::Button(button_type.to_string()), value)
+            }
+            _ => return None,
+        };
+
+        Some(Control {
+            id,
+            kind,
+            name: self.attribute(id, "name").map(str::to_string),
+            value,
+            is_checked,
+            is_disabled: self.attribute(id, "disabled").is_some()
+                || self.is_in_disabled_fieldset(id),
+        })
+    }
+
+    /**
+     * The options of a select and of its groups. Only the last selected option of a single select
+     * is selected, and the first enabled option is when none is and the select is a drop-down.
+     */
+    fn select_options(&self, id: NodeId, is_multiple: bool) -> Vec<SelectOption> {
+        let mut options = Vec::new();
+
+        for child in self.element_children(id) {
+            let (group, is_group_disabled) = if self.is_element(child, &LocalName::Optgroup) {
+                (
+                    self.element_children(child).collect(),
+                    self.attribute(child, "disabled").is_some(),
+                )
+            } else {
+                (vec![child], false)
+            };
+
+            for option in group {
+                if !self.is_element(option, &LocalName::Option) {
+                    continue;
+                }
+
+                let text = collapse_and_trim_whitespace(&self.text_content(option)).into_owned();
+
+                options.push(SelectOption {
+                    id: option,
+                    value: self
+                        .attribute(option, "value")
+                        .map(str::to_string)
+                        .unwrap_or_else(|| text.clone()),
+                    label: self
+                        .attribute(option, "label")
+                        .filter(|label| !label.is_empty())
+                        .map(str::to_string)
+                        .unwrap_or(text),
+                    is_selected: self.attribute(option, "selected").is_some(),
+                    is_disabled: is_group_disabled || self.attribute(option, "disabled").is_some(),
+                });
+            }
+        }
+
+        if !is_multiple {
+            let size = self
+                .attribute(id, "size")
+                .and_then(|size| size.trim().parse::<usize>().ok())
+                .filter(|size| *size > 0)
+                .unwrap_or(1);
+            let selected = options
+                .iter()
+                .rposition(|option| option.is_selected)
+                .or_else(|| {
+                    (size == 1)
+                        .then(|| options.iter().position(|option| !option.is_disabled))
+                        .flatten()
+                });
+
+            for (index, option) in options.iter_mut().enumerate() {
+                option.is_selected = Some(index) == selected;
+            }
+        }
+        options
+    }
+
+    fn ancestors(&self, id: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        std::iter::successors(self[id].parent, |parent| self[*parent].parent)
+    }
+
+    fn is_in(&self, id: NodeId, name: &LocalName) -> bool {
+        self.ancestors(id)
+            .any(|ancestor| self.is_element(ancestor, name))
+    }
+
+    /** Whether a disabled `fieldset` contains the element, outside of its first `legend`. */
+    fn is_in_disabled_fieldset(&self, id: NodeId) -> bool {
+        let mut child = id;
+
+        self.ancestors(id).any(|ancestor| {
+            let is_disabled = self.is_element(ancestor, &LocalName::Fieldset)
+                && self.attribute(ancestor, "disabled").is_some()
+                && self
+                    .element_children(ancestor)
+                    .find(|legend| self.is_element(*legend, &LocalName::Legend))
+                    != Some(child);
+
+            child = ancestor;
+            is_disabled
+        })
+    }
+}
+
+/** Unchecks all but the last checked radio button of each group, the buttons with the same name. */
+fn uncheck_radio_groups(controls: &mut [Control]) {
+    let mut checked: HashMap<String, usize> = HashMap::new();
+
+    for index in 0..controls.len() {
+        let control = &controls[index];
+
+        if control.is_checked && control.kind == ControlKind::Input("radio".to_string()) {
+            if let Some(name) = control.name.clone() {
+                if let Some(previous) = checked.insert(name, index) {
+                    controls[previous].is_checked = false;
+                }
+            }
+        }
+    }
+}
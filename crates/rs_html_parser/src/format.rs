@@ -0,0 +1,617 @@
+use crate::element_info::{
+    is_block_elements, is_foreign_context_elements, is_html_integration_elements,
+    is_preformatted_elements, is_void_elements,
+};
+use crate::tree::{Document, NodeData, NodeId, ProcessingInstruction};
+use crate::whitespace::is_html_whitespace;
+use crate::{Parser, ParserOptions};
+use rs_html_parser_tokenizer::{Tokenizer, TokenizerOptions};
+use rs_html_parser_tokenizer_tokens::{QuoteType, TokenizerTokenLocation};
+use rs_html_parser_tokens::{RawAttributes, TokenKind};
+use std::collections::{HashMap, HashSet};
+use std::mem::take;
+
+/**
+ * The deepest level of nesting that is indented, deeper blocks are written at this level. The
+ * indentation of deeply nested documents would otherwise grow with the square of their size.
+ */
+const MAX_INDENT_DEPTH: usize = 100;
+
+/** When the attributes of a start tag are put on lines of their own. */
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum AttributeWrapping {
+    /** When the start tag does not fit on its line. */
+    #[default]
+    Auto,
+    /** When the tag has more than one attribute. */
+    Always,
+    /** Never, start tags are kept on one line. */
+    Never,
+}
+
+/** How attribute values are quoted. */
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum QuoteStyle {
+    #[default]
+    Double,
+    Single,
+    /** Every value keeps the quotes it was written with, see `QuoteType`. */
+    Keep,
+}
+
+#[derive(Default)]
+pub struct FormatOptions {
+    /**
+     * Formats XML: every element starts on a new line and no content is special, as with
+     * `ParserOptions::xml_mode`.
+     *
+     * @default false
+     */
+    pub xml_mode: bool,
+
+    /**
+     * Number of spaces per level of nesting.
+     *
+     * @default 2
+     */
+    pub indent_width: Option<usize>,
+
+    /**
+     * Lines are wrapped at whitespace to fit this width where possible. Words and inline tags that
+     * are longer are not split.
+     *
+     * @default 80
+     */
+    pub max_line_width: Option<usize>,
+
+    /** @default AttributeWrapping::Auto */
+    pub attribute_wrapping: Option<AttributeWrapping>,
+
+    /** @default QuoteStyle::Double */
+    pub quote_style: Option<QuoteStyle>,
+}
+
+pub(crate) struct Attribute {
+    pub(crate) name: Box<str>,
+    pub(crate) value: Option<(Box<str>, QuoteType)>,
+}
+
+/** What the tree does not tell about an element. */
+#[derive(Default)]
+struct ElementSource {
+    /** In the order of the source, with the values undecoded. */
+    attributes: Vec<Attribute>,
+    is_open_implied: bool,
+    is_close_implied: bool,
+}
+
+/** The attributes of a tag in the order of the source, the first of duplicate names wins. */
+pub(crate) fn source_attributes(raw_attrs: Option<&RawAttributes>) -> Vec<Attribute> {
+    let Some(raw_attrs) = raw_attrs else {
+        return Vec::new();
+    };
+    let options = TokenizerOptions {
+        decode_entities: Some(false),
+        ..Default::default()
+    };
+    let tag = [b"<a", raw_attrs.source(), b">"].concat();
+    let mut attributes: Vec<Attribute> = Vec::new();
+    let mut name = String::new();
+    let mut value = String::new();
+
+    for tokenizer_token in Tokenizer::new(&tag, &options) {
+        let source = String::from_utf8_lossy(&tag[tokenizer_token.start..tokenizer_token.end]);
+
+        match tokenizer_token.location {
+            TokenizerTokenLocation::OpenTagEnd => break,
+            TokenizerTokenLocation::AttrName => name = source.into_owned(),
+            TokenizerTokenLocation::AttrData => value.push_str(&source),
+            TokenizerTokenLocation::AttrEnd => {
+                let value = take(&mut value);
+
+                if !attributes
+                    .iter()
+                    .any(|a| a.name.eq_ignore_ascii_case(&name))
+                {
+                    attributes.push(Attribute {
+                        name: take(&mut name).into(),
+                        value: match tokenizer_token.quote {
+                            QuoteType::NoValue => None,
+                            quote => Some((value.into(), quote)),
+                        },
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+
+    attributes
+}
+
+/**
+ * Splits inline content into words at whitespace. Tags and other markup are glued to the text
+ * around them, so whitespace is neither added nor removed where it is significant.
+ */
+#[derive(Default)]
+struct Words {
+    words: Vec<String>,
+    word: String,
+}
+
+impl Words {
+    fn push_text(&mut self, text: &str) {
+        for (index, part) in text.split(is_html_whitespace).enumerate() {
+            if index > 0 && !self.word.is_empty() {
+                self.words.push(take(&mut self.word));
+            }
+            self.word.push_str(part);
+        }
+    }
+
+    fn push_markup(&mut self, markup: &str) {
+        self.word.push_str(markup);
+    }
+
+    fn finish(mut self) -> Vec<String> {
+        if !self.word.is_empty() {
+            self.words.push(self.word);
+        }
+
+        self.words
+    }
+}
+
+struct Formatter {
+    document: Document,
+    elements: HashMap<NodeId, ElementSource>,
+    /** Elements that start on a new line, because of their name or their content. */
+    blocks: HashSet<NodeId>,
+    /** Elements in SVG or MathML content, that may be self-closing. */
+    foreign: HashSet<NodeId>,
+    xml_mode: bool,
+    indent_width: usize,
+    max_line_width: usize,
+    attribute_wrapping: AttributeWrapping,
+    quote_style: QuoteStyle,
+    output: String,
+}
+
+impl Formatter {
+    fn new(html: &str, options: &FormatOptions) -> Formatter {
+        // Text and attribute values are kept as they are written, entities included
+        let parser_options = ParserOptions {
+            xml_mode: options.xml_mode,
+            lazy_attributes: true,
+            tokenizer_options: TokenizerOptions {
+                decode_entities: Some(false),
+                ignore_whitespace_between_tags: Some(false),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let mut sources = Vec::new();
+        let mut open = Vec::new();
+        let tokens = Parser::new(html, &parser_options).inspect(|token| match token.kind {
+            TokenKind::OpenTag => {
+                open.push(sources.len());
+                sources.push(ElementSource {
+                    attributes: source_attributes(token.raw_attrs.as_ref()),
+                    is_open_implied: token.is_implied,
+                    is_close_implied: false,
+                });
+            }
+            TokenKind::CloseTag => {
+                if let Some(index) = open.pop() {
+                    sources[index].is_close_implied = token.is_implied;
+                }
+            }
+            _ => {}
+        });
+        let document = Document::from_tokens(tokens);
+        let nodes: Vec<_> = document.descendants(document.root()).collect();
+
+        // The elements are created in the order of their start tags
+        let elements: HashMap<_, _> = nodes
+            .iter()
+            .copied()
+            .filter(|id| document.name(*id).is_some())
+            .zip(sources)
+            .collect();
+
+        let mut foreign = HashSet::new();
+
+        for id in &nodes {
+            let Some(name) = document.name(*id) else {
+                continue;
+            };
+            let parent = document[*id].parent.unwrap();
+            let in_foreign = match document.name(parent) {
+                Some(parent_name) if is_html_integration_elements(parent_name) => false,
+                _ => foreign.contains(&parent),
+            };
+
+            if options.xml_mode || in_foreign || is_foreign_context_elements(name) {
+                foreign.insert(*id);
+            }
+        }
+
+        let mut blocks = HashSet::new();
+
+        // Children come after their parents, so they are decided first
+        for id in nodes.iter().rev() {
+            let is_block = match &document[*id].data {
+                NodeData::Element { name, .. } => {
+                    options.xml_mode
+                        || (is_block_elements(name) && !foreign.contains(id))
+                        || document[*id]
+                            .children
+                            .iter()
+                            .any(|child| blocks.contains(child))
+                }
+                NodeData::ProcessingInstruction(_) => true,
+                _ => false,
+            };
+
+            if is_block {
+                blocks.insert(*id);
+            }
+        }
+
+        Formatter {
+            document,
+            elements,
+            blocks,
+            foreign,
+            xml_mode: options.xml_mode,
+            indent_width: options.indent_width.unwrap_or(2),
+            max_line_width: options.max_line_width.unwrap_or(80),
+            attribute_wrapping: options.attribute_wrapping.unwrap_or_default(),
+            quote_style: options.quote_style.unwrap_or_default(),
+            output: String::new(),
+        }
+    }
+
+    fn is_preformatted(&self, id: NodeId) -> bool {
+        !self.xml_mode
+            && !self.foreign.contains(&id)
+            && self.document.name(id).is_some_and(is_preformatted_elements)
+    }
+
+    fn is_self_closing(&self, id: NodeId) -> bool {
+        let element = &self.elements[&id];
+
+        self.foreign.contains(&id)
+            && element.is_close_implied
+            && !element.is_open_implied
+            && self.document[id].children.is_empty()
+            && (self.xml_mode || !is_void_elements(self.document.name(id).unwrap()))
+    }
+
+    fn attribute(&self, attribute: &Attribute) -> String {
+        let Some((value, quote_type)) = &attribute.value else {
+            return attribute.name.to_string();
+        };
+        let quote = match (self.quote_style, quote_type) {
+            (QuoteStyle::Keep, QuoteType::Unquoted) => "",
+            (QuoteStyle::Keep, QuoteType::Single) | (QuoteStyle::Single, _) => "'",
+            _ => "\"",
+        };
+        let (quote, value) = match quote {
+            "\"" if value.contains('"') && !value.contains('\'') => ("'", value.to_string()),
+            "'" if value.contains('\'') && !value.contains('"') => ("\"", value.to_string()),
+            "\"" => (quote, value.replace('"', "&quot;")),
+            "'" => (quote, value.replace('\'', "&#39;")),
+            _ => (quote, value.to_string()),
+        };
+
+        format!("{}={}{}{}", attribute.name, quote, value, quote)
+    }
+
+    /** The start tag on one line, or with the attributes on lines of their own. */
+    fn start_tag(&self, id: NodeId, depth: Option<usize>) -> String {
+        let element = &self.elements[&id];
+        let name = self.document.name(id).unwrap();
+
+        if element.is_open_implied {
+            return String::new();
+        }
+
+        let attributes: Vec<_> = element
+            .attributes
+            .iter()
+            .map(|attribute| self.attribute(attribute))
+            .collect();
+        let end = if self.is_self_closing(id) { "/>" } else { ">" };
+        let mut tag = format!("<{}", name);
+
+        for attribute in &attributes {
+            tag.push(' ');
+            tag.push_str(attribute);
+        }
+        tag.push_str(end);
+
+        let Some(depth) = depth else {
+            return tag;
+        };
+        let wrap = match self.attribute_wrapping {
+            AttributeWrapping::Auto => {
+                !attributes.is_empty() && self.width(depth, &tag) > self.max_line_width
+            }
+            AttributeWrapping::Always => attributes.len() > 1,
+            AttributeWrapping::Never => false,
+        };
+
+        if !wrap {
+            return tag;
+        }
+
+        let mut tag = format!("<{}", name);
+
+        for attribute in &attributes {
+            tag.push('\n');
+            tag.push_str(&self.indent(depth + 1));
+            tag.push_str(attribute);
+        }
+        tag.push('\n');
+        tag.push_str(&self.indent(depth));
+        tag.push_str(end);
+
+        tag
+    }
+
+    fn end_tag(&self, id: NodeId) -> String {
+        // `</br>` opens a `br`, which can't be written with an end tag
+        let is_void = !self.xml_mode
+            && is_void_elements(self.document.name(id).unwrap())
+            && self.document[id].children.is_empty();
+
+        if is_void || self.elements[&id].is_close_implied {
+            String::new()
+        } else {
+            format!("</{}>", self.document.name(id).unwrap())
+        }
+    }
+
+    fn indent(&self, depth: usize) -> String {
+        " ".repeat(depth * self.indent_width)
+    }
+
+    fn width(&self, depth: usize, line: &str) -> usize {
+        depth * self.indent_width + line.chars().count()
+    }
+
+    fn line(&mut self, depth: usize, line: &str) {
+        self.output.push_str(&self.indent(depth));
+        self.output.push_str(line);
+        self.output.push('\n');
+    }
+
+    /** The markup of the node as it is, without changing any whitespace. */
+    fn write_as_is(&self, markup: &mut String, id: NodeId) {
+        let mut stack = vec![Part::Node(id)];
+
+        while let Some(part) = stack.pop() {
+            let id = match part {
+                Part::Node(id) => id,
+                Part::Markup(end_tag) => {
+                    markup.push_str(&end_tag);
+                    continue;
+                }
+            };
+
+            match &self.document[id].data {
+                NodeData::Document => {}
+                NodeData::Element { .. } => {
+                    markup.push_str(&self.start_tag(id, None));
+                    stack.push(Part::Markup(self.end_tag(id)));
+                    stack.extend(
+                        self.document[id]
+                            .children
+                            .iter()
+                            .rev()
+                            .map(|child| Part::Node(*child)),
+                    );
+                }
+                NodeData::Text(data) => markup.push_str(data),
+                NodeData::Comment(data) => {
+                    markup.push_str("<!--");
+                    markup.push_str(data);
+                    markup.push_str("-->");
+                }
+                NodeData::ProcessingInstruction(data) => {
+                    markup.push_str(&ProcessingInstruction(data).to_string());
+                }
+            }
+        }
+    }
+
+    fn push_inline(&self, words: &mut Words, id: NodeId) {
+        let mut stack = vec![Part::Node(id)];
+
+        while let Some(part) = stack.pop() {
+            let id = match part {
+                Part::Node(id) => id,
+                Part::Markup(end_tag) => {
+                    words.push_markup(&end_tag);
+                    continue;
+                }
+            };
+
+            match &self.document[id].data {
+                NodeData::Text(data) => words.push_text(data),
+                NodeData::Element { .. } if !self.is_preformatted(id) => {
+                    words.push_markup(&self.start_tag(id, None));
+                    stack.push(Part::Markup(self.end_tag(id)));
+                    stack.extend(
+                        self.document[id]
+                            .children
+                            .iter()
+                            .rev()
+                            .map(|child| Part::Node(*child)),
+                    );
+                }
+                _ => {
+                    let mut markup = String::new();
+
+                    self.write_as_is(&mut markup, id);
+                    words.push_markup(&markup);
+                }
+            }
+        }
+    }
+
+    fn words(&self, nodes: &[NodeId]) -> Vec<String> {
+        let mut words = Words::default();
+
+        for id in nodes {
+            self.push_inline(&mut words, *id);
+        }
+
+        words.finish()
+    }
+
+    /** Writes the words on as few lines as fit. */
+    fn write_words(&mut self, depth: usize, words: &[String]) {
+        let mut line = String::new();
+
+        for word in words {
+            if !line.is_empty()
+                && self.width(depth, &line) + 1 + word.chars().count() > self.max_line_width
+            {
+                self.line(depth, &take(&mut line));
+            }
+            if !line.is_empty() {
+                line.push(' ');
+            }
+            line.push_str(word);
+        }
+
+        if !line.is_empty() {
+            self.line(depth, &line);
+        }
+    }
+
+    /** Writes the nodes, the inline nodes between blocks are written as paragraphs. */
+    fn write_children(&mut self, depth: usize, children: &[NodeId]) {
+        let mut steps = Vec::new();
+
+        self.push_children(&mut steps, depth, children);
+        while let Some(step) = steps.pop() {
+            match step {
+                Step::Inline(depth, nodes) => {
+                    let words = self.words(&nodes);
+
+                    self.write_words(depth, &words);
+                }
+                Step::Block(depth, id) => self.write_block(&mut steps, depth, id),
+                Step::Line(depth, line) => self.line(depth, &line),
+            }
+        }
+    }
+
+    /** Pushes the steps that write the nodes, so that they are popped in document order. */
+    fn push_children(&self, steps: &mut Vec<Step>, depth: usize, children: &[NodeId]) {
+        let mut children_steps = Vec::new();
+        let mut inline = Vec::new();
+
+        for child in children {
+            if self.blocks.contains(child) {
+                if !inline.is_empty() {
+                    children_steps.push(Step::Inline(depth, take(&mut inline)));
+                }
+                children_steps.push(Step::Block(depth, *child));
+            } else {
+                inline.push(*child);
+            }
+        }
+        if !inline.is_empty() {
+            children_steps.push(Step::Inline(depth, inline));
+        }
+
+        steps.extend(children_steps.into_iter().rev());
+    }
+
+    /**
+     * Writes a block on one line when it fits, or else its start tag. Its children and end tag are
+     * pushed as steps, so that deeply nested blocks don't overflow the stack.
+     */
+    fn write_block(&mut self, steps: &mut Vec<Step>, depth: usize, id: NodeId) {
+        if let NodeData::ProcessingInstruction(data) = &self.document[id].data {
+            let line = ProcessingInstruction(data).to_string();
+
+            self.line(depth, &line);
+            return;
+        }
+
+        let start_tag = self.start_tag(id, Some(depth));
+        let end_tag = self.end_tag(id);
+        let children = self.document[id].children.clone();
+
+        if self.is_preformatted(id) {
+            let mut markup = start_tag;
+
+            for child in &children {
+                self.write_as_is(&mut markup, *child);
+            }
+            markup.push_str(&end_tag);
+            self.line(depth, &markup);
+            return;
+        }
+
+        if children.iter().all(|child| !self.blocks.contains(child)) {
+            let words = self.words(&children);
+            let line = format!("{}{}{}", start_tag, words.join(" "), end_tag);
+
+            if line.contains('\n') && words.is_empty() {
+                // Only the start tag is wrapped
+                self.output.push_str(&self.indent(depth));
+                self.output.push_str(&line);
+                self.output.push('\n');
+                return;
+            }
+            if !line.contains('\n') && self.width(depth, &line) <= self.max_line_width {
+                self.line(depth, &line);
+                return;
+            }
+        }
+
+        if !start_tag.is_empty() {
+            self.line(depth, &start_tag);
+        }
+        if !end_tag.is_empty() {
+            steps.push(Step::Line(depth, end_tag));
+        }
+        self.push_children(steps, (depth + 1).min(MAX_INDENT_DEPTH), &children);
+    }
+}
+
+/** A node to write, or the markup after its children, eg. an end tag. */
+enum Part {
+    Node(NodeId),
+    Markup(String),
+}
+
+/** A step of writing blocks at a depth, see `Formatter::write_children`. */
+enum Step {
+    /** Inline nodes between blocks, written as a paragraph. */
+    Inline(usize, Vec<NodeId>),
+    Block(usize, NodeId),
+    /** A line of its own, eg. the end tag of a block after its children. */
+    Line(usize, String),
+}
+
+/**
+ * Formats a document: block-level elements are put on lines of their own and indented, and the
+ * inline content between them is wrapped at whitespace. Whitespace is only changed where it is
+ * not rendered, the content of eg. `pre`, `textarea` and `script` is kept as it is, and end tags
+ * that were left out are not added. Formatting the result again does not change it.
+ */
+pub fn format(html: &str, options: &FormatOptions) -> String {
+    let mut formatter = Formatter::new(html, options);
+    let root = formatter.document.root();
+    let children = formatter.document[root].children.clone();
+
+    formatter.write_children(0, &children);
+    formatter.output
+}
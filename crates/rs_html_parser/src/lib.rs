@@ -1,19 +1,72 @@
-mod element_info;
+#![forbid(unsafe_code)]
 
+mod article;
+mod attribute_decoder;
+mod attributes;
+mod element_info;
+mod form;
+mod format;
+mod limits;
+mod links;
+mod markdown;
+mod metadata;
+mod minify;
+#[cfg(feature = "parallel")]
+mod parallel;
+mod parse_error;
+mod selector;
+mod table;
+mod text;
+mod tree;
+mod url;
+mod validate;
+mod whitespace;
+
+use crate::attribute_decoder::LazyAttributeDecoder;
+use crate::attributes::{slice, AttributesBuilder};
 use crate::element_info::{
-    is_foreign_context_elements, is_html_integration_elements, is_void_elements, open_implies_close,
+    is_foreign_context_elements, is_html_integration_elements, is_plaintext, is_rawtext_elements,
+    is_rcdata_elements, is_script, is_void_elements, is_whitespace_preserving_elements,
+    open_implies_close,
 };
+use crate::limits::truncate;
+#[cfg(feature = "parallel")]
+use crate::parallel::ChunkedTokens;
+use crate::parse_error::requires_end_tag;
+use crate::whitespace::strip_leading_newline;
 use lazy_static::lazy_static;
 use regex::Regex;
-use rs_html_parser_tokenizer::{Tokenizer, TokenizerOptions};
-use rs_html_parser_tokenizer_tokens::{QuoteType, TokenizerToken, TokenizerTokenLocation};
-use rs_html_parser_tokens::{Token, TokenKind};
+use rs_html_parser_tokenizer::{ContentModel, Tokenizer, TokenizerOptions};
+use rs_html_parser_tokenizer_tokens::{TokenizerToken, TokenizerTokenLocation};
+use rs_html_parser_tokens::{AttributeDecoder, LocalName, RawAttributes, Token, TokenKind};
 use std::borrow::Cow;
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 use std::mem::take;
 use std::str;
-use unicase_collections::unicase_btree_map::UniCaseBTreeMap;
+use std::sync::Arc;
 
+pub use crate::article::{Article, ArticleOptions};
+pub use crate::form::{
+    Control, ControlKind, Form, FormData, FormEnctype, FormMethod, FormValue, SelectOption,
+};
+pub use crate::format::{format, AttributeWrapping, FormatOptions, QuoteStyle};
+pub use crate::limits::{Limit, Limits};
+pub use crate::links::{extract_links, Link, LinkOptions};
+pub use crate::markdown::{html_to_markdown, MarkdownOptions, UnsupportedElements};
+pub use crate::metadata::{
+    extract_metadata, AlternateLink, Icon, Metadata, OpenGraph, Robots, TwitterCard,
+};
+pub use crate::minify::{minify, MinifyOptions};
+#[cfg(feature = "parallel")]
+pub use crate::parallel::DEFAULT_CHUNK_SIZE;
+pub use crate::parse_error::{ParseError, ParseErrorKind};
+pub use crate::selector::{Selector, SelectorError};
+pub use crate::table::{Cell, Table};
+pub use crate::tree::{Descendants, Document, Node, NodeData, NodeId, ProcessingInstruction};
+pub use crate::validate::{validate_stream, StreamError, StreamErrorKind, StreamValidator};
+pub use crate::whitespace::{apply_whitespace_mode, WhitespaceMode};
+
+#[derive(Default)]
 pub struct ParserOptions {
     /**
      * Indicates whether special tags (`<script>`, `<style>`, `<textarea>` and `<title>`) should get special treatment
@@ -25,6 +78,39 @@ pub struct ParserOptions {
      */
     pub xml_mode: bool,
 
+    /**
+     * How whitespace in text is handled, see `WhitespaceMode`. The mode applies to the whole text
+     * between tags, so setting it implies `coalesce_text`.
+     *
+     * @default None, whitespace is handled by `TokenizerOptions::ignore_whitespace_between_tags`
+     */
+    pub whitespace: Option<WhitespaceMode>,
+
+    /**
+     * Merges consecutive text, including decoded entities, into a single `Text` token.
+     * `a &amp; b` is then one token instead of three.
+     *
+     * @default true
+     */
+    pub coalesce_text: Option<bool>,
+
+    /**
+     * Resource limits for untrusted input, see `Limits`.
+     *
+     * @default None, nothing is limited
+     */
+    pub limits: Option<Limits>,
+
+    /**
+     * Keeps the attributes of open tags undecoded in `Token::raw_attrs`, instead of decoding them
+     * into `Token::attrs`. They are decoded when `Token::attributes` or `Token::attribute` is
+     * called, with the same result. The attribute limits apply then, but they are not reported by
+     * `Parser::exceeded_limits`.
+     *
+     * @default false
+     */
+    pub lazy_attributes: bool,
+
     pub tokenizer_options: TokenizerOptions,
 }
 
@@ -38,16 +124,47 @@ pub struct Parser<'a> {
     buffer: &'a [u8],
 
     tokenizer: Tokenizer<'a>,
-    tag_name: &'a str,
+    // The tokens that were tokenized in parallel ahead of the parser, see `Parser::new_parallel`
+    #[cfg(feature = "parallel")]
+    chunks: Option<ChunkedTokens<'a>>,
+    tag_name: LocalName,
     next_nodes: VecDeque<Token>,
-    stack: VecDeque<Box<str>>,
-    foreign_context: VecDeque<bool>,
-    attribs: UniCaseBTreeMap<Option<(Box<str>, QuoteType)>>,
-    attrib_value: Option<String>,
-    attrib_name: &'a str,
+    stack: VecDeque<LocalName>,
+    // How many times each tag name is on the stack, so end tags can be matched in constant time
+    open_elements: HashMap<LocalName, usize>,
+    // Whether we are in foreign content, and the stack depth of the element that started it
+    foreign_context: VecDeque<(bool, usize)>,
+    // Whether whitespace is preserved, and the stack depth of the element that started it
+    whitespace_context: VecDeque<(bool, usize)>,
+    whitespace: Option<WhitespaceMode>,
+    coalesce_text: bool,
+    text: String,
+    strip_leading_newline: bool,
+    content_model: ContentModel,
+    attributes: AttributesBuilder<'a>,
+    // Decodes the raw attributes in lazy mode, see `ParserOptions::lazy_attributes`
+    attribute_decoder: Option<Arc<dyn AttributeDecoder>>,
+    raw_attrs_start: usize,
+    raw_attrs: Option<RawAttributes>,
+    // The byte offset of the `<` of the current open tag
+    open_tag_start: usize,
+    limits: Limits,
+    exceeded_limits: Vec<Limit>,
+    parse_errors: Vec<ParseError>,
+    // Whether the name of the current open tag has been read, but not its end
+    is_in_open_tag: bool,
+    // Whether the current tag is not on the stack, because the depth limit was reached
+    is_flattened: bool,
+    // Whether the merged text was cut off, the rest of it is dropped
+    is_text_truncated: bool,
+    token_count: usize,
+    has_stopped: bool,
+    // Checks every emitted token in debug builds
+    #[cfg(debug_assertions)]
+    validator: StreamValidator,
 }
 
-fn get_instruction_name(value: &str) -> Cow<str> {
+fn get_instruction_name(value: &str) -> Cow<'_, str> {
     // Use the regex search method to find the index
     if let Some(index) = RE_NAME_END.find(value) {
         // Extract the substring up to the match index
@@ -59,112 +176,491 @@ fn get_instruction_name(value: &str) -> Cow<str> {
     Cow::Borrowed(value)
 }
 
+/**
+ * The content model of the text following the start tag, outside of foreign content: the
+ * contents of eg. `<title>` and `<style>` are text.
+ */
+fn html_content_model(name: &LocalName) -> ContentModel {
+    if is_rcdata_elements(name) {
+        ContentModel::RcData
+    } else if is_rawtext_elements(name) {
+        ContentModel::RawText
+    } else if is_script(name) {
+        ContentModel::ScriptData
+    } else if is_plaintext(name) {
+        ContentModel::PlainText
+    } else {
+        ContentModel::Data
+    }
+}
+
+/** A tokenizer for the document, set up as the parser needs it. */
+fn new_tokenizer<'a>(bytes: &'a [u8], options: &'a ParserOptions) -> Tokenizer<'a> {
+    let mut tokenizer = Tokenizer::new(bytes, &options.tokenizer_options);
+
+    tokenizer.set_cdata_allowed(options.xml_mode);
+    if options.whitespace.is_some() {
+        tokenizer.set_ignore_whitespace_between_tags(false);
+    }
+
+    tokenizer
+}
+
 impl<'i> Parser<'i> {
     pub fn new<'a>(html: &'a str, options: &'a ParserOptions) -> Parser<'a> {
-        let bytes = html.as_bytes();
+        Parser::from_bytes(html.as_bytes(), options)
+    }
+
+    /**
+     * Parses input that is not known to be valid UTF-8, eg. scraped pages in an unknown encoding.
+     * Invalid UTF-8 sequences are replaced with U+FFFD REPLACEMENT CHARACTER in the tokens.
+     */
+    pub fn from_bytes<'a>(bytes: &'a [u8], options: &'a ParserOptions) -> Parser<'a> {
+        let limits = options.limits.unwrap_or_default();
+        let mut exceeded_limits = Vec::new();
+        let mut bytes = bytes;
+
+        if limits.max_input_size.is_some_and(|max| bytes.len() > max) {
+            exceeded_limits.push(Limit::InputSize);
+            bytes = &[];
+        }
 
         Parser {
             buffer: bytes,
             html_mode: !options.xml_mode,
-            tokenizer: Tokenizer::new(&bytes, &options.tokenizer_options),
-            tag_name: "".into(),
+            tokenizer: new_tokenizer(bytes, options),
+            #[cfg(feature = "parallel")]
+            chunks: None,
+            tag_name: LocalName::new(""),
             next_nodes: Default::default(),
             stack: Default::default(),
-            foreign_context: VecDeque::from([options.xml_mode]),
-            attribs: Default::default(),
-            attrib_value: None,
-            attrib_name: Default::default(),
+            open_elements: Default::default(),
+            foreign_context: VecDeque::from([(options.xml_mode, 0)]),
+            whitespace_context: VecDeque::from([(false, 0)]),
+            whitespace: options.whitespace,
+            coalesce_text: options.coalesce_text.unwrap_or(true) || options.whitespace.is_some(),
+            text: String::new(),
+            strip_leading_newline: false,
+            content_model: ContentModel::Data,
+            attributes: Default::default(),
+            attribute_decoder: if options.lazy_attributes {
+                Some(Arc::new(LazyAttributeDecoder::new(options)))
+            } else {
+                None
+            },
+            raw_attrs_start: 0,
+            open_tag_start: 0,
+            raw_attrs: None,
+            limits,
+            exceeded_limits,
+            parse_errors: Vec::new(),
+            is_in_open_tag: false,
+            is_flattened: false,
+            is_text_truncated: false,
+            token_count: 0,
+            has_stopped: false,
+            #[cfg(debug_assertions)]
+            validator: StreamValidator::new(options.xml_mode),
+        }
+    }
+
+    /**
+     * Starts over with a new document, as if the parser was just created with the same options.
+     * The buffers and collections keep their capacity, so a parser can be reused for many
+     * documents without allocating them again.
+     */
+    pub fn reset(&mut self, html: &'i str) {
+        self.reset_bytes(html.as_bytes());
+    }
+
+    /** Like `reset`, for input that is not known to be valid UTF-8, see `from_bytes`. */
+    pub fn reset_bytes(&mut self, bytes: &'i [u8]) {
+        let xml_mode = !self.html_mode;
+
+        self.exceeded_limits.clear();
+        self.parse_errors.clear();
+        self.buffer = bytes;
+        if self.limits.max_input_size.is_some_and(|max| bytes.len() > max) {
+            self.exceeded_limits.push(Limit::InputSize);
+            self.buffer = &[];
+        }
+
+        self.tokenizer.reset(self.buffer);
+        self.tokenizer.set_cdata_allowed(xml_mode);
+        #[cfg(feature = "parallel")]
+        {
+            self.chunks = None;
+        }
+
+        self.tag_name = LocalName::new("");
+        self.next_nodes.clear();
+        self.stack.clear();
+        self.open_elements.clear();
+        self.foreign_context.clear();
+        self.foreign_context.push_back((xml_mode, 0));
+        self.whitespace_context.clear();
+        self.whitespace_context.push_back((false, 0));
+        self.text.clear();
+        self.strip_leading_newline = false;
+        self.content_model = ContentModel::Data;
+        self.attributes.clear();
+        self.raw_attrs_start = 0;
+        self.open_tag_start = 0;
+        self.raw_attrs = None;
+        self.is_in_open_tag = false;
+        self.is_flattened = false;
+        self.is_text_truncated = false;
+        self.token_count = 0;
+        self.has_stopped = false;
+        #[cfg(debug_assertions)]
+        {
+            self.validator = StreamValidator::new(xml_mode);
+        }
+    }
+
+    /** The limits that were exceeded so far, in the order they were first exceeded. */
+    pub fn exceeded_limits(&self) -> &[Limit] {
+        &self.exceeded_limits
+    }
+
+    /**
+     * The problems in the markup found so far, in the order of the input. The parser recovers from
+     * them like a browser would, see `ParseErrorKind`.
+     */
+    pub fn parse_errors(&self) -> &[ParseError] {
+        &self.parse_errors
+    }
+
+    fn exceed(&mut self, limit: Limit) {
+        if !self.exceeded_limits.contains(&limit) {
+            self.exceeded_limits.push(limit);
+        }
+    }
+
+    fn report(&mut self, position: usize, kind: ParseErrorKind) {
+        self.parse_errors.push(ParseError { position, kind });
+    }
+
+    /** Ends the document early, the open elements are closed. */
+    fn stop(&mut self, limit: Limit) {
+        self.exceed(limit);
+        self.has_stopped = true;
+        self.text.clear();
+        self.onend();
+    }
+
+    fn in_foreign_content(&self) -> bool {
+        self.foreign_context[0].0
+    }
+
+    /**
+     * The content model of the text following the start tag, as the tree builder of the spec
+     * decides it. Elements in foreign content (eg. `<style>` inside `<svg>`) are regular elements.
+     */
+    fn content_model(&self, name: &LocalName) -> ContentModel {
+        if !self.html_mode || self.in_foreign_content() {
+            ContentModel::Data
+        } else {
+            html_content_model(name)
+        }
+    }
+
+    /** Tells the tokenizer the content model of the text following the current start tag. */
+    fn set_content_model(&mut self) {
+        #[cfg(feature = "parallel")]
+        if let Some(chunks) = &mut self.chunks {
+            chunks.set_content_model(&mut self.tokenizer, self.content_model);
+        }
+
+        self.tokenizer.set_content_model(self.content_model);
+    }
+
+    /** Tells the tokenizer whether `<![CDATA[` starts a CDATA section, as in foreign content. */
+    fn set_cdata_allowed(&mut self) {
+        let cdata_allowed = self.in_foreign_content();
+
+        #[cfg(feature = "parallel")]
+        if let Some(chunks) = &mut self.chunks {
+            chunks.set_cdata_allowed(&mut self.tokenizer, cdata_allowed);
+        }
+
+        self.tokenizer.set_cdata_allowed(cdata_allowed);
+    }
+
+    fn next_tokenizer_token(&mut self) -> Option<TokenizerToken> {
+        #[cfg(feature = "parallel")]
+        if let Some(chunks) = &mut self.chunks {
+            return chunks.next(&mut self.tokenizer);
+        }
+
+        self.tokenizer.next()
+    }
+
+    /** Decodes the part of the input of the token, replacing invalid UTF-8. */
+    fn slice(&self, tokenizer_token: &TokenizerToken) -> Cow<'i, str> {
+        slice(self.buffer, tokenizer_token)
+    }
+
+    /** The key of an element in `open_elements`, HTML element names ignore ASCII case. */
+    fn open_element_key<'n>(&self, name: &'n LocalName) -> &'n LocalName {
+        if self.html_mode {
+            name.as_element()
+        } else {
+            name
+        }
+    }
+
+    fn push_stack(&mut self, name: LocalName) {
+        let key = self.open_element_key(&name).clone();
+
+        self.stack.push_front(name);
+        *self.open_elements.entry(key).or_insert(0) += 1;
+    }
+
+    fn pop_stack(&mut self) -> Option<LocalName> {
+        let element = self.stack.pop_front();
+
+        if let Some(name) = &element {
+            let key = self.open_element_key(name);
+
+            if let Some(count) = self.open_elements.get_mut(key) {
+                *count -= 1;
+            }
+        }
+
+        if self.foreign_context.len() > 1 && self.foreign_context[0].1 > self.stack.len() {
+            self.foreign_context.pop_front();
+            self.set_cdata_allowed();
+        }
+        if self.whitespace_context.len() > 1 && self.whitespace_context[0].1 > self.stack.len() {
+            self.whitespace_context.pop_front();
+        }
+
+        element
+    }
+
+    fn preserves_whitespace(&self) -> bool {
+        if self.content_model != ContentModel::Data {
+            return true;
+        }
+
+        // Only `xml:space` is decoded in lazy mode, the other attributes stay raw
+        let xml_space = match &self.raw_attrs {
+            Some(raw_attrs) => raw_attrs
+                .decode_attribute("xml:space")
+                .flatten()
+                .map(|(value, _)| Cow::Owned(value.into_string())),
+            None => self
+                .attributes
+                .attribs
+                .get("xml:space")
+                .and_then(|value| value.as_ref())
+                .map(|(value, _)| Cow::Borrowed(&**value)),
+        };
+
+        match xml_space.as_deref() {
+            Some("preserve") => true,
+            Some("default") => false,
+            _ => {
+                self.whitespace_context[0].0
+                    || (self.html_mode && is_whitespace_preserving_elements(&self.tag_name))
+            }
         }
     }
 
-    unsafe fn on_text(&mut self, tokenizer_token: TokenizerToken) {
+    /** Emits the merged text in front of the tokens that ended it. */
+    fn flush_text(&mut self, preserve_whitespace: bool) {
+        if self.text.is_empty() {
+            return;
+        }
+
+        let data = match self.whitespace {
+            Some(mode) if !preserve_whitespace => apply_whitespace_mode(mode, &self.text),
+            _ => Some(Cow::Borrowed(self.text.as_str())),
+        };
+
+        if let Some(data) = data {
+            self.next_nodes.push_front(Token {
+                data: data.into(),
+                attrs: None,
+                raw_attrs: None,
+                kind: TokenKind::Text,
+                is_implied: false,
+                is_cdata: false,
+                start: None,
+            });
+        }
+
+        self.text.clear();
+        self.is_text_truncated = false;
+    }
+
+    /** Appends to the merged text, as long as it fits into `max_text_length`. */
+    fn push_text(&mut self, text: &str) {
+        if self.is_text_truncated {
+            return;
+        }
+
+        let text = match self.limits.max_text_length {
+            Some(max) if self.text.len() + text.len() > max => {
+                self.exceed(Limit::TextLength);
+                self.is_text_truncated = true;
+                truncate(text, max.saturating_sub(self.text.len()))
+            }
+            _ => text,
+        };
+
+        self.text.push_str(text);
+    }
+
+    fn emit_text(&mut self, text: &str) {
+        let text = match self.limits.max_text_length {
+            Some(max) if text.len() > max => {
+                self.exceed(Limit::TextLength);
+                truncate(text, max)
+            }
+            _ => text,
+        };
+
         self.next_nodes.push_back(Token {
-            data: String::from_utf8_unchecked(
-                self.buffer[tokenizer_token.start..tokenizer_token.end].to_owned(),
-            ).into_boxed_str(),
+            data: text.into(),
             attrs: None,
+            raw_attrs: None,
             kind: TokenKind::Text,
             is_implied: false,
+            is_cdata: false,
+            start: None,
         });
     }
 
+    fn on_text(&mut self, tokenizer_token: TokenizerToken) {
+        let text = self.slice(&tokenizer_token);
+
+        if self.coalesce_text {
+            if take(&mut self.strip_leading_newline) {
+                self.push_text(strip_leading_newline(&text));
+            } else {
+                self.push_text(&text);
+            }
+        } else {
+            self.emit_text(&text);
+        }
+    }
+
     fn on_text_entity(&mut self, tokenizer_token: TokenizerToken) {
-        let data_string = char::from_u32(tokenizer_token.code).unwrap();
+        let c = char::from_u32(tokenizer_token.code).unwrap_or(char::REPLACEMENT_CHARACTER);
+        let mut buffer = [0; 4];
 
-        self.next_nodes.push_back(Token {
-            data: data_string.to_string().into_boxed_str(),
-            attrs: None,
-            kind: TokenKind::Text,
-            is_implied: false,
-        });
+        if self.coalesce_text {
+            if !(take(&mut self.strip_leading_newline) && c == '\n') {
+                self.push_text(c.encode_utf8(&mut buffer));
+            }
+        } else {
+            self.emit_text(c.encode_utf8(&mut buffer));
+        }
     }
 
-    fn is_void_element(&self, name: &str) -> bool {
+    fn is_void_element(&self, name: &LocalName) -> bool {
         self.html_mode && is_void_elements(name)
     }
 
-    unsafe fn on_open_tag_name(&mut self, tokenizer_token: TokenizerToken) {
-        let name = str::from_utf8_unchecked(
-            &self.buffer[tokenizer_token.start..tokenizer_token.end],
-        );
+    fn on_open_tag_name(&mut self, tokenizer_token: TokenizerToken) {
+        let name = LocalName::new(&self.slice(&tokenizer_token));
+
+        self.open_tag_start = tokenizer_token.start.saturating_sub(1);
+        self.content_model = self.content_model(&name);
 
         self.emit_open_tag(name);
+        self.is_in_open_tag = true;
+        self.raw_attrs_start = tokenizer_token.end;
+        self.set_content_model();
     }
 
-    fn emit_open_tag(&mut self, name: &'i str) {
+    fn emit_open_tag(&mut self, name: LocalName) {
         self.tag_name = name;
 
-        let open_implies_close_option: Option<fn(tag_name: &str) -> bool> =
+        let open_implies_close_option: Option<fn(tag_name: &LocalName) -> bool> =
             open_implies_close(&self.tag_name);
 
         if let Some(open_implies_close_fn) = open_implies_close_option {
             while !self.stack.is_empty() && open_implies_close_fn(&self.stack[0]) {
-                let element = self.stack.pop_front().unwrap();
+                let element = self.pop_stack().unwrap();
 
                 self.next_nodes.push_back(Token {
-                    data: element,
+                    data: element.into(),
                     attrs: None,
+                    raw_attrs: None,
                     kind: TokenKind::CloseTag,
                     is_implied: true,
+                    is_cdata: false,
+                    start: None,
                 });
             }
         }
+        self.is_flattened = false;
+
         if !self.is_void_element(&self.tag_name) {
-            self.stack.push_front(self.tag_name.to_string().into_boxed_str());
+            if self.limits.max_depth.is_some_and(|max| self.stack.len() >= max) {
+                self.exceed(Limit::Depth);
+                self.is_flattened = true;
+
+                return;
+            }
+
+            self.push_stack(self.tag_name.clone());
 
             if self.html_mode {
+                let depth = self.stack.len();
+
                 if is_foreign_context_elements(&self.tag_name) {
-                    self.foreign_context.push_front(true);
+                    self.foreign_context.push_front((true, depth));
                 } else if is_html_integration_elements(&self.tag_name) {
-                    self.foreign_context.push_front(false);
+                    self.foreign_context.push_front((false, depth));
                 }
+                self.set_cdata_allowed();
             }
         }
     }
 
     fn end_open_tag(&mut self, is_implied: bool) {
+        self.is_in_open_tag = false;
+
         let is_void = self.is_void_element(&self.tag_name);
 
-        let close_node_option = if is_void {
+        if !is_void && !self.is_flattened && self.whitespace.is_some() {
+            let preserve = self.preserves_whitespace();
+
+            self.whitespace_context.push_front((preserve, self.stack.len()));
+            self.strip_leading_newline =
+                self.html_mode && is_whitespace_preserving_elements(&self.tag_name);
+        }
+
+        let close_node_option = if is_void || self.is_flattened {
             Some(Token {
-                data: self.tag_name.to_string().into_boxed_str(),
+                data: self.tag_name.clone().into(),
                 attrs: None,
+                raw_attrs: None,
                 kind: TokenKind::CloseTag,
                 is_implied: true,
+                is_cdata: false,
+                start: None,
             })
         } else {
             None
         };
 
         self.next_nodes.push_back(Token {
-            data: self.tag_name.to_string().into_boxed_str(),
-            attrs: if self.attribs.is_empty() {
+            data: self.tag_name.clone().into(),
+            attrs: if self.attributes.attribs.is_empty() {
                 None
             } else {
-                Some(take(&mut self.attribs))
+                Some(take(&mut self.attributes.attribs))
             },
+            raw_attrs: self.raw_attrs.take(),
             kind: TokenKind::OpenTag,
             is_implied,
+            is_cdata: false,
+            start: (!is_implied).then_some(self.open_tag_start),
         });
 
         if let Some(close_node) = close_node_option {
@@ -172,58 +668,113 @@ impl<'i> Parser<'i> {
         }
     }
 
-    fn on_open_tag_end(&mut self) {
+    /** Keeps the source of the attributes in lazy mode, the tag ends at `tokenizer_token`. */
+    fn read_raw_attributes(&mut self, tokenizer_token: &TokenizerToken) {
+        let Some(decoder) = &self.attribute_decoder else {
+            return;
+        };
+        let source = &self.buffer[self.raw_attrs_start..tokenizer_token.start];
+
+        if source.iter().all(|c| matches!(c, b' ' | b'\n' | b'\t' | b'/')) {
+            return;
+        }
+
+        self.raw_attrs = Some(RawAttributes::new(source.into(), decoder.clone()));
+    }
+
+    fn on_open_tag_end(&mut self, tokenizer_token: TokenizerToken) {
+        self.read_raw_attributes(&tokenizer_token);
         self.end_open_tag(false);
     }
 
-    unsafe fn on_close_tag(&mut self, tokenizer_token: TokenizerToken) {
-        let name: &str =
-            str::from_utf8_unchecked(&self.buffer[tokenizer_token.start..tokenizer_token.end]);
+    fn on_close_tag(&mut self, tokenizer_token: TokenizerToken) {
+        let source = self.slice(&tokenizer_token);
+        // HTML end tags of known elements close them in any case
+        let name = if self.html_mode {
+            LocalName::known(&source).or_else(|| LocalName::get(&source))
+        } else {
+            LocalName::get(&source)
+        };
+        // A name that was never interned can't be open, and is not `p` or `br`
+        let Some(name) = name else {
+            let name = LocalName::new(&source);
 
-        if is_foreign_context_elements(name) || is_html_integration_elements(name) {
-            self.foreign_context.pop_front();
-        }
+            self.report(tokenizer_token.start, ParseErrorKind::UnexpectedEndTag(name));
+            return;
+        };
 
-        if !self.is_void_element(name) {
-            let pos = self.stack.iter().position(|n| &**n == name);
+        if !self.is_void_element(&name) {
+            let key = self.open_element_key(&name);
+            let is_open = self.open_elements.get(key).is_some_and(|count| *count > 0);
+            let pos = if is_open {
+                self.stack
+                    .iter()
+                    .position(|n| self.open_element_key(n) == key)
+            } else {
+                None
+            };
             if let Some(index) = pos {
                 for i in 0..index + 1 {
-                    let tag = self.stack.pop_front().unwrap();
+                    let tag = self.pop_stack().unwrap();
+                    if i != index && requires_end_tag(&tag, self.html_mode) {
+                        let kind = ParseErrorKind::UnclosedElement(tag.clone());
+
+                        self.report(tokenizer_token.start, kind);
+                    }
                     self.next_nodes.push_back(Token {
-                        data: tag,
+                        data: tag.into(),
                         attrs: None,
+                        raw_attrs: None,
                         kind: TokenKind::CloseTag,
                         is_implied: i != index,
+                        is_cdata: false,
+                        start: None,
                     });
                 }
-            } else if self.html_mode && name == "p" {
+            } else if self.html_mode && name == LocalName::P {
+                self.report(tokenizer_token.start, ParseErrorKind::UnexpectedEndTag(name));
                 // Implicit open before close
-                self.emit_open_tag("p");
+                self.content_model = ContentModel::Data;
+                self.emit_open_tag(LocalName::P);
                 self.close_current_tag(true);
+            } else {
+                self.report(tokenizer_token.start, ParseErrorKind::UnexpectedEndTag(name));
             }
-        } else if self.html_mode && name == "br" {
+        } else if self.html_mode && name == LocalName::Br {
+            self.report(tokenizer_token.start, ParseErrorKind::UnexpectedEndTag(name));
             // We can't use `emit_open_tag` for implicit open, as `br` would be implicitly closed.
             self.next_nodes.push_back(Token {
-                data: "br".to_string().into_boxed_str(),
+                data: LocalName::Br.into(),
                 attrs: None,
+                raw_attrs: None,
                 kind: TokenKind::OpenTag,
                 is_implied: false,
+                is_cdata: false,
+                start: Some(tokenizer_token.start.saturating_sub(2)),
             });
             self.next_nodes.push_back(Token {
-                data: "br".to_string().into_boxed_str(),
+                data: LocalName::Br.into(),
                 attrs: None,
+                raw_attrs: None,
                 kind: TokenKind::CloseTag,
                 is_implied: false,
+                is_cdata: false,
+                start: None,
             });
+        } else {
+            self.report(tokenizer_token.start, ParseErrorKind::UnexpectedEndTag(name));
         }
     }
 
-    fn on_self_closing_tag(&mut self) {
-        if self.foreign_context[0] {
+    fn on_self_closing_tag(&mut self, tokenizer_token: TokenizerToken) {
+        self.read_raw_attributes(&tokenizer_token);
+
+        if self.in_foreign_content() {
             self.close_current_tag(false);
         } else {
             // Ignore the fact that the tag is self-closing.
-            self.on_open_tag_end();
+            self.content_model = ContentModel::Data;
+            self.end_open_tag(false);
         }
     }
 
@@ -231,162 +782,176 @@ impl<'i> Parser<'i> {
         self.end_open_tag(is_open_implied);
 
         // Self-closing tags will be on the top of the stack
-        if &*self.stack[0] == self.tag_name {
+        if !self.is_flattened && self.stack.front() == Some(&self.tag_name) {
             // If the opening tag isn't implied, the closing tag has to be implied.
             self.next_nodes.push_back(Token {
-                data: self.tag_name.to_string().into_boxed_str(),
+                data: self.tag_name.clone().into(),
                 attrs: None,
+                raw_attrs: None,
                 kind: TokenKind::CloseTag,
                 is_implied: !is_open_implied,
+                is_cdata: false,
+                start: None,
             });
-            self.stack.pop_front();
+            self.pop_stack();
         }
     }
 
-    unsafe fn on_attrib_name(&mut self, tokenizer_token: TokenizerToken) {
-        let name: &str =
-            str::from_utf8_unchecked(&self.buffer[tokenizer_token.start..tokenizer_token.end]);
-
-        self.attrib_name = name;
-    }
-
-    unsafe fn on_attrib_data(&mut self, tokenizer_token: TokenizerToken) {
-        let new_attrib = match self.attrib_value.take() {
-            None => Some(String::from_utf8_unchecked(
-                self.buffer[tokenizer_token.start..tokenizer_token.end].to_owned(),
-            )),
-            Some(existing_value) => {
-                let mut modified_cow = existing_value;
-
-                modified_cow.push_str(str::from_utf8_unchecked(
-                    &self.buffer[tokenizer_token.start..tokenizer_token.end],
-                ));
-
-                Some(modified_cow)
-            }
-        };
-
-        self.attrib_value = new_attrib;
-    }
-
-    fn on_attrib_entity(&mut self, tokenizer_token: TokenizerToken) {
-        let c = char::from_u32(tokenizer_token.code).unwrap();
-
-        let new_attrib = match self.attrib_value.take() {
-            None => Some(c.to_string()),
-            Some(existing_value) => {
-                let mut owned_value = existing_value;
-                owned_value.push(c);
-
-                Some(owned_value)
-            }
-        };
-
-        self.attrib_value = new_attrib;
-    }
-
-    fn on_attrib_end(&mut self, tokenizer_token: TokenizerToken) {
-        if !self.attribs.contains_key(self.attrib_name) {
-            let new_attribute: Option<(Box<str>, QuoteType)> = self
-                .attrib_value
-                .as_mut()
-                .map(|attrib_value| (attrib_value.clone().into_boxed_str(), tokenizer_token.quote));
+    fn on_attrib(&mut self, tokenizer_token: TokenizerToken) {
+        let buffer: &'i [u8] = self.buffer;
 
-            self.attribs.insert(self.attrib_name.to_owned(), new_attribute);
+        if let Some(limit) = self.attributes.on_token(&tokenizer_token, buffer, &self.limits) {
+            self.exceed(limit);
         }
-        self.attrib_value = None;
     }
 
-    unsafe fn on_declaration(&mut self, tokenizer_token: TokenizerToken) {
-        let value: &str =
-            str::from_utf8_unchecked(&self.buffer[tokenizer_token.start..tokenizer_token.end]);
+    fn on_declaration(&mut self, tokenizer_token: TokenizerToken) {
+        let value = self.slice(&tokenizer_token);
         let name = get_instruction_name(&value);
 
         self.next_nodes.push_back(Token {
-            data: name.to_string().into_boxed_str(),
+            data: name.into(),
             attrs: None,
+            raw_attrs: None,
             kind: TokenKind::ProcessingInstruction,
             is_implied: false,
+            is_cdata: false,
+            start: None,
         });
     }
 
-    unsafe fn on_processing_instruction(&mut self, tokenizer_token: TokenizerToken) {
-        let value: &str =
-            str::from_utf8_unchecked(&self.buffer[tokenizer_token.start..tokenizer_token.end]);
-        let name = get_instruction_name(value);
+    fn on_processing_instruction(&mut self, tokenizer_token: TokenizerToken) {
+        let value = self.slice(&tokenizer_token);
+        let name = get_instruction_name(&value);
 
         self.next_nodes.push_back(Token {
-            data: name.to_string().into_boxed_str(),
+            data: name.into(),
             attrs: None,
+            raw_attrs: None,
             kind: TokenKind::ProcessingInstruction,
             is_implied: false,
+            is_cdata: false,
+            start: None,
         });
     }
 
-    unsafe fn on_comment(&mut self, tokenizer_token: TokenizerToken) {
+    fn on_comment(&mut self, tokenizer_token: TokenizerToken, is_cdata: bool) {
         self.next_nodes.push_back(Token {
-            data: String::from_utf8_unchecked(
-                self.buffer[tokenizer_token.start..tokenizer_token.end].to_owned(),
-            ).into_boxed_str(),
+            data: self.slice(&tokenizer_token).into(),
             attrs: None,
+            raw_attrs: None,
             kind: TokenKind::Comment,
             is_implied: false,
+            is_cdata,
+            start: None,
         });
         self.next_nodes.push_back(Token {
             data: "".into(),
             attrs: None,
+            raw_attrs: None,
             kind: TokenKind::CommentEnd,
             is_implied: false,
+            is_cdata,
+            start: None,
         });
     }
 
-    unsafe fn on_cdata(&mut self, tokenizer_token: TokenizerToken) {
-        self.on_comment(tokenizer_token);
-    }
-
     fn onend(&mut self) {
+        // A tag that is cut off by the end of the input is dropped
+        if take(&mut self.is_in_open_tag)
+            && !self.is_void_element(&self.tag_name)
+            && !self.is_flattened
+        {
+            self.pop_stack();
+        }
+
+        // Elements that are cut off by a limit are not errors in the markup
+        if !self.has_stopped {
+            for index in 0..self.stack.len() {
+                if requires_end_tag(&self.stack[index], self.html_mode) {
+                    let kind = ParseErrorKind::UnclosedElement(self.stack[index].clone());
+
+                    self.report(self.buffer.len(), kind);
+                }
+            }
+        }
+
         // Set the end index for all remaining tags
         let stack_iter = self.stack.iter();
         for item in stack_iter {
             self.next_nodes.push_back(Token {
-                data: item.to_owned(),
+                data: item.clone().into(),
                 attrs: None,
+                raw_attrs: None,
                 kind: TokenKind::CloseTag,
                 is_implied: true,
+                is_cdata: false,
+                start: None,
             })
         }
 
         self.stack.clear();
+        self.open_elements.clear();
     }
-    unsafe fn parser_next(&mut self) -> Option<Token> {
+    fn parser_next(&mut self) -> Option<Token> {
         loop {
             if let Some(existing_node) = self.next_nodes.pop_front() {
+                self.token_count += 1;
+
                 return Some(existing_node);
             }
+            if self.has_stopped {
+                return None;
+            }
+            if self.limits.max_tokens.is_some_and(|max| self.token_count >= max) {
+                self.stop(Limit::Tokens);
+                continue;
+            }
 
-            let possible_token = self.tokenizer.next();
-
-            match possible_token {
-                None => return None,
-                Some(tokenizer_token) => match tokenizer_token.location {
-                    TokenizerTokenLocation::AttrData => self.on_attrib_data(tokenizer_token),
-                    TokenizerTokenLocation::AttrEntity => self.on_attrib_entity(tokenizer_token),
-                    TokenizerTokenLocation::AttrEnd => self.on_attrib_end(tokenizer_token),
-                    TokenizerTokenLocation::AttrName => self.on_attrib_name(tokenizer_token),
-                    TokenizerTokenLocation::CData => self.on_cdata(tokenizer_token),
-                    TokenizerTokenLocation::CloseTag => self.on_close_tag(tokenizer_token),
-                    TokenizerTokenLocation::Comment => self.on_comment(tokenizer_token),
-                    TokenizerTokenLocation::Declaration => self.on_declaration(tokenizer_token),
-                    TokenizerTokenLocation::OpenTagEnd => self.on_open_tag_end(),
-                    TokenizerTokenLocation::OpenTagName => self.on_open_tag_name(tokenizer_token),
-                    TokenizerTokenLocation::ProcessingInstruction => {
-                        self.on_processing_instruction(tokenizer_token)
-                    }
-                    TokenizerTokenLocation::SelfClosingTag => self.on_self_closing_tag(),
-                    TokenizerTokenLocation::Text => self.on_text(tokenizer_token),
-                    TokenizerTokenLocation::TextEntity => self.on_text_entity(tokenizer_token),
-                    TokenizerTokenLocation::End => self.onend(),
-                },
+            let tokenizer_token = self.next_tokenizer_token()?;
+            let location = tokenizer_token.location;
+            let is_text = matches!(
+                location,
+                TokenizerTokenLocation::Text | TokenizerTokenLocation::TextEntity
+            );
+            let preserve_whitespace = self.whitespace_context[0].0;
+
+            if !is_text {
+                self.strip_leading_newline = false;
+            }
+
+            match location {
+                // The attributes are read from the source at the end of the tag in lazy mode
+                TokenizerTokenLocation::AttrData
+                | TokenizerTokenLocation::AttrEntity
+                | TokenizerTokenLocation::AttrEnd
+                | TokenizerTokenLocation::AttrName
+                    if self.attribute_decoder.is_some() => {}
+                TokenizerTokenLocation::AttrData
+                | TokenizerTokenLocation::AttrEntity
+                | TokenizerTokenLocation::AttrEnd
+                | TokenizerTokenLocation::AttrName => self.on_attrib(tokenizer_token),
+                TokenizerTokenLocation::CData => self.on_comment(tokenizer_token, true),
+                TokenizerTokenLocation::CloseTag => self.on_close_tag(tokenizer_token),
+                TokenizerTokenLocation::Comment => self.on_comment(tokenizer_token, false),
+                TokenizerTokenLocation::Declaration => self.on_declaration(tokenizer_token),
+                TokenizerTokenLocation::OpenTagEnd => self.on_open_tag_end(tokenizer_token),
+                TokenizerTokenLocation::OpenTagName => self.on_open_tag_name(tokenizer_token),
+                TokenizerTokenLocation::ProcessingInstruction => {
+                    self.on_processing_instruction(tokenizer_token)
+                }
+                TokenizerTokenLocation::SelfClosingTag => self.on_self_closing_tag(tokenizer_token),
+                TokenizerTokenLocation::Text => self.on_text(tokenizer_token),
+                TokenizerTokenLocation::TextEntity => self.on_text_entity(tokenizer_token),
+                TokenizerTokenLocation::End => self.onend(),
+            }
+
+            // The text ends when something else than text is emitted. Tokens that are ignored,
+            // eg. orphan end tags, do not split it.
+            if !is_text
+                && (!self.next_nodes.is_empty() || location == TokenizerTokenLocation::End)
+            {
+                self.flush_text(preserve_whitespace);
             }
         }
     }
@@ -396,6 +961,13 @@ impl <'a> Iterator for Parser<'a> {
     type Item = Token;
 
     fn next(&mut self) -> Option<Token> {
-        unsafe { self.parser_next() }
+        let token = self.parser_next();
+
+        #[cfg(debug_assertions)]
+        if let Err(error) = self.validator.check(token.as_ref()) {
+            panic!("{}", error);
+        }
+
+        token
     }
 }
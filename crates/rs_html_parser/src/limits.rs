@@ -0,0 +1,59 @@
+/**
+ * Resource limits for parsing untrusted input. Every limit is disabled by default.
+ *
+ * Exceeded limits are reported by `Parser::exceeded_limits`.
+ */
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct Limits {
+    /**
+     * Maximum number of open elements. Deeper elements are closed right after they are opened,
+     * so their content becomes the content of their parent.
+     */
+    pub max_depth: Option<usize>,
+
+    /** Maximum number of attributes per tag, the remaining attributes are ignored. */
+    pub max_attributes: Option<usize>,
+
+    /**
+     * Maximum length of attribute names and values in bytes. Longer values are truncated and
+     * attributes with longer names are ignored.
+     */
+    pub max_attribute_length: Option<usize>,
+
+    /** Maximum length of a `Text` token in bytes, longer text is truncated. */
+    pub max_text_length: Option<usize>,
+
+    /**
+     * Maximum number of tokens. Once it is reached, parsing stops and the open elements are
+     * closed.
+     */
+    pub max_tokens: Option<usize>,
+
+    /** Maximum size of the input in bytes. Larger input is not parsed at all. */
+    pub max_input_size: Option<usize>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Limit {
+    Depth,
+    Attributes,
+    AttributeLength,
+    TextLength,
+    Tokens,
+    InputSize,
+}
+
+/** The longest prefix of `value` that is at most `max_length` bytes long. */
+pub fn truncate(value: &str, max_length: usize) -> &str {
+    if value.len() <= max_length {
+        return value;
+    }
+
+    let mut end = max_length;
+
+    while !value.is_char_boundary(end) {
+        end -= 1;
+    }
+
+    &value[..end]
+}
@@ -0,0 +1,267 @@
+use crate::url::resolve_url;
+use crate::whitespace::is_html_whitespace;
+use crate::{Parser, ParserOptions};
+use rs_html_parser_tokens::{LocalName, Token, TokenKind};
+
+#[derive(Debug, Default)]
+pub struct LinkOptions {
+    /**
+     * The URL of the document, relative URLs are resolved against it, or against the first
+     * `<base href>` resolved against it.
+     *
+     * @default None, only a `<base href>` with an absolute URL resolves relative URLs
+     */
+    pub document_url: Option<String>,
+}
+
+/** A URL in an attribute of an element. */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Link {
+    pub element: LocalName,
+    /** The name of the attribute, in ASCII lowercase. */
+    pub attribute: String,
+    /** The URL as it is written, with character references decoded. */
+    pub url: String,
+    /** The absolute URL, `None` when the URL is relative and there is no base URL. */
+    pub resolved: Option<String>,
+    /** The `rel` keywords of the element, in ASCII lowercase. */
+    pub rel: Vec<String>,
+    /** Byte offset in the input of the start tag of the element. */
+    pub position: usize,
+}
+
+/** How the URLs are written in the value of an attribute. */
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum UrlSyntax {
+    Url,
+    /** URLs separated by whitespace, eg. `ping`. */
+    Urls,
+    /** Image candidates of `srcset`, URLs with a width or a pixel density. */
+    SrcSet,
+    /** The `content` of a `<meta http-equiv=refresh>`, a delay and a URL. */
+    Refresh,
+    /** The `url()` functions of CSS declarations. */
+    Style,
+}
+
+/** The attributes of the element that contain URLs, in the order that they are reported. */
+fn url_attributes(name: &LocalName) -> &'static [(&'static str, UrlSyntax)] {
+    use UrlSyntax::*;
+
+    match name.element() {
+        LocalName::A | LocalName::Area => &[("href", Url), ("ping", Urls)],
+        LocalName::Link => &[("href", Url), ("imagesrcset", SrcSet)],
+        LocalName::Base => &[("href", Url)],
+        LocalName::Img => &[("src", Url), ("srcset", SrcSet), ("longdesc", Url)],
+        LocalName::Source => &[("src", Url), ("srcset", SrcSet)],
+        LocalName::Script
+        | LocalName::Iframe
+        | LocalName::Frame
+        | LocalName::Embed
+        | LocalName::Track
+        | LocalName::Audio => &[("src", Url)],
+        LocalName::Video => &[("src", Url), ("poster", Url)],
+        LocalName::Input => &[("src", Url), ("formaction", Url)],
+        LocalName::Button => &[("formaction", Url)],
+        LocalName::Form => &[("action", Url)],
+        LocalName::Object => &[("data", Url)],
+        LocalName::Blockquote | LocalName::Q | LocalName::Del | LocalName::Ins => &[("cite", Url)],
+        LocalName::Html => &[("manifest", Url)],
+        LocalName::Body | LocalName::Table | LocalName::Td | LocalName::Th => {
+            &[("background", Url)]
+        }
+        LocalName::Meta => &[("content", Refresh)],
+        LocalName::Image | LocalName::Use => &[("href", Url), ("xlink:href", Url)],
+        _ => &[],
+    }
+}
+
+/** The URLs of the image candidates of a `srcset`, as browsers parse it. */
+fn srcset_urls(srcset: &str) -> Vec<&str> {
+    let mut urls = Vec::new();
+    let mut rest = srcset;
+
+    loop {
+        rest = rest.trim_start_matches(|c| is_html_whitespace(c) || c == ',');
+        if rest.is_empty() {
+            return urls;
+        }
+
+        let end = rest.find(is_html_whitespace).unwrap_or(rest.len());
+        let url = &rest[..end];
+
+        rest = &rest[end..];
+        // A comma right after the URL ends the candidate, or else the descriptors do
+        if url.ends_with(',') {
+            urls.push(url.trim_end_matches(','));
+            continue;
+        }
+        urls.push(url);
+
+        let mut depth = 0;
+        let end = rest
+            .find(|c| {
+                match c {
+                    '(' => depth += 1,
+                    ')' if depth > 0 => depth -= 1,
+                    ',' if depth == 0 => return true,
+                    _ => {}
+                }
+                false
+            })
+            .unwrap_or(rest.len());
+
+        rest = &rest[end..];
+    }
+}
+
+/** The URL of a refresh, `5; url=/next`, without quotes. A refresh without one reloads. */
+fn refresh_url(content: &str) -> Option<&str> {
+    let rest = content
+        .trim_start_matches(is_html_whitespace)
+        .trim_start_matches(|c: char| c.is_ascii_digit() || c == '.')
+        .trim_start_matches(is_html_whitespace);
+    let rest = rest
+        .strip_prefix([';', ','])
+        .unwrap_or(rest)
+        .trim_start_matches(is_html_whitespace);
+    let rest = match rest.get(..3) {
+        Some(prefix) if prefix.eq_ignore_ascii_case("url") => {
+            let after = rest[3..].trim_start_matches(is_html_whitespace);
+
+            match after.strip_prefix('=') {
+                Some(after) => after.trim_start_matches(is_html_whitespace),
+                None => rest,
+            }
+        }
+        _ => rest,
+    };
+    let url = match rest.chars().next() {
+        Some(quote @ ('"' | '\'')) => {
+            let rest = &rest[1..];
+
+            &rest[..rest.find(quote).unwrap_or(rest.len())]
+        }
+        _ => rest.trim_end_matches(is_html_whitespace),
+    };
+
+    (!url.is_empty()).then_some(url)
+}
+
+/** The URLs of the `url()` functions in CSS, quoted or not. */
+fn css_urls(css: &str) -> Vec<&str> {
+    let mut urls = Vec::new();
+    let mut rest = css;
+
+    while let Some(start) = rest.to_ascii_lowercase().find("url(") {
+        let value = rest[start + 4..].trim_start_matches(is_html_whitespace);
+        let (url, after) = match value.chars().next() {
+            Some(quote @ ('"' | '\'')) => {
+                let value = &value[1..];
+                let end = value.find(quote).unwrap_or(value.len());
+
+                (&value[..end], &value[end..])
+            }
+            _ => {
+                let end = value.find(')').unwrap_or(value.len());
+
+                (
+                    value[..end].trim_end_matches(is_html_whitespace),
+                    &value[end..],
+                )
+            }
+        };
+
+        if !url.is_empty() {
+            urls.push(url);
+        }
+        rest = after;
+    }
+    urls
+}
+
+/** The URLs in the attributes of the start tag, the base URL is not known yet. */
+fn tag_links(token: &Token, position: usize, links: &mut Vec<Link>) {
+    let Some(element) = token.name() else {
+        return;
+    };
+    let rel: Vec<String> = token
+        .attribute("rel")
+        .map(|rel| {
+            rel.split(is_html_whitespace)
+                .filter(|keyword| !keyword.is_empty())
+                .map(str::to_ascii_lowercase)
+                .collect()
+        })
+        .unwrap_or_default();
+    let is_refresh = token
+        .attribute("http-equiv")
+        .is_some_and(|http_equiv| http_equiv.trim().eq_ignore_ascii_case("refresh"));
+
+    for (attribute, syntax) in url_attributes(&element)
+        .iter()
+        .chain(&[("style", UrlSyntax::Style)])
+    {
+        let Some(value) = token.attribute(attribute) else {
+            continue;
+        };
+        let urls = match syntax {
+            UrlSyntax::Url => vec![value.as_ref()],
+            UrlSyntax::Urls => value
+                .split(is_html_whitespace)
+                .filter(|url| !url.is_empty())
+                .collect(),
+            UrlSyntax::SrcSet => srcset_urls(&value),
+            UrlSyntax::Refresh if is_refresh => refresh_url(&value).into_iter().collect(),
+            UrlSyntax::Refresh => Vec::new(),
+            UrlSyntax::Style => css_urls(&value),
+        };
+
+        for url in urls {
+            links.push(Link {
+                element: element.clone(),
+                attribute: attribute.to_string(),
+                url: url.to_string(),
+                resolved: None,
+                rel: rel.clone(),
+                position,
+            });
+        }
+    }
+}
+
+/**
+ * Collects the URLs in the attributes of the elements: links, resources like images, scripts and
+ * frames, form actions, `srcset` candidates, refresh URLs and `url()` in `style` attributes. They
+ * are resolved against the first `<base href>` and the document URL. The links are in document
+ * order, the links of an element in a fixed order of its attributes.
+ */
+pub fn extract_links(html: &str, options: &LinkOptions) -> Vec<Link> {
+    let parser_options = ParserOptions::default();
+    let mut links = Vec::new();
+
+    // Implied start tags are not in the input, and have no attributes
+    for token in Parser::new(html, &parser_options) {
+        if let (TokenKind::OpenTag, Some(position)) = (token.kind, token.start) {
+            tag_links(&token, position, &mut links);
+        }
+    }
+
+    let document_url = options.document_url.as_deref();
+    let base_url = links
+        .iter()
+        .find(|link| link.element.element() == LocalName::Base && link.attribute == "href")
+        .and_then(|base| resolve_url(document_url, &base.url))
+        .or_else(|| document_url.map(str::to_string));
+
+    for link in &mut links {
+        // The base URL is not resolved against itself
+        let base = match link.element.element() {
+            LocalName::Base => document_url,
+            _ => base_url.as_deref(),
+        };
+
+        link.resolved = resolve_url(base, &link.url);
+    }
+    links
+}
@@ -0,0 +1,731 @@
+use crate::element_info::is_block_elements;
+use crate::tree::{Document, NodeData, NodeId};
+use crate::whitespace::is_html_whitespace;
+use crate::ParserOptions;
+use rs_html_parser_tokenizer::TokenizerOptions;
+use rs_html_parser_tokens::LocalName;
+
+/** How elements without a Markdown syntax, eg. `<sup>` or `<video>`, are written. */
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum UnsupportedElements {
+    /** As inline HTML, which Markdown renderers pass through. */
+    #[default]
+    Html,
+    /** Not at all, with their content. */
+    Drop,
+}
+
+#[derive(Default)]
+pub struct MarkdownOptions {
+    /** @default UnsupportedElements::Html */
+    pub unsupported_elements: Option<UnsupportedElements>,
+
+    /**
+     * Writes tables and strikethrough with the syntax of GitHub Flavored Markdown. Otherwise they
+     * are unsupported elements, as in CommonMark.
+     *
+     * @default true
+     */
+    pub gfm: Option<bool>,
+}
+
+/** How an element is converted. */
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Kind {
+    /** Not rendered, so not written. */
+    Hidden,
+    Unsupported,
+    /** Converted by its name, or only its content if it has no Markdown syntax. */
+    Block,
+    Inline,
+}
+
+/**
+ * The deepest level of elements that is converted, the content of deeper elements is written as
+ * text. The conversion is recursive, this keeps the stack small for any document.
+ */
+const MAX_DEPTH: usize = 200;
+
+/** The largest number of an ordered list item, CommonMark allows at most 9 digits. */
+const MAX_LIST_NUMBER: u32 = 999_999_999;
+
+struct Block {
+    markdown: String,
+    is_list: bool,
+}
+
+struct Converter<'a> {
+    document: &'a Document,
+    unsupported_elements: UnsupportedElements,
+    gfm: bool,
+    /** Whether inline content is written in a table cell, where it has to stay on one line. */
+    in_table: bool,
+    /** The number of elements whose children are being converted. */
+    depth: usize,
+}
+
+/** The number of the longest run of the character in the text. */
+fn longest_run(text: &str, c: char) -> usize {
+    text.split(|other| other != c)
+        .map(str::len)
+        .max()
+        .unwrap_or(0)
+}
+
+/** Escapes what would start a block at the start of a line, eg. `# a` or `1. a`. */
+fn escape_line_start(line: &str) -> String {
+    let digits = line.len() - line.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+
+    match line.as_bytes().first() {
+        Some(b'#' | b'>' | b'-' | b'+' | b'=') => format!("\\{}", line),
+        Some(b'0'..=b'9') if matches!(line.as_bytes().get(digits), Some(b'.' | b')')) => {
+            format!("{}\\{}", &line[..digits], &line[digits..])
+        }
+        _ => line.to_string(),
+    }
+}
+
+/** Writes the content between the delimiters, with its surrounding whitespace outside of them. */
+fn delimit(markdown: &mut String, content: &str, start: &str, end: &str) {
+    let trimmed = content.trim_matches(' ');
+
+    if trimmed.is_empty() {
+        push_inline(markdown, content);
+        return;
+    }
+    if content.starts_with(' ') {
+        push_inline(markdown, " ");
+    }
+    markdown.push_str(start);
+    markdown.push_str(trimmed);
+    markdown.push_str(end);
+    if content.ends_with(' ') {
+        markdown.push(' ');
+    }
+}
+
+/** Writes inline content, whitespace at its start collapses with the whitespace before it. */
+fn push_inline(markdown: &mut String, content: &str) {
+    if markdown.is_empty() || markdown.ends_with([' ', '\n']) {
+        markdown.push_str(content.trim_start_matches(' '));
+    } else {
+        markdown.push_str(content);
+    }
+}
+
+/** A link destination, in angle brackets when it has spaces or parentheses. */
+fn destination(url: &str) -> String {
+    if url.contains([' ', '(', ')', '<', '>']) {
+        format!("<{}>", url.replace('<', "%3C").replace('>', "%3E"))
+    } else {
+        url.to_string()
+    }
+}
+
+fn title(title: Option<&str>) -> String {
+    match title {
+        Some(title) if !title.is_empty() => {
+            format!(" \"{}\"", title.replace('\\', "\\\\").replace('"', "\\\""))
+        }
+        _ => String::new(),
+    }
+}
+
+impl Converter<'_> {
+    fn kind(&self, id: NodeId) -> Kind {
+        let Some(name) = self.document.name(id) else {
+            return Kind::Inline;
+        };
+
+        use LocalName::*;
+        match name.element() {
+            Head | Title | Meta | Link | Base | Script | Style | Template | Noscript | Source
+            | Track | Param | Area | Col | Colgroup | Wbr => Kind::Hidden,
+            Sub | Sup | U | Mark | Kbd | Iframe | Video | Audio | Canvas | Svg | Math | Object
+            | Embed | Input | Button | Select | Textarea | Progress | Meter | Ruby | Details
+            | Dialog | Map => Kind::Unsupported,
+            Table | Del | S | Strike if !self.gfm => Kind::Unsupported,
+            _ if self.document.attribute(id, "hidden").is_some() => Kind::Hidden,
+            element if is_block_elements(&element) => Kind::Block,
+            _ => Kind::Inline,
+        }
+    }
+
+    fn unsupported(&self, id: NodeId) -> String {
+        match self.unsupported_elements {
+            // A blank line would end the HTML in Markdown
+            UnsupportedElements::Html => self
+                .document
+                .outer_html(id)
+                .lines()
+                .filter(|line| !line.trim().is_empty())
+                .collect::<Vec<_>>()
+                .join("\n"),
+            UnsupportedElements::Drop => String::new(),
+        }
+    }
+
+    fn text(&self, markdown: &mut String, text: &str) {
+        let mut chars = text.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match c {
+                c if is_html_whitespace(c) => {
+                    if !markdown.ends_with([' ', '\n']) {
+                        markdown.push(' ');
+                    }
+                    continue;
+                }
+                '\\' | '`' | '*' | '_' | '[' | ']' | '<' => markdown.push('\\'),
+                '~' if self.gfm => markdown.push('\\'),
+                '|' if self.in_table => markdown.push('\\'),
+                // Only a character reference would be decoded
+                '&' if chars
+                    .peek()
+                    .is_some_and(|next| next.is_ascii_alphanumeric() || *next == '#') =>
+                {
+                    markdown.push('\\')
+                }
+                _ => {}
+            }
+            markdown.push(c);
+        }
+    }
+
+    /** Writes the text of the node without its hidden elements, for content that is too deep. */
+    fn flat_text(&self, markdown: &mut String, id: NodeId) {
+        let mut stack = vec![id];
+
+        while let Some(id) = stack.pop() {
+            match self.kind(id) {
+                Kind::Hidden => continue,
+                Kind::Block if !markdown.is_empty() && !markdown.ends_with([' ', '\n']) => {
+                    markdown.push(' ')
+                }
+                _ => {}
+            }
+            if let NodeData::Text(text) = &self.document[id].data {
+                self.text(markdown, text);
+            }
+            stack.extend(self.document[id].children.iter().rev());
+        }
+    }
+
+    fn inline_children(&mut self, id: NodeId) -> String {
+        let mut markdown = String::new();
+
+        if self.depth >= MAX_DEPTH {
+            for child in &self.document[id].children {
+                self.flat_text(&mut markdown, *child);
+            }
+            return markdown;
+        }
+
+        self.depth += 1;
+        for child in &self.document[id].children {
+            self.inline(&mut markdown, *child);
+        }
+        self.depth -= 1;
+        markdown
+    }
+
+    fn inline(&mut self, markdown: &mut String, id: NodeId) {
+        let document = self.document;
+        let name = match &document[id].data {
+            NodeData::Text(text) => return self.text(markdown, text),
+            NodeData::Element { name, .. } => name.element(),
+            _ => return,
+        };
+
+        match self.kind(id) {
+            Kind::Hidden => return,
+            Kind::Unsupported => return markdown.push_str(&self.unsupported(id)),
+            Kind::Block | Kind::Inline => {}
+        }
+
+        use LocalName::*;
+        match name {
+            Strong | B => delimit(markdown, &self.inline_children(id), "**", "**"),
+            Em | I => delimit(markdown, &self.inline_children(id), "*", "*"),
+            Del | S | Strike => delimit(markdown, &self.inline_children(id), "~~", "~~"),
+            Code | Tt | Samp => {
+                let mut code = document.text_content(id).replace(['\n', '\r'], " ");
+
+                if code.is_empty() {
+                    return;
+                }
+
+                let fence = "`".repeat(longest_run(&code, '`') + 1);
+                let padding = if code.starts_with('`') || code.ends_with('`') {
+                    " "
+                } else {
+                    ""
+                };
+
+                // Table cells are split at pipes before code spans are read
+                if self.in_table {
+                    code = code.replace('|', "\\|");
+                }
+                markdown.push_str(&format!("{fence}{padding}{code}{padding}{fence}"));
+            }
+            Br if self.in_table => markdown.push_str("<br>"),
+            Br => {
+                while markdown.ends_with(' ') {
+                    markdown.pop();
+                }
+                markdown.push_str("\\\n");
+            }
+            Img => {
+                let Some(src) = document.attribute(id, "src").map(str::trim) else {
+                    return;
+                };
+                let mut alt = String::new();
+
+                self.text(&mut alt, document.attribute(id, "alt").unwrap_or(""));
+                markdown.push_str(&format!(
+                    "![{}]({}{})",
+                    alt.trim(),
+                    destination(src),
+                    title(document.attribute(id, "title"))
+                ));
+            }
+            A => {
+                let text = self.inline_children(id);
+                let Some(href) = document.attribute(id, "href").map(str::trim) else {
+                    return push_inline(markdown, &text);
+                };
+                let title = title(document.attribute(id, "title"));
+                let is_autolink = text.trim() == href
+                    && title.is_empty()
+                    && ["http://", "https://", "mailto:"]
+                        .iter()
+                        .any(|scheme| href.starts_with(scheme));
+
+                if is_autolink {
+                    delimit(markdown, &text, "<", ">");
+                } else {
+                    let end = format!("]({}{})", destination(href), title);
+
+                    delimit(markdown, &text, "[", &end);
+                }
+            }
+            _ => {
+                let children = self.inline_children(id);
+
+                // The content of a block in inline content is separated from what is around it
+                if self.kind(id) == Kind::Block && !markdown.ends_with([' ', '\n']) {
+                    markdown.push(' ');
+                }
+                push_inline(markdown, &children);
+            }
+        }
+    }
+
+    /**
+     * Writes the inline content as paragraphs, when it is not empty. Line breaks at the end of a
+     * paragraph are not rendered, and an empty line between them starts a new paragraph.
+     */
+    fn paragraph(&self, blocks: &mut Vec<Block>, inline: &mut String) {
+        let text = std::mem::take(inline);
+        let mut lines = Vec::new();
+
+        for line in text.lines().map(|line| line.trim_matches(' ')) {
+            if line.is_empty() || line == "\\" {
+                Self::push_paragraph(blocks, &mut lines);
+            } else {
+                lines.push(line);
+            }
+        }
+        Self::push_paragraph(blocks, &mut lines);
+    }
+
+    fn push_paragraph(blocks: &mut Vec<Block>, lines: &mut Vec<&str>) {
+        if let Some(last) = lines.last_mut() {
+            *last = last
+                .strip_suffix('\\')
+                .unwrap_or(last)
+                .trim_end_matches(' ');
+        } else {
+            return;
+        }
+        blocks.push(Block {
+            markdown: lines
+                .drain(..)
+                .map(escape_line_start)
+                .collect::<Vec<_>>()
+                .join("\n"),
+            is_list: false,
+        });
+    }
+
+    /** Converts the children of the node to blocks, inline content becomes paragraphs. */
+    fn blocks(&mut self, id: NodeId) -> Vec<Block> {
+        let mut blocks = Vec::new();
+        let mut inline = String::new();
+
+        if self.depth >= MAX_DEPTH {
+            for child in &self.document[id].children {
+                self.flat_text(&mut inline, *child);
+            }
+            self.paragraph(&mut blocks, &mut inline);
+            return blocks;
+        }
+
+        self.depth += 1;
+        for child in &self.document[id].children {
+            match self.kind(*child) {
+                Kind::Hidden => {}
+                Kind::Block => {
+                    self.paragraph(&mut blocks, &mut inline);
+                    self.block(&mut blocks, *child);
+                }
+                Kind::Unsupported if self.document.name(*child).is_some_and(is_block_elements) => {
+                    self.paragraph(&mut blocks, &mut inline);
+
+                    let html = self.unsupported(*child);
+
+                    if !html.is_empty() {
+                        blocks.push(Block {
+                            markdown: html,
+                            is_list: false,
+                        });
+                    }
+                }
+                Kind::Unsupported | Kind::Inline => self.inline(&mut inline, *child),
+            }
+        }
+        self.depth -= 1;
+        self.paragraph(&mut blocks, &mut inline);
+
+        blocks
+    }
+
+    fn block(&mut self, blocks: &mut Vec<Block>, id: NodeId) {
+        let document = self.document;
+        let name = document.name(id).unwrap().element();
+
+        use LocalName::*;
+        let markdown = match name {
+            H1 | H2 | H3 | H4 | H5 | H6 => {
+                let level = name.as_str()[1..].parse().unwrap_or(1);
+                let text = self.inline_children(id).replace("\\\n", " ");
+                let text = text.trim();
+
+                if text.is_empty() {
+                    return;
+                }
+                format!("{} {}", "#".repeat(level), text)
+            }
+            Hr => "---".to_string(),
+            Pre => self.code_block(id),
+            Blockquote => {
+                let content = join(&self.blocks(id), false);
+
+                if content.is_empty() {
+                    return;
+                }
+                content
+                    .lines()
+                    .map(|line| match line {
+                        "" => ">".to_string(),
+                        line => format!("> {}", line),
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            }
+            Ul | Ol | Menu => {
+                let list = self.list(id);
+
+                if list.is_empty() {
+                    return;
+                }
+                return blocks.push(Block {
+                    markdown: list,
+                    is_list: true,
+                });
+            }
+            Table => {
+                if let Some(caption) = document
+                    .element_children(id)
+                    .find(|child| document.is_element(*child, &Caption))
+                {
+                    blocks.extend(self.blocks(caption));
+                }
+
+                let table = self.table(id);
+
+                if table.is_empty() {
+                    return;
+                }
+                table
+            }
+            _ => return blocks.extend(self.blocks(id)),
+        };
+
+        blocks.push(Block {
+            markdown,
+            is_list: false,
+        });
+    }
+
+    fn code_block(&self, id: NodeId) -> String {
+        let document = self.document;
+        // `<pre><code class="language-rust">` is the common markup of a code block
+        let code = document
+            .element_children(id)
+            .next()
+            .filter(|code| {
+                document.is_element(*code, &LocalName::Code)
+                    && document[id].children.iter().all(|child| {
+                        child == code
+                            || matches!(&document[*child].data, NodeData::Text(text) if text.trim().is_empty())
+                    })
+            })
+            .unwrap_or(id);
+        let language = [code, id]
+            .iter()
+            .filter_map(|id| document.attribute(*id, "class"))
+            .flat_map(|class| class.split_ascii_whitespace())
+            .find_map(|class| {
+                class
+                    .strip_prefix("language-")
+                    .or_else(|| class.strip_prefix("lang-"))
+            })
+            .unwrap_or("");
+        let text = document.text_content(code);
+        // A newline at the start of `pre` is not rendered
+        let text = text.strip_prefix('\n').unwrap_or(&text);
+        let text = text.strip_suffix('\n').unwrap_or(text);
+        let fence = "`".repeat(longest_run(text, '`').max(2) + 1);
+
+        format!("{fence}{language}\n{text}\n{fence}")
+    }
+
+    fn list(&mut self, id: NodeId) -> String {
+        let document = self.document;
+        let is_ordered = document.is_element(id, &LocalName::Ol);
+        let mut number = document
+            .attribute(id, "start")
+            .and_then(|start| start.trim().parse::<i64>().ok())
+            .map_or(1, |start| start.clamp(0, MAX_LIST_NUMBER.into()) as u32);
+        let mut items: Vec<String> = Vec::new();
+
+        for child in document.element_children(id) {
+            if self.kind(child) == Kind::Hidden {
+                continue;
+            }
+
+            let blocks = if document.is_element(child, &LocalName::Li) {
+                self.blocks(child)
+            } else {
+                // A list in a list without an item, it belongs to the item before it
+                let mut blocks = Vec::new();
+
+                self.block(&mut blocks, child);
+                if let Some(item) = items.last_mut() {
+                    item.push('\n');
+                    item.push_str(&indent(&join(&blocks, true), 2));
+                    continue;
+                }
+                blocks
+            };
+            let marker = if is_ordered {
+                let marker = format!("{}.", number);
+
+                number = number.saturating_add(1).min(MAX_LIST_NUMBER);
+                marker
+            } else {
+                "-".to_string()
+            };
+            let content = indent(&join(&blocks, true), marker.len() + 1);
+
+            if content.is_empty() {
+                items.push(marker);
+            } else {
+                items.push(format!("{} {}", marker, content.trim_start()));
+            }
+        }
+
+        items.join("\n")
+    }
+
+    fn table(&mut self, id: NodeId) -> String {
+        let document = self.document;
+        let mut rows: Vec<NodeId> = Vec::new();
+
+        for child in document.element_children(id) {
+            match document.name(child).unwrap().element() {
+                LocalName::Tr => rows.push(child),
+                LocalName::Thead | LocalName::Tbody | LocalName::Tfoot => rows.extend(
+                    document
+                        .element_children(child)
+                        .filter(|row| document.is_element(*row, &LocalName::Tr)),
+                ),
+                _ => {}
+            }
+        }
+
+        let mut alignments = Vec::new();
+        let rows: Vec<Vec<String>> = rows
+            .iter()
+            .map(|row| {
+                let mut cells = Vec::new();
+
+                for cell in document.element_children(*row) {
+                    if !matches!(
+                        document.name(cell).unwrap().element(),
+                        LocalName::Td | LocalName::Th
+                    ) {
+                        continue;
+                    }
+
+                    self.in_table = true;
+                    let content = self.inline_children(cell);
+                    self.in_table = false;
+
+                    if cells.len() >= alignments.len() {
+                        alignments.push(document.attribute(cell, "align").unwrap_or(""));
+                    }
+                    cells.push(content.trim().to_string());
+
+                    // The cells that a cell spans are left empty
+                    let colspan: usize = document
+                        .attribute(cell, "colspan")
+                        .and_then(|colspan| colspan.trim().parse().ok())
+                        .unwrap_or(1);
+
+                    for _ in 1..colspan.min(1000) {
+                        cells.push(String::new());
+                    }
+                }
+                cells
+            })
+            .filter(|cells| !cells.is_empty())
+            .collect();
+        let columns = rows.iter().map(Vec::len).max().unwrap_or(0);
+
+        if columns == 0 {
+            return String::new();
+        }
+
+        let line = |cells: &[String]| {
+            let mut line = String::from("|");
+
+            for column in 0..columns {
+                line.push(' ');
+                line.push_str(cells.get(column).map_or("", String::as_str));
+                line.push_str(" |");
+            }
+            line
+        };
+        let delimiters: Vec<String> = (0..columns)
+            .map(|column| {
+                let align = alignments.get(column).copied().unwrap_or("");
+
+                match align.to_ascii_lowercase().as_str() {
+                    "left" => ":---",
+                    "center" => ":---:",
+                    "right" => "---:",
+                    _ => "---",
+                }
+                .to_string()
+            })
+            .collect();
+        // The first row is the header, GFM tables always have one
+        let mut table = vec![line(&rows[0]), line(&delimiters)];
+
+        table.extend(rows[1..].iter().map(|row| line(row)));
+        table.join("\n")
+    }
+}
+
+/**
+ * Separates the blocks with blank lines. In a list item, a list after a one line paragraph is only
+ * on the next line, so the list stays tight.
+ */
+fn join(blocks: &[Block], is_list_item: bool) -> String {
+    let mut markdown = String::new();
+
+    for (index, block) in blocks.iter().enumerate() {
+        if index > 0 {
+            let is_tight = is_list_item
+                && block.is_list
+                && !blocks[index - 1].is_list
+                && blocks[index - 1].markdown.lines().count() == 1;
+
+            markdown.push_str(if is_tight { "\n" } else { "\n\n" });
+        }
+        markdown.push_str(&block.markdown);
+    }
+    markdown
+}
+
+/** Indents the lines after the first one, for the content of a list item. */
+fn indent(markdown: &str, width: usize) -> String {
+    let indentation = " ".repeat(width);
+
+    markdown
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
+            if index == 0 || line.is_empty() {
+                line.to_string()
+            } else {
+                format!("{}{}", indentation, line)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+impl Document {
+    /**
+     * Converts the node and its descendants to CommonMark, or to GitHub Flavored Markdown. Text is
+     * escaped where it would be read as Markdown syntax.
+     */
+    pub fn to_markdown(&self, id: NodeId, options: &MarkdownOptions) -> String {
+        let mut converter = Converter {
+            document: self,
+            unsupported_elements: options.unsupported_elements.unwrap_or_default(),
+            gfm: options.gfm.unwrap_or(true),
+            in_table: false,
+            depth: 0,
+        };
+        let blocks = if id == self.root() {
+            converter.blocks(id)
+        } else {
+            let mut blocks = Vec::new();
+
+            match converter.kind(id) {
+                Kind::Block => converter.block(&mut blocks, id),
+                _ => {
+                    let mut inline = String::new();
+
+                    converter.inline(&mut inline, id);
+                    converter.paragraph(&mut blocks, &mut inline);
+                }
+            }
+            blocks
+        };
+        let mut markdown = join(&blocks, false);
+
+        if !markdown.is_empty() {
+            markdown.push('\n');
+        }
+        markdown
+    }
+}
+
+/** Parses the document and converts it to Markdown, see `Document::to_markdown`. */
+pub fn html_to_markdown(html: &str, options: &MarkdownOptions) -> String {
+    let parser_options = ParserOptions {
+        tokenizer_options: TokenizerOptions {
+            ignore_whitespace_between_tags: Some(false),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let document = Document::parse(html, &parser_options);
+
+    document.to_markdown(document.root(), options)
+}
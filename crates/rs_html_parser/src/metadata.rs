@@ -0,0 +1,321 @@
+use crate::element_info::is_metadata_elements;
+use crate::whitespace::{collapse_and_trim_whitespace, is_html_whitespace};
+use crate::{Parser, ParserOptions};
+use rs_html_parser_tokens::{LocalName, Token, TokenKind};
+
+/** The `og:` properties of the Open Graph protocol. */
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OpenGraph {
+    pub title: Option<String>,
+    /** `og:type`, eg. `article` or `website`. */
+    pub kind: Option<String>,
+    pub url: Option<String>,
+    pub description: Option<String>,
+    pub site_name: Option<String>,
+    pub locale: Option<String>,
+    /** `og:image` and `og:image:url`, in document order. */
+    pub images: Vec<String>,
+    /** `og:video` and `og:video:url`, in document order. */
+    pub videos: Vec<String>,
+}
+
+/** The `twitter:` fields of a Twitter card, in `name` or `property` attributes. */
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TwitterCard {
+    /** `twitter:card`, eg. `summary_large_image`. */
+    pub card: Option<String>,
+    /** `twitter:site`, the account of the website. */
+    pub site: Option<String>,
+    /** `twitter:creator`, the account of the author. */
+    pub creator: Option<String>,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    /** `twitter:image` or `twitter:image:src`. */
+    pub image: Option<String>,
+    pub image_alt: Option<String>,
+}
+
+/** The directives of `<meta name=robots>` in ASCII lowercase, eg. `noindex` or `max-snippet:5`. */
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Robots {
+    pub directives: Vec<String>,
+}
+
+/** A `<link rel=alternate>`, eg. a translation with `hreflang` or a feed with `type`. */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AlternateLink {
+    pub href: String,
+    pub hreflang: Option<String>,
+    /** The `type`, eg. `application/rss+xml`. */
+    pub media_type: Option<String>,
+    pub title: Option<String>,
+}
+
+/** A `link` with an icon, eg. `rel=icon`, `rel="shortcut icon"` or `rel=apple-touch-icon`. */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Icon {
+    pub href: String,
+    /** The `rel` keywords, in ASCII lowercase. */
+    pub rel: Vec<String>,
+    pub sizes: Option<String>,
+    /** The `type`, eg. `image/png`. */
+    pub media_type: Option<String>,
+}
+
+/**
+ * The metadata in the `head` of a document. The URLs are as they are written, relative URLs are
+ * relative to `base` when there is one. Only the first of the values that can't be repeated is
+ * kept.
+ */
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Metadata {
+    /** The text of the `title`, with whitespace collapsed. */
+    pub title: Option<String>,
+    /** The `href` of the first `base`. */
+    pub base: Option<String>,
+    /** The `charset` of a `meta`, or from the `content` of `<meta http-equiv=content-type>`. */
+    pub charset: Option<String>,
+    /** The `content` of `<meta name=viewport>`. */
+    pub viewport: Option<String>,
+    /** The `content` of `<meta name=description>`. */
+    pub description: Option<String>,
+    pub open_graph: OpenGraph,
+    pub twitter: TwitterCard,
+    pub robots: Robots,
+    /** The `href` of `<link rel=canonical>`. */
+    pub canonical: Option<String>,
+    pub alternates: Vec<AlternateLink>,
+    pub icons: Vec<Icon>,
+    /** The `href` of `<link rel=manifest>`. */
+    pub manifest: Option<String>,
+    /** The `name` and `content` of all `meta` elements, with the name in ASCII lowercase. */
+    pub names: Vec<(String, String)>,
+    /** The `property` and `content` of all `meta` elements, eg. the Open Graph properties. */
+    pub properties: Vec<(String, String)>,
+    /** The `http-equiv` and `content` of all `meta` elements, with the name in ASCII lowercase. */
+    pub http_equiv: Vec<(String, String)>,
+}
+
+fn set_once(field: &mut Option<String>, value: &str) {
+    if field.is_none() {
+        *field = Some(value.to_string());
+    }
+}
+
+/** The `charset` parameter of a content type, eg. `text/html; charset=utf-8`. */
+fn content_type_charset(content_type: &str) -> Option<&str> {
+    content_type.split(';').skip(1).find_map(|parameter| {
+        let (name, value) = parameter.split_once('=')?;
+
+        name.trim()
+            .eq_ignore_ascii_case("charset")
+            .then(|| value.trim().trim_matches(['"', '\'']))
+            .filter(|charset| !charset.is_empty())
+    })
+}
+
+impl Robots {
+    /** Whether the page may be indexed, without `noindex` or `none`. */
+    pub fn index(&self) -> bool {
+        !self.has_directive("noindex") && !self.has_directive("none")
+    }
+
+    /** Whether the links of the page may be followed, without `nofollow` or `none`. */
+    pub fn follow(&self) -> bool {
+        !self.has_directive("nofollow") && !self.has_directive("none")
+    }
+
+    fn has_directive(&self, directive: &str) -> bool {
+        self.directives.iter().any(|d| d == directive)
+    }
+}
+
+impl Metadata {
+    /**
+     * Reads the metadata from the tokens of a document, eg. of a `Parser`. The tokens are read up
+     * to the start of the body content: a `body` start tag, or an element or text that can't be in
+     * the `head`. The rest of the tokens are not read, so the document is not parsed further.
+     */
+    pub fn from_tokens<I: IntoIterator<Item = Token>>(tokens: I) -> Metadata {
+        let mut metadata = Metadata::default();
+        // The element with text content that is open, eg. `title` or `script`
+        let mut text_element: Option<LocalName> = None;
+        let mut title: Option<String> = None;
+        // The depth of `template` and `noscript` elements, their content is not read
+        let mut ignored_depth = 0;
+
+        for token in tokens {
+            let name = token.name().map(|name| name.element());
+            let is_ignored = matches!(name, Some(LocalName::Template | LocalName::Noscript));
+
+            match token.kind {
+                TokenKind::OpenTag if is_ignored => ignored_depth += 1,
+                TokenKind::CloseTag if is_ignored && ignored_depth > 0 => ignored_depth -= 1,
+                _ if ignored_depth > 0 => {}
+                TokenKind::OpenTag => match name {
+                    Some(LocalName::Html | LocalName::Head) => {}
+                    Some(LocalName::Title) if title.is_none() => {
+                        title = Some(String::new());
+                        text_element = name;
+                    }
+                    Some(LocalName::Meta) => metadata.read_meta(&token),
+                    Some(LocalName::Link) => metadata.read_link(&token),
+                    Some(LocalName::Base) => {
+                        if let Some(href) = token.attribute("href") {
+                            set_once(&mut metadata.base, &href);
+                        }
+                    }
+                    Some(name) if is_metadata_elements(&name) => text_element = Some(name),
+                    _ => break,
+                },
+                TokenKind::CloseTag if name == text_element => {
+                    if text_element == Some(LocalName::Title) && metadata.title.is_none() {
+                        metadata.title = title
+                            .as_deref()
+                            .map(|title| collapse_and_trim_whitespace(title).into_owned());
+                    }
+                    text_element = None;
+                }
+                TokenKind::Text => match &text_element {
+                    // Only the first title is read
+                    Some(LocalName::Title) if metadata.title.is_none() => {
+                        if let Some(title) = &mut title {
+                            title.push_str(&token.data);
+                        }
+                    }
+                    Some(_) => {}
+                    // Whitespace, and a byte order mark at the start of the input, are not content
+                    None if token
+                        .data
+                        .trim_start_matches('\u{FEFF}')
+                        .chars()
+                        .all(is_html_whitespace) => {}
+                    None => break,
+                },
+                _ => {}
+            }
+        }
+
+        // A title that is cut off by the end of the input
+        if metadata.title.is_none() {
+            metadata.title = title
+                .as_deref()
+                .map(|title| collapse_and_trim_whitespace(title).into_owned());
+        }
+        metadata
+    }
+
+    fn read_meta(&mut self, token: &Token) {
+        if let Some(charset) = token.attribute("charset") {
+            set_once(&mut self.charset, charset.trim());
+        }
+
+        let content = token.attribute("content").unwrap_or_default();
+
+        if let Some(http_equiv) = token.attribute("http-equiv") {
+            let http_equiv = http_equiv.trim().to_ascii_lowercase();
+
+            if http_equiv == "content-type" {
+                if let Some(charset) = content_type_charset(&content) {
+                    set_once(&mut self.charset, charset);
+                }
+            }
+            self.http_equiv.push((http_equiv, content.to_string()));
+        }
+        if let Some(name) = token.attribute("name") {
+            let name = name.trim().to_ascii_lowercase();
+
+            match name.as_str() {
+                "viewport" => set_once(&mut self.viewport, &content),
+                "description" => set_once(&mut self.description, &content),
+                "robots" => self.robots.directives.extend(
+                    content
+                        .split(',')
+                        .map(|directive| directive.trim().to_ascii_lowercase())
+                        .filter(|directive| !directive.is_empty()),
+                ),
+                _ => {}
+            }
+            self.read_social(&name, &content);
+            self.names.push((name, content.to_string()));
+        }
+        if let Some(property) = token.attribute("property") {
+            let property = property.trim();
+
+            self.read_social(&property.to_ascii_lowercase(), &content);
+            self.properties
+                .push((property.to_string(), content.to_string()));
+        }
+    }
+
+    /** Reads an Open Graph or a Twitter card field, they are in `name` or `property` attributes. */
+    fn read_social(&mut self, key: &str, content: &str) {
+        let open_graph = &mut self.open_graph;
+        let twitter = &mut self.twitter;
+
+        match key {
+            "og:title" => set_once(&mut open_graph.title, content),
+            "og:type" => set_once(&mut open_graph.kind, content),
+            "og:url" => set_once(&mut open_graph.url, content),
+            "og:description" => set_once(&mut open_graph.description, content),
+            "og:site_name" => set_once(&mut open_graph.site_name, content),
+            "og:locale" => set_once(&mut open_graph.locale, content),
+            "og:image" | "og:image:url" => open_graph.images.push(content.to_string()),
+            "og:video" | "og:video:url" => open_graph.videos.push(content.to_string()),
+            "twitter:card" => set_once(&mut twitter.card, content),
+            "twitter:site" => set_once(&mut twitter.site, content),
+            "twitter:creator" => set_once(&mut twitter.creator, content),
+            "twitter:title" => set_once(&mut twitter.title, content),
+            "twitter:description" => set_once(&mut twitter.description, content),
+            "twitter:image" | "twitter:image:src" => set_once(&mut twitter.image, content),
+            "twitter:image:alt" => set_once(&mut twitter.image_alt, content),
+            _ => {}
+        }
+    }
+
+    fn read_link(&mut self, token: &Token) {
+        let Some(href) = token.attribute("href") else {
+            return;
+        };
+        let rel: Vec<String> = token
+            .attribute("rel")
+            .unwrap_or_default()
+            .split(is_html_whitespace)
+            .filter(|keyword| !keyword.is_empty())
+            .map(str::to_ascii_lowercase)
+            .collect();
+        let optional = |name: &str| token.attribute(name).map(|value| value.to_string());
+
+        for keyword in &rel {
+            match keyword.as_str() {
+                "canonical" => set_once(&mut self.canonical, &href),
+                "manifest" => set_once(&mut self.manifest, &href),
+                "alternate" => self.alternates.push(AlternateLink {
+                    href: href.to_string(),
+                    hreflang: optional("hreflang"),
+                    media_type: optional("type"),
+                    title: optional("title"),
+                }),
+                _ => {}
+            }
+        }
+        if rel.iter().any(|keyword| {
+            matches!(
+                keyword.as_str(),
+                "icon" | "apple-touch-icon" | "apple-touch-icon-precomposed" | "mask-icon"
+            )
+        }) {
+            self.icons.push(Icon {
+                href: href.to_string(),
+                sizes: optional("sizes"),
+                media_type: optional("type"),
+                rel,
+            });
+        }
+    }
+}
+
+/** The metadata of the document, see `Metadata::from_tokens`. */
+pub fn extract_metadata(html: &str) -> Metadata {
+    Metadata::from_tokens(Parser::new(html, &ParserOptions::default()))
+}
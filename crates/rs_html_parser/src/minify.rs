@@ -0,0 +1,552 @@
+use crate::element_info::{
+    has_optional_end_tag, is_block_elements, is_foreign_context_elements,
+    is_html_integration_elements, is_metadata_elements, is_preformatted_elements, is_void_elements,
+    open_implies_close,
+};
+use crate::format::{source_attributes, Attribute};
+use crate::tree::ProcessingInstruction;
+use crate::whitespace::is_html_whitespace;
+use crate::{Parser, ParserOptions};
+use rs_html_parser_tokenizer::TokenizerOptions;
+use rs_html_parser_tokenizer_tokens::QuoteType;
+use rs_html_parser_tokens::{LocalName, Token, TokenKind};
+
+#[derive(Default)]
+pub struct MinifyOptions {
+    /**
+     * Collapses whitespace in text to one space, and removes it next to block-level elements and
+     * in the document head. The content of `pre`, `textarea` and raw text elements is kept.
+     *
+     * @default true
+     */
+    pub collapse_whitespace: Option<bool>,
+
+    /**
+     * Removes comments, except conditional comments like `<!--[if IE]>`.
+     *
+     * @default true
+     */
+    pub remove_comments: Option<bool>,
+
+    /**
+     * Omits the end tags that the spec allows to omit, eg. `</li>` before another `<li>`, when the
+     * parser closes the element at the same place without them.
+     *
+     * @default true
+     */
+    pub remove_optional_tags: Option<bool>,
+
+    /**
+     * Writes attribute values without quotes where the spec allows it.
+     *
+     * @default true
+     */
+    pub remove_attribute_quotes: Option<bool>,
+
+    /**
+     * Writes boolean attributes like `disabled="disabled"` without their value.
+     *
+     * @default true
+     */
+    pub collapse_boolean_attributes: Option<bool>,
+}
+
+struct Element {
+    name: LocalName,
+    parent: Option<usize>,
+    attributes: Vec<Attribute>,
+    is_foreign: bool,
+    /** Whether the element or an ancestor keeps its whitespace or is raw text. */
+    is_preformatted: bool,
+    is_empty: bool,
+}
+
+enum Item {
+    Open(usize),
+    Close {
+        element: usize,
+        is_implied: bool,
+    },
+    Text {
+        text: String,
+        parent: Option<usize>,
+    },
+    /** Comments, CDATA sections and processing instructions, written as they are. */
+    Markup(String),
+}
+
+/** What comes after an end tag in the output, to decide whether it can be omitted. */
+#[derive(Copy, Clone)]
+enum Next {
+    Open(usize),
+    Close(usize),
+    /** Text, a comment or other markup. */
+    Content,
+    End,
+}
+
+const BOOLEAN_ATTRIBUTES: [&str; 25] = [
+    "allowfullscreen",
+    "async",
+    "autofocus",
+    "autoplay",
+    "checked",
+    "controls",
+    "default",
+    "defer",
+    "disabled",
+    "formnovalidate",
+    "inert",
+    "ismap",
+    "itemscope",
+    "loop",
+    "multiple",
+    "muted",
+    "nomodule",
+    "novalidate",
+    "open",
+    "playsinline",
+    "readonly",
+    "required",
+    "reversed",
+    "selected",
+    "shadowrootdelegatesfocus",
+];
+
+/** Comments that old versions of Internet Explorer read, eg. `<!--[if IE]>` and `<![endif]-->`. */
+fn is_conditional_comment(data: &str) -> bool {
+    data.starts_with("[if ") || data.ends_with("[endif]")
+}
+
+/** Unquoted attribute values can't be empty or contain these, and a `/` at the end is risky. */
+fn can_be_unquoted(value: &str) -> bool {
+    !value.is_empty()
+        && !value.ends_with('/')
+        && !value
+            .contains(|c| is_html_whitespace(c) || matches!(c, '"' | '\'' | '=' | '<' | '>' | '`'))
+}
+
+struct Minifier {
+    elements: Vec<Element>,
+    items: Vec<Item>,
+    collapse_whitespace: bool,
+    remove_optional_tags: bool,
+    remove_attribute_quotes: bool,
+    collapse_boolean_attributes: bool,
+}
+
+impl Minifier {
+    fn new(html: &str, options: &MinifyOptions) -> Minifier {
+        // Text and attribute values are kept as they are written, entities included
+        let parser_options = ParserOptions {
+            lazy_attributes: true,
+            tokenizer_options: TokenizerOptions {
+                decode_entities: Some(false),
+                ignore_whitespace_between_tags: Some(false),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let tokens: Vec<Token> = Parser::new(html, &parser_options).collect();
+        let remove_comments = options.remove_comments.unwrap_or(true);
+        let mut minifier = Minifier {
+            elements: Vec::new(),
+            items: Vec::new(),
+            collapse_whitespace: options.collapse_whitespace.unwrap_or(true),
+            remove_optional_tags: options.remove_optional_tags.unwrap_or(true),
+            remove_attribute_quotes: options.remove_attribute_quotes.unwrap_or(true),
+            collapse_boolean_attributes: options.collapse_boolean_attributes.unwrap_or(true),
+        };
+        let mut stack: Vec<usize> = Vec::new();
+
+        for token in tokens {
+            let parent = stack.last().copied();
+
+            match token.kind {
+                TokenKind::OpenTag => {
+                    let name = token.name().unwrap();
+                    let (parent_foreign, parent_preformatted) = match parent {
+                        Some(parent) => {
+                            let parent = &mut minifier.elements[parent];
+
+                            parent.is_empty = false;
+                            (
+                                parent.is_foreign && !is_html_integration_elements(&parent.name),
+                                parent.is_preformatted,
+                            )
+                        }
+                        None => (false, false),
+                    };
+                    let is_foreign = parent_foreign || is_foreign_context_elements(&name);
+
+                    stack.push(minifier.elements.len());
+                    minifier.items.push(Item::Open(minifier.elements.len()));
+                    minifier.elements.push(Element {
+                        is_preformatted: parent_preformatted
+                            || (!is_foreign && is_preformatted_elements(&name)),
+                        name,
+                        parent,
+                        attributes: source_attributes(token.raw_attrs.as_ref()),
+                        is_foreign,
+                        is_empty: true,
+                    });
+                }
+                TokenKind::CloseTag => {
+                    if let Some(element) = stack.pop() {
+                        minifier.items.push(Item::Close {
+                            element,
+                            is_implied: token.is_implied,
+                        });
+                    }
+                }
+                TokenKind::Text => {
+                    if let Some(parent) = parent {
+                        minifier.elements[parent].is_empty = false;
+                    }
+                    // Text that was split by a removed comment is joined again
+                    match minifier.items.last_mut() {
+                        Some(Item::Text { text, .. }) => text.push_str(&token.data),
+                        _ => minifier.items.push(Item::Text {
+                            text: token.data.to_string(),
+                            parent,
+                        }),
+                    }
+                }
+                TokenKind::Comment => {
+                    // CDATA sections are emitted as comments, they are content that must be kept
+                    if !token.is_cdata && remove_comments && !is_conditional_comment(&token.data) {
+                        continue;
+                    }
+                    if let Some(parent) = parent {
+                        minifier.elements[parent].is_empty = false;
+                    }
+                    minifier.items.push(Item::Markup(if token.is_cdata {
+                        format!("<![CDATA[{}]]>", &*token.data)
+                    } else {
+                        format!("<!--{}-->", &*token.data)
+                    }));
+                }
+                TokenKind::ProcessingInstruction => {
+                    if let Some(parent) = parent {
+                        minifier.elements[parent].is_empty = false;
+                    }
+                    minifier
+                        .items
+                        .push(Item::Markup(ProcessingInstruction(&token.data).to_string()));
+                }
+                TokenKind::CommentEnd => {}
+            }
+        }
+
+        minifier
+    }
+
+    /** Whitespace at the start and the end of block-level elements is not rendered. */
+    fn is_block(&self, element: usize) -> bool {
+        let element = &self.elements[element];
+
+        !element.is_foreign
+            && ((is_block_elements(&element.name) && !is_metadata_elements(&element.name))
+                || matches!(element.name.element(), LocalName::Br))
+    }
+
+    fn is_block_item(&self, item: Option<&Item>) -> bool {
+        match item {
+            Some(Item::Open(element) | Item::Close { element, .. }) => self.is_block(*element),
+            Some(_) => false,
+            None => true,
+        }
+    }
+
+    fn collapse_whitespace(&mut self) {
+        // Whitespace after a block boundary, or after other whitespace, is not rendered
+        let mut trim_start = true;
+
+        for index in 0..self.items.len() {
+            let (text, parent) = match &self.items[index] {
+                Item::Open(element) | Item::Close { element, .. } => {
+                    trim_start = self.is_block(*element);
+                    continue;
+                }
+                Item::Markup(_) => continue,
+                Item::Text { text, parent } => (text, *parent),
+            };
+
+            if parent.is_some_and(|parent| self.elements[parent].is_preformatted) {
+                trim_start = false;
+                continue;
+            }
+
+            let is_unrendered = parent.is_none_or(|parent| {
+                matches!(
+                    self.elements[parent].name.element(),
+                    LocalName::Html | LocalName::Head
+                )
+            });
+            let mut collapsed = String::with_capacity(text.len());
+
+            for c in text.chars() {
+                if !is_html_whitespace(c) {
+                    collapsed.push(c);
+                } else if !(collapsed.ends_with(' ') || (trim_start && collapsed.is_empty())) {
+                    collapsed.push(' ');
+                }
+            }
+            if collapsed.ends_with(' ') && self.is_block_item(self.items.get(index + 1)) {
+                collapsed.pop();
+            }
+            if is_unrendered && collapsed == " " {
+                collapsed.clear();
+            }
+            if !collapsed.is_empty() {
+                trim_start = collapsed.ends_with(' ');
+            }
+
+            self.items[index] = Item::Text {
+                text: collapsed,
+                parent,
+            };
+        }
+    }
+
+    /** Whether the end tag can be omitted as the spec allows, before the next item. */
+    fn is_end_tag_optional(&self, element: usize, next: Next) -> bool {
+        let parent = self.elements[element].parent;
+        let sibling = match next {
+            Next::Open(next) if self.elements[next].parent == parent => {
+                Some(self.elements[next].name.element())
+            }
+            _ => None,
+        };
+        let has_no_more_content = match next {
+            Next::Open(_) => sibling.is_none(),
+            Next::Close(_) | Next::End => true,
+            Next::Content => false,
+        };
+
+        use LocalName::*;
+        match self.elements[element].name.element() {
+            Html | Head | Body | Colgroup | Caption => !matches!(next, Next::Content),
+            Li => matches!(sibling, Some(Li)) || has_no_more_content,
+            Dt => matches!(sibling, Some(Dt | Dd)),
+            Dd => matches!(sibling, Some(Dt | Dd)) || has_no_more_content,
+            P => {
+                let is_in_transparent = parent.is_some_and(|parent| {
+                    matches!(
+                        self.elements[parent].name.element(),
+                        A | Audio | Del | Ins | Map | Noscript | Video
+                    )
+                });
+
+                matches!(
+                    sibling,
+                    Some(
+                        Address
+                            | Article
+                            | Aside
+                            | Blockquote
+                            | Details
+                            | Dialog
+                            | Div
+                            | Dl
+                            | Fieldset
+                            | Figcaption
+                            | Figure
+                            | Footer
+                            | Form
+                            | H1
+                            | H2
+                            | H3
+                            | H4
+                            | H5
+                            | H6
+                            | Header
+                            | Hgroup
+                            | Hr
+                            | Main
+                            | Menu
+                            | Nav
+                            | Ol
+                            | P
+                            | Pre
+                            | Search
+                            | Section
+                            | Table
+                            | Ul
+                    )
+                ) || (has_no_more_content && !is_in_transparent)
+            }
+            Rt | Rp => matches!(sibling, Some(Rt | Rp)) || has_no_more_content,
+            Optgroup => matches!(sibling, Some(Optgroup | Hr)) || has_no_more_content,
+            Option => matches!(sibling, Some(Option | Optgroup | Hr)) || has_no_more_content,
+            Thead => matches!(sibling, Some(Tbody | Tfoot)),
+            Tbody => matches!(sibling, Some(Tbody | Tfoot)) || has_no_more_content,
+            Tfoot => has_no_more_content,
+            Tr => matches!(sibling, Some(Tr)) || has_no_more_content,
+            Td | Th => matches!(sibling, Some(Td | Th)) || has_no_more_content,
+            _ => false,
+        }
+    }
+
+    /** Whether the parser closes the element before the next item without its end tag. */
+    fn is_closed_by(&self, element: usize, next: Next) -> bool {
+        match next {
+            Next::Open(next) => open_implies_close(&self.elements[next].name)
+                .is_some_and(|implies_close| implies_close(&self.elements[element].name)),
+            // The end tag closes the innermost open element with its name
+            Next::Close(ancestor) => {
+                let name = self.elements[ancestor].name.element();
+                let mut current = Some(element);
+
+                while let Some(open) = current {
+                    if self.elements[open].name.element() == name {
+                        return open == ancestor;
+                    }
+                    current = self.elements[open].parent;
+                }
+                false
+            }
+            Next::Content => false,
+            Next::End => true,
+        }
+    }
+
+    fn is_self_closing(&self, element: usize) -> bool {
+        let element = &self.elements[element];
+
+        element.is_foreign && element.is_empty && !is_void_elements(&element.name)
+    }
+
+    /** Decides which end tags are written, from the last to the first. */
+    fn end_tags(&self) -> Vec<bool> {
+        let mut is_written = vec![false; self.items.len()];
+        let mut next = Next::End;
+
+        for (index, item) in self.items.iter().enumerate().rev() {
+            next = match item {
+                Item::Open(element) => Next::Open(*element),
+                Item::Close {
+                    element,
+                    is_implied,
+                } => {
+                    let name = &self.elements[*element].name;
+
+                    if (is_void_elements(name) && !self.elements[*element].is_foreign)
+                        || self.is_self_closing(*element)
+                    {
+                        continue;
+                    }
+
+                    let is_omitted = !self.elements[*element].is_foreign
+                        && self.is_closed_by(*element, next)
+                        && (*is_implied
+                            || (self.remove_optional_tags
+                                && has_optional_end_tag(name)
+                                && self.is_end_tag_optional(*element, next)));
+
+                    if is_omitted {
+                        continue;
+                    }
+                    is_written[index] = true;
+                    Next::Close(*element)
+                }
+                Item::Text { text, .. } if text.is_empty() => continue,
+                Item::Text { .. } | Item::Markup(_) => Next::Content,
+            };
+        }
+
+        is_written
+    }
+
+    fn write_attribute(&self, html: &mut String, element: &Element, attribute: &Attribute) {
+        html.push(' ');
+        html.push_str(&attribute.name);
+
+        let Some((value, quote_type)) = &attribute.value else {
+            return;
+        };
+
+        if self.collapse_boolean_attributes
+            && !element.is_foreign
+            && (value.is_empty() || value.eq_ignore_ascii_case(&attribute.name))
+            && BOOLEAN_ATTRIBUTES
+                .iter()
+                .any(|name| name.eq_ignore_ascii_case(&attribute.name))
+        {
+            return;
+        }
+
+        html.push('=');
+        if self.remove_attribute_quotes && can_be_unquoted(value) {
+            html.push_str(value);
+            return;
+        }
+
+        // A value in single quotes can contain double quotes, and the other way around
+        let quote = match quote_type {
+            QuoteType::Single if !value.contains('"') => '"',
+            QuoteType::Single => '\'',
+            _ if value.contains('"') && !value.contains('\'') => '\'',
+            _ => '"',
+        };
+
+        html.push(quote);
+        if quote == '"' {
+            html.push_str(&value.replace('"', "&quot;"));
+        } else {
+            html.push_str(value);
+        }
+        html.push(quote);
+    }
+
+    fn write(mut self) -> String {
+        if self.collapse_whitespace {
+            self.collapse_whitespace();
+        }
+
+        let end_tags = self.end_tags();
+        let mut html = String::new();
+
+        for (index, item) in self.items.iter().enumerate() {
+            match item {
+                Item::Open(id) => {
+                    let element = &self.elements[*id];
+
+                    html.push('<');
+                    html.push_str(element.name.as_str());
+                    for attribute in &element.attributes {
+                        self.write_attribute(&mut html, element, attribute);
+                    }
+                    if self.is_self_closing(*id) {
+                        // An unquoted value would take the slash
+                        if !element.attributes.is_empty() && !html.ends_with(['"', '\'']) {
+                            html.push(' ');
+                        }
+                        html.push('/');
+                    }
+                    html.push('>');
+                }
+                Item::Close { element, .. } => {
+                    if end_tags[index] {
+                        html.push_str("</");
+                        html.push_str(self.elements[*element].name.as_str());
+                        html.push('>');
+                    }
+                }
+                Item::Text { text, .. } => html.push_str(text),
+                Item::Markup(markup) => html.push_str(markup),
+            }
+        }
+
+        html
+    }
+}
+
+/**
+ * Minifies HTML for serving: removes whitespace, comments, end tags and attribute quotes that
+ * don't change how the document is parsed and rendered. The output parses to the same elements,
+ * attributes and text, except for the collapsed whitespace.
+ */
+pub fn minify(html: &str, options: &MinifyOptions) -> String {
+    Minifier::new(html, options).write()
+}
@@ -0,0 +1,460 @@
+use crate::{html_content_model, new_tokenizer, Parser, ParserOptions};
+use memchr::{memchr, memchr2, memmem};
+use rayon::prelude::*;
+use rs_html_parser_tokenizer::{ContentModel, TagBoundary, Tokenizer};
+use rs_html_parser_tokenizer_tokens::{TokenizerToken, TokenizerTokenLocation};
+use rs_html_parser_tokens::LocalName;
+use std::str;
+use std::vec::IntoIter;
+
+/** The least size of a chunk of `Parser::new_parallel`, smaller documents are not split. */
+pub const DEFAULT_CHUNK_SIZE: usize = 256 * 1024;
+
+const CDATA_START: &[u8] = b"<![CDATA[";
+
+/** The elements whose contents are text up to their end tag, outside of foreign content. */
+const RAW_TEXT_ELEMENTS: [&[u8]; 8] = [
+    b"script",
+    b"style",
+    b"title",
+    b"textarea",
+    b"xmp",
+    b"iframe",
+    b"noembed",
+    b"noframes",
+];
+
+fn find(buffer: &[u8], from: usize, needle: &[u8]) -> Option<usize> {
+    memmem::find(&buffer[from..], needle).map(|offset| from + offset)
+}
+
+/** The end of the start tag at `start`, after its `>`. Quoted attribute values are skipped. */
+fn start_tag_end(buffer: &[u8], start: usize) -> Option<usize> {
+    let mut index = start + 1;
+
+    while index < buffer.len() {
+        match buffer[index] {
+            b'>' => return Some(index + 1),
+            b'=' => {
+                index += 1;
+                while buffer.get(index).is_some_and(u8::is_ascii_whitespace) {
+                    index += 1;
+                }
+
+                match buffer.get(index) {
+                    Some(&quote @ (b'"' | b'\'')) => index = find(buffer, index + 1, &[quote])?,
+                    _ => continue,
+                }
+            }
+            _ => {}
+        }
+
+        index += 1;
+    }
+
+    None
+}
+
+/** The start of the end tag of the raw text element `name`, eg. `</script>`. */
+fn raw_text_end(buffer: &[u8], from: usize, name: &[u8]) -> Option<usize> {
+    let mut index = from;
+
+    loop {
+        let start = find(buffer, index, b"</")?;
+        let name_end = start + 2 + name.len();
+
+        if buffer
+            .get(start + 2..name_end)
+            .is_some_and(|tag_name| tag_name.eq_ignore_ascii_case(name))
+            && buffer
+                .get(name_end)
+                .is_some_and(|&c| c == b'>' || c.is_ascii_whitespace())
+        {
+            return Some(start);
+        }
+
+        index = start + 2;
+    }
+}
+
+/**
+ * Finds boundaries about `chunk_size` bytes apart with a quick scan of the markup. Comments,
+ * declarations, processing instructions, quoted attribute values and the contents of raw text
+ * elements (eg. `<script>`) are skipped, so the boundaries are at tags in text. The scan does not
+ * know about foreign content and is not exact, the boundaries are checked when the chunks are
+ * stitched together.
+ *
+ * The text before a tag decides whether whitespace-only text after it is dropped. Tags after
+ * whitespace-only text or references can't be told apart without tokenizing, so they are not
+ * used.
+ */
+fn find_boundaries(buffer: &[u8], options: &ParserOptions, chunk_size: usize) -> Vec<TagBoundary> {
+    let tokenizer_options = &options.tokenizer_options;
+    let ignores_whitespace = options.whitespace.is_none()
+        && tokenizer_options
+            .ignore_whitespace_between_tags
+            .unwrap_or(true);
+    let decodes_entities = tokenizer_options.decode_entities.unwrap_or(true);
+    let follows_text = |text: &[u8]| {
+        if text.is_empty() || !ignores_whitespace {
+            Some(false)
+        } else if !decodes_entities
+            || text.iter().all(u8::is_ascii_whitespace)
+            || memchr2(b'<', b'&', text).is_some()
+        {
+            None
+        } else {
+            Some(true)
+        }
+    };
+
+    let mut boundaries = Vec::new();
+    // The tags of XML mode are not scanned
+    if tokenizer_options.xml_mode.unwrap_or(false) {
+        return boundaries;
+    }
+
+    let mut next_boundary = chunk_size;
+    // The start of the text before the next tag, `None` at the end tag of a raw text element
+    let mut text_start = Some(0);
+    let mut index = 0;
+
+    while let Some(offset) = memchr(b'<', &buffer[index..]) {
+        let start = index + offset;
+        let Some(&c) = buffer.get(start + 1) else {
+            break;
+        };
+
+        let tag_end = match c {
+            b'!' if buffer[start..].starts_with(b"<!--") => {
+                find(buffer, start + 2, b"-->").map(|end| end + 3)
+            }
+            b'!' if options.xml_mode && buffer[start..].starts_with(CDATA_START) => {
+                find(buffer, start, b"]]>").map(|end| end + 3)
+            }
+            b'!' | b'?' | b'/' => memchr(b'>', &buffer[start..]).map(|end| start + end + 1),
+            c if c.is_ascii_alphabetic() => start_tag_end(buffer, start),
+            // A `<` in text
+            _ => {
+                index = start + 1;
+                continue;
+            }
+        };
+
+        if start >= next_boundary {
+            let text = text_start.map(|text_start| &buffer[text_start..start]);
+
+            if let Some(follows_text) = text.and_then(follows_text) {
+                boundaries.push(TagBoundary {
+                    index: start,
+                    follows_text,
+                });
+                next_boundary = start + chunk_size;
+            }
+        }
+
+        let Some(tag_end) = tag_end else {
+            break;
+        };
+
+        index = tag_end;
+        text_start = Some(tag_end);
+
+        if options.xml_mode || !c.is_ascii_alphabetic() || buffer[tag_end - 2] == b'/' {
+            continue;
+        }
+
+        let name_end = buffer[start + 1..tag_end]
+            .iter()
+            .position(|&c| c.is_ascii_whitespace() || c == b'/' || c == b'>')
+            .map_or(tag_end, |end| start + 1 + end);
+        let name = &buffer[start + 1..name_end];
+
+        if name.eq_ignore_ascii_case(b"plaintext") {
+            break;
+        }
+        if RAW_TEXT_ELEMENTS
+            .iter()
+            .any(|element| name.eq_ignore_ascii_case(element))
+        {
+            let Some(end) = raw_text_end(buffer, tag_end, name) else {
+                break;
+            };
+
+            index = end;
+            text_start = None;
+        }
+    }
+
+    boundaries
+}
+
+/** The tokens of a part of the document, from a boundary up to the next one. */
+struct Chunk<'a> {
+    start: Option<TagBoundary>,
+    end: usize,
+    tokens: IntoIter<TokenizerToken>,
+    // The content models the start tags of the chunk were tokenized with, in order
+    content_models: Vec<ContentModel>,
+    // The tokenizer of the chunk, stopped at its end
+    tokenizer: Option<Tokenizer<'a>>,
+}
+
+/**
+ * Tokenizes a part of the document on its own. The start tags get the content model they have
+ * outside of foreign content, and CDATA sections are allowed as in XML mode. The parser checks
+ * that when it reads the tokens.
+ */
+fn tokenize_chunk<'a>(
+    buffer: &'a [u8],
+    options: &'a ParserOptions,
+    start: Option<TagBoundary>,
+    end: usize,
+) -> Chunk<'a> {
+    let mut tokenizer = new_tokenizer(buffer, options);
+    let mut tokens = Vec::new();
+    let mut content_models = Vec::new();
+
+    if let Some(start) = start {
+        tokenizer.resume(start);
+    }
+    tokenizer.set_end(end);
+
+    while let Some(token) = tokenizer.next() {
+        if token.location == TokenizerTokenLocation::OpenTagName {
+            let content_model = if options.xml_mode {
+                ContentModel::Data
+            } else {
+                str::from_utf8(&buffer[token.start..token.end])
+                    .ok()
+                    .and_then(LocalName::known)
+                    .map_or(ContentModel::Data, |name| html_content_model(&name))
+            };
+
+            tokenizer.set_content_model(content_model);
+            content_models.push(content_model);
+        }
+
+        tokens.push(token);
+    }
+
+    Chunk {
+        start,
+        end,
+        tokens: tokens.into_iter(),
+        content_models,
+        tokenizer: Some(tokenizer),
+    }
+}
+
+/**
+ * The tokens of the chunks, read in order by the parser. A chunk is used when the tokenizer
+ * reached the boundary it starts from, and while the parser agrees with the content models and
+ * the CDATA sections it was tokenized with. Otherwise the tokenizer of the parser continues
+ * sequentially until it reaches the start of a later chunk.
+ */
+pub(crate) struct ChunkedTokens<'a> {
+    buffer: &'a [u8],
+    xml_mode: bool,
+    chunks: Vec<Chunk<'a>>,
+    // The current chunk, and the number of its tokens and start tags read so far
+    chunk: usize,
+    position: usize,
+    open_tags: usize,
+    // The end of the token that was read last
+    token_end: usize,
+    // Whether the tokens come from the tokenizer of the parser instead of the current chunk
+    is_live: bool,
+    // Whether the parser allows CDATA sections, the chunks allow them in XML mode only
+    cdata_allowed: bool,
+}
+
+impl<'a> ChunkedTokens<'a> {
+    /** Tokenizes the chunks in parallel. `None` when the document is not split. */
+    fn new(
+        buffer: &'a [u8],
+        options: &'a ParserOptions,
+        chunk_size: usize,
+    ) -> Option<ChunkedTokens<'a>> {
+        let boundaries = find_boundaries(buffer, options, chunk_size.max(1));
+
+        if boundaries.is_empty() {
+            return None;
+        }
+
+        let chunks = (0..=boundaries.len())
+            .into_par_iter()
+            .map(|chunk| {
+                let start = chunk.checked_sub(1).map(|previous| boundaries[previous]);
+                let end = boundaries
+                    .get(chunk)
+                    .map_or(buffer.len(), |boundary| boundary.index + 1);
+
+                tokenize_chunk(buffer, options, start, end)
+            })
+            .collect();
+
+        Some(ChunkedTokens {
+            buffer,
+            xml_mode: options.xml_mode,
+            chunks,
+            chunk: 0,
+            position: 0,
+            open_tags: 0,
+            token_end: 0,
+            is_live: false,
+            cdata_allowed: options.xml_mode,
+        })
+    }
+
+    /** Whether a CDATA section is in `from..to` while the parser and the chunks disagree on it. */
+    fn has_cdata_mismatch(&self, from: usize, to: usize) -> bool {
+        self.cdata_allowed != self.xml_mode && find(&self.buffer[..to], from, CDATA_START).is_some()
+    }
+
+    /**
+     * Switches to the tokenizer of the parser in the middle of the current chunk. It tokenizes
+     * the chunk again up to the token that was read last.
+     */
+    fn go_live(&mut self, tokenizer: &mut Tokenizer<'a>) {
+        let chunk = &self.chunks[self.chunk];
+        let mut content_models = chunk.content_models.iter();
+
+        tokenizer.reset(self.buffer);
+        tokenizer.set_cdata_allowed(self.xml_mode);
+        if let Some(start) = chunk.start {
+            tokenizer.resume(start);
+        }
+        tokenizer.set_end(chunk.end);
+
+        for _ in 0..self.position {
+            let Some(token) = tokenizer.next() else {
+                break;
+            };
+
+            if token.location == TokenizerTokenLocation::OpenTagName {
+                tokenizer.set_content_model(*content_models.next().unwrap());
+            }
+        }
+
+        tokenizer.set_cdata_allowed(self.cdata_allowed);
+        self.is_live = true;
+    }
+
+    /** The parser sets the content model of the start tag that was read last. */
+    pub(crate) fn set_content_model(
+        &mut self,
+        tokenizer: &mut Tokenizer<'a>,
+        content_model: ContentModel,
+    ) {
+        if self.is_live {
+            return;
+        }
+
+        let chunk_content_model = self.chunks[self.chunk].content_models[self.open_tags];
+
+        self.open_tags += 1;
+        if content_model != chunk_content_model {
+            self.go_live(tokenizer);
+        }
+    }
+
+    /** The parser allows CDATA sections or not after the token that was read last. */
+    pub(crate) fn set_cdata_allowed(&mut self, tokenizer: &mut Tokenizer<'a>, cdata_allowed: bool) {
+        self.cdata_allowed = cdata_allowed;
+
+        if self.is_live {
+            return;
+        }
+
+        if self.has_cdata_mismatch(self.token_end, self.chunks[self.chunk].end) {
+            self.go_live(tokenizer);
+        }
+    }
+
+    pub(crate) fn next(&mut self, tokenizer: &mut Tokenizer<'a>) -> Option<TokenizerToken> {
+        loop {
+            let token = if self.is_live {
+                tokenizer.next()
+            } else {
+                self.chunks[self.chunk].tokens.next()
+            };
+
+            if let Some(token) = token {
+                self.position += 1;
+                self.token_end = token.end;
+
+                return Some(token);
+            }
+
+            // The end of the chunk, the next one starts right after it
+            let next = self.chunk + 1;
+            let next_start = self.chunks.get(next)?.start;
+            let boundary = if self.is_live {
+                tokenizer.boundary()
+            } else {
+                self.chunks[self.chunk].tokenizer.as_ref()?.boundary()
+            };
+            let is_next_usable = boundary.is_some_and(|boundary| {
+                Some(boundary) == next_start
+                    && !self.has_cdata_mismatch(boundary.index, self.chunks[next].end)
+            });
+
+            if is_next_usable {
+                self.is_live = false;
+            } else {
+                if !self.is_live {
+                    *tokenizer = self.chunks[self.chunk].tokenizer.take()?;
+                    tokenizer.set_cdata_allowed(self.cdata_allowed);
+                    self.is_live = true;
+                }
+
+                tokenizer.set_end(self.chunks[next].end);
+            }
+
+            let chunk = &mut self.chunks[self.chunk];
+
+            chunk.content_models = Vec::new();
+            chunk.tokenizer = None;
+            self.chunk = next;
+            self.position = 0;
+            self.open_tags = 0;
+        }
+    }
+}
+
+impl<'i> Parser<'i> {
+    /**
+     * Like `Parser::new`, but tokenizes large documents on multiple threads first, in chunks of
+     * `DEFAULT_CHUNK_SIZE`, see `Parser::from_bytes_parallel`. With a single thread nothing is
+     * gained, the document is parsed sequentially then.
+     */
+    pub fn new_parallel<'a>(html: &'a str, options: &'a ParserOptions) -> Parser<'a> {
+        if rayon::current_num_threads() == 1 {
+            return Parser::new(html, options);
+        }
+
+        Parser::from_bytes_parallel(html.as_bytes(), options, DEFAULT_CHUNK_SIZE)
+    }
+
+    /**
+     * Like `Parser::from_bytes`, but splits the document into chunks of at least `chunk_size`
+     * bytes at tags in text, and tokenizes them on the rayon thread pool before parsing.
+     *
+     * The tokens are the same as parsed sequentially. When a chunk does not start the way the
+     * tokenizer reaches it (eg. `<style>` in `<svg>` is a regular element, not raw text), the
+     * document is tokenized sequentially from there until the start of a later chunk.
+     * `Parser::reset` parses the next document sequentially.
+     */
+    pub fn from_bytes_parallel<'a>(
+        bytes: &'a [u8],
+        options: &'a ParserOptions,
+        chunk_size: usize,
+    ) -> Parser<'a> {
+        let mut parser = Parser::from_bytes(bytes, options);
+
+        parser.chunks = ChunkedTokens::new(parser.buffer, options, chunk_size);
+
+        parser
+    }
+}
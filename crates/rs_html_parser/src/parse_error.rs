@@ -0,0 +1,45 @@
+use crate::element_info::has_optional_end_tag;
+use rs_html_parser_tokens::LocalName;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /** An end tag that does not close an open element. It is ignored, except `</p>` and `</br>`. */
+    UnexpectedEndTag(LocalName),
+    /**
+     * An element that is closed by the end tag of an ancestor or by the end of the document,
+     * although its end tag is required.
+     */
+    UnclosedElement(LocalName),
+}
+
+/**
+ * A problem in the markup that the parser recovered from, see `Parser::parse_errors`. Only the
+ * errors that change how elements are nested are reported.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /** Byte offset in the input of the tag name of the end tag, or the end of the input. */
+    pub position: usize,
+    pub kind: ParseErrorKind,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            ParseErrorKind::UnexpectedEndTag(name) => {
+                write!(f, "unexpected end tag </{}>", name)
+            }
+            ParseErrorKind::UnclosedElement(name) => {
+                write!(f, "element <{}> is not closed", name)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/** Whether closing the element without its end tag is an error. */
+pub fn requires_end_tag(name: &LocalName, html_mode: bool) -> bool {
+    !html_mode || !has_optional_end_tag(name)
+}
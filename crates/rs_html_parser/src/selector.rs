@@ -0,0 +1,610 @@
+use crate::tree::{Document, NodeData, NodeId};
+use std::collections::HashSet;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum AttributeOperator {
+    /** `[name]` */
+    Exists,
+    /** `[name=value]` */
+    Equals,
+    /** `[name~=value]`, one of the whitespace separated words */
+    Includes,
+    /** `[name|=value]`, the value or the value followed by `-` */
+    DashMatch,
+    /** `[name^=value]` */
+    Prefix,
+    /** `[name$=value]` */
+    Suffix,
+    /** `[name*=value]` */
+    Substring,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum SimpleSelector {
+    /** An element name, or `*` for any element. */
+    Type(Option<Box<str>>),
+    Id(Box<str>),
+    Class(Box<str>),
+    Attribute {
+        name: Box<str>,
+        operator: AttributeOperator,
+        value: Box<str>,
+        ignore_case: bool,
+    },
+    FirstChild,
+    LastChild,
+    OnlyChild,
+    Empty,
+    Not(Vec<SimpleSelector>),
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Combinator {
+    /** `a b` */
+    Descendant,
+    /** `a > b` */
+    Child,
+    /** `a + b` */
+    NextSibling,
+    /** `a ~ b` */
+    SubsequentSibling,
+}
+
+/** Compound selectors joined by combinators, eg. `ul > li.active`. */
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ComplexSelector {
+    compounds: Vec<Vec<SimpleSelector>>,
+    /** The combinator in front of every compound selector but the first one. */
+    combinators: Vec<Combinator>,
+}
+
+/**
+ * A CSS selector list for finding elements in a `Document`, eg. `main > p.lead, a[href^="http"]`.
+ *
+ * Supported are type, universal, id, class and attribute selectors (with the `i` flag), the
+ * pseudo-classes `:first-child`, `:last-child`, `:only-child`, `:empty` and `:not()`, and the
+ * descendant, child, next sibling and subsequent sibling combinators. Element names and
+ * attribute names are matched ignoring ASCII case.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Selector {
+    alternatives: Vec<ComplexSelector>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelectorError {
+    /** Byte offset of the problem in the selector. */
+    pub position: usize,
+    pub message: &'static str,
+}
+
+impl fmt::Display for SelectorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid selector at {}: {}", self.position, self.message)
+    }
+}
+
+impl std::error::Error for SelectorError {}
+
+struct SelectorParser<'a> {
+    input: &'a str,
+    position: usize,
+}
+
+fn is_name_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '-' | '_' | '\\') || !c.is_ascii()
+}
+
+impl<'a> SelectorParser<'a> {
+    fn error(&self, message: &'static str) -> SelectorError {
+        SelectorError {
+            position: self.position,
+            message,
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.input[self.position..].chars().next()
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.position += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    /** Skips whitespace, and tells whether there was any. */
+    fn skip_whitespace(&mut self) -> bool {
+        let start = self.position;
+
+        while self.peek().is_some_and(|c| c.is_ascii_whitespace()) {
+            self.position += 1;
+        }
+
+        self.position > start
+    }
+
+    fn name(&mut self) -> Result<Box<str>, SelectorError> {
+        let mut name = String::new();
+
+        while let Some(c) = self.peek() {
+            if c == '\\' {
+                self.position += 1;
+                let escaped = self.peek().ok_or_else(|| self.error("escape at the end"))?;
+
+                self.position += escaped.len_utf8();
+                name.push(escaped);
+            } else if is_name_char(c) {
+                self.position += c.len_utf8();
+                name.push(c);
+            } else {
+                break;
+            }
+        }
+
+        if name.is_empty() {
+            return Err(self.error("expected a name"));
+        }
+
+        Ok(name.into())
+    }
+
+    fn string(&mut self, quote: char) -> Result<Box<str>, SelectorError> {
+        let mut value = String::new();
+
+        loop {
+            match self.peek() {
+                None => return Err(self.error("unterminated string")),
+                Some(c) if c == quote => {
+                    self.position += 1;
+                    return Ok(value.into());
+                }
+                Some('\\') => {
+                    self.position += 1;
+                    if let Some(c) = self.peek() {
+                        self.position += c.len_utf8();
+                        value.push(c);
+                    }
+                }
+                Some(c) => {
+                    self.position += c.len_utf8();
+                    value.push(c);
+                }
+            }
+        }
+    }
+
+    fn attribute(&mut self) -> Result<SimpleSelector, SelectorError> {
+        self.skip_whitespace();
+        let name = self.name()?;
+
+        self.skip_whitespace();
+        if self.eat(']') {
+            return Ok(SimpleSelector::Attribute {
+                name,
+                operator: AttributeOperator::Exists,
+                value: "".into(),
+                ignore_case: false,
+            });
+        }
+
+        let operator = match self.peek() {
+            Some('=') => AttributeOperator::Equals,
+            Some('~') => AttributeOperator::Includes,
+            Some('|') => AttributeOperator::DashMatch,
+            Some('^') => AttributeOperator::Prefix,
+            Some('$') => AttributeOperator::Suffix,
+            Some('*') => AttributeOperator::Substring,
+            _ => return Err(self.error("expected an attribute operator")),
+        };
+
+        self.position += 1;
+        if operator != AttributeOperator::Equals && !self.eat('=') {
+            return Err(self.error("expected `=`"));
+        }
+        self.skip_whitespace();
+
+        let value = match self.peek() {
+            Some(quote @ ('"' | '\'')) => {
+                self.position += 1;
+                self.string(quote)?
+            }
+            _ => self.name()?,
+        };
+
+        self.skip_whitespace();
+        let ignore_case = self.eat('i') || self.eat('I');
+
+        self.skip_whitespace();
+        if !self.eat(']') {
+            return Err(self.error("expected `]`"));
+        }
+
+        Ok(SimpleSelector::Attribute {
+            name,
+            operator,
+            value,
+            ignore_case,
+        })
+    }
+
+    fn pseudo_class(&mut self) -> Result<SimpleSelector, SelectorError> {
+        let start = self.position;
+        let name = self.name()?;
+
+        match name.to_ascii_lowercase().as_str() {
+            "first-child" => Ok(SimpleSelector::FirstChild),
+            "last-child" => Ok(SimpleSelector::LastChild),
+            "only-child" => Ok(SimpleSelector::OnlyChild),
+            "empty" => Ok(SimpleSelector::Empty),
+            "not" => {
+                if !self.eat('(') {
+                    return Err(self.error("expected `(`"));
+                }
+                self.skip_whitespace();
+                let compound = self.compound()?;
+
+                self.skip_whitespace();
+                if !self.eat(')') {
+                    return Err(self.error("expected `)`"));
+                }
+
+                Ok(SimpleSelector::Not(compound))
+            }
+            _ => Err(SelectorError {
+                position: start,
+                message: "unsupported pseudo-class",
+            }),
+        }
+    }
+
+    fn compound(&mut self) -> Result<Vec<SimpleSelector>, SelectorError> {
+        let mut compound = Vec::new();
+
+        if self.eat('*') {
+            compound.push(SimpleSelector::Type(None));
+        } else if self.peek().is_some_and(is_name_char) {
+            compound.push(SimpleSelector::Type(Some(self.name()?)));
+        }
+
+        loop {
+            if self.eat('#') {
+                compound.push(SimpleSelector::Id(self.name()?));
+            } else if self.eat('.') {
+                compound.push(SimpleSelector::Class(self.name()?));
+            } else if self.eat('[') {
+                compound.push(self.attribute()?);
+            } else if self.eat(':') {
+                compound.push(self.pseudo_class()?);
+            } else {
+                break;
+            }
+        }
+
+        if compound.is_empty() {
+            return Err(self.error("expected a selector"));
+        }
+
+        Ok(compound)
+    }
+
+    fn complex(&mut self) -> Result<ComplexSelector, SelectorError> {
+        self.skip_whitespace();
+
+        let mut selector = ComplexSelector {
+            compounds: vec![self.compound()?],
+            combinators: Vec::new(),
+        };
+
+        loop {
+            let has_whitespace = self.skip_whitespace();
+            let combinator = if self.eat('>') {
+                Combinator::Child
+            } else if self.eat('+') {
+                Combinator::NextSibling
+            } else if self.eat('~') {
+                Combinator::SubsequentSibling
+            } else if has_whitespace && !matches!(self.peek(), None | Some(',')) {
+                Combinator::Descendant
+            } else {
+                break;
+            };
+
+            self.skip_whitespace();
+            selector.combinators.push(combinator);
+            selector.compounds.push(self.compound()?);
+        }
+
+        Ok(selector)
+    }
+
+    fn selector(&mut self) -> Result<Selector, SelectorError> {
+        let mut alternatives = vec![self.complex()?];
+
+        while self.eat(',') {
+            alternatives.push(self.complex()?);
+        }
+
+        if self.position < self.input.len() {
+            return Err(self.error("unexpected character"));
+        }
+
+        Ok(Selector { alternatives })
+    }
+}
+
+fn matches_attribute(
+    value: &str,
+    operator: &AttributeOperator,
+    expected: &str,
+    ignore_case: bool,
+) -> bool {
+    let (value, expected) = if ignore_case {
+        (value.to_lowercase(), expected.to_lowercase())
+    } else {
+        (value.to_string(), expected.to_string())
+    };
+
+    match operator {
+        AttributeOperator::Exists => true,
+        AttributeOperator::Equals => value == expected,
+        AttributeOperator::Includes => {
+            !expected.is_empty() && value.split_ascii_whitespace().any(|word| word == expected)
+        }
+        AttributeOperator::DashMatch => {
+            value == expected
+                || value
+                    .strip_prefix(&expected)
+                    .is_some_and(|rest| rest.starts_with('-'))
+        }
+        AttributeOperator::Prefix => !expected.is_empty() && value.starts_with(&expected),
+        AttributeOperator::Suffix => !expected.is_empty() && value.ends_with(&expected),
+        AttributeOperator::Substring => !expected.is_empty() && value.contains(&expected),
+    }
+}
+
+/** The children of the element's parent, and the position of the element among them. */
+fn siblings(document: &Document, id: NodeId) -> (&[NodeId], usize) {
+    match document[id].parent {
+        // The children are in document order, like their ids
+        Some(parent) => {
+            let children = &document[parent].children;
+
+            (children, children.binary_search(&id).unwrap_or_default())
+        }
+        None => (&[], 0),
+    }
+}
+
+/** The element siblings before the element, nearest first. */
+fn previous_siblings(document: &Document, id: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+    let (siblings, position) = siblings(document, id);
+
+    siblings[..position]
+        .iter()
+        .rev()
+        .copied()
+        .filter(|sibling| document.name(*sibling).is_some())
+}
+
+/** The element siblings after the element. */
+fn next_siblings(document: &Document, id: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+    let (siblings, position) = siblings(document, id);
+
+    siblings[siblings.len().min(position + 1)..]
+        .iter()
+        .copied()
+        .filter(|sibling| document.name(*sibling).is_some())
+}
+
+fn matches_simple(document: &Document, id: NodeId, selector: &SimpleSelector) -> bool {
+    match selector {
+        SimpleSelector::Type(None) => true,
+        SimpleSelector::Type(Some(name)) => document
+            .name(id)
+            .is_some_and(|element| element.as_str().eq_ignore_ascii_case(name)),
+        SimpleSelector::Id(id_value) => document.attribute(id, "id") == Some(&**id_value),
+        SimpleSelector::Class(class) => document
+            .attribute(id, "class")
+            .is_some_and(|value| value.split_ascii_whitespace().any(|word| word == &**class)),
+        SimpleSelector::Attribute {
+            name,
+            operator,
+            value,
+            ignore_case,
+        } => document
+            .attribute(id, name)
+            .is_some_and(|actual| matches_attribute(actual, operator, value, *ignore_case)),
+        SimpleSelector::FirstChild => previous_siblings(document, id).next().is_none(),
+        SimpleSelector::LastChild => next_siblings(document, id).next().is_none(),
+        SimpleSelector::OnlyChild => {
+            previous_siblings(document, id).next().is_none()
+                && next_siblings(document, id).next().is_none()
+        }
+        SimpleSelector::Empty => document[id].children.iter().all(|child| {
+            matches!(
+                &document[*child].data,
+                NodeData::Comment(_) | NodeData::ProcessingInstruction(_)
+            )
+        }),
+        SimpleSelector::Not(compound) => !matches_compound(document, id, compound),
+    }
+}
+
+fn matches_compound(document: &Document, id: NodeId, compound: &[SimpleSelector]) -> bool {
+    document.name(id).is_some()
+        && compound
+            .iter()
+            .all(|selector| matches_simple(document, id, selector))
+}
+
+/**
+ * Matches the selectors against the elements of a document, remembering what did not match so
+ * that combinators do not try the same element against the same compound selector twice.
+ */
+struct Matcher<'a> {
+    document: &'a Document,
+    /** The alternative, the index of the compound selector and the element that did not match. */
+    failed: HashSet<(usize, usize, NodeId)>,
+    /**
+     * The alternative, the index of the compound selector and the element for which no ancestor,
+     * or no previous sibling, matched.
+     */
+    failed_scans: HashSet<(usize, usize, NodeId)>,
+}
+
+impl<'a> Matcher<'a> {
+    fn new(document: &'a Document) -> Matcher<'a> {
+        Matcher {
+            document,
+            failed: HashSet::new(),
+            failed_scans: HashSet::new(),
+        }
+    }
+
+    fn matches(&mut self, selector: &Selector, id: NodeId) -> bool {
+        selector
+            .alternatives
+            .iter()
+            .enumerate()
+            .any(|(alternative, complex)| {
+                self.matches_complex(complex, alternative, id, complex.compounds.len() - 1)
+            })
+    }
+
+    /** Whether the element matches the compound selectors up to `index`, from right to left. */
+    fn matches_complex(
+        &mut self,
+        selector: &ComplexSelector,
+        alternative: usize,
+        id: NodeId,
+        index: usize,
+    ) -> bool {
+        if self.failed.contains(&(alternative, index, id)) {
+            return false;
+        }
+
+        let matches = self.matches_complex_uncached(selector, alternative, id, index);
+
+        // Every element is tried once against the last compound selector
+        if !matches && index + 1 < selector.compounds.len() {
+            self.failed.insert((alternative, index, id));
+        }
+
+        matches
+    }
+
+    fn matches_complex_uncached(
+        &mut self,
+        selector: &ComplexSelector,
+        alternative: usize,
+        id: NodeId,
+        index: usize,
+    ) -> bool {
+        let document = self.document;
+
+        if !matches_compound(document, id, &selector.compounds[index]) {
+            return false;
+        }
+        if index == 0 {
+            return true;
+        }
+
+        match selector.combinators[index - 1] {
+            Combinator::Descendant => self.scan(selector, alternative, id, index - 1, |id| {
+                document[id].parent
+            }),
+            Combinator::Child => document[id].parent.is_some_and(|parent| {
+                self.matches_complex(selector, alternative, parent, index - 1)
+            }),
+            Combinator::NextSibling => {
+                previous_siblings(document, id)
+                    .next()
+                    .is_some_and(|sibling| {
+                        self.matches_complex(selector, alternative, sibling, index - 1)
+                    })
+            }
+            Combinator::SubsequentSibling => {
+                self.scan(selector, alternative, id, index - 1, |id| {
+                    previous_siblings(document, id).next()
+                })
+            }
+        }
+    }
+
+    /**
+     * Whether one of the elements that `next` leads to from the element, its ancestors or its
+     * previous siblings, matches the compound selectors up to `index`.
+     */
+    fn scan(
+        &mut self,
+        selector: &ComplexSelector,
+        alternative: usize,
+        id: NodeId,
+        index: usize,
+        next: impl Fn(NodeId) -> Option<NodeId>,
+    ) -> bool {
+        // The elements whose scan did not find a match so far
+        let mut scanned = vec![id];
+        let mut current = id;
+
+        while let Some(candidate) = next(current) {
+            if self.matches_complex(selector, alternative, candidate, index) {
+                return true;
+            }
+            if self.failed_scans.contains(&(alternative, index, candidate)) {
+                break;
+            }
+
+            scanned.push(candidate);
+            current = candidate;
+        }
+
+        self.failed_scans
+            .extend(scanned.into_iter().map(|id| (alternative, index, id)));
+
+        false
+    }
+}
+
+impl Selector {
+    pub fn parse(selector: &str) -> Result<Selector, SelectorError> {
+        SelectorParser {
+            input: selector,
+            position: 0,
+        }
+        .selector()
+    }
+
+    /** Whether the node is an element that matches the selector. */
+    pub fn matches(&self, document: &Document, id: NodeId) -> bool {
+        Matcher::new(document).matches(self, id)
+    }
+}
+
+impl FromStr for Selector {
+    type Err = SelectorError;
+
+    fn from_str(selector: &str) -> Result<Selector, SelectorError> {
+        Selector::parse(selector)
+    }
+}
+
+impl Document {
+    /** The elements below the node that match the selector, in document order. */
+    pub fn select<'a>(
+        &'a self,
+        id: NodeId,
+        selector: &'a Selector,
+    ) -> impl Iterator<Item = NodeId> + 'a {
+        let mut matcher = Matcher::new(self);
+
+        self.descendants(id)
+            .filter(move |node| matcher.matches(selector, *node))
+    }
+}
@@ -0,0 +1,316 @@
+use crate::element_info::is_block_elements;
+use crate::text::is_hidden;
+use crate::tree::{Document, NodeData, NodeId};
+use crate::whitespace::collapse_and_trim_whitespace;
+use rs_html_parser_tokens::LocalName;
+
+/** The most columns that a cell spans, larger values are clamped as in browsers. */
+const MAX_COLSPAN: usize = 1000;
+/** The most rows that a cell spans, larger values are clamped as in browsers. */
+const MAX_ROWSPAN: usize = 65534;
+/**
+ * The most cells in the grid of a table. Rows are padded to the widest one, so the width is
+ * limited by the number of rows, the cells past it are left out and spans are cut at it.
+ */
+const MAX_CELLS: usize = 1 << 20;
+/** The most bytes of text that are copied to spanned cells in a table, spans are cut at it. */
+const MAX_SPANNED_TEXT: usize = 1 << 24;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Cell {
+    /** The text of the cell with whitespace collapsed, without the text of nested tables. */
+    pub text: String,
+    /** Whether the cell is a `th`. */
+    pub is_header: bool,
+    /**
+     * Whether the cell is covered by a cell that spans more than one row or column, it has the
+     * text of that cell.
+     */
+    pub is_spanned: bool,
+}
+
+/**
+ * A table as a grid, a cell that spans rows or columns fills each of them. The grid of a very
+ * large table is cut at about a million cells.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Table {
+    /** The `table` element. */
+    pub id: NodeId,
+    /** The text of the `caption`. */
+    pub caption: Option<String>,
+    /** The number of rows at the top that are headers, from `thead` or with only `th` cells. */
+    pub header_rows: usize,
+    /** The rows, all with the same number of cells. The rows of a `tfoot` are the last ones. */
+    pub rows: Vec<Vec<Cell>>,
+}
+
+/** The rows of a `thead`, `tbody` or `tfoot`, or the `tr` elements directly in the table. */
+struct RowGroup {
+    rows: Vec<NodeId>,
+    is_head: bool,
+}
+
+fn push_csv_field(csv: &mut String, field: &str) {
+    if field.contains([',', '"', '\r', '\n']) {
+        csv.push('"');
+        csv.push_str(&field.replace('"', "\"\""));
+        csv.push('"');
+    } else {
+        csv.push_str(field);
+    }
+}
+
+impl Table {
+    /** The header rows. */
+    pub fn head(&self) -> &[Vec<Cell>] {
+        &self.rows[..self.header_rows]
+    }
+
+    /** The rows after the header rows. */
+    pub fn body(&self) -> &[Vec<Cell>] {
+        &self.rows[self.header_rows..]
+    }
+
+    /**
+     * The rows as CSV, quoted as in RFC 4180 but with a line feed after each row. The caption is
+     * not included.
+     */
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::new();
+
+        for row in &self.rows {
+            for (index, cell) in row.iter().enumerate() {
+                if index > 0 {
+                    csv.push(',');
+                }
+                push_csv_field(&mut csv, &cell.text);
+            }
+            csv.push('\n');
+        }
+        csv
+    }
+}
+
+impl Document {
+    /**
+     * The tables below the node in document order. A table nested in a cell is a table of its
+     * own, it comes after the table that contains it.
+     */
+    pub fn tables(&self, id: NodeId) -> Vec<Table> {
+        self.descendants(id)
+            .filter(|table| self.is_element(*table, &LocalName::Table))
+            .map(|table| self.table(table))
+            .collect()
+    }
+
+    fn table(&self, id: NodeId) -> Table {
+        let mut caption = None;
+        let mut groups = Vec::new();
+        let mut footers = Vec::new();
+        // Consecutive rows directly in the table are a group of their own
+        let mut is_in_rows = false;
+
+        for child in self.element_children(id) {
+            let name = self.name(child).map(LocalName::element);
+
+            match &name {
+                Some(LocalName::Caption) if caption.is_none() => {
+                    caption = Some(self.cell_text(child))
+                }
+                Some(LocalName::Tr) => {
+                    if !is_in_rows {
+                        groups.push(RowGroup {
+                            rows: Vec::new(),
+                            is_head: false,
+                        });
+                    }
+                    if let Some(group) = groups.last_mut() {
+                        group.rows.push(child);
+                    }
+                }
+                Some(group_name @ (LocalName::Thead | LocalName::Tbody | LocalName::Tfoot)) => {
+                    let group = RowGroup {
+                        rows: self
+                            .element_children(child)
+                            .filter(|row| self.is_element(*row, &LocalName::Tr))
+                            .collect(),
+                        is_head: *group_name == LocalName::Thead,
+                    };
+
+                    if *group_name == LocalName::Tfoot {
+                        footers.push(group);
+                    } else {
+                        groups.push(group);
+                    }
+                }
+                _ => {}
+            }
+            is_in_rows = name == Some(LocalName::Tr);
+        }
+        groups.extend(footers);
+
+        let row_count: usize = groups.iter().map(|group| group.rows.len()).sum();
+        let max_width = (MAX_CELLS / row_count.max(1)).max(1);
+        let mut grid: Vec<Vec<Option<Cell>>> = Vec::new();
+        let mut head_rows = Vec::new();
+        let mut spanned_text = 0;
+
+        for group in groups {
+            let start = grid.len();
+            let end = start + group.rows.len();
+
+            grid.resize(end, Vec::new());
+            for (offset, row) in group.rows.into_iter().enumerate() {
+                let row_index = start + offset;
+                let mut column = 0;
+
+                head_rows.push(group.is_head);
+                for cell in self.element_children(row) {
+                    let is_header = match self.name(cell).map(LocalName::element) {
+                        Some(LocalName::Th) => true,
+                        Some(LocalName::Td) => false,
+                        _ => continue,
+                    };
+
+                    while grid[row_index].get(column).is_some_and(Option::is_some) {
+                        column += 1;
+                    }
+                    if column >= max_width {
+                        break;
+                    }
+
+                    let mut colspan = self
+                        .span(cell, "colspan")
+                        .unwrap_or(1)
+                        .clamp(1, MAX_COLSPAN)
+                        .min(max_width - column);
+                    // A `rowspan` of 0 spans the rest of the row group
+                    let mut rowspan = match self.span(cell, "rowspan") {
+                        Some(0) => end - row_index,
+                        rowspan => rowspan.unwrap_or(1).min(MAX_ROWSPAN).min(end - row_index),
+                    };
+                    let text = self.cell_text(cell);
+                    // The cell itself and the spanned cells that its text can still be copied to
+                    let max_area = (MAX_SPANNED_TEXT - spanned_text) / text.len().max(1) + 1;
+
+                    if rowspan * colspan > max_area {
+                        colspan = colspan.min((max_area / rowspan).max(1));
+                        rowspan = rowspan.min((max_area / colspan).max(1));
+                    }
+                    spanned_text += (rowspan * colspan - 1) * text.len();
+
+                    for (index, cells) in
+                        grid[row_index..row_index + rowspan].iter_mut().enumerate()
+                    {
+                        if cells.len() < column + colspan {
+                            cells.resize(column + colspan, None);
+                        }
+                        for (offset, spanned) in
+                            cells[column..column + colspan].iter_mut().enumerate()
+                        {
+                            *spanned = Some(Cell {
+                                text: text.clone(),
+                                is_header,
+                                is_spanned: index > 0 || offset > 0,
+                            });
+                        }
+                    }
+                    column += colspan;
+                }
+            }
+        }
+
+        let header_rows = grid
+            .iter()
+            .zip(head_rows)
+            .take_while(|(cells, is_head)| {
+                *is_head
+                    || cells.iter().any(Option::is_some)
+                        && cells.iter().flatten().all(|cell| cell.is_header)
+            })
+            .count();
+        let width = grid.iter().map(Vec::len).max().unwrap_or(0);
+
+        Table {
+            id,
+            caption,
+            header_rows,
+            rows: grid
+                .into_iter()
+                .map(|cells| {
+                    let mut row: Vec<_> =
+                        cells.into_iter().map(Option::unwrap_or_default).collect();
+
+                    row.resize(width, Cell::default());
+                    row
+                })
+                .collect(),
+        }
+    }
+
+    /** The value of a `colspan` or `rowspan` attribute, if it is a valid number. */
+    fn span(&self, id: NodeId, name: &str) -> Option<usize> {
+        self.attribute(id, name)
+            .and_then(|value| value.trim().parse().ok())
+    }
+
+    fn cell_text(&self, id: NodeId) -> String {
+        let mut text = String::new();
+
+        self.write_cell_text(&mut text, id);
+        collapse_and_trim_whitespace(&text).into_owned()
+    }
+
+    /** Collects the text, blocks and `br` separate words, nested tables are left out. */
+    fn write_cell_text(&self, text: &mut String, id: NodeId) {
+        enum Step {
+            Node(NodeId),
+            /** The space after a block. */
+            Separator,
+        }
+
+        // The nodes to write in reverse document order
+        let mut stack: Vec<Step> = self[id]
+            .children
+            .iter()
+            .rev()
+            .map(|child| Step::Node(*child))
+            .collect();
+
+        while let Some(step) = stack.pop() {
+            let id = match step {
+                Step::Node(id) => id,
+                Step::Separator => {
+                    text.push(' ');
+                    continue;
+                }
+            };
+
+            match &self[id].data {
+                NodeData::Text(data) => text.push_str(data),
+                NodeData::Element { name, .. } => {
+                    let name = name.element();
+
+                    if name == LocalName::Table || is_hidden(self, id, &name) {
+                        continue;
+                    }
+                    if name == LocalName::Br || is_block_elements(&name) {
+                        text.push(' ');
+                    }
+                    if is_block_elements(&name) {
+                        stack.push(Step::Separator);
+                    }
+                    stack.extend(
+                        self[id]
+                            .children
+                            .iter()
+                            .rev()
+                            .map(|child| Step::Node(*child)),
+                    );
+                }
+                _ => {}
+            }
+        }
+    }
+}
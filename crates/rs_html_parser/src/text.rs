@@ -0,0 +1,256 @@
+use crate::element_info::is_block_elements;
+use crate::tree::{Document, NodeData, NodeId};
+use crate::whitespace::is_html_whitespace;
+use rs_html_parser_tokens::LocalName;
+
+/**
+ * Collects the text, a line break or whitespace is only written once the text after it is, so
+ * that whitespace at the start and the end of lines is dropped.
+ */
+#[derive(Default)]
+struct TextWriter {
+    text: String,
+    /** The number of line breaks that a block needs before the next text, at most 2. */
+    required_line_breaks: usize,
+    has_space: bool,
+}
+
+impl TextWriter {
+    fn start_text(&mut self) {
+        if self.text.is_empty() {
+            // Nothing is written before the first text
+        } else if self.required_line_breaks > 0 {
+            let line_breaks = self.text.len() - self.text.trim_end_matches('\n').len();
+
+            for _ in line_breaks..self.required_line_breaks {
+                self.text.push('\n');
+            }
+        } else if self.has_space && !self.text.ends_with(['\n', '\t']) {
+            self.text.push(' ');
+        }
+        self.required_line_breaks = 0;
+        self.has_space = false;
+    }
+
+    fn push_text(&mut self, text: &str) {
+        for c in text.chars() {
+            if is_html_whitespace(c) {
+                self.has_space = true;
+            } else {
+                self.start_text();
+                self.text.push(c);
+            }
+        }
+    }
+
+    fn push_preformatted(&mut self, text: &str) {
+        if !text.is_empty() {
+            self.start_text();
+            self.text.push_str(text);
+        }
+    }
+
+    /** Writes a character that ends a line or a cell, whitespace before it is dropped. */
+    fn push_separator(&mut self, c: char) {
+        self.has_space = false;
+        self.start_text();
+        self.text.push(c);
+    }
+
+    fn require_line_breaks(&mut self, count: usize) {
+        if count > 0 {
+            self.required_line_breaks = self.required_line_breaks.max(count);
+            self.has_space = false;
+        }
+    }
+}
+
+struct ListNumbers {
+    next: i64,
+    step: i64,
+}
+
+impl ListNumbers {
+    /** The number of the item, a `value` sets it and the numbers of the items after it. */
+    fn next(&mut self, document: &Document, item: NodeId) -> i64 {
+        if let Some(value) = document
+            .attribute(item, "value")
+            .and_then(|value| value.trim().parse().ok())
+        {
+            self.next = value;
+        }
+
+        let number = self.next;
+
+        self.next = self.next.saturating_add(self.step);
+        number
+    }
+}
+
+/** A step of writing the text of nodes, see `Document::write_text`. */
+enum Step<'a> {
+    /**
+     * A node, with the number of an item of an ordered list and whether it is in a preformatted
+     * element.
+     */
+    Node(NodeId, Option<i64>, bool),
+    Preformatted(&'a str),
+    Separator(char),
+    /** The line breaks after a block, once its children are written. */
+    LineBreaks(usize),
+}
+
+/** Whether the element is not rendered, so has no text. */
+pub(crate) fn is_hidden(document: &Document, id: NodeId, name: &LocalName) -> bool {
+    matches!(
+        name.element(),
+        LocalName::Head
+            | LocalName::Script
+            | LocalName::Style
+            | LocalName::Template
+            | LocalName::Noscript
+            | LocalName::Noembed
+            | LocalName::Noframes
+            | LocalName::Iframe
+            | LocalName::Canvas
+    ) || document.attribute(id, "hidden").is_some()
+        || document
+            .attribute(id, "aria-hidden")
+            .is_some_and(|value| value.trim().eq_ignore_ascii_case("true"))
+}
+
+fn is_cell(document: &Document, id: NodeId) -> bool {
+    document.is_element(id, &LocalName::Td) || document.is_element(id, &LocalName::Th)
+}
+
+impl Document {
+    /** Writes the text of the node and its descendants. */
+    fn write_text(&self, writer: &mut TextWriter, id: NodeId) {
+        // The nodes to write and what is written after their children, in reverse order
+        let mut stack = vec![Step::Node(id, None, false)];
+
+        while let Some(step) = stack.pop() {
+            let (id, number, is_preformatted) = match step {
+                Step::Node(id, number, is_preformatted) => (id, number, is_preformatted),
+                Step::Preformatted(text) => {
+                    writer.push_preformatted(text);
+                    continue;
+                }
+                Step::Separator(c) => {
+                    writer.push_separator(c);
+                    continue;
+                }
+                Step::LineBreaks(count) => {
+                    writer.require_line_breaks(count);
+                    continue;
+                }
+            };
+            let name = match &self[id].data {
+                NodeData::Text(text) if is_preformatted => {
+                    writer.push_preformatted(text);
+                    continue;
+                }
+                NodeData::Text(text) => {
+                    writer.push_text(text);
+                    continue;
+                }
+                NodeData::Element { name, .. } => name.element(),
+                NodeData::Document => LocalName::Html,
+                _ => continue,
+            };
+
+            if is_hidden(self, id, &name) {
+                continue;
+            }
+
+            let line_breaks = match name {
+                LocalName::Br => {
+                    writer.push_separator('\n');
+                    continue;
+                }
+                LocalName::P => 2,
+                LocalName::Td | LocalName::Th => 0,
+                _ if is_block_elements(&name) => 1,
+                _ => 0,
+            };
+
+            writer.require_line_breaks(line_breaks);
+            if let Some(number) = number {
+                writer.push_text(&format!("{}. ", number));
+            }
+
+            let is_preformatted = is_preformatted
+                || matches!(
+                    name,
+                    LocalName::Pre
+                        | LocalName::Textarea
+                        | LocalName::Listing
+                        | LocalName::Plaintext
+                        | LocalName::Xmp
+                );
+            let children = &self[id].children;
+            // Cells are separated by tabs, only blocks and rows end lines
+            let last_cell = children.iter().rposition(|child| is_cell(self, *child));
+            let mut numbers = (name == LocalName::Ol).then(|| self.list_numbers(id));
+            let mut steps = Vec::new();
+
+            for (index, child) in children.iter().enumerate() {
+                let number = match &mut numbers {
+                    Some(numbers) if self.is_element(*child, &LocalName::Li) => {
+                        Some(numbers.next(self, *child))
+                    }
+                    _ => None,
+                };
+
+                steps.push(match &self[*child].data {
+                    // A newline at the start of `pre` is not rendered
+                    NodeData::Text(text) if index == 0 && name == LocalName::Pre => {
+                        Step::Preformatted(text.strip_prefix('\n').unwrap_or(text))
+                    }
+                    _ => Step::Node(*child, number, is_preformatted),
+                });
+
+                if is_cell(self, *child) && Some(index) != last_cell {
+                    steps.push(Step::Separator('\t'));
+                }
+            }
+            stack.push(Step::LineBreaks(line_breaks));
+            stack.extend(steps.into_iter().rev());
+        }
+    }
+
+    /** Numbers the items of an ordered list from `start`, or down for a `reversed` list. */
+    fn list_numbers(&self, id: NodeId) -> ListNumbers {
+        let is_reversed = self.attribute(id, "reversed").is_some();
+        let start = self
+            .attribute(id, "start")
+            .and_then(|start| start.trim().parse().ok())
+            .unwrap_or_else(|| {
+                if is_reversed {
+                    self.element_children(id)
+                        .filter(|child| self.is_element(*child, &LocalName::Li))
+                        .count() as i64
+                } else {
+                    1
+                }
+            });
+
+        ListNumbers {
+            next: start,
+            step: if is_reversed { -1 } else { 1 },
+        }
+    }
+
+    /**
+     * The text of the node as it is rendered, in the spirit of `innerText` in the DOM. Blocks and
+     * `br` end lines, paragraphs are separated by an empty line, table cells by tabs and items of
+     * ordered lists are numbered. Whitespace is collapsed outside of preformatted elements, and
+     * elements that are not rendered, or are hidden with `hidden` or `aria-hidden`, are skipped.
+     */
+    pub fn inner_text(&self, id: NodeId) -> String {
+        let mut writer = TextWriter::default();
+
+        self.write_text(&mut writer, id);
+        writer.text
+    }
+}
@@ -0,0 +1,407 @@
+use crate::element_info::{
+    is_foreign_context_elements, is_html_integration_elements, is_plaintext, is_rawtext_elements,
+    is_script, is_void_elements,
+};
+use crate::{Parser, ParserOptions};
+use rs_html_parser_tokens::{Attributes, LocalName, Token, TokenKind};
+use std::fmt;
+use std::fmt::Write;
+use std::ops::Index;
+
+/** The index of a node in its `Document`. */
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NodeId(usize);
+
+impl NodeId {
+    /** The position of the node in its document, for tables with a value per node. */
+    pub(crate) fn index(self) -> usize {
+        self.0
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NodeData {
+    /** The root of the document, the only node without a parent. */
+    Document,
+    Element {
+        name: LocalName,
+        attrs: Attributes,
+    },
+    Text(Box<str>),
+    /** A comment, or a CDATA section. */
+    Comment(Box<str>),
+    /**
+     * A declaration or a processing instruction without its `<!` or `<?`, eg. `DOCTYPE html` or
+     * `xml version="1.0"?`. Processing instructions end with `?`.
+     */
+    ProcessingInstruction(Box<str>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Node {
+    pub data: NodeData,
+    pub parent: Option<NodeId>,
+    pub children: Vec<NodeId>,
+}
+
+/**
+ * The element tree of a document, built from the tokens of the parser. The nodes are stored in
+ * document order, so comparing two `NodeId`s compares their position in the document.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Document {
+    nodes: Vec<Node>,
+}
+
+impl Document {
+    pub fn parse(html: &str, options: &ParserOptions) -> Document {
+        Document::from_tokens(Parser::new(html, options))
+    }
+
+    /**
+     * Builds the tree from a token stream of the parser. The elements of the tokens have to be
+     * properly nested, as the parser emits them.
+     */
+    pub fn from_tokens<I: IntoIterator<Item = Token>>(tokens: I) -> Document {
+        let mut document = Document {
+            nodes: vec![Node {
+                data: NodeData::Document,
+                parent: None,
+                children: Vec::new(),
+            }],
+        };
+        let mut current = document.root();
+
+        for token in tokens {
+            let data = match token.kind {
+                TokenKind::OpenTag => NodeData::Element {
+                    name: token.name().unwrap(),
+                    attrs: token
+                        .attributes()
+                        .map(|attrs| attrs.into_owned())
+                        .unwrap_or_default(),
+                },
+                TokenKind::CloseTag => {
+                    current = document[current].parent.unwrap_or(current);
+                    continue;
+                }
+                TokenKind::Text => NodeData::Text(token.data.to_string().into()),
+                TokenKind::Comment => NodeData::Comment(token.data.to_string().into()),
+                TokenKind::ProcessingInstruction => {
+                    NodeData::ProcessingInstruction(token.data.to_string().into())
+                }
+                TokenKind::CommentEnd => continue,
+            };
+            let is_element = matches!(data, NodeData::Element { .. });
+            let id = document.push(current, data);
+
+            if is_element {
+                current = id;
+            }
+        }
+
+        document
+    }
+
+    fn push(&mut self, parent: NodeId, data: NodeData) -> NodeId {
+        let id = NodeId(self.nodes.len());
+
+        self.nodes.push(Node {
+            data,
+            parent: Some(parent),
+            children: Vec::new(),
+        });
+        self.nodes[parent.0].children.push(id);
+
+        id
+    }
+
+    /**
+     * Copies the node and its descendants to the end of the children of `parent`, without the
+     * nodes, and their descendants, that `skip` is true for.
+     */
+    pub(crate) fn append_copy(
+        &mut self,
+        parent: NodeId,
+        source: &Document,
+        id: NodeId,
+        skip: &dyn Fn(NodeId) -> bool,
+    ) {
+        // The nodes to copy and the parents of their copies, in document order
+        let mut stack = vec![(id, parent)];
+
+        while let Some((id, parent)) = stack.pop() {
+            if skip(id) {
+                continue;
+            }
+
+            let copy = self.push(parent, source[id].data.clone());
+
+            stack.extend(source[id].children.iter().rev().map(|child| (*child, copy)));
+        }
+    }
+
+    pub fn root(&self) -> NodeId {
+        NodeId(0)
+    }
+
+    /** All the nodes in document order, starting with the root. */
+    pub(crate) fn nodes(&self) -> impl DoubleEndedIterator<Item = NodeId> {
+        (0..self.nodes.len()).map(NodeId)
+    }
+
+    /** The number of nodes, including the root. */
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /** Whether the document has no nodes besides the root. */
+    pub fn is_empty(&self) -> bool {
+        self.nodes.len() == 1
+    }
+
+    /** The element name of the node, if it is an element. */
+    pub fn name(&self, id: NodeId) -> Option<&LocalName> {
+        match &self[id].data {
+            NodeData::Element { name, .. } => Some(name),
+            _ => None,
+        }
+    }
+
+    /** The attributes of the node, if it is an element. */
+    pub fn attributes(&self, id: NodeId) -> Option<&Attributes> {
+        match &self[id].data {
+            NodeData::Element { attrs, .. } => Some(attrs),
+            _ => None,
+        }
+    }
+
+    /** The value of an attribute, ignoring ASCII case. An attribute without a value is empty. */
+    pub fn attribute(&self, id: NodeId, name: &str) -> Option<&str> {
+        let value = self.attributes(id)?.get(name)?;
+
+        Some(value.as_ref().map_or("", |(value, _)| value))
+    }
+
+    /** Whether the node is an element with the name, ignoring ASCII case. */
+    pub fn is_element(&self, id: NodeId, name: &LocalName) -> bool {
+        self.name(id)
+            .is_some_and(|element| element.as_str().eq_ignore_ascii_case(name.as_str()))
+    }
+
+    /** The nodes below the node in document order, not including the node itself. */
+    pub fn descendants(&self, id: NodeId) -> Descendants<'_> {
+        Descendants {
+            document: self,
+            stack: self[id].children.iter().rev().copied().collect(),
+        }
+    }
+
+    /** The element children of the node. */
+    pub fn element_children(&self, id: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        self[id]
+            .children
+            .iter()
+            .copied()
+            .filter(|child| self.name(*child).is_some())
+    }
+
+    /** The text of the node and its descendants, as `textContent` in the DOM. */
+    pub fn text_content(&self, id: NodeId) -> String {
+        let mut text = String::new();
+
+        if let NodeData::Text(data) = &self[id].data {
+            text.push_str(data);
+        }
+        for node in self.descendants(id) {
+            if let NodeData::Text(data) = &self[node].data {
+                text.push_str(data);
+            }
+        }
+
+        text
+    }
+
+    /**
+     * Whether the node is in foreign content, below an `svg` or `math` element but not below an
+     * HTML integration point like `foreignObject`. The text of eg. `style` is not raw text there.
+     */
+    fn is_in_foreign_content(&self, id: NodeId) -> bool {
+        let mut ancestor = self[id].parent;
+
+        while let Some(id) = ancestor {
+            match self.name(id) {
+                Some(name) if is_foreign_context_elements(name) => return true,
+                Some(name) if is_html_integration_elements(name) => return false,
+                _ => ancestor = self[id].parent,
+            }
+        }
+        false
+    }
+
+    /** Whether the children of the node are in foreign content, see `is_in_foreign_content`. */
+    fn has_foreign_children(&self, id: NodeId, is_in_foreign_content: bool) -> bool {
+        match self.name(id) {
+            Some(name) if is_foreign_context_elements(name) => true,
+            Some(name) if is_html_integration_elements(name) => false,
+            _ => is_in_foreign_content,
+        }
+    }
+
+    /** The markup of the node, including the node itself. */
+    pub fn outer_html(&self, id: NodeId) -> String {
+        let mut html = String::new();
+
+        self.write_nodes(&mut html, &[id], self.is_in_foreign_content(id));
+        html
+    }
+
+    /** The markup of the children of the node. */
+    pub fn inner_html(&self, id: NodeId) -> String {
+        let mut html = String::new();
+        let is_foreign = self.has_foreign_children(id, self.is_in_foreign_content(id));
+
+        self.write_nodes(&mut html, &self[id].children, is_foreign);
+        html
+    }
+
+    /** Writes the markup of the nodes, they are siblings with the same foreign content state. */
+    fn write_nodes(&self, html: &mut String, ids: &[NodeId], is_in_foreign_content: bool) {
+        // The nodes to write and the end tags of the elements whose children are written
+        let mut stack: Vec<Step> = ids
+            .iter()
+            .rev()
+            .map(|id| Step::Node(*id, is_in_foreign_content))
+            .collect();
+
+        while let Some(step) = stack.pop() {
+            let (id, is_foreign) = match step {
+                Step::Node(id, is_foreign) => (id, is_foreign),
+                Step::EndTag(id) => {
+                    if let Some(name) = self.name(id) {
+                        let _ = write!(html, "</{}>", name);
+                    }
+                    continue;
+                }
+            };
+            let has_foreign_children = self.has_foreign_children(id, is_foreign);
+            let push_children = |stack: &mut Vec<Step>| {
+                stack.extend(
+                    self[id]
+                        .children
+                        .iter()
+                        .rev()
+                        .map(|child| Step::Node(*child, has_foreign_children)),
+                );
+            };
+
+            match &self[id].data {
+                NodeData::Document => push_children(&mut stack),
+                NodeData::Element { name, attrs } => {
+                    html.push('<');
+                    html.push_str(name.as_str());
+                    for (key, value) in attrs.iter() {
+                        html.push(' ');
+                        html.push_str(key);
+                        if let Some((value, _)) = value {
+                            html.push_str("=\"");
+                            escape(html, value, true);
+                            html.push('"');
+                        }
+                    }
+                    html.push('>');
+
+                    if is_void_elements(name) && self[id].children.is_empty() {
+                        continue;
+                    }
+                    stack.push(Step::EndTag(id));
+                    push_children(&mut stack);
+                }
+                NodeData::Text(data) => {
+                    // Text in foreign content was decoded, even in eg. `<svg><style>`
+                    let is_raw = !is_foreign
+                        && self[id]
+                            .parent
+                            .and_then(|parent| self.name(parent))
+                            .is_some_and(|parent| {
+                                is_rawtext_elements(parent)
+                                    || is_script(parent)
+                                    || is_plaintext(parent)
+                            });
+
+                    if is_raw {
+                        html.push_str(data);
+                    } else {
+                        escape(html, data, false);
+                    }
+                }
+                NodeData::Comment(data) => {
+                    let _ = write!(html, "<!--{}-->", data);
+                }
+                NodeData::ProcessingInstruction(data) => {
+                    let _ = write!(html, "{}", ProcessingInstruction(data));
+                }
+            }
+        }
+    }
+}
+
+/** A step of writing the markup of nodes, see `Document::write_nodes`. */
+enum Step {
+    /** A node and whether it is in foreign content. */
+    Node(NodeId, bool),
+    /** The end tag of an element, after its children. */
+    EndTag(NodeId),
+}
+
+/** Escapes text for markup, attribute values are double quoted. */
+fn escape(html: &mut String, value: &str, is_attribute: bool) {
+    for c in value.chars() {
+        match c {
+            '&' => html.push_str("&amp;"),
+            '\u{a0}' => html.push_str("&nbsp;"),
+            '"' if is_attribute => html.push_str("&quot;"),
+            '<' if !is_attribute => html.push_str("&lt;"),
+            '>' if !is_attribute => html.push_str("&gt;"),
+            _ => html.push(c),
+        }
+    }
+}
+
+/** Formats the markup of a `NodeData::ProcessingInstruction`. */
+pub struct ProcessingInstruction<'a>(pub &'a str);
+
+impl fmt::Display for ProcessingInstruction<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let start = if self.0.ends_with('?') { '?' } else { '!' };
+
+        write!(f, "<{}{}>", start, self.0)
+    }
+}
+
+impl Index<NodeId> for Document {
+    type Output = Node;
+
+    fn index(&self, id: NodeId) -> &Node {
+        &self.nodes[id.0]
+    }
+}
+
+/** Iterates over the descendants of a node in document order, see `Document::descendants`. */
+pub struct Descendants<'a> {
+    document: &'a Document,
+    stack: Vec<NodeId>,
+}
+
+impl Iterator for Descendants<'_> {
+    type Item = NodeId;
+
+    fn next(&mut self) -> Option<NodeId> {
+        let id = self.stack.pop()?;
+
+        self.stack
+            .extend(self.document[id].children.iter().rev().copied());
+
+        Some(id)
+    }
+}
@@ -0,0 +1,165 @@
+/** The schemes that have a host, for which a backslash is a slash as in browsers. */
+const SPECIAL_SCHEMES: [&str; 6] = ["http", "https", "ftp", "ws", "wss", "file"];
+
+/** An absolute URL split into its parts, as in RFC 3986. */
+struct Url<'a> {
+    scheme: &'a str,
+    authority: Option<&'a str>,
+    path: &'a str,
+    query: Option<&'a str>,
+}
+
+/** The scheme of the URL, if it is absolute. */
+fn scheme(url: &str) -> Option<&str> {
+    let end = url.find(':')?;
+    let scheme = &url[..end];
+    let mut chars = scheme.chars();
+
+    (chars.next()?.is_ascii_alphabetic()
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.')))
+    .then_some(scheme)
+}
+
+fn parse(url: &str) -> Option<Url<'_>> {
+    let scheme = scheme(url)?;
+    let rest = &url[scheme.len() + 1..];
+    let rest = rest.split_once('#').map_or(rest, |(rest, _)| rest);
+    let (rest, query) = match rest.split_once('?') {
+        Some((rest, query)) => (rest, Some(query)),
+        None => (rest, None),
+    };
+    let (authority, path) = match rest.strip_prefix("//") {
+        Some(rest) => {
+            let end = rest.find('/').unwrap_or(rest.len());
+
+            (Some(&rest[..end]), &rest[end..])
+        }
+        None => (None, rest),
+    };
+
+    Some(Url {
+        scheme,
+        authority,
+        path,
+        query,
+    })
+}
+
+/** Removes the `.` and `..` segments of an absolute path, as in RFC 3986. */
+fn remove_dot_segments(path: &str) -> String {
+    let mut segments: Vec<&str> = Vec::new();
+    // The empty segment before the leading slash is skipped
+    let mut parts = path.split('/').skip(1).peekable();
+
+    while let Some(segment) = parts.next() {
+        match segment {
+            "." | ".." => {
+                if segment == ".." {
+                    segments.pop();
+                }
+                // A path that ends with a dot segment ends with a slash
+                if parts.peek().is_none() {
+                    segments.push("");
+                }
+            }
+            _ => segments.push(segment),
+        }
+    }
+    format!("/{}", segments.join("/"))
+}
+
+/**
+ * Resolves a URL reference against an absolute base URL, as a browser resolves an `href`.
+ * Whitespace around the reference and tabs and newlines in it are removed. Returns `None` when
+ * the reference is relative and there is no base, or the base can't have relative references.
+ */
+pub(crate) fn resolve_url(base: Option<&str>, reference: &str) -> Option<String> {
+    let reference: String = reference
+        .trim_matches(|c: char| c <= ' ')
+        .chars()
+        .filter(|c| !matches!(c, '\t' | '\n' | '\r'))
+        .collect();
+
+    if scheme(&reference).is_some() {
+        return Some(reference);
+    }
+
+    let base = parse(base?)?;
+    let is_special = SPECIAL_SCHEMES
+        .iter()
+        .any(|special| base.scheme.eq_ignore_ascii_case(special));
+    let reference = if is_special {
+        reference.replace('\\', "/")
+    } else {
+        reference
+    };
+    let with_query = |query: Option<&str>| match query {
+        Some(query) => format!("?{}", query),
+        None => String::new(),
+    };
+
+    if let Some(fragment) = reference.strip_prefix('#') {
+        return Some(format!(
+            "{}:{}{}{}#{}",
+            base.scheme,
+            base.authority
+                .map_or(String::new(), |authority| format!("//{}", authority)),
+            base.path,
+            with_query(base.query),
+            fragment
+        ));
+    }
+
+    let authority = base.authority?;
+    let (reference, fragment) = match reference.split_once('#') {
+        Some((reference, fragment)) => (reference, Some(fragment)),
+        None => (reference.as_str(), None),
+    };
+
+    if reference.starts_with("//") {
+        let resolved = format!("{}:{}", base.scheme, reference);
+
+        return Some(match fragment {
+            Some(fragment) => format!("{}#{}", resolved, fragment),
+            None => resolved,
+        });
+    }
+
+    let (reference_path, query) = match reference.split_once('?') {
+        Some((path, query)) => (path, Some(query)),
+        None => (reference, None),
+    };
+    let (path, query) = if reference_path.is_empty() {
+        (base.path.to_string(), query.or(base.query))
+    } else if reference_path.starts_with('/') {
+        (remove_dot_segments(reference_path), query)
+    } else {
+        let directory = match base.path.rfind('/') {
+            Some(end) => &base.path[..=end],
+            None => "/",
+        };
+
+        (
+            remove_dot_segments(&format!("{}{}", directory, reference_path)),
+            query,
+        )
+    };
+    let path = if path.is_empty() && is_special {
+        "/".to_string()
+    } else {
+        path
+    };
+    let mut resolved = format!(
+        "{}://{}{}{}",
+        base.scheme,
+        authority,
+        path,
+        with_query(query)
+    );
+
+    if let Some(fragment) = fragment {
+        resolved.push('#');
+        resolved.push_str(fragment);
+    }
+    Some(resolved)
+}
@@ -0,0 +1,177 @@
+use crate::element_info::is_void_elements;
+use rs_html_parser_tokens::{Token, TokenData, TokenKind};
+use std::fmt;
+use std::mem::take;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StreamErrorKind {
+    /// A `CloseTag` that does not match the innermost open element.
+    MismatchedCloseTag {
+        expected: Option<Box<str>>,
+        found: Box<str>,
+    },
+    /// A void element that is not closed right after it is opened.
+    UnclosedVoidElement(Box<str>),
+    /// A `Comment` that is not followed by a `CommentEnd`.
+    UnclosedComment,
+    /// A `CommentEnd` without a `Comment` before it.
+    UnexpectedCommentEnd,
+    /// Elements that are still open at the end of the stream, innermost first.
+    UnclosedElements(Vec<Box<str>>),
+    /// A token after the end of the stream.
+    TokenAfterEnd,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StreamError {
+    /** Index of the offending token, the end of the stream counts as a token. */
+    pub index: usize,
+    pub kind: StreamErrorKind,
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid token stream at token {}: ", self.index)?;
+
+        match &self.kind {
+            StreamErrorKind::MismatchedCloseTag {
+                expected: Some(expected),
+                found,
+            } => write!(f, "expected </{}>, found </{}>", expected, found),
+            StreamErrorKind::MismatchedCloseTag {
+                expected: None,
+                found,
+            } => write!(f, "found </{}> without open elements", found),
+            StreamErrorKind::UnclosedVoidElement(name) => {
+                write!(f, "void element <{}> is not closed immediately", name)
+            }
+            StreamErrorKind::UnclosedComment => write!(f, "comment is not followed by its end"),
+            StreamErrorKind::UnexpectedCommentEnd => write!(f, "comment end without a comment"),
+            StreamErrorKind::UnclosedElements(names) => {
+                write!(f, "{} elements are not closed", names.len())
+            }
+            StreamErrorKind::TokenAfterEnd => write!(f, "token after the end of the stream"),
+        }
+    }
+}
+
+impl std::error::Error for StreamError {}
+
+/**
+ * Checks the guarantees of the parser token by token:
+ * - every `OpenTag` has exactly one matching `CloseTag`, and they are properly nested
+ * - void elements are closed right after they are opened (not in xml mode)
+ * - every `Comment` is directly followed by a `CommentEnd`
+ * - nothing comes after the end of the stream
+ */
+#[derive(Debug, Default)]
+pub struct StreamValidator {
+    xml_mode: bool,
+    stack: Vec<TokenData>,
+    open_void: Option<TokenData>,
+    in_comment: bool,
+    index: usize,
+    has_ended: bool,
+}
+
+impl StreamValidator {
+    pub fn new(xml_mode: bool) -> StreamValidator {
+        StreamValidator {
+            xml_mode,
+            ..Default::default()
+        }
+    }
+
+    /** Checks the next item of the stream, `None` is the end of the stream. */
+    pub fn check(&mut self, token: Option<&Token>) -> Result<(), StreamError> {
+        let index = self.index;
+        self.index += 1;
+
+        let error = |kind| Err(StreamError { index, kind });
+
+        let Some(token) = token else {
+            if self.has_ended {
+                return Ok(());
+            }
+            self.has_ended = true;
+
+            if let Some(name) = self.open_void.take() {
+                return error(StreamErrorKind::UnclosedVoidElement((*name).into()));
+            }
+            if self.in_comment {
+                return error(StreamErrorKind::UnclosedComment);
+            }
+            if !self.stack.is_empty() {
+                let names = self.stack.drain(..).rev().map(|name| (*name).into()).collect();
+
+                return error(StreamErrorKind::UnclosedElements(names));
+            }
+
+            return Ok(());
+        };
+
+        if self.has_ended {
+            return error(StreamErrorKind::TokenAfterEnd);
+        }
+        let follows_comment = take(&mut self.in_comment);
+
+        if follows_comment && token.kind != TokenKind::CommentEnd {
+            return error(StreamErrorKind::UnclosedComment);
+        }
+        if let Some(name) = self.open_void.take() {
+            if token.kind != TokenKind::CloseTag || token.data != name {
+                return error(StreamErrorKind::UnclosedVoidElement((*name).into()));
+            }
+        }
+
+        match token.kind {
+            TokenKind::OpenTag => {
+                if !self.xml_mode && token.name().is_some_and(|name| is_void_elements(&name)) {
+                    self.open_void = Some(token.data.clone());
+                }
+                self.stack.push(token.data.clone());
+            }
+            TokenKind::CloseTag => {
+                if self.stack.last() != Some(&token.data) {
+                    return error(StreamErrorKind::MismatchedCloseTag {
+                        expected: self.stack.last().map(|name| (**name).into()),
+                        found: (*token.data).into(),
+                    });
+                }
+                self.stack.pop();
+            }
+            TokenKind::Comment => self.in_comment = true,
+            TokenKind::CommentEnd => {
+                if !follows_comment {
+                    return error(StreamErrorKind::UnexpectedCommentEnd);
+                }
+            }
+            TokenKind::Text | TokenKind::ProcessingInstruction => {}
+        }
+
+        Ok(())
+    }
+}
+
+/**
+ * Validates a complete token stream, see `StreamValidator`. The stream is polled once more after
+ * it has ended, to check that nothing comes after the end.
+ */
+pub fn validate_stream<I>(tokens: I, xml_mode: bool) -> Result<(), StreamError>
+where
+    I: IntoIterator<Item = Token>,
+{
+    let mut tokens = tokens.into_iter();
+    let mut validator = StreamValidator::new(xml_mode);
+
+    loop {
+        let token = tokens.next();
+        let is_end = token.is_none();
+
+        validator.check(token.as_ref())?;
+
+        if is_end {
+            return validator.check(tokens.next().as_ref());
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use std::borrow::Cow;
+
+/**
+ * How the parser treats whitespace in text.
+ *
+ * Text inside `<pre>`, `<textarea>`, `<listing>`, elements with `xml:space="preserve"` and raw text
+ * elements (eg. `<script>`) is always kept as is. A leading newline directly after `<pre>`,
+ * `<textarea>` and `<listing>` is removed, like the spec does.
+ */
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum WhitespaceMode {
+    /// Keep all whitespace.
+    Preserve,
+    /// Drop text that consists only of whitespace, eg. the indentation between elements.
+    DropInterElement,
+    /// Collapse each run of whitespace into a single space, like `white-space: normal`.
+    Collapse,
+    /// Collapse whitespace and remove it from the start and the end of the text.
+    Trim,
+}
+
+pub fn is_html_whitespace(c: char) -> bool {
+    matches!(c, ' ' | '\t' | '\n' | '\x0C' | '\r')
+}
+
+pub fn is_whitespace_only(text: &str) -> bool {
+    text.chars().all(is_html_whitespace)
+}
+
+pub fn strip_leading_newline(text: &str) -> &str {
+    text.strip_prefix("\r\n")
+        .or_else(|| text.strip_prefix('\n'))
+        .or_else(|| text.strip_prefix('\r'))
+        .unwrap_or(text)
+}
+
+pub fn collapse_whitespace(text: &str) -> Cow<'_, str> {
+    let mut previous_whitespace = false;
+    let needs_collapse = text.chars().any(|c| {
+        let needs = is_html_whitespace(c) && (c != ' ' || previous_whitespace);
+        previous_whitespace = is_html_whitespace(c);
+
+        needs
+    });
+
+    if !needs_collapse {
+        return Cow::Borrowed(text);
+    }
+
+    let mut collapsed = String::with_capacity(text.len());
+    previous_whitespace = false;
+
+    for c in text.chars() {
+        if is_html_whitespace(c) {
+            if !previous_whitespace {
+                collapsed.push(' ');
+            }
+            previous_whitespace = true;
+        } else {
+            collapsed.push(c);
+            previous_whitespace = false;
+        }
+    }
+
+    Cow::Owned(collapsed)
+}
+
+/** Collapses the whitespace like `collapse_whitespace` and removes it from both ends. */
+pub fn collapse_and_trim_whitespace(text: &str) -> Cow<'_, str> {
+    match collapse_whitespace(text) {
+        Cow::Borrowed(value) => Cow::Borrowed(value.trim_matches(is_html_whitespace)),
+        Cow::Owned(value) => Cow::Owned(value.trim_matches(is_html_whitespace).to_string()),
+    }
+}
+
+/** Applies the whitespace mode to a run of text, `None` means the text is dropped. */
+pub fn apply_whitespace_mode(mode: WhitespaceMode, text: &str) -> Option<Cow<'_, str>> {
+    let result = match mode {
+        WhitespaceMode::Preserve => Cow::Borrowed(text),
+        WhitespaceMode::DropInterElement => {
+            if is_whitespace_only(text) {
+                return None;
+            }
+
+            Cow::Borrowed(text)
+        }
+        WhitespaceMode::Collapse => collapse_whitespace(text),
+        WhitespaceMode::Trim => collapse_and_trim_whitespace(text),
+    };
+
+    if result.is_empty() {
+        None
+    } else {
+        Some(result)
+    }
+}
@@ -0,0 +1,113 @@
+mod tests {
+    use rs_html_parser::{ArticleOptions, Document, ParserOptions};
+
+    const PARAGRAPH: &str = concat!(
+        "The committee met on Tuesday, after months of delays, to discuss the budget, the new ",
+        "building and the plans for the next year, which were approved without changes."
+    );
+
+    fn page(article: &str) -> String {
+        format!(
+            concat!(
+                "<html><head><title>City budget approved after long delays | The Daily Example</title>",
+                "<script>var a = 1;</script></head><body>",
+                "<div id=header><ul class=menu><li><a href=/>Home</a><li><a href=/news>News</a></ul></div>",
+                "<div class=sidebar><p>Subscribe to our newsletter, it is free, weekly and short.</p></div>",
+                "{}",
+                "<div class=comments><p>I disagree with this, the budget is far too large, in my opinion.</p></div>",
+                "<div id=footer><a href=/about>About us</a> <a href=/contact>Contact</a></div>",
+                "</body></html>"
+            ),
+            article
+        )
+    }
+
+    #[test]
+    fn extracts_the_main_content() {
+        let html = page(&format!(
+            concat!(
+                "<div class=story><h1>Budget approved</h1>",
+                "<span class=byline>By A. Writer</span>",
+                "<img src=/committee.jpg width=600><p>{0}</p><p>{0}</p>",
+                "<div class=share><a href=/share>Share</a></div><p>{0}</p></div>"
+            ),
+            PARAGRAPH
+        ));
+        let document = Document::parse(&html, &ParserOptions::default());
+        let article = document.article(&ArticleOptions::default()).unwrap();
+
+        assert_eq!(
+            article.title.as_deref(),
+            Some("City budget approved after long delays")
+        );
+        assert_eq!(article.byline.as_deref(), Some("By A. Writer"));
+        assert_eq!(article.lead_image.as_deref(), Some("/committee.jpg"));
+        assert_eq!(
+            article.content.inner_text(article.content.root()),
+            format!("Budget approved\n\n{0}\n\n{0}\n\n{0}", PARAGRAPH)
+        );
+    }
+
+    #[test]
+    fn prefers_metadata() {
+        let html = format!(
+            concat!(
+                "<meta property=og:title content='The budget'><meta name=author content='B. Writer'>",
+                "<meta property=og:image content=https://example.com/image.jpg>{}"
+            ),
+            page(&format!("<article><p>{0}</p><p>{0}</p></article>", PARAGRAPH))
+        );
+        let document = Document::parse(&html, &ParserOptions::default());
+        let article = document.article(&ArticleOptions::default()).unwrap();
+
+        assert_eq!(article.title.as_deref(), Some("The budget"));
+        assert_eq!(article.byline.as_deref(), Some("B. Writer"));
+        assert_eq!(
+            article.lead_image.as_deref(),
+            Some("https://example.com/image.jpg")
+        );
+    }
+
+    #[test]
+    fn keeps_siblings_of_the_content() {
+        let html = page(&format!(
+            "<div><div class=text><p>{0}</p><p>{0}</p></div><p>{0}</p><div class=text><p>{0}</p></div></div>",
+            PARAGRAPH
+        ));
+        let document = Document::parse(&html, &ParserOptions::default());
+        let article = document.article(&ArticleOptions::default()).unwrap();
+
+        assert_eq!(
+            article.content.inner_text(article.content.root()),
+            [PARAGRAPH; 4].join("\n\n")
+        );
+    }
+
+    #[test]
+    fn returns_nothing_without_text() {
+        let document = Document::parse(
+            "<html><body><img src=a.png></body></html>",
+            &ParserOptions::default(),
+        );
+
+        assert!(document.article(&ArticleOptions::default()).is_none());
+    }
+
+    #[test]
+    fn extracts_from_deeply_nested_documents() {
+        let html = format!(
+            "{}<figure><div><img src=a.jpg><img src=b.jpg></div></figure><p>{1}</p><p>{1}</p>",
+            "<div class=text>".repeat(20_000),
+            PARAGRAPH
+        );
+        let document = Document::parse(&html, &ParserOptions::default());
+        let article = document.article(&ArticleOptions::default()).unwrap();
+
+        // The images in a figure are content
+        assert_eq!(article.lead_image.as_deref(), Some("a.jpg"));
+        assert_eq!(
+            article.content.inner_text(article.content.root()),
+            [PARAGRAPH; 2].join("\n\n")
+        );
+    }
+}
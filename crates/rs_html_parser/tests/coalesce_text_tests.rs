@@ -0,0 +1,33 @@
+mod tests {
+    use rs_html_parser::{Parser, ParserOptions};
+    use rs_html_parser_tokens::Token;
+
+    fn parser_test(data: &str, coalesce_text: bool) -> Vec<Token> {
+        let options = ParserOptions {
+            coalesce_text: Some(coalesce_text),
+            ..Default::default()
+        };
+
+        Parser::new(data, &options).collect()
+    }
+
+    #[test]
+    fn text_and_entities_are_merged() {
+        insta::assert_debug_snapshot!(parser_test("<p>a &amp; b &lt;c&gt; d</p>", true))
+    }
+
+    #[test]
+    fn ignored_end_tags_do_not_split_text() {
+        insta::assert_debug_snapshot!(parser_test("a</b>&amp;</i>c", true))
+    }
+
+    #[test]
+    fn comments_split_text() {
+        insta::assert_debug_snapshot!(parser_test("a<!-- b -->&amp;", true))
+    }
+
+    #[test]
+    fn disabled() {
+        insta::assert_debug_snapshot!(parser_test("<p>a &amp; b</p>", false))
+    }
+}
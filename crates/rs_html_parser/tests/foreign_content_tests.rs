@@ -0,0 +1,41 @@
+mod test_utils;
+
+mod tests {
+    use crate::test_utils::*;
+
+    #[test]
+    fn svg_style_with_child_elements() {
+        insta::assert_debug_snapshot!(parser_test(
+            "<svg><style>.a{}<circle/></style></svg><style><b></b></style>"
+        ))
+    }
+
+    #[test]
+    fn svg_title_is_not_rcdata() {
+        insta::assert_debug_snapshot!(parser_test(
+            "<svg><title><tspan>a</tspan></title></svg><title><b>&amp;</b></title>"
+        ))
+    }
+
+    #[test]
+    fn cdata_in_foreign_content() {
+        insta::assert_debug_snapshot!(parser_test("<math><![CDATA[a<b]]></math>"))
+    }
+
+    #[test]
+    fn cdata_in_html_is_bogus_comment() {
+        insta::assert_debug_snapshot!(parser_test("<div><![CDATA[a]]></div>"))
+    }
+
+    #[test]
+    fn raw_text_elements() {
+        insta::assert_debug_snapshot!(parser_test(
+            "<xmp><b>a</b></xmp><iframe><p>b</p></iframe><noembed>&amp;</noembed>"
+        ))
+    }
+
+    #[test]
+    fn textarea_decodes_entities() {
+        insta::assert_debug_snapshot!(parser_test("<textarea>a &lt;b&gt;</textarea>"))
+    }
+}
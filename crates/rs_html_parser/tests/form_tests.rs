@@ -0,0 +1,184 @@
+mod tests {
+    use rs_html_parser::{
+        ControlKind, Document, Form, FormEnctype, FormMethod, FormValue, ParserOptions,
+    };
+
+    fn forms(html: &str) -> Vec<Form> {
+        Document::parse(html, &ParserOptions::default()).forms()
+    }
+
+    fn text(value: &str) -> FormValue {
+        FormValue::Text(value.to_string())
+    }
+
+    #[test]
+    fn reads_the_form_attributes() {
+        let forms = forms(concat!(
+            "<form action=/search method=POST enctype=multipart/form-data></form>",
+            "<form method=put enctype=text/plain></form><form></form>"
+        ));
+
+        assert_eq!(forms[0].action.as_deref(), Some("/search"));
+        assert_eq!(forms[0].method, FormMethod::Post);
+        assert_eq!(forms[0].enctype, FormEnctype::Multipart);
+        assert_eq!(forms[1].method, FormMethod::Get);
+        assert_eq!(forms[1].enctype, FormEnctype::TextPlain);
+        assert_eq!(forms[2].action, None);
+        assert_eq!(forms[2].enctype, FormEnctype::UrlEncoded);
+    }
+
+    #[test]
+    fn applies_default_values() {
+        let forms = forms(concat!(
+            "<form><input name=a value='x\ny'><input type=checkbox name=b checked>",
+            "<input type=RANGE name=c min=10><input type=color name=d value=#ABCDEF>",
+            "<input type=color name=e value=red><input type=number name=f value=x>",
+            "<input type=unknown name=g><input type=email name=h value=' a@b.c '>",
+            "<textarea name=i>\nline\r\nnext &amp; <b></textarea><button name=j>Go</button></form>"
+        ));
+        let controls: Vec<_> = forms[0]
+            .controls
+            .iter()
+            .map(|control| (control.name.as_deref().unwrap(), control.value.as_str()))
+            .collect();
+
+        assert_eq!(
+            controls,
+            [
+                ("a", "xy"),
+                ("b", "on"),
+                ("c", "55"),
+                ("d", "#abcdef"),
+                ("e", "#000000"),
+                ("f", ""),
+                ("g", ""),
+                ("h", "a@b.c"),
+                ("i", "line\nnext & <b>"),
+                ("j", "")
+            ]
+        );
+        assert_eq!(
+            forms[0].controls[6].kind,
+            ControlKind::Input("text".to_string())
+        );
+        assert_eq!(
+            forms[0].controls[9].kind,
+            ControlKind::Button("submit".to_string())
+        );
+    }
+
+    #[test]
+    fn selects_options() {
+        let forms = forms(concat!(
+            "<form><select name=a><option disabled>x<option> y  z <option>w</select>",
+            "<select name=b><option selected>1<option selected value=2>two</select>",
+            "<select name=c multiple><option selected>1<optgroup disabled><option selected>2",
+            "</optgroup><option label=Three selected>3</select>",
+            "<select name=d size=3><option>1</select></form>"
+        ));
+        let controls = &forms[0].controls;
+
+        assert_eq!(controls[0].value, "y z");
+        assert_eq!(controls[1].value, "2");
+        let ControlKind::Select {
+            is_multiple,
+            options,
+        } = &controls[2].kind
+        else {
+            panic!("not a select");
+        };
+        assert!(is_multiple);
+        assert_eq!(options[2].label, "Three");
+        assert!(options.iter().all(|option| option.is_selected));
+        assert!(options[1].is_disabled);
+        assert_eq!(controls[3].value, "");
+        assert_eq!(
+            forms[0].form_data(None).entries,
+            [
+                ("a".to_string(), text("y z")),
+                ("b".to_string(), text("2")),
+                ("c".to_string(), text("1")),
+                ("c".to_string(), text("3"))
+            ]
+        );
+    }
+
+    #[test]
+    fn finds_the_form_owner_and_disabled_state() {
+        let forms = forms(concat!(
+            "<form id=f><input name=a><fieldset disabled><legend><input name=b></legend>",
+            "<input name=c></fieldset><input name=d disabled></form>",
+            "<input name=e form=f><input name=f form=missing><input name=g>",
+            "<form><datalist><span><input name=h></span></datalist><input name=i></form>"
+        ));
+        let names = |form: &Form| -> Vec<_> {
+            form.controls
+                .iter()
+                .filter(|control| !control.is_disabled)
+                .map(|control| control.name.clone().unwrap())
+                .collect()
+        };
+
+        assert_eq!(names(&forms[0]), ["a", "b", "e"]);
+        assert_eq!(forms[0].controls.len(), 5);
+        assert_eq!(names(&forms[1]), ["i"]);
+    }
+
+    #[test]
+    fn builds_the_form_data_set() {
+        let forms = forms(concat!(
+            "<form><input type=radio name=r value=1 checked><input type=radio name=r value=2 checked>",
+            "<input type=checkbox name=c><input type=hidden name=_charset_>",
+            "<input type=file name=file><input type=submit name=s value=Send>",
+            "<input type=image name=i><button name=b value=x>B</button>",
+            "<input type=reset name=reset><input name=''></form>"
+        ));
+        let form = &forms[0];
+        let submit = form.controls[5].id;
+        let image = form.controls[6].id;
+
+        assert!(!form.controls[0].is_checked);
+        assert_eq!(
+            form.form_data(Some(submit)).entries,
+            [
+                ("r".to_string(), text("2")),
+                ("_charset_".to_string(), text("UTF-8")),
+                ("file".to_string(), FormValue::File),
+                ("s".to_string(), text("Send"))
+            ]
+        );
+        assert_eq!(
+            form.form_data(Some(image)).to_url_encoded(),
+            "r=2&_charset_=UTF-8&file=&i.x=0&i.y=0"
+        );
+        assert_eq!(form.form_data(Some(form.controls[8].id)).entries.len(), 3);
+    }
+
+    #[test]
+    fn encodes_the_form_data_set() {
+        let forms = forms(concat!(
+            "<form><input name='a b' value='1 + 2 = 3 & é'><textarea name=t>x\ny</textarea>",
+            "<input type=file name='f\"'></form>"
+        ));
+        let data = forms[0].form_data(None);
+
+        assert_eq!(
+            data.to_url_encoded(),
+            "a+b=1+%2B+2+%3D+3+%26+%C3%A9&t=x%0D%0Ay&f%22="
+        );
+        assert_eq!(
+            data.to_text_plain(),
+            "a b=1 + 2 = 3 & é\r\nt=x\r\ny\r\nf\"=\r\n"
+        );
+        assert_eq!(
+            data.to_multipart("boundary"),
+            concat!(
+                "--boundary\r\nContent-Disposition: form-data; name=\"a b\"\r\n\r\n1 + 2 = 3 & é\r\n",
+                "--boundary\r\nContent-Disposition: form-data; name=\"t\"\r\n\r\nx\r\ny\r\n",
+                "--boundary\r\nContent-Disposition: form-data; name=\"f%22\"; filename=\"\"\r\n",
+                "Content-Type: application/octet-stream\r\n\r\n\r\n",
+                "--boundary--\r\n"
+            )
+        );
+    }
+}
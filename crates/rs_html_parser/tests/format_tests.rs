@@ -0,0 +1,149 @@
+mod tests {
+    use insta::assert_snapshot;
+    use rs_html_parser::{format, AttributeWrapping, FormatOptions, QuoteStyle};
+
+    const HTML: &str = concat!(
+        "<!DOCTYPE html><html><head><title>Formatting</title>",
+        "<style>p { color: red }</style></head><body>",
+        "<div class=page><h1>Title</h1><p>Some <b>bold</b> and <i>italic</i> text that is ",
+        "long enough to be wrapped at the maximum line width of the formatter.</p>",
+        "<ul><li>One<li>Two</ul>",
+        "<pre>  keep\n    this</pre><textarea>\n  and  this</textarea>",
+        "<script>if (a < b) { run() }</script>",
+        "<svg><circle r=1 /></svg></div></body></html>"
+    );
+
+    #[test]
+    fn formats_with_default_options() {
+        let formatted = format(HTML, &FormatOptions::default());
+
+        assert_snapshot!(formatted);
+        assert_eq!(format(&formatted, &FormatOptions::default()), formatted);
+    }
+
+    #[test]
+    fn keeps_preformatted_content() {
+        let formatted = format(HTML, &FormatOptions::default());
+
+        assert!(formatted.contains("<pre>  keep\n    this</pre>"));
+        assert!(formatted.contains("<textarea>\n  and  this</textarea>"));
+        assert!(formatted.contains("<script>if (a < b) { run() }</script>"));
+        assert!(formatted.contains("<style>p { color: red }</style>"));
+    }
+
+    #[test]
+    fn indents_and_wraps_with_options() {
+        let options = FormatOptions {
+            indent_width: Some(4),
+            max_line_width: Some(40),
+            ..Default::default()
+        };
+        let formatted = format(HTML, &options);
+
+        assert_snapshot!(formatted);
+        assert_eq!(format(&formatted, &options), formatted);
+    }
+
+    #[test]
+    fn wraps_attributes() {
+        let html = "<div id=a class='b c' data-long-attribute-name='a value that is long'>x</div>";
+        let wrap = |attribute_wrapping, max_line_width| {
+            format(
+                html,
+                &FormatOptions {
+                    max_line_width: Some(max_line_width),
+                    attribute_wrapping: Some(attribute_wrapping),
+                    ..Default::default()
+                },
+            )
+        };
+
+        assert_snapshot!(wrap(AttributeWrapping::Auto, 40));
+        assert_eq!(
+            wrap(AttributeWrapping::Never, 40),
+            "<div id=\"a\" class=\"b c\" data-long-attribute-name=\"a value that is long\">\n  x\n</div>\n"
+        );
+        assert_eq!(
+            wrap(AttributeWrapping::Auto, 120),
+            wrap(AttributeWrapping::Never, 120)
+        );
+        assert_eq!(
+            wrap(AttributeWrapping::Always, 120),
+            wrap(AttributeWrapping::Auto, 40)
+        );
+    }
+
+    #[test]
+    fn quotes_attribute_values() {
+        let html = r#"<p a=1 b='2' c="3" d='say "hi"' e="it's" f></p>"#;
+        let quote = |quote_style| {
+            format(
+                html,
+                &FormatOptions {
+                    quote_style: Some(quote_style),
+                    ..Default::default()
+                },
+            )
+        };
+
+        assert_eq!(
+            quote(QuoteStyle::Double),
+            "<p a=\"1\" b=\"2\" c=\"3\" d='say \"hi\"' e=\"it's\" f></p>\n"
+        );
+        assert_eq!(
+            quote(QuoteStyle::Single),
+            "<p a='1' b='2' c='3' d='say \"hi\"' e=\"it's\" f></p>\n"
+        );
+        assert_eq!(quote(QuoteStyle::Keep), format!("{}\n", html));
+    }
+
+    #[test]
+    fn formats_xml() {
+        let xml = "<?xml version=\"1.0\"?><feed><entry><title>A <b>b</b></title><link href='x'/></entry></feed>";
+        let options = FormatOptions {
+            xml_mode: true,
+            ..Default::default()
+        };
+        let formatted = format(xml, &options);
+
+        assert_snapshot!(formatted);
+        assert_eq!(format(&formatted, &options), formatted);
+    }
+
+    #[test]
+    fn keeps_omitted_tags_omitted() {
+        let formatted = format("<p>One<p>Two</br>", &FormatOptions::default());
+
+        assert_eq!(formatted, "<p>One\n<p>Two<br>\n");
+    }
+
+    #[test]
+    fn formats_deeply_nested_documents() {
+        let options = FormatOptions {
+            indent_width: Some(0),
+            ..Default::default()
+        };
+        let html = format!("{}{}x", "<div>".repeat(50_000), "<b>".repeat(50_000));
+        let formatted = format(&html, &options);
+
+        // The end tags were left out, and the inline content is too wide for the last `div`
+        assert_eq!(formatted.lines().count(), 50_001);
+        assert!(formatted.ends_with(&format!("<div>\n{}x\n", "<b>".repeat(50_000))));
+
+        let html = format!(
+            "<pre>{}x{}</pre>",
+            "<i>".repeat(50_000),
+            "</i>".repeat(50_000)
+        );
+
+        assert_eq!(format(&html, &options), format!("{}\n", html));
+
+        // Blocks deeper than the deepest indented level are not indented further
+        let html = "<div>\n".repeat(1000);
+        let formatted = format(&html, &FormatOptions::default());
+
+        assert_eq!(formatted.lines().count(), 1000);
+        assert!(formatted.ends_with(&format!("{}<div>\n", " ".repeat(200))));
+        assert_eq!(format(&formatted, &FormatOptions::default()), formatted);
+    }
+}
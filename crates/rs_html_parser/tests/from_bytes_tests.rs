@@ -0,0 +1,33 @@
+mod tests {
+    use rs_html_parser::{Parser, ParserOptions};
+    use rs_html_parser_tokens::Token;
+
+    fn parser_test(data: &[u8]) -> Vec<Token> {
+        let options = ParserOptions::default();
+
+        Parser::from_bytes(data, &options).collect()
+    }
+
+    #[test]
+    fn invalid_utf8_is_replaced() {
+        insta::assert_debug_snapshot!(parser_test(
+            b"<p title=\"a\xffb\">\xc3(&amp;\xe2\x82</p>"
+        ))
+    }
+
+    #[test]
+    fn invalid_utf8_in_names() {
+        insta::assert_debug_snapshot!(parser_test(b"<d\xffv d\xfe=x>a</d\xffv><!--\xc0-->"))
+    }
+
+    #[test]
+    fn valid_utf8_matches_new() {
+        let data = "<p title=\"é\">ä &amp; ö</p>";
+        let options = ParserOptions::default();
+
+        assert_eq!(
+            format!("{:?}", parser_test(data.as_bytes())),
+            format!("{:?}", Parser::new(data, &options).collect::<Vec<_>>())
+        );
+    }
+}
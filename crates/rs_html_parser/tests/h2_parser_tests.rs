@@ -2,6 +2,7 @@ mod test_utils;
 
 mod tests {
     use crate::test_utils::*;
+    use rs_html_parser_tokens::TokenKind;
 
     #[test]
     fn basic_element() {
@@ -97,4 +98,28 @@ mod tests {
     fn for_multi_byte_entities() {
         insta::assert_debug_snapshot!(parser_test("&NotGreaterFullEqual;"))
     }
+
+    #[test]
+    fn open_tags_have_their_start_offset() {
+        let html = "<ul><li>a<li>b</ul></p><br/>\n</br><svg><path/></svg><a href=x";
+        let starts: Vec<_> = parser_test(html)
+            .into_iter()
+            .filter(|token| token.kind == TokenKind::OpenTag)
+            .map(|token| (token.data.to_string(), token.start))
+            .collect();
+
+        assert_eq!(
+            starts,
+            [
+                ("ul".to_string(), Some(0)),
+                ("li".to_string(), Some(4)),
+                ("li".to_string(), Some(9)),
+                ("p".to_string(), None),
+                ("br".to_string(), Some(23)),
+                ("br".to_string(), Some(29)),
+                ("svg".to_string(), Some(34)),
+                ("path".to_string(), Some(39)),
+            ]
+        );
+    }
 }
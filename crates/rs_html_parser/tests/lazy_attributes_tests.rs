@@ -0,0 +1,71 @@
+mod tests {
+    use rs_html_parser::{Parser, ParserOptions, WhitespaceMode};
+    use rs_html_parser_tokens::{Token, TokenKind};
+    use std::borrow::Cow;
+
+    fn lazy_parser_test(data: &str) -> Vec<Token> {
+        let options = ParserOptions {
+            lazy_attributes: true,
+            ..Default::default()
+        };
+
+        Parser::new(data, &options).collect()
+    }
+
+    #[test]
+    fn raw_attributes_are_kept() {
+        insta::assert_debug_snapshot!(lazy_parser_test(
+            "<a href=\"/x?a=1&amp;b=2\" HREF=y download>x</a><br/><img />"
+        ))
+    }
+
+    #[test]
+    fn attribute_is_decoded_on_access() {
+        let tokens = lazy_parser_test("<a Href='/x?a=1&amp;b=2' download>x</a>");
+
+        assert_eq!(tokens[0].attribute("href"), Some(Cow::from("/x?a=1&b=2")));
+        assert_eq!(tokens[0].attribute("download"), Some(Cow::from("")));
+        assert_eq!(tokens[0].attribute("title"), None);
+        assert_eq!(tokens[1].attribute("href"), None);
+    }
+
+    #[test]
+    fn eager_attribute_is_borrowed() {
+        let tokens: Vec<Token> = Parser::new("<a href=x>", &ParserOptions::default()).collect();
+
+        assert!(matches!(
+            tokens[0].attribute("href"),
+            Some(Cow::Borrowed("x"))
+        ));
+    }
+
+    #[test]
+    fn tags_without_attributes_have_no_raw_attributes() {
+        let tokens = lazy_parser_test("<p><br/><img / ></p>");
+
+        assert!(tokens
+            .iter()
+            .filter(|token| token.kind == TokenKind::OpenTag)
+            .all(|token| token.raw_attrs.is_none()));
+    }
+
+    #[test]
+    fn xml_space_is_read_from_raw_attributes() {
+        let html = "<svg XML:SPACE=preserve><text> a  <tspan xml:space='default'> b  </tspan></text></svg>";
+        let text = |lazy_attributes| {
+            let options = ParserOptions {
+                whitespace: Some(WhitespaceMode::Trim),
+                lazy_attributes,
+                ..Default::default()
+            };
+
+            Parser::new(html, &options)
+                .filter(|token| token.kind == TokenKind::Text)
+                .map(|token| token.data.to_string())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(text(true), [" a  ", "b"]);
+        assert_eq!(text(true), text(false));
+    }
+}
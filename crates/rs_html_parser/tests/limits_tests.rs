@@ -0,0 +1,131 @@
+mod tests {
+    use rs_html_parser::{Limit, Limits, Parser, ParserOptions};
+    use rs_html_parser_tokens::{Token, TokenKind};
+    use std::time::{Duration, Instant};
+
+    fn parser_test(data: &str, limits: Limits) -> (Vec<Token>, Vec<Limit>) {
+        let options = ParserOptions {
+            limits: Some(limits),
+            ..Default::default()
+        };
+        let mut parser = Parser::new(data, &options);
+        let tokens = parser.by_ref().collect();
+
+        (tokens, parser.exceeded_limits().to_vec())
+    }
+
+    fn assert_balanced(tokens: &[Token]) {
+        let mut stack = Vec::new();
+
+        for token in tokens {
+            match token.kind {
+                TokenKind::OpenTag => stack.push(&token.data),
+                TokenKind::CloseTag => assert_eq!(stack.pop(), Some(&token.data)),
+                _ => {}
+            }
+        }
+        assert!(stack.is_empty());
+    }
+
+    #[test]
+    fn max_depth() {
+        insta::assert_debug_snapshot!(parser_test(
+            "<div><span><b>a</b>b</span></div>",
+            Limits {
+                max_depth: Some(2),
+                ..Default::default()
+            }
+        ))
+    }
+
+    #[test]
+    fn max_attributes() {
+        insta::assert_debug_snapshot!(parser_test(
+            "<a href=x id=y class=z></a>",
+            Limits {
+                max_attributes: Some(2),
+                ..Default::default()
+            }
+        ))
+    }
+
+    #[test]
+    fn max_attribute_length() {
+        insta::assert_debug_snapshot!(parser_test(
+            "<a href=\"é&amp;long\" averylongname></a>",
+            Limits {
+                max_attribute_length: Some(4),
+                ..Default::default()
+            }
+        ))
+    }
+
+    #[test]
+    fn max_text_length() {
+        insta::assert_debug_snapshot!(parser_test(
+            "<p>abcé&amp;def</p>",
+            Limits {
+                max_text_length: Some(4),
+                ..Default::default()
+            }
+        ))
+    }
+
+    #[test]
+    fn max_tokens() {
+        insta::assert_debug_snapshot!(parser_test(
+            "<div><p>a</p><p>b</p></div>",
+            Limits {
+                max_tokens: Some(4),
+                ..Default::default()
+            }
+        ))
+    }
+
+    #[test]
+    fn max_input_size() {
+        insta::assert_debug_snapshot!(parser_test(
+            "<p>too long</p>",
+            Limits {
+                max_input_size: Some(8),
+                ..Default::default()
+            }
+        ))
+    }
+
+    #[test]
+    fn deep_nesting_stays_balanced() {
+        let data = "<div>".repeat(1000) + "</div>";
+        let (tokens, exceeded_limits) = parser_test(
+            &data,
+            Limits {
+                max_depth: Some(100),
+                ..Default::default()
+            },
+        );
+
+        assert_balanced(&tokens);
+        assert_eq!(exceeded_limits, vec![Limit::Depth]);
+    }
+
+    #[test]
+    fn unclosed_elements_are_linear() {
+        let data = "<div>".repeat(100_000) + &"</span>".repeat(100_000) + "</div>";
+        let start = Instant::now();
+        let (tokens, _) = parser_test(&data, Limits::default());
+
+        assert_eq!(tokens.len(), 200_000);
+        assert_balanced(&tokens);
+        assert!(start.elapsed() < Duration::from_secs(10));
+    }
+
+    #[test]
+    fn invalid_character_references_do_not_panic() {
+        let (tokens, _) = parser_test(
+            "&#xD800;<a title=\"&#x110000;\">&#0;</a>",
+            Limits::default(),
+        );
+
+        assert_balanced(&tokens);
+    }
+}
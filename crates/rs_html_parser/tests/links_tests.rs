@@ -0,0 +1,150 @@
+mod tests {
+    use rs_html_parser::{extract_links, Link, LinkOptions};
+
+    fn links(html: &str, document_url: Option<&str>) -> Vec<Link> {
+        extract_links(
+            html,
+            &LinkOptions {
+                document_url: document_url.map(str::to_string),
+            },
+        )
+    }
+
+    fn resolved(links: &[Link]) -> Vec<&str> {
+        links
+            .iter()
+            .map(|link| link.resolved.as_deref().unwrap_or("-"))
+            .collect()
+    }
+
+    #[test]
+    fn resolves_references_as_in_rfc_3986() {
+        let references = [
+            ("g:h", "g:h"),
+            ("g", "http://a/b/c/g"),
+            ("./g", "http://a/b/c/g"),
+            ("g/", "http://a/b/c/g/"),
+            ("/g", "http://a/g"),
+            ("//g", "http://g"),
+            ("?y", "http://a/b/c/d;p?y"),
+            ("g?y", "http://a/b/c/g?y"),
+            ("#s", "http://a/b/c/d;p?q#s"),
+            ("g?y#s", "http://a/b/c/g?y#s"),
+            (";x", "http://a/b/c/;x"),
+            ("", "http://a/b/c/d;p?q"),
+            (".", "http://a/b/c/"),
+            ("..", "http://a/b/"),
+            ("../g", "http://a/b/g"),
+            ("../..", "http://a/"),
+            ("../../../g", "http://a/g"),
+            ("/./g", "http://a/g"),
+            ("g/../h", "http://a/b/c/h"),
+            (" g\\h\n ", "http://a/b/c/g/h"),
+        ];
+        let html: String = references
+            .iter()
+            .map(|(reference, _)| format!("<a href=\"{}\">", reference))
+            .collect();
+        let links = links(&html, Some("http://a/b/c/d;p?q"));
+
+        assert_eq!(
+            resolved(&links),
+            references
+                .iter()
+                .map(|(_, resolved)| *resolved)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn resolves_against_the_first_base() {
+        let html = concat!(
+            "<a href=a>a</a><base href=/dir/><base href=https://other.example/>",
+            "<a href='mailto:x@example.com'>x</a>"
+        );
+
+        assert_eq!(
+            resolved(&links(html, Some("https://example.com/page"))),
+            [
+                "https://example.com/dir/a",
+                "https://example.com/dir/",
+                "https://other.example/",
+                "mailto:x@example.com"
+            ]
+        );
+        assert_eq!(
+            resolved(&links(html, None)),
+            ["-", "-", "https://other.example/", "mailto:x@example.com"]
+        );
+        assert_eq!(
+            resolved(&links(
+                "<base href=https://b.example/x/><img src=y.png>",
+                None
+            )),
+            ["https://b.example/x/", "https://b.example/x/y.png"]
+        );
+    }
+
+    #[test]
+    fn collects_urls_from_attributes() {
+        let html = concat!(
+            "<html manifest=app.appcache><head>",
+            "<link rel='Stylesheet  preload' href=style.css>",
+            "<meta http-equiv=Refresh content=\"5; URL='next.html'\">",
+            "<meta http-equiv=refresh content=10><meta name=x content=y.html>",
+            "<script src=app.js></script></head><body background=bg.png>",
+            "<img src=a.png srcset='a-1x.png 1x,a-2x.png 2x, a,b.png 3x, data:x;y,z 4x'>",
+            "<a href=/l rel=nofollow ping='p1 p2' style='background: url( \"i.png\" ) url(j.png)'>",
+            "<iframe src=frame.html></iframe><form action=/post><button formaction=/b></button>",
+            "</form><video poster=v.jpg><source src=v.mp4></video><q cite=/c>q</q>",
+            "<svg><use xlink:href=#icon /></svg><div style='color: red'></div></body></html>"
+        );
+        let links = links(html, None);
+        let attributes: Vec<_> = links
+            .iter()
+            .map(|link| format!("{} {} {}", link.element.as_str(), link.attribute, link.url))
+            .collect();
+
+        assert_eq!(
+            attributes,
+            [
+                "html manifest app.appcache",
+                "link href style.css",
+                "meta content next.html",
+                "script src app.js",
+                "body background bg.png",
+                "img src a.png",
+                "img srcset a-1x.png",
+                "img srcset a-2x.png",
+                "img srcset a,b.png",
+                "img srcset data:x;y,z",
+                "a href /l",
+                "a ping p1",
+                "a ping p2",
+                "a style i.png",
+                "a style j.png",
+                "iframe src frame.html",
+                "form action /post",
+                "button formaction /b",
+                "video poster v.jpg",
+                "source src v.mp4",
+                "q cite /c",
+                "use xlink:href #icon"
+            ]
+        );
+        assert_eq!(links[1].rel, ["stylesheet", "preload"]);
+        assert_eq!(links[10].rel, ["nofollow"]);
+        assert!(links[5].rel.is_empty());
+    }
+
+    #[test]
+    fn reports_the_position_of_the_start_tag() {
+        let html = "<p>text</p>\n<img src=a.png></br><a href='&amp;x'>a</a></p><a href=b";
+        let links = links(html, None);
+
+        assert_eq!(links.len(), 2);
+        assert_eq!(links[0].position, html.find("<img").unwrap());
+        assert_eq!(links[1].position, html.find("<a").unwrap());
+        assert_eq!(links[1].url, "&x");
+    }
+}
@@ -0,0 +1,144 @@
+mod tests {
+    use insta::assert_snapshot;
+    use rs_html_parser::{html_to_markdown, MarkdownOptions, UnsupportedElements};
+
+    const HTML: &str = concat!(
+        "<!DOCTYPE html><html><head><title>Markdown</title><style>p { }</style></head><body>",
+        "<h1>A <em>title</em></h1><h3>Section</h3>",
+        "<p>Some <b>bold </b>and <i>italic</i> text, a <code>co`de</code> span<br>",
+        "and a <a href='https://example.com/a b' title='The \"title\"'>link</a> ",
+        "with <a href=https://example.com>https://example.com</a> after it.</p>",
+        "<p><img src=image.png alt='An image' title=Image> x<sup>2</sup></p>",
+        "<ul><li>One<li>Two<ul><li>Nested</li><li><p>Paragraph</p><p>Paragraph</p></ul><li>Three</ul>",
+        "<ol start=3><li>Three<li>Four<ol><li>One</ol></ol>",
+        "<blockquote><p>Quoted</p><blockquote>Nested</blockquote></blockquote>",
+        "<pre><code class='language-rust'>fn main() {\n    println!(\"```\");\n}\n</code></pre>",
+        "<hr><table><caption>Caption</caption><thead><tr><th>Name</th><th align=right>Value</th></thead>",
+        "<tbody><tr><td>a|b</td><td>1<br>2</td><tr><td colspan=2>Both</td></tbody></table>",
+        "<video src=video.mp4></video><p><del>Removed</del></p>",
+        "</body></html>"
+    );
+
+    fn markdown(html: &str) -> String {
+        html_to_markdown(html, &MarkdownOptions::default())
+    }
+
+    #[test]
+    fn converts_with_default_options() {
+        assert_snapshot!(markdown(HTML));
+    }
+
+    #[test]
+    fn escapes_markdown_syntax() {
+        assert_eq!(
+            markdown("<p>*a* _b_ [c](d) `e` &lt;f&gt; ~g~ \\ &amp;amp;</p>"),
+            "\\*a\\* \\_b\\_ \\[c\\](d) \\`e\\` \\<f> \\~g\\~ \\\\ \\&amp;\n"
+        );
+        assert_eq!(
+            markdown("<p># a</p><p>1. b</p><p>- c</p><p>+ d<br>=</p>"),
+            "\\# a\n\n1\\. b\n\n\\- c\n\n\\+ d\\\n\\=\n"
+        );
+    }
+
+    #[test]
+    fn collapses_whitespace() {
+        assert_eq!(
+            markdown(
+                "<div>\n  <p>  a\n <b> b </b>  c </p>\n  <span>d</span> <span>e</span>\n</div>"
+            ),
+            "a **b** c\n\nd e\n"
+        );
+        assert_eq!(markdown("<p>a<br><br>b<br></p>"), "a\n\nb\n");
+    }
+
+    #[test]
+    fn escapes_pipes_in_code_spans_in_tables() {
+        assert_eq!(
+            markdown("<table><tr><th>a</th></tr><tr><td><code>a || b</code></td></tr></table>"),
+            "| a |\n| --- |\n| `a \\|\\| b` |\n"
+        );
+        assert_eq!(markdown("<p><code>a | b</code></p>"), "`a | b`\n");
+    }
+
+    #[test]
+    fn takes_the_language_of_code_blocks_from_the_class() {
+        assert_eq!(
+            markdown("<pre class='lang-js'><code>a\n  b</code></pre>"),
+            "```js\na\n  b\n```\n"
+        );
+        assert_eq!(
+            markdown("<pre><code class='highlight language-css'>p {}</code></pre><pre>\n*</pre>"),
+            "```css\np {}\n```\n\n```\n*\n```\n"
+        );
+    }
+
+    #[test]
+    fn keeps_or_drops_unsupported_elements() {
+        let html = "<p>x<sup>2</sup> <kbd>Ctrl</kbd></p><details><summary>More</summary>\n\nText</details>";
+
+        assert_eq!(
+            markdown(html),
+            "x<sup>2</sup> <kbd>Ctrl</kbd>\n\n<details><summary>More</summary>\nText</details>\n"
+        );
+        assert_eq!(
+            html_to_markdown(
+                html,
+                &MarkdownOptions {
+                    unsupported_elements: Some(UnsupportedElements::Drop),
+                    ..Default::default()
+                }
+            ),
+            "x\n"
+        );
+    }
+
+    #[test]
+    fn writes_commonmark_without_gfm() {
+        assert_eq!(
+            html_to_markdown(
+                "<p><s>a</s> ~</p><table><tr><td>b</td></tr></table>",
+                &MarkdownOptions {
+                    gfm: Some(false),
+                    ..Default::default()
+                }
+            ),
+            "<s>a</s> ~\n\n<table><tr><td>b</td></tr></table>\n"
+        );
+    }
+
+    #[test]
+    fn skips_hidden_content() {
+        assert_eq!(
+            markdown("<p>a<script>b</script><span hidden>c</span><template>d</template></p>"),
+            "a\n"
+        );
+    }
+
+    #[test]
+    fn limits_the_numbers_of_ordered_lists() {
+        assert_eq!(
+            markdown("<ol start=9223372036854775807><li>a<li>b</ol>"),
+            "999999999. a\n999999999. b\n"
+        );
+        assert_eq!(
+            markdown("<ol start=1234567890><li>a</ol><ol start=-5><li>b</ol>"),
+            "999999999. a\n\n0. b\n"
+        );
+    }
+
+    #[test]
+    fn writes_deeply_nested_content_as_text() {
+        let html = format!(
+            "{}a<script>b</script><p>c<b>d</b></p>",
+            "<div><blockquote>".repeat(50_000)
+        );
+
+        assert!(markdown(&html).ends_with("> a cd\n"));
+
+        let html = format!("<p>{}x<br>y", "<b><i>".repeat(50_000));
+        let converted = markdown(&html);
+
+        assert!(converted.starts_with("****"));
+        assert!(converted.contains("xy"));
+    }
+}
@@ -0,0 +1,115 @@
+mod tests {
+    use rs_html_parser::{extract_metadata, Metadata, Parser, ParserOptions};
+
+    const HEAD: &str = concat!(
+        "<!DOCTYPE html><html><head><meta charset=UTF-8>",
+        "<title>\n  The  page &amp; more\n</title>",
+        "<meta name=Viewport content='width=device-width, initial-scale=1'>",
+        "<meta name=description content='A page.'><meta name=ROBOTS content='NoIndex, max-snippet:50'>",
+        "<base href=https://example.com/>",
+        "<meta property=og:title content='The page'><meta property=og:type content=article>",
+        "<meta property=og:image content=/a.png><meta property=og:image:url content=/b.png>",
+        "<meta name=twitter:card content=summary_large_image><meta property=twitter:site content=@site>",
+        "<meta name=twitter:image:src content=/t.png>",
+        "<link rel=canonical href=/page><link rel=alternate hreflang=fi href=/fi/page>",
+        "<link rel=alternate type=application/rss+xml title=Feed href=/feed.xml>",
+        "<link rel='shortcut icon' href=/favicon.ico><link rel=apple-touch-icon sizes=180x180 href=/touch.png>",
+        "<link rel=manifest href=/manifest.json><link rel=stylesheet href=/style.css>",
+        "<script>var title = '<title>x</title>';</script><style>p {}</style>",
+        "<template><meta name=description content=template></template>",
+        "<noscript><link rel=canonical href=/noscript></noscript>",
+        "</head>"
+    );
+
+    #[test]
+    fn reads_the_head() {
+        let metadata = extract_metadata(&format!("{}<body><p>Text</p></body></html>", HEAD));
+
+        assert_eq!(metadata.title.as_deref(), Some("The page & more"));
+        assert_eq!(metadata.charset.as_deref(), Some("UTF-8"));
+        assert_eq!(
+            metadata.viewport.as_deref(),
+            Some("width=device-width, initial-scale=1")
+        );
+        assert_eq!(metadata.description.as_deref(), Some("A page."));
+        assert_eq!(metadata.base.as_deref(), Some("https://example.com/"));
+        assert_eq!(metadata.robots.directives, ["noindex", "max-snippet:50"]);
+        assert!(!metadata.robots.index());
+        assert!(metadata.robots.follow());
+        assert_eq!(metadata.open_graph.title.as_deref(), Some("The page"));
+        assert_eq!(metadata.open_graph.kind.as_deref(), Some("article"));
+        assert_eq!(metadata.open_graph.images, ["/a.png", "/b.png"]);
+        assert_eq!(
+            metadata.twitter.card.as_deref(),
+            Some("summary_large_image")
+        );
+        assert_eq!(metadata.twitter.site.as_deref(), Some("@site"));
+        assert_eq!(metadata.twitter.image.as_deref(), Some("/t.png"));
+        assert_eq!(metadata.canonical.as_deref(), Some("/page"));
+        assert_eq!(metadata.alternates.len(), 2);
+        assert_eq!(metadata.alternates[0].hreflang.as_deref(), Some("fi"));
+        assert_eq!(
+            metadata.alternates[1].media_type.as_deref(),
+            Some("application/rss+xml")
+        );
+        assert_eq!(metadata.alternates[1].title.as_deref(), Some("Feed"));
+        assert_eq!(metadata.icons.len(), 2);
+        assert_eq!(metadata.icons[0].rel, ["shortcut", "icon"]);
+        assert_eq!(metadata.icons[1].sizes.as_deref(), Some("180x180"));
+        assert_eq!(metadata.manifest.as_deref(), Some("/manifest.json"));
+        assert_eq!(metadata.names.len(), 5);
+        assert_eq!(metadata.names[0].0, "viewport");
+        assert_eq!(metadata.properties.len(), 5);
+    }
+
+    #[test]
+    fn stops_at_the_body_content() {
+        let html = format!(
+            "{}<body><p>Text</p><meta name=description content=body><title>Body</title></body>",
+            HEAD
+        );
+        let options = ParserOptions::default();
+        let mut count = 0;
+        let metadata = Metadata::from_tokens(Parser::new(&html, &options).inspect(|_| count += 1));
+        let total = Parser::new(&html, &options).count();
+
+        assert_eq!(metadata.description.as_deref(), Some("A page."));
+        assert!(count < total - 5);
+
+        // Without tags for the head and the body
+        let metadata = extract_metadata(
+            "<title>A</title>\n<meta name=description content=a>\nText<meta name=robots content=none>",
+        );
+
+        assert_eq!(metadata.title.as_deref(), Some("A"));
+        assert_eq!(metadata.description.as_deref(), Some("a"));
+        assert!(metadata.robots.directives.is_empty());
+
+        let metadata = extract_metadata("<meta charset=utf-8><div><title>No</title></div>");
+
+        assert_eq!(metadata.title, None);
+        assert_eq!(
+            extract_metadata("\u{FEFF}\n<title>B</title>")
+                .title
+                .as_deref(),
+            Some("B")
+        );
+    }
+
+    #[test]
+    fn reads_the_charset_of_the_content_type() {
+        let metadata = extract_metadata(
+            "<meta http-equiv=Content-Type content='text/html; charset=\"iso-8859-1\"'>",
+        );
+
+        assert_eq!(metadata.charset.as_deref(), Some("iso-8859-1"));
+        assert_eq!(
+            metadata.http_equiv,
+            [(
+                "content-type".to_string(),
+                "text/html; charset=\"iso-8859-1\"".to_string()
+            )]
+        );
+        assert_eq!(extract_metadata("<p>a</p>"), Metadata::default());
+    }
+}
@@ -0,0 +1,124 @@
+mod tests {
+    use rs_html_parser::{minify, MinifyOptions};
+
+    fn minified(html: &str) -> String {
+        minify(html, &MinifyOptions::default())
+    }
+
+    #[test]
+    fn collapses_whitespace() {
+        assert_eq!(
+            minified("<div>\n  <p>Some   <b>bold</b>\n  text </p>\n  <span>a</span> <span>b</span>\n</div>"),
+            "<div><p>Some <b>bold</b> text</p><span>a</span> <span>b</span></div>"
+        );
+        assert_eq!(
+            minified("<p>a <img src=x> b <br> c</p>"),
+            "<p>a <img src=x> b<br>c"
+        );
+        assert_eq!(
+            minified("<head>\n  <title> A  title </title>\n  <meta charset=utf-8>\n</head>"),
+            "<head><title> A title </title><meta charset=utf-8>"
+        );
+    }
+
+    #[test]
+    fn keeps_preformatted_content() {
+        let html = concat!(
+            "<pre>  a\n    b </pre><textarea>  c  </textarea>",
+            "<script>if (a  <  b) {}</script><style> p  { } </style>"
+        );
+
+        assert_eq!(minified(html), html);
+    }
+
+    #[test]
+    fn removes_comments() {
+        assert_eq!(
+            minified("<p>a<!-- note -->b</p><!--[if IE]><p>IE<![endif]--><!--<![endif]-->"),
+            "<p>ab</p><!--[if IE]><p>IE<![endif]--><!--<![endif]-->"
+        );
+        assert_eq!(
+            minify(
+                "<p>a<!-- note -->b</p>",
+                &MinifyOptions {
+                    remove_comments: Some(false),
+                    ..Default::default()
+                }
+            ),
+            "<p>a<!-- note -->b"
+        );
+    }
+
+    #[test]
+    fn keeps_cdata_sections() {
+        assert_eq!(
+            minified(
+                "<svg><!-- icon --><style><![CDATA[ .a { } ]]></style><path d='M0 0'></path></svg>"
+            ),
+            "<svg><style><![CDATA[ .a { } ]]></style><path d=\"M0 0\"/></svg>"
+        );
+    }
+
+    #[test]
+    fn omits_optional_end_tags() {
+        assert_eq!(
+            minified("<ul><li>a</li><li>b</li></ul><p>c</p><div>d</div>"),
+            "<ul><li>a<li>b</ul><p>c<div>d</div>"
+        );
+        assert_eq!(
+            minified("<div><p>a</p><p>b</p></div><a><p>c</p></a>"),
+            "<div><p>a<p>b</div><a><p>c</p></a>"
+        );
+        assert_eq!(
+            minified(concat!(
+                "<table><thead><tr><th>a</th></tr></thead>",
+                "<tbody><tr><td>1</td><td>2</td></tr><tr><td>3</td></tr></tbody></table>"
+            )),
+            "<table><thead><tr><th>a</tr><tbody><tr><td>1<td>2<tr><td>3</table>"
+        );
+        assert_eq!(
+            minified("<html><head><title>a</title></head><body><p>b</p></body></html>"),
+            "<html><head><title>a</title><body><p>b"
+        );
+        assert_eq!(
+            minify(
+                "<ul><li>a</li><li>b</ul>",
+                &MinifyOptions {
+                    remove_optional_tags: Some(false),
+                    ..Default::default()
+                }
+            ),
+            "<ul><li>a</li><li>b</ul>"
+        );
+    }
+
+    #[test]
+    fn writes_end_tags_that_are_needed_again() {
+        // The `li` would be nested in the `p` without its end tag
+        assert_eq!(
+            minified("<ul><li><p>a</li><li>b</li></ul>"),
+            "<ul><li><p>a</p><li>b</ul>"
+        );
+    }
+
+    #[test]
+    fn shortens_attributes() {
+        assert_eq!(
+            minified(
+                r#"<input type="checkbox" checked="checked" disabled="" value="a b" title='say "hi"' data-x="">"#
+            ),
+            r#"<input type=checkbox checked disabled value="a b" title='say "hi"' data-x="">"#
+        );
+        assert_eq!(
+            minify(
+                r#"<input type="checkbox" checked="checked">"#,
+                &MinifyOptions {
+                    remove_attribute_quotes: Some(false),
+                    collapse_boolean_attributes: Some(false),
+                    ..Default::default()
+                }
+            ),
+            r#"<input type="checkbox" checked="checked">"#
+        );
+    }
+}
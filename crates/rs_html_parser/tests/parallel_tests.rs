@@ -0,0 +1,98 @@
+#![cfg(feature = "parallel")]
+
+mod tests {
+    use rs_html_parser::{Parser, ParserOptions, WhitespaceMode};
+    use rs_html_parser_tokenizer::TokenizerOptions;
+
+    const DOCUMENTS: [&str; 8] = [
+        "<ul><li>one</li> <li>two</li>\n<li>three &amp; four</li></ul><p>a<b>b</b>c</p>",
+        "<p>x</p>   <p>y</p> <p> </p><p>z</p>  <div> a <span> </span> b</div>",
+        "<head><title>a <b> &lt;</title><style>p > a { color: red }</style></head><p>x</p>",
+        "<script>if (a < b) { document.write('<p>x</p>') }</script><p>y</p><textarea><p></textarea>",
+        "<svg><title><b>x</b></title><style><g/></style></svg><title><b>y</b></title><p>z</p>",
+        "<p>a</p><svg><![CDATA[<p>x</p>]]></svg><p><![CDATA[<b>y</b>]]></p><math><![CDATA[z]]>",
+        "<!-- <p>x</p> --><p title='<p>' data-x=\"a>b\">y</p><!DOCTYPE html><?x <p>?><p>z",
+        "<p>a &notin; b</p><p>&#x41;</p>< p>c</p><p>d<</p><plaintext><p>e</p>",
+    ];
+
+    fn all_options() -> [ParserOptions; 4] {
+        [
+            ParserOptions::default(),
+            ParserOptions {
+                xml_mode: true,
+                ..Default::default()
+            },
+            ParserOptions {
+                whitespace: Some(WhitespaceMode::Trim),
+                coalesce_text: Some(false),
+                ..Default::default()
+            },
+            ParserOptions {
+                tokenizer_options: TokenizerOptions {
+                    decode_entities: Some(false),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        ]
+    }
+
+    #[test]
+    fn chunks_match_sequential_parsing() {
+        for options in &all_options() {
+            for document in DOCUMENTS {
+                let expected: Vec<_> = Parser::new(document, options).collect();
+                let expected_starts: Vec<_> = expected.iter().map(|token| token.start).collect();
+                let expected = format!("{:?}", expected);
+
+                for chunk_size in 1..=document.len() {
+                    let tokens: Vec<_> =
+                        Parser::from_bytes_parallel(document.as_bytes(), options, chunk_size)
+                            .collect();
+
+                    assert_eq!(
+                        format!("{:?}", tokens),
+                        expected,
+                        "{document} in chunks of {chunk_size}"
+                    );
+                    assert_eq!(
+                        tokens.iter().map(|token| token.start).collect::<Vec<_>>(),
+                        expected_starts,
+                        "{document} in chunks of {chunk_size}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn small_documents_are_not_split() {
+        let options = ParserOptions::default();
+        let tokens: Vec<_> = Parser::new_parallel(DOCUMENTS[0], &options).collect();
+
+        assert_eq!(
+            format!("{:?}", tokens),
+            format!(
+                "{:?}",
+                Parser::new(DOCUMENTS[0], &options).collect::<Vec<_>>()
+            )
+        );
+    }
+
+    #[test]
+    fn reset_parses_sequentially() {
+        let options = ParserOptions::default();
+        let mut parser = Parser::from_bytes_parallel(DOCUMENTS[1].as_bytes(), &options, 4);
+
+        assert!(parser.next().is_some());
+        parser.reset(DOCUMENTS[2]);
+
+        assert_eq!(
+            format!("{:?}", parser.collect::<Vec<_>>()),
+            format!(
+                "{:?}",
+                Parser::new(DOCUMENTS[2], &options).collect::<Vec<_>>()
+            )
+        );
+    }
+}
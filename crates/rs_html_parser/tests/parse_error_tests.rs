@@ -0,0 +1,63 @@
+mod tests {
+    use rs_html_parser::{ParseError, Parser, ParserOptions};
+
+    fn parse_errors(data: &str, options: &ParserOptions) -> Vec<ParseError> {
+        let mut parser = Parser::new(data, options);
+
+        parser.by_ref().for_each(drop);
+        parser.parse_errors().to_vec()
+    }
+
+    #[test]
+    fn well_formed_documents_have_no_errors() {
+        let options = ParserOptions::default();
+
+        for data in [
+            "<!DOCTYPE html><html><head><title>x</title></head><body><p>a<br>b</p></body></html>",
+            // Optional end tags
+            "<ul><li>a<li>b</ul><table><tr><td>1<td>2</table><p>x<div>y</div>",
+            "<dl><dt>a<dd>b</dl><select><option>a<option>b</select>",
+        ] {
+            assert_eq!(parse_errors(data, &options), [], "{data}");
+        }
+    }
+
+    #[test]
+    fn unexpected_end_tags() {
+        insta::assert_debug_snapshot!(parse_errors(
+            "<div></span></custom-x></div></p></br></img>",
+            &ParserOptions::default()
+        ))
+    }
+
+    #[test]
+    fn unclosed_elements() {
+        insta::assert_debug_snapshot!(parse_errors(
+            "<div><span><b>a</div><section><p><em>b",
+            &ParserOptions::default()
+        ))
+    }
+
+    #[test]
+    fn end_tags_are_required_in_xml_mode() {
+        let options = ParserOptions {
+            xml_mode: true,
+            ..Default::default()
+        };
+
+        insta::assert_debug_snapshot!(parse_errors("<feed><entry><p>a</feed>", &options))
+    }
+
+    #[test]
+    fn reset_clears_errors() {
+        let options = ParserOptions::default();
+        let mut parser = Parser::new("<div></span>", &options);
+
+        parser.by_ref().for_each(drop);
+        assert_eq!(parser.parse_errors().len(), 2);
+
+        parser.reset("<div></div>");
+        parser.by_ref().for_each(drop);
+        assert_eq!(parser.parse_errors(), []);
+    }
+}
@@ -0,0 +1,74 @@
+mod tests {
+    use rs_html_parser::{Limit, Limits, Parser, ParserOptions};
+
+    const SECOND: &str = "<ul><li>a &amp; b<li><svg><style>x</style></svg></ul><p>text";
+
+    fn assert_reset_matches_new(first: &str, consumed: usize, options: &ParserOptions) {
+        let mut parser = Parser::new(first, options);
+
+        parser.by_ref().take(consumed).for_each(drop);
+        parser.reset(SECOND);
+
+        assert_eq!(
+            format!("{:?}", parser.by_ref().collect::<Vec<_>>()),
+            format!("{:?}", Parser::new(SECOND, options).collect::<Vec<_>>())
+        );
+        assert_eq!(parser.exceeded_limits(), &[]);
+    }
+
+    #[test]
+    fn reset_in_the_middle_of_a_document() {
+        assert_reset_matches_new(
+            "<div><pre><math><p title=\"a\">text<b>bold",
+            4,
+            &ParserOptions::default(),
+        );
+    }
+
+    #[test]
+    fn reset_in_xml_mode() {
+        let options = ParserOptions {
+            xml_mode: true,
+            ..Default::default()
+        };
+
+        assert_reset_matches_new("<a><b><![CDATA[x]]><c>", 3, &options);
+    }
+
+    #[test]
+    fn reset_after_a_limit_was_exceeded() {
+        let options = ParserOptions {
+            limits: Some(Limits {
+                max_tokens: Some(40),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let first = "<i>a</i>".repeat(100);
+        let mut parser = Parser::new(&first, &options);
+
+        parser.by_ref().for_each(drop);
+        assert_eq!(parser.exceeded_limits(), &[Limit::Tokens]);
+
+        assert_reset_matches_new(&first, usize::MAX, &options);
+    }
+
+    #[test]
+    fn reset_checks_input_size() {
+        let options = ParserOptions {
+            limits: Some(Limits {
+                max_input_size: Some(10),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let mut parser = Parser::new("<p>", &options);
+
+        parser.by_ref().for_each(drop);
+        parser.reset(SECOND);
+
+        assert_eq!(parser.by_ref().count(), 0);
+        assert_eq!(parser.exceeded_limits(), &[Limit::InputSize]);
+    }
+}
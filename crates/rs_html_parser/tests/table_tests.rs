@@ -0,0 +1,143 @@
+mod tests {
+    use rs_html_parser::{Document, ParserOptions, Table};
+
+    fn tables(html: &str) -> Vec<Table> {
+        let document = Document::parse(html, &ParserOptions::default());
+
+        document.tables(document.root())
+    }
+
+    fn texts(table: &Table) -> Vec<Vec<&str>> {
+        table
+            .rows
+            .iter()
+            .map(|row| row.iter().map(|cell| cell.text.as_str()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn expands_spanning_cells() {
+        let tables = tables(concat!(
+            "<table><tr><td rowspan=2>a</td><td colspan=2>b</td></tr>",
+            "<tr><td>c</td><td>d</td><td>e</td></tr><tr><td>f</td></tr></table>"
+        ));
+
+        assert_eq!(
+            texts(&tables[0]),
+            [["a", "b", "b", ""], ["a", "c", "d", "e"], ["f", "", "", ""]]
+        );
+        assert!(!tables[0].rows[0][1].is_spanned);
+        assert!(tables[0].rows[0][2].is_spanned);
+        assert!(tables[0].rows[1][0].is_spanned);
+        assert!(!tables[0].rows[2][1].is_spanned);
+    }
+
+    #[test]
+    fn spans_the_rest_of_the_row_group_with_a_zero_rowspan() {
+        let tables = tables(concat!(
+            "<table><tbody><tr><td rowspan=0>a</td><td>b</td><tr><td>c</td><tr><td rowspan=5>d</td>",
+            "</tr></tbody><tbody><tr><td>e</td><td>f</td></table>"
+        ));
+
+        assert_eq!(
+            texts(&tables[0]),
+            [["a", "b"], ["a", "c"], ["a", "d"], ["e", "f"]]
+        );
+    }
+
+    #[test]
+    fn finds_the_header_rows() {
+        let tables = tables(concat!(
+            "<table><caption> Prices\n of <b>fruit</b> </caption>",
+            "<tfoot><tr><td>Total</td><td>3</td></tfoot>",
+            "<thead><tr><td>Name</td><td>Price</td></thead>",
+            "<tbody><tr><th>Apple</th><td>1</td><tr><th>Pear</th><td>2</td></tbody></table>",
+            "<table><tr><th>A</th><th>B</th><tr><th>C</th><tr><td>1</td><td>2</td></table>"
+        ));
+
+        assert_eq!(tables[0].caption.as_deref(), Some("Prices of fruit"));
+        assert_eq!(tables[0].header_rows, 1);
+        assert_eq!(
+            texts(&tables[0]),
+            [
+                ["Name", "Price"],
+                ["Apple", "1"],
+                ["Pear", "2"],
+                ["Total", "3"]
+            ]
+        );
+        assert_eq!(tables[1].caption, None);
+        assert_eq!(tables[1].header_rows, 2);
+        assert_eq!(tables[1].head().len(), 2);
+        assert_eq!(tables[1].body().len(), 1);
+    }
+
+    #[test]
+    fn extracts_nested_tables_separately() {
+        let tables = tables(concat!(
+            "<table><tr><td>a <table><tr><td>b</td></tr></table> c</td><td>d</td></tr></table>",
+            "<table><tr><td>e</td></tr></table>"
+        ));
+
+        assert_eq!(tables.len(), 3);
+        assert_eq!(texts(&tables[0]), [["a c", "d"]]);
+        assert_eq!(texts(&tables[1]), [["b"]]);
+        assert_eq!(texts(&tables[2]), [["e"]]);
+    }
+
+    #[test]
+    fn normalizes_whitespace_in_cells() {
+        let tables = tables(concat!(
+            "<table><tr><td>\n  a\tb <span>c</span><br>d<p>e</p>f<script>g</script>",
+            "<span hidden>h</span></td></tr></table>"
+        ));
+
+        assert_eq!(texts(&tables[0]), [["a b c d e f"]]);
+    }
+
+    #[test]
+    fn writes_csv() {
+        let tables = tables(concat!(
+            "<table><tr><th>Name</th><th>Quote</th>",
+            "<tr><td>Smith, J.</td><td>\"Hi\"</td><tr><td colspan=2>x</td></table>"
+        ));
+
+        assert_eq!(
+            tables[0].to_csv(),
+            "Name,Quote\n\"Smith, J.\",\"\"\"Hi\"\"\"\nx,x\n"
+        );
+    }
+
+    #[test]
+    fn extracts_deeply_nested_cells() {
+        let html = format!("<table><tr><td>{}a", "<span>".repeat(200_000));
+        let tables = tables(&html);
+
+        assert_eq!(texts(&tables[0]), [["a"]]);
+    }
+
+    #[test]
+    fn limits_the_size_of_the_grid() {
+        let html = format!(
+            "<table>{}</table>",
+            "<tr><td colspan=1000 rowspan=1000>aaaaaaaaaa</td>".repeat(5000)
+        );
+        let spanned = &tables(&html)[0];
+        let cells: usize = spanned.rows.iter().map(Vec::len).sum();
+
+        assert_eq!(spanned.rows.len(), 5000);
+        assert!(cells <= 1 << 20);
+        assert!(spanned.rows[0].iter().all(|cell| cell.text == "aaaaaaaaaa"));
+
+        let html = format!(
+            "<table><tr>{}{}</table>",
+            "<td>a</td>".repeat(2000),
+            "<tr><td>b</td>".repeat(2000)
+        );
+        let padded = &tables(&html)[0];
+
+        assert_eq!(padded.rows.len(), 2001);
+        assert_eq!(padded.rows[0].len(), (1 << 20) / 2001);
+        assert_eq!(padded.rows[2000][0].text, "b");
+    }
+}
@@ -4,6 +4,10 @@ use rs_html_parser_tokens::Token;
 
 static OPTIONS: ParserOptions = ParserOptions {
     xml_mode: false,
+    whitespace: None,
+    coalesce_text: None,
+    limits: None,
+    lazy_attributes: false,
     tokenizer_options: TokenizerOptions {
         xml_mode: None,
         decode_entities: None,
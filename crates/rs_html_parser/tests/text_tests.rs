@@ -0,0 +1,93 @@
+mod tests {
+    use rs_html_parser::{Document, ParserOptions};
+
+    fn inner_text(html: &str) -> String {
+        let document = Document::parse(html, &ParserOptions::default());
+
+        document.inner_text(document.root())
+    }
+
+    #[test]
+    fn collapses_whitespace() {
+        assert_eq!(
+            inner_text("<p>  Some   <b>bold</b>\n text <span> here </span>.</p>"),
+            "Some bold text here ."
+        );
+        assert_eq!(inner_text("<div> a </div> <div> b\n</div>"), "a\nb");
+    }
+
+    #[test]
+    fn ends_lines_at_blocks_and_line_breaks() {
+        assert_eq!(
+            inner_text(
+                "<h1>Title</h1><p>One<br> two <br></p><p>Three</p><div>a<div>b</div>c</div>"
+            ),
+            "Title\n\nOne\ntwo\n\nThree\n\na\nb\nc"
+        );
+    }
+
+    #[test]
+    fn keeps_preformatted_text() {
+        assert_eq!(
+            inner_text("<p>a</p><pre>\n  b  c\n</pre><textarea> d  </textarea>"),
+            "a\n\n  b  c\n d  "
+        );
+    }
+
+    #[test]
+    fn separates_table_cells_with_tabs() {
+        assert_eq!(
+            inner_text("<table><tr><th>a <td> b</td><td>c</td></tr><tr><td>d</td></tr></table>"),
+            "a\tb\tc\nd"
+        );
+    }
+
+    #[test]
+    fn numbers_ordered_list_items() {
+        assert_eq!(
+            inner_text("<ol start=3><li>a<li value=7>b<li>c</ol><ul><li>d<li>e</ul>"),
+            "3. a\n7. b\n8. c\nd\ne"
+        );
+        assert_eq!(
+            inner_text("<ol reversed><li>a<li>b<ol><li>c</ol></ol>"),
+            "2. a\n1. b\n1. c"
+        );
+        assert_eq!(
+            inner_text(
+                "<ol start=9223372036854775807><li>a<li>b<li value=-9223372036854775808>c</ol>"
+            ),
+            "9223372036854775807. a\n9223372036854775807. b\n-9223372036854775808. c"
+        );
+        assert_eq!(
+            inner_text("<ol reversed start=-9223372036854775808><li>a<li>b</ol>"),
+            "-9223372036854775808. a\n-9223372036854775808. b"
+        );
+    }
+
+    #[test]
+    fn skips_hidden_content() {
+        assert_eq!(
+            inner_text(concat!(
+                "<head><title>a</title></head><body>b<script>c</script><style>d</style>",
+                "<template>e</template><p hidden>f</p><span aria-hidden=true>g</span>",
+                "<span aria-hidden=false>h</span></body>"
+            )),
+            "bh"
+        );
+    }
+
+    #[test]
+    fn writes_deeply_nested_documents() {
+        let html = format!(
+            "{}a<table><tr><td>b<td>c</table>{}",
+            "<div><p><span>".repeat(50_000),
+            "</span></p></div>".repeat(50_000)
+        );
+
+        assert_eq!(inner_text(&html), "a\nb\tc");
+        assert_eq!(
+            inner_text(&format!("<pre>{}\n x </pre>", "<b>".repeat(100_000))),
+            "\n x "
+        );
+    }
+}
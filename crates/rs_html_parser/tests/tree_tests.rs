@@ -0,0 +1,156 @@
+mod tests {
+    use rs_html_parser::{Document, NodeData, ParserOptions, Selector};
+
+    const HTML: &str = concat!(
+        "<!DOCTYPE html><html><head><title>a &amp; b</title>",
+        "<script>if (a < b) {}</script></head><body>",
+        "<main id=content class='page wide'><h1>Title</h1>",
+        "<p class=lead>First <a href='/one' hreflang=en-US>one</a></p>",
+        "<p>Second <a href='http://x.org/two' rel='nofollow noopener'>two</a></p>",
+        "<ul><li>1<li class=active>2<li>3</ul><!-- note --><p></p></main>",
+        "<input disabled value='a \"b\"'></body></html>"
+    );
+
+    fn select(document: &Document, selector: &str) -> Vec<String> {
+        let selector = Selector::parse(selector).unwrap();
+
+        document
+            .select(document.root(), &selector)
+            .map(|id| document.outer_html(id))
+            .collect()
+    }
+
+    #[test]
+    fn builds_the_tree() {
+        let document = Document::parse(HTML, &ParserOptions::default());
+        let html = document.element_children(document.root()).next().unwrap();
+
+        assert_eq!(document.name(html).unwrap().as_str(), "html");
+        assert_eq!(document[html].parent, Some(document.root()));
+        assert!(matches!(
+            document[document[document.root()].children[0]].data,
+            NodeData::ProcessingInstruction(_)
+        ));
+
+        let title = Selector::parse("title").unwrap();
+        let title = document.select(document.root(), &title).next().unwrap();
+
+        assert_eq!(document.text_content(title), "a & b");
+    }
+
+    #[test]
+    fn serializes_the_tree() {
+        let document = Document::parse(HTML, &ParserOptions::default());
+
+        insta::assert_snapshot!(document.outer_html(document.root()))
+    }
+
+    #[test]
+    fn serialized_tree_parses_to_the_same_tree() {
+        let options = ParserOptions::default();
+        let document = Document::parse(HTML, &options);
+        let html = document.outer_html(document.root());
+        let document = Document::parse(&html, &options);
+
+        assert_eq!(document.outer_html(document.root()), html);
+    }
+
+    #[test]
+    fn text_in_foreign_content_is_escaped() {
+        let options = ParserOptions::default();
+        let html = concat!(
+            "<svg><style>a &lt;b&gt; &amp;</style><foreignObject><style>p > a</style>",
+            "</foreignObject></svg><style>p > a</style>"
+        );
+        let document = Document::parse(html, &options);
+        let serialized = document.outer_html(document.root());
+
+        assert_eq!(serialized, html);
+        assert_eq!(Document::parse(&serialized, &options), document);
+
+        let style = document[document[document.root()].children[0]].children[0];
+
+        assert_eq!(document.inner_html(style), "a &lt;b&gt; &amp;");
+    }
+
+    #[test]
+    fn deeply_nested_documents() {
+        let html = "<div><b>".repeat(100_000);
+        let document = Document::parse(&html, &ParserOptions::default());
+        let serialized = document.outer_html(document.root());
+
+        assert!(serialized.starts_with("<div><b><div>"));
+        assert!(serialized.ends_with("</b></div></b></div>"));
+        assert_eq!(serialized.len(), html.len() + "</b></div>".len() * 100_000);
+        assert_eq!(document.inner_html(document.root()), serialized);
+    }
+
+    #[test]
+    fn end_tags_ignore_case() {
+        let document = Document::parse(
+            "<div><Script>a</SCRIPT><SPAN>b</span></div><p>c",
+            &ParserOptions::default(),
+        );
+        let div = document.element_children(document.root()).next().unwrap();
+
+        assert_eq!(
+            document.outer_html(div),
+            "<div><Script>a</Script><SPAN>b</SPAN></div>"
+        );
+    }
+
+    #[test]
+    fn selectors() {
+        let document = Document::parse(HTML, &ParserOptions::default());
+
+        insta::assert_debug_snapshot!([
+            select(&document, "main > p.lead a"),
+            select(&document, "#content h1, li.active"),
+            select(&document, "A[HREF^=http]"),
+            select(&document, "a[rel~=noopener], a[hreflang|=en]"),
+            select(&document, "a[href$='/ONE' i], a[href*=x]"),
+            select(&document, "li:first-child, li:last-child"),
+            select(&document, "h1 + p > a, ul ~ p:empty"),
+            select(&document, "main > :not(p):not(ul)"),
+            select(&document, "li:not(:first-child):not(:last-child), h1:only-child"),
+            select(&document, "input[disabled]"),
+        ])
+    }
+
+    #[test]
+    fn selectors_on_large_documents() {
+        let html = format!("{}<span>", "<div>".repeat(40));
+        let document = Document::parse(&html, &ParserOptions::default());
+
+        assert!(select(&document, &format!("p{}", " div".repeat(10))).is_empty());
+        assert!(select(&document, &format!("p ~ div{}", " div".repeat(10))).is_empty());
+        assert_eq!(
+            select(&document, &format!("div{}", " div".repeat(9))).len(),
+            31
+        );
+        assert_eq!(
+            select(&document, &format!("{}span", "div ".repeat(10))).len(),
+            1
+        );
+
+        let html = format!("<ul>{}</ul>", "<li>a</li> ".repeat(50_000));
+        let document = Document::parse(&html, &ParserOptions::default());
+
+        assert_eq!(select(&document, "li:first-child"), ["<li>a</li>"]);
+        assert_eq!(select(&document, "li:last-child").len(), 1);
+        assert_eq!(select(&document, "li + li").len(), 49_999);
+        assert!(select(&document, "p ~ li, li:only-child").is_empty());
+    }
+
+    #[test]
+    fn invalid_selectors() {
+        insta::assert_debug_snapshot!([
+            Selector::parse(""),
+            Selector::parse("a["),
+            Selector::parse("a[href=\"x]"),
+            Selector::parse("a > "),
+            Selector::parse("a:hover"),
+            Selector::parse("a{}"),
+        ])
+    }
+}
@@ -0,0 +1,122 @@
+mod tests {
+    use rs_html_parser::{validate_stream, StreamError, StreamErrorKind};
+    use rs_html_parser_tokens::{Token, TokenKind};
+
+    fn token(kind: TokenKind, data: &str) -> Token {
+        Token {
+            data: data.into(),
+            attrs: None,
+            raw_attrs: None,
+            kind,
+            is_implied: false,
+            is_cdata: false,
+            start: None,
+        }
+    }
+
+    fn error(index: usize, kind: StreamErrorKind) -> Result<(), StreamError> {
+        Err(StreamError { index, kind })
+    }
+
+    #[test]
+    fn balanced_stream() {
+        let tokens = vec![
+            token(TokenKind::OpenTag, "p"),
+            token(TokenKind::OpenTag, "br"),
+            token(TokenKind::CloseTag, "br"),
+            token(TokenKind::Comment, "a"),
+            token(TokenKind::CommentEnd, ""),
+            token(TokenKind::CloseTag, "p"),
+        ];
+
+        assert_eq!(validate_stream(tokens, false), Ok(()));
+    }
+
+    #[test]
+    fn mismatched_close_tag() {
+        let tokens = vec![
+            token(TokenKind::OpenTag, "p"),
+            token(TokenKind::CloseTag, "div"),
+        ];
+
+        assert_eq!(
+            validate_stream(tokens, false),
+            error(
+                1,
+                StreamErrorKind::MismatchedCloseTag {
+                    expected: Some("p".into()),
+                    found: "div".into(),
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn unclosed_void_element() {
+        let tokens = || {
+            vec![
+                token(TokenKind::OpenTag, "br"),
+                token(TokenKind::Text, "a"),
+                token(TokenKind::CloseTag, "br"),
+            ]
+        };
+
+        assert_eq!(
+            validate_stream(tokens(), false),
+            error(1, StreamErrorKind::UnclosedVoidElement("br".into()))
+        );
+        assert_eq!(validate_stream(tokens(), true), Ok(()));
+    }
+
+    #[test]
+    fn unclosed_comment() {
+        let tokens = vec![
+            token(TokenKind::Comment, "a"),
+            token(TokenKind::Text, "b"),
+        ];
+
+        assert_eq!(
+            validate_stream(tokens, false),
+            error(1, StreamErrorKind::UnclosedComment)
+        );
+    }
+
+    #[test]
+    fn unexpected_comment_end() {
+        let tokens = vec![token(TokenKind::CommentEnd, "")];
+
+        assert_eq!(
+            validate_stream(tokens, false),
+            error(0, StreamErrorKind::UnexpectedCommentEnd)
+        );
+    }
+
+    #[test]
+    fn unclosed_elements() {
+        let tokens = vec![token(TokenKind::OpenTag, "a"), token(TokenKind::OpenTag, "b")];
+
+        assert_eq!(
+            validate_stream(tokens, false),
+            error(
+                2,
+                StreamErrorKind::UnclosedElements(vec!["b".into(), "a".into()])
+            )
+        );
+    }
+
+    #[test]
+    fn token_after_end() {
+        let mut items = vec![
+            Some(token(TokenKind::Text, "a")),
+            None,
+            Some(token(TokenKind::Text, "b")),
+        ]
+        .into_iter();
+        let unfused = std::iter::from_fn(move || items.next().flatten());
+
+        assert_eq!(
+            validate_stream(unfused, false),
+            error(2, StreamErrorKind::TokenAfterEnd)
+        );
+    }
+}
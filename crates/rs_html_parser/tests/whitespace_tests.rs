@@ -0,0 +1,68 @@
+mod tests {
+    use rs_html_parser::{Parser, ParserOptions, WhitespaceMode};
+    use rs_html_parser_tokens::Token;
+
+    fn parser_test(data: &str, whitespace: WhitespaceMode) -> Vec<Token> {
+        let options = ParserOptions {
+            whitespace: Some(whitespace),
+            ..Default::default()
+        };
+
+        Parser::new(data, &options).collect()
+    }
+
+    const TEMPLATE: &str = "<ul>\n  <li> a  &amp;\n b </li>\n  <li>\tc</li>\n</ul>\n<pre>\n  x\n</pre>";
+
+    #[test]
+    fn preserve() {
+        insta::assert_debug_snapshot!(parser_test(TEMPLATE, WhitespaceMode::Preserve))
+    }
+
+    #[test]
+    fn drop_inter_element() {
+        insta::assert_debug_snapshot!(parser_test(TEMPLATE, WhitespaceMode::DropInterElement))
+    }
+
+    #[test]
+    fn collapse() {
+        insta::assert_debug_snapshot!(parser_test(TEMPLATE, WhitespaceMode::Collapse))
+    }
+
+    #[test]
+    fn trim() {
+        insta::assert_debug_snapshot!(parser_test(TEMPLATE, WhitespaceMode::Trim))
+    }
+
+    #[test]
+    fn textarea_leading_newline() {
+        insta::assert_debug_snapshot!(parser_test(
+            "<textarea>\n\n  a  </textarea><listing>\r\nb</listing>",
+            WhitespaceMode::Trim
+        ))
+    }
+
+    #[test]
+    fn xml_space_preserve() {
+        insta::assert_debug_snapshot!(parser_test(
+            "<svg xml:space=\"preserve\"><text> a  <tspan xml:space=\"default\"> b  </tspan></text></svg>",
+            WhitespaceMode::Trim
+        ))
+    }
+
+    #[test]
+    fn raw_text_is_preserved() {
+        insta::assert_debug_snapshot!(parser_test(
+            "<script>\n  let a;\n</script><style> a {} </style>",
+            WhitespaceMode::Trim
+        ))
+    }
+
+    #[test]
+    fn whitespace_only_text() {
+        let tokens = parser_test("<b> \n</b><i> x </i>", WhitespaceMode::Preserve);
+
+        assert!(tokens[1].is_whitespace());
+        assert!(!tokens[4].is_whitespace());
+        assert!(!tokens[0].is_whitespace());
+    }
+}
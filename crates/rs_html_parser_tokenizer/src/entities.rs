@@ -0,0 +1,151 @@
+/*!
+ * The named and numeric character references of HTML. The tables and the trie of the names are
+ * generated static arrays, so they are available without `std` and without allocating.
+ */
+
+use crate::entity_table::{TRIE_EDGES, TRIE_NODES, TRIE_VALUES};
+
+pub use crate::entity_table::ENTITIES;
+
+/** The length of the longest name, including the `&` and the `;`. */
+pub const ENTITY_MAX_LENGTH: usize = 33;
+
+/** The length of the shortest name, including the `&`. */
+pub const ENTITY_MIN_LENGTH: usize = 3;
+
+/** Code points above this are outside of Unicode, numeric references stop growing past it. */
+pub const MAX_CODE_POINT: u32 = 0x10FFFF;
+
+/** The characters of the named reference `name`, eg. `&amp;`, or `None` when there is none. */
+pub fn lookup(name: &[u8]) -> Option<&'static str> {
+    ENTITIES
+        .binary_search_by(|(entity, _)| entity.as_bytes().cmp(name))
+        .ok()
+        .map(|index| ENTITIES[index].1)
+}
+
+/** A named reference found by `EntityMatcher`. */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EntityMatch {
+    /** The length of the name after the `&`, including the `;` if it has one. */
+    pub length: usize,
+    /** Whether the name ends with a `;`. Only the legacy names can be used without it. */
+    pub is_closed: bool,
+    /** The code points of the reference, the second one is 0 for most of them. */
+    pub code_points: (u32, u32),
+}
+
+/**
+ * Matches the longest named reference incrementally, one byte at a time, by walking the trie of
+ * the names. It does not need the whole name at once, so a name can be split across chunks of
+ * input: the matcher keeps its place between them.
+ *
+ * The bytes that were pushed after the longest match are not part of the reference. Fewer than
+ * `ENTITY_MAX_LENGTH` bytes are pushed before the matcher stops.
+ */
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EntityMatcher {
+    node: u16,
+    length: usize,
+    longest_match: Option<EntityMatch>,
+}
+
+impl EntityMatcher {
+    /** A matcher at the start of a name, after the `&`. */
+    pub fn new() -> EntityMatcher {
+        EntityMatcher::default()
+    }
+
+    /**
+     * Pushes the next byte of the name. Returns `false` when no name continues with it, the byte
+     * is not pushed then and the matching is done.
+     */
+    pub fn push(&mut self, c: u8) -> bool {
+        let (edges_start, edge_count, _) = TRIE_NODES[self.node as usize];
+        let edges_start = edges_start as usize;
+        let edges = &TRIE_EDGES[edges_start..edges_start + edge_count as usize];
+
+        let Ok(edge) = edges.binary_search_by_key(&c, |(byte, _)| *byte) else {
+            return false;
+        };
+
+        self.node = edges[edge].1;
+        self.length += 1;
+
+        let value = TRIE_NODES[self.node as usize].2;
+        if value != 0 {
+            self.longest_match = Some(EntityMatch {
+                length: self.length,
+                is_closed: c == b';',
+                code_points: TRIE_VALUES[value as usize - 1],
+            });
+        }
+
+        true
+    }
+
+    /** The number of bytes pushed. */
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /** The longest name in the pushed bytes, if any. */
+    pub fn longest_match(&self) -> Option<EntityMatch> {
+        self.longest_match
+    }
+}
+
+/** The longest named reference at the start of `name`, the bytes after the `&`. */
+pub fn longest_match(name: &[u8]) -> Option<EntityMatch> {
+    let mut matcher = EntityMatcher::new();
+
+    for c in name {
+        if !matcher.push(*c) {
+            break;
+        }
+    }
+
+    matcher.longest_match()
+}
+
+/**
+ * The code point of a numeric reference to `code`, with the replacements of the spec. Null,
+ * surrogates and code points outside of Unicode are replaced with U+FFFD.
+ */
+pub fn numeric_code_point(code: u32) -> u32 {
+    match code {
+        0x80 => 0x20AC,
+        0x82 => 0x201A,
+        0x83 => 0x0192,
+        0x84 => 0x201E,
+        0x85 => 0x2026,
+        0x86 => 0x2020,
+        0x87 => 0x2021,
+        0x88 => 0x02C6,
+        0x89 => 0x2030,
+        0x8A => 0x0160,
+        0x8B => 0x2039,
+        0x8C => 0x0152,
+        0x8E => 0x017D,
+        0x91 => 0x2018,
+        0x92 => 0x2019,
+        0x93 => 0x201C,
+        0x94 => 0x201D,
+        0x95 => 0x2022,
+        0x96 => 0x2013,
+        0x97 => 0x2014,
+        0x98 => 0x02DC,
+        0x99 => 0x2122,
+        0x9A => 0x0161,
+        0x9B => 0x203A,
+        0x9C => 0x0153,
+        0x9E => 0x017E,
+        0x9F => 0x0178,
+        0 | 0xD800..=0xDFFF | 0x110000.. => char::REPLACEMENT_CHARACTER as u32,
+        code => code,
+    }
+}
@@ -0,0 +1,5382 @@
+// Generated by scripts/entities_generator.js from the WHATWG entities.json, do not edit.
+
+/**
+ * The named character references of HTML and their characters, sorted by name. The names
+ * include the `&`, the legacy ones are listed both with and without the `;`.
+ */
+pub static ENTITIES: [(&str, &str); 2231] = [
+    ("&AElig", "\u{c6}"),
+    ("&AElig;", "\u{c6}"),
+    ("&AMP", "&"),
+    ("&AMP;", "&"),
+    ("&Aacute", "\u{c1}"),
+    ("&Aacute;", "\u{c1}"),
+    ("&Abreve;", "\u{102}"),
+    ("&Acirc", "\u{c2}"),
+    ("&Acirc;", "\u{c2}"),
+    ("&Acy;", "\u{410}"),
+    ("&Afr;", "\u{1d504}"),
+    ("&Agrave", "\u{c0}"),
+    ("&Agrave;", "\u{c0}"),
+    ("&Alpha;", "\u{391}"),
+    ("&Amacr;", "\u{100}"),
+    ("&And;", "\u{2a53}"),
+    ("&Aogon;", "\u{104}"),
+    ("&Aopf;", "\u{1d538}"),
+    ("&ApplyFunction;", "\u{2061}"),
+    ("&Aring", "\u{c5}"),
+    ("&Aring;", "\u{c5}"),
+    ("&Ascr;", "\u{1d49c}"),
+    ("&Assign;", "\u{2254}"),
+    ("&Atilde", "\u{c3}"),
+    ("&Atilde;", "\u{c3}"),
+    ("&Auml", "\u{c4}"),
+    ("&Auml;", "\u{c4}"),
+    ("&Backslash;", "\u{2216}"),
+    ("&Barv;", "\u{2ae7}"),
+    ("&Barwed;", "\u{2306}"),
+    ("&Bcy;", "\u{411}"),
+    ("&Because;", "\u{2235}"),
+    ("&Bernoullis;", "\u{212c}"),
+    ("&Beta;", "\u{392}"),
+    ("&Bfr;", "\u{1d505}"),
+    ("&Bopf;", "\u{1d539}"),
+    ("&Breve;", "\u{2d8}"),
+    ("&Bscr;", "\u{212c}"),
+    ("&Bumpeq;", "\u{224e}"),
+    ("&CHcy;", "\u{427}"),
+    ("&COPY", "\u{a9}"),
+    ("&COPY;", "\u{a9}"),
+    ("&Cacute;", "\u{106}"),
+    ("&Cap;", "\u{22d2}"),
+    ("&CapitalDifferentialD;", "\u{2145}"),
+    ("&Cayleys;", "\u{212d}"),
+    ("&Ccaron;", "\u{10c}"),
+    ("&Ccedil", "\u{c7}"),
+    ("&Ccedil;", "\u{c7}"),
+    ("&Ccirc;", "\u{108}"),
+    ("&Cconint;", "\u{2230}"),
+    ("&Cdot;", "\u{10a}"),
+    ("&Cedilla;", "\u{b8}"),
+    ("&CenterDot;", "\u{b7}"),
+    ("&Cfr;", "\u{212d}"),
+    ("&Chi;", "\u{3a7}"),
+    ("&CircleDot;", "\u{2299}"),
+    ("&CircleMinus;", "\u{2296}"),
+    ("&CirclePlus;", "\u{2295}"),
+    ("&CircleTimes;", "\u{2297}"),
+    ("&ClockwiseContourIntegral;", "\u{2232}"),
+    ("&CloseCurlyDoubleQuote;", "\u{201d}"),
+    ("&CloseCurlyQuote;", "\u{2019}"),
+    ("&Colon;", "\u{2237}"),
+    ("&Colone;", "\u{2a74}"),
+    ("&Congruent;", "\u{2261}"),
+    ("&Conint;", "\u{222f}"),
+    ("&ContourIntegral;", "\u{222e}"),
+    ("&Copf;", "\u{2102}"),
+    ("&Coproduct;", "\u{2210}"),
+    ("&CounterClockwiseContourIntegral;", "\u{2233}"),
+    ("&Cross;", "\u{2a2f}"),
+    ("&Cscr;", "\u{1d49e}"),
+    ("&Cup;", "\u{22d3}"),
+    ("&CupCap;", "\u{224d}"),
+    ("&DD;", "\u{2145}"),
+    ("&DDotrahd;", "\u{2911}"),
+    ("&DJcy;", "\u{402}"),
+    ("&DScy;", "\u{405}"),
+    ("&DZcy;", "\u{40f}"),
+    ("&Dagger;", "\u{2021}"),
+    ("&Darr;", "\u{21a1}"),
+    ("&Dashv;", "\u{2ae4}"),
+    ("&Dcaron;", "\u{10e}"),
+    ("&Dcy;", "\u{414}"),
+    ("&Del;", "\u{2207}"),
+    ("&Delta;", "\u{394}"),
+    ("&Dfr;", "\u{1d507}"),
+    ("&DiacriticalAcute;", "\u{b4}"),
+    ("&DiacriticalDot;", "\u{2d9}"),
+    ("&DiacriticalDoubleAcute;", "\u{2dd}"),
+    ("&DiacriticalGrave;", "`"),
+    ("&DiacriticalTilde;", "\u{2dc}"),
+    ("&Diamond;", "\u{22c4}"),
+    ("&DifferentialD;", "\u{2146}"),
+    ("&Dopf;", "\u{1d53b}"),
+    ("&Dot;", "\u{a8}"),
+    ("&DotDot;", "\u{20dc}"),
+    ("&DotEqual;", "\u{2250}"),
+    ("&DoubleContourIntegral;", "\u{222f}"),
+    ("&DoubleDot;", "\u{a8}"),
+    ("&DoubleDownArrow;", "\u{21d3}"),
+    ("&DoubleLeftArrow;", "\u{21d0}"),
+    ("&DoubleLeftRightArrow;", "\u{21d4}"),
+    ("&DoubleLeftTee;", "\u{2ae4}"),
+    ("&DoubleLongLeftArrow;", "\u{27f8}"),
+    ("&DoubleLongLeftRightArrow;", "\u{27fa}"),
+    ("&DoubleLongRightArrow;", "\u{27f9}"),
+    ("&DoubleRightArrow;", "\u{21d2}"),
+    ("&DoubleRightTee;", "\u{22a8}"),
+    ("&DoubleUpArrow;", "\u{21d1}"),
+    ("&DoubleUpDownArrow;", "\u{21d5}"),
+    ("&DoubleVerticalBar;", "\u{2225}"),
+    ("&DownArrow;", "\u{2193}"),
+    ("&DownArrowBar;", "\u{2913}"),
+    ("&DownArrowUpArrow;", "\u{21f5}"),
+    ("&DownBreve;", "\u{311}"),
+    ("&DownLeftRightVector;", "\u{2950}"),
+    ("&DownLeftTeeVector;", "\u{295e}"),
+    ("&DownLeftVector;", "\u{21bd}"),
+    ("&DownLeftVectorBar;", "\u{2956}"),
+    ("&DownRightTeeVector;", "\u{295f}"),
+    ("&DownRightVector;", "\u{21c1}"),
+    ("&DownRightVectorBar;", "\u{2957}"),
+    ("&DownTee;", "\u{22a4}"),
+    ("&DownTeeArrow;", "\u{21a7}"),
+    ("&Downarrow;", "\u{21d3}"),
+    ("&Dscr;", "\u{1d49f}"),
+    ("&Dstrok;", "\u{110}"),
+    ("&ENG;", "\u{14a}"),
+    ("&ETH", "\u{d0}"),
+    ("&ETH;", "\u{d0}"),
+    ("&Eacute", "\u{c9}"),
+    ("&Eacute;", "\u{c9}"),
+    ("&Ecaron;", "\u{11a}"),
+    ("&Ecirc", "\u{ca}"),
+    ("&Ecirc;", "\u{ca}"),
+    ("&Ecy;", "\u{42d}"),
+    ("&Edot;", "\u{116}"),
+    ("&Efr;", "\u{1d508}"),
+    ("&Egrave", "\u{c8}"),
+    ("&Egrave;", "\u{c8}"),
+    ("&Element;", "\u{2208}"),
+    ("&Emacr;", "\u{112}"),
+    ("&EmptySmallSquare;", "\u{25fb}"),
+    ("&EmptyVerySmallSquare;", "\u{25ab}"),
+    ("&Eogon;", "\u{118}"),
+    ("&Eopf;", "\u{1d53c}"),
+    ("&Epsilon;", "\u{395}"),
+    ("&Equal;", "\u{2a75}"),
+    ("&EqualTilde;", "\u{2242}"),
+    ("&Equilibrium;", "\u{21cc}"),
+    ("&Escr;", "\u{2130}"),
+    ("&Esim;", "\u{2a73}"),
+    ("&Eta;", "\u{397}"),
+    ("&Euml", "\u{cb}"),
+    ("&Euml;", "\u{cb}"),
+    ("&Exists;", "\u{2203}"),
+    ("&ExponentialE;", "\u{2147}"),
+    ("&Fcy;", "\u{424}"),
+    ("&Ffr;", "\u{1d509}"),
+    ("&FilledSmallSquare;", "\u{25fc}"),
+    ("&FilledVerySmallSquare;", "\u{25aa}"),
+    ("&Fopf;", "\u{1d53d}"),
+    ("&ForAll;", "\u{2200}"),
+    ("&Fouriertrf;", "\u{2131}"),
+    ("&Fscr;", "\u{2131}"),
+    ("&GJcy;", "\u{403}"),
+    ("&GT", ">"),
+    ("&GT;", ">"),
+    ("&Gamma;", "\u{393}"),
+    ("&Gammad;", "\u{3dc}"),
+    ("&Gbreve;", "\u{11e}"),
+    ("&Gcedil;", "\u{122}"),
+    ("&Gcirc;", "\u{11c}"),
+    ("&Gcy;", "\u{413}"),
+    ("&Gdot;", "\u{120}"),
+    ("&Gfr;", "\u{1d50a}"),
+    ("&Gg;", "\u{22d9}"),
+    ("&Gopf;", "\u{1d53e}"),
+    ("&GreaterEqual;", "\u{2265}"),
+    ("&GreaterEqualLess;", "\u{22db}"),
+    ("&GreaterFullEqual;", "\u{2267}"),
+    ("&GreaterGreater;", "\u{2aa2}"),
+    ("&GreaterLess;", "\u{2277}"),
+    ("&GreaterSlantEqual;", "\u{2a7e}"),
+    ("&GreaterTilde;", "\u{2273}"),
+    ("&Gscr;", "\u{1d4a2}"),
+    ("&Gt;", "\u{226b}"),
+    ("&HARDcy;", "\u{42a}"),
+    ("&Hacek;", "\u{2c7}"),
+    ("&Hat;", "^"),
+    ("&Hcirc;", "\u{124}"),
+    ("&Hfr;", "\u{210c}"),
+    ("&HilbertSpace;", "\u{210b}"),
+    ("&Hopf;", "\u{210d}"),
+    ("&HorizontalLine;", "\u{2500}"),
+    ("&Hscr;", "\u{210b}"),
+    ("&Hstrok;", "\u{126}"),
+    ("&HumpDownHump;", "\u{224e}"),
+    ("&HumpEqual;", "\u{224f}"),
+    ("&IEcy;", "\u{415}"),
+    ("&IJlig;", "\u{132}"),
+    ("&IOcy;", "\u{401}"),
+    ("&Iacute", "\u{cd}"),
+    ("&Iacute;", "\u{cd}"),
+    ("&Icirc", "\u{ce}"),
+    ("&Icirc;", "\u{ce}"),
+    ("&Icy;", "\u{418}"),
+    ("&Idot;", "\u{130}"),
+    ("&Ifr;", "\u{2111}"),
+    ("&Igrave", "\u{cc}"),
+    ("&Igrave;", "\u{cc}"),
+    ("&Im;", "\u{2111}"),
+    ("&Imacr;", "\u{12a}"),
+    ("&ImaginaryI;", "\u{2148}"),
+    ("&Implies;", "\u{21d2}"),
+    ("&Int;", "\u{222c}"),
+    ("&Integral;", "\u{222b}"),
+    ("&Intersection;", "\u{22c2}"),
+    ("&InvisibleComma;", "\u{2063}"),
+    ("&InvisibleTimes;", "\u{2062}"),
+    ("&Iogon;", "\u{12e}"),
+    ("&Iopf;", "\u{1d540}"),
+    ("&Iota;", "\u{399}"),
+    ("&Iscr;", "\u{2110}"),
+    ("&Itilde;", "\u{128}"),
+    ("&Iukcy;", "\u{406}"),
+    ("&Iuml", "\u{cf}"),
+    ("&Iuml;", "\u{cf}"),
+    ("&Jcirc;", "\u{134}"),
+    ("&Jcy;", "\u{419}"),
+    ("&Jfr;", "\u{1d50d}"),
+    ("&Jopf;", "\u{1d541}"),
+    ("&Jscr;", "\u{1d4a5}"),
+    ("&Jsercy;", "\u{408}"),
+    ("&Jukcy;", "\u{404}"),
+    ("&KHcy;", "\u{425}"),
+    ("&KJcy;", "\u{40c}"),
+    ("&Kappa;", "\u{39a}"),
+    ("&Kcedil;", "\u{136}"),
+    ("&Kcy;", "\u{41a}"),
+    ("&Kfr;", "\u{1d50e}"),
+    ("&Kopf;", "\u{1d542}"),
+    ("&Kscr;", "\u{1d4a6}"),
+    ("&LJcy;", "\u{409}"),
+    ("&LT", "<"),
+    ("&LT;", "<"),
+    ("&Lacute;", "\u{139}"),
+    ("&Lambda;", "\u{39b}"),
+    ("&Lang;", "\u{27ea}"),
+    ("&Laplacetrf;", "\u{2112}"),
+    ("&Larr;", "\u{219e}"),
+    ("&Lcaron;", "\u{13d}"),
+    ("&Lcedil;", "\u{13b}"),
+    ("&Lcy;", "\u{41b}"),
+    ("&LeftAngleBracket;", "\u{27e8}"),
+    ("&LeftArrow;", "\u{2190}"),
+    ("&LeftArrowBar;", "\u{21e4}"),
+    ("&LeftArrowRightArrow;", "\u{21c6}"),
+    ("&LeftCeiling;", "\u{2308}"),
+    ("&LeftDoubleBracket;", "\u{27e6}"),
+    ("&LeftDownTeeVector;", "\u{2961}"),
+    ("&LeftDownVector;", "\u{21c3}"),
+    ("&LeftDownVectorBar;", "\u{2959}"),
+    ("&LeftFloor;", "\u{230a}"),
+    ("&LeftRightArrow;", "\u{2194}"),
+    ("&LeftRightVector;", "\u{294e}"),
+    ("&LeftTee;", "\u{22a3}"),
+    ("&LeftTeeArrow;", "\u{21a4}"),
+    ("&LeftTeeVector;", "\u{295a}"),
+    ("&LeftTriangle;", "\u{22b2}"),
+    ("&LeftTriangleBar;", "\u{29cf}"),
+    ("&LeftTriangleEqual;", "\u{22b4}"),
+    ("&LeftUpDownVector;", "\u{2951}"),
+    ("&LeftUpTeeVector;", "\u{2960}"),
+    ("&LeftUpVector;", "\u{21bf}"),
+    ("&LeftUpVectorBar;", "\u{2958}"),
+    ("&LeftVector;", "\u{21bc}"),
+    ("&LeftVectorBar;", "\u{2952}"),
+    ("&Leftarrow;", "\u{21d0}"),
+    ("&Leftrightarrow;", "\u{21d4}"),
+    ("&LessEqualGreater;", "\u{22da}"),
+    ("&LessFullEqual;", "\u{2266}"),
+    ("&LessGreater;", "\u{2276}"),
+    ("&LessLess;", "\u{2aa1}"),
+    ("&LessSlantEqual;", "\u{2a7d}"),
+    ("&LessTilde;", "\u{2272}"),
+    ("&Lfr;", "\u{1d50f}"),
+    ("&Ll;", "\u{22d8}"),
+    ("&Lleftarrow;", "\u{21da}"),
+    ("&Lmidot;", "\u{13f}"),
+    ("&LongLeftArrow;", "\u{27f5}"),
+    ("&LongLeftRightArrow;", "\u{27f7}"),
+    ("&LongRightArrow;", "\u{27f6}"),
+    ("&Longleftarrow;", "\u{27f8}"),
+    ("&Longleftrightarrow;", "\u{27fa}"),
+    ("&Longrightarrow;", "\u{27f9}"),
+    ("&Lopf;", "\u{1d543}"),
+    ("&LowerLeftArrow;", "\u{2199}"),
+    ("&LowerRightArrow;", "\u{2198}"),
+    ("&Lscr;", "\u{2112}"),
+    ("&Lsh;", "\u{21b0}"),
+    ("&Lstrok;", "\u{141}"),
+    ("&Lt;", "\u{226a}"),
+    ("&Map;", "\u{2905}"),
+    ("&Mcy;", "\u{41c}"),
+    ("&MediumSpace;", "\u{205f}"),
+    ("&Mellintrf;", "\u{2133}"),
+    ("&Mfr;", "\u{1d510}"),
+    ("&MinusPlus;", "\u{2213}"),
+    ("&Mopf;", "\u{1d544}"),
+    ("&Mscr;", "\u{2133}"),
+    ("&Mu;", "\u{39c}"),
+    ("&NJcy;", "\u{40a}"),
+    ("&Nacute;", "\u{143}"),
+    ("&Ncaron;", "\u{147}"),
+    ("&Ncedil;", "\u{145}"),
+    ("&Ncy;", "\u{41d}"),
+    ("&NegativeMediumSpace;", "\u{200b}"),
+    ("&NegativeThickSpace;", "\u{200b}"),
+    ("&NegativeThinSpace;", "\u{200b}"),
+    ("&NegativeVeryThinSpace;", "\u{200b}"),
+    ("&NestedGreaterGreater;", "\u{226b}"),
+    ("&NestedLessLess;", "\u{226a}"),
+    ("&NewLine;", "\u{a}"),
+    ("&Nfr;", "\u{1d511}"),
+    ("&NoBreak;", "\u{2060}"),
+    ("&NonBreakingSpace;", "\u{a0}"),
+    ("&Nopf;", "\u{2115}"),
+    ("&Not;", "\u{2aec}"),
+    ("&NotCongruent;", "\u{2262}"),
+    ("&NotCupCap;", "\u{226d}"),
+    ("&NotDoubleVerticalBar;", "\u{2226}"),
+    ("&NotElement;", "\u{2209}"),
+    ("&NotEqual;", "\u{2260}"),
+    ("&NotEqualTilde;", "\u{2242}\u{338}"),
+    ("&NotExists;", "\u{2204}"),
+    ("&NotGreater;", "\u{226f}"),
+    ("&NotGreaterEqual;", "\u{2271}"),
+    ("&NotGreaterFullEqual;", "\u{2267}\u{338}"),
+    ("&NotGreaterGreater;", "\u{226b}\u{338}"),
+    ("&NotGreaterLess;", "\u{2279}"),
+    ("&NotGreaterSlantEqual;", "\u{2a7e}\u{338}"),
+    ("&NotGreaterTilde;", "\u{2275}"),
+    ("&NotHumpDownHump;", "\u{224e}\u{338}"),
+    ("&NotHumpEqual;", "\u{224f}\u{338}"),
+    ("&NotLeftTriangle;", "\u{22ea}"),
+    ("&NotLeftTriangleBar;", "\u{29cf}\u{338}"),
+    ("&NotLeftTriangleEqual;", "\u{22ec}"),
+    ("&NotLess;", "\u{226e}"),
+    ("&NotLessEqual;", "\u{2270}"),
+    ("&NotLessGreater;", "\u{2278}"),
+    ("&NotLessLess;", "\u{226a}\u{338}"),
+    ("&NotLessSlantEqual;", "\u{2a7d}\u{338}"),
+    ("&NotLessTilde;", "\u{2274}"),
+    ("&NotNestedGreaterGreater;", "\u{2aa2}\u{338}"),
+    ("&NotNestedLessLess;", "\u{2aa1}\u{338}"),
+    ("&NotPrecedes;", "\u{2280}"),
+    ("&NotPrecedesEqual;", "\u{2aaf}\u{338}"),
+    ("&NotPrecedesSlantEqual;", "\u{22e0}"),
+    ("&NotReverseElement;", "\u{220c}"),
+    ("&NotRightTriangle;", "\u{22eb}"),
+    ("&NotRightTriangleBar;", "\u{29d0}\u{338}"),
+    ("&NotRightTriangleEqual;", "\u{22ed}"),
+    ("&NotSquareSubset;", "\u{228f}\u{338}"),
+    ("&NotSquareSubsetEqual;", "\u{22e2}"),
+    ("&NotSquareSuperset;", "\u{2290}\u{338}"),
+    ("&NotSquareSupersetEqual;", "\u{22e3}"),
+    ("&NotSubset;", "\u{2282}\u{20d2}"),
+    ("&NotSubsetEqual;", "\u{2288}"),
+    ("&NotSucceeds;", "\u{2281}"),
+    ("&NotSucceedsEqual;", "\u{2ab0}\u{338}"),
+    ("&NotSucceedsSlantEqual;", "\u{22e1}"),
+    ("&NotSucceedsTilde;", "\u{227f}\u{338}"),
+    ("&NotSuperset;", "\u{2283}\u{20d2}"),
+    ("&NotSupersetEqual;", "\u{2289}"),
+    ("&NotTilde;", "\u{2241}"),
+    ("&NotTildeEqual;", "\u{2244}"),
+    ("&NotTildeFullEqual;", "\u{2247}"),
+    ("&NotTildeTilde;", "\u{2249}"),
+    ("&NotVerticalBar;", "\u{2224}"),
+    ("&Nscr;", "\u{1d4a9}"),
+    ("&Ntilde", "\u{d1}"),
+    ("&Ntilde;", "\u{d1}"),
+    ("&Nu;", "\u{39d}"),
+    ("&OElig;", "\u{152}"),
+    ("&Oacute", "\u{d3}"),
+    ("&Oacute;", "\u{d3}"),
+    ("&Ocirc", "\u{d4}"),
+    ("&Ocirc;", "\u{d4}"),
+    ("&Ocy;", "\u{41e}"),
+    ("&Odblac;", "\u{150}"),
+    ("&Ofr;", "\u{1d512}"),
+    ("&Ograve", "\u{d2}"),
+    ("&Ograve;", "\u{d2}"),
+    ("&Omacr;", "\u{14c}"),
+    ("&Omega;", "\u{3a9}"),
+    ("&Omicron;", "\u{39f}"),
+    ("&Oopf;", "\u{1d546}"),
+    ("&OpenCurlyDoubleQuote;", "\u{201c}"),
+    ("&OpenCurlyQuote;", "\u{2018}"),
+    ("&Or;", "\u{2a54}"),
+    ("&Oscr;", "\u{1d4aa}"),
+    ("&Oslash", "\u{d8}"),
+    ("&Oslash;", "\u{d8}"),
+    ("&Otilde", "\u{d5}"),
+    ("&Otilde;", "\u{d5}"),
+    ("&Otimes;", "\u{2a37}"),
+    ("&Ouml", "\u{d6}"),
+    ("&Ouml;", "\u{d6}"),
+    ("&OverBar;", "\u{203e}"),
+    ("&OverBrace;", "\u{23de}"),
+    ("&OverBracket;", "\u{23b4}"),
+    ("&OverParenthesis;", "\u{23dc}"),
+    ("&PartialD;", "\u{2202}"),
+    ("&Pcy;", "\u{41f}"),
+    ("&Pfr;", "\u{1d513}"),
+    ("&Phi;", "\u{3a6}"),
+    ("&Pi;", "\u{3a0}"),
+    ("&PlusMinus;", "\u{b1}"),
+    ("&Poincareplane;", "\u{210c}"),
+    ("&Popf;", "\u{2119}"),
+    ("&Pr;", "\u{2abb}"),
+    ("&Precedes;", "\u{227a}"),
+    ("&PrecedesEqual;", "\u{2aaf}"),
+    ("&PrecedesSlantEqual;", "\u{227c}"),
+    ("&PrecedesTilde;", "\u{227e}"),
+    ("&Prime;", "\u{2033}"),
+    ("&Product;", "\u{220f}"),
+    ("&Proportion;", "\u{2237}"),
+    ("&Proportional;", "\u{221d}"),
+    ("&Pscr;", "\u{1d4ab}"),
+    ("&Psi;", "\u{3a8}"),
+    ("&QUOT", "\""),
+    ("&QUOT;", "\""),
+    ("&Qfr;", "\u{1d514}"),
+    ("&Qopf;", "\u{211a}"),
+    ("&Qscr;", "\u{1d4ac}"),
+    ("&RBarr;", "\u{2910}"),
+    ("&REG", "\u{ae}"),
+    ("&REG;", "\u{ae}"),
+    ("&Racute;", "\u{154}"),
+    ("&Rang;", "\u{27eb}"),
+    ("&Rarr;", "\u{21a0}"),
+    ("&Rarrtl;", "\u{2916}"),
+    ("&Rcaron;", "\u{158}"),
+    ("&Rcedil;", "\u{156}"),
+    ("&Rcy;", "\u{420}"),
+    ("&Re;", "\u{211c}"),
+    ("&ReverseElement;", "\u{220b}"),
+    ("&ReverseEquilibrium;", "\u{21cb}"),
+    ("&ReverseUpEquilibrium;", "\u{296f}"),
+    ("&Rfr;", "\u{211c}"),
+    ("&Rho;", "\u{3a1}"),
+    ("&RightAngleBracket;", "\u{27e9}"),
+    ("&RightArrow;", "\u{2192}"),
+    ("&RightArrowBar;", "\u{21e5}"),
+    ("&RightArrowLeftArrow;", "\u{21c4}"),
+    ("&RightCeiling;", "\u{2309}"),
+    ("&RightDoubleBracket;", "\u{27e7}"),
+    ("&RightDownTeeVector;", "\u{295d}"),
+    ("&RightDownVector;", "\u{21c2}"),
+    ("&RightDownVectorBar;", "\u{2955}"),
+    ("&RightFloor;", "\u{230b}"),
+    ("&RightTee;", "\u{22a2}"),
+    ("&RightTeeArrow;", "\u{21a6}"),
+    ("&RightTeeVector;", "\u{295b}"),
+    ("&RightTriangle;", "\u{22b3}"),
+    ("&RightTriangleBar;", "\u{29d0}"),
+    ("&RightTriangleEqual;", "\u{22b5}"),
+    ("&RightUpDownVector;", "\u{294f}"),
+    ("&RightUpTeeVector;", "\u{295c}"),
+    ("&RightUpVector;", "\u{21be}"),
+    ("&RightUpVectorBar;", "\u{2954}"),
+    ("&RightVector;", "\u{21c0}"),
+    ("&RightVectorBar;", "\u{2953}"),
+    ("&Rightarrow;", "\u{21d2}"),
+    ("&Ropf;", "\u{211d}"),
+    ("&RoundImplies;", "\u{2970}"),
+    ("&Rrightarrow;", "\u{21db}"),
+    ("&Rscr;", "\u{211b}"),
+    ("&Rsh;", "\u{21b1}"),
+    ("&RuleDelayed;", "\u{29f4}"),
+    ("&SHCHcy;", "\u{429}"),
+    ("&SHcy;", "\u{428}"),
+    ("&SOFTcy;", "\u{42c}"),
+    ("&Sacute;", "\u{15a}"),
+    ("&Sc;", "\u{2abc}"),
+    ("&Scaron;", "\u{160}"),
+    ("&Scedil;", "\u{15e}"),
+    ("&Scirc;", "\u{15c}"),
+    ("&Scy;", "\u{421}"),
+    ("&Sfr;", "\u{1d516}"),
+    ("&ShortDownArrow;", "\u{2193}"),
+    ("&ShortLeftArrow;", "\u{2190}"),
+    ("&ShortRightArrow;", "\u{2192}"),
+    ("&ShortUpArrow;", "\u{2191}"),
+    ("&Sigma;", "\u{3a3}"),
+    ("&SmallCircle;", "\u{2218}"),
+    ("&Sopf;", "\u{1d54a}"),
+    ("&Sqrt;", "\u{221a}"),
+    ("&Square;", "\u{25a1}"),
+    ("&SquareIntersection;", "\u{2293}"),
+    ("&SquareSubset;", "\u{228f}"),
+    ("&SquareSubsetEqual;", "\u{2291}"),
+    ("&SquareSuperset;", "\u{2290}"),
+    ("&SquareSupersetEqual;", "\u{2292}"),
+    ("&SquareUnion;", "\u{2294}"),
+    ("&Sscr;", "\u{1d4ae}"),
+    ("&Star;", "\u{22c6}"),
+    ("&Sub;", "\u{22d0}"),
+    ("&Subset;", "\u{22d0}"),
+    ("&SubsetEqual;", "\u{2286}"),
+    ("&Succeeds;", "\u{227b}"),
+    ("&SucceedsEqual;", "\u{2ab0}"),
+    ("&SucceedsSlantEqual;", "\u{227d}"),
+    ("&SucceedsTilde;", "\u{227f}"),
+    ("&SuchThat;", "\u{220b}"),
+    ("&Sum;", "\u{2211}"),
+    ("&Sup;", "\u{22d1}"),
+    ("&Superset;", "\u{2283}"),
+    ("&SupersetEqual;", "\u{2287}"),
+    ("&Supset;", "\u{22d1}"),
+    ("&THORN", "\u{de}"),
+    ("&THORN;", "\u{de}"),
+    ("&TRADE;", "\u{2122}"),
+    ("&TSHcy;", "\u{40b}"),
+    ("&TScy;", "\u{426}"),
+    ("&Tab;", "\u{9}"),
+    ("&Tau;", "\u{3a4}"),
+    ("&Tcaron;", "\u{164}"),
+    ("&Tcedil;", "\u{162}"),
+    ("&Tcy;", "\u{422}"),
+    ("&Tfr;", "\u{1d517}"),
+    ("&Therefore;", "\u{2234}"),
+    ("&Theta;", "\u{398}"),
+    ("&ThickSpace;", "\u{205f}\u{200a}"),
+    ("&ThinSpace;", "\u{2009}"),
+    ("&Tilde;", "\u{223c}"),
+    ("&TildeEqual;", "\u{2243}"),
+    ("&TildeFullEqual;", "\u{2245}"),
+    ("&TildeTilde;", "\u{2248}"),
+    ("&Topf;", "\u{1d54b}"),
+    ("&TripleDot;", "\u{20db}"),
+    ("&Tscr;", "\u{1d4af}"),
+    ("&Tstrok;", "\u{166}"),
+    ("&Uacute", "\u{da}"),
+    ("&Uacute;", "\u{da}"),
+    ("&Uarr;", "\u{219f}"),
+    ("&Uarrocir;", "\u{2949}"),
+    ("&Ubrcy;", "\u{40e}"),
+    ("&Ubreve;", "\u{16c}"),
+    ("&Ucirc", "\u{db}"),
+    ("&Ucirc;", "\u{db}"),
+    ("&Ucy;", "\u{423}"),
+    ("&Udblac;", "\u{170}"),
+    ("&Ufr;", "\u{1d518}"),
+    ("&Ugrave", "\u{d9}"),
+    ("&Ugrave;", "\u{d9}"),
+    ("&Umacr;", "\u{16a}"),
+    ("&UnderBar;", "_"),
+    ("&UnderBrace;", "\u{23df}"),
+    ("&UnderBracket;", "\u{23b5}"),
+    ("&UnderParenthesis;", "\u{23dd}"),
+    ("&Union;", "\u{22c3}"),
+    ("&UnionPlus;", "\u{228e}"),
+    ("&Uogon;", "\u{172}"),
+    ("&Uopf;", "\u{1d54c}"),
+    ("&UpArrow;", "\u{2191}"),
+    ("&UpArrowBar;", "\u{2912}"),
+    ("&UpArrowDownArrow;", "\u{21c5}"),
+    ("&UpDownArrow;", "\u{2195}"),
+    ("&UpEquilibrium;", "\u{296e}"),
+    ("&UpTee;", "\u{22a5}"),
+    ("&UpTeeArrow;", "\u{21a5}"),
+    ("&Uparrow;", "\u{21d1}"),
+    ("&Updownarrow;", "\u{21d5}"),
+    ("&UpperLeftArrow;", "\u{2196}"),
+    ("&UpperRightArrow;", "\u{2197}"),
+    ("&Upsi;", "\u{3d2}"),
+    ("&Upsilon;", "\u{3a5}"),
+    ("&Uring;", "\u{16e}"),
+    ("&Uscr;", "\u{1d4b0}"),
+    ("&Utilde;", "\u{168}"),
+    ("&Uuml", "\u{dc}"),
+    ("&Uuml;", "\u{dc}"),
+    ("&VDash;", "\u{22ab}"),
+    ("&Vbar;", "\u{2aeb}"),
+    ("&Vcy;", "\u{412}"),
+    ("&Vdash;", "\u{22a9}"),
+    ("&Vdashl;", "\u{2ae6}"),
+    ("&Vee;", "\u{22c1}"),
+    ("&Verbar;", "\u{2016}"),
+    ("&Vert;", "\u{2016}"),
+    ("&VerticalBar;", "\u{2223}"),
+    ("&VerticalLine;", "|"),
+    ("&VerticalSeparator;", "\u{2758}"),
+    ("&VerticalTilde;", "\u{2240}"),
+    ("&VeryThinSpace;", "\u{200a}"),
+    ("&Vfr;", "\u{1d519}"),
+    ("&Vopf;", "\u{1d54d}"),
+    ("&Vscr;", "\u{1d4b1}"),
+    ("&Vvdash;", "\u{22aa}"),
+    ("&Wcirc;", "\u{174}"),
+    ("&Wedge;", "\u{22c0}"),
+    ("&Wfr;", "\u{1d51a}"),
+    ("&Wopf;", "\u{1d54e}"),
+    ("&Wscr;", "\u{1d4b2}"),
+    ("&Xfr;", "\u{1d51b}"),
+    ("&Xi;", "\u{39e}"),
+    ("&Xopf;", "\u{1d54f}"),
+    ("&Xscr;", "\u{1d4b3}"),
+    ("&YAcy;", "\u{42f}"),
+    ("&YIcy;", "\u{407}"),
+    ("&YUcy;", "\u{42e}"),
+    ("&Yacute", "\u{dd}"),
+    ("&Yacute;", "\u{dd}"),
+    ("&Ycirc;", "\u{176}"),
+    ("&Ycy;", "\u{42b}"),
+    ("&Yfr;", "\u{1d51c}"),
+    ("&Yopf;", "\u{1d550}"),
+    ("&Yscr;", "\u{1d4b4}"),
+    ("&Yuml;", "\u{178}"),
+    ("&ZHcy;", "\u{416}"),
+    ("&Zacute;", "\u{179}"),
+    ("&Zcaron;", "\u{17d}"),
+    ("&Zcy;", "\u{417}"),
+    ("&Zdot;", "\u{17b}"),
+    ("&ZeroWidthSpace;", "\u{200b}"),
+    ("&Zeta;", "\u{396}"),
+    ("&Zfr;", "\u{2128}"),
+    ("&Zopf;", "\u{2124}"),
+    ("&Zscr;", "\u{1d4b5}"),
+    ("&aacute", "\u{e1}"),
+    ("&aacute;", "\u{e1}"),
+    ("&abreve;", "\u{103}"),
+    ("&ac;", "\u{223e}"),
+    ("&acE;", "\u{223e}\u{333}"),
+    ("&acd;", "\u{223f}"),
+    ("&acirc", "\u{e2}"),
+    ("&acirc;", "\u{e2}"),
+    ("&acute", "\u{b4}"),
+    ("&acute;", "\u{b4}"),
+    ("&acy;", "\u{430}"),
+    ("&aelig", "\u{e6}"),
+    ("&aelig;", "\u{e6}"),
+    ("&af;", "\u{2061}"),
+    ("&afr;", "\u{1d51e}"),
+    ("&agrave", "\u{e0}"),
+    ("&agrave;", "\u{e0}"),
+    ("&alefsym;", "\u{2135}"),
+    ("&aleph;", "\u{2135}"),
+    ("&alpha;", "\u{3b1}"),
+    ("&amacr;", "\u{101}"),
+    ("&amalg;", "\u{2a3f}"),
+    ("&amp", "&"),
+    ("&amp;", "&"),
+    ("&and;", "\u{2227}"),
+    ("&andand;", "\u{2a55}"),
+    ("&andd;", "\u{2a5c}"),
+    ("&andslope;", "\u{2a58}"),
+    ("&andv;", "\u{2a5a}"),
+    ("&ang;", "\u{2220}"),
+    ("&ange;", "\u{29a4}"),
+    ("&angle;", "\u{2220}"),
+    ("&angmsd;", "\u{2221}"),
+    ("&angmsdaa;", "\u{29a8}"),
+    ("&angmsdab;", "\u{29a9}"),
+    ("&angmsdac;", "\u{29aa}"),
+    ("&angmsdad;", "\u{29ab}"),
+    ("&angmsdae;", "\u{29ac}"),
+    ("&angmsdaf;", "\u{29ad}"),
+    ("&angmsdag;", "\u{29ae}"),
+    ("&angmsdah;", "\u{29af}"),
+    ("&angrt;", "\u{221f}"),
+    ("&angrtvb;", "\u{22be}"),
+    ("&angrtvbd;", "\u{299d}"),
+    ("&angsph;", "\u{2222}"),
+    ("&angst;", "\u{c5}"),
+    ("&angzarr;", "\u{237c}"),
+    ("&aogon;", "\u{105}"),
+    ("&aopf;", "\u{1d552}"),
+    ("&ap;", "\u{2248}"),
+    ("&apE;", "\u{2a70}"),
+    ("&apacir;", "\u{2a6f}"),
+    ("&ape;", "\u{224a}"),
+    ("&apid;", "\u{224b}"),
+    ("&apos;", "'"),
+    ("&approx;", "\u{2248}"),
+    ("&approxeq;", "\u{224a}"),
+    ("&aring", "\u{e5}"),
+    ("&aring;", "\u{e5}"),
+    ("&ascr;", "\u{1d4b6}"),
+    ("&ast;", "*"),
+    ("&asymp;", "\u{2248}"),
+    ("&asympeq;", "\u{224d}"),
+    ("&atilde", "\u{e3}"),
+    ("&atilde;", "\u{e3}"),
+    ("&auml", "\u{e4}"),
+    ("&auml;", "\u{e4}"),
+    ("&awconint;", "\u{2233}"),
+    ("&awint;", "\u{2a11}"),
+    ("&bNot;", "\u{2aed}"),
+    ("&backcong;", "\u{224c}"),
+    ("&backepsilon;", "\u{3f6}"),
+    ("&backprime;", "\u{2035}"),
+    ("&backsim;", "\u{223d}"),
+    ("&backsimeq;", "\u{22cd}"),
+    ("&barvee;", "\u{22bd}"),
+    ("&barwed;", "\u{2305}"),
+    ("&barwedge;", "\u{2305}"),
+    ("&bbrk;", "\u{23b5}"),
+    ("&bbrktbrk;", "\u{23b6}"),
+    ("&bcong;", "\u{224c}"),
+    ("&bcy;", "\u{431}"),
+    ("&bdquo;", "\u{201e}"),
+    ("&becaus;", "\u{2235}"),
+    ("&because;", "\u{2235}"),
+    ("&bemptyv;", "\u{29b0}"),
+    ("&bepsi;", "\u{3f6}"),
+    ("&bernou;", "\u{212c}"),
+    ("&beta;", "\u{3b2}"),
+    ("&beth;", "\u{2136}"),
+    ("&between;", "\u{226c}"),
+    ("&bfr;", "\u{1d51f}"),
+    ("&bigcap;", "\u{22c2}"),
+    ("&bigcirc;", "\u{25ef}"),
+    ("&bigcup;", "\u{22c3}"),
+    ("&bigodot;", "\u{2a00}"),
+    ("&bigoplus;", "\u{2a01}"),
+    ("&bigotimes;", "\u{2a02}"),
+    ("&bigsqcup;", "\u{2a06}"),
+    ("&bigstar;", "\u{2605}"),
+    ("&bigtriangledown;", "\u{25bd}"),
+    ("&bigtriangleup;", "\u{25b3}"),
+    ("&biguplus;", "\u{2a04}"),
+    ("&bigvee;", "\u{22c1}"),
+    ("&bigwedge;", "\u{22c0}"),
+    ("&bkarow;", "\u{290d}"),
+    ("&blacklozenge;", "\u{29eb}"),
+    ("&blacksquare;", "\u{25aa}"),
+    ("&blacktriangle;", "\u{25b4}"),
+    ("&blacktriangledown;", "\u{25be}"),
+    ("&blacktriangleleft;", "\u{25c2}"),
+    ("&blacktriangleright;", "\u{25b8}"),
+    ("&blank;", "\u{2423}"),
+    ("&blk12;", "\u{2592}"),
+    ("&blk14;", "\u{2591}"),
+    ("&blk34;", "\u{2593}"),
+    ("&block;", "\u{2588}"),
+    ("&bne;", "=\u{20e5}"),
+    ("&bnequiv;", "\u{2261}\u{20e5}"),
+    ("&bnot;", "\u{2310}"),
+    ("&bopf;", "\u{1d553}"),
+    ("&bot;", "\u{22a5}"),
+    ("&bottom;", "\u{22a5}"),
+    ("&bowtie;", "\u{22c8}"),
+    ("&boxDL;", "\u{2557}"),
+    ("&boxDR;", "\u{2554}"),
+    ("&boxDl;", "\u{2556}"),
+    ("&boxDr;", "\u{2553}"),
+    ("&boxH;", "\u{2550}"),
+    ("&boxHD;", "\u{2566}"),
+    ("&boxHU;", "\u{2569}"),
+    ("&boxHd;", "\u{2564}"),
+    ("&boxHu;", "\u{2567}"),
+    ("&boxUL;", "\u{255d}"),
+    ("&boxUR;", "\u{255a}"),
+    ("&boxUl;", "\u{255c}"),
+    ("&boxUr;", "\u{2559}"),
+    ("&boxV;", "\u{2551}"),
+    ("&boxVH;", "\u{256c}"),
+    ("&boxVL;", "\u{2563}"),
+    ("&boxVR;", "\u{2560}"),
+    ("&boxVh;", "\u{256b}"),
+    ("&boxVl;", "\u{2562}"),
+    ("&boxVr;", "\u{255f}"),
+    ("&boxbox;", "\u{29c9}"),
+    ("&boxdL;", "\u{2555}"),
+    ("&boxdR;", "\u{2552}"),
+    ("&boxdl;", "\u{2510}"),
+    ("&boxdr;", "\u{250c}"),
+    ("&boxh;", "\u{2500}"),
+    ("&boxhD;", "\u{2565}"),
+    ("&boxhU;", "\u{2568}"),
+    ("&boxhd;", "\u{252c}"),
+    ("&boxhu;", "\u{2534}"),
+    ("&boxminus;", "\u{229f}"),
+    ("&boxplus;", "\u{229e}"),
+    ("&boxtimes;", "\u{22a0}"),
+    ("&boxuL;", "\u{255b}"),
+    ("&boxuR;", "\u{2558}"),
+    ("&boxul;", "\u{2518}"),
+    ("&boxur;", "\u{2514}"),
+    ("&boxv;", "\u{2502}"),
+    ("&boxvH;", "\u{256a}"),
+    ("&boxvL;", "\u{2561}"),
+    ("&boxvR;", "\u{255e}"),
+    ("&boxvh;", "\u{253c}"),
+    ("&boxvl;", "\u{2524}"),
+    ("&boxvr;", "\u{251c}"),
+    ("&bprime;", "\u{2035}"),
+    ("&breve;", "\u{2d8}"),
+    ("&brvbar", "\u{a6}"),
+    ("&brvbar;", "\u{a6}"),
+    ("&bscr;", "\u{1d4b7}"),
+    ("&bsemi;", "\u{204f}"),
+    ("&bsim;", "\u{223d}"),
+    ("&bsime;", "\u{22cd}"),
+    ("&bsol;", "\\"),
+    ("&bsolb;", "\u{29c5}"),
+    ("&bsolhsub;", "\u{27c8}"),
+    ("&bull;", "\u{2022}"),
+    ("&bullet;", "\u{2022}"),
+    ("&bump;", "\u{224e}"),
+    ("&bumpE;", "\u{2aae}"),
+    ("&bumpe;", "\u{224f}"),
+    ("&bumpeq;", "\u{224f}"),
+    ("&cacute;", "\u{107}"),
+    ("&cap;", "\u{2229}"),
+    ("&capand;", "\u{2a44}"),
+    ("&capbrcup;", "\u{2a49}"),
+    ("&capcap;", "\u{2a4b}"),
+    ("&capcup;", "\u{2a47}"),
+    ("&capdot;", "\u{2a40}"),
+    ("&caps;", "\u{2229}\u{fe00}"),
+    ("&caret;", "\u{2041}"),
+    ("&caron;", "\u{2c7}"),
+    ("&ccaps;", "\u{2a4d}"),
+    ("&ccaron;", "\u{10d}"),
+    ("&ccedil", "\u{e7}"),
+    ("&ccedil;", "\u{e7}"),
+    ("&ccirc;", "\u{109}"),
+    ("&ccups;", "\u{2a4c}"),
+    ("&ccupssm;", "\u{2a50}"),
+    ("&cdot;", "\u{10b}"),
+    ("&cedil", "\u{b8}"),
+    ("&cedil;", "\u{b8}"),
+    ("&cemptyv;", "\u{29b2}"),
+    ("&cent", "\u{a2}"),
+    ("&cent;", "\u{a2}"),
+    ("&centerdot;", "\u{b7}"),
+    ("&cfr;", "\u{1d520}"),
+    ("&chcy;", "\u{447}"),
+    ("&check;", "\u{2713}"),
+    ("&checkmark;", "\u{2713}"),
+    ("&chi;", "\u{3c7}"),
+    ("&cir;", "\u{25cb}"),
+    ("&cirE;", "\u{29c3}"),
+    ("&circ;", "\u{2c6}"),
+    ("&circeq;", "\u{2257}"),
+    ("&circlearrowleft;", "\u{21ba}"),
+    ("&circlearrowright;", "\u{21bb}"),
+    ("&circledR;", "\u{ae}"),
+    ("&circledS;", "\u{24c8}"),
+    ("&circledast;", "\u{229b}"),
+    ("&circledcirc;", "\u{229a}"),
+    ("&circleddash;", "\u{229d}"),
+    ("&cire;", "\u{2257}"),
+    ("&cirfnint;", "\u{2a10}"),
+    ("&cirmid;", "\u{2aef}"),
+    ("&cirscir;", "\u{29c2}"),
+    ("&clubs;", "\u{2663}"),
+    ("&clubsuit;", "\u{2663}"),
+    ("&colon;", ":"),
+    ("&colone;", "\u{2254}"),
+    ("&coloneq;", "\u{2254}"),
+    ("&comma;", ","),
+    ("&commat;", "@"),
+    ("&comp;", "\u{2201}"),
+    ("&compfn;", "\u{2218}"),
+    ("&complement;", "\u{2201}"),
+    ("&complexes;", "\u{2102}"),
+    ("&cong;", "\u{2245}"),
+    ("&congdot;", "\u{2a6d}"),
+    ("&conint;", "\u{222e}"),
+    ("&copf;", "\u{1d554}"),
+    ("&coprod;", "\u{2210}"),
+    ("&copy", "\u{a9}"),
+    ("&copy;", "\u{a9}"),
+    ("&copysr;", "\u{2117}"),
+    ("&crarr;", "\u{21b5}"),
+    ("&cross;", "\u{2717}"),
+    ("&cscr;", "\u{1d4b8}"),
+    ("&csub;", "\u{2acf}"),
+    ("&csube;", "\u{2ad1}"),
+    ("&csup;", "\u{2ad0}"),
+    ("&csupe;", "\u{2ad2}"),
+    ("&ctdot;", "\u{22ef}"),
+    ("&cudarrl;", "\u{2938}"),
+    ("&cudarrr;", "\u{2935}"),
+    ("&cuepr;", "\u{22de}"),
+    ("&cuesc;", "\u{22df}"),
+    ("&cularr;", "\u{21b6}"),
+    ("&cularrp;", "\u{293d}"),
+    ("&cup;", "\u{222a}"),
+    ("&cupbrcap;", "\u{2a48}"),
+    ("&cupcap;", "\u{2a46}"),
+    ("&cupcup;", "\u{2a4a}"),
+    ("&cupdot;", "\u{228d}"),
+    ("&cupor;", "\u{2a45}"),
+    ("&cups;", "\u{222a}\u{fe00}"),
+    ("&curarr;", "\u{21b7}"),
+    ("&curarrm;", "\u{293c}"),
+    ("&curlyeqprec;", "\u{22de}"),
+    ("&curlyeqsucc;", "\u{22df}"),
+    ("&curlyvee;", "\u{22ce}"),
+    ("&curlywedge;", "\u{22cf}"),
+    ("&curren", "\u{a4}"),
+    ("&curren;", "\u{a4}"),
+    ("&curvearrowleft;", "\u{21b6}"),
+    ("&curvearrowright;", "\u{21b7}"),
+    ("&cuvee;", "\u{22ce}"),
+    ("&cuwed;", "\u{22cf}"),
+    ("&cwconint;", "\u{2232}"),
+    ("&cwint;", "\u{2231}"),
+    ("&cylcty;", "\u{232d}"),
+    ("&dArr;", "\u{21d3}"),
+    ("&dHar;", "\u{2965}"),
+    ("&dagger;", "\u{2020}"),
+    ("&daleth;", "\u{2138}"),
+    ("&darr;", "\u{2193}"),
+    ("&dash;", "\u{2010}"),
+    ("&dashv;", "\u{22a3}"),
+    ("&dbkarow;", "\u{290f}"),
+    ("&dblac;", "\u{2dd}"),
+    ("&dcaron;", "\u{10f}"),
+    ("&dcy;", "\u{434}"),
+    ("&dd;", "\u{2146}"),
+    ("&ddagger;", "\u{2021}"),
+    ("&ddarr;", "\u{21ca}"),
+    ("&ddotseq;", "\u{2a77}"),
+    ("&deg", "\u{b0}"),
+    ("&deg;", "\u{b0}"),
+    ("&delta;", "\u{3b4}"),
+    ("&demptyv;", "\u{29b1}"),
+    ("&dfisht;", "\u{297f}"),
+    ("&dfr;", "\u{1d521}"),
+    ("&dharl;", "\u{21c3}"),
+    ("&dharr;", "\u{21c2}"),
+    ("&diam;", "\u{22c4}"),
+    ("&diamond;", "\u{22c4}"),
+    ("&diamondsuit;", "\u{2666}"),
+    ("&diams;", "\u{2666}"),
+    ("&die;", "\u{a8}"),
+    ("&digamma;", "\u{3dd}"),
+    ("&disin;", "\u{22f2}"),
+    ("&div;", "\u{f7}"),
+    ("&divide", "\u{f7}"),
+    ("&divide;", "\u{f7}"),
+    ("&divideontimes;", "\u{22c7}"),
+    ("&divonx;", "\u{22c7}"),
+    ("&djcy;", "\u{452}"),
+    ("&dlcorn;", "\u{231e}"),
+    ("&dlcrop;", "\u{230d}"),
+    ("&dollar;", "$"),
+    ("&dopf;", "\u{1d555}"),
+    ("&dot;", "\u{2d9}"),
+    ("&doteq;", "\u{2250}"),
+    ("&doteqdot;", "\u{2251}"),
+    ("&dotminus;", "\u{2238}"),
+    ("&dotplus;", "\u{2214}"),
+    ("&dotsquare;", "\u{22a1}"),
+    ("&doublebarwedge;", "\u{2306}"),
+    ("&downarrow;", "\u{2193}"),
+    ("&downdownarrows;", "\u{21ca}"),
+    ("&downharpoonleft;", "\u{21c3}"),
+    ("&downharpoonright;", "\u{21c2}"),
+    ("&drbkarow;", "\u{2910}"),
+    ("&drcorn;", "\u{231f}"),
+    ("&drcrop;", "\u{230c}"),
+    ("&dscr;", "\u{1d4b9}"),
+    ("&dscy;", "\u{455}"),
+    ("&dsol;", "\u{29f6}"),
+    ("&dstrok;", "\u{111}"),
+    ("&dtdot;", "\u{22f1}"),
+    ("&dtri;", "\u{25bf}"),
+    ("&dtrif;", "\u{25be}"),
+    ("&duarr;", "\u{21f5}"),
+    ("&duhar;", "\u{296f}"),
+    ("&dwangle;", "\u{29a6}"),
+    ("&dzcy;", "\u{45f}"),
+    ("&dzigrarr;", "\u{27ff}"),
+    ("&eDDot;", "\u{2a77}"),
+    ("&eDot;", "\u{2251}"),
+    ("&eacute", "\u{e9}"),
+    ("&eacute;", "\u{e9}"),
+    ("&easter;", "\u{2a6e}"),
+    ("&ecaron;", "\u{11b}"),
+    ("&ecir;", "\u{2256}"),
+    ("&ecirc", "\u{ea}"),
+    ("&ecirc;", "\u{ea}"),
+    ("&ecolon;", "\u{2255}"),
+    ("&ecy;", "\u{44d}"),
+    ("&edot;", "\u{117}"),
+    ("&ee;", "\u{2147}"),
+    ("&efDot;", "\u{2252}"),
+    ("&efr;", "\u{1d522}"),
+    ("&eg;", "\u{2a9a}"),
+    ("&egrave", "\u{e8}"),
+    ("&egrave;", "\u{e8}"),
+    ("&egs;", "\u{2a96}"),
+    ("&egsdot;", "\u{2a98}"),
+    ("&el;", "\u{2a99}"),
+    ("&elinters;", "\u{23e7}"),
+    ("&ell;", "\u{2113}"),
+    ("&els;", "\u{2a95}"),
+    ("&elsdot;", "\u{2a97}"),
+    ("&emacr;", "\u{113}"),
+    ("&empty;", "\u{2205}"),
+    ("&emptyset;", "\u{2205}"),
+    ("&emptyv;", "\u{2205}"),
+    ("&emsp13;", "\u{2004}"),
+    ("&emsp14;", "\u{2005}"),
+    ("&emsp;", "\u{2003}"),
+    ("&eng;", "\u{14b}"),
+    ("&ensp;", "\u{2002}"),
+    ("&eogon;", "\u{119}"),
+    ("&eopf;", "\u{1d556}"),
+    ("&epar;", "\u{22d5}"),
+    ("&eparsl;", "\u{29e3}"),
+    ("&eplus;", "\u{2a71}"),
+    ("&epsi;", "\u{3b5}"),
+    ("&epsilon;", "\u{3b5}"),
+    ("&epsiv;", "\u{3f5}"),
+    ("&eqcirc;", "\u{2256}"),
+    ("&eqcolon;", "\u{2255}"),
+    ("&eqsim;", "\u{2242}"),
+    ("&eqslantgtr;", "\u{2a96}"),
+    ("&eqslantless;", "\u{2a95}"),
+    ("&equals;", "="),
+    ("&equest;", "\u{225f}"),
+    ("&equiv;", "\u{2261}"),
+    ("&equivDD;", "\u{2a78}"),
+    ("&eqvparsl;", "\u{29e5}"),
+    ("&erDot;", "\u{2253}"),
+    ("&erarr;", "\u{2971}"),
+    ("&escr;", "\u{212f}"),
+    ("&esdot;", "\u{2250}"),
+    ("&esim;", "\u{2242}"),
+    ("&eta;", "\u{3b7}"),
+    ("&eth", "\u{f0}"),
+    ("&eth;", "\u{f0}"),
+    ("&euml", "\u{eb}"),
+    ("&euml;", "\u{eb}"),
+    ("&euro;", "\u{20ac}"),
+    ("&excl;", "!"),
+    ("&exist;", "\u{2203}"),
+    ("&expectation;", "\u{2130}"),
+    ("&exponentiale;", "\u{2147}"),
+    ("&fallingdotseq;", "\u{2252}"),
+    ("&fcy;", "\u{444}"),
+    ("&female;", "\u{2640}"),
+    ("&ffilig;", "\u{fb03}"),
+    ("&fflig;", "\u{fb00}"),
+    ("&ffllig;", "\u{fb04}"),
+    ("&ffr;", "\u{1d523}"),
+    ("&filig;", "\u{fb01}"),
+    ("&fjlig;", "fj"),
+    ("&flat;", "\u{266d}"),
+    ("&fllig;", "\u{fb02}"),
+    ("&fltns;", "\u{25b1}"),
+    ("&fnof;", "\u{192}"),
+    ("&fopf;", "\u{1d557}"),
+    ("&forall;", "\u{2200}"),
+    ("&fork;", "\u{22d4}"),
+    ("&forkv;", "\u{2ad9}"),
+    ("&fpartint;", "\u{2a0d}"),
+    ("&frac12", "\u{bd}"),
+    ("&frac12;", "\u{bd}"),
+    ("&frac13;", "\u{2153}"),
+    ("&frac14", "\u{bc}"),
+    ("&frac14;", "\u{bc}"),
+    ("&frac15;", "\u{2155}"),
+    ("&frac16;", "\u{2159}"),
+    ("&frac18;", "\u{215b}"),
+    ("&frac23;", "\u{2154}"),
+    ("&frac25;", "\u{2156}"),
+    ("&frac34", "\u{be}"),
+    ("&frac34;", "\u{be}"),
+    ("&frac35;", "\u{2157}"),
+    ("&frac38;", "\u{215c}"),
+    ("&frac45;", "\u{2158}"),
+    ("&frac56;", "\u{215a}"),
+    ("&frac58;", "\u{215d}"),
+    ("&frac78;", "\u{215e}"),
+    ("&frasl;", "\u{2044}"),
+    ("&frown;", "\u{2322}"),
+    ("&fscr;", "\u{1d4bb}"),
+    ("&gE;", "\u{2267}"),
+    ("&gEl;", "\u{2a8c}"),
+    ("&gacute;", "\u{1f5}"),
+    ("&gamma;", "\u{3b3}"),
+    ("&gammad;", "\u{3dd}"),
+    ("&gap;", "\u{2a86}"),
+    ("&gbreve;", "\u{11f}"),
+    ("&gcirc;", "\u{11d}"),
+    ("&gcy;", "\u{433}"),
+    ("&gdot;", "\u{121}"),
+    ("&ge;", "\u{2265}"),
+    ("&gel;", "\u{22db}"),
+    ("&geq;", "\u{2265}"),
+    ("&geqq;", "\u{2267}"),
+    ("&geqslant;", "\u{2a7e}"),
+    ("&ges;", "\u{2a7e}"),
+    ("&gescc;", "\u{2aa9}"),
+    ("&gesdot;", "\u{2a80}"),
+    ("&gesdoto;", "\u{2a82}"),
+    ("&gesdotol;", "\u{2a84}"),
+    ("&gesl;", "\u{22db}\u{fe00}"),
+    ("&gesles;", "\u{2a94}"),
+    ("&gfr;", "\u{1d524}"),
+    ("&gg;", "\u{226b}"),
+    ("&ggg;", "\u{22d9}"),
+    ("&gimel;", "\u{2137}"),
+    ("&gjcy;", "\u{453}"),
+    ("&gl;", "\u{2277}"),
+    ("&glE;", "\u{2a92}"),
+    ("&gla;", "\u{2aa5}"),
+    ("&glj;", "\u{2aa4}"),
+    ("&gnE;", "\u{2269}"),
+    ("&gnap;", "\u{2a8a}"),
+    ("&gnapprox;", "\u{2a8a}"),
+    ("&gne;", "\u{2a88}"),
+    ("&gneq;", "\u{2a88}"),
+    ("&gneqq;", "\u{2269}"),
+    ("&gnsim;", "\u{22e7}"),
+    ("&gopf;", "\u{1d558}"),
+    ("&grave;", "`"),
+    ("&gscr;", "\u{210a}"),
+    ("&gsim;", "\u{2273}"),
+    ("&gsime;", "\u{2a8e}"),
+    ("&gsiml;", "\u{2a90}"),
+    ("&gt", ">"),
+    ("&gt;", ">"),
+    ("&gtcc;", "\u{2aa7}"),
+    ("&gtcir;", "\u{2a7a}"),
+    ("&gtdot;", "\u{22d7}"),
+    ("&gtlPar;", "\u{2995}"),
+    ("&gtquest;", "\u{2a7c}"),
+    ("&gtrapprox;", "\u{2a86}"),
+    ("&gtrarr;", "\u{2978}"),
+    ("&gtrdot;", "\u{22d7}"),
+    ("&gtreqless;", "\u{22db}"),
+    ("&gtreqqless;", "\u{2a8c}"),
+    ("&gtrless;", "\u{2277}"),
+    ("&gtrsim;", "\u{2273}"),
+    ("&gvertneqq;", "\u{2269}\u{fe00}"),
+    ("&gvnE;", "\u{2269}\u{fe00}"),
+    ("&hArr;", "\u{21d4}"),
+    ("&hairsp;", "\u{200a}"),
+    ("&half;", "\u{bd}"),
+    ("&hamilt;", "\u{210b}"),
+    ("&hardcy;", "\u{44a}"),
+    ("&harr;", "\u{2194}"),
+    ("&harrcir;", "\u{2948}"),
+    ("&harrw;", "\u{21ad}"),
+    ("&hbar;", "\u{210f}"),
+    ("&hcirc;", "\u{125}"),
+    ("&hearts;", "\u{2665}"),
+    ("&heartsuit;", "\u{2665}"),
+    ("&hellip;", "\u{2026}"),
+    ("&hercon;", "\u{22b9}"),
+    ("&hfr;", "\u{1d525}"),
+    ("&hksearow;", "\u{2925}"),
+    ("&hkswarow;", "\u{2926}"),
+    ("&hoarr;", "\u{21ff}"),
+    ("&homtht;", "\u{223b}"),
+    ("&hookleftarrow;", "\u{21a9}"),
+    ("&hookrightarrow;", "\u{21aa}"),
+    ("&hopf;", "\u{1d559}"),
+    ("&horbar;", "\u{2015}"),
+    ("&hscr;", "\u{1d4bd}"),
+    ("&hslash;", "\u{210f}"),
+    ("&hstrok;", "\u{127}"),
+    ("&hybull;", "\u{2043}"),
+    ("&hyphen;", "\u{2010}"),
+    ("&iacute", "\u{ed}"),
+    ("&iacute;", "\u{ed}"),
+    ("&ic;", "\u{2063}"),
+    ("&icirc", "\u{ee}"),
+    ("&icirc;", "\u{ee}"),
+    ("&icy;", "\u{438}"),
+    ("&iecy;", "\u{435}"),
+    ("&iexcl", "\u{a1}"),
+    ("&iexcl;", "\u{a1}"),
+    ("&iff;", "\u{21d4}"),
+    ("&ifr;", "\u{1d526}"),
+    ("&igrave", "\u{ec}"),
+    ("&igrave;", "\u{ec}"),
+    ("&ii;", "\u{2148}"),
+    ("&iiiint;", "\u{2a0c}"),
+    ("&iiint;", "\u{222d}"),
+    ("&iinfin;", "\u{29dc}"),
+    ("&iiota;", "\u{2129}"),
+    ("&ijlig;", "\u{133}"),
+    ("&imacr;", "\u{12b}"),
+    ("&image;", "\u{2111}"),
+    ("&imagline;", "\u{2110}"),
+    ("&imagpart;", "\u{2111}"),
+    ("&imath;", "\u{131}"),
+    ("&imof;", "\u{22b7}"),
+    ("&imped;", "\u{1b5}"),
+    ("&in;", "\u{2208}"),
+    ("&incare;", "\u{2105}"),
+    ("&infin;", "\u{221e}"),
+    ("&infintie;", "\u{29dd}"),
+    ("&inodot;", "\u{131}"),
+    ("&int;", "\u{222b}"),
+    ("&intcal;", "\u{22ba}"),
+    ("&integers;", "\u{2124}"),
+    ("&intercal;", "\u{22ba}"),
+    ("&intlarhk;", "\u{2a17}"),
+    ("&intprod;", "\u{2a3c}"),
+    ("&iocy;", "\u{451}"),
+    ("&iogon;", "\u{12f}"),
+    ("&iopf;", "\u{1d55a}"),
+    ("&iota;", "\u{3b9}"),
+    ("&iprod;", "\u{2a3c}"),
+    ("&iquest", "\u{bf}"),
+    ("&iquest;", "\u{bf}"),
+    ("&iscr;", "\u{1d4be}"),
+    ("&isin;", "\u{2208}"),
+    ("&isinE;", "\u{22f9}"),
+    ("&isindot;", "\u{22f5}"),
+    ("&isins;", "\u{22f4}"),
+    ("&isinsv;", "\u{22f3}"),
+    ("&isinv;", "\u{2208}"),
+    ("&it;", "\u{2062}"),
+    ("&itilde;", "\u{129}"),
+    ("&iukcy;", "\u{456}"),
+    ("&iuml", "\u{ef}"),
+    ("&iuml;", "\u{ef}"),
+    ("&jcirc;", "\u{135}"),
+    ("&jcy;", "\u{439}"),
+    ("&jfr;", "\u{1d527}"),
+    ("&jmath;", "\u{237}"),
+    ("&jopf;", "\u{1d55b}"),
+    ("&jscr;", "\u{1d4bf}"),
+    ("&jsercy;", "\u{458}"),
+    ("&jukcy;", "\u{454}"),
+    ("&kappa;", "\u{3ba}"),
+    ("&kappav;", "\u{3f0}"),
+    ("&kcedil;", "\u{137}"),
+    ("&kcy;", "\u{43a}"),
+    ("&kfr;", "\u{1d528}"),
+    ("&kgreen;", "\u{138}"),
+    ("&khcy;", "\u{445}"),
+    ("&kjcy;", "\u{45c}"),
+    ("&kopf;", "\u{1d55c}"),
+    ("&kscr;", "\u{1d4c0}"),
+    ("&lAarr;", "\u{21da}"),
+    ("&lArr;", "\u{21d0}"),
+    ("&lAtail;", "\u{291b}"),
+    ("&lBarr;", "\u{290e}"),
+    ("&lE;", "\u{2266}"),
+    ("&lEg;", "\u{2a8b}"),
+    ("&lHar;", "\u{2962}"),
+    ("&lacute;", "\u{13a}"),
+    ("&laemptyv;", "\u{29b4}"),
+    ("&lagran;", "\u{2112}"),
+    ("&lambda;", "\u{3bb}"),
+    ("&lang;", "\u{27e8}"),
+    ("&langd;", "\u{2991}"),
+    ("&langle;", "\u{27e8}"),
+    ("&lap;", "\u{2a85}"),
+    ("&laquo", "\u{ab}"),
+    ("&laquo;", "\u{ab}"),
+    ("&larr;", "\u{2190}"),
+    ("&larrb;", "\u{21e4}"),
+    ("&larrbfs;", "\u{291f}"),
+    ("&larrfs;", "\u{291d}"),
+    ("&larrhk;", "\u{21a9}"),
+    ("&larrlp;", "\u{21ab}"),
+    ("&larrpl;", "\u{2939}"),
+    ("&larrsim;", "\u{2973}"),
+    ("&larrtl;", "\u{21a2}"),
+    ("&lat;", "\u{2aab}"),
+    ("&latail;", "\u{2919}"),
+    ("&late;", "\u{2aad}"),
+    ("&lates;", "\u{2aad}\u{fe00}"),
+    ("&lbarr;", "\u{290c}"),
+    ("&lbbrk;", "\u{2772}"),
+    ("&lbrace;", "{"),
+    ("&lbrack;", "["),
+    ("&lbrke;", "\u{298b}"),
+    ("&lbrksld;", "\u{298f}"),
+    ("&lbrkslu;", "\u{298d}"),
+    ("&lcaron;", "\u{13e}"),
+    ("&lcedil;", "\u{13c}"),
+    ("&lceil;", "\u{2308}"),
+    ("&lcub;", "{"),
+    ("&lcy;", "\u{43b}"),
+    ("&ldca;", "\u{2936}"),
+    ("&ldquo;", "\u{201c}"),
+    ("&ldquor;", "\u{201e}"),
+    ("&ldrdhar;", "\u{2967}"),
+    ("&ldrushar;", "\u{294b}"),
+    ("&ldsh;", "\u{21b2}"),
+    ("&le;", "\u{2264}"),
+    ("&leftarrow;", "\u{2190}"),
+    ("&leftarrowtail;", "\u{21a2}"),
+    ("&leftharpoondown;", "\u{21bd}"),
+    ("&leftharpoonup;", "\u{21bc}"),
+    ("&leftleftarrows;", "\u{21c7}"),
+    ("&leftrightarrow;", "\u{2194}"),
+    ("&leftrightarrows;", "\u{21c6}"),
+    ("&leftrightharpoons;", "\u{21cb}"),
+    ("&leftrightsquigarrow;", "\u{21ad}"),
+    ("&leftthreetimes;", "\u{22cb}"),
+    ("&leg;", "\u{22da}"),
+    ("&leq;", "\u{2264}"),
+    ("&leqq;", "\u{2266}"),
+    ("&leqslant;", "\u{2a7d}"),
+    ("&les;", "\u{2a7d}"),
+    ("&lescc;", "\u{2aa8}"),
+    ("&lesdot;", "\u{2a7f}"),
+    ("&lesdoto;", "\u{2a81}"),
+    ("&lesdotor;", "\u{2a83}"),
+    ("&lesg;", "\u{22da}\u{fe00}"),
+    ("&lesges;", "\u{2a93}"),
+    ("&lessapprox;", "\u{2a85}"),
+    ("&lessdot;", "\u{22d6}"),
+    ("&lesseqgtr;", "\u{22da}"),
+    ("&lesseqqgtr;", "\u{2a8b}"),
+    ("&lessgtr;", "\u{2276}"),
+    ("&lesssim;", "\u{2272}"),
+    ("&lfisht;", "\u{297c}"),
+    ("&lfloor;", "\u{230a}"),
+    ("&lfr;", "\u{1d529}"),
+    ("&lg;", "\u{2276}"),
+    ("&lgE;", "\u{2a91}"),
+    ("&lhard;", "\u{21bd}"),
+    ("&lharu;", "\u{21bc}"),
+    ("&lharul;", "\u{296a}"),
+    ("&lhblk;", "\u{2584}"),
+    ("&ljcy;", "\u{459}"),
+    ("&ll;", "\u{226a}"),
+    ("&llarr;", "\u{21c7}"),
+    ("&llcorner;", "\u{231e}"),
+    ("&llhard;", "\u{296b}"),
+    ("&lltri;", "\u{25fa}"),
+    ("&lmidot;", "\u{140}"),
+    ("&lmoust;", "\u{23b0}"),
+    ("&lmoustache;", "\u{23b0}"),
+    ("&lnE;", "\u{2268}"),
+    ("&lnap;", "\u{2a89}"),
+    ("&lnapprox;", "\u{2a89}"),
+    ("&lne;", "\u{2a87}"),
+    ("&lneq;", "\u{2a87}"),
+    ("&lneqq;", "\u{2268}"),
+    ("&lnsim;", "\u{22e6}"),
+    ("&loang;", "\u{27ec}"),
+    ("&loarr;", "\u{21fd}"),
+    ("&lobrk;", "\u{27e6}"),
+    ("&longleftarrow;", "\u{27f5}"),
+    ("&longleftrightarrow;", "\u{27f7}"),
+    ("&longmapsto;", "\u{27fc}"),
+    ("&longrightarrow;", "\u{27f6}"),
+    ("&looparrowleft;", "\u{21ab}"),
+    ("&looparrowright;", "\u{21ac}"),
+    ("&lopar;", "\u{2985}"),
+    ("&lopf;", "\u{1d55d}"),
+    ("&loplus;", "\u{2a2d}"),
+    ("&lotimes;", "\u{2a34}"),
+    ("&lowast;", "\u{2217}"),
+    ("&lowbar;", "_"),
+    ("&loz;", "\u{25ca}"),
+    ("&lozenge;", "\u{25ca}"),
+    ("&lozf;", "\u{29eb}"),
+    ("&lpar;", "("),
+    ("&lparlt;", "\u{2993}"),
+    ("&lrarr;", "\u{21c6}"),
+    ("&lrcorner;", "\u{231f}"),
+    ("&lrhar;", "\u{21cb}"),
+    ("&lrhard;", "\u{296d}"),
+    ("&lrm;", "\u{200e}"),
+    ("&lrtri;", "\u{22bf}"),
+    ("&lsaquo;", "\u{2039}"),
+    ("&lscr;", "\u{1d4c1}"),
+    ("&lsh;", "\u{21b0}"),
+    ("&lsim;", "\u{2272}"),
+    ("&lsime;", "\u{2a8d}"),
+    ("&lsimg;", "\u{2a8f}"),
+    ("&lsqb;", "["),
+    ("&lsquo;", "\u{2018}"),
+    ("&lsquor;", "\u{201a}"),
+    ("&lstrok;", "\u{142}"),
+    ("&lt", "<"),
+    ("&lt;", "<"),
+    ("&ltcc;", "\u{2aa6}"),
+    ("&ltcir;", "\u{2a79}"),
+    ("&ltdot;", "\u{22d6}"),
+    ("&lthree;", "\u{22cb}"),
+    ("&ltimes;", "\u{22c9}"),
+    ("&ltlarr;", "\u{2976}"),
+    ("&ltquest;", "\u{2a7b}"),
+    ("&ltrPar;", "\u{2996}"),
+    ("&ltri;", "\u{25c3}"),
+    ("&ltrie;", "\u{22b4}"),
+    ("&ltrif;", "\u{25c2}"),
+    ("&lurdshar;", "\u{294a}"),
+    ("&luruhar;", "\u{2966}"),
+    ("&lvertneqq;", "\u{2268}\u{fe00}"),
+    ("&lvnE;", "\u{2268}\u{fe00}"),
+    ("&mDDot;", "\u{223a}"),
+    ("&macr", "\u{af}"),
+    ("&macr;", "\u{af}"),
+    ("&male;", "\u{2642}"),
+    ("&malt;", "\u{2720}"),
+    ("&maltese;", "\u{2720}"),
+    ("&map;", "\u{21a6}"),
+    ("&mapsto;", "\u{21a6}"),
+    ("&mapstodown;", "\u{21a7}"),
+    ("&mapstoleft;", "\u{21a4}"),
+    ("&mapstoup;", "\u{21a5}"),
+    ("&marker;", "\u{25ae}"),
+    ("&mcomma;", "\u{2a29}"),
+    ("&mcy;", "\u{43c}"),
+    ("&mdash;", "\u{2014}"),
+    ("&measuredangle;", "\u{2221}"),
+    ("&mfr;", "\u{1d52a}"),
+    ("&mho;", "\u{2127}"),
+    ("&micro", "\u{b5}"),
+    ("&micro;", "\u{b5}"),
+    ("&mid;", "\u{2223}"),
+    ("&midast;", "*"),
+    ("&midcir;", "\u{2af0}"),
+    ("&middot", "\u{b7}"),
+    ("&middot;", "\u{b7}"),
+    ("&minus;", "\u{2212}"),
+    ("&minusb;", "\u{229f}"),
+    ("&minusd;", "\u{2238}"),
+    ("&minusdu;", "\u{2a2a}"),
+    ("&mlcp;", "\u{2adb}"),
+    ("&mldr;", "\u{2026}"),
+    ("&mnplus;", "\u{2213}"),
+    ("&models;", "\u{22a7}"),
+    ("&mopf;", "\u{1d55e}"),
+    ("&mp;", "\u{2213}"),
+    ("&mscr;", "\u{1d4c2}"),
+    ("&mstpos;", "\u{223e}"),
+    ("&mu;", "\u{3bc}"),
+    ("&multimap;", "\u{22b8}"),
+    ("&mumap;", "\u{22b8}"),
+    ("&nGg;", "\u{22d9}\u{338}"),
+    ("&nGt;", "\u{226b}\u{20d2}"),
+    ("&nGtv;", "\u{226b}\u{338}"),
+    ("&nLeftarrow;", "\u{21cd}"),
+    ("&nLeftrightarrow;", "\u{21ce}"),
+    ("&nLl;", "\u{22d8}\u{338}"),
+    ("&nLt;", "\u{226a}\u{20d2}"),
+    ("&nLtv;", "\u{226a}\u{338}"),
+    ("&nRightarrow;", "\u{21cf}"),
+    ("&nVDash;", "\u{22af}"),
+    ("&nVdash;", "\u{22ae}"),
+    ("&nabla;", "\u{2207}"),
+    ("&nacute;", "\u{144}"),
+    ("&nang;", "\u{2220}\u{20d2}"),
+    ("&nap;", "\u{2249}"),
+    ("&napE;", "\u{2a70}\u{338}"),
+    ("&napid;", "\u{224b}\u{338}"),
+    ("&napos;", "\u{149}"),
+    ("&napprox;", "\u{2249}"),
+    ("&natur;", "\u{266e}"),
+    ("&natural;", "\u{266e}"),
+    ("&naturals;", "\u{2115}"),
+    ("&nbsp", "\u{a0}"),
+    ("&nbsp;", "\u{a0}"),
+    ("&nbump;", "\u{224e}\u{338}"),
+    ("&nbumpe;", "\u{224f}\u{338}"),
+    ("&ncap;", "\u{2a43}"),
+    ("&ncaron;", "\u{148}"),
+    ("&ncedil;", "\u{146}"),
+    ("&ncong;", "\u{2247}"),
+    ("&ncongdot;", "\u{2a6d}\u{338}"),
+    ("&ncup;", "\u{2a42}"),
+    ("&ncy;", "\u{43d}"),
+    ("&ndash;", "\u{2013}"),
+    ("&ne;", "\u{2260}"),
+    ("&neArr;", "\u{21d7}"),
+    ("&nearhk;", "\u{2924}"),
+    ("&nearr;", "\u{2197}"),
+    ("&nearrow;", "\u{2197}"),
+    ("&nedot;", "\u{2250}\u{338}"),
+    ("&nequiv;", "\u{2262}"),
+    ("&nesear;", "\u{2928}"),
+    ("&nesim;", "\u{2242}\u{338}"),
+    ("&nexist;", "\u{2204}"),
+    ("&nexists;", "\u{2204}"),
+    ("&nfr;", "\u{1d52b}"),
+    ("&ngE;", "\u{2267}\u{338}"),
+    ("&nge;", "\u{2271}"),
+    ("&ngeq;", "\u{2271}"),
+    ("&ngeqq;", "\u{2267}\u{338}"),
+    ("&ngeqslant;", "\u{2a7e}\u{338}"),
+    ("&nges;", "\u{2a7e}\u{338}"),
+    ("&ngsim;", "\u{2275}"),
+    ("&ngt;", "\u{226f}"),
+    ("&ngtr;", "\u{226f}"),
+    ("&nhArr;", "\u{21ce}"),
+    ("&nharr;", "\u{21ae}"),
+    ("&nhpar;", "\u{2af2}"),
+    ("&ni;", "\u{220b}"),
+    ("&nis;", "\u{22fc}"),
+    ("&nisd;", "\u{22fa}"),
+    ("&niv;", "\u{220b}"),
+    ("&njcy;", "\u{45a}"),
+    ("&nlArr;", "\u{21cd}"),
+    ("&nlE;", "\u{2266}\u{338}"),
+    ("&nlarr;", "\u{219a}"),
+    ("&nldr;", "\u{2025}"),
+    ("&nle;", "\u{2270}"),
+    ("&nleftarrow;", "\u{219a}"),
+    ("&nleftrightarrow;", "\u{21ae}"),
+    ("&nleq;", "\u{2270}"),
+    ("&nleqq;", "\u{2266}\u{338}"),
+    ("&nleqslant;", "\u{2a7d}\u{338}"),
+    ("&nles;", "\u{2a7d}\u{338}"),
+    ("&nless;", "\u{226e}"),
+    ("&nlsim;", "\u{2274}"),
+    ("&nlt;", "\u{226e}"),
+    ("&nltri;", "\u{22ea}"),
+    ("&nltrie;", "\u{22ec}"),
+    ("&nmid;", "\u{2224}"),
+    ("&nopf;", "\u{1d55f}"),
+    ("&not", "\u{ac}"),
+    ("&not;", "\u{ac}"),
+    ("&notin;", "\u{2209}"),
+    ("&notinE;", "\u{22f9}\u{338}"),
+    ("&notindot;", "\u{22f5}\u{338}"),
+    ("&notinva;", "\u{2209}"),
+    ("&notinvb;", "\u{22f7}"),
+    ("&notinvc;", "\u{22f6}"),
+    ("&notni;", "\u{220c}"),
+    ("&notniva;", "\u{220c}"),
+    ("&notnivb;", "\u{22fe}"),
+    ("&notnivc;", "\u{22fd}"),
+    ("&npar;", "\u{2226}"),
+    ("&nparallel;", "\u{2226}"),
+    ("&nparsl;", "\u{2afd}\u{20e5}"),
+    ("&npart;", "\u{2202}\u{338}"),
+    ("&npolint;", "\u{2a14}"),
+    ("&npr;", "\u{2280}"),
+    ("&nprcue;", "\u{22e0}"),
+    ("&npre;", "\u{2aaf}\u{338}"),
+    ("&nprec;", "\u{2280}"),
+    ("&npreceq;", "\u{2aaf}\u{338}"),
+    ("&nrArr;", "\u{21cf}"),
+    ("&nrarr;", "\u{219b}"),
+    ("&nrarrc;", "\u{2933}\u{338}"),
+    ("&nrarrw;", "\u{219d}\u{338}"),
+    ("&nrightarrow;", "\u{219b}"),
+    ("&nrtri;", "\u{22eb}"),
+    ("&nrtrie;", "\u{22ed}"),
+    ("&nsc;", "\u{2281}"),
+    ("&nsccue;", "\u{22e1}"),
+    ("&nsce;", "\u{2ab0}\u{338}"),
+    ("&nscr;", "\u{1d4c3}"),
+    ("&nshortmid;", "\u{2224}"),
+    ("&nshortparallel;", "\u{2226}"),
+    ("&nsim;", "\u{2241}"),
+    ("&nsime;", "\u{2244}"),
+    ("&nsimeq;", "\u{2244}"),
+    ("&nsmid;", "\u{2224}"),
+    ("&nspar;", "\u{2226}"),
+    ("&nsqsube;", "\u{22e2}"),
+    ("&nsqsupe;", "\u{22e3}"),
+    ("&nsub;", "\u{2284}"),
+    ("&nsubE;", "\u{2ac5}\u{338}"),
+    ("&nsube;", "\u{2288}"),
+    ("&nsubset;", "\u{2282}\u{20d2}"),
+    ("&nsubseteq;", "\u{2288}"),
+    ("&nsubseteqq;", "\u{2ac5}\u{338}"),
+    ("&nsucc;", "\u{2281}"),
+    ("&nsucceq;", "\u{2ab0}\u{338}"),
+    ("&nsup;", "\u{2285}"),
+    ("&nsupE;", "\u{2ac6}\u{338}"),
+    ("&nsupe;", "\u{2289}"),
+    ("&nsupset;", "\u{2283}\u{20d2}"),
+    ("&nsupseteq;", "\u{2289}"),
+    ("&nsupseteqq;", "\u{2ac6}\u{338}"),
+    ("&ntgl;", "\u{2279}"),
+    ("&ntilde", "\u{f1}"),
+    ("&ntilde;", "\u{f1}"),
+    ("&ntlg;", "\u{2278}"),
+    ("&ntriangleleft;", "\u{22ea}"),
+    ("&ntrianglelefteq;", "\u{22ec}"),
+    ("&ntriangleright;", "\u{22eb}"),
+    ("&ntrianglerighteq;", "\u{22ed}"),
+    ("&nu;", "\u{3bd}"),
+    ("&num;", "#"),
+    ("&numero;", "\u{2116}"),
+    ("&numsp;", "\u{2007}"),
+    ("&nvDash;", "\u{22ad}"),
+    ("&nvHarr;", "\u{2904}"),
+    ("&nvap;", "\u{224d}\u{20d2}"),
+    ("&nvdash;", "\u{22ac}"),
+    ("&nvge;", "\u{2265}\u{20d2}"),
+    ("&nvgt;", ">\u{20d2}"),
+    ("&nvinfin;", "\u{29de}"),
+    ("&nvlArr;", "\u{2902}"),
+    ("&nvle;", "\u{2264}\u{20d2}"),
+    ("&nvlt;", "<\u{20d2}"),
+    ("&nvltrie;", "\u{22b4}\u{20d2}"),
+    ("&nvrArr;", "\u{2903}"),
+    ("&nvrtrie;", "\u{22b5}\u{20d2}"),
+    ("&nvsim;", "\u{223c}\u{20d2}"),
+    ("&nwArr;", "\u{21d6}"),
+    ("&nwarhk;", "\u{2923}"),
+    ("&nwarr;", "\u{2196}"),
+    ("&nwarrow;", "\u{2196}"),
+    ("&nwnear;", "\u{2927}"),
+    ("&oS;", "\u{24c8}"),
+    ("&oacute", "\u{f3}"),
+    ("&oacute;", "\u{f3}"),
+    ("&oast;", "\u{229b}"),
+    ("&ocir;", "\u{229a}"),
+    ("&ocirc", "\u{f4}"),
+    ("&ocirc;", "\u{f4}"),
+    ("&ocy;", "\u{43e}"),
+    ("&odash;", "\u{229d}"),
+    ("&odblac;", "\u{151}"),
+    ("&odiv;", "\u{2a38}"),
+    ("&odot;", "\u{2299}"),
+    ("&odsold;", "\u{29bc}"),
+    ("&oelig;", "\u{153}"),
+    ("&ofcir;", "\u{29bf}"),
+    ("&ofr;", "\u{1d52c}"),
+    ("&ogon;", "\u{2db}"),
+    ("&ograve", "\u{f2}"),
+    ("&ograve;", "\u{f2}"),
+    ("&ogt;", "\u{29c1}"),
+    ("&ohbar;", "\u{29b5}"),
+    ("&ohm;", "\u{3a9}"),
+    ("&oint;", "\u{222e}"),
+    ("&olarr;", "\u{21ba}"),
+    ("&olcir;", "\u{29be}"),
+    ("&olcross;", "\u{29bb}"),
+    ("&oline;", "\u{203e}"),
+    ("&olt;", "\u{29c0}"),
+    ("&omacr;", "\u{14d}"),
+    ("&omega;", "\u{3c9}"),
+    ("&omicron;", "\u{3bf}"),
+    ("&omid;", "\u{29b6}"),
+    ("&ominus;", "\u{2296}"),
+    ("&oopf;", "\u{1d560}"),
+    ("&opar;", "\u{29b7}"),
+    ("&operp;", "\u{29b9}"),
+    ("&oplus;", "\u{2295}"),
+    ("&or;", "\u{2228}"),
+    ("&orarr;", "\u{21bb}"),
+    ("&ord;", "\u{2a5d}"),
+    ("&order;", "\u{2134}"),
+    ("&orderof;", "\u{2134}"),
+    ("&ordf", "\u{aa}"),
+    ("&ordf;", "\u{aa}"),
+    ("&ordm", "\u{ba}"),
+    ("&ordm;", "\u{ba}"),
+    ("&origof;", "\u{22b6}"),
+    ("&oror;", "\u{2a56}"),
+    ("&orslope;", "\u{2a57}"),
+    ("&orv;", "\u{2a5b}"),
+    ("&oscr;", "\u{2134}"),
+    ("&oslash", "\u{f8}"),
+    ("&oslash;", "\u{f8}"),
+    ("&osol;", "\u{2298}"),
+    ("&otilde", "\u{f5}"),
+    ("&otilde;", "\u{f5}"),
+    ("&otimes;", "\u{2297}"),
+    ("&otimesas;", "\u{2a36}"),
+    ("&ouml", "\u{f6}"),
+    ("&ouml;", "\u{f6}"),
+    ("&ovbar;", "\u{233d}"),
+    ("&par;", "\u{2225}"),
+    ("&para", "\u{b6}"),
+    ("&para;", "\u{b6}"),
+    ("&parallel;", "\u{2225}"),
+    ("&parsim;", "\u{2af3}"),
+    ("&parsl;", "\u{2afd}"),
+    ("&part;", "\u{2202}"),
+    ("&pcy;", "\u{43f}"),
+    ("&percnt;", "%"),
+    ("&period;", "."),
+    ("&permil;", "\u{2030}"),
+    ("&perp;", "\u{22a5}"),
+    ("&pertenk;", "\u{2031}"),
+    ("&pfr;", "\u{1d52d}"),
+    ("&phi;", "\u{3c6}"),
+    ("&phiv;", "\u{3d5}"),
+    ("&phmmat;", "\u{2133}"),
+    ("&phone;", "\u{260e}"),
+    ("&pi;", "\u{3c0}"),
+    ("&pitchfork;", "\u{22d4}"),
+    ("&piv;", "\u{3d6}"),
+    ("&planck;", "\u{210f}"),
+    ("&planckh;", "\u{210e}"),
+    ("&plankv;", "\u{210f}"),
+    ("&plus;", "+"),
+    ("&plusacir;", "\u{2a23}"),
+    ("&plusb;", "\u{229e}"),
+    ("&pluscir;", "\u{2a22}"),
+    ("&plusdo;", "\u{2214}"),
+    ("&plusdu;", "\u{2a25}"),
+    ("&pluse;", "\u{2a72}"),
+    ("&plusmn", "\u{b1}"),
+    ("&plusmn;", "\u{b1}"),
+    ("&plussim;", "\u{2a26}"),
+    ("&plustwo;", "\u{2a27}"),
+    ("&pm;", "\u{b1}"),
+    ("&pointint;", "\u{2a15}"),
+    ("&popf;", "\u{1d561}"),
+    ("&pound", "\u{a3}"),
+    ("&pound;", "\u{a3}"),
+    ("&pr;", "\u{227a}"),
+    ("&prE;", "\u{2ab3}"),
+    ("&prap;", "\u{2ab7}"),
+    ("&prcue;", "\u{227c}"),
+    ("&pre;", "\u{2aaf}"),
+    ("&prec;", "\u{227a}"),
+    ("&precapprox;", "\u{2ab7}"),
+    ("&preccurlyeq;", "\u{227c}"),
+    ("&preceq;", "\u{2aaf}"),
+    ("&precnapprox;", "\u{2ab9}"),
+    ("&precneqq;", "\u{2ab5}"),
+    ("&precnsim;", "\u{22e8}"),
+    ("&precsim;", "\u{227e}"),
+    ("&prime;", "\u{2032}"),
+    ("&primes;", "\u{2119}"),
+    ("&prnE;", "\u{2ab5}"),
+    ("&prnap;", "\u{2ab9}"),
+    ("&prnsim;", "\u{22e8}"),
+    ("&prod;", "\u{220f}"),
+    ("&profalar;", "\u{232e}"),
+    ("&profline;", "\u{2312}"),
+    ("&profsurf;", "\u{2313}"),
+    ("&prop;", "\u{221d}"),
+    ("&propto;", "\u{221d}"),
+    ("&prsim;", "\u{227e}"),
+    ("&prurel;", "\u{22b0}"),
+    ("&pscr;", "\u{1d4c5}"),
+    ("&psi;", "\u{3c8}"),
+    ("&puncsp;", "\u{2008}"),
+    ("&qfr;", "\u{1d52e}"),
+    ("&qint;", "\u{2a0c}"),
+    ("&qopf;", "\u{1d562}"),
+    ("&qprime;", "\u{2057}"),
+    ("&qscr;", "\u{1d4c6}"),
+    ("&quaternions;", "\u{210d}"),
+    ("&quatint;", "\u{2a16}"),
+    ("&quest;", "?"),
+    ("&questeq;", "\u{225f}"),
+    ("&quot", "\""),
+    ("&quot;", "\""),
+    ("&rAarr;", "\u{21db}"),
+    ("&rArr;", "\u{21d2}"),
+    ("&rAtail;", "\u{291c}"),
+    ("&rBarr;", "\u{290f}"),
+    ("&rHar;", "\u{2964}"),
+    ("&race;", "\u{223d}\u{331}"),
+    ("&racute;", "\u{155}"),
+    ("&radic;", "\u{221a}"),
+    ("&raemptyv;", "\u{29b3}"),
+    ("&rang;", "\u{27e9}"),
+    ("&rangd;", "\u{2992}"),
+    ("&range;", "\u{29a5}"),
+    ("&rangle;", "\u{27e9}"),
+    ("&raquo", "\u{bb}"),
+    ("&raquo;", "\u{bb}"),
+    ("&rarr;", "\u{2192}"),
+    ("&rarrap;", "\u{2975}"),
+    ("&rarrb;", "\u{21e5}"),
+    ("&rarrbfs;", "\u{2920}"),
+    ("&rarrc;", "\u{2933}"),
+    ("&rarrfs;", "\u{291e}"),
+    ("&rarrhk;", "\u{21aa}"),
+    ("&rarrlp;", "\u{21ac}"),
+    ("&rarrpl;", "\u{2945}"),
+    ("&rarrsim;", "\u{2974}"),
+    ("&rarrtl;", "\u{21a3}"),
+    ("&rarrw;", "\u{219d}"),
+    ("&ratail;", "\u{291a}"),
+    ("&ratio;", "\u{2236}"),
+    ("&rationals;", "\u{211a}"),
+    ("&rbarr;", "\u{290d}"),
+    ("&rbbrk;", "\u{2773}"),
+    ("&rbrace;", "}"),
+    ("&rbrack;", "]"),
+    ("&rbrke;", "\u{298c}"),
+    ("&rbrksld;", "\u{298e}"),
+    ("&rbrkslu;", "\u{2990}"),
+    ("&rcaron;", "\u{159}"),
+    ("&rcedil;", "\u{157}"),
+    ("&rceil;", "\u{2309}"),
+    ("&rcub;", "}"),
+    ("&rcy;", "\u{440}"),
+    ("&rdca;", "\u{2937}"),
+    ("&rdldhar;", "\u{2969}"),
+    ("&rdquo;", "\u{201d}"),
+    ("&rdquor;", "\u{201d}"),
+    ("&rdsh;", "\u{21b3}"),
+    ("&real;", "\u{211c}"),
+    ("&realine;", "\u{211b}"),
+    ("&realpart;", "\u{211c}"),
+    ("&reals;", "\u{211d}"),
+    ("&rect;", "\u{25ad}"),
+    ("&reg", "\u{ae}"),
+    ("&reg;", "\u{ae}"),
+    ("&rfisht;", "\u{297d}"),
+    ("&rfloor;", "\u{230b}"),
+    ("&rfr;", "\u{1d52f}"),
+    ("&rhard;", "\u{21c1}"),
+    ("&rharu;", "\u{21c0}"),
+    ("&rharul;", "\u{296c}"),
+    ("&rho;", "\u{3c1}"),
+    ("&rhov;", "\u{3f1}"),
+    ("&rightarrow;", "\u{2192}"),
+    ("&rightarrowtail;", "\u{21a3}"),
+    ("&rightharpoondown;", "\u{21c1}"),
+    ("&rightharpoonup;", "\u{21c0}"),
+    ("&rightleftarrows;", "\u{21c4}"),
+    ("&rightleftharpoons;", "\u{21cc}"),
+    ("&rightrightarrows;", "\u{21c9}"),
+    ("&rightsquigarrow;", "\u{219d}"),
+    ("&rightthreetimes;", "\u{22cc}"),
+    ("&ring;", "\u{2da}"),
+    ("&risingdotseq;", "\u{2253}"),
+    ("&rlarr;", "\u{21c4}"),
+    ("&rlhar;", "\u{21cc}"),
+    ("&rlm;", "\u{200f}"),
+    ("&rmoust;", "\u{23b1}"),
+    ("&rmoustache;", "\u{23b1}"),
+    ("&rnmid;", "\u{2aee}"),
+    ("&roang;", "\u{27ed}"),
+    ("&roarr;", "\u{21fe}"),
+    ("&robrk;", "\u{27e7}"),
+    ("&ropar;", "\u{2986}"),
+    ("&ropf;", "\u{1d563}"),
+    ("&roplus;", "\u{2a2e}"),
+    ("&rotimes;", "\u{2a35}"),
+    ("&rpar;", ")"),
+    ("&rpargt;", "\u{2994}"),
+    ("&rppolint;", "\u{2a12}"),
+    ("&rrarr;", "\u{21c9}"),
+    ("&rsaquo;", "\u{203a}"),
+    ("&rscr;", "\u{1d4c7}"),
+    ("&rsh;", "\u{21b1}"),
+    ("&rsqb;", "]"),
+    ("&rsquo;", "\u{2019}"),
+    ("&rsquor;", "\u{2019}"),
+    ("&rthree;", "\u{22cc}"),
+    ("&rtimes;", "\u{22ca}"),
+    ("&rtri;", "\u{25b9}"),
+    ("&rtrie;", "\u{22b5}"),
+    ("&rtrif;", "\u{25b8}"),
+    ("&rtriltri;", "\u{29ce}"),
+    ("&ruluhar;", "\u{2968}"),
+    ("&rx;", "\u{211e}"),
+    ("&sacute;", "\u{15b}"),
+    ("&sbquo;", "\u{201a}"),
+    ("&sc;", "\u{227b}"),
+    ("&scE;", "\u{2ab4}"),
+    ("&scap;", "\u{2ab8}"),
+    ("&scaron;", "\u{161}"),
+    ("&sccue;", "\u{227d}"),
+    ("&sce;", "\u{2ab0}"),
+    ("&scedil;", "\u{15f}"),
+    ("&scirc;", "\u{15d}"),
+    ("&scnE;", "\u{2ab6}"),
+    ("&scnap;", "\u{2aba}"),
+    ("&scnsim;", "\u{22e9}"),
+    ("&scpolint;", "\u{2a13}"),
+    ("&scsim;", "\u{227f}"),
+    ("&scy;", "\u{441}"),
+    ("&sdot;", "\u{22c5}"),
+    ("&sdotb;", "\u{22a1}"),
+    ("&sdote;", "\u{2a66}"),
+    ("&seArr;", "\u{21d8}"),
+    ("&searhk;", "\u{2925}"),
+    ("&searr;", "\u{2198}"),
+    ("&searrow;", "\u{2198}"),
+    ("&sect", "\u{a7}"),
+    ("&sect;", "\u{a7}"),
+    ("&semi;", ";"),
+    ("&seswar;", "\u{2929}"),
+    ("&setminus;", "\u{2216}"),
+    ("&setmn;", "\u{2216}"),
+    ("&sext;", "\u{2736}"),
+    ("&sfr;", "\u{1d530}"),
+    ("&sfrown;", "\u{2322}"),
+    ("&sharp;", "\u{266f}"),
+    ("&shchcy;", "\u{449}"),
+    ("&shcy;", "\u{448}"),
+    ("&shortmid;", "\u{2223}"),
+    ("&shortparallel;", "\u{2225}"),
+    ("&shy", "\u{ad}"),
+    ("&shy;", "\u{ad}"),
+    ("&sigma;", "\u{3c3}"),
+    ("&sigmaf;", "\u{3c2}"),
+    ("&sigmav;", "\u{3c2}"),
+    ("&sim;", "\u{223c}"),
+    ("&simdot;", "\u{2a6a}"),
+    ("&sime;", "\u{2243}"),
+    ("&simeq;", "\u{2243}"),
+    ("&simg;", "\u{2a9e}"),
+    ("&simgE;", "\u{2aa0}"),
+    ("&siml;", "\u{2a9d}"),
+    ("&simlE;", "\u{2a9f}"),
+    ("&simne;", "\u{2246}"),
+    ("&simplus;", "\u{2a24}"),
+    ("&simrarr;", "\u{2972}"),
+    ("&slarr;", "\u{2190}"),
+    ("&smallsetminus;", "\u{2216}"),
+    ("&smashp;", "\u{2a33}"),
+    ("&smeparsl;", "\u{29e4}"),
+    ("&smid;", "\u{2223}"),
+    ("&smile;", "\u{2323}"),
+    ("&smt;", "\u{2aaa}"),
+    ("&smte;", "\u{2aac}"),
+    ("&smtes;", "\u{2aac}\u{fe00}"),
+    ("&softcy;", "\u{44c}"),
+    ("&sol;", "/"),
+    ("&solb;", "\u{29c4}"),
+    ("&solbar;", "\u{233f}"),
+    ("&sopf;", "\u{1d564}"),
+    ("&spades;", "\u{2660}"),
+    ("&spadesuit;", "\u{2660}"),
+    ("&spar;", "\u{2225}"),
+    ("&sqcap;", "\u{2293}"),
+    ("&sqcaps;", "\u{2293}\u{fe00}"),
+    ("&sqcup;", "\u{2294}"),
+    ("&sqcups;", "\u{2294}\u{fe00}"),
+    ("&sqsub;", "\u{228f}"),
+    ("&sqsube;", "\u{2291}"),
+    ("&sqsubset;", "\u{228f}"),
+    ("&sqsubseteq;", "\u{2291}"),
+    ("&sqsup;", "\u{2290}"),
+    ("&sqsupe;", "\u{2292}"),
+    ("&sqsupset;", "\u{2290}"),
+    ("&sqsupseteq;", "\u{2292}"),
+    ("&squ;", "\u{25a1}"),
+    ("&square;", "\u{25a1}"),
+    ("&squarf;", "\u{25aa}"),
+    ("&squf;", "\u{25aa}"),
+    ("&srarr;", "\u{2192}"),
+    ("&sscr;", "\u{1d4c8}"),
+    ("&ssetmn;", "\u{2216}"),
+    ("&ssmile;", "\u{2323}"),
+    ("&sstarf;", "\u{22c6}"),
+    ("&star;", "\u{2606}"),
+    ("&starf;", "\u{2605}"),
+    ("&straightepsilon;", "\u{3f5}"),
+    ("&straightphi;", "\u{3d5}"),
+    ("&strns;", "\u{af}"),
+    ("&sub;", "\u{2282}"),
+    ("&subE;", "\u{2ac5}"),
+    ("&subdot;", "\u{2abd}"),
+    ("&sube;", "\u{2286}"),
+    ("&subedot;", "\u{2ac3}"),
+    ("&submult;", "\u{2ac1}"),
+    ("&subnE;", "\u{2acb}"),
+    ("&subne;", "\u{228a}"),
+    ("&subplus;", "\u{2abf}"),
+    ("&subrarr;", "\u{2979}"),
+    ("&subset;", "\u{2282}"),
+    ("&subseteq;", "\u{2286}"),
+    ("&subseteqq;", "\u{2ac5}"),
+    ("&subsetneq;", "\u{228a}"),
+    ("&subsetneqq;", "\u{2acb}"),
+    ("&subsim;", "\u{2ac7}"),
+    ("&subsub;", "\u{2ad5}"),
+    ("&subsup;", "\u{2ad3}"),
+    ("&succ;", "\u{227b}"),
+    ("&succapprox;", "\u{2ab8}"),
+    ("&succcurlyeq;", "\u{227d}"),
+    ("&succeq;", "\u{2ab0}"),
+    ("&succnapprox;", "\u{2aba}"),
+    ("&succneqq;", "\u{2ab6}"),
+    ("&succnsim;", "\u{22e9}"),
+    ("&succsim;", "\u{227f}"),
+    ("&sum;", "\u{2211}"),
+    ("&sung;", "\u{266a}"),
+    ("&sup1", "\u{b9}"),
+    ("&sup1;", "\u{b9}"),
+    ("&sup2", "\u{b2}"),
+    ("&sup2;", "\u{b2}"),
+    ("&sup3", "\u{b3}"),
+    ("&sup3;", "\u{b3}"),
+    ("&sup;", "\u{2283}"),
+    ("&supE;", "\u{2ac6}"),
+    ("&supdot;", "\u{2abe}"),
+    ("&supdsub;", "\u{2ad8}"),
+    ("&supe;", "\u{2287}"),
+    ("&supedot;", "\u{2ac4}"),
+    ("&suphsol;", "\u{27c9}"),
+    ("&suphsub;", "\u{2ad7}"),
+    ("&suplarr;", "\u{297b}"),
+    ("&supmult;", "\u{2ac2}"),
+    ("&supnE;", "\u{2acc}"),
+    ("&supne;", "\u{228b}"),
+    ("&supplus;", "\u{2ac0}"),
+    ("&supset;", "\u{2283}"),
+    ("&supseteq;", "\u{2287}"),
+    ("&supseteqq;", "\u{2ac6}"),
+    ("&supsetneq;", "\u{228b}"),
+    ("&supsetneqq;", "\u{2acc}"),
+    ("&supsim;", "\u{2ac8}"),
+    ("&supsub;", "\u{2ad4}"),
+    ("&supsup;", "\u{2ad6}"),
+    ("&swArr;", "\u{21d9}"),
+    ("&swarhk;", "\u{2926}"),
+    ("&swarr;", "\u{2199}"),
+    ("&swarrow;", "\u{2199}"),
+    ("&swnwar;", "\u{292a}"),
+    ("&szlig", "\u{df}"),
+    ("&szlig;", "\u{df}"),
+    ("&target;", "\u{2316}"),
+    ("&tau;", "\u{3c4}"),
+    ("&tbrk;", "\u{23b4}"),
+    ("&tcaron;", "\u{165}"),
+    ("&tcedil;", "\u{163}"),
+    ("&tcy;", "\u{442}"),
+    ("&tdot;", "\u{20db}"),
+    ("&telrec;", "\u{2315}"),
+    ("&tfr;", "\u{1d531}"),
+    ("&there4;", "\u{2234}"),
+    ("&therefore;", "\u{2234}"),
+    ("&theta;", "\u{3b8}"),
+    ("&thetasym;", "\u{3d1}"),
+    ("&thetav;", "\u{3d1}"),
+    ("&thickapprox;", "\u{2248}"),
+    ("&thicksim;", "\u{223c}"),
+    ("&thinsp;", "\u{2009}"),
+    ("&thkap;", "\u{2248}"),
+    ("&thksim;", "\u{223c}"),
+    ("&thorn", "\u{fe}"),
+    ("&thorn;", "\u{fe}"),
+    ("&tilde;", "\u{2dc}"),
+    ("&times", "\u{d7}"),
+    ("&times;", "\u{d7}"),
+    ("&timesb;", "\u{22a0}"),
+    ("&timesbar;", "\u{2a31}"),
+    ("&timesd;", "\u{2a30}"),
+    ("&tint;", "\u{222d}"),
+    ("&toea;", "\u{2928}"),
+    ("&top;", "\u{22a4}"),
+    ("&topbot;", "\u{2336}"),
+    ("&topcir;", "\u{2af1}"),
+    ("&topf;", "\u{1d565}"),
+    ("&topfork;", "\u{2ada}"),
+    ("&tosa;", "\u{2929}"),
+    ("&tprime;", "\u{2034}"),
+    ("&trade;", "\u{2122}"),
+    ("&triangle;", "\u{25b5}"),
+    ("&triangledown;", "\u{25bf}"),
+    ("&triangleleft;", "\u{25c3}"),
+    ("&trianglelefteq;", "\u{22b4}"),
+    ("&triangleq;", "\u{225c}"),
+    ("&triangleright;", "\u{25b9}"),
+    ("&trianglerighteq;", "\u{22b5}"),
+    ("&tridot;", "\u{25ec}"),
+    ("&trie;", "\u{225c}"),
+    ("&triminus;", "\u{2a3a}"),
+    ("&triplus;", "\u{2a39}"),
+    ("&trisb;", "\u{29cd}"),
+    ("&tritime;", "\u{2a3b}"),
+    ("&trpezium;", "\u{23e2}"),
+    ("&tscr;", "\u{1d4c9}"),
+    ("&tscy;", "\u{446}"),
+    ("&tshcy;", "\u{45b}"),
+    ("&tstrok;", "\u{167}"),
+    ("&twixt;", "\u{226c}"),
+    ("&twoheadleftarrow;", "\u{219e}"),
+    ("&twoheadrightarrow;", "\u{21a0}"),
+    ("&uArr;", "\u{21d1}"),
+    ("&uHar;", "\u{2963}"),
+    ("&uacute", "\u{fa}"),
+    ("&uacute;", "\u{fa}"),
+    ("&uarr;", "\u{2191}"),
+    ("&ubrcy;", "\u{45e}"),
+    ("&ubreve;", "\u{16d}"),
+    ("&ucirc", "\u{fb}"),
+    ("&ucirc;", "\u{fb}"),
+    ("&ucy;", "\u{443}"),
+    ("&udarr;", "\u{21c5}"),
+    ("&udblac;", "\u{171}"),
+    ("&udhar;", "\u{296e}"),
+    ("&ufisht;", "\u{297e}"),
+    ("&ufr;", "\u{1d532}"),
+    ("&ugrave", "\u{f9}"),
+    ("&ugrave;", "\u{f9}"),
+    ("&uharl;", "\u{21bf}"),
+    ("&uharr;", "\u{21be}"),
+    ("&uhblk;", "\u{2580}"),
+    ("&ulcorn;", "\u{231c}"),
+    ("&ulcorner;", "\u{231c}"),
+    ("&ulcrop;", "\u{230f}"),
+    ("&ultri;", "\u{25f8}"),
+    ("&umacr;", "\u{16b}"),
+    ("&uml", "\u{a8}"),
+    ("&uml;", "\u{a8}"),
+    ("&uogon;", "\u{173}"),
+    ("&uopf;", "\u{1d566}"),
+    ("&uparrow;", "\u{2191}"),
+    ("&updownarrow;", "\u{2195}"),
+    ("&upharpoonleft;", "\u{21bf}"),
+    ("&upharpoonright;", "\u{21be}"),
+    ("&uplus;", "\u{228e}"),
+    ("&upsi;", "\u{3c5}"),
+    ("&upsih;", "\u{3d2}"),
+    ("&upsilon;", "\u{3c5}"),
+    ("&upuparrows;", "\u{21c8}"),
+    ("&urcorn;", "\u{231d}"),
+    ("&urcorner;", "\u{231d}"),
+    ("&urcrop;", "\u{230e}"),
+    ("&uring;", "\u{16f}"),
+    ("&urtri;", "\u{25f9}"),
+    ("&uscr;", "\u{1d4ca}"),
+    ("&utdot;", "\u{22f0}"),
+    ("&utilde;", "\u{169}"),
+    ("&utri;", "\u{25b5}"),
+    ("&utrif;", "\u{25b4}"),
+    ("&uuarr;", "\u{21c8}"),
+    ("&uuml", "\u{fc}"),
+    ("&uuml;", "\u{fc}"),
+    ("&uwangle;", "\u{29a7}"),
+    ("&vArr;", "\u{21d5}"),
+    ("&vBar;", "\u{2ae8}"),
+    ("&vBarv;", "\u{2ae9}"),
+    ("&vDash;", "\u{22a8}"),
+    ("&vangrt;", "\u{299c}"),
+    ("&varepsilon;", "\u{3f5}"),
+    ("&varkappa;", "\u{3f0}"),
+    ("&varnothing;", "\u{2205}"),
+    ("&varphi;", "\u{3d5}"),
+    ("&varpi;", "\u{3d6}"),
+    ("&varpropto;", "\u{221d}"),
+    ("&varr;", "\u{2195}"),
+    ("&varrho;", "\u{3f1}"),
+    ("&varsigma;", "\u{3c2}"),
+    ("&varsubsetneq;", "\u{228a}\u{fe00}"),
+    ("&varsubsetneqq;", "\u{2acb}\u{fe00}"),
+    ("&varsupsetneq;", "\u{228b}\u{fe00}"),
+    ("&varsupsetneqq;", "\u{2acc}\u{fe00}"),
+    ("&vartheta;", "\u{3d1}"),
+    ("&vartriangleleft;", "\u{22b2}"),
+    ("&vartriangleright;", "\u{22b3}"),
+    ("&vcy;", "\u{432}"),
+    ("&vdash;", "\u{22a2}"),
+    ("&vee;", "\u{2228}"),
+    ("&veebar;", "\u{22bb}"),
+    ("&veeeq;", "\u{225a}"),
+    ("&vellip;", "\u{22ee}"),
+    ("&verbar;", "|"),
+    ("&vert;", "|"),
+    ("&vfr;", "\u{1d533}"),
+    ("&vltri;", "\u{22b2}"),
+    ("&vnsub;", "\u{2282}\u{20d2}"),
+    ("&vnsup;", "\u{2283}\u{20d2}"),
+    ("&vopf;", "\u{1d567}"),
+    ("&vprop;", "\u{221d}"),
+    ("&vrtri;", "\u{22b3}"),
+    ("&vscr;", "\u{1d4cb}"),
+    ("&vsubnE;", "\u{2acb}\u{fe00}"),
+    ("&vsubne;", "\u{228a}\u{fe00}"),
+    ("&vsupnE;", "\u{2acc}\u{fe00}"),
+    ("&vsupne;", "\u{228b}\u{fe00}"),
+    ("&vzigzag;", "\u{299a}"),
+    ("&wcirc;", "\u{175}"),
+    ("&wedbar;", "\u{2a5f}"),
+    ("&wedge;", "\u{2227}"),
+    ("&wedgeq;", "\u{2259}"),
+    ("&weierp;", "\u{2118}"),
+    ("&wfr;", "\u{1d534}"),
+    ("&wopf;", "\u{1d568}"),
+    ("&wp;", "\u{2118}"),
+    ("&wr;", "\u{2240}"),
+    ("&wreath;", "\u{2240}"),
+    ("&wscr;", "\u{1d4cc}"),
+    ("&xcap;", "\u{22c2}"),
+    ("&xcirc;", "\u{25ef}"),
+    ("&xcup;", "\u{22c3}"),
+    ("&xdtri;", "\u{25bd}"),
+    ("&xfr;", "\u{1d535}"),
+    ("&xhArr;", "\u{27fa}"),
+    ("&xharr;", "\u{27f7}"),
+    ("&xi;", "\u{3be}"),
+    ("&xlArr;", "\u{27f8}"),
+    ("&xlarr;", "\u{27f5}"),
+    ("&xmap;", "\u{27fc}"),
+    ("&xnis;", "\u{22fb}"),
+    ("&xodot;", "\u{2a00}"),
+    ("&xopf;", "\u{1d569}"),
+    ("&xoplus;", "\u{2a01}"),
+    ("&xotime;", "\u{2a02}"),
+    ("&xrArr;", "\u{27f9}"),
+    ("&xrarr;", "\u{27f6}"),
+    ("&xscr;", "\u{1d4cd}"),
+    ("&xsqcup;", "\u{2a06}"),
+    ("&xuplus;", "\u{2a04}"),
+    ("&xutri;", "\u{25b3}"),
+    ("&xvee;", "\u{22c1}"),
+    ("&xwedge;", "\u{22c0}"),
+    ("&yacute", "\u{fd}"),
+    ("&yacute;", "\u{fd}"),
+    ("&yacy;", "\u{44f}"),
+    ("&ycirc;", "\u{177}"),
+    ("&ycy;", "\u{44b}"),
+    ("&yen", "\u{a5}"),
+    ("&yen;", "\u{a5}"),
+    ("&yfr;", "\u{1d536}"),
+    ("&yicy;", "\u{457}"),
+    ("&yopf;", "\u{1d56a}"),
+    ("&yscr;", "\u{1d4ce}"),
+    ("&yucy;", "\u{44e}"),
+    ("&yuml", "\u{ff}"),
+    ("&yuml;", "\u{ff}"),
+    ("&zacute;", "\u{17a}"),
+    ("&zcaron;", "\u{17e}"),
+    ("&zcy;", "\u{437}"),
+    ("&zdot;", "\u{17c}"),
+    ("&zeetrf;", "\u{2128}"),
+    ("&zeta;", "\u{3b6}"),
+    ("&zfr;", "\u{1d537}"),
+    ("&zhcy;", "\u{436}"),
+    ("&zigrarr;", "\u{21dd}"),
+    ("&zopf;", "\u{1d56b}"),
+    ("&zscr;", "\u{1d4cf}"),
+    ("&zwj;", "\u{200d}"),
+    ("&zwnj;", "\u{200c}"),
+];
+
+/**
+ * The nodes of the trie of the names after the `&`, the first one is the root. A node is the
+ * start of its edges in `TRIE_EDGES`, the number of them, and the index of its code points in
+ * `TRIE_VALUES` plus one when a name ends at the node, otherwise 0.
+ */
+pub(crate) static TRIE_NODES: [(u16, u8, u16); 9854] = [
+    (0, 52, 0), (52, 16, 0), (68, 8, 0), (76, 14, 0), (90, 11, 0), (101, 16, 0),
+    (117, 5, 0), (122, 12, 0), (134, 8, 0), (142, 14, 0), (156, 5, 0), (161, 7, 0),
+    (168, 11, 0), (179, 8, 0), (187, 9, 0), (196, 14, 0), (210, 9, 0), (219, 4, 0),
+    (223, 12, 0), (235, 13, 0), (248, 11, 0), (259, 14, 0), (273, 9, 0), (282, 5, 0),
+    (287, 4, 0), (291, 9, 0), (300, 8, 0), (308, 16, 0), (324, 16, 0), (340, 15, 0),
+    (355, 19, 0), (374, 18, 0), (392, 12, 0), (404, 17, 0), (421, 10, 0), (431, 15, 0),
+    (446, 6, 0), (452, 8, 0), (460, 23, 0), (483, 14, 0), (497, 24, 0), (521, 18, 0),
+    (539, 12, 0), (551, 6, 0), (557, 21, 0), (578, 19, 0), (597, 13, 0), (610, 18, 0),
+    (628, 15, 0), (643, 7, 0), (650, 14, 0), (664, 8, 0), (672, 10, 0), (682, 1, 0),
+    (683, 1, 0), (684, 1, 0), (685, 1, 0), (686, 2, 0), (688, 1, 0), (689, 1, 0),
+    (690, 1, 0), (691, 1, 0), (692, 1, 0), (693, 2, 0), (695, 1, 0), (696, 1, 0),
+    (697, 2, 0), (699, 1, 0), (700, 1, 0), (701, 2, 0), (703, 1, 0), (704, 3, 0),
+    (707, 1, 0), (708, 1, 0), (709, 1, 0), (710, 1, 0), (711, 1, 0), (712, 1, 0),
+    (713, 1, 0), (714, 3, 0), (717, 4, 0), (721, 1, 0), (722, 2, 0), (724, 1, 0),
+    (725, 1, 0), (726, 1, 0), (727, 1, 0), (728, 4, 0), (732, 1, 0), (733, 1, 0),
+    (734, 1, 0), (735, 2, 0), (737, 1, 0), (738, 1, 0), (739, 1, 0), (740, 3, 0),
+    (743, 2, 0), (745, 1, 0), (746, 1, 0), (747, 2, 0), (749, 4, 0), (753, 2, 0),
+    (755, 1, 0), (756, 1, 0), (757, 1, 0), (758, 3, 0), (761, 1, 0), (762, 1, 0),
+    (763, 1, 0), (764, 1, 0), (765, 2, 0), (767, 2, 0), (769, 1, 0), (770, 1, 0),
+    (771, 2, 0), (773, 1, 0), (774, 1, 0), (775, 2, 0), (777, 1, 0), (778, 1, 0),
+    (779, 1, 0), (780, 3, 0), (783, 1, 0), (784, 1, 0), (785, 1, 146), (786, 1, 0),
+    (787, 1, 0), (788, 3, 0), (791, 1, 0), (792, 1, 0), (793, 1, 0), (794, 1, 0),
+    (795, 1, 0), (796, 1, 0), (797, 1, 0), (798, 1, 0), (799, 2, 0), (801, 1, 0),
+    (802, 1, 0), (803, 1, 0), (804, 2, 0), (806, 2, 0), (808, 1, 0), (809, 1, 0),
+    (810, 1, 0), (811, 1, 0), (812, 1, 0), (813, 2, 0), (815, 1, 0), (816, 1, 0),
+    (817, 1, 0), (818, 3, 0), (821, 2, 0), (823, 3, 0), (826, 1, 0), (827, 1, 0),
+    (828, 2, 0), (830, 2, 0), (832, 1, 0), (833, 1, 0), (834, 2, 0), (836, 1, 0),
+    (837, 1, 0), (838, 1, 0), (839, 1, 0), (840, 2, 0), (842, 1, 0), (843, 1, 0),
+    (844, 1, 0), (845, 1, 0), (846, 1, 215), (847, 5, 0), (852, 3, 0), (855, 2, 0),
+    (857, 1, 0), (858, 2, 0), (860, 1, 0), (861, 3, 0), (864, 3, 0), (867, 1, 0),
+    (868, 1, 0), (869, 1, 0), (870, 2, 0), (872, 1, 0), (873, 1, 0), (874, 1, 0),
+    (875, 1, 0), (876, 1, 0), (877, 1, 0), (878, 1, 0), (879, 3, 0), (882, 3, 0),
+    (885, 1, 0), (886, 4, 0), (890, 1, 0), (891, 1, 0), (892, 1, 0), (893, 1, 0),
+    (894, 1, 0), (895, 2, 0), (897, 1, 0), (898, 1, 0), (899, 1, 0), (900, 3, 0),
+    (903, 1, 0), (904, 1, 0), (905, 1, 0), (906, 2, 0), (908, 1, 0), (909, 1, 0),
+    (910, 1, 0), (911, 1, 0), (912, 1, 0), (913, 1, 0), (914, 1, 0), (915, 1, 0),
+    (916, 1, 0), (917, 2, 0), (919, 4, 0), (923, 2, 0), (925, 1, 0), (926, 1, 0),
+    (927, 1, 0), (928, 1, 0), (929, 1, 0), (930, 1, 0), (931, 3, 0), (934, 3, 0),
+    (937, 2, 0), (939, 1, 0), (940, 1, 0), (941, 1, 0), (942, 2, 0), (944, 1, 0),
+    (945, 2, 0), (947, 1, 0), (948, 2, 0), (950, 1, 0), (951, 1, 0), (952, 5, 0),
+    (957, 1, 0), (958, 1, 0), (959, 1, 0), (960, 1, 0), (961, 1, 0), (962, 2, 0),
+    (964, 1, 0), (965, 1, 0), (966, 4, 0), (970, 1, 0), (971, 1, 0), (972, 2, 0),
+    (974, 2, 0), (976, 3, 0), (979, 1, 0), (980, 2, 0), (982, 1, 0), (983, 1, 0),
+    (984, 1, 0), (985, 2, 0), (987, 2, 0), (989, 1, 0), (990, 2, 0), (992, 1, 0),
+    (993, 1, 0), (994, 1, 0), (995, 1, 0), (996, 2, 0), (998, 2, 0), (1000, 8, 0),
+    (1008, 1, 0), (1009, 1, 0), (1010, 1, 0), (1011, 1, 0), (1012, 1, 0), (1013, 1, 0),
+    (1014, 1, 0), (1015, 1, 0), (1016, 2, 0), (1018, 1, 0), (1019, 1, 0), (1020, 1, 0),
+    (1021, 1, 0), (1022, 1, 0), (1023, 1, 0), (1024, 1, 0), (1025, 1, 0), (1026, 1, 0),
+    (1027, 1, 0), (1028, 1, 0), (1029, 1, 0), (1030, 1, 0), (1031, 1, 0), (1032, 1, 0),
+    (1033, 1, 0), (1034, 1, 0), (1035, 2, 0), (1037, 1, 0), (1038, 1, 0), (1039, 1, 0),
+    (1040, 1, 0), (1041, 1, 0), (1042, 1, 0), (1043, 2, 0), (1045, 1, 0), (1046, 2, 0),
+    (1048, 1, 0), (1049, 1, 0), (1050, 1, 0), (1051, 1, 0), (1052, 1, 0), (1053, 6, 0),
+    (1059, 1, 0), (1060, 2, 0), (1062, 1, 0), (1063, 2, 0), (1065, 2, 0), (1067, 2, 0),
+    (1069, 2, 0), (1071, 7, 0), (1078, 1, 0), (1079, 3, 0), (1082, 1, 0), (1083, 1, 0),
+    (1084, 2, 0), (1086, 1, 0), (1087, 2, 0), (1089, 1, 0), (1090, 2, 0), (1092, 1, 0),
+    (1093, 5, 0), (1098, 1, 0), (1099, 1, 0), (1100, 1, 0), (1101, 3, 0), (1104, 2, 0),
+    (1106, 4, 0), (1110, 1, 0), (1111, 2, 0), (1113, 4, 0), (1117, 2, 0), (1119, 3, 0),
+    (1122, 4, 0), (1126, 1, 0), (1127, 3, 0), (1130, 1, 0), (1131, 3, 0), (1134, 1, 0),
+    (1135, 1, 0), (1136, 4, 0), (1140, 2, 0), (1142, 2, 0), (1144, 1, 0), (1145, 7, 0),
+    (1152, 2, 0), (1154, 1, 0), (1155, 1, 0), (1156, 1, 0), (1157, 4, 0), (1161, 2, 0),
+    (1163, 2, 0), (1165, 3, 0), (1168, 3, 0), (1171, 2, 0), (1173, 1, 0), (1174, 5, 0),
+    (1179, 1, 0), (1180, 1, 0), (1181, 5, 0), (1186, 2, 0), (1188, 3, 0), (1191, 2, 0),
+    (1193, 2, 0), (1195, 1, 0), (1196, 2, 0), (1198, 2, 0), (1200, 2, 0), (1202, 4, 0),
+    (1206, 1, 0), (1207, 1, 0), (1208, 2, 0), (1210, 3, 0), (1213, 4, 0), (1217, 3, 0),
+    (1220, 2, 0), (1222, 2, 0), (1224, 3, 0), (1227, 4, 0), (1231, 2, 0), (1233, 3, 0),
+    (1236, 2, 0), (1238, 2, 0), (1240, 3, 0), (1243, 1, 0), (1244, 1, 0), (1245, 1, 0),
+    (1246, 3, 0), (1249, 1, 0), (1250, 1, 0), (1251, 3, 0), (1254, 1, 0), (1255, 2, 0),
+    (1257, 1, 0), (1258, 2, 0), (1260, 1, 0), (1261, 2, 0), (1263, 3, 0), (1266, 1, 0),
+    (1267, 2, 0), (1269, 1, 0), (1270, 4, 0), (1274, 1, 0), (1275, 2, 0), (1277, 1, 0),
+    (1278, 1, 0), (1279, 4, 0), (1283, 4, 0), (1287, 1, 0), (1288, 1, 0), (1289, 2, 0),
+    (1291, 6, 146), (1297, 2, 0), (1299, 1, 0), (1300, 4, 0), (1304, 1, 0), (1305, 1, 0),
+    (1306, 3, 0), (1309, 1, 0), (1310, 1, 0), (1311, 5, 0), (1316, 3, 0), (1319, 2, 0),
+    (1321, 1, 0), (1322, 3, 0), (1325, 2, 0), (1327, 2, 0), (1329, 1, 0), (1330, 4, 0),
+    (1334, 1, 0), (1335, 3, 0), (1338, 5, 0), (1343, 4, 0), (1347, 1, 0), (1348, 1, 0),
+    (1349, 2, 0), (1351, 2, 0), (1353, 2, 0), (1355, 2, 0), (1357, 1, 0), (1358, 1, 0),
+    (1359, 1, 0), (1360, 2, 0), (1362, 1, 0), (1363, 1, 0), (1364, 2, 0), (1366, 1, 0),
+    (1367, 1, 0), (1368, 1, 0), (1369, 1, 0), (1370, 1, 0), (1371, 1, 0), (1372, 3, 0),
+    (1375, 1, 0), (1376, 2, 0), (1378, 1, 0), (1379, 9, 0), (1388, 3, 0), (1391, 4, 0),
+    (1395, 4, 0), (1399, 5, 0), (1404, 3, 0), (1407, 2, 0), (1409, 2, 0), (1411, 1, 0),
+    (1412, 5, 0), (1417, 2, 0), (1419, 4, 0), (1423, 8, 0), (1431, 1, 0), (1432, 5, 0),
+    (1437, 6, 0), (1443, 8, 215), (1451, 1, 0), (1452, 2, 0), (1454, 1, 0), (1455, 4, 0),
+    (1459, 2, 0), (1461, 1, 0), (1462, 1, 0), (1463, 1, 0), (1464, 1, 0), (1465, 3, 0),
+    (1468, 2, 0), (1470, 1, 0), (1471, 2, 0), (1473, 1, 0), (1474, 2, 0), (1476, 3, 0),
+    (1479, 2, 0), (1481, 3, 0), (1484, 1, 0), (1485, 2, 0), (1487, 5, 0), (1492, 2, 0),
+    (1494, 5, 0), (1499, 1, 0), (1500, 7, 0), (1507, 1, 0), (1508, 4, 0), (1512, 3, 0),
+    (1515, 3, 0), (1518, 1, 0), (1519, 7, 0), (1526, 1, 0), (1527, 2, 0), (1529, 3, 0),
+    (1532, 4, 0), (1536, 7, 0), (1543, 4, 0), (1547, 2, 0), (1549, 9, 0), (1558, 3, 0),
+    (1561, 1, 0), (1562, 2, 0), (1564, 2, 0), (1566, 5, 0), (1571, 1, 0), (1572, 2, 0),
+    (1574, 3, 0), (1577, 2, 0), (1579, 1, 0), (1580, 4, 0), (1584, 3, 0), (1587, 1, 0),
+    (1588, 3, 0), (1591, 7, 0), (1598, 3, 0), (1601, 1, 0), (1602, 1, 0), (1603, 1, 0),
+    (1604, 1, 0), (1605, 1, 0), (1606, 1, 0), (1607, 1, 0), (1608, 3, 0), (1611, 3, 0),
+    (1614, 2, 0), (1616, 1, 0), (1617, 3, 0), (1620, 10, 0), (1630, 2, 0), (1632, 1, 0),
+    (1633, 1, 0), (1634, 1, 0), (1635, 1, 0), (1636, 1, 0), (1637, 1, 0), (1638, 3, 0),
+    (1641, 3, 0), (1644, 1, 0), (1645, 1, 0), (1646, 7, 0), (1653, 3, 0), (1656, 4, 0),
+    (1660, 4, 0), (1664, 3, 0), (1667, 3, 0), (1670, 2, 0), (1672, 3, 0), (1675, 3, 0),
+    (1678, 1, 0), (1679, 1, 0), (1680, 4, 0), (1684, 2, 0), (1686, 1, 0), (1687, 4, 0),
+    (1691, 3, 0), (1694, 1, 0), (1695, 1, 0), (1696, 1, 0), (1697, 1, 0), (1698, 10, 0),
+    (1708, 1, 0), (1709, 7, 0), (1716, 1, 0), (1717, 4, 0), (1721, 2, 0), (1723, 1, 0),
+    (1724, 4, 0), (1728, 3, 0), (1731, 1, 0), (1732, 3, 0), (1735, 1, 0), (1736, 4, 0),
+    (1740, 2, 0), (1742, 5, 0), (1747, 3, 0), (1750, 1, 0), (1751, 2, 0), (1753, 1, 0),
+    (1754, 3, 0), (1757, 1, 0), (1758, 1, 0), (1759, 1, 0), (1760, 4, 0), (1764, 3, 0),
+    (1767, 3, 0), (1770, 1, 0), (1771, 3, 0), (1774, 3, 0), (1777, 2, 0), (1779, 1, 0),
+    (1780, 1, 0), (1781, 2, 0), (1783, 1, 0), (1784, 2, 0), (1786, 3, 0), (1789, 2, 0),
+    (1791, 1, 0), (1792, 2, 0), (1794, 2, 0), (1796, 2, 0), (1798, 2, 0), (1800, 6, 0),
+    (1806, 3, 0), (1809, 1, 0), (1810, 3, 0), (1813, 2, 0), (1815, 1, 0), (1816, 1, 0),
+    (1817, 1, 0), (1818, 1, 0), (1819, 2, 0), (1821, 1, 0), (1822, 1, 0), (1823, 3, 0),
+    (1826, 1, 0), (1827, 1, 0), (1828, 1, 0), (1829, 1, 0), (1830, 1, 0), (1831, 1, 0),
+    (1832, 2, 0), (1834, 1, 0), (1835, 1, 0), (1836, 2, 0), (1838, 1, 0), (1839, 1, 0),
+    (1840, 1, 0), (1841, 2, 0), (1843, 1, 0), (1844, 3, 0), (1847, 1, 0), (1848, 1, 0),
+    (1849, 2, 0), (1851, 1, 0), (1852, 2, 0), (1854, 1, 0), (1855, 1, 0), (1856, 3, 0),
+    (1859, 2, 0), (1861, 2, 0), (1863, 2, 0), (1865, 1, 0), (1866, 1, 0), (1867, 1, 0),
+    (1868, 2, 0), (1870, 1, 0), (1871, 1, 0), (1872, 1, 0), (1873, 1, 0), (1874, 1, 0),
+    (1875, 2, 0), (1877, 1, 0), (1878, 2, 0), (1880, 1, 0), (1881, 2, 0), (1883, 1, 0),
+    (1884, 1, 0), (1885, 1, 0), (1886, 1, 0), (1887, 1, 0), (1888, 2, 0), (1890, 1, 0),
+    (1891, 1, 2), (1892, 1, 0), (1893, 1, 0), (1894, 1, 0), (1895, 1, 0), (1896, 1, 0),
+    (1897, 1, 0), (1898, 1, 0), (1899, 1, 0), (1900, 1, 0), (1901, 1, 0), (1902, 1, 0),
+    (1903, 1, 0), (1904, 1, 0), (1905, 1, 0), (1906, 1, 0), (1907, 1, 0), (1908, 1, 0),
+    (1909, 1, 0), (1910, 2, 0), (1912, 1, 0), (1913, 1, 0), (1914, 1, 0), (1915, 1, 0),
+    (1916, 1, 0), (1917, 1, 0), (1918, 1, 0), (1919, 1, 0), (1920, 1, 0), (1921, 1, 0),
+    (1922, 1, 0), (1923, 1, 0), (1924, 2, 0), (1926, 1, 0), (1927, 1, 0), (1928, 1, 0),
+    (1929, 1, 0), (1930, 1, 0), (1931, 1, 0), (1932, 1, 0), (1933, 1, 0), (1934, 1, 0),
+    (1935, 1, 0), (1936, 1, 0), (1937, 2, 0), (1939, 1, 0), (1940, 3, 0), (1943, 2, 0),
+    (1945, 1, 0), (1946, 1, 0), (1947, 1, 0), (1948, 2, 0), (1950, 0, 35), (1950, 1, 0),
+    (1951, 1, 0), (1952, 1, 0), (1953, 1, 0), (1954, 1, 0), (1955, 1, 0), (1956, 1, 0),
+    (1957, 1, 0), (1958, 1, 0), (1959, 2, 0), (1961, 1, 0), (1962, 2, 0), (1964, 1, 0),
+    (1965, 1, 0), (1966, 3, 0), (1969, 1, 0), (1970, 1, 0), (1971, 1, 0), (1972, 1, 0),
+    (1973, 1, 0), (1974, 1, 114), (1975, 1, 0), (1976, 1, 0), (1977, 1, 0), (1978, 1, 0),
+    (1979, 1, 0), (1980, 1, 0), (1981, 1, 0), (1982, 1, 0), (1983, 1, 0), (1984, 1, 0),
+    (1985, 1, 0), (1986, 1, 0), (1987, 1, 0), (1988, 2, 0), (1990, 1, 0), (1991, 1, 0),
+    (1992, 1, 0), (1993, 1, 0), (1994, 1, 0), (1995, 1, 0), (1996, 1, 0), (1997, 1, 0),
+    (1998, 1, 0), (1999, 1, 0), (2000, 1, 0), (2001, 1, 0), (2002, 1, 0), (2003, 1, 0),
+    (2004, 0, 146), (2004, 1, 0), (2005, 1, 0), (2006, 1, 0), (2007, 1, 0), (2008, 1, 0),
+    (2009, 1, 0), (2010, 1, 0), (2011, 0, 155), (2011, 1, 0), (2012, 1, 0), (2013, 1, 0),
+    (2014, 0, 165), (2014, 1, 0), (2015, 1, 0), (2016, 1, 0), (2017, 1, 0), (2018, 1, 0),
+    (2019, 1, 0), (2020, 1, 0), (2021, 1, 0), (2022, 1, 0), (2023, 1, 0), (2024, 1, 0),
+    (2025, 1, 0), (2026, 1, 0), (2027, 1, 0), (2028, 1, 0), (2029, 1, 0), (2030, 1, 0),
+    (2031, 1, 0), (2032, 1, 0), (2033, 1, 0), (2034, 0, 183), (2034, 2, 0), (2036, 1, 0),
+    (2037, 2, 0), (2039, 1, 0), (2040, 1, 0), (2041, 1, 0), (2042, 1, 0), (2043, 1, 0),
+    (2044, 1, 0), (2045, 1, 0), (2046, 1, 0), (2047, 1, 0), (2048, 1, 0), (2049, 1, 0),
+    (2050, 1, 0), (2051, 1, 0), (2052, 1, 0), (2053, 1, 0), (2054, 1, 0), (2055, 1, 0),
+    (2056, 1, 0), (2057, 1, 0), (2058, 1, 0), (2059, 1, 0), (2060, 1, 0), (2061, 1, 0),
+    (2062, 1, 0), (2063, 0, 215), (2063, 1, 0), (2064, 1, 0), (2065, 1, 0), (2066, 1, 0),
+    (2067, 1, 0), (2068, 1, 0), (2069, 1, 0), (2070, 1, 0), (2071, 1, 0), (2072, 1, 0),
+    (2073, 1, 0), (2074, 0, 255), (2074, 1, 0), (2075, 1, 0), (2076, 1, 0), (2077, 1, 0),
+    (2078, 1, 0), (2079, 1, 0), (2080, 1, 0), (2081, 1, 0), (2082, 0, 266), (2082, 1, 0),
+    (2083, 1, 0), (2084, 1, 0), (2085, 1, 0), (2086, 1, 0), (2087, 1, 0), (2088, 1, 0),
+    (2089, 1, 0), (2090, 0, 274), (2090, 1, 0), (2091, 1, 0), (2092, 1, 0), (2093, 1, 0),
+    (2094, 1, 0), (2095, 1, 0), (2096, 1, 0), (2097, 1, 0), (2098, 1, 0), (2099, 1, 0),
+    (2100, 1, 0), (2101, 1, 0), (2102, 13, 0), (2115, 1, 0), (2116, 1, 0), (2117, 0, 340),
+    (2117, 1, 0), (2118, 1, 0), (2119, 1, 0), (2120, 1, 0), (2121, 1, 0), (2122, 1, 0),
+    (2123, 1, 0), (2124, 1, 0), (2125, 1, 0), (2126, 1, 0), (2127, 1, 0), (2128, 1, 0),
+    (2129, 0, 354), (2129, 1, 0), (2130, 1, 0), (2131, 2, 0), (2133, 1, 0), (2134, 1, 0),
+    (2135, 1, 0), (2136, 1, 0), (2137, 1, 0), (2138, 1, 0), (2139, 0, 368), (2139, 1, 0),
+    (2140, 1, 0), (2141, 1, 0), (2142, 0, 371), (2142, 1, 0), (2143, 1, 0), (2144, 2, 0),
+    (2146, 1, 0), (2147, 1, 0), (2148, 1, 0), (2149, 1, 0), (2150, 1, 0), (2151, 1, 0),
+    (2152, 1, 0), (2153, 1, 386), (2154, 1, 0), (2155, 1, 0), (2156, 1, 0), (2157, 1, 0),
+    (2158, 1, 0), (2159, 1, 0), (2160, 0, 394), (2160, 1, 0), (2161, 1, 0), (2162, 1, 0),
+    (2163, 1, 0), (2164, 1, 0), (2165, 1, 0), (2166, 1, 0), (2167, 1, 0), (2168, 1, 0),
+    (2169, 1, 0), (2170, 1, 0), (2171, 1, 0), (2172, 1, 0), (2173, 1, 0), (2174, 0, 431),
+    (2174, 1, 0), (2175, 1, 0), (2176, 1, 0), (2177, 1, 0), (2178, 1, 0), (2179, 1, 0),
+    (2180, 1, 0), (2181, 1, 0), (2182, 1, 0), (2183, 1, 0), (2184, 1, 0), (2185, 1, 0),
+    (2186, 1, 0), (2187, 2, 0), (2189, 2, 0), (2191, 1, 0), (2192, 3, 0), (2195, 1, 0),
+    (2196, 1, 0), (2197, 1, 0), (2198, 1, 0), (2199, 1, 0), (2200, 1, 0), (2201, 1, 0),
+    (2202, 1, 0), (2203, 1, 0), (2204, 1, 0), (2205, 2, 0), (2207, 2, 0), (2209, 1, 0),
+    (2210, 1, 0), (2211, 1, 0), (2212, 1, 0), (2213, 1, 0), (2214, 1, 0), (2215, 1, 0),
+    (2216, 2, 0), (2218, 1, 0), (2219, 1, 0), (2220, 1, 0), (2221, 1, 0), (2222, 1, 0),
+    (2223, 1, 0), (2224, 1, 0), (2225, 1, 0), (2226, 1, 0), (2227, 1, 0), (2228, 1, 0),
+    (2229, 1, 0), (2230, 1, 0), (2231, 1, 0), (2232, 1, 0), (2233, 1, 0), (2234, 1, 0),
+    (2235, 1, 0), (2236, 1, 0), (2237, 1, 0), (2238, 1, 0), (2239, 1, 0), (2240, 1, 0),
+    (2241, 1, 0), (2242, 1, 0), (2243, 1, 0), (2244, 1, 0), (2245, 3, 0), (2248, 1, 0),
+    (2249, 1, 0), (2250, 1, 0), (2251, 1, 0), (2252, 1, 0), (2253, 1, 0), (2254, 1, 0),
+    (2255, 1, 0), (2256, 1, 0), (2257, 1, 0), (2258, 0, 538), (2258, 1, 0), (2259, 1, 0),
+    (2260, 1, 0), (2261, 1, 0), (2262, 1, 0), (2263, 1, 0), (2264, 1, 0), (2265, 1, 0),
+    (2266, 1, 0), (2267, 1, 0), (2268, 1, 0), (2269, 1, 0), (2270, 1, 0), (2271, 1, 0),
+    (2272, 1, 0), (2273, 1, 0), (2274, 1, 0), (2275, 1, 0), (2276, 1, 0), (2277, 1, 0),
+    (2278, 1, 0), (2279, 1, 0), (2280, 1, 0), (2281, 1, 0), (2282, 0, 562), (2282, 1, 0),
+    (2283, 1, 0), (2284, 1, 0), (2285, 1, 0), (2286, 1, 0), (2287, 1, 0), (2288, 0, 14),
+    (2288, 1, 0), (2289, 1, 0), (2290, 2, 0), (2292, 1, 0), (2293, 2, 0), (2295, 1, 2),
+    (2296, 5, 0), (2301, 7, 0), (2308, 1, 0), (2309, 1, 0), (2310, 0, 478), (2310, 1, 0),
+    (2311, 1, 0), (2312, 1, 0), (2313, 1, 0), (2314, 1, 0), (2315, 1, 0), (2316, 1, 0),
+    (2317, 1, 0), (2318, 1, 0), (2319, 1, 0), (2320, 1, 0), (2321, 1, 0), (2322, 1, 0),
+    (2323, 1, 0), (2324, 1, 0), (2325, 1, 0), (2326, 2, 0), (2328, 1, 0), (2329, 1, 0),
+    (2330, 1, 0), (2331, 1, 0), (2332, 1, 0), (2333, 1, 0), (2334, 1, 0), (2335, 1, 0),
+    (2336, 3, 0), (2339, 1, 0), (2340, 7, 0), (2347, 1, 0), (2348, 2, 0), (2350, 2, 0),
+    (2352, 1, 0), (2353, 2, 0), (2355, 1, 0), (2356, 1, 0), (2357, 2, 0), (2359, 1, 0),
+    (2360, 12, 0), (2372, 1, 0), (2373, 1, 0), (2374, 1, 0), (2375, 1, 0), (2376, 1, 0),
+    (2377, 1, 0), (2378, 1, 0), (2379, 1, 0), (2380, 1, 0), (2381, 1, 0), (2382, 6, 0),
+    (2388, 2, 0), (2390, 2, 0), (2392, 1, 0), (2393, 1, 0), (2394, 1, 0), (2395, 1, 0),
+    (2396, 1, 0), (2397, 1, 0), (2398, 1, 0), (2399, 1, 0), (2400, 1, 0), (2401, 1, 0),
+    (2402, 1, 0), (2403, 7, 0), (2410, 1, 0), (2411, 1, 0), (2412, 2, 0), (2414, 2, 0),
+    (2416, 3, 0), (2419, 1, 0), (2420, 1, 0), (2421, 1, 0), (2422, 2, 0), (2424, 1, 0),
+    (2425, 1, 0), (2426, 2, 0), (2428, 1, 0), (2429, 6, 0), (2435, 4, 0), (2439, 1, 0),
+    (2440, 1, 0), (2441, 1, 0), (2442, 1, 0), (2443, 1, 0), (2444, 1, 0), (2445, 1, 0),
+    (2446, 1, 0), (2447, 1, 0), (2448, 1, 0), (2449, 1, 0), (2450, 1, 0), (2451, 1, 0),
+    (2452, 1, 0), (2453, 1, 0), (2454, 0, 82), (2454, 2, 0), (2456, 1, 0), (2457, 1, 780),
+    (2458, 1, 0), (2459, 1, 0), (2460, 1, 0), (2461, 1, 0), (2462, 1, 0), (2463, 1, 0),
+    (2464, 1, 0), (2465, 1, 0), (2466, 1, 0), (2467, 3, 0), (2470, 1, 0), (2471, 2, 0),
+    (2473, 1, 0), (2474, 1, 0), (2475, 5, 0), (2480, 1, 0), (2481, 1, 0), (2482, 1, 0),
+    (2483, 2, 0), (2485, 2, 0), (2487, 1, 0), (2488, 1, 0), (2489, 1, 0), (2490, 1, 0),
+    (2491, 1, 0), (2492, 1, 0), (2493, 1, 0), (2494, 1, 0), (2495, 1, 0), (2496, 1, 0),
+    (2497, 1, 0), (2498, 1, 0), (2499, 1, 0), (2500, 1, 0), (2501, 1, 0), (2502, 1, 0),
+    (2503, 1, 0), (2504, 1, 0), (2505, 0, 137), (2505, 1, 0), (2506, 1, 0), (2507, 0, 820),
+    (2507, 1, 0), (2508, 2, 0), (2510, 0, 824), (2510, 1, 0), (2511, 1, 0), (2512, 2, 0),
+    (2514, 1, 0), (2515, 1, 0), (2516, 1, 0), (2517, 1, 0), (2518, 1, 0), (2519, 1, 0),
+    (2520, 1, 0), (2521, 1, 0), (2522, 1, 0), (2523, 1, 0), (2524, 2, 0), (2526, 2, 0),
+    (2528, 3, 0), (2531, 1, 0), (2532, 1, 0), (2533, 1, 0), (2534, 1, 0), (2535, 1, 0),
+    (2536, 1, 0), (2537, 1, 0), (2538, 1, 851), (2539, 1, 0), (2540, 1, 0), (2541, 1, 0),
+    (2542, 1, 0), (2543, 2, 0), (2545, 1, 0), (2546, 1, 0), (2547, 1, 0), (2548, 1, 0),
+    (2549, 2, 0), (2551, 1, 0), (2552, 1, 0), (2553, 1, 0), (2554, 1, 0), (2555, 1, 0),
+    (2556, 1, 0), (2557, 1, 0), (2558, 1, 0), (2559, 2, 0), (2561, 1, 0), (2562, 2, 0),
+    (2564, 1, 0), (2565, 1, 0), (2566, 0, 159), (2566, 1, 0), (2567, 1, 0), (2568, 1, 0),
+    (2569, 1, 0), (2570, 1, 0), (2571, 1, 0), (2572, 1, 0), (2573, 1, 0), (2574, 0, 157),
+    (2574, 1, 0), (2575, 3, 0), (2578, 4, 0), (2582, 1, 0), (2583, 0, 165), (2583, 1, 0),
+    (2584, 1, 0), (2585, 1, 0), (2586, 0, 161), (2586, 1, 0), (2587, 1, 0), (2588, 1, 0),
+    (2589, 1, 0), (2590, 1, 0), (2591, 2, 0), (2593, 1, 0), (2594, 1, 0), (2595, 1, 0),
+    (2596, 1, 0), (2597, 1, 0), (2598, 0, 146), (2598, 2, 0), (2600, 1, 0), (2601, 1, 0),
+    (2602, 1, 0), (2603, 5, 0), (2608, 1, 0), (2609, 1, 0), (2610, 1, 0), (2611, 1, 0),
+    (2612, 1, 0), (2613, 1, 0), (2614, 2, 0), (2616, 1, 0), (2617, 1, 0), (2618, 1, 0),
+    (2619, 1, 0), (2620, 1, 0), (2621, 1, 0), (2622, 2, 0), (2624, 1, 0), (2625, 1, 0),
+    (2626, 1, 0), (2627, 1, 0), (2628, 1, 0), (2629, 1, 0), (2630, 1, 0), (2631, 1, 0),
+    (2632, 1, 0), (2633, 1, 0), (2634, 1, 0), (2635, 0, 190), (2635, 1, 0), (2636, 1, 0),
+    (2637, 1, 0), (2638, 1, 0), (2639, 1, 0), (2640, 1, 0), (2641, 1, 0), (2642, 0, 186),
+    (2642, 2, 0), (2644, 1, 0), (2645, 1, 0), (2646, 1, 0), (2647, 3, 0), (2650, 1, 0),
+    (2651, 1, 0), (2652, 0, 122), (2652, 1, 0), (2653, 1, 0), (2654, 1, 0), (2655, 5, 0),
+    (2660, 1, 0), (2661, 1, 0), (2662, 1, 0), (2663, 1, 0), (2664, 1, 0), (2665, 1, 0),
+    (2666, 1, 0), (2667, 1, 0), (2668, 0, 191), (2668, 1, 0), (2669, 1, 0), (2670, 1, 0),
+    (2671, 1, 0), (2672, 1, 0), (2673, 1, 0), (2674, 1, 0), (2675, 1, 0), (2676, 1, 0),
+    (2677, 1, 0), (2678, 1, 0), (2679, 1, 0), (2680, 1, 0), (2681, 1, 0), (2682, 1, 0),
+    (2683, 1, 0), (2684, 1, 0), (2685, 1, 0), (2686, 1, 0), (2687, 1, 0), (2688, 1, 0),
+    (2689, 1, 0), (2690, 1, 0), (2691, 1, 0), (2692, 0, 249), (2692, 1, 0), (2693, 1, 0),
+    (2694, 1, 0), (2695, 1, 0), (2696, 1, 0), (2697, 1, 0), (2698, 1, 0), (2699, 1, 0),
+    (2700, 1, 0), (2701, 1, 0), (2702, 3, 0), (2705, 1, 0), (2706, 1, 0), (2707, 2, 0),
+    (2709, 1, 0), (2710, 2, 0), (2712, 1, 0), (2713, 1, 0), (2714, 1, 0), (2715, 1, 0),
+    (2716, 2, 0), (2718, 1, 0), (2719, 0, 1031), (2719, 1, 0), (2720, 1, 0), (2721, 3, 0),
+    (2724, 5, 0), (2729, 1, 0), (2730, 1, 0), (2731, 1, 0), (2732, 0, 250), (2732, 1, 0),
+    (2733, 1, 0), (2734, 1, 0), (2735, 1, 0), (2736, 0, 266), (2736, 1, 0), (2737, 1, 0),
+    (2738, 1, 0), (2739, 1, 0), (2740, 1, 0), (2741, 1, 0), (2742, 1, 0), (2743, 1, 0),
+    (2744, 2, 0), (2746, 1, 0), (2747, 2, 0), (2749, 1, 0), (2750, 1, 0), (2751, 1, 0),
+    (2752, 3, 0), (2755, 1, 0), (2756, 2, 0), (2758, 3, 0), (2761, 1, 0), (2762, 1, 0),
+    (2763, 1, 0), (2764, 1, 0), (2765, 1, 0), (2766, 1, 0), (2767, 1, 0), (2768, 1, 0),
+    (2769, 1, 0), (2770, 1, 0), (2771, 2, 0), (2773, 1, 0), (2774, 0, 215), (2774, 2, 0),
+    (2776, 1, 0), (2777, 1, 0), (2778, 1, 0), (2779, 1, 0), (2780, 1, 0), (2781, 2, 0),
+    (2783, 2, 0), (2785, 1, 0), (2786, 1, 0), (2787, 1, 0), (2788, 1, 0), (2789, 2, 0),
+    (2791, 2, 0), (2793, 1, 0), (2794, 1, 0), (2795, 1, 0), (2796, 1, 0), (2797, 1, 0),
+    (2798, 1, 0), (2799, 1, 0), (2800, 1, 0), (2801, 4, 0), (2805, 1, 0), (2806, 1, 0),
+    (2807, 1, 0), (2808, 1, 0), (2809, 1, 0), (2810, 1, 0), (2811, 0, 272), (2811, 1, 0),
+    (2812, 1, 0), (2813, 0, 1104), (2813, 1, 0), (2814, 1, 0), (2815, 1, 0), (2816, 2, 0),
+    (2818, 1, 0), (2819, 1, 0), (2820, 2, 0), (2822, 1, 0), (2823, 1, 0), (2824, 1, 0),
+    (2825, 1, 0), (2826, 1, 0), (2827, 1, 0), (2828, 5, 0), (2833, 1, 0), (2834, 1, 0),
+    (2835, 1, 0), (2836, 2, 0), (2838, 1, 0), (2839, 1, 0), (2840, 1, 0), (2841, 1, 0),
+    (2842, 1, 0), (2843, 0, 291), (2843, 1, 0), (2844, 1, 0), (2845, 1, 0), (2846, 1, 0),
+    (2847, 2, 0), (2849, 1, 0), (2850, 1, 0), (2851, 1, 0), (2852, 3, 0), (2855, 1, 0),
+    (2856, 2, 0), (2858, 1, 0), (2859, 1, 0), (2860, 1, 0), (2861, 0, 395), (2861, 2, 0),
+    (2863, 1, 0), (2864, 1, 0), (2865, 1, 0), (2866, 1, 0), (2867, 1, 0), (2868, 1, 0),
+    (2869, 4, 0), (2873, 1, 0), (2874, 2, 0), (2876, 1, 0), (2877, 1, 0), (2878, 3, 1142),
+    (2881, 1, 0), (2882, 1, 0), (2883, 3, 0), (2886, 1, 0), (2887, 1, 0), (2888, 1, 0),
+    (2889, 1, 0), (2890, 4, 0), (2894, 1, 0), (2895, 1, 0), (2896, 1, 0), (2897, 1, 0),
+    (2898, 1, 0), (2899, 3, 0), (2902, 1, 0), (2903, 1, 0), (2904, 1, 0), (2905, 1, 0),
+    (2906, 0, 1161), (2906, 3, 0), (2909, 1, 0), (2910, 1, 0), (2911, 1, 0), (2912, 1, 0),
+    (2913, 2, 0), (2915, 1, 0), (2916, 3, 0), (2919, 2, 0), (2921, 1, 0), (2922, 1, 0),
+    (2923, 1, 0), (2924, 1, 0), (2925, 0, 728), (2925, 1, 0), (2926, 1, 0), (2927, 1, 0),
+    (2928, 1, 0), (2929, 1, 0), (2930, 1, 0), (2931, 1, 0), (2932, 1, 0), (2933, 1, 0),
+    (2934, 1, 0), (2935, 1, 0), (2936, 1, 0), (2937, 1, 0), (2938, 1, 0), (2939, 1, 0),
+    (2940, 1, 0), (2941, 1, 0), (2942, 1, 0), (2943, 1, 0), (2944, 2, 0), (2946, 1, 0),
+    (2947, 1, 0), (2948, 1, 0), (2949, 1, 0), (2950, 3, 0), (2953, 1, 0), (2954, 1, 0),
+    (2955, 1, 0), (2956, 1, 0), (2957, 0, 1205), (2957, 1, 0), (2958, 4, 0), (2962, 1, 0),
+    (2963, 1, 0), (2964, 1, 0), (2965, 1, 0), (2966, 1, 0), (2967, 1, 0), (2968, 1, 0),
+    (2969, 2, 0), (2971, 1, 0), (2972, 1, 0), (2973, 4, 0), (2977, 1, 0), (2978, 5, 0),
+    (2983, 1, 0), (2984, 2, 0), (2986, 1, 0), (2987, 1, 0), (2988, 0, 1232), (2988, 1, 0),
+    (2989, 1, 0), (2990, 1, 0), (2991, 1, 0), (2992, 0, 369), (2992, 1, 0), (2993, 1, 0),
+    (2994, 1, 0), (2995, 0, 372), (2995, 1, 0), (2996, 1, 0), (2997, 1, 0), (2998, 2, 0),
+    (3000, 1, 0), (3001, 3, 0), (3004, 3, 0), (3007, 1, 0), (3008, 1, 0), (3009, 1, 0),
+    (3010, 1, 0), (3011, 1, 0), (3012, 1, 0), (3013, 1, 0), (3014, 1, 0), (3015, 1, 0),
+    (3016, 1, 0), (3017, 1, 0), (3018, 1, 0), (3019, 1, 0), (3020, 1, 0), (3021, 1, 0),
+    (3022, 1, 0), (3023, 1, 0), (3024, 1, 0), (3025, 2, 0), (3027, 1, 0), (3028, 1, 0),
+    (3029, 1, 0), (3030, 1, 0), (3031, 1, 0), (3032, 2, 0), (3034, 1, 0), (3035, 1, 0),
+    (3036, 2, 0), (3038, 1, 0), (3039, 2, 0), (3041, 1, 0), (3042, 1, 0), (3043, 1, 0),
+    (3044, 1, 0), (3045, 1, 0), (3046, 1, 0), (3047, 1, 0), (3048, 1, 0), (3049, 1, 386),
+    (3050, 1, 0), (3051, 1, 0), (3052, 1, 0), (3053, 1, 0), (3054, 2, 0), (3056, 1, 0),
+    (3057, 1, 0), (3058, 1, 0), (3059, 1, 0), (3060, 1, 0), (3061, 1, 0), (3062, 1, 0),
+    (3063, 1, 0), (3064, 2, 0), (3066, 1, 0), (3067, 3, 0), (3070, 1, 0), (3071, 1, 0),
+    (3072, 1, 0), (3073, 1, 0), (3074, 1, 0), (3075, 1, 0), (3076, 1, 0), (3077, 2, 0),
+    (3079, 1, 0), (3080, 1, 0), (3081, 1, 0), (3082, 1, 0), (3083, 0, 1321), (3083, 1, 0),
+    (3084, 1, 0), (3085, 0, 453), (3085, 1, 0), (3086, 2, 0), (3088, 1, 0), (3089, 2, 0),
+    (3091, 1, 0), (3092, 3, 0), (3095, 1, 0), (3096, 1, 0), (3097, 1, 0), (3098, 1, 0),
+    (3099, 1, 0), (3100, 1, 0), (3101, 1, 0), (3102, 1, 0), (3103, 1, 0), (3104, 1, 0),
+    (3105, 1, 0), (3106, 2, 0), (3108, 1, 0), (3109, 2, 0), (3111, 1, 0), (3112, 1, 1344),
+    (3113, 1, 0), (3114, 8, 0), (3122, 1, 0), (3123, 2, 0), (3125, 1, 0), (3126, 2, 0),
+    (3128, 2, 0), (3130, 1, 0), (3131, 2, 0), (3133, 1, 0), (3134, 2, 0), (3136, 2, 0),
+    (3138, 1, 0), (3139, 3, 0), (3142, 1, 0), (3143, 1, 0), (3144, 1, 0), (3145, 1, 0),
+    (3146, 1, 0), (3147, 1, 0), (3148, 2, 0), (3150, 9, 0), (3159, 1, 0), (3160, 1, 0),
+    (3161, 1, 0), (3162, 13, 0), (3175, 1, 0), (3176, 1, 0), (3177, 1, 0), (3178, 1, 0),
+    (3179, 1, 0), (3180, 1, 0), (3181, 1, 0), (3182, 1, 0), (3183, 1, 0), (3184, 1, 0),
+    (3185, 1, 0), (3186, 1, 0), (3187, 1, 0), (3188, 2, 0), (3190, 2, 0), (3192, 2, 0),
+    (3194, 1, 0), (3195, 1, 0), (3196, 1, 0), (3197, 1, 0), (3198, 1, 0), (3199, 4, 0),
+    (3203, 1, 0), (3204, 1, 0), (3205, 1, 0), (3206, 7, 0), (3213, 1, 0), (3214, 2, 0),
+    (3216, 1, 0), (3217, 1, 0), (3218, 1, 0), (3219, 1, 0), (3220, 1, 0), (3221, 1, 0),
+    (3222, 1, 0), (3223, 1, 0), (3224, 2, 0), (3226, 1, 0), (3227, 1, 0), (3228, 1, 0),
+    (3229, 1, 0), (3230, 1, 0), (3231, 1, 0), (3232, 1, 0), (3233, 1, 0), (3234, 1, 0),
+    (3235, 1, 0), (3236, 2, 0), (3238, 1, 0), (3239, 1, 0), (3240, 1, 84), (3241, 1, 0),
+    (3242, 1, 0), (3243, 1, 0), (3244, 1, 0), (3245, 1, 0), (3246, 1, 0), (3247, 1, 0),
+    (3248, 1, 0), (3249, 2, 0), (3251, 1, 0), (3252, 1, 0), (3253, 1, 0), (3254, 1, 0),
+    (3255, 1, 0), (3256, 1, 0), (3257, 1, 0), (3258, 1, 0), (3259, 1, 0), (3260, 1, 0),
+    (3261, 1, 0), (3262, 1, 0), (3263, 1, 0), (3264, 7, 0), (3271, 1, 0), (3272, 1, 0),
+    (3273, 3, 0), (3276, 1, 0), (3277, 2, 0), (3279, 1, 0), (3280, 1, 0), (3281, 1, 0),
+    (3282, 1, 0), (3283, 1, 0), (3284, 1, 0), (3285, 1, 0), (3286, 2, 0), (3288, 1, 0),
+    (3289, 1, 0), (3290, 2, 0), (3292, 1, 0), (3293, 1, 0), (3294, 1, 0), (3295, 0, 1480),
+    (3295, 0, 526), (3295, 1, 0), (3296, 1, 0), (3297, 1, 0), (3298, 1, 0), (3299, 1, 0),
+    (3300, 1, 0), (3301, 1, 0), (3302, 1, 0), (3303, 1, 0), (3304, 0, 1485), (3304, 1, 0),
+    (3305, 1, 0), (3306, 1, 0), (3307, 1, 0), (3308, 1, 0), (3309, 2, 0), (3311, 1, 0),
+    (3312, 1, 0), (3313, 1, 0), (3314, 1, 0), (3315, 1, 0), (3316, 1, 0), (3317, 1, 0),
+    (3318, 1, 0), (3319, 1, 0), (3320, 2, 0), (3322, 1, 0), (3323, 1, 0), (3324, 1, 1493),
+    (3325, 1, 0), (3326, 1, 0), (3327, 1, 0), (3328, 1, 0), (3329, 1, 0), (3330, 1, 0),
+    (3331, 1, 0), (3332, 1, 0), (3333, 1, 0), (3334, 1, 0), (3335, 1, 0), (3336, 1, 0),
+    (3337, 1, 0), (3338, 1, 0), (3339, 1, 0), (3340, 1, 0), (3341, 1, 0), (3342, 1, 0),
+    (3343, 1, 0), (3344, 1, 0), (3345, 0, 2), (3345, 1, 0), (3346, 1, 0), (3347, 1, 0),
+    (3348, 0, 6), (3348, 0, 7), (3348, 1, 0), (3349, 1, 0), (3350, 1, 0), (3351, 0, 11),
+    (3351, 1, 0), (3352, 1, 0), (3353, 1, 0), (3354, 1, 0), (3355, 1, 0), (3356, 1, 0),
+    (3357, 1, 0), (3358, 1, 19), (3359, 1, 0), (3360, 1, 0), (3361, 1, 0), (3362, 0, 23),
+    (3362, 1, 0), (3363, 1, 0), (3364, 1, 0), (3365, 0, 27), (3365, 1, 0), (3366, 1, 0),
+    (3367, 1, 0), (3368, 1, 0), (3369, 1, 0), (3370, 1, 32), (3371, 1, 0), (3372, 0, 34),
+    (3372, 1, 0), (3373, 1, 0), (3374, 1, 0), (3375, 1, 0), (3376, 1, 0), (3377, 1, 0),
+    (3378, 1, 0), (3379, 1, 0), (3380, 1, 0), (3381, 0, 36), (3381, 0, 44), (3381, 1, 0),
+    (3382, 1, 0), (3383, 1, 0), (3384, 1, 0), (3385, 1, 0), (3386, 1, 0), (3387, 1, 0),
+    (3388, 1, 0), (3389, 1, 0), (3390, 1, 0), (3391, 1, 0), (3392, 1, 0), (3393, 0, 62),
+    (3393, 1, 0), (3394, 1, 0), (3395, 1, 0), (3396, 1, 0), (3397, 1, 0), (3398, 1, 0),
+    (3399, 1, 0), (3400, 1, 0), (3401, 1, 0), (3402, 0, 72), (3402, 0, 73), (3402, 1, 0),
+    (3403, 0, 75), (3403, 1, 0), (3404, 1, 0), (3405, 1, 0), (3406, 1, 0), (3407, 0, 84),
+    (3407, 1, 0), (3408, 1, 0), (3409, 1, 0), (3410, 6, 0), (3416, 1, 0), (3417, 1, 0),
+    (3418, 0, 113), (3418, 0, 114), (3418, 1, 0), (3419, 1, 0), (3420, 1, 0), (3421, 0, 118),
+    (3421, 1, 0), (3422, 0, 120), (3422, 1, 0), (3423, 1, 0), (3424, 1, 0), (3425, 1, 0),
+    (3426, 1, 0), (3427, 1, 0), (3428, 1, 0), (3429, 1, 0), (3430, 1, 0), (3431, 1, 0),
+    (3432, 1, 0), (3433, 0, 134), (3433, 1, 135), (3434, 1, 0), (3435, 1, 0), (3436, 0, 138),
+    (3436, 0, 139), (3436, 1, 0), (3437, 1, 0), (3438, 1, 0), (3439, 1, 0), (3440, 1, 0),
+    (3441, 1, 0), (3442, 1, 0), (3443, 1, 0), (3444, 1, 0), (3445, 1, 0), (3446, 0, 152),
+    (3446, 1, 0), (3447, 0, 154), (3447, 1, 0), (3448, 1, 0), (3449, 1, 0), (3450, 1, 0),
+    (3451, 1, 0), (3452, 0, 168), (3452, 1, 0), (3453, 0, 170), (3453, 1, 0), (3454, 1, 0),
+    (3455, 1, 0), (3456, 1, 0), (3457, 1, 0), (3458, 2, 0), (3460, 1, 0), (3461, 1, 0),
+    (3462, 1, 0), (3463, 1, 0), (3464, 1, 0), (3465, 0, 181), (3465, 1, 0), (3466, 0, 183),
+    (3466, 1, 0), (3467, 1, 0), (3468, 1, 0), (3469, 1, 0), (3470, 0, 187), (3470, 2, 0),
+    (3472, 1, 0), (3473, 1, 0), (3474, 1, 0), (3475, 1, 0), (3476, 1, 0), (3477, 1, 0),
+    (3478, 1, 0), (3479, 1, 198), (3480, 1, 0), (3481, 0, 200), (3481, 0, 201), (3481, 1, 0),
+    (3482, 1, 0), (3483, 1, 0), (3484, 1, 0), (3485, 1, 0), (3486, 1, 0), (3487, 1, 0),
+    (3488, 1, 0), (3489, 0, 210), (3489, 0, 211), (3489, 1, 0), (3490, 1, 0), (3491, 1, 0),
+    (3492, 1, 0), (3493, 1, 0), (3494, 1, 0), (3495, 1, 0), (3496, 1, 0), (3497, 1, 0),
+    (3498, 1, 0), (3499, 0, 223), (3499, 10, 0), (3509, 6, 0), (3515, 0, 254), (3515, 1, 0),
+    (3516, 1, 0), (3517, 4, 0), (3521, 1, 0), (3522, 1, 0), (3523, 1, 0), (3524, 0, 264),
+    (3524, 1, 0), (3525, 0, 267), (3525, 0, 268), (3525, 1, 0), (3526, 1, 0), (3527, 0, 271),
+    (3527, 1, 0), (3528, 1, 0), (3529, 1, 0), (3530, 1, 0), (3531, 1, 0), (3532, 1, 0),
+    (3533, 1, 0), (3534, 0, 279), (3534, 1, 0), (3535, 1, 0), (3536, 1, 0), (3537, 0, 282),
+    (3537, 1, 0), (3538, 1, 0), (3539, 1, 0), (3540, 0, 286), (3540, 2, 0), (3542, 1, 0),
+    (3543, 3, 0), (3546, 1, 0), (3547, 1, 0), (3548, 1, 0), (3549, 1, 0), (3550, 1, 0),
+    (3551, 2, 0), (3553, 2, 0), (3555, 1, 0), (3556, 1, 0), (3557, 1, 0), (3558, 1, 0),
+    (3559, 1, 0), (3560, 1, 0), (3561, 1, 0), (3562, 0, 344), (3562, 1, 0), (3563, 0, 346),
+    (3563, 1, 0), (3564, 1, 0), (3565, 1, 0), (3566, 1, 0), (3567, 1, 0), (3568, 1, 0),
+    (3569, 1, 0), (3570, 1, 0), (3571, 1, 0), (3572, 1, 0), (3573, 1, 359), (3574, 2, 0),
+    (3576, 1, 0), (3577, 0, 365), (3577, 0, 366), (3577, 0, 367), (3577, 1, 0), (3578, 1, 0),
+    (3579, 1, 0), (3580, 1, 0), (3581, 1, 0), (3582, 1, 0), (3583, 1, 0), (3584, 1, 0),
+    (3585, 0, 380), (3585, 1, 381), (3586, 0, 382), (3586, 1, 0), (3587, 1, 0), (3588, 1, 0),
+    (3589, 0, 386), (3589, 1, 0), (3590, 1, 0), (3591, 2, 0), (3593, 1, 0), (3594, 1, 0),
+    (3595, 0, 393), (3595, 1, 0), (3596, 0, 394), (3596, 0, 398), (3596, 1, 0), (3597, 1, 0),
+    (3598, 1, 0), (3599, 1, 0), (3600, 1, 0), (3601, 0, 425), (3601, 1, 0), (3602, 1, 0),
+    (3603, 1, 0), (3604, 1, 0), (3605, 1, 0), (3606, 1, 0), (3607, 1, 0), (3608, 1, 0),
+    (3609, 0, 435), (3609, 0, 436), (3609, 1, 0), (3610, 1, 0), (3611, 1, 0), (3612, 1, 0),
+    (3613, 1, 0), (3614, 1, 0), (3615, 1, 0), (3616, 1, 0), (3617, 0, 451), (3617, 1, 0),
+    (3618, 1, 0), (3619, 1, 0), (3620, 0, 457), (3620, 0, 458), (3620, 1, 0), (3621, 1, 0),
+    (3622, 1, 0), (3623, 1, 0), (3624, 1, 0), (3625, 1, 0), (3626, 0, 465), (3626, 0, 466),
+    (3626, 1, 0), (3627, 1, 0), (3628, 0, 469), (3628, 0, 470), (3628, 1, 0), (3629, 1, 0),
+    (3630, 1, 0), (3631, 1, 0), (3632, 1, 0), (3633, 1, 0), (3634, 1, 0), (3635, 1, 0),
+    (3636, 1, 0), (3637, 1, 0), (3638, 2, 0), (3640, 1, 0), (3641, 1, 0), (3642, 1, 0),
+    (3643, 0, 489), (3643, 1, 0), (3644, 0, 491), (3644, 1, 0), (3645, 1, 0), (3646, 1, 0),
+    (3647, 1, 0), (3648, 1, 0), (3649, 1, 0), (3650, 1, 0), (3651, 1, 0), (3652, 1, 0),
+    (3653, 1, 0), (3654, 1, 0), (3655, 1, 0), (3656, 1, 0), (3657, 2, 0), (3659, 1, 0),
+    (3660, 1, 0), (3661, 1, 0), (3662, 1, 515), (3663, 1, 0), (3664, 1, 0), (3665, 0, 518),
+    (3665, 1, 0), (3666, 0, 521), (3666, 1, 0), (3667, 2, 0), (3669, 1, 0), (3670, 0, 528),
+    (3670, 1, 0), (3671, 1, 0), (3672, 1, 0), (3673, 1, 0), (3674, 1, 0), (3675, 0, 534),
+    (3675, 1, 0), (3676, 1, 0), (3677, 0, 537), (3677, 1, 0), (3678, 1, 0), (3679, 1, 0),
+    (3680, 1, 0), (3681, 1, 0), (3682, 1, 0), (3683, 1, 0), (3684, 0, 546), (3684, 0, 547),
+    (3684, 1, 0), (3685, 1, 0), (3686, 1, 0), (3687, 1, 0), (3688, 1, 0), (3689, 1, 0),
+    (3690, 0, 554), (3690, 1, 0), (3691, 1, 0), (3692, 1, 0), (3693, 0, 557), (3693, 1, 0),
+    (3694, 1, 0), (3695, 1, 0), (3696, 1, 0), (3697, 0, 563), (3697, 0, 564), (3697, 1, 0),
+    (3698, 1, 0), (3699, 0, 566), (3699, 1, 0), (3700, 0, 568), (3700, 1, 0), (3701, 1, 0),
+    (3702, 1, 0), (3703, 1, 0), (3704, 1, 0), (3705, 1, 0), (3706, 0, 2), (3706, 0, 574),
+    (3706, 1, 0), (3707, 1, 0), (3708, 1, 0), (3709, 1, 0), (3710, 0, 579), (3710, 1, 0),
+    (3711, 1, 0), (3712, 1, 0), (3713, 1, 0), (3714, 2, 0), (3716, 1, 0), (3717, 1, 0),
+    (3718, 1, 0), (3719, 0, 597), (3719, 1, 0), (3720, 0, 599), (3720, 1, 0), (3721, 1, 0),
+    (3722, 1, 0), (3723, 1, 0), (3724, 1, 0), (3725, 0, 604), (3725, 1, 0), (3726, 1, 0),
+    (3727, 1, 606), (3728, 1, 0), (3729, 1, 0), (3730, 1, 0), (3731, 4, 0), (3735, 1, 0),
+    (3736, 1, 0), (3737, 2, 0), (3739, 1, 0), (3740, 0, 617), (3740, 1, 0), (3741, 1, 0),
+    (3742, 1, 0), (3743, 1, 0), (3744, 1, 0), (3745, 1, 0), (3746, 1, 0), (3747, 1, 0),
+    (3748, 0, 623), (3748, 3, 0), (3751, 3, 0), (3754, 2, 0), (3756, 1, 0), (3757, 1, 0),
+    (3758, 1, 0), (3759, 1, 0), (3760, 1, 0), (3761, 1, 0), (3762, 1, 0), (3763, 2, 0),
+    (3765, 1, 0), (3766, 1, 0), (3767, 0, 644), (3767, 1, 0), (3768, 1, 0), (3769, 1, 0),
+    (3770, 0, 506), (3770, 1, 0), (3771, 1, 0), (3772, 4, 0), (3776, 5, 0), (3781, 4, 0),
+    (3785, 7, 0), (3792, 1, 0), (3793, 4, 0), (3797, 5, 0), (3802, 1, 0), (3803, 1, 0),
+    (3804, 1, 0), (3805, 4, 0), (3809, 7, 0), (3816, 1, 0), (3817, 1, 0), (3818, 1, 0),
+    (3819, 1, 0), (3820, 1, 0), (3821, 2, 0), (3823, 3, 0), (3826, 2, 0), (3828, 3, 0),
+    (3831, 1, 0), (3832, 0, 701), (3832, 1, 0), (3833, 1, 0), (3834, 2, 0), (3836, 1, 0),
+    (3837, 1, 0), (3838, 1, 0), (3839, 1, 0), (3840, 1, 0), (3841, 1, 0), (3842, 1, 0),
+    (3843, 1, 0), (3844, 1, 0), (3845, 1, 0), (3846, 1, 0), (3847, 1, 0), (3848, 2, 717),
+    (3850, 0, 718), (3850, 1, 0), (3851, 1, 0), (3852, 0, 721), (3852, 0, 722), (3852, 1, 0),
+    (3853, 3, 0), (3856, 1, 0), (3857, 1, 0), (3858, 1, 0), (3859, 1, 0), (3860, 1, 0),
+    (3861, 1, 0), (3862, 1, 0), (3863, 3, 0), (3866, 2, 0), (3868, 1, 0), (3869, 1, 0),
+    (3870, 1, 0), (3871, 2, 32), (3873, 1, 0), (3874, 1, 0), (3875, 1, 0), (3876, 2, 0),
+    (3878, 2, 0), (3880, 1, 0), (3881, 1, 0), (3882, 1, 0), (3883, 1, 0), (3884, 1, 0),
+    (3885, 0, 757), (3885, 1, 0), (3886, 2, 0), (3888, 1, 0), (3889, 1, 0), (3890, 1, 0),
+    (3891, 1, 0), (3892, 1, 0), (3893, 1, 0), (3894, 1, 0), (3895, 1, 0), (3896, 1, 0),
+    (3897, 1, 0), (3898, 1, 0), (3899, 1, 0), (3900, 1, 0), (3901, 1, 0), (3902, 1, 0),
+    (3903, 1, 0), (3904, 1, 0), (3905, 2, 0), (3907, 1, 0), (3908, 1, 0), (3909, 1, 0),
+    (3910, 0, 777), (3910, 1, 0), (3911, 1, 0), (3912, 1, 0), (3913, 0, 780), (3913, 1, 0),
+    (3914, 1, 0), (3915, 1, 0), (3916, 0, 784), (3916, 2, 0), (3918, 3, 0), (3921, 0, 84),
+    (3921, 1, 0), (3922, 1, 0), (3923, 0, 788), (3923, 1, 0), (3924, 1, 0), (3925, 1, 0),
+    (3926, 1, 0), (3927, 1, 0), (3928, 1, 0), (3929, 1, 0), (3930, 0, 77), (3930, 1, 0),
+    (3931, 1, 0), (3932, 1, 0), (3933, 1, 0), (3934, 1, 0), (3935, 3, 0), (3938, 1, 0),
+    (3939, 1, 0), (3940, 1, 0), (3941, 1, 0), (3942, 1, 0), (3943, 1, 0), (3944, 1, 0),
+    (3945, 1, 0), (3946, 2, 0), (3948, 1, 0), (3949, 1, 0), (3950, 1, 0), (3951, 1, 0),
+    (3952, 1, 0), (3953, 1, 0), (3954, 1, 0), (3955, 1, 0), (3956, 1, 0), (3957, 1, 0),
+    (3958, 2, 0), (3960, 1, 0), (3961, 0, 816), (3961, 1, 0), (3962, 1, 0), (3963, 0, 819),
+    (3963, 1, 0), (3964, 0, 822), (3964, 1, 0), (3965, 1, 0), (3966, 0, 826), (3966, 0, 827),
+    (3966, 1, 0), (3967, 1, 0), (3968, 1, 0), (3969, 2, 0), (3971, 0, 834), (3971, 1, 0),
+    (3972, 1, 0), (3973, 1, 0), (3974, 2, 0), (3976, 1, 0), (3977, 3, 0), (3980, 1, 0),
+    (3981, 1, 0), (3982, 1, 0), (3983, 1, 0), (3984, 1, 0), (3985, 1, 0), (3986, 1, 0),
+    (3987, 1, 0), (3988, 1, 0), (3989, 1, 0), (3990, 1, 0), (3991, 1, 0), (3992, 1, 0),
+    (3993, 0, 850), (3993, 0, 851), (3993, 1, 852), (3994, 1, 0), (3995, 1, 0), (3996, 1, 0),
+    (3997, 1, 0), (3998, 1, 0), (3999, 1, 0), (4000, 0, 855), (4000, 1, 0), (4001, 1, 0),
+    (4002, 1, 0), (4003, 1, 0), (4004, 0, 860), (4004, 1, 0), (4005, 1, 0), (4006, 1, 0),
+    (4007, 1, 0), (4008, 1, 0), (4009, 1, 0), (4010, 1, 0), (4011, 1, 0), (4012, 2, 0),
+    (4014, 1, 0), (4015, 6, 0), (4021, 1, 0), (4022, 1, 0), (4023, 1, 0), (4024, 0, 889),
+    (4024, 1, 0), (4025, 1, 0), (4026, 0, 892), (4026, 1, 0), (4027, 1, 0), (4028, 0, 895),
+    (4028, 1, 0), (4029, 0, 158), (4029, 0, 157), (4029, 1, 0), (4030, 1, 0), (4031, 0, 162),
+    (4031, 1, 0), (4032, 1, 0), (4033, 2, 0), (4035, 0, 903), (4035, 0, 155), (4035, 1, 0),
+    (4036, 1, 0), (4037, 0, 906), (4037, 0, 907), (4037, 0, 908), (4037, 0, 909), (4037, 2, 0),
+    (4039, 0, 911), (4039, 2, 0), (4041, 1, 0), (4042, 1, 0), (4043, 1, 0), (4044, 1, 0),
+    (4045, 3, 0), (4048, 1, 0), (4049, 1, 0), (4050, 1, 0), (4051, 1, 0), (4052, 1, 0),
+    (4053, 2, 0), (4055, 1, 0), (4056, 1, 0), (4057, 1, 0), (4058, 1, 0), (4059, 1, 0),
+    (4060, 1, 0), (4061, 1, 0), (4062, 1, 0), (4063, 1, 0), (4064, 1, 0), (4065, 1, 0),
+    (4066, 3, 0), (4069, 1, 0), (4070, 1, 0), (4071, 1, 0), (4072, 1, 0), (4073, 1, 0),
+    (4074, 0, 932), (4074, 1, 0), (4075, 1, 0), (4076, 1, 0), (4077, 1, 0), (4078, 2, 0),
+    (4080, 1, 0), (4081, 1, 0), (4082, 1, 0), (4083, 1, 0), (4084, 1, 0), (4085, 1, 0),
+    (4086, 1, 0), (4087, 1, 0), (4088, 1, 0), (4089, 0, 946), (4089, 1, 0), (4090, 1, 0),
+    (4091, 0, 89), (4091, 0, 949), (4091, 1, 0), (4092, 1, 0), (4093, 1, 0), (4094, 1, 0),
+    (4095, 1, 0), (4096, 1, 0), (4097, 1, 0), (4098, 3, 0), (4101, 1, 0), (4102, 1, 0),
+    (4103, 1, 0), (4104, 1, 0), (4105, 1, 0), (4106, 1, 0), (4107, 0, 188), (4107, 1, 0),
+    (4108, 2, 0), (4110, 1, 0), (4111, 1, 0), (4112, 1, 0), (4113, 1, 0), (4114, 1, 0),
+    (4115, 1, 0), (4116, 1, 0), (4117, 1, 0), (4118, 1, 0), (4119, 5, 0), (4124, 1, 0),
+    (4125, 1, 0), (4126, 1, 978), (4127, 1, 0), (4128, 0, 980), (4128, 0, 981), (4128, 1, 0),
+    (4129, 1, 0), (4130, 1, 0), (4131, 1, 0), (4132, 1, 0), (4133, 1, 0), (4134, 1, 0),
+    (4135, 0, 990), (4135, 0, 991), (4135, 1, 0), (4136, 1, 0), (4137, 1, 0), (4138, 1, 0),
+    (4139, 1, 0), (4140, 1, 0), (4141, 1, 0), (4142, 1, 0), (4143, 1, 0), (4144, 0, 999),
+    (4144, 1, 0), (4145, 1, 0), (4146, 1, 0), (4147, 1, 0), (4148, 1, 0), (4149, 3, 0),
+    (4152, 0, 1005), (4152, 1, 0), (4153, 8, 0), (4161, 0, 1013), (4161, 1, 0), (4162, 2, 0),
+    (4164, 1, 0), (4165, 1, 0), (4166, 1, 0), (4167, 2, 0), (4169, 1, 0), (4170, 1, 0),
+    (4171, 1, 0), (4172, 1, 0), (4173, 0, 1026), (4173, 1, 0), (4174, 1, 0), (4175, 1, 0),
+    (4176, 1, 0), (4177, 1, 0), (4178, 5, 0), (4183, 0, 248), (4183, 0, 1031), (4183, 1, 0),
+    (4184, 1, 0), (4185, 0, 252), (4185, 1, 0), (4186, 1, 0), (4187, 2, 0), (4189, 5, 0),
+    (4194, 1, 0), (4195, 1, 0), (4196, 0, 1042), (4196, 0, 1043), (4196, 2, 0), (4198, 1, 0),
+    (4199, 1, 0), (4200, 1, 0), (4201, 1, 0), (4202, 1, 0), (4203, 1, 0), (4204, 1, 0),
+    (4205, 1, 0), (4206, 0, 1051), (4206, 2, 0), (4208, 0, 1053), (4208, 2, 0), (4210, 1, 0),
+    (4211, 1, 0), (4212, 1, 0), (4213, 1, 0), (4214, 3, 0), (4217, 1, 0), (4218, 1, 0),
+    (4219, 1, 0), (4220, 1, 0), (4221, 1, 0), (4222, 1, 0), (4223, 1, 0), (4224, 0, 1064),
+    (4224, 1, 0), (4225, 1, 0), (4226, 2, 0), (4228, 1, 0), (4229, 1, 0), (4230, 1, 0),
+    (4231, 0, 1068), (4231, 1, 0), (4232, 1, 0), (4233, 1, 0), (4234, 0, 264), (4234, 3, 0),
+    (4237, 1, 0), (4238, 1, 0), (4239, 1, 0), (4240, 1, 0), (4241, 1, 0), (4242, 1, 0),
+    (4243, 1, 0), (4244, 1, 0), (4245, 1, 0), (4246, 1, 0), (4247, 1, 0), (4248, 3, 0),
+    (4251, 1, 0), (4252, 1, 0), (4253, 1, 0), (4254, 1, 0), (4255, 1, 0), (4256, 1, 1087),
+    (4257, 1, 0), (4258, 2, 0), (4260, 0, 410), (4260, 1, 0), (4261, 1, 0), (4262, 1, 0),
+    (4263, 0, 1092), (4263, 1, 0), (4264, 1, 0), (4265, 0, 1094), (4265, 0, 1095), (4265, 1, 0),
+    (4266, 0, 523), (4266, 1, 0), (4267, 1, 0), (4268, 1, 0), (4269, 1, 0), (4270, 1, 0),
+    (4271, 1, 0), (4272, 1, 0), (4273, 1, 0), (4274, 1, 0), (4275, 1, 0), (4276, 1, 0),
+    (4277, 1, 0), (4278, 1, 0), (4279, 0, 1106), (4279, 0, 1107), (4279, 1, 0), (4280, 1, 0),
+    (4281, 0, 1110), (4281, 0, 1111), (4281, 1, 0), (4282, 1, 0), (4283, 1, 0), (4284, 1, 0),
+    (4285, 1, 0), (4286, 1, 0), (4287, 1, 0), (4288, 0, 336), (4288, 1, 0), (4289, 1, 0),
+    (4290, 1, 0), (4291, 1, 0), (4292, 1, 0), (4293, 1, 284), (4294, 1, 0), (4295, 1, 0),
+    (4296, 1, 0), (4297, 1, 0), (4298, 1, 0), (4299, 1, 0), (4300, 0, 1126), (4300, 1, 0),
+    (4301, 1, 0), (4302, 2, 0), (4304, 1, 0), (4305, 1, 0), (4306, 1, 0), (4307, 1, 0),
+    (4308, 1, 0), (4309, 0, 1132), (4309, 0, 296), (4309, 0, 295), (4309, 3, 0), (4312, 1, 0),
+    (4313, 1, 0), (4314, 0, 294), (4314, 1, 0), (4315, 1, 0), (4316, 1, 0), (4317, 1, 0),
+    (4318, 0, 1135), (4318, 1, 0), (4319, 0, 395), (4319, 1, 0), (4320, 1, 0), (4321, 0, 1138),
+    (4321, 1, 0), (4322, 1, 0), (4323, 0, 307), (4323, 1, 0), (4324, 3, 0), (4327, 2, 0),
+    (4329, 1, 0), (4330, 0, 306), (4330, 1, 0), (4331, 1, 0), (4332, 1, 0), (4333, 0, 1142),
+    (4333, 1, 0), (4334, 1, 0), (4335, 4, 0), (4339, 1, 0), (4340, 0, 314), (4340, 1, 0),
+    (4341, 2, 0), (4343, 1, 0), (4344, 1, 0), (4345, 1, 0), (4346, 1, 0), (4347, 0, 327),
+    (4347, 1, 0), (4348, 1, 0), (4349, 1, 0), (4350, 1, 0), (4351, 2, 0), (4353, 1, 0),
+    (4354, 1, 0), (4355, 1, 0), (4356, 4, 0), (4360, 1, 0), (4361, 4, 0), (4365, 1, 0),
+    (4366, 1, 0), (4367, 1, 0), (4368, 1, 0), (4369, 0, 1162), (4369, 1, 0), (4370, 1, 0),
+    (4371, 1, 0), (4372, 1, 0), (4373, 1, 0), (4374, 1, 0), (4375, 1, 0), (4376, 1, 0),
+    (4377, 1, 0), (4378, 1, 0), (4379, 1, 0), (4380, 2, 0), (4382, 1, 0), (4383, 1, 0),
+    (4384, 1, 0), (4385, 1, 0), (4386, 2, 0), (4388, 1, 0), (4389, 1, 0), (4390, 1, 0),
+    (4391, 2, 0), (4393, 0, 1184), (4393, 1, 0), (4394, 1, 0), (4395, 1, 0), (4396, 1, 0),
+    (4397, 1, 0), (4398, 1, 0), (4399, 1, 0), (4400, 0, 1190), (4400, 1, 0), (4401, 1, 0),
+    (4402, 0, 1193), (4402, 1, 0), (4403, 0, 349), (4403, 1, 0), (4404, 1, 0), (4405, 1, 0),
+    (4406, 1, 0), (4407, 1, 0), (4408, 0, 1197), (4408, 1, 0), (4409, 1, 0), (4410, 1, 0),
+    (4411, 1, 0), (4412, 1, 0), (4413, 1, 0), (4414, 1, 0), (4415, 1, 0), (4416, 1, 0),
+    (4417, 1, 0), (4418, 0, 1206), (4418, 1, 0), (4419, 1, 1208), (4420, 1, 1209), (4421, 1, 0),
+    (4422, 1, 0), (4423, 1, 0), (4424, 0, 1213), (4424, 1, 0), (4425, 1, 0), (4426, 1, 0),
+    (4427, 1, 0), (4428, 1, 0), (4429, 1, 1218), (4430, 1, 0), (4431, 0, 97), (4431, 2, 1220),
+    (4433, 2, 0), (4435, 1, 0), (4436, 0, 1223), (4436, 1, 0), (4437, 1, 0), (4438, 1, 0),
+    (4439, 1, 0), (4440, 1, 0), (4441, 0, 1228), (4441, 0, 1229), (4441, 1, 0), (4442, 1, 0),
+    (4443, 1, 0), (4444, 1, 0), (4445, 0, 1233), (4445, 2, 0), (4447, 9, 0), (4456, 1, 0),
+    (4457, 1, 0), (4458, 1, 0), (4459, 0, 1245), (4459, 1, 0), (4460, 1, 0), (4461, 0, 373),
+    (4461, 6, 0), (4467, 1, 0), (4468, 1, 0), (4469, 1, 0), (4470, 1, 0), (4471, 1, 0),
+    (4472, 3, 0), (4475, 2, 0), (4477, 1, 0), (4478, 1, 0), (4479, 1, 0), (4480, 0, 1256),
+    (4480, 1, 0), (4481, 0, 1258), (4481, 1, 0), (4482, 1, 0), (4483, 1, 0), (4484, 1, 0),
+    (4485, 2, 0), (4487, 1, 0), (4488, 1, 381), (4489, 1, 0), (4490, 1, 0), (4491, 1, 0),
+    (4492, 1, 0), (4493, 1, 0), (4494, 1, 0), (4495, 1, 0), (4496, 1, 0), (4497, 1, 0),
+    (4498, 4, 0), (4502, 1, 0), (4503, 11, 0), (4514, 1, 0), (4515, 1, 0), (4516, 1, 0),
+    (4517, 1, 0), (4518, 1, 0), (4519, 2, 0), (4521, 1, 0), (4522, 1, 0), (4523, 1, 0),
+    (4524, 1, 0), (4525, 0, 1290), (4525, 1, 0), (4526, 1, 0), (4527, 1, 0), (4528, 1, 0),
+    (4529, 4, 0), (4533, 1, 0), (4534, 0, 386), (4534, 1, 0), (4535, 1, 0), (4536, 0, 1296),
+    (4536, 2, 0), (4538, 0, 1298), (4538, 1, 0), (4539, 1, 0), (4540, 1, 0), (4541, 1, 0),
+    (4542, 1, 0), (4543, 1, 0), (4544, 0, 1303), (4544, 1, 0), (4545, 1, 0), (4546, 1, 0),
+    (4547, 1, 0), (4548, 1, 0), (4549, 1, 0), (4550, 1, 0), (4551, 1, 0), (4552, 1, 0),
+    (4553, 2, 0), (4555, 1, 0), (4556, 1, 0), (4557, 1, 0), (4558, 1, 0), (4559, 0, 425),
+    (4559, 1, 0), (4560, 1, 0), (4561, 1, 0), (4562, 1, 0), (4563, 4, 0), (4567, 1, 0),
+    (4568, 1, 0), (4569, 1, 0), (4570, 0, 1323), (4570, 1, 0), (4571, 1, 0), (4572, 1, 0),
+    (4573, 0, 454), (4573, 1, 0), (4574, 1, 0), (4575, 1, 0), (4576, 1, 0), (4577, 1, 0),
+    (4578, 1, 0), (4579, 1, 0), (4580, 0, 1332), (4580, 3, 0), (4583, 1, 0), (4584, 2, 0),
+    (4586, 1, 1336), (4587, 1, 0), (4588, 1, 0), (4589, 2, 0), (4591, 1, 0), (4592, 0, 1340),
+    (4592, 1, 0), (4593, 1, 0), (4594, 1, 0), (4595, 1, 0), (4596, 1, 0), (4597, 0, 1344),
+    (4597, 1, 0), (4598, 0, 475), (4598, 1, 0), (4599, 2, 0), (4601, 2, 0), (4603, 2, 0),
+    (4605, 1, 0), (4606, 1, 0), (4607, 1, 0), (4608, 1, 0), (4609, 1, 0), (4610, 1, 0),
+    (4611, 1, 0), (4612, 1, 0), (4613, 1, 0), (4614, 0, 1358), (4614, 2, 0), (4616, 1, 0),
+    (4617, 0, 1362), (4617, 2, 0), (4619, 1, 0), (4620, 1, 0), (4621, 1, 0), (4622, 1, 0),
+    (4623, 1, 0), (4624, 2, 0), (4626, 0, 442), (4626, 1, 0), (4627, 1, 0), (4628, 1, 0),
+    (4629, 1, 0), (4630, 1, 0), (4631, 1, 0), (4632, 1, 0), (4633, 2, 0), (4635, 1, 0),
+    (4636, 1, 0), (4637, 0, 1371), (4637, 1, 0), (4638, 1, 0), (4639, 2, 0), (4641, 1, 0),
+    (4642, 2, 0), (4644, 1, 0), (4645, 1, 0), (4646, 3, 0), (4649, 6, 0), (4655, 0, 457),
+    (4655, 1, 0), (4656, 1, 1384), (4657, 1, 1385), (4658, 1, 1386), (4659, 0, 459), (4659, 1, 0),
+    (4660, 2, 0), (4662, 2, 0), (4664, 1, 0), (4665, 1, 0), (4666, 1, 0), (4667, 2, 0),
+    (4669, 1, 0), (4670, 3, 0), (4673, 1, 0), (4674, 2, 0), (4676, 1, 0), (4677, 1, 0),
+    (4678, 1, 0), (4679, 0, 1405), (4679, 1, 0), (4680, 1, 0), (4681, 1, 0), (4682, 0, 1408),
+    (4682, 1, 0), (4683, 1, 0), (4684, 0, 1410), (4684, 1, 0), (4685, 1, 0), (4686, 1, 0),
+    (4687, 1, 0), (4688, 1, 0), (4689, 1, 0), (4690, 1, 0), (4691, 1, 0), (4692, 1, 0),
+    (4693, 1, 0), (4694, 1, 0), (4695, 0, 109), (4695, 1, 0), (4696, 1, 0), (4697, 2, 0),
+    (4699, 1, 0), (4700, 1, 0), (4701, 1, 0), (4702, 1, 0), (4703, 1, 0), (4704, 1, 0),
+    (4705, 1, 0), (4706, 1, 0), (4707, 1, 0), (4708, 1, 0), (4709, 1, 0), (4710, 1, 0),
+    (4711, 1, 0), (4712, 1, 0), (4713, 1, 0), (4714, 1, 0), (4715, 1, 0), (4716, 1, 0),
+    (4717, 1, 0), (4718, 1, 0), (4719, 1, 0), (4720, 1, 0), (4721, 1, 0), (4722, 1, 0),
+    (4723, 0, 1439), (4723, 1, 0), (4724, 1, 0), (4725, 1, 0), (4726, 1, 0), (4727, 0, 1442),
+    (4727, 1, 0), (4728, 2, 0), (4730, 1, 0), (4731, 1, 0), (4732, 1, 0), (4733, 1, 0),
+    (4734, 1, 0), (4735, 0, 84), (4735, 1, 0), (4736, 1, 0), (4737, 1, 0), (4738, 1, 0),
+    (4739, 1, 0), (4740, 1, 0), (4741, 3, 0), (4744, 1, 0), (4745, 1, 0), (4746, 1, 0),
+    (4747, 1, 0), (4748, 1, 0), (4749, 1, 0), (4750, 1, 0), (4751, 1, 0), (4752, 2, 0),
+    (4754, 1, 0), (4755, 1, 1460), (4756, 1, 0), (4757, 1, 0), (4758, 2, 0), (4760, 1, 0),
+    (4761, 1, 0), (4762, 1, 0), (4763, 1, 0), (4764, 1, 0), (4765, 3, 0), (4768, 2, 0),
+    (4770, 2, 0), (4772, 2, 0), (4774, 0, 1469), (4774, 1, 0), (4775, 0, 1205), (4775, 1, 0),
+    (4776, 1, 0), (4777, 1, 0), (4778, 1, 0), (4779, 1, 0), (4780, 0, 1473), (4780, 1, 0),
+    (4781, 2, 0), (4783, 1, 0), (4784, 1, 0), (4785, 1, 0), (4786, 1, 0), (4787, 1, 0),
+    (4788, 1, 0), (4789, 1, 0), (4790, 1, 0), (4791, 1, 0), (4792, 1, 0), (4793, 1, 0),
+    (4794, 0, 1481), (4794, 1, 0), (4795, 1, 0), (4796, 1, 0), (4797, 1, 0), (4798, 1, 0),
+    (4799, 1, 0), (4800, 1, 0), (4801, 0, 1484), (4801, 1, 0), (4802, 1, 0), (4803, 1, 0),
+    (4804, 1, 0), (4805, 1, 0), (4806, 1, 0), (4807, 1, 0), (4808, 1, 0), (4809, 1, 0),
+    (4810, 1, 0), (4811, 1, 0), (4812, 1, 0), (4813, 1, 0), (4814, 1, 0), (4815, 1, 0),
+    (4816, 1, 0), (4817, 1, 0), (4818, 1, 0), (4819, 1, 0), (4820, 1, 0), (4821, 1, 0),
+    (4822, 0, 1492), (4822, 0, 1493), (4822, 0, 1494), (4822, 1, 0), (4823, 1, 0), (4824, 1, 0),
+    (4825, 1, 0), (4826, 1, 1499), (4827, 1, 0), (4828, 1, 0), (4829, 0, 1502), (4829, 1, 0),
+    (4830, 1, 0), (4831, 1, 0), (4832, 0, 1505), (4832, 1, 0), (4833, 1, 0), (4834, 1, 0),
+    (4835, 1, 0), (4836, 0, 1510), (4836, 1, 0), (4837, 1, 1), (4838, 1, 0), (4839, 1, 0),
+    (4840, 1, 5), (4841, 1, 0), (4842, 1, 0), (4843, 1, 0), (4844, 1, 0), (4845, 0, 13),
+    (4845, 1, 0), (4846, 1, 15), (4847, 0, 16), (4847, 1, 0), (4848, 1, 0), (4849, 0, 19),
+    (4849, 1, 0), (4850, 0, 21), (4850, 1, 0), (4851, 1, 0), (4852, 1, 0), (4853, 0, 26),
+    (4853, 0, 28), (4853, 1, 0), (4854, 0, 25), (4854, 1, 0), (4855, 0, 31), (4855, 0, 32),
+    (4855, 1, 0), (4856, 1, 0), (4857, 1, 0), (4858, 1, 0), (4859, 1, 0), (4860, 1, 0),
+    (4861, 1, 0), (4862, 0, 41), (4862, 1, 0), (4863, 1, 0), (4864, 1, 0), (4865, 1, 0),
+    (4866, 1, 0), (4867, 2, 0), (4869, 1, 0), (4870, 1, 0), (4871, 1, 0), (4872, 0, 57),
+    (4872, 1, 0), (4873, 1, 0), (4874, 1, 0), (4875, 0, 61), (4875, 1, 0), (4876, 1, 0),
+    (4877, 0, 65), (4877, 0, 66), (4877, 0, 67), (4877, 1, 0), (4878, 0, 69), (4878, 1, 0),
+    (4879, 1, 0), (4880, 1, 0), (4881, 1, 0), (4882, 1, 0), (4883, 1, 0), (4884, 0, 83),
+    (4884, 1, 0), (4885, 1, 0), (4886, 1, 0), (4887, 1, 0), (4888, 1, 0), (4889, 1, 0),
+    (4890, 1, 0), (4891, 1, 0), (4892, 1, 0), (4893, 0, 111), (4893, 1, 0), (4894, 1, 0),
+    (4895, 1, 0), (4896, 1, 117), (4897, 0, 119), (4897, 1, 0), (4898, 1, 0), (4899, 1, 0),
+    (4900, 2, 0), (4902, 1, 0), (4903, 0, 127), (4903, 1, 0), (4904, 2, 0), (4906, 1, 0),
+    (4907, 0, 132), (4907, 0, 133), (4907, 0, 135), (4907, 1, 0), (4908, 1, 0), (4909, 1, 0),
+    (4910, 0, 142), (4910, 1, 0), (4911, 1, 0), (4912, 0, 144), (4912, 0, 145), (4912, 2, 0),
+    (4914, 1, 0), (4915, 1, 0), (4916, 1, 0), (4917, 0, 153), (4917, 0, 156), (4917, 1, 0),
+    (4918, 0, 164), (4918, 1, 0), (4919, 1, 0), (4920, 1, 0), (4921, 1, 0), (4922, 0, 172),
+    (4922, 1, 0), (4923, 0, 171), (4923, 1, 0), (4924, 1, 0), (4925, 1, 0), (4926, 0, 176),
+    (4926, 1, 0), (4927, 0, 178), (4927, 1, 0), (4928, 1, 180), (4929, 0, 182), (4929, 1, 0),
+    (4930, 1, 0), (4931, 1, 0), (4932, 1, 0), (4933, 1, 0), (4934, 1, 0), (4935, 1, 0),
+    (4936, 1, 0), (4937, 0, 193), (4937, 0, 194), (4937, 0, 195), (4937, 1, 0), (4938, 1, 0),
+    (4939, 0, 198), (4939, 1, 0), (4940, 0, 202), (4940, 0, 203), (4940, 1, 0), (4941, 1, 0),
+    (4942, 0, 206), (4942, 0, 207), (4942, 1, 0), (4943, 1, 0), (4944, 0, 212), (4944, 0, 213),
+    (4944, 0, 214), (4944, 1, 0), (4945, 1, 0), (4946, 0, 218), (4946, 1, 0), (4947, 0, 220),
+    (4947, 1, 0), (4948, 1, 0), (4949, 2, 0), (4951, 1, 0), (4952, 1, 0), (4953, 1, 0),
+    (4954, 1, 0), (4955, 2, 0), (4957, 1, 0), (4958, 1, 0), (4959, 1, 0), (4960, 1, 0),
+    (4961, 1, 0), (4962, 1, 0), (4963, 1, 0), (4964, 1, 0), (4965, 1, 0), (4966, 1, 0),
+    (4967, 1, 0), (4968, 1, 0), (4969, 1, 0), (4970, 1, 0), (4971, 1, 0), (4972, 1, 0),
+    (4973, 0, 261), (4973, 2, 0), (4975, 0, 219), (4975, 1, 0), (4976, 1, 0), (4977, 1, 0),
+    (4978, 1, 0), (4979, 0, 273), (4979, 0, 270), (4979, 0, 275), (4979, 1, 0), (4980, 1, 0),
+    (4981, 1, 0), (4982, 1, 0), (4983, 1, 0), (4984, 1, 0), (4985, 1, 0), (4986, 1, 0),
+    (4987, 0, 285), (4987, 1, 0), (4988, 1, 0), (4989, 1, 0), (4990, 1, 0), (4991, 1, 0),
+    (4992, 1, 0), (4993, 1, 0), (4994, 1, 0), (4995, 2, 0), (4997, 1, 0), (4998, 1, 0),
+    (4999, 1, 0), (5000, 1, 0), (5001, 1, 0), (5002, 3, 0), (5005, 1, 0), (5006, 1, 0),
+    (5007, 0, 338), (5007, 1, 0), (5008, 1, 0), (5009, 1, 0), (5010, 1, 343), (5011, 1, 0),
+    (5012, 1, 0), (5013, 1, 0), (5014, 1, 0), (5015, 1, 0), (5016, 0, 351), (5016, 1, 0),
+    (5017, 0, 355), (5017, 1, 0), (5018, 1, 0), (5019, 1, 0), (5020, 0, 359), (5020, 2, 0),
+    (5022, 1, 0), (5023, 1, 0), (5024, 1, 0), (5025, 1, 0), (5026, 0, 370), (5026, 1, 0),
+    (5027, 1, 0), (5028, 1, 0), (5029, 1, 0), (5030, 0, 379), (5030, 0, 381), (5030, 0, 383),
+    (5030, 0, 384), (5030, 1, 0), (5031, 1, 0), (5032, 0, 388), (5032, 0, 389), (5032, 1, 0),
+    (5033, 1, 0), (5034, 1, 0), (5035, 1, 0), (5036, 8, 0), (5044, 0, 421), (5044, 1, 0),
+    (5045, 1, 0), (5046, 0, 424), (5046, 1, 0), (5047, 1, 0), (5048, 0, 428), (5048, 1, 0),
+    (5049, 1, 0), (5050, 1, 0), (5051, 1, 0), (5052, 1, 0), (5053, 4, 0), (5057, 1, 0),
+    (5058, 1, 0), (5059, 0, 440), (5059, 0, 441), (5059, 1, 0), (5060, 0, 449), (5060, 0, 450),
+    (5060, 1, 0), (5061, 1, 0), (5062, 1, 0), (5063, 1, 0), (5064, 1, 0), (5065, 1, 461),
+    (5066, 1, 0), (5067, 1, 0), (5068, 0, 464), (5068, 1, 0), (5069, 1, 0), (5070, 1, 0),
+    (5071, 1, 0), (5072, 1, 0), (5073, 1, 0), (5074, 4, 0), (5078, 0, 479), (5078, 1, 0),
+    (5079, 0, 481), (5079, 1, 0), (5080, 1, 0), (5081, 0, 484), (5081, 1, 0), (5082, 1, 0),
+    (5083, 1, 0), (5084, 1, 488), (5085, 1, 0), (5086, 1, 0), (5087, 1, 0), (5088, 2, 0),
+    (5090, 2, 0), (5092, 1, 0), (5093, 0, 501), (5093, 1, 0), (5094, 1, 0), (5095, 1, 0),
+    (5096, 2, 0), (5098, 1, 0), (5099, 1, 0), (5100, 2, 0), (5102, 0, 510), (5102, 1, 0),
+    (5103, 1, 0), (5104, 0, 513), (5104, 1, 0), (5105, 0, 515), (5105, 1, 0), (5106, 0, 517),
+    (5106, 2, 0), (5108, 1, 0), (5109, 0, 522), (5109, 1, 0), (5110, 1, 0), (5111, 0, 529),
+    (5111, 0, 530), (5111, 1, 0), (5112, 1, 0), (5113, 1, 0), (5114, 0, 535), (5114, 0, 536),
+    (5114, 0, 539), (5114, 0, 540), (5114, 0, 541), (5114, 0, 542), (5114, 0, 543), (5114, 1, 0),
+    (5115, 1, 0), (5116, 0, 548), (5116, 0, 549), (5116, 0, 550), (5116, 0, 551), (5116, 1, 0),
+    (5117, 1, 0), (5118, 0, 555), (5118, 1, 0), (5119, 0, 556), (5119, 0, 558), (5119, 0, 559),
+    (5119, 1, 0), (5120, 1, 0), (5121, 1, 565), (5122, 1, 76), (5123, 1, 567), (5124, 1, 0),
+    (5125, 1, 0), (5126, 1, 0), (5127, 1, 0), (5128, 1, 0), (5129, 1, 0), (5130, 1, 0),
+    (5131, 0, 576), (5131, 1, 0), (5132, 0, 578), (5132, 0, 580), (5132, 1, 0), (5133, 1, 0),
+    (5134, 2, 0), (5136, 1, 0), (5137, 1, 0), (5138, 1, 0), (5139, 1, 0), (5140, 0, 596),
+    (5140, 1, 0), (5141, 0, 600), (5141, 0, 601), (5141, 1, 0), (5142, 1, 602), (5143, 0, 603),
+    (5143, 2, 0), (5145, 1, 0), (5146, 0, 606), (5146, 1, 0), (5147, 1, 0), (5148, 0, 608),
+    (5148, 1, 0), (5149, 1, 0), (5150, 1, 0), (5151, 1, 0), (5152, 1, 0), (5153, 1, 0),
+    (5154, 0, 496), (5154, 1, 0), (5155, 1, 0), (5156, 1, 0), (5157, 1, 0), (5158, 1, 0),
+    (5159, 1, 0), (5160, 1, 0), (5161, 0, 620), (5161, 0, 621), (5161, 1, 0), (5162, 1, 0),
+    (5163, 1, 0), (5164, 1, 0), (5165, 1, 0), (5166, 1, 0), (5167, 1, 0), (5168, 1, 0),
+    (5169, 1, 0), (5170, 1, 0), (5171, 1, 0), (5172, 1, 0), (5173, 1, 0), (5174, 1, 0),
+    (5175, 3, 0), (5178, 1, 0), (5179, 1, 0), (5180, 1, 0), (5181, 1, 0), (5182, 1, 0),
+    (5183, 1, 0), (5184, 0, 646), (5184, 0, 647), (5184, 1, 0), (5185, 1, 0), (5186, 1, 0),
+    (5187, 1, 0), (5188, 1, 0), (5189, 1, 0), (5190, 0, 653), (5190, 1, 0), (5191, 1, 0),
+    (5192, 1, 0), (5193, 1, 0), (5194, 1, 0), (5195, 1, 0), (5196, 1, 0), (5197, 1, 0),
+    (5198, 0, 662), (5198, 1, 0), (5199, 1, 0), (5200, 1, 0), (5201, 1, 0), (5202, 1, 0),
+    (5203, 1, 0), (5204, 1, 0), (5205, 1, 0), (5206, 1, 0), (5207, 1, 0), (5208, 1, 0),
+    (5209, 0, 173), (5209, 1, 0), (5210, 1, 0), (5211, 1, 0), (5212, 1, 0), (5213, 1, 0),
+    (5214, 1, 0), (5215, 1, 0), (5216, 1, 0), (5217, 1, 0), (5218, 1, 0), (5219, 1, 0),
+    (5220, 0, 685), (5220, 1, 0), (5221, 1, 0), (5222, 1, 0), (5223, 1, 0), (5224, 1, 0),
+    (5225, 1, 0), (5226, 1, 0), (5227, 1, 0), (5228, 1, 0), (5229, 0, 693), (5229, 1, 0),
+    (5230, 0, 612), (5230, 1, 0), (5231, 0, 695), (5231, 1, 0), (5232, 1, 0), (5233, 0, 698),
+    (5233, 1, 0), (5234, 0, 30), (5234, 1, 0), (5235, 2, 0), (5237, 1, 0), (5238, 1, 0),
+    (5239, 1, 0), (5240, 1, 0), (5241, 1, 0), (5242, 1, 0), (5243, 0, 707), (5243, 1, 0),
+    (5244, 1, 0), (5245, 1, 0), (5246, 1, 0), (5247, 1, 0), (5248, 1, 0), (5249, 2, 0),
+    (5251, 0, 715), (5251, 1, 42), (5252, 1, 0), (5253, 0, 717), (5253, 1, 0), (5254, 0, 719),
+    (5254, 2, 0), (5256, 0, 723), (5256, 0, 724), (5256, 1, 0), (5257, 1, 0), (5258, 0, 725),
+    (5258, 1, 0), (5259, 1, 0), (5260, 1, 0), (5261, 2, 0), (5263, 2, 0), (5265, 2, 0),
+    (5267, 0, 739), (5267, 1, 0), (5268, 1, 0), (5269, 0, 477), (5269, 1, 0), (5270, 1, 0),
+    (5271, 0, 741), (5271, 1, 0), (5272, 0, 32), (5272, 1, 0), (5273, 1, 0), (5274, 1, 0),
+    (5275, 0, 745), (5275, 0, 746), (5275, 1, 0), (5276, 0, 748), (5276, 1, 0), (5277, 1, 0),
+    (5278, 1, 0), (5279, 1, 0), (5280, 1, 0), (5281, 1, 0), (5282, 1, 0), (5283, 1, 0),
+    (5284, 1, 0), (5285, 1, 0), (5286, 1, 0), (5287, 0, 763), (5287, 1, 0), (5288, 3, 0),
+    (5291, 1, 0), (5292, 1, 0), (5293, 1, 0), (5294, 1, 0), (5295, 1, 0), (5296, 1, 0),
+    (5297, 1, 0), (5298, 0, 87), (5298, 0, 771), (5298, 1, 0), (5299, 1, 0), (5300, 0, 98),
+    (5300, 0, 774), (5300, 1, 0), (5301, 1, 0), (5302, 1, 0), (5303, 1, 0), (5304, 1, 0),
+    (5305, 1, 0), (5306, 1, 0), (5307, 1, 0), (5308, 1, 0), (5309, 1, 0), (5310, 1, 0),
+    (5311, 1, 0), (5312, 0, 81), (5312, 1, 0), (5313, 1, 0), (5314, 1, 0), (5315, 1, 0),
+    (5316, 1, 0), (5317, 1, 0), (5318, 0, 790), (5318, 1, 0), (5319, 1, 0), (5320, 1, 0),
+    (5321, 0, 794), (5321, 2, 0), (5323, 1, 0), (5324, 1, 0), (5325, 1, 0), (5326, 1, 0),
+    (5327, 1, 0), (5328, 1, 0), (5329, 1, 0), (5330, 1, 0), (5331, 1, 0), (5332, 1, 0),
+    (5333, 0, 801), (5333, 0, 802), (5333, 0, 803), (5333, 1, 0), (5334, 1, 0), (5335, 0, 806),
+    (5335, 1, 0), (5336, 1, 0), (5337, 1, 0), (5338, 1, 0), (5339, 0, 808), (5339, 1, 0),
+    (5340, 1, 0), (5341, 0, 795), (5341, 1, 0), (5342, 1, 0), (5343, 1, 0), (5344, 0, 813),
+    (5344, 1, 814), (5345, 1, 0), (5346, 0, 817), (5346, 1, 0), (5347, 1, 0), (5348, 1, 0),
+    (5349, 1, 0), (5350, 1, 0), (5351, 1, 0), (5352, 3, 0), (5355, 2, 0), (5357, 0, 833),
+    (5357, 0, 835), (5357, 1, 0), (5358, 0, 837), (5358, 0, 838), (5358, 1, 0), (5359, 1, 0),
+    (5360, 0, 841), (5360, 1, 0), (5361, 1, 0), (5362, 1, 0), (5363, 1, 0), (5364, 1, 0),
+    (5365, 1, 0), (5366, 1, 0), (5367, 1, 0), (5368, 2, 0), (5370, 1, 0), (5371, 1, 0),
+    (5372, 1, 0), (5373, 0, 849), (5373, 1, 0), (5374, 0, 130), (5374, 0, 852), (5374, 0, 853),
+    (5374, 0, 854), (5374, 1, 0), (5375, 1, 0), (5376, 1, 0), (5377, 1, 0), (5378, 1, 0),
+    (5379, 1, 0), (5380, 1, 0), (5381, 1, 0), (5382, 1, 0), (5383, 1, 0), (5384, 0, 863),
+    (5384, 1, 0), (5385, 1, 0), (5386, 0, 866), (5386, 0, 867), (5386, 1, 0), (5387, 0, 868),
+    (5387, 1, 0), (5388, 1, 0), (5389, 6, 0), (5395, 2, 0), (5397, 3, 0), (5400, 1, 0),
+    (5401, 2, 0), (5403, 1, 0), (5404, 1, 0), (5405, 1, 0), (5406, 0, 888), (5406, 1, 0),
+    (5407, 2, 0), (5409, 1, 0), (5410, 1, 0), (5411, 0, 896), (5411, 0, 159), (5411, 1, 0),
+    (5412, 1, 0), (5413, 1, 0), (5414, 0, 901), (5414, 1, 0), (5415, 1, 0), (5416, 0, 905),
+    (5416, 0, 910), (5416, 1, 0), (5417, 0, 911), (5417, 1, 0), (5418, 1, 0), (5419, 0, 913),
+    (5419, 1, 0), (5420, 0, 914), (5420, 0, 163), (5420, 1, 0), (5421, 1, 0), (5422, 0, 917),
+    (5422, 1, 0), (5423, 1, 0), (5424, 1, 0), (5425, 1, 0), (5426, 1, 0), (5427, 1, 0),
+    (5428, 1, 0), (5429, 2, 0), (5431, 1, 0), (5432, 1, 0), (5433, 1, 0), (5434, 0, 923),
+    (5434, 0, 89), (5434, 1, 0), (5435, 0, 871), (5435, 1, 0), (5436, 1, 0), (5437, 0, 234),
+    (5437, 1, 0), (5438, 1, 0), (5439, 0, 927), (5439, 1, 0), (5440, 1, 0), (5441, 1, 0),
+    (5442, 1, 0), (5443, 1, 0), (5444, 1, 0), (5445, 1, 0), (5446, 1, 0), (5447, 1, 0),
+    (5448, 1, 0), (5449, 0, 939), (5449, 1, 0), (5450, 0, 941), (5450, 1, 0), (5451, 1, 0),
+    (5452, 1, 0), (5453, 1, 0), (5454, 1, 0), (5455, 1, 945), (5456, 0, 947), (5456, 1, 948),
+    (5457, 1, 0), (5458, 1, 0), (5459, 1, 0), (5460, 1, 0), (5461, 1, 0), (5462, 1, 0),
+    (5463, 1, 0), (5464, 1, 0), (5465, 1, 0), (5466, 1, 0), (5467, 1, 0), (5468, 0, 958),
+    (5468, 1, 0), (5469, 1, 0), (5470, 2, 0), (5472, 1, 0), (5473, 1, 0), (5474, 1, 0),
+    (5475, 1, 0), (5476, 1, 0), (5477, 1, 0), (5478, 0, 966), (5478, 1, 0), (5479, 0, 968),
+    (5479, 0, 969), (5479, 1, 0), (5480, 1, 0), (5481, 0, 971), (5481, 0, 122), (5481, 1, 0),
+    (5482, 1, 0), (5483, 2, 0), (5485, 1, 0), (5486, 1, 0), (5487, 1, 0), (5488, 0, 978),
+    (5488, 1, 0), (5489, 1, 0), (5490, 0, 983), (5490, 0, 984), (5490, 1, 0), (5491, 1, 0),
+    (5492, 2, 0), (5494, 1, 0), (5495, 1, 0), (5496, 0, 993), (5496, 0, 994), (5496, 0, 995),
+    (5496, 0, 996), (5496, 1, 0), (5497, 0, 88), (5497, 1, 0), (5498, 1, 0), (5499, 0, 1000),
+    (5499, 1, 0), (5500, 1, 0), (5501, 1, 0), (5502, 1, 0), (5503, 0, 224), (5503, 1, 0),
+    (5504, 1, 0), (5505, 1, 1006), (5506, 0, 225), (5506, 2, 0), (5508, 1, 0), (5509, 1, 0),
+    (5510, 1, 0), (5511, 1, 0), (5512, 1, 0), (5513, 1, 0), (5514, 1, 0), (5515, 0, 1015),
+    (5515, 1, 0), (5516, 1, 0), (5517, 1, 0), (5518, 2, 0), (5520, 1, 0), (5521, 1, 0),
+    (5522, 1, 0), (5523, 1, 0), (5524, 1, 0), (5525, 0, 1019), (5525, 0, 1027), (5525, 2, 0),
+    (5527, 1, 0), (5528, 1, 0), (5529, 0, 1030), (5529, 1, 0), (5530, 1, 0), (5531, 1, 0),
+    (5532, 1, 0), (5533, 1, 0), (5534, 0, 249), (5534, 1, 0), (5535, 1, 0), (5536, 1, 0),
+    (5537, 0, 1038), (5537, 1, 0), (5538, 1, 0), (5539, 1, 0), (5540, 1, 0), (5541, 1, 0),
+    (5542, 1, 0), (5543, 1, 0), (5544, 1, 0), (5545, 1, 0), (5546, 2, 0), (5548, 1, 0),
+    (5549, 0, 1046), (5549, 1, 0), (5550, 1, 0), (5551, 1, 0), (5552, 1, 0), (5553, 1, 0),
+    (5554, 1, 0), (5555, 0, 1052), (5555, 1, 0), (5556, 0, 1053), (5556, 1, 0), (5557, 1, 0),
+    (5558, 1, 0), (5559, 1, 0), (5560, 1, 0), (5561, 1, 0), (5562, 1, 0), (5563, 1, 0),
+    (5564, 1, 0), (5565, 1, 0), (5566, 0, 1060), (5566, 1, 0), (5567, 1, 0), (5568, 1, 0),
+    (5569, 1, 0), (5570, 1, 0), (5571, 0, 634), (5571, 0, 1065), (5571, 1, 0), (5572, 1, 0),
+    (5573, 1, 0), (5574, 2, 0), (5576, 1, 0), (5577, 1, 0), (5578, 0, 1071), (5578, 0, 253),
+    (5578, 1, 0), (5579, 1, 0), (5580, 0, 1020), (5580, 2, 0), (5582, 1, 0), (5583, 0, 1076),
+    (5583, 1, 0), (5584, 1, 0), (5585, 1, 0), (5586, 1, 0), (5587, 1, 0), (5588, 1, 0),
+    (5589, 1, 0), (5590, 0, 1082), (5590, 1, 0), (5591, 1, 0), (5592, 1, 0), (5593, 1, 0),
+    (5594, 1, 0), (5595, 0, 1085), (5595, 1, 0), (5596, 0, 1087), (5596, 0, 1088), (5596, 0, 1089),
+    (5596, 1, 0), (5597, 1, 0), (5598, 1, 0), (5599, 1, 0), (5600, 1, 0), (5601, 1, 0),
+    (5602, 1, 1096), (5603, 1, 0), (5604, 1, 0), (5605, 1, 0), (5606, 3, 0), (5609, 0, 1100),
+    (5609, 0, 930), (5609, 1, 0), (5610, 1, 0), (5611, 0, 1102), (5611, 0, 1103), (5611, 1, 0),
+    (5612, 1, 0), (5613, 1, 0), (5614, 0, 297), (5614, 2, 0), (5616, 0, 309), (5616, 1, 0),
+    (5617, 1, 0), (5618, 1, 0), (5619, 1, 0), (5620, 1, 0), (5621, 0, 1116), (5621, 0, 1117),
+    (5621, 1, 0), (5622, 1, 0), (5623, 1, 0), (5624, 2, 0), (5626, 0, 284), (5626, 2, 0),
+    (5628, 0, 1121), (5628, 1, 0), (5629, 1, 0), (5630, 2, 0), (5632, 0, 1125), (5632, 1, 0),
+    (5633, 1, 0), (5634, 1, 0), (5635, 2, 0), (5637, 1, 0), (5638, 1, 0), (5639, 1, 0),
+    (5640, 1, 0), (5641, 1, 0), (5642, 0, 295), (5642, 1, 0), (5643, 1, 0), (5644, 0, 299),
+    (5644, 1, 0), (5645, 0, 294), (5645, 1, 0), (5646, 1, 0), (5647, 1, 0), (5648, 0, 1136),
+    (5648, 0, 1137), (5648, 1, 0), (5649, 1, 0), (5650, 0, 1140), (5650, 2, 0), (5652, 0, 307),
+    (5652, 1, 0), (5653, 1, 0), (5654, 0, 310), (5654, 1, 0), (5655, 1, 0), (5656, 2, 0),
+    (5658, 0, 337), (5658, 0, 1141), (5658, 4, 0), (5662, 2, 0), (5664, 0, 289), (5664, 1, 0),
+    (5665, 1, 0), (5666, 1, 0), (5667, 1, 0), (5668, 1, 0), (5669, 0, 315), (5669, 2, 0),
+    (5671, 1, 0), (5672, 3, 0), (5675, 1, 0), (5676, 2, 0), (5678, 1, 0), (5679, 0, 328),
+    (5679, 0, 1155), (5679, 1, 0), (5680, 0, 333), (5680, 2, 0), (5682, 1, 0), (5683, 1, 0),
+    (5684, 2, 0), (5686, 0, 1156), (5686, 1, 0), (5687, 1, 0), (5688, 1, 0), (5689, 2, 0),
+    (5691, 0, 1158), (5691, 1, 0), (5692, 1, 0), (5693, 1, 0), (5694, 0, 298), (5694, 1, 0),
+    (5695, 0, 308), (5695, 1, 0), (5696, 1, 0), (5697, 1, 0), (5698, 1, 0), (5699, 1, 0),
+    (5700, 0, 1167), (5700, 1, 0), (5701, 0, 1169), (5701, 0, 1170), (5701, 1, 0), (5702, 1, 0),
+    (5703, 0, 1173), (5703, 0, 1174), (5703, 1, 0), (5704, 1, 0), (5705, 1, 0), (5706, 1, 0),
+    (5707, 1, 0), (5708, 1, 0), (5709, 2, 0), (5711, 1, 0), (5712, 1, 0), (5713, 0, 729),
+    (5713, 0, 730), (5713, 1, 1183), (5714, 1, 0), (5715, 1, 0), (5716, 0, 1186), (5716, 0, 45),
+    (5716, 1, 0), (5717, 1, 0), (5718, 1, 0), (5719, 0, 1191), (5719, 1, 0), (5720, 1, 0),
+    (5721, 0, 56), (5721, 1, 0), (5722, 1, 0), (5723, 1, 0), (5724, 1, 0), (5725, 1, 0),
+    (5726, 1, 0), (5727, 1, 0), (5728, 0, 1201), (5728, 1, 0), (5729, 0, 1202), (5729, 0, 1203),
+    (5729, 1, 0), (5730, 1, 0), (5731, 1, 0), (5732, 2, 0), (5734, 0, 1208), (5734, 0, 1209),
+    (5734, 1, 0), (5735, 0, 1211), (5735, 1, 0), (5736, 0, 1207), (5736, 1, 0), (5737, 0, 1215),
+    (5737, 1, 0), (5738, 1, 0), (5739, 0, 1218), (5739, 1, 0), (5740, 0, 1220), (5740, 1, 0),
+    (5741, 1, 0), (5742, 1, 0), (5743, 0, 364), (5743, 1, 0), (5744, 1, 0), (5745, 1, 0),
+    (5746, 0, 506), (5746, 1, 0), (5747, 0, 1230), (5747, 1, 0), (5748, 1, 0), (5749, 1, 0),
+    (5750, 1, 0), (5751, 1, 0), (5752, 0, 1235), (5752, 1, 0), (5753, 1, 0), (5754, 1, 0),
+    (5755, 2, 0), (5757, 1, 0), (5758, 1, 0), (5759, 1, 0), (5760, 1, 0), (5761, 1, 0),
+    (5762, 0, 1243), (5762, 1, 1244), (5763, 0, 1246), (5763, 1, 0), (5764, 0, 372), (5764, 1, 0),
+    (5765, 1, 0), (5766, 1, 0), (5767, 3, 0), (5770, 1, 0), (5771, 2, 0), (5773, 0, 1248),
+    (5773, 1, 0), (5774, 1, 0), (5775, 0, 377), (5775, 1, 0), (5776, 1, 0), (5777, 1, 0),
+    (5778, 0, 378), (5778, 1, 0), (5779, 1, 0), (5780, 1, 0), (5781, 0, 1255), (5781, 1, 0),
+    (5782, 0, 951), (5782, 0, 1259), (5782, 1, 0), (5783, 0, 1261), (5783, 1, 0), (5784, 1, 0),
+    (5785, 2, 0), (5787, 0, 381), (5787, 1, 0), (5788, 0, 93), (5788, 1, 0), (5789, 1, 0),
+    (5790, 0, 1265), (5790, 0, 1266), (5790, 1, 0), (5791, 1, 0), (5792, 1, 0), (5793, 0, 399),
+    (5793, 1, 0), (5794, 1, 0), (5795, 1, 0), (5796, 1, 1271), (5797, 0, 400), (5797, 1, 0),
+    (5798, 2, 0), (5800, 1, 0), (5801, 1, 0), (5802, 1, 0), (5803, 1, 0), (5804, 1, 0),
+    (5805, 1, 0), (5806, 1, 0), (5807, 1, 0), (5808, 1, 0), (5809, 2, 0), (5811, 1, 0),
+    (5812, 1, 0), (5813, 2, 0), (5815, 1, 0), (5816, 1, 0), (5817, 1, 0), (5818, 1, 0),
+    (5819, 1, 0), (5820, 0, 1283), (5820, 0, 1291), (5820, 1, 0), (5821, 2, 0), (5823, 0, 1293),
+    (5823, 0, 394), (5823, 1, 0), (5824, 1, 0), (5825, 1, 0), (5826, 0, 1294), (5826, 1, 0),
+    (5827, 1, 0), (5828, 1, 0), (5829, 2, 0), (5831, 0, 1299), (5831, 6, 0), (5837, 0, 1302),
+    (5837, 1, 0), (5838, 1, 0), (5839, 1, 0), (5840, 1, 0), (5841, 1, 0), (5842, 1, 0),
+    (5843, 1, 0), (5844, 1, 0), (5845, 1, 0), (5846, 0, 1309), (5846, 1, 0), (5847, 1, 0),
+    (5848, 0, 1312), (5848, 1, 0), (5849, 1, 0), (5850, 1, 0), (5851, 1, 0), (5852, 0, 1316),
+    (5852, 0, 1284), (5852, 2, 0), (5854, 1, 0), (5855, 1, 0), (5856, 0, 1318), (5856, 1, 0),
+    (5857, 1, 0), (5858, 1, 0), (5859, 1, 0), (5860, 1, 0), (5861, 1, 0), (5862, 0, 1324),
+    (5862, 1, 0), (5863, 1, 0), (5864, 1, 0), (5865, 1, 0), (5866, 0, 1328), (5866, 1, 0),
+    (5867, 1, 0), (5868, 1, 0), (5869, 1, 0), (5870, 0, 1333), (5870, 1, 0), (5871, 1, 0),
+    (5872, 1, 0), (5873, 1, 0), (5874, 2, 0), (5876, 0, 1336), (5876, 0, 1337), (5876, 1, 0),
+    (5877, 1, 0), (5878, 1, 0), (5879, 0, 1339), (5879, 1, 0), (5880, 1, 0), (5881, 1, 0),
+    (5882, 0, 1343), (5882, 2, 0), (5884, 3, 0), (5887, 1, 0), (5888, 0, 476), (5888, 1, 0),
+    (5889, 0, 1348), (5889, 1, 0), (5890, 0, 1350), (5890, 1, 0), (5891, 1, 0), (5892, 1, 0),
+    (5893, 1, 0), (5894, 1, 0), (5895, 1, 0), (5896, 1, 0), (5897, 1, 0), (5898, 0, 523),
+    (5898, 1, 0), (5899, 0, 1359), (5899, 1, 0), (5900, 1, 0), (5901, 0, 1363), (5901, 1, 0),
+    (5902, 0, 1365), (5902, 1, 0), (5903, 0, 97), (5903, 2, 0), (5905, 2, 0), (5907, 3, 0),
+    (5910, 3, 0), (5913, 2, 0), (5915, 0, 141), (5915, 1, 0), (5916, 0, 1369), (5916, 1, 0),
+    (5917, 1, 0), (5918, 1, 0), (5919, 0, 1370), (5919, 1, 0), (5920, 1, 0), (5921, 1, 0),
+    (5922, 0, 1372), (5922, 1, 0), (5923, 0, 452), (5923, 1, 0), (5924, 1, 0), (5925, 1, 0),
+    (5926, 1, 0), (5927, 1, 0), (5928, 1, 0), (5929, 1, 0), (5930, 1, 0), (5931, 2, 0),
+    (5933, 0, 453), (5933, 1, 0), (5934, 1, 0), (5935, 1, 0), (5936, 3, 0), (5939, 1, 0),
+    (5940, 0, 1383), (5940, 0, 1384), (5940, 0, 1385), (5940, 0, 1386), (5940, 0, 1387), (5940, 1, 0),
+    (5941, 1, 0), (5942, 0, 460), (5942, 1, 0), (5943, 2, 0), (5945, 1, 0), (5946, 1, 0),
+    (5947, 1, 0), (5948, 1, 0), (5949, 1, 0), (5950, 1, 0), (5951, 1, 0), (5952, 2, 0),
+    (5954, 1, 0), (5955, 1, 0), (5956, 2, 0), (5958, 1, 0), (5959, 1, 1403), (5960, 1, 0),
+    (5961, 0, 362), (5961, 1, 0), (5962, 1, 0), (5963, 0, 480), (5963, 1, 0), (5964, 2, 0),
+    (5966, 3, 0), (5969, 2, 0), (5971, 1, 0), (5972, 1, 0), (5973, 1, 0), (5974, 1, 1413),
+    (5975, 1, 0), (5976, 3, 1414), (5979, 0, 952), (5979, 0, 1131), (5979, 1, 0), (5980, 1, 0),
+    (5981, 0, 1419), (5981, 1, 0), (5982, 0, 1338), (5982, 1, 0), (5983, 1, 0), (5984, 1, 0),
+    (5985, 1, 0), (5986, 0, 1423), (5986, 1, 0), (5987, 1, 0), (5988, 1, 0), (5989, 1, 0),
+    (5990, 1, 0), (5991, 0, 1430), (5991, 0, 1431), (5991, 1, 0), (5992, 1, 0), (5993, 1, 0),
+    (5994, 1, 0), (5995, 0, 95), (5995, 0, 1434), (5995, 1, 0), (5996, 0, 437), (5996, 1, 0),
+    (5997, 1, 0), (5998, 1, 1438), (5999, 1, 0), (6000, 1, 0), (6001, 1, 0), (6002, 1, 0),
+    (6003, 1, 0), (6004, 1, 0), (6005, 1, 0), (6006, 1, 0), (6007, 1, 0), (6008, 1, 0),
+    (6009, 1, 0), (6010, 1, 0), (6011, 1, 0), (6012, 0, 1450), (6012, 1, 0), (6013, 1, 0),
+    (6014, 1, 0), (6015, 1, 0), (6016, 0, 1451), (6016, 1, 0), (6017, 1, 0), (6018, 1, 0),
+    (6019, 1, 0), (6020, 1, 0), (6021, 1, 0), (6022, 1, 0), (6023, 0, 1457), (6023, 1, 0),
+    (6024, 1, 0), (6025, 0, 1422), (6025, 1, 0), (6026, 1, 0), (6027, 0, 1460), (6027, 1, 0),
+    (6028, 0, 96), (6028, 0, 1462), (6028, 1, 0), (6029, 1, 0), (6030, 1, 0), (6031, 1, 0),
+    (6032, 1, 0), (6033, 1, 0), (6034, 1, 0), (6035, 1, 0), (6036, 1, 0), (6037, 0, 504),
+    (6037, 1, 0), (6038, 1, 0), (6039, 2, 0), (6041, 1, 0), (6042, 1, 0), (6043, 1, 0),
+    (6044, 1, 0), (6045, 1, 0), (6046, 1, 0), (6047, 1, 0), (6048, 0, 524), (6048, 1, 0),
+    (6049, 1, 0), (6050, 1, 0), (6051, 0, 1474), (6051, 1, 0), (6052, 1, 0), (6053, 0, 1475),
+    (6053, 2, 0), (6055, 2, 0), (6057, 1, 0), (6058, 1, 0), (6059, 1, 0), (6060, 2, 0),
+    (6062, 1, 0), (6063, 0, 1482), (6063, 1, 0), (6064, 0, 1483), (6064, 0, 189), (6064, 1, 0),
+    (6065, 0, 498), (6065, 1, 0), (6066, 1, 0), (6067, 1, 0), (6068, 1, 0), (6069, 1, 0),
+    (6070, 0, 1057), (6070, 0, 1486), (6070, 1, 0), (6071, 0, 1487), (6071, 1, 0), (6072, 1, 0),
+    (6073, 1, 0), (6074, 1, 0), (6075, 0, 1488), (6075, 1, 0), (6076, 1, 0), (6077, 1, 0),
+    (6078, 0, 521), (6078, 1, 0), (6079, 1, 0), (6080, 0, 1490), (6080, 1, 0), (6081, 0, 1495),
+    (6081, 0, 1496), (6081, 0, 1497), (6081, 0, 1498), (6081, 0, 1499), (6081, 1, 0), (6082, 1, 0),
+    (6083, 0, 1503), (6083, 1, 0), (6084, 0, 1504), (6084, 0, 1506), (6084, 1, 0), (6085, 0, 1508),
+    (6085, 0, 1509), (6085, 0, 1511), (6085, 0, 1), (6085, 1, 3), (6086, 1, 0), (6087, 0, 5),
+    (6087, 1, 8), (6088, 0, 9), (6088, 0, 10), (6088, 0, 12), (6088, 1, 0), (6089, 0, 15),
+    (6089, 1, 0), (6090, 1, 18), (6091, 1, 0), (6092, 1, 0), (6093, 1, 0), (6094, 1, 0),
+    (6095, 0, 29), (6095, 1, 0), (6096, 1, 0), (6097, 1, 0), (6098, 1, 0), (6099, 1, 0),
+    (6100, 1, 38), (6101, 0, 39), (6101, 1, 0), (6102, 1, 0), (6103, 1, 0), (6104, 4, 0),
+    (6108, 1, 0), (6109, 1, 0), (6110, 0, 52), (6110, 1, 0), (6111, 1, 0), (6112, 1, 0),
+    (6113, 1, 0), (6114, 1, 0), (6115, 1, 0), (6116, 0, 60), (6116, 1, 0), (6117, 1, 0),
+    (6118, 1, 0), (6119, 0, 70), (6119, 1, 0), (6120, 0, 74), (6120, 1, 0), (6121, 1, 0),
+    (6122, 1, 0), (6123, 1, 0), (6124, 1, 0), (6125, 6, 0), (6131, 1, 0), (6132, 1, 0),
+    (6133, 1, 0), (6134, 1, 0), (6135, 1, 0), (6136, 1, 0), (6137, 1, 0), (6138, 1, 115),
+    (6139, 1, 0), (6140, 0, 117), (6140, 1, 121), (6141, 1, 0), (6142, 0, 123), (6142, 1, 0),
+    (6143, 1, 0), (6144, 0, 126), (6144, 1, 0), (6145, 0, 129), (6145, 1, 0), (6146, 1, 0),
+    (6147, 1, 0), (6148, 1, 0), (6149, 2, 0), (6151, 1, 0), (6152, 1, 0), (6153, 0, 147),
+    (6153, 1, 0), (6154, 1, 0), (6155, 1, 0), (6156, 0, 151), (6156, 1, 0), (6157, 1, 0),
+    (6158, 0, 167), (6158, 0, 169), (6158, 1, 0), (6159, 1, 0), (6160, 1, 0), (6161, 1, 0),
+    (6162, 1, 0), (6163, 0, 177), (6163, 1, 179), (6164, 0, 180), (6164, 1, 184), (6165, 0, 185),
+    (6165, 1, 0), (6166, 1, 0), (6167, 1, 0), (6168, 1, 0), (6169, 1, 0), (6170, 0, 192),
+    (6170, 1, 0), (6171, 0, 197), (6171, 0, 199), (6171, 1, 0), (6172, 0, 205), (6172, 0, 208),
+    (6172, 1, 0), (6173, 1, 0), (6174, 1, 0), (6175, 1, 0), (6176, 1, 0), (6177, 1, 0),
+    (6178, 1, 0), (6179, 1, 0), (6180, 1, 0), (6181, 2, 0), (6183, 1, 0), (6184, 1, 0),
+    (6185, 1, 0), (6186, 1, 0), (6187, 3, 0), (6190, 1, 0), (6191, 1, 0), (6192, 1, 0),
+    (6193, 1, 0), (6194, 1, 0), (6195, 1, 0), (6196, 1, 0), (6197, 1, 0), (6198, 1, 0),
+    (6199, 1, 0), (6200, 1, 0), (6201, 1, 0), (6202, 1, 0), (6203, 1, 0), (6204, 1, 0),
+    (6205, 1, 0), (6206, 1, 0), (6207, 1, 0), (6208, 1, 0), (6209, 1, 0), (6210, 1, 0),
+    (6211, 1, 0), (6212, 1, 0), (6213, 1, 0), (6214, 1, 0), (6215, 2, 0), (6217, 1, 0),
+    (6218, 1, 0), (6219, 1, 0), (6220, 1, 0), (6221, 1, 0), (6222, 1, 0), (6223, 1, 0),
+    (6224, 1, 0), (6225, 1, 0), (6226, 1, 0), (6227, 1, 0), (6228, 1, 0), (6229, 1, 0),
+    (6230, 1, 0), (6231, 1, 0), (6232, 1, 0), (6233, 1, 0), (6234, 1, 0), (6235, 1, 0),
+    (6236, 1, 0), (6237, 1, 0), (6238, 1, 0), (6239, 1, 0), (6240, 1, 339), (6241, 0, 341),
+    (6241, 1, 342), (6242, 0, 343), (6242, 1, 0), (6243, 1, 347), (6244, 0, 348), (6244, 0, 349),
+    (6244, 1, 0), (6245, 1, 0), (6246, 1, 356), (6247, 1, 357), (6248, 1, 0), (6249, 1, 0),
+    (6250, 1, 0), (6251, 1, 0), (6252, 1, 0), (6253, 1, 0), (6254, 1, 0), (6255, 1, 0),
+    (6256, 0, 376), (6256, 1, 0), (6257, 1, 0), (6258, 0, 385), (6258, 1, 0), (6259, 1, 0),
+    (6260, 1, 0), (6261, 1, 0), (6262, 1, 0), (6263, 2, 0), (6265, 1, 0), (6266, 1, 0),
+    (6267, 1, 0), (6268, 2, 0), (6270, 1, 0), (6271, 1, 0), (6272, 1, 0), (6273, 1, 0),
+    (6274, 1, 0), (6275, 1, 0), (6276, 1, 0), (6277, 1, 0), (6278, 1, 0), (6279, 1, 0),
+    (6280, 1, 0), (6281, 0, 434), (6281, 1, 0), (6282, 1, 0), (6283, 1, 0), (6284, 1, 0),
+    (6285, 0, 438), (6285, 1, 0), (6286, 4, 0), (6290, 2, 0), (6292, 1, 0), (6293, 1, 0),
+    (6294, 1, 0), (6295, 1, 0), (6296, 0, 461), (6296, 0, 462), (6296, 0, 463), (6296, 1, 0),
+    (6297, 1, 0), (6298, 1, 0), (6299, 0, 472), (6299, 1, 0), (6300, 1, 0), (6301, 0, 475),
+    (6301, 1, 0), (6302, 1, 0), (6303, 1, 0), (6304, 1, 0), (6305, 1, 0), (6306, 1, 483),
+    (6307, 1, 0), (6308, 0, 486), (6308, 1, 0), (6309, 0, 488), (6309, 1, 0), (6310, 1, 492),
+    (6311, 0, 493), (6311, 2, 0), (6313, 1, 0), (6314, 0, 498), (6314, 1, 0), (6315, 0, 500),
+    (6315, 1, 0), (6316, 1, 0), (6317, 1, 0), (6318, 0, 506), (6318, 1, 0), (6319, 1, 0),
+    (6320, 1, 0), (6321, 1, 0), (6322, 1, 0), (6323, 1, 0), (6324, 0, 512), (6324, 1, 0),
+    (6325, 0, 516), (6325, 0, 519), (6325, 1, 0), (6326, 1, 0), (6327, 1, 0), (6328, 1, 0),
+    (6329, 1, 0), (6330, 0, 532), (6330, 0, 533), (6330, 1, 544), (6331, 0, 545), (6331, 1, 0),
+    (6332, 1, 0), (6333, 1, 0), (6334, 1, 560), (6335, 1, 0), (6336, 0, 565), (6336, 0, 76),
+    (6336, 0, 567), (6336, 1, 569), (6337, 1, 0), (6338, 0, 570), (6338, 0, 571), (6338, 0, 572),
+    (6338, 0, 573), (6338, 1, 0), (6339, 1, 0), (6340, 0, 579), (6340, 2, 0), (6342, 0, 590),
+    (6342, 1, 0), (6343, 1, 0), (6344, 0, 15), (6344, 1, 0), (6345, 0, 595), (6345, 1, 0),
+    (6346, 2, 0), (6348, 0, 602), (6348, 0, 478), (6348, 1, 0), (6349, 1, 605), (6350, 1, 0),
+    (6351, 0, 607), (6351, 1, 0), (6352, 1, 0), (6353, 1, 0), (6354, 1, 0), (6355, 1, 0),
+    (6356, 2, 0), (6358, 1, 0), (6359, 0, 609), (6359, 0, 618), (6359, 2, 0), (6361, 1, 0),
+    (6362, 0, 610), (6362, 1, 0), (6363, 1, 0), (6364, 1, 0), (6365, 1, 0), (6366, 1, 0),
+    (6367, 1, 0), (6368, 1, 0), (6369, 1, 0), (6370, 1, 0), (6371, 1, 0), (6372, 1, 0),
+    (6373, 1, 0), (6374, 1, 0), (6375, 1, 0), (6376, 1, 0), (6377, 1, 0), (6378, 1, 0),
+    (6379, 1, 0), (6380, 0, 639), (6380, 0, 640), (6380, 0, 641), (6380, 0, 642), (6380, 0, 643),
+    (6380, 1, 0), (6381, 1, 0), (6382, 1, 0), (6383, 0, 649), (6383, 0, 650), (6383, 0, 651),
+    (6383, 0, 652), (6383, 0, 654), (6383, 0, 655), (6383, 0, 656), (6383, 0, 657), (6383, 0, 658),
+    (6383, 0, 659), (6383, 0, 660), (6383, 0, 661), (6383, 0, 663), (6383, 0, 664), (6383, 0, 665),
+    (6383, 0, 666), (6383, 0, 667), (6383, 0, 668), (6383, 1, 0), (6384, 0, 670), (6384, 0, 671),
+    (6384, 0, 672), (6384, 0, 673), (6384, 0, 674), (6384, 0, 675), (6384, 0, 676), (6384, 0, 677),
+    (6384, 1, 0), (6385, 1, 0), (6386, 1, 0), (6387, 0, 681), (6387, 0, 682), (6387, 0, 683),
+    (6387, 0, 684), (6387, 0, 686), (6387, 0, 687), (6387, 0, 688), (6387, 0, 689), (6387, 0, 690),
+    (6387, 0, 691), (6387, 1, 0), (6388, 0, 29), (6388, 1, 692), (6389, 0, 694), (6389, 0, 613),
+    (6389, 0, 696), (6389, 1, 0), (6390, 1, 0), (6391, 0, 699), (6391, 0, 175), (6391, 1, 0),
+    (6392, 1, 0), (6393, 1, 0), (6394, 1, 0), (6395, 1, 0), (6396, 1, 0), (6397, 1, 0),
+    (6398, 0, 708), (6398, 0, 167), (6398, 0, 709), (6398, 1, 0), (6399, 1, 711), (6400, 0, 712),
+    (6400, 0, 713), (6400, 1, 0), (6401, 0, 42), (6401, 1, 0), (6402, 1, 0), (6403, 0, 720),
+    (6403, 1, 0), (6404, 1, 0), (6405, 2, 0), (6407, 1, 0), (6408, 1, 0), (6409, 1, 0),
+    (6410, 0, 735), (6410, 1, 0), (6411, 0, 736), (6411, 2, 0), (6413, 0, 737), (6413, 1, 0),
+    (6414, 1, 0), (6415, 2, 0), (6417, 1, 0), (6418, 1, 0), (6419, 1, 0), (6420, 1, 0),
+    (6421, 0, 743), (6421, 0, 744), (6421, 0, 747), (6421, 0, 749), (6421, 0, 750), (6421, 2, 0),
+    (6423, 0, 753), (6423, 0, 754), (6423, 2, 0), (6425, 1, 0), (6426, 1, 0), (6427, 1, 0),
+    (6428, 1, 0), (6429, 0, 762), (6429, 2, 0), (6431, 1, 0), (6432, 1, 0), (6433, 1, 0),
+    (6434, 1, 768), (6435, 1, 0), (6436, 0, 766), (6436, 0, 767), (6436, 1, 0), (6437, 0, 769),
+    (6437, 1, 0), (6438, 1, 0), (6439, 1, 0), (6440, 0, 236), (6440, 1, 0), (6441, 0, 78),
+    (6441, 1, 0), (6442, 1, 0), (6443, 0, 778), (6443, 1, 0), (6444, 0, 781), (6444, 1, 0),
+    (6445, 1, 0), (6446, 0, 231), (6446, 0, 406), (6446, 1, 0), (6447, 0, 785), (6447, 1, 0),
+    (6448, 0, 787), (6448, 2, 788), (6450, 1, 0), (6451, 1, 0), (6452, 1, 0), (6453, 1, 0),
+    (6454, 0, 86), (6454, 1, 0), (6455, 1, 0), (6456, 1, 0), (6457, 1, 0), (6458, 1, 0),
+    (6459, 1, 0), (6460, 1, 0), (6461, 1, 0), (6462, 1, 0), (6463, 1, 0), (6464, 1, 0),
+    (6465, 1, 0), (6466, 0, 805), (6466, 0, 636), (6466, 0, 100), (6466, 0, 397), (6466, 1, 0),
+    (6467, 1, 0), (6468, 0, 779), (6468, 1, 810), (6469, 1, 0), (6470, 1, 0), (6471, 0, 814),
+    (6471, 1, 0), (6472, 0, 818), (6472, 1, 821), (6473, 1, 0), (6474, 1, 0), (6475, 1, 0),
+    (6476, 0, 829), (6476, 0, 830), (6476, 1, 0), (6477, 1, 0), (6478, 1, 0), (6479, 1, 0),
+    (6480, 0, 836), (6480, 1, 0), (6481, 0, 840), (6481, 1, 0), (6482, 0, 842), (6482, 1, 0),
+    (6483, 1, 0), (6484, 0, 130), (6484, 1, 0), (6485, 1, 0), (6486, 1, 0), (6487, 0, 54),
+    (6487, 1, 0), (6488, 1, 0), (6489, 0, 847), (6489, 0, 848), (6489, 0, 86), (6489, 0, 136),
+    (6489, 1, 0), (6490, 1, 0), (6491, 1, 0), (6492, 1, 0), (6493, 1, 0), (6494, 0, 858),
+    (6494, 1, 0), (6495, 0, 861), (6495, 0, 862), (6495, 0, 864), (6495, 0, 865), (6495, 1, 0),
+    (6496, 0, 869), (6496, 1, 0), (6497, 1, 871), (6498, 1, 0), (6499, 1, 873), (6500, 1, 0),
+    (6501, 1, 0), (6502, 1, 0), (6503, 1, 0), (6504, 1, 0), (6505, 1, 879), (6506, 1, 0),
+    (6507, 1, 0), (6508, 1, 0), (6509, 1, 0), (6510, 1, 0), (6511, 1, 0), (6512, 0, 886),
+    (6512, 0, 887), (6512, 1, 0), (6513, 0, 891), (6513, 1, 0), (6514, 1, 0), (6515, 0, 894),
+    (6515, 1, 0), (6516, 0, 897), (6516, 2, 0), (6518, 1, 0), (6519, 0, 904), (6519, 1, 0),
+    (6520, 0, 909), (6520, 0, 912), (6520, 0, 79), (6520, 0, 915), (6520, 0, 916), (6520, 0, 918),
+    (6520, 0, 919), (6520, 1, 0), (6521, 1, 0), (6522, 1, 0), (6523, 1, 0), (6524, 1, 0),
+    (6525, 1, 0), (6526, 1, 0), (6527, 1, 0), (6528, 1, 0), (6529, 1, 0), (6530, 1, 0),
+    (6531, 1, 0), (6532, 1, 0), (6533, 1, 0), (6534, 0, 926), (6534, 0, 928), (6534, 2, 0),
+    (6536, 1, 0), (6537, 1, 0), (6538, 1, 0), (6539, 1, 0), (6540, 0, 935), (6540, 1, 0),
+    (6541, 1, 0), (6542, 1, 0), (6543, 1, 0), (6544, 1, 0), (6545, 1, 0), (6546, 1, 0),
+    (6547, 1, 0), (6548, 1, 944), (6549, 0, 945), (6549, 0, 948), (6549, 1, 950), (6550, 1, 0),
+    (6551, 0, 952), (6551, 1, 0), (6552, 0, 954), (6552, 0, 955), (6552, 0, 956), (6552, 0, 183),
+    (6552, 1, 0), (6553, 1, 0), (6554, 0, 957), (6554, 0, 959), (6554, 1, 0), (6555, 0, 961),
+    (6555, 1, 0), (6556, 1, 0), (6557, 1, 0), (6558, 1, 0), (6559, 1, 0), (6560, 1, 0),
+    (6561, 1, 0), (6562, 0, 967), (6562, 0, 965), (6562, 1, 970), (6563, 0, 972), (6563, 1, 0),
+    (6564, 0, 974), (6564, 1, 0), (6565, 0, 122), (6565, 1, 0), (6566, 0, 977), (6566, 0, 979),
+    (6566, 0, 982), (6566, 1, 0), (6567, 0, 986), (6567, 0, 987), (6567, 1, 0), (6568, 1, 0),
+    (6569, 1, 0), (6570, 0, 256), (6570, 1, 0), (6571, 0, 998), (6571, 1, 0), (6572, 1, 0),
+    (6573, 1, 0), (6574, 1, 0), (6575, 0, 1004), (6575, 1, 0), (6576, 0, 1006), (6576, 0, 226),
+    (6576, 1, 0), (6577, 1, 0), (6578, 1, 0), (6579, 1, 0), (6580, 1, 0), (6581, 1, 0),
+    (6582, 1, 0), (6583, 1, 0), (6584, 0, 1016), (6584, 0, 1017), (6584, 0, 1018), (6584, 1, 0),
+    (6585, 1, 0), (6586, 0, 1021), (6586, 2, 0), (6588, 1, 0), (6589, 1, 0), (6590, 0, 228),
+    (6590, 0, 352), (6590, 1, 0), (6591, 1, 0), (6592, 1, 0), (6593, 1, 0), (6594, 1, 0),
+    (6595, 1, 0), (6596, 1, 0), (6597, 1, 0), (6598, 1, 0), (6599, 0, 1034), (6599, 2, 0),
+    (6601, 1, 0), (6602, 1, 0), (6603, 1, 0), (6604, 2, 0), (6606, 1, 0), (6607, 1, 0),
+    (6608, 1, 0), (6609, 1, 0), (6610, 0, 104), (6610, 0, 246), (6610, 1, 0), (6611, 0, 1045),
+    (6611, 0, 1032), (6611, 1, 0), (6612, 1, 0), (6613, 0, 1048), (6613, 1, 0), (6614, 2, 0),
+    (6616, 1, 0), (6617, 0, 1051), (6617, 0, 1054), (6617, 0, 1055), (6617, 0, 1056), (6617, 0, 229),
+    (6617, 1, 0), (6618, 1, 0), (6619, 1, 0), (6620, 1, 0), (6621, 0, 1059), (6621, 1, 0),
+    (6622, 1, 0), (6623, 1, 0), (6624, 1, 0), (6625, 1, 0), (6626, 1, 0), (6627, 0, 227),
+    (6627, 1, 0), (6628, 0, 396), (6628, 1, 0), (6629, 0, 1069), (6629, 1, 0), (6630, 0, 1072),
+    (6630, 0, 1073), (6630, 0, 353), (6630, 1, 0), (6631, 1, 0), (6632, 0, 1077), (6632, 0, 1040),
+    (6632, 1, 0), (6633, 1, 0), (6634, 1, 0), (6635, 1, 0), (6636, 1, 0), (6637, 0, 241),
+    (6637, 0, 637), (6637, 1, 0), (6638, 1, 0), (6639, 1, 0), (6640, 0, 1086), (6640, 1, 0),
+    (6641, 4, 0), (6645, 1, 0), (6646, 1, 0), (6647, 0, 1093), (6647, 1, 0), (6648, 0, 1096),
+    (6648, 1, 0), (6649, 1, 0), (6650, 1, 43), (6651, 0, 1098), (6651, 1, 0), (6652, 2, 0),
+    (6654, 1, 0), (6655, 1, 0), (6656, 1, 0), (6657, 1, 0), (6658, 0, 1105), (6658, 1, 0),
+    (6659, 1, 0), (6660, 1, 0), (6661, 1, 0), (6662, 1, 0), (6663, 0, 73), (6663, 1, 0),
+    (6664, 0, 1118), (6664, 0, 1119), (6664, 1, 0), (6665, 0, 1120), (6665, 1, 0), (6666, 0, 301),
+    (6666, 1, 0), (6667, 1, 0), (6668, 1, 0), (6669, 0, 335), (6669, 1, 0), (6670, 0, 1127),
+    (6670, 0, 1128), (6670, 1, 0), (6671, 0, 509), (6671, 1, 0), (6672, 0, 1130), (6672, 1, 0),
+    (6673, 1, 0), (6674, 0, 292), (6674, 2, 0), (6676, 0, 296), (6676, 1, 0), (6677, 0, 300),
+    (6677, 0, 1109), (6677, 0, 1133), (6677, 0, 1134), (6677, 0, 1108), (6677, 0, 1139), (6677, 1, 0),
+    (6678, 1, 0), (6679, 0, 1138), (6679, 1, 0), (6680, 0, 306), (6680, 0, 311), (6680, 0, 303),
+    (6680, 1, 0), (6681, 0, 290), (6681, 1, 0), (6682, 1, 0), (6683, 3, 0), (6686, 0, 317),
+    (6686, 3, 0), (6689, 1, 0), (6690, 1, 0), (6691, 0, 1150), (6691, 1, 0), (6692, 1, 0),
+    (6693, 0, 314), (6693, 1, 0), (6694, 0, 1112), (6694, 0, 1152), (6694, 1, 0), (6695, 1, 0),
+    (6696, 1, 0), (6697, 0, 318), (6697, 1, 0), (6698, 1, 0), (6699, 2, 0), (6701, 0, 334),
+    (6701, 1, 0), (6702, 0, 337), (6702, 0, 289), (6702, 1, 0), (6703, 1, 0), (6704, 0, 1157),
+    (6704, 0, 326), (6704, 1, 0), (6705, 0, 327), (6705, 1, 0), (6706, 0, 1159), (6706, 0, 332),
+    (6706, 1, 0), (6707, 1, 1160), (6708, 1, 0), (6709, 1, 0), (6710, 0, 1164), (6710, 1, 0),
+    (6711, 1, 0), (6712, 1, 0), (6713, 1, 0), (6714, 1, 0), (6715, 1, 0), (6716, 1, 0),
+    (6717, 1, 0), (6718, 0, 1178), (6718, 0, 1179), (6718, 1, 0), (6719, 0, 508), (6719, 1, 0),
+    (6720, 1, 0), (6721, 1, 1182), (6722, 0, 1183), (6722, 0, 731), (6722, 1, 0), (6723, 1, 0),
+    (6724, 0, 1188), (6724, 0, 1189), (6724, 1, 1192), (6725, 0, 1194), (6725, 0, 726), (6725, 0, 1195),
+    (6725, 1, 0), (6726, 0, 360), (6726, 0, 1198), (6726, 0, 1199), (6726, 1, 0), (6727, 1, 0),
+    (6728, 0, 1204), (6728, 0, 47), (6728, 0, 727), (6728, 0, 1207), (6728, 1, 0), (6729, 1, 0),
+    (6730, 1, 0), (6731, 1, 1214), (6732, 1, 1216), (6733, 2, 0), (6735, 0, 1219), (6735, 1, 0),
+    (6736, 1, 0), (6737, 0, 1222), (6737, 1, 0), (6738, 1, 0), (6739, 1, 0), (6740, 1, 0),
+    (6741, 1, 0), (6742, 0, 1231), (6742, 1, 0), (6743, 2, 0), (6745, 1, 0), (6746, 1, 0),
+    (6747, 0, 679), (6747, 1, 0), (6748, 1, 0), (6749, 1, 0), (6750, 0, 1239), (6750, 1, 369),
+    (6751, 1, 0), (6752, 1, 0), (6753, 1, 0), (6754, 0, 1244), (6754, 0, 374), (6754, 1, 0),
+    (6755, 1, 0), (6756, 1, 0), (6757, 1, 0), (6758, 1, 0), (6759, 1, 0), (6760, 1, 0),
+    (6761, 0, 1250), (6761, 1, 0), (6762, 0, 1247), (6762, 1, 0), (6763, 1, 0), (6764, 1, 0),
+    (6765, 1, 0), (6766, 1, 0), (6767, 0, 375), (6767, 1, 0), (6768, 1, 0), (6769, 1, 0),
+    (6770, 1, 0), (6771, 1, 0), (6772, 0, 1263), (6772, 1, 0), (6773, 0, 423), (6773, 1, 0),
+    (6774, 0, 775), (6774, 1, 0), (6775, 0, 441), (6775, 1, 0), (6776, 0, 1269), (6776, 0, 1270),
+    (6776, 1, 0), (6777, 0, 1271), (6777, 1, 0), (6778, 0, 401), (6778, 1, 0), (6779, 0, 1274),
+    (6779, 1, 0), (6780, 1, 0), (6781, 1, 0), (6782, 1, 0), (6783, 1, 0), (6784, 1, 0),
+    (6785, 0, 1279), (6785, 1, 0), (6786, 0, 1281), (6786, 1, 0), (6787, 0, 633), (6787, 0, 1282),
+    (6787, 1, 0), (6788, 1, 0), (6789, 0, 1285), (6789, 2, 0), (6791, 1, 0), (6792, 1, 0),
+    (6793, 0, 403), (6793, 1, 0), (6794, 0, 50), (6794, 1, 0), (6795, 1, 0), (6796, 1, 0),
+    (6797, 0, 421), (6797, 1, 0), (6798, 1, 0), (6799, 0, 107), (6799, 0, 419), (6799, 1, 0),
+    (6800, 1, 0), (6801, 1, 0), (6802, 1, 0), (6803, 1, 0), (6804, 1, 0), (6805, 1, 0),
+    (6806, 1, 0), (6807, 0, 402), (6807, 0, 131), (6807, 2, 0), (6809, 0, 1305), (6809, 0, 1306),
+    (6809, 0, 1307), (6809, 0, 404), (6809, 0, 1308), (6809, 1, 0), (6810, 1, 0), (6811, 1, 0),
+    (6812, 1, 0), (6813, 0, 1300), (6813, 1, 0), (6814, 0, 51), (6814, 1, 0), (6815, 1, 0),
+    (6816, 1, 0), (6817, 0, 414), (6817, 0, 638), (6817, 1, 0), (6818, 1, 0), (6819, 1, 0),
+    (6820, 0, 1074), (6820, 1, 0), (6821, 0, 455), (6821, 1, 0), (6822, 0, 1327), (6822, 0, 1329),
+    (6822, 1, 0), (6823, 1, 0), (6824, 0, 456), (6824, 0, 798), (6824, 0, 1334), (6824, 0, 1335),
+    (6824, 1, 0), (6825, 0, 263), (6825, 1, 0), (6826, 1, 0), (6827, 1, 0), (6828, 0, 20),
+    (6828, 1, 0), (6829, 0, 1341), (6829, 1, 0), (6830, 1, 0), (6831, 1, 0), (6832, 0, 1345),
+    (6832, 1, 0), (6833, 1, 0), (6834, 1, 0), (6835, 0, 476), (6835, 0, 1349), (6835, 0, 1351),
+    (6835, 0, 1352), (6835, 1, 0), (6836, 1, 0), (6837, 0, 225), (6837, 1, 0), (6838, 1, 0),
+    (6839, 1, 0), (6840, 0, 1357), (6840, 0, 1360), (6840, 1, 0), (6841, 1, 0), (6842, 2, 0),
+    (6844, 0, 443), (6844, 1, 0), (6845, 0, 448), (6845, 1, 0), (6846, 0, 444), (6846, 1, 0),
+    (6847, 1, 0), (6848, 0, 446), (6848, 1, 0), (6849, 1, 0), (6850, 1, 0), (6851, 1, 0),
+    (6852, 0, 400), (6852, 1, 0), (6853, 1, 0), (6854, 1, 0), (6855, 0, 629), (6855, 1, 0),
+    (6856, 0, 1087), (6856, 1, 0), (6857, 1, 0), (6858, 1, 0), (6859, 0, 1376), (6859, 0, 1377),
+    (6859, 1, 0), (6860, 1, 0), (6861, 3, 0), (6864, 1, 0), (6865, 1, 0), (6866, 1, 0),
+    (6867, 1, 0), (6868, 1, 0), (6869, 1, 0), (6870, 1, 0), (6871, 1, 0), (6872, 1, 0),
+    (6873, 1, 0), (6874, 1, 0), (6875, 1, 0), (6876, 1, 0), (6877, 1, 0), (6878, 1, 0),
+    (6879, 1, 0), (6880, 1, 0), (6881, 0, 1395), (6881, 0, 1396), (6881, 1, 0), (6882, 3, 0),
+    (6885, 1, 0), (6886, 1, 0), (6887, 1, 0), (6888, 0, 1401), (6888, 1, 0), (6889, 0, 262),
+    (6889, 1, 0), (6890, 1, 0), (6891, 0, 1403), (6891, 1, 0), (6892, 1, 0), (6893, 1, 0),
+    (6894, 1, 0), (6895, 1, 0), (6896, 1, 0), (6897, 0, 1411), (6897, 1, 0), (6898, 1, 0),
+    (6899, 1, 0), (6900, 1, 0), (6901, 1, 0), (6902, 0, 478), (6902, 1, 0), (6903, 0, 1413),
+    (6903, 0, 80), (6903, 0, 1414), (6903, 2, 0), (6905, 1, 0), (6906, 1, 0), (6907, 1, 0),
+    (6908, 1, 0), (6909, 1, 0), (6910, 0, 462), (6910, 1, 0), (6911, 1, 0), (6912, 1, 0),
+    (6913, 1, 0), (6914, 0, 1427), (6914, 1, 0), (6915, 1, 0), (6916, 0, 1432), (6916, 1, 0),
+    (6917, 0, 622), (6917, 1, 0), (6918, 1, 1435), (6919, 0, 1436), (6919, 1, 0), (6920, 0, 1438),
+    (6920, 0, 503), (6920, 1, 0), (6921, 0, 505), (6921, 1, 0), (6922, 1, 1443), (6923, 0, 244),
+    (6923, 0, 417), (6923, 0, 1444), (6923, 2, 0), (6925, 1, 0), (6926, 0, 1447), (6926, 0, 1448),
+    (6926, 0, 1449), (6926, 1, 0), (6927, 1, 0), (6928, 1, 0), (6929, 0, 499), (6929, 0, 510),
+    (6929, 1, 0), (6930, 1, 0), (6931, 2, 0), (6933, 1, 0), (6934, 0, 1455), (6934, 0, 1456),
+    (6934, 0, 1458), (6934, 1, 0), (6935, 0, 635), (6935, 0, 1452), (6935, 1, 0), (6936, 0, 1463),
+    (6936, 0, 94), (6936, 1, 0), (6937, 1, 0), (6938, 1, 0), (6939, 1, 0), (6940, 1, 0),
+    (6941, 0, 1233), (6941, 1, 0), (6942, 1, 0), (6943, 1, 0), (6944, 1, 0), (6945, 1, 0),
+    (6946, 1, 0), (6947, 1, 0), (6948, 0, 409), (6948, 1, 0), (6949, 0, 1471), (6949, 1, 0),
+    (6950, 1, 0), (6951, 0, 239), (6951, 0, 325), (6951, 0, 331), (6951, 0, 378), (6951, 0, 412),
+    (6951, 1, 0), (6952, 1, 0), (6953, 1, 0), (6954, 1, 0), (6955, 1, 0), (6956, 0, 1477),
+    (6956, 1, 0), (6957, 0, 574), (6957, 1, 0), (6958, 1, 0), (6959, 1, 0), (6960, 0, 624),
+    (6960, 0, 630), (6960, 0, 91), (6960, 0, 259), (6960, 0, 90), (6960, 0, 258), (6960, 0, 625),
+    (6960, 1, 0), (6961, 1, 0), (6962, 0, 92), (6962, 0, 260), (6962, 1, 0), (6963, 1, 0),
+    (6964, 0, 631), (6964, 1, 0), (6965, 1, 1489), (6966, 0, 1491), (6966, 1, 0), (6967, 1, 0),
+    (6968, 1, 0), (6969, 1, 0), (6970, 0, 3), (6970, 0, 4), (6970, 0, 8), (6970, 1, 0),
+    (6971, 0, 17), (6971, 0, 18), (6971, 1, 0), (6972, 0, 22), (6972, 1, 0), (6973, 1, 0),
+    (6974, 0, 30), (6974, 0, 33), (6974, 1, 0), (6975, 1, 0), (6976, 0, 37), (6976, 0, 38),
+    (6976, 1, 0), (6977, 1, 0), (6978, 1, 0), (6979, 1, 0), (6980, 1, 0), (6981, 1, 0),
+    (6982, 1, 0), (6983, 1, 0), (6984, 1, 0), (6985, 0, 53), (6985, 1, 0), (6986, 0, 55),
+    (6986, 1, 0), (6987, 1, 0), (6988, 1, 0), (6989, 0, 63), (6989, 1, 0), (6990, 0, 68),
+    (6990, 0, 71), (6990, 1, 0), (6991, 1, 0), (6992, 1, 0), (6993, 0, 85), (6993, 1, 0),
+    (6994, 1, 0), (6995, 1, 0), (6996, 2, 0), (6998, 1, 0), (6999, 1, 0), (7000, 1, 0),
+    (7001, 1, 0), (7002, 1, 0), (7003, 1, 0), (7004, 1, 0), (7005, 2, 0), (7007, 1, 0),
+    (7008, 0, 112), (7008, 0, 115), (7008, 0, 116), (7008, 0, 121), (7008, 1, 0), (7009, 1, 0),
+    (7010, 1, 0), (7011, 1, 0), (7012, 1, 0), (7013, 1, 0), (7014, 0, 136), (7014, 1, 0),
+    (7015, 1, 0), (7016, 1, 0), (7017, 0, 143), (7017, 1, 0), (7018, 0, 148), (7018, 0, 149),
+    (7018, 0, 150), (7018, 6, 0), (7024, 0, 166), (7024, 1, 0), (7025, 1, 0), (7026, 0, 174),
+    (7026, 1, 0), (7027, 1, 0), (7028, 0, 179), (7028, 0, 184), (7028, 1, 0), (7029, 1, 0),
+    (7030, 1, 0), (7031, 1, 0), (7032, 1, 0), (7033, 0, 196), (7033, 0, 204), (7033, 0, 209),
+    (7033, 0, 216), (7033, 0, 217), (7033, 1, 0), (7034, 0, 221), (7034, 0, 222), (7034, 1, 0),
+    (7035, 1, 0), (7036, 1, 0), (7037, 1, 0), (7038, 1, 0), (7039, 1, 0), (7040, 1, 0),
+    (7041, 3, 0), (7044, 1, 0), (7045, 1, 0), (7046, 1, 0), (7047, 1, 0), (7048, 1, 0),
+    (7049, 1, 0), (7050, 1, 0), (7051, 1, 0), (7052, 1, 0), (7053, 1, 0), (7054, 1, 0),
+    (7055, 1, 0), (7056, 1, 0), (7057, 1, 0), (7058, 0, 257), (7058, 1, 0), (7059, 1, 0),
+    (7060, 1, 0), (7061, 1, 0), (7062, 1, 0), (7063, 1, 0), (7064, 0, 265), (7064, 1, 0),
+    (7065, 1, 0), (7066, 1, 0), (7067, 0, 276), (7067, 0, 277), (7067, 0, 278), (7067, 1, 0),
+    (7068, 1, 0), (7069, 1, 0), (7070, 1, 0), (7071, 1, 0), (7072, 1, 0), (7073, 1, 0),
+    (7074, 1, 0), (7075, 1, 0), (7076, 1, 0), (7077, 1, 0), (7078, 1, 0), (7079, 1, 0),
+    (7080, 2, 0), (7082, 1, 0), (7083, 6, 0), (7089, 1, 0), (7090, 1, 0), (7091, 1, 0),
+    (7092, 1, 0), (7093, 1, 0), (7094, 1, 0), (7095, 1, 0), (7096, 1, 0), (7097, 1, 0),
+    (7098, 1, 0), (7099, 0, 339), (7099, 0, 342), (7099, 0, 345), (7099, 0, 347), (7099, 1, 0),
+    (7100, 1, 0), (7101, 0, 356), (7101, 0, 357), (7101, 0, 358), (7101, 1, 0), (7102, 1, 0),
+    (7103, 1, 0), (7104, 1, 0), (7105, 1, 0), (7106, 1, 0), (7107, 1, 0), (7108, 1, 0),
+    (7109, 1, 0), (7110, 0, 387), (7110, 0, 390), (7110, 0, 391), (7110, 0, 392), (7110, 2, 0),
+    (7112, 1, 0), (7113, 1, 0), (7114, 1, 0), (7115, 2, 0), (7117, 1, 0), (7118, 1, 0),
+    (7119, 1, 0), (7120, 3, 0), (7123, 1, 0), (7124, 1, 0), (7125, 1, 0), (7126, 1, 0),
+    (7127, 1, 0), (7128, 0, 427), (7128, 0, 429), (7128, 0, 430), (7128, 0, 432), (7128, 0, 433),
+    (7128, 1, 0), (7129, 1, 0), (7130, 1, 0), (7131, 1, 0), (7132, 1, 0), (7133, 0, 442),
+    (7133, 1, 0), (7134, 1, 0), (7135, 1, 0), (7136, 0, 451), (7136, 1, 0), (7137, 1, 0),
+    (7138, 1, 0), (7139, 1, 0), (7140, 0, 458), (7140, 0, 467), (7140, 0, 468), (7140, 1, 0),
+    (7141, 1, 0), (7142, 1, 0), (7143, 1, 0), (7144, 1, 0), (7145, 1, 0), (7146, 1, 0),
+    (7147, 0, 482), (7147, 0, 483), (7147, 1, 0), (7148, 0, 487), (7148, 0, 490), (7148, 0, 492),
+    (7148, 1, 0), (7149, 1, 0), (7150, 1, 0), (7151, 1, 0), (7152, 3, 0), (7155, 1, 0),
+    (7156, 1, 0), (7157, 1, 0), (7158, 1, 0), (7159, 1, 0), (7160, 1, 0), (7161, 1, 0),
+    (7162, 1, 0), (7163, 0, 514), (7163, 0, 520), (7163, 0, 522), (7163, 1, 0), (7164, 1, 0),
+    (7165, 0, 531), (7165, 0, 544), (7165, 0, 552), (7165, 0, 553), (7165, 1, 0), (7166, 0, 560),
+    (7166, 0, 561), (7166, 0, 569), (7166, 1, 0), (7167, 0, 575), (7167, 1, 0), (7168, 0, 581),
+    (7168, 8, 0), (7176, 2, 0), (7178, 0, 593), (7178, 1, 0), (7179, 0, 598), (7179, 0, 478),
+    (7179, 1, 0), (7180, 1, 0), (7181, 0, 605), (7181, 1, 0), (7182, 1, 0), (7183, 1, 0),
+    (7184, 1, 0), (7185, 2, 0), (7187, 0, 614), (7187, 0, 615), (7187, 1, 0), (7188, 1, 0),
+    (7189, 0, 24), (7189, 1, 0), (7190, 1, 0), (7191, 0, 25), (7191, 1, 0), (7192, 0, 189),
+    (7192, 1, 0), (7193, 0, 498), (7193, 1, 0), (7194, 1, 0), (7195, 1, 0), (7196, 1, 0),
+    (7197, 1, 0), (7198, 1, 0), (7199, 1, 0), (7200, 0, 521), (7200, 1, 0), (7201, 0, 633),
+    (7201, 1, 0), (7202, 1, 0), (7203, 1, 0), (7204, 1, 0), (7205, 0, 506), (7205, 0, 648),
+    (7205, 0, 669), (7205, 1, 0), (7206, 1, 0), (7207, 1, 0), (7208, 0, 611), (7208, 0, 692),
+    (7208, 1, 0), (7209, 0, 698), (7209, 0, 175), (7209, 0, 700), (7209, 0, 702), (7209, 1, 0),
+    (7210, 0, 704), (7210, 0, 705), (7210, 0, 706), (7210, 0, 710), (7210, 0, 711), (7210, 1, 0),
+    (7211, 1, 0), (7212, 1, 0), (7213, 1, 0), (7214, 0, 725), (7214, 1, 0), (7215, 5, 0),
+    (7220, 1, 0), (7221, 0, 733), (7221, 1, 0), (7222, 1, 0), (7223, 0, 17), (7223, 1, 0),
+    (7224, 0, 738), (7224, 0, 439), (7224, 1, 0), (7225, 1, 0), (7226, 1, 0), (7227, 0, 56),
+    (7227, 0, 58), (7227, 0, 742), (7227, 1, 0), (7228, 1, 0), (7229, 0, 755), (7229, 1, 0),
+    (7230, 1, 0), (7231, 0, 759), (7231, 0, 760), (7231, 0, 761), (7231, 0, 764), (7231, 1, 0),
+    (7232, 2, 0), (7234, 1, 0), (7235, 1, 0), (7236, 0, 768), (7236, 1, 0), (7237, 1, 0),
+    (7238, 0, 770), (7238, 0, 772), (7238, 0, 773), (7238, 1, 0), (7239, 0, 776), (7239, 1, 0),
+    (7240, 1, 0), (7241, 1, 0), (7242, 0, 783), (7242, 2, 0), (7244, 1, 0), (7245, 0, 788),
+    (7245, 1, 0), (7246, 0, 789), (7246, 0, 791), (7246, 0, 792), (7246, 0, 793), (7246, 1, 0),
+    (7247, 1, 0), (7248, 1, 0), (7249, 1, 0), (7250, 1, 0), (7251, 1, 0), (7252, 1, 0),
+    (7253, 1, 0), (7254, 1, 0), (7255, 0, 799), (7255, 0, 800), (7255, 0, 804), (7255, 1, 0),
+    (7256, 1, 0), (7257, 0, 810), (7257, 0, 811), (7257, 0, 812), (7257, 0, 815), (7257, 0, 821),
+    (7257, 0, 823), (7257, 1, 0), (7258, 0, 828), (7258, 1, 0), (7259, 0, 830), (7259, 0, 831),
+    (7259, 0, 832), (7259, 0, 839), (7259, 1, 0), (7260, 0, 813), (7260, 1, 0), (7261, 2, 0),
+    (7263, 0, 843), (7263, 0, 844), (7263, 1, 0), (7264, 1, 0), (7265, 1, 0), (7266, 1, 0),
+    (7267, 1, 0), (7268, 0, 856), (7268, 0, 857), (7268, 0, 859), (7268, 0, 143), (7268, 1, 0),
+    (7269, 0, 871), (7269, 0, 872), (7269, 0, 873), (7269, 0, 874), (7269, 0, 875), (7269, 0, 876),
+    (7269, 0, 877), (7269, 0, 878), (7269, 0, 879), (7269, 0, 880), (7269, 0, 881), (7269, 0, 882),
+    (7269, 0, 883), (7269, 0, 884), (7269, 0, 885), (7269, 0, 890), (7269, 0, 786), (7269, 0, 893),
+    (7269, 1, 0), (7270, 0, 898), (7270, 2, 0), (7272, 0, 902), (7272, 1, 0), (7273, 0, 920),
+    (7273, 1, 0), (7274, 1, 0), (7275, 0, 922), (7275, 0, 919), (7275, 1, 0), (7276, 1, 0),
+    (7277, 1, 0), (7278, 0, 163), (7278, 1, 0), (7279, 0, 527), (7279, 0, 171), (7279, 0, 924),
+    (7279, 1, 0), (7280, 0, 929), (7280, 1, 0), (7281, 0, 930), (7281, 0, 931), (7281, 1, 0),
+    (7282, 1, 0), (7283, 0, 936), (7283, 1, 0), (7284, 1, 0), (7285, 0, 940), (7285, 0, 927),
+    (7285, 0, 942), (7285, 0, 943), (7285, 0, 774), (7285, 0, 944), (7285, 0, 950), (7285, 0, 951),
+    (7285, 0, 953), (7285, 1, 0), (7286, 1, 0), (7287, 0, 960), (7287, 1, 0), (7288, 0, 957),
+    (7288, 0, 963), (7288, 1, 0), (7289, 1, 0), (7290, 1, 0), (7291, 1, 0), (7292, 0, 970),
+    (7292, 1, 0), (7293, 0, 975), (7293, 0, 976), (7293, 0, 985), (7293, 0, 988), (7293, 0, 989),
+    (7293, 0, 992), (7293, 0, 997), (7293, 0, 1001), (7293, 1, 0), (7294, 0, 219), (7294, 0, 1003),
+    (7294, 0, 224), (7294, 1, 0), (7295, 0, 1008), (7295, 0, 937), (7295, 0, 1009), (7295, 0, 1010),
+    (7295, 1, 0), (7296, 0, 1012), (7296, 0, 1014), (7296, 0, 1019), (7296, 0, 1020), (7296, 1, 0),
+    (7297, 1, 0), (7298, 0, 1024), (7298, 0, 1025), (7298, 0, 618), (7298, 1, 0), (7299, 1, 0),
+    (7300, 1, 0), (7301, 1, 0), (7302, 1, 0), (7303, 1, 0), (7304, 1, 0), (7305, 1, 0),
+    (7306, 0, 1035), (7306, 2, 0), (7308, 0, 1039), (7308, 1, 0), (7309, 1, 0), (7310, 1, 0),
+    (7311, 1, 0), (7312, 1, 0), (7313, 1, 0), (7314, 0, 1041), (7314, 0, 233), (7314, 0, 1044),
+    (7314, 1, 0), (7315, 0, 1047), (7315, 0, 1049), (7315, 0, 1050), (7315, 1, 0), (7316, 1, 0),
+    (7317, 1, 0), (7318, 1, 0), (7319, 1, 0), (7320, 1, 0), (7321, 0, 1061), (7321, 1, 0),
+    (7322, 0, 1063), (7322, 0, 494), (7322, 1, 0), (7323, 0, 1066), (7323, 1, 0), (7324, 0, 1067),
+    (7324, 0, 1070), (7324, 0, 1074), (7324, 0, 1075), (7324, 0, 1033), (7324, 0, 1078), (7324, 0, 1079),
+    (7324, 1, 0), (7325, 0, 1081), (7325, 1, 0), (7326, 1, 0), (7327, 1, 0), (7328, 1, 0),
+    (7329, 0, 410), (7329, 1, 0), (7330, 1, 0), (7331, 1, 0), (7332, 0, 1090), (7332, 0, 1091),
+    (7332, 1, 0), (7333, 0, 604), (7333, 0, 1097), (7333, 0, 43), (7333, 0, 678), (7333, 0, 796),
+    (7333, 1, 0), (7334, 0, 272), (7334, 0, 1101), (7334, 0, 562), (7334, 1, 0), (7335, 1, 0),
+    (7336, 1, 0), (7337, 1, 0), (7338, 0, 1113), (7338, 0, 1114), (7338, 0, 1115), (7338, 1, 0),
+    (7339, 2, 0), (7341, 0, 302), (7341, 0, 1122), (7341, 0, 1123), (7341, 1, 0), (7342, 0, 1129),
+    (7342, 1, 0), (7343, 0, 287), (7343, 0, 1131), (7343, 0, 293), (7343, 1, 0), (7344, 1, 0),
+    (7345, 1, 0), (7346, 1, 0), (7347, 1, 0), (7348, 0, 305), (7348, 0, 1143), (7348, 1, 0),
+    (7349, 1, 0), (7350, 1, 0), (7351, 1, 0), (7352, 1, 0), (7353, 1, 0), (7354, 1, 0),
+    (7355, 1, 0), (7356, 0, 1149), (7356, 1, 0), (7357, 0, 316), (7357, 1, 0), (7358, 0, 1153),
+    (7358, 0, 1154), (7358, 1, 0), (7359, 0, 320), (7359, 0, 329), (7359, 1, 0), (7360, 1, 0),
+    (7361, 0, 334), (7361, 1, 0), (7362, 1, 0), (7363, 2, 0), (7365, 1, 0), (7366, 2, 0),
+    (7368, 0, 1160), (7368, 1, 0), (7369, 0, 1163), (7369, 0, 1165), (7369, 0, 1166), (7369, 0, 1168),
+    (7369, 1, 0), (7370, 0, 1172), (7370, 1, 0), (7371, 0, 1176), (7371, 1, 0), (7372, 0, 1180),
+    (7372, 1, 0), (7373, 0, 1181), (7373, 0, 1182), (7373, 0, 1185), (7373, 0, 1187), (7373, 0, 1192),
+    (7373, 1, 0), (7374, 1, 0), (7375, 0, 46), (7375, 1, 0), (7376, 0, 1210), (7376, 1, 0),
+    (7377, 0, 1214), (7377, 0, 1216), (7377, 0, 48), (7377, 1, 0), (7378, 1, 0), (7379, 0, 1221),
+    (7379, 0, 1224), (7379, 0, 1225), (7379, 0, 1226), (7379, 1, 0), (7380, 0, 270), (7380, 1, 0),
+    (7381, 0, 927), (7381, 1, 0), (7382, 0, 927), (7382, 1, 0), (7383, 1, 0), (7384, 0, 797),
+    (7384, 0, 1238), (7384, 0, 369), (7384, 1, 0), (7385, 1, 0), (7386, 1, 0), (7387, 1, 0),
+    (7388, 1, 0), (7389, 0, 373), (7389, 1, 0), (7390, 1, 0), (7391, 1, 0), (7392, 1, 0),
+    (7393, 0, 370), (7393, 0, 1249), (7393, 1, 0), (7394, 1, 0), (7395, 1, 0), (7396, 0, 378),
+    (7396, 0, 1254), (7396, 0, 1257), (7396, 0, 1260), (7396, 1, 0), (7397, 1, 0), (7398, 1, 0),
+    (7399, 0, 1264), (7399, 0, 1267), (7399, 1, 0), (7400, 0, 399), (7400, 0, 1272), (7400, 1, 0),
+    (7401, 0, 1275), (7401, 0, 938), (7401, 0, 1058), (7401, 0, 1276), (7401, 1, 0), (7402, 0, 1278),
+    (7402, 0, 1280), (7402, 1, 0), (7403, 0, 1283), (7403, 0, 1284), (7403, 1, 0), (7404, 1, 0),
+    (7405, 0, 1288), (7405, 0, 1289), (7405, 1, 0), (7406, 0, 50), (7406, 1, 0), (7407, 1, 0),
+    (7408, 0, 1295), (7408, 0, 408), (7408, 0, 1297), (7408, 1, 0), (7409, 1, 0), (7410, 1, 0),
+    (7411, 1, 0), (7412, 1, 0), (7413, 1, 0), (7414, 1, 0), (7415, 0, 1304), (7415, 1, 0),
+    (7416, 0, 1310), (7416, 1, 0), (7417, 0, 1313), (7417, 1, 0), (7418, 0, 1315), (7418, 0, 51),
+    (7418, 0, 1301), (7418, 0, 1317), (7418, 1, 0), (7419, 1, 0), (7420, 0, 1322), (7420, 0, 1325),
+    (7420, 0, 1326), (7420, 0, 1330), (7420, 1, 0), (7421, 0, 933), (7421, 1, 0), (7422, 0, 1338),
+    (7422, 1, 0), (7423, 0, 887), (7423, 0, 1342), (7423, 1, 0), (7424, 1, 0), (7425, 0, 1346),
+    (7425, 0, 1346), (7425, 0, 1347), (7425, 1, 0), (7426, 1, 0), (7427, 1, 0), (7428, 0, 1355),
+    (7428, 1, 0), (7429, 0, 1361), (7429, 0, 1364), (7429, 0, 1366), (7429, 1, 0), (7430, 0, 1367),
+    (7430, 0, 1368), (7430, 0, 445), (7430, 1, 0), (7431, 0, 447), (7431, 1, 0), (7432, 0, 442),
+    (7432, 0, 141), (7432, 0, 20), (7432, 0, 1357), (7432, 0, 450), (7432, 1, 0), (7433, 0, 1373),
+    (7433, 1, 0), (7434, 1, 0), (7435, 1, 0), (7436, 1, 0), (7437, 0, 1371), (7437, 1, 0),
+    (7438, 1, 0), (7439, 0, 1380), (7439, 0, 1381), (7439, 0, 1382), (7439, 1, 0), (7440, 1, 0),
+    (7441, 0, 454), (7441, 1, 0), (7442, 1, 0), (7443, 1, 0), (7444, 1, 0), (7445, 0, 1388),
+    (7445, 1, 0), (7446, 1, 0), (7447, 1, 0), (7448, 1, 0), (7449, 1, 0), (7450, 1, 0),
+    (7451, 1, 0), (7452, 0, 459), (7452, 1, 0), (7453, 1, 0), (7454, 0, 1398), (7454, 0, 1399),
+    (7454, 0, 1400), (7454, 0, 934), (7454, 1, 0), (7455, 0, 1402), (7455, 0, 1404), (7455, 0, 1406),
+    (7455, 0, 1407), (7455, 0, 1409), (7455, 0, 471), (7455, 1, 0), (7456, 1, 0), (7457, 0, 1412),
+    (7457, 1, 0), (7458, 1, 0), (7459, 0, 474), (7459, 0, 475), (7459, 0, 680), (7459, 1, 0),
+    (7460, 0, 1416), (7460, 0, 1417), (7460, 0, 1418), (7460, 1, 0), (7461, 0, 1421), (7461, 1, 0),
+    (7462, 0, 1424), (7462, 1, 0), (7463, 1, 0), (7464, 1, 0), (7465, 1, 0), (7466, 0, 1433),
+    (7466, 2, 0), (7468, 0, 1435), (7468, 0, 1437), (7468, 0, 1440), (7468, 0, 1441), (7468, 0, 1443),
+    (7468, 0, 1445), (7468, 1, 0), (7469, 0, 1446), (7469, 1, 0), (7470, 1, 0), (7471, 1, 0),
+    (7472, 1, 0), (7473, 1, 0), (7474, 0, 1453), (7474, 1, 0), (7475, 0, 1454), (7475, 0, 1459),
+    (7475, 1, 0), (7476, 0, 1464), (7476, 1, 0), (7477, 1, 0), (7478, 1, 0), (7479, 0, 1230),
+    (7479, 1, 0), (7480, 0, 1299), (7480, 1, 0), (7481, 1, 0), (7482, 1, 0), (7483, 1, 0),
+    (7484, 1, 0), (7485, 0, 1470), (7485, 0, 1472), (7485, 0, 524), (7485, 0, 1466), (7485, 0, 1465),
+    (7485, 0, 1468), (7485, 0, 1467), (7485, 1, 0), (7486, 0, 1478), (7486, 0, 1479), (7486, 0, 1480),
+    (7486, 0, 526), (7486, 0, 626), (7486, 0, 627), (7486, 0, 628), (7486, 0, 632), (7486, 0, 533),
+    (7486, 0, 1489), (7486, 0, 1500), (7486, 0, 1501), (7486, 0, 557), (7486, 1, 0), (7487, 1, 0),
+    (7488, 1, 0), (7489, 0, 24), (7489, 1, 0), (7490, 1, 0), (7491, 0, 36), (7491, 0, 40),
+    (7491, 0, 42), (7491, 1, 0), (7492, 1, 0), (7493, 1, 0), (7494, 1, 0), (7495, 1, 0),
+    (7496, 1, 0), (7497, 1, 0), (7498, 1, 0), (7499, 1, 0), (7500, 1, 0), (7501, 1, 0),
+    (7502, 1, 0), (7503, 1, 0), (7504, 0, 81), (7504, 1, 0), (7505, 1, 0), (7506, 1, 0),
+    (7507, 2, 0), (7509, 1, 0), (7510, 1, 0), (7511, 1, 0), (7512, 2, 0), (7514, 1, 0),
+    (7515, 1, 0), (7516, 1, 0), (7517, 3, 0), (7520, 1, 0), (7521, 0, 109), (7521, 1, 0),
+    (7522, 1, 0), (7523, 0, 122), (7523, 1, 0), (7524, 1, 0), (7525, 0, 128), (7525, 1, 0),
+    (7526, 1, 0), (7527, 1, 0), (7528, 1, 0), (7529, 1, 0), (7530, 1, 0), (7531, 1, 0),
+    (7532, 1, 0), (7533, 1, 0), (7534, 1, 0), (7535, 1, 0), (7536, 1, 0), (7537, 1, 0),
+    (7538, 1, 0), (7539, 1, 0), (7540, 1, 0), (7541, 1, 0), (7542, 0, 93), (7542, 1, 0),
+    (7543, 1, 0), (7544, 1, 0), (7545, 1, 0), (7546, 1, 0), (7547, 1, 0), (7548, 1, 0),
+    (7549, 1, 0), (7550, 2, 0), (7552, 1, 0), (7553, 1, 0), (7554, 0, 236), (7554, 1, 0),
+    (7555, 1, 0), (7556, 1, 0), (7557, 1, 0), (7558, 1, 0), (7559, 1, 0), (7560, 1, 0),
+    (7561, 1, 0), (7562, 1, 0), (7563, 1, 0), (7564, 1, 0), (7565, 1, 0), (7566, 1, 0),
+    (7567, 1, 0), (7568, 1, 0), (7569, 1, 0), (7570, 2, 0), (7572, 1, 0), (7573, 2, 0),
+    (7575, 1, 0), (7576, 1, 0), (7577, 1, 0), (7578, 1, 0), (7579, 1, 0), (7580, 1, 0),
+    (7581, 3, 0), (7584, 1, 0), (7585, 1, 0), (7586, 0, 281), (7586, 0, 283), (7586, 1, 0),
+    (7587, 1, 0), (7588, 1, 0), (7589, 1, 0), (7590, 1, 0), (7591, 2, 0), (7593, 1, 0),
+    (7594, 1, 0), (7595, 1, 0), (7596, 1, 0), (7597, 1, 0), (7598, 0, 306), (7598, 1, 0),
+    (7599, 1, 0), (7600, 1, 0), (7601, 1, 0), (7602, 1, 0), (7603, 1, 0), (7604, 1, 0),
+    (7605, 1, 0), (7606, 1, 0), (7607, 1, 0), (7608, 1, 0), (7609, 1, 0), (7610, 1, 0),
+    (7611, 4, 0), (7615, 1, 0), (7616, 0, 350), (7616, 1, 0), (7617, 0, 360), (7617, 2, 0),
+    (7619, 1, 0), (7620, 1, 0), (7621, 1, 0), (7622, 1, 0), (7623, 4, 0), (7627, 0, 377),
+    (7627, 1, 0), (7628, 2, 0), (7630, 1, 0), (7631, 1, 0), (7632, 1, 0), (7633, 1, 0),
+    (7634, 1, 0), (7635, 1, 0), (7636, 1, 0), (7637, 3, 0), (7640, 1, 0), (7641, 1, 0),
+    (7642, 1, 0), (7643, 1, 0), (7644, 1, 0), (7645, 1, 0), (7646, 1, 0), (7647, 1, 0),
+    (7648, 1, 0), (7649, 1, 0), (7650, 1, 0), (7651, 1, 0), (7652, 1, 0), (7653, 1, 0),
+    (7654, 1, 0), (7655, 2, 0), (7657, 1, 0), (7658, 1, 0), (7659, 4, 0), (7663, 1, 0),
+    (7664, 2, 0), (7666, 1, 0), (7667, 1, 0), (7668, 1, 0), (7669, 1, 0), (7670, 1, 0),
+    (7671, 1, 0), (7672, 1, 0), (7673, 1, 0), (7674, 1, 0), (7675, 1, 0), (7676, 1, 0),
+    (7677, 1, 0), (7678, 0, 437), (7678, 1, 0), (7679, 1, 0), (7680, 1, 0), (7681, 1, 0),
+    (7682, 1, 0), (7683, 0, 95), (7683, 1, 0), (7684, 1, 0), (7685, 1, 0), (7686, 0, 511),
+    (7686, 4, 0), (7690, 1, 0), (7691, 1, 0), (7692, 0, 570), (7692, 1, 0), (7693, 1, 0),
+    (7694, 1, 0), (7695, 1, 0), (7696, 1, 0), (7697, 1, 0), (7698, 1, 0), (7699, 1, 0),
+    (7700, 1, 0), (7701, 0, 591), (7701, 1, 0), (7702, 0, 594), (7702, 1, 0), (7703, 0, 63),
+    (7703, 1, 0), (7704, 1, 0), (7705, 1, 0), (7706, 1, 0), (7707, 0, 612), (7707, 1, 0),
+    (7708, 1, 0), (7709, 1, 0), (7710, 0, 24), (7710, 0, 619), (7710, 0, 622), (7710, 0, 624),
+    (7710, 0, 625), (7710, 1, 0), (7711, 1, 0), (7712, 1, 0), (7713, 0, 629), (7713, 1, 0),
+    (7714, 1, 0), (7715, 1, 0), (7716, 1, 0), (7717, 1, 0), (7718, 1, 0), (7719, 0, 645),
+    (7719, 1, 0), (7720, 0, 679), (7720, 1, 0), (7721, 1, 0), (7722, 1, 0), (7723, 0, 714),
+    (7723, 0, 716), (7723, 1, 0), (7724, 1, 0), (7725, 1, 0), (7726, 1, 0), (7727, 1, 0),
+    (7728, 1, 0), (7729, 1, 0), (7730, 1, 0), (7731, 1, 0), (7732, 0, 734), (7732, 1, 0),
+    (7733, 0, 17), (7733, 1, 0), (7734, 1, 0), (7735, 0, 740), (7735, 0, 751), (7735, 0, 752),
+    (7735, 0, 756), (7735, 1, 0), (7736, 0, 765), (7736, 1, 0), (7737, 1, 0), (7738, 1, 0),
+    (7739, 1, 0), (7740, 1, 0), (7741, 1, 0), (7742, 0, 775), (7742, 0, 68), (7742, 0, 779),
+    (7742, 0, 782), (7742, 0, 81), (7742, 1, 0), (7743, 0, 786), (7743, 1, 0), (7744, 1, 0),
+    (7745, 1, 0), (7746, 0, 797), (7746, 1, 0), (7747, 1, 0), (7748, 1, 0), (7749, 1, 0),
+    (7750, 1, 0), (7751, 1, 0), (7752, 0, 807), (7752, 1, 0), (7753, 1, 0), (7754, 1, 0),
+    (7755, 0, 841), (7755, 0, 815), (7755, 1, 0), (7756, 1, 0), (7757, 0, 845), (7757, 1, 0),
+    (7758, 1, 0), (7759, 1, 0), (7760, 1, 0), (7761, 1, 0), (7762, 1, 0), (7763, 0, 899),
+    (7763, 1, 0), (7764, 1, 0), (7765, 0, 921), (7765, 1, 0), (7766, 1, 0), (7767, 1, 0),
+    (7768, 0, 161), (7768, 1, 0), (7769, 0, 925), (7769, 1, 0), (7770, 1, 0), (7771, 1, 0),
+    (7772, 1, 0), (7773, 1, 0), (7774, 1, 0), (7775, 1, 0), (7776, 1, 0), (7777, 1, 0),
+    (7778, 1, 0), (7779, 1, 0), (7780, 0, 965), (7780, 0, 973), (7780, 1, 0), (7781, 0, 1007),
+    (7781, 0, 1011), (7781, 0, 1022), (7781, 0, 1023), (7781, 0, 1028), (7781, 1, 0), (7782, 1, 0),
+    (7783, 1, 0), (7784, 1, 0), (7785, 1, 0), (7786, 1, 0), (7787, 1, 0), (7788, 0, 1036),
+    (7788, 1, 0), (7789, 1, 0), (7790, 0, 1040), (7790, 1, 0), (7791, 1, 0), (7792, 0, 250),
+    (7792, 0, 253), (7792, 1, 0), (7793, 1, 0), (7794, 1, 0), (7795, 2, 0), (7797, 1, 0),
+    (7798, 1, 0), (7799, 1, 0), (7800, 0, 1062), (7800, 0, 1064), (7800, 1, 0), (7801, 0, 1080),
+    (7801, 1, 0), (7802, 0, 1084), (7802, 1, 0), (7803, 0, 1089), (7803, 1, 0), (7804, 1, 0),
+    (7805, 1, 0), (7806, 1, 0), (7807, 0, 1099), (7807, 1, 0), (7808, 1, 0), (7809, 1, 0),
+    (7810, 1, 0), (7811, 0, 336), (7811, 0, 1120), (7811, 1, 0), (7812, 1, 0), (7813, 0, 509),
+    (7813, 0, 293), (7813, 1, 0), (7814, 1, 0), (7815, 1, 0), (7816, 1, 0), (7817, 1, 0),
+    (7818, 0, 290), (7818, 0, 1145), (7818, 0, 1146), (7818, 0, 317), (7818, 0, 1147), (7818, 0, 1148),
+    (7818, 1, 0), (7819, 0, 1151), (7819, 0, 315), (7819, 1, 0), (7820, 1, 0), (7821, 1, 0),
+    (7822, 0, 322), (7822, 0, 324), (7822, 0, 325), (7822, 1, 0), (7823, 0, 328), (7823, 0, 331),
+    (7823, 1, 0), (7824, 1, 0), (7825, 0, 1171), (7825, 0, 1175), (7825, 0, 1177), (7825, 0, 508),
+    (7825, 0, 1196), (7825, 0, 1200), (7825, 0, 1207), (7825, 0, 1212), (7825, 1, 0), (7826, 1, 0),
+    (7827, 0, 1227), (7827, 1, 0), (7828, 0, 1234), (7828, 1, 0), (7829, 0, 1237), (7829, 0, 1240),
+    (7829, 0, 1241), (7829, 1, 0), (7830, 1, 0), (7831, 1, 0), (7832, 1, 0), (7833, 1, 0),
+    (7834, 1, 0), (7835, 0, 375), (7835, 1, 0), (7836, 1, 0), (7837, 1, 0), (7838, 1, 0),
+    (7839, 0, 1262), (7839, 0, 844), (7839, 1, 0), (7840, 0, 1273), (7840, 0, 1277), (7840, 1, 0),
+    (7841, 0, 1286), (7841, 0, 1287), (7841, 0, 1292), (7841, 0, 424), (7841, 1, 0), (7842, 1, 0),
+    (7843, 1, 0), (7844, 1, 0), (7845, 1, 0), (7846, 1, 0), (7847, 1, 0), (7848, 1, 0),
+    (7849, 1, 0), (7850, 0, 1311), (7850, 1, 0), (7851, 1, 0), (7852, 0, 1320), (7852, 1, 0),
+    (7853, 0, 263), (7853, 1, 0), (7854, 1, 0), (7855, 1, 0), (7856, 0, 1353), (7856, 0, 1354),
+    (7856, 1, 0), (7857, 1, 0), (7858, 1, 0), (7859, 2, 0), (7861, 2, 0), (7863, 2, 0),
+    (7865, 0, 1374), (7865, 0, 1375), (7865, 0, 1378), (7865, 0, 1379), (7865, 2, 0), (7867, 1, 0),
+    (7868, 1, 0), (7869, 1, 0), (7870, 1, 0), (7871, 1, 0), (7872, 1, 0), (7873, 0, 456),
+    (7873, 0, 1389), (7873, 0, 1390), (7873, 0, 1391), (7873, 0, 1392), (7873, 0, 1393), (7873, 0, 1394),
+    (7873, 0, 1397), (7873, 2, 0), (7875, 1, 0), (7876, 0, 262), (7876, 1, 0), (7877, 1, 0),
+    (7878, 1, 0), (7879, 1, 0), (7880, 1, 0), (7881, 0, 1420), (7881, 5, 0), (7886, 1, 0),
+    (7887, 0, 1426), (7887, 0, 1428), (7887, 1, 0), (7888, 1, 0), (7889, 1, 0), (7890, 1, 0),
+    (7891, 0, 437), (7891, 1, 0), (7892, 1, 0), (7893, 0, 1451), (7893, 1, 0), (7894, 1, 0),
+    (7895, 0, 1461), (7895, 1, 0), (7896, 1, 0), (7897, 1, 0), (7898, 1, 0), (7899, 1, 0),
+    (7900, 1, 0), (7901, 1, 0), (7902, 1, 0), (7903, 1, 0), (7904, 0, 1476), (7904, 0, 1507),
+    (7904, 1, 0), (7905, 1, 0), (7906, 1, 0), (7907, 1, 0), (7908, 1, 0), (7909, 1, 0),
+    (7910, 1, 0), (7911, 1, 0), (7912, 1, 0), (7913, 1, 0), (7914, 1, 0), (7915, 1, 0),
+    (7916, 1, 0), (7917, 1, 0), (7918, 1, 0), (7919, 0, 64), (7919, 1, 0), (7920, 1, 0),
+    (7921, 0, 86), (7921, 1, 0), (7922, 1, 0), (7923, 1, 0), (7924, 1, 0), (7925, 1, 0),
+    (7926, 1, 0), (7927, 1, 0), (7928, 1, 0), (7929, 1, 0), (7930, 3, 0), (7933, 1, 0),
+    (7934, 1, 0), (7935, 1, 0), (7936, 1, 0), (7937, 2, 0), (7939, 1, 0), (7940, 1, 0),
+    (7941, 1, 0), (7942, 1, 0), (7943, 1, 0), (7944, 1, 0), (7945, 1, 0), (7946, 1, 0),
+    (7947, 1, 0), (7948, 1, 0), (7949, 1, 0), (7950, 1, 0), (7951, 1, 0), (7952, 1, 0),
+    (7953, 1, 0), (7954, 1, 0), (7955, 1, 0), (7956, 1, 0), (7957, 1, 0), (7958, 1, 0),
+    (7959, 1, 0), (7960, 0, 188), (7960, 1, 0), (7961, 2, 0), (7963, 1, 0), (7964, 1, 0),
+    (7965, 3, 0), (7968, 1, 0), (7969, 1, 0), (7970, 1, 0), (7971, 1, 0), (7972, 1, 0),
+    (7973, 2, 0), (7975, 1, 0), (7976, 1, 0), (7977, 1, 0), (7978, 1, 0), (7979, 1, 0),
+    (7980, 1, 0), (7981, 1, 0), (7982, 1, 0), (7983, 1, 0), (7984, 1, 0), (7985, 1, 0),
+    (7986, 1, 0), (7987, 0, 251), (7987, 1, 0), (7988, 1, 0), (7989, 1, 0), (7990, 1, 0),
+    (7991, 1, 0), (7992, 1, 0), (7993, 1, 0), (7994, 1, 0), (7995, 1, 0), (7996, 1, 0),
+    (7997, 1, 0), (7998, 1, 0), (7999, 1, 0), (8000, 1, 0), (8001, 1, 0), (8002, 1, 0),
+    (8003, 1, 0), (8004, 1, 0), (8005, 1, 0), (8006, 1, 0), (8007, 1, 0), (8008, 1, 0),
+    (8009, 1, 0), (8010, 1, 0), (8011, 0, 291), (8011, 1, 0), (8012, 1, 0), (8013, 1, 0),
+    (8014, 1, 0), (8015, 1, 0), (8016, 1, 0), (8017, 1, 0), (8018, 1, 0), (8019, 1, 0),
+    (8020, 1, 0), (8021, 1, 0), (8022, 2, 0), (8024, 1, 0), (8025, 1, 0), (8026, 1, 0),
+    (8027, 1, 0), (8028, 2, 0), (8030, 1, 0), (8031, 1, 0), (8032, 0, 333), (8032, 1, 0),
+    (8033, 1, 0), (8034, 1, 0), (8035, 1, 0), (8036, 2, 0), (8038, 1, 0), (8039, 1, 0),
+    (8040, 1, 0), (8041, 0, 364), (8041, 1, 0), (8042, 1, 0), (8043, 0, 372), (8043, 1, 0),
+    (8044, 1, 0), (8045, 1, 0), (8046, 1, 0), (8047, 1, 0), (8048, 1, 0), (8049, 1, 0),
+    (8050, 1, 0), (8051, 1, 0), (8052, 1, 0), (8053, 1, 0), (8054, 2, 0), (8056, 1, 0),
+    (8057, 0, 409), (8057, 1, 0), (8058, 1, 0), (8059, 1, 0), (8060, 1, 0), (8061, 1, 0),
+    (8062, 1, 0), (8063, 1, 0), (8064, 1, 0), (8065, 1, 0), (8066, 1, 0), (8067, 1, 0),
+    (8068, 1, 0), (8069, 1, 0), (8070, 1, 0), (8071, 1, 0), (8072, 1, 0), (8073, 1, 0),
+    (8074, 1, 0), (8075, 1, 0), (8076, 1, 0), (8077, 1, 0), (8078, 0, 453), (8078, 1, 0),
+    (8079, 1, 0), (8080, 1, 0), (8081, 0, 395), (8081, 0, 459), (8081, 1, 0), (8082, 1, 0),
+    (8083, 1, 0), (8084, 1, 0), (8085, 1, 0), (8086, 1, 0), (8087, 1, 0), (8088, 1, 0),
+    (8089, 0, 485), (8089, 0, 494), (8089, 2, 0), (8091, 1, 0), (8092, 1, 0), (8093, 1, 0),
+    (8094, 1, 0), (8095, 1, 0), (8096, 1, 0), (8097, 1, 0), (8098, 1, 0), (8099, 1, 0),
+    (8100, 1, 0), (8101, 1, 0), (8102, 1, 0), (8103, 1, 0), (8104, 1, 0), (8105, 1, 0),
+    (8106, 1, 0), (8107, 0, 577), (8107, 0, 582), (8107, 0, 583), (8107, 0, 584), (8107, 0, 585),
+    (8107, 0, 586), (8107, 0, 587), (8107, 0, 588), (8107, 0, 589), (8107, 0, 592), (8107, 0, 599),
+    (8107, 0, 59), (8107, 0, 609), (8107, 1, 0), (8108, 1, 0), (8109, 1, 0), (8110, 0, 615),
+    (8110, 0, 616), (8110, 0, 626), (8110, 1, 0), (8111, 0, 628), (8111, 1, 0), (8112, 0, 632),
+    (8112, 0, 533), (8112, 1, 0), (8113, 1, 0), (8114, 1, 0), (8115, 0, 678), (8115, 0, 680),
+    (8115, 0, 697), (8115, 0, 703), (8115, 1, 0), (8116, 1, 0), (8117, 1, 0), (8118, 0, 386),
+    (8118, 0, 728), (8118, 1, 0), (8119, 1, 0), (8120, 1, 0), (8121, 0, 732), (8121, 0, 735),
+    (8121, 1, 0), (8122, 1, 0), (8123, 0, 758), (8123, 1, 0), (8124, 1, 0), (8125, 0, 766),
+    (8125, 1, 0), (8126, 1, 0), (8127, 0, 49), (8127, 1, 0), (8128, 1, 0), (8129, 0, 795),
+    (8129, 0, 796), (8129, 1, 0), (8130, 1, 0), (8131, 1, 0), (8132, 1, 0), (8133, 1, 0),
+    (8134, 0, 385), (8134, 0, 809), (8134, 0, 825), (8134, 0, 830), (8134, 1, 0), (8135, 1, 0),
+    (8136, 0, 846), (8136, 1, 0), (8137, 1, 0), (8138, 1, 0), (8139, 0, 870), (8139, 0, 162),
+    (8139, 0, 900), (8139, 0, 910), (8139, 1, 0), (8140, 1, 0), (8141, 1, 0), (8142, 1, 0),
+    (8143, 1, 0), (8144, 0, 933), (8144, 0, 934), (8144, 1, 0), (8145, 1, 0), (8146, 0, 195),
+    (8146, 0, 183), (8146, 0, 962), (8146, 0, 558), (8146, 0, 963), (8146, 0, 964), (8146, 0, 1002),
+    (8146, 0, 1029), (8146, 2, 0), (8148, 1, 0), (8149, 1, 0), (8150, 3, 0), (8153, 1, 0),
+    (8154, 0, 252), (8154, 0, 1037), (8154, 1, 0), (8155, 1, 0), (8156, 1, 0), (8157, 0, 791),
+    (8157, 1, 0), (8158, 0, 1052), (8158, 1, 0), (8159, 1, 0), (8160, 1, 0), (8161, 1, 0),
+    (8162, 2, 0), (8164, 0, 799), (8164, 0, 1083), (8164, 1, 0), (8165, 1, 0), (8166, 1, 0),
+    (8167, 0, 507), (8167, 1, 0), (8168, 0, 1105), (8168, 1, 0), (8169, 1, 0), (8170, 1, 0),
+    (8171, 0, 285), (8171, 0, 1124), (8171, 1, 0), (8172, 1, 0), (8173, 1, 0), (8174, 1, 0),
+    (8175, 0, 1144), (8175, 1, 0), (8176, 1, 0), (8177, 1, 0), (8178, 1, 0), (8179, 2, 0),
+    (8181, 2, 0), (8183, 2, 0), (8185, 0, 1217), (8185, 0, 97), (8185, 1, 0), (8186, 0, 1236),
+    (8186, 0, 1242), (8186, 1, 0), (8187, 1, 0), (8188, 1, 0), (8189, 0, 1248), (8189, 0, 1249),
+    (8189, 0, 1251), (8189, 0, 1252), (8189, 0, 1253), (8189, 1, 0), (8190, 0, 1268), (8190, 1, 0),
+    (8191, 0, 394), (8191, 1, 0), (8192, 1, 0), (8193, 2, 0), (8195, 1, 0), (8196, 1, 0),
+    (8197, 1, 0), (8198, 1, 0), (8199, 1, 0), (8200, 0, 1314), (8200, 0, 1319), (8200, 0, 1331),
+    (8200, 0, 20), (8200, 0, 523), (8200, 1, 0), (8201, 1, 0), (8202, 0, 1356), (8202, 1, 0),
+    (8203, 0, 444), (8203, 1, 0), (8204, 0, 446), (8204, 1, 0), (8205, 1, 0), (8206, 1, 0),
+    (8207, 0, 452), (8207, 1, 0), (8208, 2, 0), (8210, 1, 0), (8211, 1, 0), (8212, 1, 0),
+    (8213, 0, 1328), (8213, 0, 1330), (8213, 0, 460), (8213, 1, 0), (8214, 2, 0), (8216, 1, 0),
+    (8217, 0, 1412), (8217, 1, 0), (8218, 0, 475), (8218, 0, 1415), (8218, 0, 1422), (8218, 1, 0),
+    (8219, 1, 0), (8220, 1, 0), (8221, 1, 0), (8222, 0, 1425), (8222, 0, 1429), (8222, 1, 0),
+    (8223, 1, 0), (8224, 0, 1445), (8224, 1, 0), (8225, 2, 0), (8227, 1, 0), (8228, 0, 1453),
+    (8228, 1, 0), (8229, 0, 988), (8229, 1, 0), (8230, 1, 0), (8231, 0, 1346), (8231, 1, 0),
+    (8232, 1, 0), (8233, 0, 1412), (8233, 1, 0), (8234, 1, 0), (8235, 0, 20), (8235, 1, 0),
+    (8236, 1, 0), (8237, 0, 43), (8237, 0, 45), (8237, 1, 0), (8238, 1, 0), (8239, 1, 0),
+    (8240, 1, 0), (8241, 2, 0), (8243, 0, 54), (8243, 1, 0), (8244, 0, 58), (8244, 1, 0),
+    (8245, 1, 0), (8246, 1, 0), (8247, 1, 0), (8248, 0, 84), (8248, 1, 0), (8249, 3, 0),
+    (8252, 2, 0), (8254, 1, 0), (8255, 1, 0), (8256, 1, 0), (8257, 1, 0), (8258, 0, 98),
+    (8258, 1, 0), (8259, 1, 0), (8260, 0, 101), (8260, 1, 0), (8261, 1, 0), (8262, 1, 0),
+    (8263, 1, 0), (8264, 1, 0), (8265, 1, 0), (8266, 0, 87), (8266, 1, 0), (8267, 1, 0),
+    (8268, 1, 0), (8269, 1, 0), (8270, 1, 0), (8271, 1, 0), (8272, 1, 0), (8273, 1, 0),
+    (8274, 1, 0), (8275, 1, 0), (8276, 1, 0), (8277, 1, 0), (8278, 1, 0), (8279, 1, 0),
+    (8280, 1, 0), (8281, 1, 0), (8282, 1, 0), (8283, 0, 175), (8283, 1, 0), (8284, 1, 0),
+    (8285, 1, 0), (8286, 1, 0), (8287, 1, 0), (8288, 1, 0), (8289, 0, 225), (8289, 1, 0),
+    (8290, 1, 0), (8291, 1, 0), (8292, 1, 0), (8293, 1, 0), (8294, 1, 0), (8295, 0, 233),
+    (8295, 1, 0), (8296, 1, 0), (8297, 1, 0), (8298, 1, 0), (8299, 1, 0), (8300, 1, 0),
+    (8301, 1, 0), (8302, 1, 0), (8303, 2, 0), (8305, 0, 88), (8305, 1, 0), (8306, 1, 0),
+    (8307, 1, 0), (8308, 1, 0), (8309, 1, 0), (8310, 0, 253), (8310, 1, 0), (8311, 1, 0),
+    (8312, 1, 0), (8313, 1, 0), (8314, 1, 0), (8315, 1, 0), (8316, 1, 0), (8317, 1, 0),
+    (8318, 1, 0), (8319, 1, 0), (8320, 0, 270), (8320, 0, 272), (8320, 1, 0), (8321, 1, 0),
+    (8322, 1, 0), (8323, 1, 0), (8324, 1, 0), (8325, 1, 0), (8326, 1, 0), (8327, 0, 288),
+    (8327, 1, 0), (8328, 1, 0), (8329, 1, 0), (8330, 0, 293), (8330, 7, 0), (8337, 1, 0),
+    (8338, 1, 0), (8339, 1, 0), (8340, 1, 0), (8341, 1, 0), (8342, 1, 0), (8343, 1, 0),
+    (8344, 1, 0), (8345, 1, 0), (8346, 1, 0), (8347, 1, 0), (8348, 1, 0), (8349, 1, 0),
+    (8350, 1, 0), (8351, 0, 325), (8351, 1, 0), (8352, 1, 0), (8353, 1, 0), (8354, 1, 0),
+    (8355, 1, 0), (8356, 1, 0), (8357, 1, 0), (8358, 1, 0), (8359, 1, 0), (8360, 0, 361),
+    (8360, 1, 0), (8361, 1, 0), (8362, 0, 369), (8362, 1, 0), (8363, 1, 0), (8364, 1, 0),
+    (8365, 1, 0), (8366, 2, 0), (8368, 1, 0), (8369, 1, 0), (8370, 1, 0), (8371, 1, 0),
+    (8372, 3, 0), (8375, 1, 0), (8376, 1, 0), (8377, 1, 0), (8378, 1, 0), (8379, 1, 0),
+    (8380, 1, 0), (8381, 1, 0), (8382, 1, 0), (8383, 1, 0), (8384, 1, 0), (8385, 1, 0),
+    (8386, 1, 0), (8387, 1, 0), (8388, 1, 0), (8389, 1, 0), (8390, 1, 0), (8391, 1, 0),
+    (8392, 1, 0), (8393, 1, 0), (8394, 1, 0), (8395, 1, 0), (8396, 1, 0), (8397, 1, 0),
+    (8398, 1, 0), (8399, 1, 0), (8400, 1, 0), (8401, 1, 0), (8402, 1, 0), (8403, 1, 0),
+    (8404, 1, 0), (8405, 0, 471), (8405, 1, 0), (8406, 0, 474), (8406, 1, 0), (8407, 1, 0),
+    (8408, 1, 0), (8409, 0, 480), (8409, 1, 0), (8410, 1, 0), (8411, 1, 0), (8412, 0, 499),
+    (8412, 1, 0), (8413, 1, 0), (8414, 1, 0), (8415, 1, 0), (8416, 1, 0), (8417, 1, 0),
+    (8418, 1, 0), (8419, 1, 0), (8420, 1, 0), (8421, 1, 0), (8422, 1, 0), (8423, 1, 0),
+    (8424, 1, 0), (8425, 1, 0), (8426, 1, 0), (8427, 0, 611), (8427, 0, 613), (8427, 0, 627),
+    (8427, 1, 0), (8428, 1, 0), (8429, 1, 0), (8430, 1, 0), (8431, 0, 43), (8431, 0, 720),
+    (8431, 1, 0), (8432, 1, 0), (8433, 1, 0), (8434, 1, 0), (8435, 1, 0), (8436, 0, 57),
+    (8436, 1, 0), (8437, 1, 0), (8438, 1, 0), (8439, 2, 0), (8441, 1, 0), (8442, 1, 0),
+    (8443, 0, 798), (8443, 1, 0), (8444, 0, 98), (8444, 1, 0), (8445, 1, 0), (8446, 1, 0),
+    (8447, 1, 0), (8448, 1, 0), (8449, 1, 0), (8450, 1, 0), (8451, 0, 892), (8451, 0, 158),
+    (8451, 1, 0), (8452, 0, 923), (8452, 0, 929), (8452, 1, 0), (8453, 1, 0), (8454, 0, 225),
+    (8454, 1, 0), (8455, 1, 0), (8456, 1, 0), (8457, 1, 0), (8458, 1, 0), (8459, 1, 0),
+    (8460, 1, 0), (8461, 1, 0), (8462, 0, 248), (8462, 1, 0), (8463, 1, 0), (8464, 1, 0),
+    (8465, 1, 0), (8466, 1, 0), (8467, 1, 0), (8468, 1, 0), (8469, 1, 0), (8470, 0, 1085),
+    (8470, 1, 0), (8471, 1, 0), (8472, 1, 0), (8473, 1, 0), (8474, 1, 0), (8475, 1, 0),
+    (8476, 0, 299), (8476, 1, 0), (8477, 1, 0), (8478, 0, 310), (8478, 0, 289), (8478, 1, 0),
+    (8479, 0, 337), (8479, 1, 0), (8480, 0, 326), (8480, 1, 0), (8481, 0, 332), (8481, 1, 0),
+    (8482, 1, 0), (8483, 1, 0), (8484, 0, 868), (8484, 1, 0), (8485, 1, 0), (8486, 1, 0),
+    (8487, 1, 0), (8488, 0, 383), (8488, 2, 0), (8490, 1, 0), (8491, 1, 0), (8492, 1, 0),
+    (8493, 1, 0), (8494, 1, 0), (8495, 1, 0), (8496, 1, 0), (8497, 1, 0), (8498, 1, 0),
+    (8499, 1, 0), (8500, 0, 1366), (8500, 1, 0), (8501, 1, 0), (8502, 1, 0), (8503, 1, 0),
+    (8504, 0, 1372), (8504, 0, 1377), (8504, 1, 0), (8505, 1, 0), (8506, 1, 0), (8507, 1, 0),
+    (8508, 0, 1387), (8508, 0, 1396), (8508, 1, 0), (8509, 0, 471), (8509, 1, 0), (8510, 1, 0),
+    (8511, 1, 0), (8512, 0, 1423), (8512, 1, 0), (8513, 1, 0), (8514, 1, 0), (8515, 1, 0),
+    (8516, 1, 0), (8517, 1, 0), (8518, 1, 0), (8519, 1, 0), (8520, 1, 0), (8521, 0, 378),
+    (8521, 1, 0), (8522, 1, 0), (8523, 1, 0), (8524, 1, 0), (8525, 0, 25), (8525, 1, 0),
+    (8526, 1, 0), (8527, 0, 47), (8527, 1, 0), (8528, 1, 0), (8529, 1, 0), (8530, 1, 0),
+    (8531, 1, 0), (8532, 1, 0), (8533, 4, 0), (8537, 1, 0), (8538, 1, 0), (8539, 1, 0),
+    (8540, 1, 0), (8541, 1, 0), (8542, 1, 0), (8543, 1, 0), (8544, 1, 0), (8545, 2, 0),
+    (8547, 1, 0), (8548, 1, 0), (8549, 1, 0), (8550, 1, 0), (8551, 1, 0), (8552, 1, 0),
+    (8553, 1, 0), (8554, 1, 0), (8555, 1, 0), (8556, 1, 0), (8557, 1, 0), (8558, 1, 0),
+    (8559, 1, 0), (8560, 0, 130), (8560, 1, 0), (8561, 1, 0), (8562, 1, 0), (8563, 1, 0),
+    (8564, 0, 144), (8564, 1, 0), (8565, 1, 0), (8566, 1, 0), (8567, 1, 0), (8568, 1, 0),
+    (8569, 1, 0), (8570, 1, 0), (8571, 1, 0), (8572, 1, 0), (8573, 0, 186), (8573, 1, 0),
+    (8574, 1, 0), (8575, 1, 0), (8576, 0, 219), (8576, 1, 0), (8577, 1, 0), (8578, 1, 0),
+    (8579, 1, 0), (8580, 1, 0), (8581, 1, 0), (8582, 1, 0), (8583, 1, 0), (8584, 1, 0),
+    (8585, 1, 0), (8586, 1, 0), (8587, 1, 0), (8588, 1, 0), (8589, 1, 0), (8590, 1, 0),
+    (8591, 0, 246), (8591, 1, 0), (8592, 1, 0), (8593, 1, 0), (8594, 1, 0), (8595, 1, 0),
+    (8596, 1, 0), (8597, 0, 256), (8597, 1, 0), (8598, 1, 0), (8599, 1, 0), (8600, 1, 0),
+    (8601, 1, 0), (8602, 1, 0), (8603, 1, 0), (8604, 1, 0), (8605, 1, 0), (8606, 1, 0),
+    (8607, 2, 0), (8609, 1, 0), (8610, 1, 0), (8611, 1, 0), (8612, 1, 0), (8613, 1, 0),
+    (8614, 1, 0), (8615, 0, 290), (8615, 1, 0), (8616, 0, 294), (8616, 1, 0), (8617, 1, 0),
+    (8618, 1, 0), (8619, 1, 0), (8620, 1, 0), (8621, 1, 0), (8622, 1, 0), (8623, 1, 0),
+    (8624, 1, 0), (8625, 1, 0), (8626, 1, 0), (8627, 1, 0), (8628, 1, 0), (8629, 1, 0),
+    (8630, 1, 0), (8631, 1, 0), (8632, 3, 0), (8635, 1, 0), (8636, 1, 0), (8637, 2, 0),
+    (8639, 1, 0), (8640, 4, 0), (8644, 2, 0), (8646, 1, 0), (8647, 1, 0), (8648, 1, 0),
+    (8649, 1, 0), (8650, 1, 0), (8651, 1, 0), (8652, 1, 0), (8653, 1, 0), (8654, 1, 0),
+    (8655, 1, 0), (8656, 1, 0), (8657, 1, 0), (8658, 0, 52), (8658, 1, 0), (8659, 1, 0),
+    (8660, 1, 0), (8661, 1, 0), (8662, 1, 0), (8663, 0, 400), (8663, 1, 0), (8664, 1, 0),
+    (8665, 1, 0), (8666, 1, 0), (8667, 1, 0), (8668, 1, 0), (8669, 0, 408), (8669, 1, 0),
+    (8670, 1, 0), (8671, 1, 0), (8672, 1, 0), (8673, 1, 0), (8674, 1, 0), (8675, 2, 0),
+    (8677, 0, 93), (8677, 1, 0), (8678, 1, 0), (8679, 1, 0), (8680, 1, 0), (8681, 1, 0),
+    (8682, 1, 0), (8683, 1, 0), (8684, 1, 0), (8685, 1, 0), (8686, 1, 0), (8687, 1, 0),
+    (8688, 1, 0), (8689, 1, 0), (8690, 1, 0), (8691, 1, 0), (8692, 1, 0), (8693, 1, 0),
+    (8694, 0, 473), (8694, 0, 476), (8694, 1, 0), (8695, 0, 478), (8695, 0, 495), (8695, 1, 0),
+    (8696, 1, 0), (8697, 0, 502), (8697, 1, 0), (8698, 1, 0), (8699, 1, 0), (8700, 0, 507),
+    (8700, 1, 0), (8701, 1, 0), (8702, 1, 0), (8703, 1, 0), (8704, 1, 0), (8705, 1, 0),
+    (8706, 1, 0), (8707, 1, 0), (8708, 1, 0), (8709, 1, 0), (8710, 2, 0), (8712, 1, 0),
+    (8713, 1, 0), (8714, 1, 0), (8715, 2, 0), (8717, 0, 729), (8717, 1, 0), (8718, 1, 0),
+    (8719, 0, 739), (8719, 1, 0), (8720, 1, 0), (8721, 0, 767), (8721, 1, 0), (8722, 1, 0),
+    (8723, 1, 0), (8724, 1, 0), (8725, 1, 0), (8726, 1, 0), (8727, 2, 0), (8729, 0, 822),
+    (8729, 1, 0), (8730, 1, 0), (8731, 1, 0), (8732, 1, 0), (8733, 0, 889), (8733, 1, 0),
+    (8734, 1, 0), (8735, 1, 0), (8736, 2, 0), (8738, 1, 0), (8739, 1, 0), (8740, 1, 0),
+    (8741, 1, 0), (8742, 1, 0), (8743, 0, 1005), (8743, 0, 999), (8743, 0, 1050), (8743, 1, 0),
+    (8744, 1, 0), (8745, 0, 1057), (8745, 1, 0), (8746, 1, 0), (8747, 1, 0), (8748, 0, 110),
+    (8748, 0, 237), (8748, 1, 0), (8749, 0, 1108), (8749, 1, 0), (8750, 1, 0), (8751, 0, 1139),
+    (8751, 1, 0), (8752, 1, 0), (8753, 1, 0), (8754, 0, 1157), (8754, 0, 1159), (8754, 1, 0),
+    (8755, 1, 0), (8756, 0, 1246), (8756, 1, 0), (8757, 1, 0), (8758, 1, 0), (8759, 0, 400),
+    (8759, 1, 0), (8760, 1, 0), (8761, 1, 0), (8762, 1, 0), (8763, 1, 0), (8764, 1, 0),
+    (8765, 1, 0), (8766, 1, 0), (8767, 0, 1304), (8767, 1, 0), (8768, 1, 0), (8769, 0, 445),
+    (8769, 0, 447), (8769, 1, 0), (8770, 1, 0), (8771, 0, 1376), (8771, 0, 1324), (8771, 1, 0),
+    (8772, 1, 0), (8773, 0, 1395), (8773, 1, 0), (8774, 1, 0), (8775, 1, 0), (8776, 1, 0),
+    (8777, 1, 0), (8778, 1, 0), (8779, 1, 0), (8780, 1, 0), (8781, 1, 0), (8782, 0, 1452),
+    (8782, 0, 842), (8782, 0, 830), (8782, 1, 0), (8783, 1, 0), (8784, 2, 0), (8786, 1, 0),
+    (8787, 1, 0), (8788, 0, 46), (8788, 0, 48), (8788, 1, 0), (8789, 1, 0), (8790, 1, 0),
+    (8791, 1, 0), (8792, 1, 0), (8793, 1, 0), (8794, 1, 0), (8795, 1, 0), (8796, 1, 0),
+    (8797, 1, 0), (8798, 1, 0), (8799, 1, 0), (8800, 1, 0), (8801, 1, 0), (8802, 1, 0),
+    (8803, 1, 0), (8804, 1, 0), (8805, 1, 0), (8806, 1, 0), (8807, 1, 0), (8808, 1, 0),
+    (8809, 1, 0), (8810, 1, 0), (8811, 1, 0), (8812, 1, 0), (8813, 1, 0), (8814, 1, 0),
+    (8815, 1, 0), (8816, 1, 0), (8817, 1, 0), (8818, 1, 0), (8819, 1, 0), (8820, 0, 131),
+    (8820, 1, 0), (8821, 1, 0), (8822, 1, 0), (8823, 2, 0), (8825, 1, 0), (8826, 1, 0),
+    (8827, 0, 161), (8827, 1, 0), (8828, 1, 0), (8829, 1, 0), (8830, 1, 0), (8831, 1, 0),
+    (8832, 1, 0), (8833, 1, 0), (8834, 1, 0), (8835, 1, 0), (8836, 1, 0), (8837, 1, 0),
+    (8838, 0, 228), (8838, 1, 0), (8839, 1, 0), (8840, 1, 0), (8841, 1, 0), (8842, 1, 0),
+    (8843, 1, 0), (8844, 1, 0), (8845, 3, 0), (8848, 1, 0), (8849, 1, 0), (8850, 2, 0),
+    (8852, 1, 0), (8853, 1, 0), (8854, 1, 0), (8855, 1, 0), (8856, 0, 250), (8856, 1, 0),
+    (8857, 1, 0), (8858, 1, 0), (8859, 1, 0), (8860, 1, 0), (8861, 1, 0), (8862, 1, 0),
+    (8863, 1, 0), (8864, 1, 0), (8865, 0, 269), (8865, 1, 0), (8866, 1, 0), (8867, 1, 0),
+    (8868, 1, 0), (8869, 1, 0), (8870, 1, 0), (8871, 1, 0), (8872, 1, 0), (8873, 1, 0),
+    (8874, 1, 0), (8875, 1, 0), (8876, 1, 0), (8877, 1, 0), (8878, 1, 0), (8879, 1, 0),
+    (8880, 1, 0), (8881, 1, 0), (8882, 1, 0), (8883, 1, 0), (8884, 1, 0), (8885, 1, 0),
+    (8886, 0, 309), (8886, 1, 0), (8887, 1, 0), (8888, 1, 0), (8889, 1, 0), (8890, 0, 314),
+    (8890, 1, 0), (8891, 1, 0), (8892, 1, 0), (8893, 1, 0), (8894, 1, 0), (8895, 1, 0),
+    (8896, 1, 0), (8897, 0, 327), (8897, 1, 0), (8898, 1, 0), (8899, 1, 0), (8900, 0, 331),
+    (8900, 1, 0), (8901, 1, 0), (8902, 1, 0), (8903, 1, 0), (8904, 1, 0), (8905, 1, 0),
+    (8906, 1, 0), (8907, 0, 362), (8907, 1, 0), (8908, 1, 0), (8909, 1, 0), (8910, 1, 0),
+    (8911, 1, 0), (8912, 1, 0), (8913, 1, 0), (8914, 1, 0), (8915, 1, 0), (8916, 1, 0),
+    (8917, 1, 0), (8918, 1, 0), (8919, 1, 0), (8920, 1, 0), (8921, 1, 0), (8922, 1, 0),
+    (8923, 1, 0), (8924, 1, 0), (8925, 1, 0), (8926, 1, 0), (8927, 1, 0), (8928, 1, 0),
+    (8929, 0, 419), (8929, 1, 0), (8930, 1, 0), (8931, 0, 423), (8931, 0, 426), (8931, 1, 0),
+    (8932, 1, 0), (8933, 1, 0), (8934, 1, 0), (8935, 0, 439), (8935, 1, 0), (8936, 2, 0),
+    (8938, 1, 0), (8939, 0, 448), (8939, 0, 452), (8939, 1, 0), (8940, 1, 0), (8941, 1, 0),
+    (8942, 1, 0), (8943, 1, 0), (8944, 1, 0), (8945, 1, 0), (8946, 1, 0), (8947, 0, 504),
+    (8947, 1, 0), (8948, 0, 96), (8948, 1, 0), (8949, 1, 0), (8950, 0, 523), (8950, 1, 0),
+    (8951, 1, 0), (8952, 1, 0), (8953, 1, 0), (8954, 1, 0), (8955, 0, 610), (8955, 1, 0),
+    (8956, 1, 0), (8957, 1, 0), (8958, 0, 141), (8958, 1, 0), (8959, 1, 0), (8960, 1, 0),
+    (8961, 0, 730), (8961, 0, 731), (8961, 0, 753), (8961, 0, 754), (8961, 1, 0), (8962, 1, 0),
+    (8963, 0, 785), (8963, 1, 0), (8964, 1, 0), (8965, 1, 0), (8966, 1, 0), (8967, 1, 0),
+    (8968, 0, 827), (8968, 0, 132), (8968, 1, 0), (8969, 1, 0), (8970, 1, 0), (8971, 1, 0),
+    (8972, 1, 0), (8973, 1, 0), (8974, 1, 0), (8975, 1, 0), (8976, 1, 0), (8977, 1, 0),
+    (8978, 1, 0), (8979, 1, 0), (8980, 1, 0), (8981, 1, 0), (8982, 1, 0), (8983, 1, 0),
+    (8984, 1, 0), (8985, 1, 0), (8986, 1, 0), (8987, 0, 1112), (8987, 1, 0), (8988, 0, 1152),
+    (8988, 1, 0), (8989, 1, 0), (8990, 1, 0), (8991, 0, 374), (8991, 0, 1247), (8991, 0, 172),
+    (8991, 1, 0), (8992, 2, 0), (8994, 1, 0), (8995, 1, 0), (8996, 1, 0), (8997, 1, 0),
+    (8998, 1, 0), (8999, 1, 0), (9000, 1, 0), (9001, 1, 0), (9002, 1, 0), (9003, 0, 1230),
+    (9003, 0, 455), (9003, 0, 1329), (9003, 0, 478), (9003, 1, 0), (9004, 2, 0), (9006, 1, 0),
+    (9007, 1, 0), (9008, 1, 0), (9009, 0, 504), (9009, 1, 0), (9010, 1, 0), (9011, 2, 0),
+    (9013, 2, 0), (9015, 1, 0), (9016, 1, 0), (9017, 1, 0), (9018, 1, 0), (9019, 1, 0),
+    (9020, 1, 0), (9021, 1, 0), (9022, 1, 0), (9023, 1, 0), (9024, 1, 0), (9025, 2, 0),
+    (9027, 1, 0), (9028, 1, 0), (9029, 1, 0), (9030, 1, 0), (9031, 1, 0), (9032, 1, 0),
+    (9033, 1, 0), (9034, 1, 0), (9035, 1, 0), (9036, 1, 0), (9037, 1, 0), (9038, 1, 0),
+    (9039, 1, 0), (9040, 1, 0), (9041, 1, 0), (9042, 0, 99), (9042, 1, 0), (9043, 1, 0),
+    (9044, 1, 0), (9045, 1, 0), (9046, 1, 0), (9047, 1, 0), (9048, 0, 110), (9048, 1, 0),
+    (9049, 1, 0), (9050, 0, 137), (9050, 1, 0), (9051, 1, 0), (9052, 0, 157), (9052, 1, 0),
+    (9053, 1, 0), (9054, 1, 0), (9055, 1, 0), (9056, 0, 163), (9056, 0, 171), (9056, 1, 0),
+    (9057, 0, 30), (9057, 0, 189), (9057, 1, 0), (9058, 1, 0), (9059, 1, 0), (9060, 0, 226),
+    (9060, 1, 0), (9061, 1, 0), (9062, 1, 0), (9063, 1, 0), (9064, 1, 0), (9065, 1, 0),
+    (9066, 0, 237), (9066, 1, 0), (9067, 0, 239), (9067, 1, 0), (9068, 1, 0), (9069, 1, 0),
+    (9070, 1, 0), (9071, 0, 244), (9071, 1, 0), (9072, 1, 0), (9073, 1, 0), (9074, 1, 0),
+    (9075, 1, 0), (9076, 1, 0), (9077, 1, 0), (9078, 1, 0), (9079, 1, 0), (9080, 1, 0),
+    (9081, 1, 0), (9082, 1, 0), (9083, 1, 0), (9084, 1, 0), (9085, 1, 0), (9086, 1, 0),
+    (9087, 1, 0), (9088, 1, 0), (9089, 1, 0), (9090, 1, 0), (9091, 1, 0), (9092, 0, 287),
+    (9092, 1, 0), (9093, 1, 0), (9094, 1, 0), (9095, 1, 0), (9096, 1, 0), (9097, 1, 0),
+    (9098, 1, 0), (9099, 1, 0), (9100, 1, 0), (9101, 0, 302), (9101, 1, 0), (9102, 0, 307),
+    (9102, 1, 0), (9103, 1, 0), (9104, 0, 311), (9104, 1, 0), (9105, 1, 0), (9106, 1, 0),
+    (9107, 1, 0), (9108, 1, 0), (9109, 1, 0), (9110, 1, 0), (9111, 1, 0), (9112, 1, 0),
+    (9113, 1, 0), (9114, 1, 0), (9115, 1, 0), (9116, 1, 0), (9117, 1, 0), (9118, 1, 0),
+    (9119, 1, 0), (9120, 1, 0), (9121, 1, 0), (9122, 1, 0), (9123, 1, 0), (9124, 1, 0),
+    (9125, 1, 0), (9126, 1, 0), (9127, 1, 0), (9128, 0, 378), (9128, 1, 0), (9129, 1, 0),
+    (9130, 1, 0), (9131, 1, 0), (9132, 1, 0), (9133, 1, 0), (9134, 0, 403), (9134, 1, 0),
+    (9135, 1, 0), (9136, 1, 0), (9137, 1, 0), (9138, 1, 0), (9139, 3, 0), (9142, 1, 0),
+    (9143, 1, 0), (9144, 2, 0), (9146, 1, 0), (9147, 0, 422), (9147, 1, 0), (9148, 1, 0),
+    (9149, 1, 0), (9150, 0, 437), (9150, 1, 0), (9151, 0, 444), (9151, 1, 0), (9152, 1, 0),
+    (9153, 1, 0), (9154, 1, 0), (9155, 1, 0), (9156, 1, 0), (9157, 1, 0), (9158, 0, 496),
+    (9158, 1, 0), (9159, 1, 0), (9160, 1, 0), (9161, 1, 0), (9162, 1, 0), (9163, 0, 524),
+    (9163, 1, 0), (9164, 1, 0), (9165, 1, 0), (9166, 1, 0), (9167, 1, 0), (9168, 1, 0),
+    (9169, 0, 634), (9169, 4, 0), (9173, 1, 0), (9174, 1, 0), (9175, 1, 0), (9176, 1, 0),
+    (9177, 1, 0), (9178, 1, 0), (9179, 1, 0), (9180, 1, 0), (9181, 1, 0), (9182, 0, 137),
+    (9182, 1, 0), (9183, 1, 0), (9184, 1, 0), (9185, 1, 0), (9186, 1, 0), (9187, 1, 0),
+    (9188, 1, 0), (9189, 1, 0), (9190, 1, 0), (9191, 1, 0), (9192, 1, 0), (9193, 1, 0),
+    (9194, 1, 0), (9195, 1, 0), (9196, 1, 0), (9197, 1, 0), (9198, 1, 0), (9199, 1, 0),
+    (9200, 1, 0), (9201, 1, 0), (9202, 2, 0), (9204, 1, 0), (9205, 1, 0), (9206, 1, 0),
+    (9207, 1, 0), (9208, 1, 0), (9209, 1, 0), (9210, 1, 0), (9211, 1, 0), (9212, 1, 0),
+    (9213, 0, 847), (9213, 1, 0), (9214, 1, 0), (9215, 1, 0), (9216, 0, 806), (9216, 0, 1082),
+    (9216, 1, 0), (9217, 2, 0), (9219, 1, 0), (9220, 1, 0), (9221, 1, 0), (9222, 1, 0),
+    (9223, 0, 1465), (9223, 1, 0), (9224, 0, 1467), (9224, 1, 0), (9225, 1, 0), (9226, 1, 0),
+    (9227, 0, 14), (9227, 1, 0), (9228, 1, 0), (9229, 1, 0), (9230, 1, 0), (9231, 1, 0),
+    (9232, 1, 0), (9233, 1, 0), (9234, 1, 0), (9235, 1, 0), (9236, 1, 0), (9237, 1, 0),
+    (9238, 0, 82), (9238, 1, 0), (9239, 1, 0), (9240, 1, 0), (9241, 1, 0), (9242, 0, 70),
+    (9242, 2, 0), (9244, 1, 0), (9245, 1, 0), (9246, 1, 0), (9247, 0, 95), (9247, 1, 0),
+    (9248, 1, 0), (9249, 1, 0), (9250, 1, 0), (9251, 1, 0), (9252, 2, 0), (9254, 1, 0),
+    (9255, 1, 0), (9256, 1, 0), (9257, 1, 0), (9258, 1, 0), (9259, 1, 0), (9260, 1, 0),
+    (9261, 1, 0), (9262, 1, 0), (9263, 1, 0), (9264, 1, 0), (9265, 1, 0), (9266, 1, 0),
+    (9267, 1, 0), (9268, 1, 0), (9269, 1, 0), (9270, 1, 0), (9271, 2, 0), (9273, 1, 0),
+    (9274, 1, 0), (9275, 0, 238), (9275, 1, 0), (9276, 1, 0), (9277, 1, 0), (9278, 1, 0),
+    (9279, 1, 0), (9280, 0, 247), (9280, 1, 0), (9281, 1, 0), (9282, 0, 249), (9282, 1, 0),
+    (9283, 0, 258), (9283, 1, 0), (9284, 1, 0), (9285, 0, 90), (9285, 1, 0), (9286, 1, 0),
+    (9287, 1, 0), (9288, 1, 0), (9289, 1, 0), (9290, 1, 0), (9291, 1, 0), (9292, 1, 0),
+    (9293, 1, 0), (9294, 1, 0), (9295, 1, 0), (9296, 1, 0), (9297, 0, 292), (9297, 1, 0),
+    (9298, 1, 0), (9299, 1, 0), (9300, 1, 0), (9301, 1, 0), (9302, 1, 0), (9303, 1, 0),
+    (9304, 1, 0), (9305, 1, 0), (9306, 1, 0), (9307, 1, 0), (9308, 1, 0), (9309, 1, 0),
+    (9310, 1, 0), (9311, 1, 0), (9312, 1, 0), (9313, 1, 0), (9314, 1, 0), (9315, 1, 0),
+    (9316, 1, 0), (9317, 1, 0), (9318, 1, 0), (9319, 1, 0), (9320, 0, 334), (9320, 1, 0),
+    (9321, 0, 336), (9321, 1, 0), (9322, 1, 0), (9323, 1, 0), (9324, 1, 0), (9325, 0, 170),
+    (9325, 0, 373), (9325, 1, 0), (9326, 0, 375), (9326, 1, 0), (9327, 1, 0), (9328, 1, 0),
+    (9329, 1, 0), (9330, 0, 401), (9330, 1, 0), (9331, 1, 0), (9332, 1, 0), (9333, 1, 0),
+    (9334, 0, 410), (9334, 1, 0), (9335, 0, 412), (9335, 1, 0), (9336, 1, 0), (9337, 1, 0),
+    (9338, 1, 0), (9339, 0, 417), (9339, 1, 0), (9340, 1, 0), (9341, 1, 0), (9342, 1, 0),
+    (9343, 1, 0), (9344, 1, 0), (9345, 1, 0), (9346, 2, 0), (9348, 0, 454), (9348, 1, 0),
+    (9349, 0, 456), (9349, 0, 460), (9349, 1, 0), (9350, 1, 0), (9351, 1, 0), (9352, 0, 505),
+    (9352, 1, 0), (9353, 1, 0), (9354, 1, 0), (9355, 0, 526), (9355, 0, 527), (9355, 1, 0),
+    (9356, 1, 0), (9357, 0, 631), (9357, 0, 635), (9357, 1, 0), (9358, 1, 0), (9359, 1, 0),
+    (9360, 1, 0), (9361, 1, 0), (9362, 1, 0), (9363, 1, 0), (9364, 0, 789), (9364, 1, 0),
+    (9365, 1, 0), (9366, 1, 0), (9367, 1, 0), (9368, 0, 818), (9368, 0, 937), (9368, 1, 0),
+    (9369, 0, 1012), (9369, 1, 0), (9370, 0, 246), (9370, 1, 0), (9371, 2, 0), (9373, 1, 0),
+    (9374, 1, 0), (9375, 1, 0), (9376, 0, 258), (9376, 1, 0), (9377, 1, 0), (9378, 0, 1009),
+    (9378, 1, 0), (9379, 0, 581), (9379, 1, 0), (9380, 1, 0), (9381, 1, 0), (9382, 0, 303),
+    (9382, 1, 0), (9383, 2, 0), (9385, 1, 0), (9386, 1, 0), (9387, 1, 0), (9388, 1, 0),
+    (9389, 1, 0), (9390, 1, 0), (9391, 1, 0), (9392, 1, 0), (9393, 0, 97), (9393, 0, 20),
+    (9393, 1, 0), (9394, 1, 0), (9395, 0, 1318), (9395, 1, 0), (9396, 1, 0), (9397, 1, 0),
+    (9398, 0, 244), (9398, 1, 0), (9399, 0, 1466), (9399, 0, 1468), (9399, 1, 0), (9400, 1, 0),
+    (9401, 1, 0), (9402, 1, 0), (9403, 1, 0), (9404, 1, 0), (9405, 1, 0), (9406, 1, 0),
+    (9407, 1, 0), (9408, 0, 77), (9408, 1, 0), (9409, 1, 0), (9410, 1, 0), (9411, 1, 0),
+    (9412, 1, 0), (9413, 1, 0), (9414, 1, 0), (9415, 1, 0), (9416, 1, 0), (9417, 1, 0),
+    (9418, 1, 0), (9419, 0, 94), (9419, 1, 0), (9420, 1, 0), (9421, 1, 0), (9422, 1, 0),
+    (9423, 1, 0), (9424, 0, 104), (9424, 1, 0), (9425, 1, 0), (9426, 2, 0), (9428, 1, 0),
+    (9429, 1, 0), (9430, 1, 0), (9431, 1, 0), (9432, 1, 0), (9433, 1, 0), (9434, 0, 160),
+    (9434, 1, 0), (9435, 0, 173), (9435, 0, 190), (9435, 0, 191), (9435, 1, 0), (9436, 1, 0),
+    (9437, 1, 0), (9438, 1, 0), (9439, 0, 231), (9439, 1, 0), (9440, 0, 234), (9440, 1, 0),
+    (9441, 1, 0), (9442, 1, 0), (9443, 1, 0), (9444, 1, 0), (9445, 1, 0), (9446, 0, 89),
+    (9446, 1, 0), (9447, 0, 252), (9447, 1, 0), (9448, 0, 260), (9448, 1, 0), (9449, 0, 92),
+    (9449, 0, 262), (9449, 1, 0), (9450, 1, 0), (9451, 1, 0), (9452, 1, 0), (9453, 1, 0),
+    (9454, 1, 0), (9455, 0, 266), (9455, 1, 0), (9456, 1, 0), (9457, 1, 0), (9458, 1, 0),
+    (9459, 1, 0), (9460, 0, 298), (9460, 1, 0), (9461, 1, 0), (9462, 1, 0), (9463, 3, 0),
+    (9466, 0, 308), (9466, 1, 0), (9467, 1, 0), (9468, 1, 0), (9469, 1, 0), (9470, 1, 0),
+    (9471, 1, 0), (9472, 1, 0), (9473, 2, 0), (9475, 1, 0), (9476, 0, 326), (9476, 1, 0),
+    (9477, 1, 0), (9478, 1, 0), (9479, 1, 0), (9480, 1, 0), (9481, 0, 337), (9481, 1, 0),
+    (9482, 0, 353), (9482, 1, 0), (9483, 1, 0), (9484, 0, 395), (9484, 1, 0), (9485, 1, 0),
+    (9486, 1, 0), (9487, 1, 0), (9488, 1, 0), (9489, 1, 0), (9490, 2, 0), (9492, 0, 411),
+    (9492, 1, 0), (9493, 1, 0), (9494, 1, 0), (9495, 1, 0), (9496, 1, 0), (9497, 0, 420),
+    (9497, 0, 98), (9497, 0, 225), (9497, 1, 0), (9498, 1, 0), (9499, 1, 0), (9500, 0, 446),
+    (9500, 1, 0), (9501, 1, 0), (9502, 0, 477), (9502, 1, 0), (9503, 1, 0), (9504, 0, 508),
+    (9504, 1, 0), (9505, 1, 0), (9506, 0, 280), (9506, 1, 0), (9507, 1, 0), (9508, 1, 0),
+    (9509, 1, 0), (9510, 1, 0), (9511, 1, 0), (9512, 0, 755), (9512, 1, 0), (9513, 0, 22),
+    (9513, 0, 778), (9513, 1, 0), (9514, 1, 0), (9515, 0, 938), (9515, 1, 0), (9516, 0, 1032),
+    (9516, 0, 234), (9516, 1, 0), (9517, 1, 0), (9518, 1, 0), (9519, 0, 1033), (9519, 1, 0),
+    (9520, 0, 260), (9520, 0, 1058), (9520, 1, 0), (9521, 1, 0), (9522, 0, 289), (9522, 1, 0),
+    (9523, 0, 318), (9523, 1, 0), (9524, 0, 1278), (9524, 1, 0), (9525, 0, 419), (9525, 1, 0),
+    (9526, 1, 0), (9527, 1, 0), (9528, 1, 0), (9529, 1, 0), (9530, 1, 0), (9531, 0, 241),
+    (9531, 1, 0), (9532, 1, 0), (9533, 1, 0), (9534, 0, 417), (9534, 1, 0), (9535, 1, 0),
+    (9536, 1, 0), (9537, 1, 0), (9538, 1, 0), (9539, 0, 51), (9539, 0, 56), (9539, 1, 0),
+    (9540, 1, 0), (9541, 1, 0), (9542, 1, 0), (9543, 1, 0), (9544, 1, 0), (9545, 0, 87),
+    (9545, 0, 88), (9545, 1, 0), (9546, 1, 0), (9547, 1, 0), (9548, 1, 0), (9549, 1, 0),
+    (9550, 1, 0), (9551, 1, 0), (9552, 1, 0), (9553, 1, 0), (9554, 1, 0), (9555, 1, 0),
+    (9556, 1, 0), (9557, 0, 107), (9557, 1, 0), (9558, 1, 0), (9559, 1, 0), (9560, 1, 0),
+    (9561, 1, 0), (9562, 1, 0), (9563, 1, 0), (9564, 1, 0), (9565, 1, 0), (9566, 1, 0),
+    (9567, 1, 0), (9568, 1, 0), (9569, 1, 0), (9570, 0, 235), (9570, 0, 240), (9570, 1, 0),
+    (9571, 1, 0), (9572, 0, 243), (9572, 0, 245), (9572, 1, 0), (9573, 1, 0), (9574, 1, 0),
+    (9575, 0, 263), (9575, 1, 0), (9576, 1, 0), (9577, 1, 0), (9578, 1, 0), (9579, 1, 0),
+    (9580, 1, 0), (9581, 1, 0), (9582, 0, 295), (9582, 1, 0), (9583, 1, 0), (9584, 1, 0),
+    (9585, 0, 300), (9585, 0, 301), (9585, 0, 303), (9585, 1, 0), (9586, 1, 0), (9587, 1, 0),
+    (9588, 1, 0), (9589, 1, 0), (9590, 1, 0), (9591, 1, 0), (9592, 1, 0), (9593, 3, 0),
+    (9596, 0, 321), (9596, 1, 0), (9597, 1, 0), (9598, 1, 0), (9599, 1, 0), (9600, 1, 0),
+    (9601, 1, 0), (9602, 1, 0), (9603, 1, 0), (9604, 0, 363), (9604, 1, 0), (9605, 1, 0),
+    (9606, 1, 0), (9607, 1, 0), (9608, 1, 0), (9609, 1, 0), (9610, 1, 0), (9611, 0, 406),
+    (9611, 1, 0), (9612, 1, 0), (9613, 1, 0), (9614, 1, 0), (9615, 1, 0), (9616, 1, 0),
+    (9617, 0, 400), (9617, 1, 0), (9618, 1, 0), (9619, 1, 0), (9620, 1, 0), (9621, 1, 0),
+    (9622, 1, 0), (9623, 0, 509), (9623, 1, 0), (9624, 0, 630), (9624, 1, 0), (9625, 1, 0),
+    (9626, 1, 0), (9627, 0, 726), (9627, 1, 0), (9628, 0, 764), (9628, 0, 231), (9628, 1, 0),
+    (9629, 0, 104), (9629, 0, 227), (9629, 1, 0), (9630, 1, 0), (9631, 1, 0), (9632, 0, 1109),
+    (9632, 0, 1133), (9632, 0, 305), (9632, 1, 0), (9633, 1, 0), (9634, 0, 402), (9634, 1, 0),
+    (9635, 1, 0), (9636, 0, 1279), (9636, 0, 1301), (9636, 0, 842), (9636, 0, 414), (9636, 1, 0),
+    (9637, 1, 0), (9638, 0, 239), (9638, 1, 0), (9639, 1, 0), (9640, 1, 0), (9641, 1, 0),
+    (9642, 1, 0), (9643, 0, 76), (9643, 1, 0), (9644, 0, 79), (9644, 0, 80), (9644, 1, 0),
+    (9645, 1, 0), (9646, 1, 0), (9647, 1, 0), (9648, 1, 0), (9649, 0, 93), (9649, 1, 0),
+    (9650, 1, 0), (9651, 0, 100), (9651, 1, 0), (9652, 1, 0), (9653, 1, 0), (9654, 1, 0),
+    (9655, 1, 0), (9656, 0, 124), (9656, 1, 0), (9657, 1, 0), (9658, 1, 0), (9659, 0, 158),
+    (9659, 0, 159), (9659, 1, 0), (9660, 0, 224), (9660, 1, 0), (9661, 1, 0), (9662, 1, 0),
+    (9663, 1, 0), (9664, 1, 0), (9665, 0, 242), (9665, 0, 248), (9665, 1, 0), (9666, 1, 0),
+    (9667, 1, 0), (9668, 1, 0), (9669, 1, 0), (9670, 1, 0), (9671, 1, 0), (9672, 0, 284),
+    (9672, 1, 0), (9673, 1, 0), (9674, 1, 0), (9675, 1, 0), (9676, 1, 0), (9677, 1, 0),
+    (9678, 1, 0), (9679, 1, 0), (9680, 1, 0), (9681, 0, 315), (9681, 1, 0), (9682, 1, 0),
+    (9683, 0, 318), (9683, 1, 0), (9684, 1, 0), (9685, 1, 0), (9686, 2, 0), (9688, 0, 328),
+    (9688, 1, 0), (9689, 0, 330), (9689, 0, 332), (9689, 1, 0), (9690, 1, 0), (9691, 1, 0),
+    (9692, 1, 0), (9693, 1, 0), (9694, 1, 0), (9695, 1, 0), (9696, 1, 0), (9697, 1, 0),
+    (9698, 1, 0), (9699, 0, 413), (9699, 1, 0), (9700, 1, 0), (9701, 0, 416), (9701, 0, 418),
+    (9701, 1, 0), (9702, 1, 0), (9703, 1, 0), (9704, 1, 0), (9705, 0, 497), (9705, 0, 503),
+    (9705, 1, 0), (9706, 1, 0), (9707, 1, 0), (9708, 1, 0), (9709, 0, 727), (9709, 0, 406),
+    (9709, 1, 0), (9710, 1, 0), (9711, 1, 0), (9712, 0, 320), (9712, 0, 107), (9712, 1, 0),
+    (9713, 0, 1300), (9713, 0, 220), (9713, 1, 0), (9714, 0, 412), (9714, 1, 0), (9715, 1, 0),
+    (9716, 1, 0), (9717, 1, 0), (9718, 1, 0), (9719, 1, 0), (9720, 1, 0), (9721, 1, 0),
+    (9722, 1, 0), (9723, 1, 0), (9724, 0, 96), (9724, 0, 97), (9724, 1, 0), (9725, 0, 103),
+    (9725, 0, 105), (9725, 1, 0), (9726, 1, 0), (9727, 1, 0), (9728, 0, 140), (9728, 1, 0),
+    (9729, 0, 162), (9729, 1, 0), (9730, 0, 229), (9730, 0, 230), (9730, 0, 232), (9730, 0, 241),
+    (9730, 1, 0), (9731, 1, 0), (9732, 1, 0), (9733, 1, 0), (9734, 0, 280), (9734, 1, 0),
+    (9735, 1, 0), (9736, 1, 0), (9737, 1, 0), (9738, 0, 297), (9738, 1, 0), (9739, 1, 0),
+    (9740, 1, 0), (9741, 0, 310), (9741, 1, 0), (9742, 0, 313), (9742, 1, 0), (9743, 0, 317),
+    (9743, 1, 0), (9744, 1, 0), (9745, 1, 0), (9746, 0, 323), (9746, 1, 0), (9747, 1, 0),
+    (9748, 0, 335), (9748, 1, 0), (9749, 1, 0), (9750, 1, 0), (9751, 1, 0), (9752, 0, 399),
+    (9752, 1, 0), (9753, 1, 0), (9754, 1, 0), (9755, 1, 0), (9756, 1, 0), (9757, 0, 415),
+    (9757, 1, 0), (9758, 0, 445), (9758, 1, 0), (9759, 1, 0), (9760, 0, 525), (9760, 0, 636),
+    (9760, 0, 637), (9760, 1, 0), (9761, 0, 396), (9761, 1, 0), (9762, 1, 0), (9763, 0, 131),
+    (9763, 0, 389), (9763, 1, 0), (9764, 1, 0), (9765, 1, 0), (9766, 1, 0), (9767, 1, 0),
+    (9768, 1, 0), (9769, 1, 0), (9770, 1, 0), (9771, 1, 0), (9772, 1, 0), (9773, 1, 0),
+    (9774, 0, 106), (9774, 0, 108), (9774, 1, 0), (9775, 1, 0), (9776, 1, 0), (9777, 0, 259),
+    (9777, 0, 91), (9777, 1, 0), (9778, 0, 280), (9778, 1, 0), (9779, 1, 0), (9780, 1, 0),
+    (9781, 1, 0), (9782, 1, 0), (9783, 0, 304), (9783, 1, 0), (9784, 1, 0), (9785, 1, 0),
+    (9786, 1, 0), (9787, 1, 0), (9788, 1, 0), (9789, 1, 0), (9790, 1, 0), (9791, 1, 0),
+    (9792, 0, 374), (9792, 0, 396), (9792, 1, 0), (9793, 1, 0), (9794, 0, 404), (9794, 0, 405),
+    (9794, 0, 407), (9794, 0, 414), (9794, 0, 443), (9794, 1, 0), (9795, 0, 455), (9795, 0, 638),
+    (9795, 1, 0), (9796, 0, 259), (9796, 1, 0), (9797, 1, 0), (9798, 1, 0), (9799, 1, 0),
+    (9800, 1, 0), (9801, 1, 0), (9802, 1, 0), (9803, 0, 90), (9803, 1, 0), (9804, 1, 0),
+    (9805, 0, 102), (9805, 1, 0), (9806, 1, 0), (9807, 0, 227), (9807, 0, 280), (9807, 1, 0),
+    (9808, 1, 0), (9809, 1, 0), (9810, 0, 296), (9810, 1, 0), (9811, 1, 0), (9812, 1, 0),
+    (9813, 1, 0), (9814, 0, 319), (9814, 1, 0), (9815, 1, 0), (9816, 1, 0), (9817, 1, 0),
+    (9818, 1, 0), (9819, 1, 0), (9820, 0, 402), (9820, 0, 447), (9820, 0, 926), (9820, 0, 35),
+    (9820, 1, 0), (9821, 1, 0), (9822, 1, 0), (9823, 1, 0), (9824, 1, 0), (9825, 0, 89),
+    (9825, 1, 0), (9826, 0, 92), (9826, 0, 125), (9826, 1, 0), (9827, 1, 0), (9828, 0, 165),
+    (9828, 0, 289), (9828, 0, 299), (9828, 0, 305), (9828, 1, 0), (9829, 1, 0), (9830, 1, 0),
+    (9831, 0, 322), (9831, 1, 0), (9832, 1, 0), (9833, 0, 352), (9833, 0, 397), (9833, 1, 0),
+    (9834, 0, 50), (9834, 1, 0), (9835, 1, 0), (9836, 0, 55), (9836, 1, 0), (9837, 0, 141),
+    (9837, 0, 280), (9837, 1, 0), (9838, 0, 316), (9838, 0, 320), (9838, 1, 0), (9839, 0, 329),
+    (9839, 1, 0), (9840, 1, 0), (9841, 0, 78), (9841, 1, 0), (9842, 1, 0), (9843, 0, 324),
+    (9843, 1, 0), (9844, 1, 0), (9845, 1, 0), (9846, 0, 312), (9846, 0, 49), (9846, 1, 0),
+    (9847, 0, 91), (9847, 1, 0), (9848, 1, 0), (9849, 1, 0), (9850, 1, 0), (9851, 1, 0),
+    (9852, 1, 0), (9853, 0, 59),
+];
+
+/** The edges of the trie: a byte of a name and the node it leads to. */
+pub(crate) static TRIE_EDGES: [(u8, u16); 9853] = [
+    (b'A', 1), (b'B', 2), (b'C', 3), (b'D', 4), (b'E', 5), (b'F', 6), (b'G', 7), (b'H', 8),
+    (b'I', 9), (b'J', 10), (b'K', 11), (b'L', 12), (b'M', 13), (b'N', 14), (b'O', 15), (b'P', 16),
+    (b'Q', 17), (b'R', 18), (b'S', 19), (b'T', 20), (b'U', 21), (b'V', 22), (b'W', 23), (b'X', 24),
+    (b'Y', 25), (b'Z', 26), (b'a', 27), (b'b', 28), (b'c', 29), (b'd', 30), (b'e', 31), (b'f', 32),
+    (b'g', 33), (b'h', 34), (b'i', 35), (b'j', 36), (b'k', 37), (b'l', 38), (b'm', 39), (b'n', 40),
+    (b'o', 41), (b'p', 42), (b'q', 43), (b'r', 44), (b's', 45), (b't', 46), (b'u', 47), (b'v', 48),
+    (b'w', 49), (b'x', 50), (b'y', 51), (b'z', 52), (b'E', 53), (b'M', 54), (b'a', 55), (b'b', 56),
+    (b'c', 57), (b'f', 58), (b'g', 59), (b'l', 60), (b'm', 61), (b'n', 62), (b'o', 63), (b'p', 64),
+    (b'r', 65), (b's', 66), (b't', 67), (b'u', 68), (b'a', 69), (b'c', 70), (b'e', 71), (b'f', 72),
+    (b'o', 73), (b'r', 74), (b's', 75), (b'u', 76), (b'H', 77), (b'O', 78), (b'a', 79), (b'c', 80),
+    (b'd', 81), (b'e', 82), (b'f', 83), (b'h', 84), (b'i', 85), (b'l', 86), (b'o', 87), (b'r', 88),
+    (b's', 89), (b'u', 90), (b'D', 91), (b'J', 92), (b'S', 93), (b'Z', 94), (b'a', 95), (b'c', 96),
+    (b'e', 97), (b'f', 98), (b'i', 99), (b'o', 100), (b's', 101), (b'N', 102), (b'T', 103), (b'a', 104),
+    (b'c', 105), (b'd', 106), (b'f', 107), (b'g', 108), (b'l', 109), (b'm', 110), (b'o', 111), (b'p', 112),
+    (b'q', 113), (b's', 114), (b't', 115), (b'u', 116), (b'x', 117), (b'c', 118), (b'f', 119), (b'i', 120),
+    (b'o', 121), (b's', 122), (b'J', 123), (b'T', 124), (b'a', 125), (b'b', 126), (b'c', 127), (b'd', 128),
+    (b'f', 129), (b'g', 130), (b'o', 131), (b'r', 132), (b's', 133), (b't', 134), (b'A', 135), (b'a', 136),
+    (b'c', 137), (b'f', 138), (b'i', 139), (b'o', 140), (b's', 141), (b'u', 142), (b'E', 143), (b'J', 144),
+    (b'O', 145), (b'a', 146), (b'c', 147), (b'd', 148), (b'f', 149), (b'g', 150), (b'm', 151), (b'n', 152),
+    (b'o', 153), (b's', 154), (b't', 155), (b'u', 156), (b'c', 157), (b'f', 158), (b'o', 159), (b's', 160),
+    (b'u', 161), (b'H', 162), (b'J', 163), (b'a', 164), (b'c', 165), (b'f', 166), (b'o', 167), (b's', 168),
+    (b'J', 169), (b'T', 170), (b'a', 171), (b'c', 172), (b'e', 173), (b'f', 174), (b'l', 175), (b'm', 176),
+    (b'o', 177), (b's', 178), (b't', 179), (b'a', 180), (b'c', 181), (b'e', 182), (b'f', 183), (b'i', 184),
+    (b'o', 185), (b's', 186), (b'u', 187), (b'J', 188), (b'a', 189), (b'c', 190), (b'e', 191), (b'f', 192),
+    (b'o', 193), (b's', 194), (b't', 195), (b'u', 196), (b'E', 197), (b'a', 198), (b'c', 199), (b'd', 200),
+    (b'f', 201), (b'g', 202), (b'm', 203), (b'o', 204), (b'p', 205), (b'r', 206), (b's', 207), (b't', 208),
+    (b'u', 209), (b'v', 210), (b'a', 211), (b'c', 212), (b'f', 213), (b'h', 214), (b'i', 215), (b'l', 216),
+    (b'o', 217), (b'r', 218), (b's', 219), (b'U', 220), (b'f', 221), (b'o', 222), (b's', 223), (b'B', 224),
+    (b'E', 225), (b'a', 226), (b'c', 227), (b'e', 228), (b'f', 229), (b'h', 230), (b'i', 231), (b'o', 232),
+    (b'r', 233), (b's', 234), (b'u', 235), (b'H', 236), (b'O', 237), (b'a', 238), (b'c', 239), (b'f', 240),
+    (b'h', 241), (b'i', 242), (b'm', 243), (b'o', 244), (b'q', 245), (b's', 246), (b't', 247), (b'u', 248),
+    (b'H', 249), (b'R', 250), (b'S', 251), (b'a', 252), (b'c', 253), (b'f', 254), (b'h', 255), (b'i', 256),
+    (b'o', 257), (b'r', 258), (b's', 259), (b'a', 260), (b'b', 261), (b'c', 262), (b'd', 263), (b'f', 264),
+    (b'g', 265), (b'm', 266), (b'n', 267), (b'o', 268), (b'p', 269), (b'r', 270), (b's', 271), (b't', 272),
+    (b'u', 273), (b'D', 274), (b'b', 275), (b'c', 276), (b'd', 277), (b'e', 278), (b'f', 279), (b'o', 280),
+    (b's', 281), (b'v', 282), (b'c', 283), (b'e', 284), (b'f', 285), (b'o', 286), (b's', 287), (b'f', 288),
+    (b'i', 289), (b'o', 290), (b's', 291), (b'A', 292), (b'I', 293), (b'U', 294), (b'a', 295), (b'c', 296),
+    (b'f', 297), (b'o', 298), (b's', 299), (b'u', 300), (b'H', 301), (b'a', 302), (b'c', 303), (b'd', 304),
+    (b'e', 305), (b'f', 306), (b'o', 307), (b's', 308), (b'a', 309), (b'b', 310), (b'c', 311), (b'e', 312),
+    (b'f', 313), (b'g', 314), (b'l', 315), (b'm', 316), (b'n', 317), (b'o', 318), (b'p', 319), (b'r', 320),
+    (b's', 321), (b't', 322), (b'u', 323), (b'w', 324), (b'N', 325), (b'a', 326), (b'b', 327), (b'c', 328),
+    (b'd', 329), (b'e', 330), (b'f', 331), (b'i', 332), (b'k', 333), (b'l', 334), (b'n', 335), (b'o', 336),
+    (b'p', 337), (b'r', 338), (b's', 339), (b'u', 340), (b'a', 341), (b'c', 342), (b'd', 343), (b'e', 344),
+    (b'f', 345), (b'h', 346), (b'i', 347), (b'l', 348), (b'o', 349), (b'r', 350), (b's', 351), (b't', 352),
+    (b'u', 353), (b'w', 354), (b'y', 355), (b'A', 356), (b'H', 357), (b'a', 358), (b'b', 359), (b'c', 360),
+    (b'd', 361), (b'e', 362), (b'f', 363), (b'h', 364), (b'i', 365), (b'j', 366), (b'l', 367), (b'o', 368),
+    (b'r', 369), (b's', 370), (b't', 371), (b'u', 372), (b'w', 373), (b'z', 374), (b'D', 375), (b'a', 376),
+    (b'c', 377), (b'd', 378), (b'e', 379), (b'f', 380), (b'g', 381), (b'l', 382), (b'm', 383), (b'n', 384),
+    (b'o', 385), (b'p', 386), (b'q', 387), (b'r', 388), (b's', 389), (b't', 390), (b'u', 391), (b'x', 392),
+    (b'a', 393), (b'c', 394), (b'e', 395), (b'f', 396), (b'i', 397), (b'j', 398), (b'l', 399), (b'n', 400),
+    (b'o', 401), (b'p', 402), (b'r', 403), (b's', 404), (b'E', 405), (b'a', 406), (b'b', 407), (b'c', 408),
+    (b'd', 409), (b'e', 410), (b'f', 411), (b'g', 412), (b'i', 413), (b'j', 414), (b'l', 415), (b'n', 416),
+    (b'o', 417), (b'r', 418), (b's', 419), (b't', 420), (b'v', 421), (b'A', 422), (b'a', 423), (b'b', 424),
+    (b'c', 425), (b'e', 426), (b'f', 427), (b'k', 428), (b'o', 429), (b's', 430), (b'y', 431), (b'a', 432),
+    (b'c', 433), (b'e', 434), (b'f', 435), (b'g', 436), (b'i', 437), (b'j', 438), (b'm', 439), (b'n', 440),
+    (b'o', 441), (b'p', 442), (b'q', 443), (b's', 444), (b't', 445), (b'u', 446), (b'c', 447), (b'f', 448),
+    (b'm', 449), (b'o', 450), (b's', 451), (b'u', 452), (b'a', 453), (b'c', 454), (b'f', 455), (b'g', 456),
+    (b'h', 457), (b'j', 458), (b'o', 459), (b's', 460), (b'A', 461), (b'B', 462), (b'E', 463), (b'H', 464),
+    (b'a', 465), (b'b', 466), (b'c', 467), (b'd', 468), (b'e', 469), (b'f', 470), (b'g', 471), (b'h', 472),
+    (b'j', 473), (b'l', 474), (b'm', 475), (b'n', 476), (b'o', 477), (b'p', 478), (b'r', 479), (b's', 480),
+    (b't', 481), (b'u', 482), (b'v', 483), (b'D', 484), (b'a', 485), (b'c', 486), (b'd', 487), (b'e', 488),
+    (b'f', 489), (b'h', 490), (b'i', 491), (b'l', 492), (b'n', 493), (b'o', 494), (b'p', 495), (b's', 496),
+    (b'u', 497), (b'G', 498), (b'L', 499), (b'R', 500), (b'V', 501), (b'a', 502), (b'b', 503), (b'c', 504),
+    (b'd', 505), (b'e', 506), (b'f', 507), (b'g', 508), (b'h', 509), (b'i', 510), (b'j', 511), (b'l', 512),
+    (b'm', 513), (b'o', 514), (b'p', 515), (b'r', 516), (b's', 517), (b't', 518), (b'u', 519), (b'v', 520),
+    (b'w', 521), (b'S', 522), (b'a', 523), (b'c', 524), (b'd', 525), (b'e', 526), (b'f', 527), (b'g', 528),
+    (b'h', 529), (b'i', 530), (b'l', 531), (b'm', 532), (b'o', 533), (b'p', 534), (b'r', 535), (b's', 536),
+    (b't', 537), (b'u', 538), (b'v', 539), (b'a', 540), (b'c', 541), (b'e', 542), (b'f', 543), (b'h', 544),
+    (b'i', 545), (b'l', 546), (b'm', 547), (b'o', 548), (b'r', 549), (b's', 550), (b'u', 551), (b'f', 552),
+    (b'i', 553), (b'o', 554), (b'p', 555), (b's', 556), (b'u', 557), (b'A', 558), (b'B', 559), (b'H', 560),
+    (b'a', 561), (b'b', 562), (b'c', 563), (b'd', 564), (b'e', 565), (b'f', 566), (b'h', 567), (b'i', 568),
+    (b'l', 569), (b'm', 570), (b'n', 571), (b'o', 572), (b'p', 573), (b'r', 574), (b's', 575), (b't', 576),
+    (b'u', 577), (b'x', 578), (b'a', 579), (b'b', 580), (b'c', 581), (b'd', 582), (b'e', 583), (b'f', 584),
+    (b'h', 585), (b'i', 586), (b'l', 587), (b'm', 588), (b'o', 589), (b'p', 590), (b'q', 591), (b'r', 592),
+    (b's', 593), (b't', 594), (b'u', 595), (b'w', 596), (b'z', 597), (b'a', 598), (b'b', 599), (b'c', 600),
+    (b'd', 601), (b'e', 602), (b'f', 603), (b'h', 604), (b'i', 605), (b'o', 606), (b'p', 607), (b'r', 608),
+    (b's', 609), (b'w', 610), (b'A', 611), (b'H', 612), (b'a', 613), (b'b', 614), (b'c', 615), (b'd', 616),
+    (b'f', 617), (b'g', 618), (b'h', 619), (b'l', 620), (b'm', 621), (b'o', 622), (b'p', 623), (b'r', 624),
+    (b's', 625), (b't', 626), (b'u', 627), (b'w', 628), (b'A', 629), (b'B', 630), (b'D', 631), (b'a', 632),
+    (b'c', 633), (b'd', 634), (b'e', 635), (b'f', 636), (b'l', 637), (b'n', 638), (b'o', 639), (b'p', 640),
+    (b'r', 641), (b's', 642), (b'z', 643), (b'c', 644), (b'e', 645), (b'f', 646), (b'o', 647), (b'p', 648),
+    (b'r', 649), (b's', 650), (b'c', 651), (b'd', 652), (b'f', 653), (b'h', 654), (b'i', 655), (b'l', 656),
+    (b'm', 657), (b'n', 658), (b'o', 659), (b'r', 660), (b's', 661), (b'u', 662), (b'v', 663), (b'w', 664),
+    (b'a', 665), (b'c', 666), (b'e', 667), (b'f', 668), (b'i', 669), (b'o', 670), (b's', 671), (b'u', 672),
+    (b'a', 673), (b'c', 674), (b'd', 675), (b'e', 676), (b'f', 677), (b'h', 678), (b'i', 679), (b'o', 680),
+    (b's', 681), (b'w', 682), (b'l', 683), (b'P', 684), (b'c', 685), (b'r', 686), (b'i', 687), (b'y', 688),
+    (b'r', 689), (b'r', 690), (b'p', 691), (b'a', 692), (b'd', 693), (b'g', 694), (b'p', 695), (b'p', 696),
+    (b'i', 697), (b'c', 698), (b's', 699), (b'i', 700), (b'm', 701), (b'c', 702), (b'r', 703), (b'y', 704),
+    (b'c', 705), (b'r', 706), (b't', 707), (b'r', 708), (b'p', 709), (b'e', 710), (b'c', 711), (b'm', 712),
+    (b'c', 713), (b'P', 714), (b'c', 715), (b'p', 716), (b'y', 717), (b'a', 718), (b'e', 719), (b'i', 720),
+    (b'o', 721), (b'o', 722), (b'd', 723), (b'n', 724), (b'r', 725), (b'i', 726), (b'r', 727), (b'o', 728),
+    (b'l', 729), (b'n', 730), (b'p', 731), (b'u', 732), (b'o', 733), (b'c', 734), (b'p', 735), (b';', 736),
+    (b'o', 737), (b'c', 738), (b'c', 739), (b'c', 740), (b'g', 741), (b'r', 742), (b's', 743), (b'a', 744),
+    (b'y', 745), (b'l', 746), (b'r', 747), (b'a', 748), (b'f', 749), (b'p', 750), (b't', 751), (b'u', 752),
+    (b'w', 753), (b'c', 754), (b't', 755), (b'G', 756), (b'H', 757), (b'c', 758), (b'a', 759), (b'i', 760),
+    (b'y', 761), (b'o', 762), (b'r', 763), (b'r', 764), (b'e', 765), (b'a', 766), (b'p', 767), (b'g', 768),
+    (b'p', 769), (b's', 770), (b'u', 771), (b'c', 772), (b'i', 773), (b'a', 774), (b'm', 775), (b'i', 776),
+    (b'p', 777), (b'y', 778), (b'r', 779), (b'l', 780), (b'p', 781), (b'r', 782), (b'u', 783), (b'c', 784),
+    (b'c', 785), (b';', 786), (b'm', 787), (b'r', 788), (b'e', 789), (b'i', 790), (b'y', 791), (b'o', 792),
+    (b'r', 793), (b';', 794), (b'p', 795), (b'e', 796), (b'c', 797), (b';', 798), (b'R', 799), (b'c', 800),
+    (b't', 801), (b'i', 802), (b'r', 803), (b'l', 804), (b'p', 805), (b'r', 806), (b'c', 807), (b't', 808),
+    (b'm', 809), (b'c', 810), (b'l', 811), (b'c', 812), (b'c', 813), (b'i', 814), (b'y', 815), (b'o', 816),
+    (b'r', 817), (b'r', 818), (b';', 819), (b'a', 820), (b'p', 821), (b't', 822), (b'v', 823), (b'g', 824),
+    (b'p', 825), (b't', 826), (b'c', 827), (b'i', 828), (b'k', 829), (b'm', 830), (b'i', 831), (b'y', 832),
+    (b'r', 833), (b'p', 834), (b'c', 835), (b'e', 836), (b'k', 837), (b'c', 838), (b'c', 839), (b'p', 840),
+    (b'e', 841), (b'y', 842), (b'r', 843), (b'p', 844), (b'c', 845), (b'c', 846), (b';', 847), (b'c', 848),
+    (b'm', 849), (b'n', 850), (b'p', 851), (b'r', 852), (b'a', 853), (b'e', 854), (b'y', 855), (b'f', 856),
+    (b's', 857), (b'r', 858), (b';', 859), (b'e', 860), (b'i', 861), (b'n', 862), (b'p', 863), (b'w', 864),
+    (b'c', 865), (b'h', 866), (b't', 867), (b';', 868), (b'p', 869), (b'y', 870), (b'd', 871), (b'l', 872),
+    (b'r', 873), (b'n', 874), (b'p', 875), (b'c', 876), (b';', 877), (b'c', 878), (b'c', 879), (b'a', 880),
+    (b'e', 881), (b'y', 882), (b'g', 883), (b's', 884), (b'w', 885), (b'r', 886), (b'B', 887), (b'n', 888),
+    (b'p', 889), (b't', 890), (b'c', 891), (b'i', 892), (b';', 893), (b'l', 894), (b'c', 895), (b'i', 896),
+    (b'y', 897), (b'b', 898), (b'r', 899), (b'r', 900), (b'a', 901), (b'e', 902), (b'i', 903), (b'p', 904),
+    (b'e', 905), (b';', 906), (b'c', 907), (b'l', 908), (b'i', 909), (b'm', 910), (b'e', 911), (b'r', 912),
+    (b'y', 913), (b'r', 914), (b'i', 915), (b';', 916), (b'u', 917), (b'i', 918), (b'p', 919), (b';', 920),
+    (b'e', 921), (b'i', 922), (b'o', 923), (b'c', 924), (b'i', 925), (b'O', 926), (b'r', 927), (b'p', 928),
+    (b'c', 929), (b'a', 930), (b'G', 931), (b'c', 932), (b'n', 933), (b'r', 934), (b'a', 935), (b'e', 936),
+    (b'y', 937), (b';', 938), (b'v', 939), (b'r', 940), (b'o', 941), (b'g', 942), (b'p', 943), (b'u', 944),
+    (b'i', 945), (b'c', 946), (b'h', 947), (b'l', 948), (b'C', 949), (b'c', 950), (b'F', 951), (b'c', 952),
+    (b';', 953), (b'a', 954), (b'e', 955), (b'i', 956), (b'y', 957), (b'r', 958), (b'o', 959), (b'g', 960),
+    (b'a', 961), (b'p', 962), (b'r', 963), (b'u', 964), (b'c', 965), (b'a', 966), (b'b', 967), (b'c', 968),
+    (b'm', 969), (b'p', 970), (b'O', 971), (b'A', 972), (b'H', 973), (b'c', 974), (b'b', 975), (b'u', 976),
+    (b'a', 977), (b'e', 978), (b'y', 979), (b'r', 980), (b'e', 981), (b'i', 982), (b'l', 983), (b'p', 984),
+    (b'i', 985), (b'c', 986), (b't', 987), (b'c', 988), (b'r', 989), (b'r', 990), (b'i', 991), (b'y', 992),
+    (b'b', 993), (b'r', 994), (b'r', 995), (b'a', 996), (b'd', 997), (b'i', 998), (b'g', 999), (b'p', 1000),
+    (b'A', 1001), (b'D', 1002), (b'E', 1003), (b'T', 1004), (b'a', 1005), (b'd', 1006), (b'p', 1007), (b's', 1008),
+    (b'i', 1009), (b'c', 1010), (b'i', 1011), (b'm', 1012), (b'a', 1013), (b'a', 1014), (b'y', 1015), (b'a', 1016),
+    (b'e', 1017), (b'r', 1018), (b'r', 1019), (b'p', 1020), (b'c', 1021), (b'd', 1022), (b'i', 1023), (b'd', 1024),
+    (b'r', 1025), (b'p', 1026), (b'c', 1027), (b'r', 1028), (b';', 1029), (b'p', 1030), (b'c', 1031), (b'c', 1032),
+    (b'c', 1033), (b'c', 1034), (b'c', 1035), (b'i', 1036), (b'y', 1037), (b'r', 1038), (b'p', 1039), (b'c', 1040),
+    (b'm', 1041), (b'c', 1042), (b'c', 1043), (b'a', 1044), (b'y', 1045), (b'o', 1046), (b'r', 1047), (b't', 1048),
+    (b'r', 1049), (b'p', 1050), (b'c', 1051), (b'c', 1052), (b'r', 1053), (b';', 1054), (b'E', 1055), (b'd', 1056),
+    (b'i', 1057), (b'u', 1058), (b'y', 1059), (b'l', 1060), (b';', 1061), (b'r', 1062), (b'r', 1063), (b'e', 1064),
+    (b'p', 1065), (b'a', 1066), (b'p', 1067), (b'd', 1068), (b'g', 1069), (b'g', 1070), (b'p', 1071), (b';', 1072),
+    (b'E', 1073), (b'a', 1074), (b'e', 1075), (b'i', 1076), (b'o', 1077), (b'p', 1078), (b'i', 1079), (b'c', 1080),
+    (b't', 1081), (b'y', 1082), (b'i', 1083), (b'm', 1084), (b'c', 1085), (b'i', 1086), (b'o', 1087), (b'c', 1088),
+    (b'r', 1089), (b'r', 1090), (b'o', 1091), (b'y', 1092), (b'q', 1093), (b'c', 1094), (b'm', 1095), (b'p', 1096),
+    (b'r', 1097), (b't', 1098), (b'r', 1099), (b'g', 1100), (b'a', 1101), (b'a', 1102), (b'k', 1103), (b'o', 1104),
+    (b'e', 1105), (b'o', 1106), (b'p', 1107), (b't', 1108), (b'w', 1109), (b'x', 1110), (b'r', 1111), (b'e', 1112),
+    (b'v', 1113), (b'c', 1114), (b'e', 1115), (b'i', 1116), (b'o', 1117), (b'l', 1118), (b'm', 1119), (b'c', 1120),
+    (b'p', 1121), (b'r', 1122), (b'a', 1123), (b'e', 1124), (b'i', 1125), (b'u', 1126), (b'o', 1127), (b'd', 1128),
+    (b'm', 1129), (b'n', 1130), (b'r', 1131), (b'c', 1132), (b'e', 1133), (b'i', 1134), (b'r', 1135), (b'u', 1136),
+    (b'l', 1137), (b'm', 1138), (b'n', 1139), (b'p', 1140), (b'a', 1141), (b'o', 1142), (b'c', 1143), (b'u', 1144),
+    (b'd', 1145), (b'd', 1146), (b'e', 1147), (b'l', 1148), (b'p', 1149), (b'r', 1150), (b'v', 1151), (b'w', 1152),
+    (b'c', 1153), (b'i', 1154), (b'l', 1155), (b'r', 1156), (b'a', 1157), (b'g', 1158), (b'l', 1159), (b'r', 1160),
+    (b's', 1161), (b'k', 1162), (b'l', 1163), (b'a', 1164), (b'y', 1165), (b';', 1166), (b'a', 1167), (b'o', 1168),
+    (b'g', 1169), (b'l', 1170), (b'm', 1171), (b'i', 1172), (b'r', 1173), (b'a', 1174), (b'a', 1175), (b'e', 1176),
+    (b'g', 1177), (b's', 1178), (b'v', 1179), (b'c', 1180), (b'c', 1181), (b'l', 1182), (b'p', 1183), (b't', 1184),
+    (b'u', 1185), (b'w', 1186), (b'b', 1187), (b'c', 1188), (b'c', 1189), (b'o', 1190), (b't', 1191), (b'd', 1192),
+    (b'r', 1193), (b'a', 1194), (b'h', 1195), (b'a', 1196), (b'c', 1197), (b'i', 1198), (b'D', 1199), (b'o', 1200),
+    (b'c', 1201), (b's', 1202), (b'a', 1203), (b'i', 1204), (b'o', 1205), (b'y', 1206), (b'o', 1207), (b';', 1208),
+    (b'D', 1209), (b'r', 1210), (b';', 1211), (b'r', 1212), (b's', 1213), (b';', 1214), (b'i', 1215), (b'l', 1216),
+    (b's', 1217), (b'a', 1218), (b'p', 1219), (b's', 1220), (b'g', 1221), (b's', 1222), (b'g', 1223), (b'p', 1224),
+    (b'a', 1225), (b'l', 1226), (b's', 1227), (b'c', 1228), (b's', 1229), (b'u', 1230), (b'v', 1231), (b'D', 1232),
+    (b'a', 1233), (b'c', 1234), (b'd', 1235), (b'i', 1236), (b'a', 1237), (b'h', 1238), (b'm', 1239), (b'r', 1240),
+    (b'c', 1241), (b'i', 1242), (b'p', 1243), (b'l', 1244), (b'y', 1245), (b'm', 1246), (b'i', 1247), (b'l', 1248),
+    (b'r', 1249), (b'l', 1250), (b'l', 1251), (b'a', 1252), (b'l', 1253), (b't', 1254), (b'o', 1255), (b'p', 1256),
+    (b'r', 1257), (b'a', 1258), (b'a', 1259), (b'o', 1260), (b'c', 1261), (b';', 1262), (b'l', 1263), (b'c', 1264),
+    (b'm', 1265), (b'p', 1266), (b'r', 1267), (b'i', 1268), (b'y', 1269), (b'o', 1270), (b';', 1271), (b'l', 1272),
+    (b'q', 1273), (b's', 1274), (b'r', 1275), (b';', 1276), (b'g', 1277), (b'm', 1278), (b'c', 1279), (b';', 1280),
+    (b'E', 1281), (b'a', 1282), (b'j', 1283), (b'E', 1284), (b'a', 1285), (b'e', 1286), (b's', 1287), (b'p', 1288),
+    (b'a', 1289), (b'c', 1290), (b'i', 1291), (b';', 1292), (b'c', 1293), (b'd', 1294), (b'l', 1295), (b'q', 1296),
+    (b'r', 1297), (b'e', 1298), (b'n', 1299), (b'r', 1300), (b'i', 1301), (b'l', 1302), (b'm', 1303), (b'r', 1304),
+    (b'a', 1305), (b'i', 1306), (b'a', 1307), (b'l', 1308), (b'r', 1309), (b'r', 1310), (b's', 1311), (b'a', 1312),
+    (b'm', 1313), (b'o', 1314), (b'p', 1315), (b'r', 1316), (b'c', 1317), (b'l', 1318), (b't', 1319), (b'b', 1320),
+    (b'p', 1321), (b'c', 1322), (b';', 1323), (b'i', 1324), (b'y', 1325), (b'c', 1326), (b'x', 1327), (b'f', 1328),
+    (b'r', 1329), (b'r', 1330), (b';', 1331), (b'i', 1332), (b'n', 1333), (b'o', 1334), (b'l', 1335), (b'a', 1336),
+    (b'o', 1337), (b'p', 1338), (b';', 1339), (b'c', 1340), (b'f', 1341), (b'o', 1342), (b't', 1343), (b'c', 1344),
+    (b'g', 1345), (b'p', 1346), (b't', 1347), (b'r', 1348), (b'u', 1349), (b'c', 1350), (b'i', 1351), (b';', 1352),
+    (b'i', 1353), (b'k', 1354), (b'm', 1355), (b'i', 1356), (b'y', 1357), (b'r', 1358), (b'a', 1359), (b'p', 1360),
+    (b'c', 1361), (b'e', 1362), (b'k', 1363), (b'p', 1364), (b'e', 1365), (b'y', 1366), (b'r', 1367), (b'r', 1368),
+    (b'c', 1369), (b'c', 1370), (b'p', 1371), (b'c', 1372), (b'a', 1373), (b'r', 1374), (b't', 1375), (b'a', 1376),
+    (b';', 1377), (b'g', 1378), (b'a', 1379), (b'c', 1380), (b'e', 1381), (b'g', 1382), (b'm', 1383), (b'n', 1384),
+    (b'p', 1385), (b'q', 1386), (b'r', 1387), (b't', 1388), (b'a', 1389), (b'b', 1390), (b'r', 1391), (b'a', 1392),
+    (b'e', 1393), (b'u', 1394), (b'y', 1395), (b'c', 1396), (b'q', 1397), (b'r', 1398), (b's', 1399), (b';', 1400),
+    (b'f', 1401), (b'g', 1402), (b'q', 1403), (b's', 1404), (b'i', 1405), (b'l', 1406), (b'r', 1407), (b';', 1408),
+    (b'E', 1409), (b'a', 1410), (b'b', 1411), (b'c', 1412), (b';', 1413), (b'a', 1414), (b'c', 1415), (b'h', 1416),
+    (b't', 1417), (b'i', 1418), (b'o', 1419), (b'E', 1420), (b'a', 1421), (b'e', 1422), (b's', 1423), (b'a', 1424),
+    (b'b', 1425), (b'n', 1426), (b'o', 1427), (b'p', 1428), (b't', 1429), (b'w', 1430), (b'z', 1431), (b'a', 1432),
+    (b'a', 1433), (b'c', 1434), (b'h', 1435), (b'm', 1436), (b't', 1437), (b'a', 1438), (b'c', 1439), (b'h', 1440),
+    (b'i', 1441), (b'q', 1442), (b't', 1443), (b';', 1444), (b'c', 1445), (b'd', 1446), (b'h', 1447), (b'i', 1448),
+    (b'l', 1449), (b'q', 1450), (b'r', 1451), (b'r', 1452), (b'e', 1453), (b'n', 1454), (b'D', 1455), (b'c', 1456),
+    (b'l', 1457), (b'p', 1458), (b'r', 1459), (b'o', 1460), (b'y', 1461), (b'a', 1462), (b'a', 1463), (b'r', 1464),
+    (b'o', 1465), (b'c', 1466), (b'd', 1467), (b'n', 1468), (b'c', 1469), (b'd', 1470), (b'p', 1471), (b'd', 1472),
+    (b'p', 1473), (b';', 1474), (b'c', 1475), (b't', 1476), (b';', 1477), (b'l', 1478), (b'm', 1479), (b'g', 1480),
+    (b't', 1481), (b'e', 1482), (b'l', 1483), (b't', 1484), (b'i', 1485), (b'D', 1486), (b'd', 1487), (b'b', 1488),
+    (b'c', 1489), (b'n', 1490), (b'p', 1491), (b't', 1492), (b's', 1493), (b'u', 1494), (b'a', 1495), (b'e', 1496),
+    (b'o', 1497), (b'u', 1498), (b'y', 1499), (b'a', 1500), (b';', 1501), (b'A', 1502), (b'a', 1503), (b'd', 1504),
+    (b'q', 1505), (b's', 1506), (b'x', 1507), (b'r', 1508), (b'E', 1509), (b'e', 1510), (b's', 1511), (b't', 1512),
+    (b'A', 1513), (b'a', 1514), (b'p', 1515), (b';', 1516), (b's', 1517), (b'v', 1518), (b'c', 1519), (b'A', 1520),
+    (b'E', 1521), (b'a', 1522), (b'd', 1523), (b'e', 1524), (b's', 1525), (b't', 1526), (b'i', 1527), (b'p', 1528),
+    (b't', 1529), (b'a', 1530), (b'o', 1531), (b'r', 1532), (b'A', 1533), (b'a', 1534), (b'i', 1535), (b't', 1536),
+    (b'c', 1537), (b'h', 1538), (b'i', 1539), (b'm', 1540), (b'p', 1541), (b'q', 1542), (b'u', 1543), (b'g', 1544),
+    (b'i', 1545), (b'l', 1546), (b'r', 1547), (b';', 1548), (b'm', 1549), (b'D', 1550), (b'H', 1551), (b'a', 1552),
+    (b'd', 1553), (b'g', 1554), (b'i', 1555), (b'l', 1556), (b'r', 1557), (b's', 1558), (b'A', 1559), (b'a', 1560),
+    (b'n', 1561), (b';', 1562), (b'c', 1563), (b's', 1564), (b'i', 1565), (b'y', 1566), (b'a', 1567), (b'b', 1568),
+    (b'i', 1569), (b'o', 1570), (b's', 1571), (b'l', 1572), (b'c', 1573), (b'r', 1574), (b'o', 1575), (b'r', 1576),
+    (b't', 1577), (b'b', 1578), (b'm', 1579), (b'n', 1580), (b'a', 1581), (b'c', 1582), (b'i', 1583), (b't', 1584),
+    (b'a', 1585), (b'e', 1586), (b'i', 1587), (b'p', 1588), (b'a', 1589), (b'e', 1590), (b'l', 1591), (b';', 1592),
+    (b'a', 1593), (b'd', 1594), (b'i', 1595), (b'o', 1596), (b's', 1597), (b'v', 1598), (b'c', 1599), (b'l', 1600),
+    (b'o', 1601), (b'i', 1602), (b'm', 1603), (b'b', 1604), (b'r', 1605), (b'y', 1606), (b'r', 1607), (b'r', 1608),
+    (b'i', 1609), (b'm', 1610), (b'o', 1611), (b';', 1612), (b't', 1613), (b'v', 1614), (b'a', 1615), (b'u', 1616),
+    (b';', 1617), (b'i', 1618), (b'p', 1619), (b'u', 1620), (b';', 1621), (b'E', 1622), (b'a', 1623), (b'c', 1624),
+    (b'e', 1625), (b'i', 1626), (b'n', 1627), (b'o', 1628), (b's', 1629), (b'u', 1630), (b'c', 1631), (b'i', 1632),
+    (b'n', 1633), (b'r', 1634), (b'n', 1635), (b'p', 1636), (b'r', 1637), (b'c', 1638), (b'a', 1639), (b'e', 1640),
+    (b'o', 1641), (b'a', 1642), (b'r', 1643), (b't', 1644), (b'a', 1645), (b'a', 1646), (b'c', 1647), (b'd', 1648),
+    (b'e', 1649), (b'n', 1650), (b'q', 1651), (b'r', 1652), (b't', 1653), (b'a', 1654), (b'b', 1655), (b'r', 1656),
+    (b'a', 1657), (b'e', 1658), (b'u', 1659), (b'y', 1660), (b'c', 1661), (b'l', 1662), (b'q', 1663), (b's', 1664),
+    (b'a', 1665), (b'c', 1666), (b'g', 1667), (b'i', 1668), (b'l', 1669), (b'r', 1670), (b'a', 1671), (b'o', 1672),
+    (b'g', 1673), (b'n', 1674), (b's', 1675), (b'a', 1676), (b'h', 1677), (b'm', 1678), (b'o', 1679), (b'm', 1680),
+    (b'a', 1681), (b'b', 1682), (b'p', 1683), (b't', 1684), (b'a', 1685), (b'p', 1686), (b'a', 1687), (b'a', 1688),
+    (b'c', 1689), (b'h', 1690), (b'q', 1691), (b'h', 1692), (b'i', 1693), (b'r', 1694), (b'l', 1695), (b';', 1696),
+    (b'c', 1697), (b'q', 1698), (b';', 1699), (b'E', 1700), (b'a', 1701), (b'c', 1702), (b'e', 1703), (b'i', 1704),
+    (b'n', 1705), (b'p', 1706), (b's', 1707), (b'y', 1708), (b'o', 1709), (b'A', 1710), (b'a', 1711), (b'c', 1712),
+    (b'm', 1713), (b's', 1714), (b't', 1715), (b'x', 1716), (b'r', 1717), (b'a', 1718), (b'c', 1719), (b'o', 1720),
+    (b'y', 1721), (b'g', 1722), (b'm', 1723), (b'a', 1724), (b'a', 1725), (b'e', 1726), (b'i', 1727), (b't', 1728),
+    (b'f', 1729), (b'l', 1730), (b'p', 1731), (b'a', 1732), (b'c', 1733), (b's', 1734), (b'u', 1735), (b'a', 1736),
+    (b'c', 1737), (b'e', 1738), (b'm', 1739), (b't', 1740), (b'a', 1741), (b'r', 1742), (b'b', 1743), (b'c', 1744),
+    (b'm', 1745), (b'n', 1746), (b'p', 1747), (b'A', 1748), (b'a', 1749), (b'n', 1750), (b'l', 1751), (b'r', 1752),
+    (b'u', 1753), (b'r', 1754), (b'a', 1755), (b'e', 1756), (b'y', 1757), (b'o', 1758), (b'l', 1759), (b'r', 1760),
+    (b'e', 1761), (b'i', 1762), (b'k', 1763), (b'o', 1764), (b'l', 1765), (b'm', 1766), (b'n', 1767), (b'e', 1768),
+    (b'p', 1769), (b's', 1770), (b'r', 1771), (b'a', 1772), (b'i', 1773), (b'p', 1774), (b'c', 1775), (b'h', 1776),
+    (b't', 1777), (b'i', 1778), (b'o', 1779), (b'r', 1780), (b'a', 1781), (b'c', 1782), (b'r', 1783), (b'r', 1784),
+    (b'i', 1785), (b'y', 1786), (b'a', 1787), (b'b', 1788), (b'h', 1789), (b'i', 1790), (b'r', 1791), (b'r', 1792),
+    (b'a', 1793), (b'b', 1794), (b'c', 1795), (b't', 1796), (b'a', 1797), (b'l', 1798), (b'g', 1799), (b'p', 1800),
+    (b'a', 1801), (b'd', 1802), (b'h', 1803), (b'l', 1804), (b's', 1805), (b'u', 1806), (b'c', 1807), (b'i', 1808),
+    (b't', 1809), (b'c', 1810), (b'd', 1811), (b'i', 1812), (b'r', 1813), (b'a', 1814), (b'm', 1815), (b'a', 1816),
+    (b'r', 1817), (b'a', 1818), (b'a', 1819), (b'n', 1820), (b'r', 1821), (b'y', 1822), (b'a', 1823), (b'e', 1824),
+    (b'l', 1825), (b'r', 1826), (b'r', 1827), (b't', 1828), (b's', 1829), (b'p', 1830), (b'r', 1831), (b't', 1832),
+    (b'c', 1833), (b'u', 1834), (b'i', 1835), (b'i', 1836), (b'd', 1837), (b'i', 1838), (b'r', 1839), (b'p', 1840),
+    (b';', 1841), (b';', 1842), (b'e', 1843), (b'c', 1844), (b'a', 1845), (b'i', 1846), (b'u', 1847), (b't', 1848),
+    (b'r', 1849), (b'A', 1850), (b'a', 1851), (b';', 1852), (b'A', 1853), (b'a', 1854), (b'a', 1855), (b'i', 1856),
+    (b'd', 1857), (b'p', 1858), (b't', 1859), (b'A', 1860), (b'a', 1861), (b'c', 1862), (b'q', 1863), (b'p', 1864),
+    (b't', 1865), (b'e', 1866), (b'e', 1867), (b'c', 1868), (b'i', 1869), (b'y', 1870), (b'n', 1871), (b'r', 1872),
+    (b'c', 1873), (b'p', 1874), (b'c', 1875), (b'c', 1876), (b'm', 1877), (b'c', 1878), (b'a', 1879), (b'y', 1880),
+    (b'o', 1881), (b'e', 1882), (b't', 1883), (b'r', 1884), (b'c', 1885), (b'g', 1886), (b'p', 1887), (b'c', 1888),
+    (b'j', 1889), (b'n', 1890), (b'i', 1891), (b';', 1892), (b'u', 1893), (b'e', 1894), (b'r', 1895), (b';', 1896),
+    (b';', 1897), (b'a', 1898), (b'h', 1899), (b'c', 1900), (b';', 1901), (b'o', 1902), (b'f', 1903), (b'l', 1904),
+    (b'n', 1905), (b'r', 1906), (b'i', 1907), (b'l', 1908), (b'l', 1909), (b'k', 1910), (b'v', 1911), (b'w', 1912),
+    (b';', 1913), (b'a', 1914), (b'n', 1915), (b'a', 1916), (b';', 1917), (b'f', 1918), (b'v', 1919), (b'r', 1920),
+    (b'p', 1921), (b'y', 1922), (b'Y', 1923), (b'u', 1924), (b';', 1925), (b'i', 1926), (b'l', 1927), (b'r', 1928),
+    (b'd', 1929), (b'r', 1930), (b'n', 1931), (b't', 1932), (b'i', 1933), (b't', 1934), (b';', 1935), (b';', 1936),
+    (b'c', 1937), (b'c', 1938), (b's', 1939), (b'o', 1940), (b'g', 1941), (b'i', 1942), (b't', 1943), (b'f', 1944),
+    (b'r', 1945), (b'n', 1946), (b's', 1947), (b'r', 1948), (b';', 1949), (b'C', 1950), (b't', 1951), (b'y', 1952),
+    (b'y', 1953), (b'y', 1954), (b'g', 1955), (b'r', 1956), (b'h', 1957), (b'r', 1958), (b';', 1959), (b';', 1960),
+    (b't', 1961), (b';', 1962), (b'c', 1963), (b'm', 1964), (b'f', 1965), (b'f', 1966), (b';', 1967), (b'D', 1968),
+    (b'E', 1969), (b'b', 1970), (b'n', 1971), (b'r', 1972), (b'r', 1973), (b';', 1974), (b';', 1975), (b'u', 1976),
+    (b'r', 1977), (b'r', 1978), (b';', 1979), (b't', 1980), (b';', 1981), (b'a', 1982), (b'm', 1983), (b'c', 1984),
+    (b't', 1985), (b'o', 1986), (b'f', 1987), (b'i', 1988), (b'a', 1989), (b'i', 1990), (b'r', 1991), (b'm', 1992),
+    (b';', 1993), (b'l', 1994), (b's', 1995), (b'o', 1996), (b';', 1997), (b';', 1998), (b'l', 1999), (b'f', 2000),
+    (b'A', 2001), (b'r', 2002), (b'r', 2003), (b'y', 2004), (b'm', 2005), (b'e', 2006), (b'd', 2007), (b'r', 2008),
+    (b';', 2009), (b't', 2010), (b';', 2011), (b'f', 2012), (b'a', 2013), (b'r', 2014), (b'D', 2015), (b'e', 2016),
+    (b';', 2017), (b'r', 2018), (b';', 2019), (b'b', 2020), (b'f', 2021), (b'i', 2022), (b'r', 2023), (b'r', 2024),
+    (b'p', 2025), (b'y', 2026), (b'i', 2027), (b'y', 2028), (b'u', 2029), (b'r', 2030), (b';', 2031), (b't', 2032),
+    (b';', 2033), (b'a', 2034), (b'c', 2035), (b'g', 2036), (b'l', 2037), (b';', 2038), (b'e', 2039), (b'i', 2040),
+    (b'o', 2041), (b'f', 2042), (b'a', 2043), (b'r', 2044), (b'l', 2045), (b'c', 2046), (b'l', 2047), (b'r', 2048),
+    (b';', 2049), (b';', 2050), (b'f', 2051), (b'r', 2052), (b'r', 2053), (b'c', 2054), (b'y', 2055), (b'y', 2056),
+    (b'p', 2057), (b'd', 2058), (b';', 2059), (b';', 2060), (b'f', 2061), (b'r', 2062), (b'y', 2063), (b'u', 2064),
+    (b'b', 2065), (b'g', 2066), (b'l', 2067), (b'r', 2068), (b'r', 2069), (b'd', 2070), (b';', 2071), (b't', 2072),
+    (b's', 2073), (b';', 2074), (b'f', 2075), (b'd', 2076), (b'g', 2077), (b'f', 2078), (b'e', 2079), (b'r', 2080),
+    (b';', 2081), (b'r', 2082), (b';', 2083), (b';', 2084), (b'i', 2085), (b'l', 2086), (b';', 2087), (b'u', 2088),
+    (b'f', 2089), (b'r', 2090), (b'y', 2091), (b'u', 2092), (b'r', 2093), (b'd', 2094), (b';', 2095), (b'a', 2096),
+    (b't', 2097), (b'L', 2098), (b';', 2099), (b'r', 2100), (b'B', 2101), (b'f', 2102), (b';', 2103), (b'C', 2104),
+    (b'D', 2105), (b'E', 2106), (b'G', 2107), (b'H', 2108), (b'L', 2109), (b'N', 2110), (b'P', 2111), (b'R', 2112),
+    (b'S', 2113), (b'T', 2114), (b'V', 2115), (b'r', 2116), (b'l', 2117), (b'i', 2118), (b'u', 2119), (b'r', 2120),
+    (b';', 2121), (b'l', 2122), (b';', 2123), (b'a', 2124), (b'c', 2125), (b'g', 2126), (b'c', 2127), (b'f', 2128),
+    (b'n', 2129), (b'r', 2130), (b'a', 2131), (b'l', 2132), (b'm', 2133), (b'l', 2134), (b'r', 2135), (b't', 2136),
+    (b';', 2137), (b';', 2138), (b';', 2139), (b's', 2140), (b'n', 2141), (b'f', 2142), (b'c', 2143), (b'm', 2144),
+    (b'd', 2145), (b'p', 2146), (b'r', 2147), (b';', 2148), (b'T', 2149), (b';', 2150), (b'f', 2151), (b'r', 2152),
+    (b'r', 2153), (b';', 2154), (b'u', 2155), (b'g', 2156), (b'r', 2157), (b'r', 2158), (b'd', 2159), (b';', 2160),
+    (b'e', 2161), (b';', 2162), (b';', 2163), (b'h', 2164), (b'f', 2165), (b'n', 2166), (b'g', 2167), (b'r', 2168),
+    (b';', 2169), (b'e', 2170), (b'H', 2171), (b'y', 2172), (b'T', 2173), (b'u', 2174), (b'r', 2175), (b'd', 2176),
+    (b'r', 2177), (b';', 2178), (b';', 2179), (b'r', 2180), (b'm', 2181), (b'l', 2182), (b'f', 2183), (b't', 2184),
+    (b'a', 2185), (b'r', 2186), (b'r', 2187), (b';', 2188), (b's', 2189), (b'c', 2190), (b'h', 2191), (b';', 2192),
+    (b';', 2193), (b'e', 2194), (b's', 2195), (b'R', 2196), (b'D', 2197), (b'c', 2198), (b'y', 2199), (b';', 2200),
+    (b';', 2201), (b'r', 2202), (b'd', 2203), (b';', 2204), (b';', 2205), (b'r', 2206), (b't', 2207), (b'c', 2208),
+    (b'n', 2209), (b'd', 2210), (b'f', 2211), (b'p', 2212), (b'r', 2213), (b'r', 2214), (b'u', 2215), (b'r', 2216),
+    (b'c', 2217), (b'e', 2218), (b'r', 2219), (b';', 2220), (b'l', 2221), (b';', 2222), (b'a', 2223), (b'c', 2224),
+    (b'e', 2225), (b'o', 2226), (b'o', 2227), (b'f', 2228), (b'r', 2229), (b'o', 2230), (b'q', 2231), (b'e', 2232),
+    (b'r', 2233), (b'o', 2234), (b'e', 2235), (b'i', 2236), (b'n', 2237), (b'r', 2238), (b'l', 2239), (b'l', 2240),
+    (b's', 2241), (b'r', 2242), (b';', 2243), (b's', 2244), (b';', 2245), (b'b', 2246), (b't', 2247), (b'y', 2248),
+    (b';', 2249), (b'f', 2250), (b'r', 2251), (b'a', 2252), (b'r', 2253), (b'g', 2254), (b';', 2255), (b'f', 2256),
+    (b'r', 2257), (b';', 2258), (b'f', 2259), (b'r', 2260), (b'y', 2261), (b'y', 2262), (b'y', 2263), (b'u', 2264),
+    (b'r', 2265), (b';', 2266), (b';', 2267), (b'f', 2268), (b'r', 2269), (b'l', 2270), (b'y', 2271), (b'u', 2272),
+    (b'r', 2273), (b';', 2274), (b't', 2275), (b'o', 2276), (b'a', 2277), (b';', 2278), (b'f', 2279), (b'r', 2280),
+    (b'u', 2281), (b'e', 2282), (b';', 2283), (b';', 2284), (b'r', 2285), (b't', 2286), (b';', 2287), (b'i', 2288),
+    (b';', 2289), (b'a', 2290), (b'f', 2291), (b'p', 2292), (b'h', 2293), (b'c', 2294), (b'l', 2295), (b';', 2296),
+    (b';', 2297), (b'a', 2298), (b'd', 2299), (b's', 2300), (b'v', 2301), (b';', 2302), (b'e', 2303), (b'l', 2304),
+    (b'm', 2305), (b'r', 2306), (b's', 2307), (b'z', 2308), (b'o', 2309), (b'f', 2310), (b';', 2311), (b'c', 2312),
+    (b';', 2313), (b'd', 2314), (b's', 2315), (b'r', 2316), (b'n', 2317), (b'r', 2318), (b';', 2319), (b'm', 2320),
+    (b'l', 2321), (b'l', 2322), (b'o', 2323), (b'n', 2324), (b't', 2325), (b'k', 2326), (b'v', 2327), (b'w', 2328),
+    (b'k', 2329), (b'n', 2330), (b';', 2331), (b'u', 2332), (b'a', 2333), (b'p', 2334), (b's', 2335), (b'n', 2336),
+    (b'a', 2337), (b'h', 2338), (b'w', 2339), (b';', 2340), (b'c', 2341), (b'o', 2342), (b's', 2343), (b't', 2344),
+    (b'u', 2345), (b'v', 2346), (b'w', 2347), (b'r', 2348), (b'c', 2349), (b'n', 2350), (b'1', 2351), (b'3', 2352),
+    (b'c', 2353), (b';', 2354), (b'q', 2355), (b't', 2356), (b'f', 2357), (b';', 2358), (b't', 2359), (b't', 2360),
+    (b'D', 2361), (b'H', 2362), (b'U', 2363), (b'V', 2364), (b'b', 2365), (b'd', 2366), (b'h', 2367), (b'm', 2368),
+    (b'p', 2369), (b't', 2370), (b'u', 2371), (b'v', 2372), (b'i', 2373), (b'v', 2374), (b'b', 2375), (b'r', 2376),
+    (b'm', 2377), (b'm', 2378), (b'l', 2379), (b'l', 2380), (b'p', 2381), (b'u', 2382), (b';', 2383), (b'a', 2384),
+    (b'b', 2385), (b'c', 2386), (b'd', 2387), (b's', 2388), (b'e', 2389), (b'o', 2390), (b'p', 2391), (b'r', 2392),
+    (b'd', 2393), (b'r', 2394), (b'p', 2395), (b't', 2396), (b'i', 2397), (b'p', 2398), (b't', 2399), (b';', 2400),
+    (b'y', 2401), (b'c', 2402), (b';', 2403), (b';', 2404), (b'E', 2405), (b'c', 2406), (b'e', 2407), (b'f', 2408),
+    (b'm', 2409), (b's', 2410), (b'b', 2411), (b'o', 2412), (b'm', 2413), (b'p', 2414), (b'g', 2415), (b'i', 2416),
+    (b'f', 2417), (b'r', 2418), (b'y', 2419), (b'r', 2420), (b's', 2421), (b'r', 2422), (b'b', 2423), (b'p', 2424),
+    (b'o', 2425), (b'a', 2426), (b'p', 2427), (b's', 2428), (b'a', 2429), (b';', 2430), (b'b', 2431), (b'c', 2432),
+    (b'd', 2433), (b'o', 2434), (b's', 2435), (b'a', 2436), (b'l', 2437), (b'r', 2438), (b'v', 2439), (b'e', 2440),
+    (b'e', 2441), (b'o', 2442), (b'n', 2443), (b'c', 2444), (b'r', 2445), (b'r', 2446), (b'g', 2447), (b'e', 2448),
+    (b'r', 2449), (b'h', 2450), (b'a', 2451), (b'a', 2452), (b'r', 2453), (b';', 2454), (b'g', 2455), (b'r', 2456),
+    (b't', 2457), (b';', 2458), (b't', 2459), (b'p', 2460), (b's', 2461), (b';', 2462), (b'r', 2463), (b'm', 2464),
+    (b';', 2465), (b'a', 2466), (b'i', 2467), (b';', 2468), (b'i', 2469), (b'o', 2470), (b'y', 2471), (b'o', 2472),
+    (b'r', 2473), (b'l', 2474), (b'f', 2475), (b';', 2476), (b'e', 2477), (b'm', 2478), (b'p', 2479), (b's', 2480),
+    (b'b', 2481), (b'n', 2482), (b'k', 2483), (b'o', 2484), (b'r', 2485), (b'r', 2486), (b'y', 2487), (b'l', 2488),
+    (b'r', 2489), (b'o', 2490), (b'i', 2491), (b'r', 2492), (b'a', 2493), (b'n', 2494), (b'y', 2495), (b'g', 2496),
+    (b'o', 2497), (b't', 2498), (b'u', 2499), (b't', 2500), (b'r', 2501), (b'r', 2502), (b'l', 2503), (b';', 2504),
+    (b't', 2505), (b'o', 2506), (b';', 2507), (b'a', 2508), (b';', 2509), (b'd', 2510), (b'n', 2511), (b';', 2512),
+    (b';', 2513), (b'd', 2514), (b'c', 2515), (b't', 2516), (b'p', 2517), (b';', 2518), (b'p', 2519), (b'o', 2520),
+    (b'f', 2521), (b'r', 2522), (b'u', 2523), (b'i', 2524), (b'i', 2525), (b'o', 2526), (b'i', 2527), (b'l', 2528),
+    (b'a', 2529), (b'e', 2530), (b'i', 2531), (b'p', 2532), (b'o', 2533), (b'r', 2534), (b'r', 2535), (b'o', 2536),
+    (b'm', 2537), (b';', 2538), (b';', 2539), (b'l', 2540), (b'o', 2541), (b'l', 2542), (b's', 2543), (b'e', 2544),
+    (b'o', 2545), (b'l', 2546), (b';', 2547), (b'a', 2548), (b'l', 2549), (b'i', 2550), (b'l', 2551), (b';', 2552),
+    (b'i', 2553), (b'i', 2554), (b't', 2555), (b'i', 2556), (b'n', 2557), (b'f', 2558), (b'f', 2559), (b'a', 2560),
+    (b'k', 2561), (b'r', 2562), (b'c', 2563), (b's', 2564), (b'w', 2565), (b'r', 2566), (b';', 2567), (b'u', 2568),
+    (b'm', 2569), (b';', 2570), (b'e', 2571), (b'r', 2572), (b';', 2573), (b't', 2574), (b';', 2575), (b';', 2576),
+    (b'q', 2577), (b's', 2578), (b';', 2579), (b'c', 2580), (b'd', 2581), (b'l', 2582), (b';', 2583), (b';', 2584),
+    (b'e', 2585), (b'y', 2586), (b';', 2587), (b';', 2588), (b';', 2589), (b';', 2590), (b'p', 2591), (b';', 2592),
+    (b'q', 2593), (b'i', 2594), (b'f', 2595), (b'v', 2596), (b'r', 2597), (b'm', 2598), (b'c', 2599), (b'i', 2600),
+    (b'o', 2601), (b'P', 2602), (b'u', 2603), (b'a', 2604), (b'd', 2605), (b'e', 2606), (b'l', 2607), (b's', 2608),
+    (b'r', 2609), (b'E', 2610), (b'r', 2611), (b'r', 2612), (b'f', 2613), (b'i', 2614), (b'd', 2615), (b'r', 2616),
+    (b'r', 2617), (b'r', 2618), (b'r', 2619), (b'l', 2620), (b'c', 2621), (b';', 2622), (b'e', 2623), (b'w', 2624),
+    (b'r', 2625), (b't', 2626), (b'k', 2627), (b'f', 2628), (b'b', 2629), (b'r', 2630), (b'a', 2631), (b'r', 2632),
+    (b'u', 2633), (b'h', 2634), (b'u', 2635), (b'r', 2636), (b';', 2637), (b'y', 2638), (b'c', 2639), (b';', 2640),
+    (b';', 2641), (b'a', 2642), (b'i', 2643), (b'n', 2644), (b'f', 2645), (b't', 2646), (b'i', 2647), (b'c', 2648),
+    (b'g', 2649), (b't', 2650), (b'f', 2651), (b'e', 2652), (b'a', 2653), (b'i', 2654), (b'd', 2655), (b';', 2656),
+    (b'c', 2657), (b'e', 2658), (b'l', 2659), (b'p', 2660), (b'y', 2661), (b'o', 2662), (b'f', 2663), (b'a', 2664),
+    (b'o', 2665), (b'e', 2666), (b'r', 2667), (b'n', 2668), (b'l', 2669), (b'c', 2670), (b'l', 2671), (b'r', 2672),
+    (b';', 2673), (b';', 2674), (b't', 2675), (b'f', 2676), (b'r', 2677), (b'r', 2678), (b'c', 2679), (b'p', 2680),
+    (b'd', 2681), (b';', 2682), (b';', 2683), (b'e', 2684), (b'y', 2685), (b'y', 2686), (b'f', 2687), (b'r', 2688),
+    (b'r', 2689), (b'r', 2690), (b'a', 2691), (b'r', 2692), (b';', 2693), (b'r', 2694), (b'u', 2695), (b'm', 2696),
+    (b'r', 2697), (b'b', 2698), (b'g', 2699), (b';', 2700), (b'u', 2701), (b'r', 2702), (b';', 2703), (b'a', 2704),
+    (b'e', 2705), (b'r', 2706), (b'r', 2707), (b'a', 2708), (b'k', 2709), (b'r', 2710), (b'd', 2711), (b'i', 2712),
+    (b'b', 2713), (b';', 2714), (b'a', 2715), (b'u', 2716), (b'd', 2717), (b'u', 2718), (b'h', 2719), (b't', 2720),
+    (b';', 2721), (b';', 2722), (b'q', 2723), (b's', 2724), (b';', 2725), (b'c', 2726), (b'd', 2727), (b'g', 2728),
+    (b's', 2729), (b's', 2730), (b'o', 2731), (b';', 2732), (b';', 2733), (b'r', 2734), (b'l', 2735), (b'y', 2736),
+    (b'r', 2737), (b'o', 2738), (b'a', 2739), (b'r', 2740), (b'd', 2741), (b'u', 2742), (b';', 2743), (b'p', 2744),
+    (b';', 2745), (b'q', 2746), (b'i', 2747), (b'n', 2748), (b'r', 2749), (b'r', 2750), (b'g', 2751), (b'p', 2752),
+    (b'a', 2753), (b'f', 2754), (b'l', 2755), (b'i', 2756), (b'a', 2757), (b'b', 2758), (b';', 2759), (b'e', 2760),
+    (b'f', 2761), (b'r', 2762), (b'r', 2763), (b'o', 2764), (b'a', 2765), (b';', 2766), (b'r', 2767), (b'q', 2768),
+    (b'r', 2769), (b';', 2770), (b'm', 2771), (b'b', 2772), (b'u', 2773), (b'r', 2774), (b'c', 2775), (b'i', 2776),
+    (b'o', 2777), (b'r', 2778), (b'm', 2779), (b'a', 2780), (b'u', 2781), (b'P', 2782), (b'i', 2783), (b'd', 2784),
+    (b'u', 2785), (b'r', 2786), (b'E', 2787), (b'o', 2788), (b'r', 2789), (b'e', 2790), (b't', 2791), (b';', 2792),
+    (b's', 2793), (b'k', 2794), (b'm', 2795), (b';', 2796), (b's', 2797), (b's', 2798), (b';', 2799), (b';', 2800),
+    (b'r', 2801), (b';', 2802), (b'a', 2803), (b'c', 2804), (b'd', 2805), (b'u', 2806), (b'p', 2807), (b'r', 2808),
+    (b'l', 2809), (b'e', 2810), (b'f', 2811), (b'r', 2812), (b'p', 2813), (b't', 2814), (b'a', 2815), (b';', 2816),
+    (b';', 2817), (b'v', 2818), (b'f', 2819), (b';', 2820), (b';', 2821), (b'v', 2822), (b'g', 2823), (b'a', 2824),
+    (b'a', 2825), (b'l', 2826), (b'u', 2827), (b'g', 2828), (b';', 2829), (b'E', 2830), (b'i', 2831), (b'o', 2832),
+    (b'p', 2833), (b'u', 2834), (b'p', 2835), (b'm', 2836), (b'p', 2837), (b'r', 2838), (b'd', 2839), (b'n', 2840),
+    (b'p', 2841), (b';', 2842), (b's', 2843), (b'r', 2844), (b'r', 2845), (b'o', 2846), (b'u', 2847), (b'e', 2848),
+    (b'i', 2849), (b'i', 2850), (b';', 2851), (b';', 2852), (b';', 2853), (b'q', 2854), (b's', 2855), (b'i', 2856),
+    (b';', 2857), (b'r', 2858), (b'r', 2859), (b'r', 2860), (b'a', 2861), (b';', 2862), (b'd', 2863), (b';', 2864),
+    (b'y', 2865), (b'r', 2866), (b';', 2867), (b'r', 2868), (b'r', 2869), (b';', 2870), (b'f', 2871), (b'q', 2872),
+    (b's', 2873), (b'i', 2874), (b';', 2875), (b'r', 2876), (b'd', 2877), (b'f', 2878), (b';', 2879), (b'i', 2880),
+    (b'n', 2881), (b'r', 2882), (b'l', 2883), (b';', 2884), (b'c', 2885), (b'e', 2886), (b'r', 2887), (b'r', 2888),
+    (b'g', 2889), (b'r', 2890), (b';', 2891), (b'c', 2892), (b'e', 2893), (b'r', 2894), (b'o', 2895), (b'm', 2896),
+    (b'i', 2897), (b'a', 2898), (b's', 2899), (b'b', 2900), (b'c', 2901), (b'p', 2902), (b'l', 2903), (b'l', 2904),
+    (b'g', 2905), (b'i', 2906), (b';', 2907), (b'e', 2908), (b's', 2909), (b'a', 2910), (b'a', 2911), (b'p', 2912),
+    (b'a', 2913), (b'e', 2914), (b't', 2915), (b'n', 2916), (b'A', 2917), (b'e', 2918), (b't', 2919), (b'A', 2920),
+    (b't', 2921), (b'i', 2922), (b'r', 2923), (b'r', 2924), (b'e', 2925), (b'u', 2926), (b't', 2927), (b'r', 2928),
+    (b';', 2929), (b's', 2930), (b'l', 2931), (b'v', 2932), (b't', 2933), (b'o', 2934), (b'i', 2935), (b'i', 2936),
+    (b';', 2937), (b'n', 2938), (b'a', 2939), (b';', 2940), (b'a', 2941), (b';', 2942), (b't', 2943), (b'r', 2944),
+    (b'i', 2945), (b'r', 2946), (b'n', 2947), (b';', 2948), (b'c', 2949), (b'g', 2950), (b'c', 2951), (b'd', 2952),
+    (b'n', 2953), (b'f', 2954), (b'r', 2955), (b'r', 2956), (b'u', 2957), (b'r', 2958), (b';', 2959), (b'e', 2960),
+    (b'f', 2961), (b'm', 2962), (b'g', 2963), (b'r', 2964), (b'l', 2965), (b';', 2966), (b'r', 2967), (b'a', 2968),
+    (b'l', 2969), (b'l', 2970), (b'm', 2971), (b'l', 2972), (b'a', 2973), (b';', 2974), (b'a', 2975), (b's', 2976),
+    (b't', 2977), (b';', 2978), (b'c', 2979), (b'i', 2980), (b'm', 2981), (b'p', 2982), (b't', 2983), (b';', 2984),
+    (b';', 2985), (b'v', 2986), (b'm', 2987), (b'n', 2988), (b'c', 2989), (b';', 2990), (b'n', 2991), (b's', 2992),
+    (b'n', 2993), (b'f', 2994), (b'n', 2995), (b';', 2996), (b'p', 2997), (b'u', 2998), (b';', 2999), (b'c', 3000),
+    (b'm', 3001), (b'E', 3002), (b'a', 3003), (b's', 3004), (b'd', 3005), (b'f', 3006), (b'p', 3007), (b'i', 3008),
+    (b'r', 3009), (b'r', 3010), (b';', 3011), (b'c', 3012), (b';', 3013), (b't', 3014), (b'f', 3015), (b'i', 3016),
+    (b'r', 3017), (b't', 3018), (b's', 3019), (b't', 3020), (b'r', 3021), (b'r', 3022), (b'a', 3023), (b'r', 3024),
+    (b'r', 3025), (b'e', 3026), (b'u', 3027), (b'i', 3028), (b'm', 3029), (b'g', 3030), (b'u', 3031), (b'r', 3032),
+    (b'a', 3033), (b'i', 3034), (b'r', 3035), (b'r', 3036), (b'a', 3037), (b'k', 3038), (b'r', 3039), (b'd', 3040),
+    (b'i', 3041), (b'b', 3042), (b';', 3043), (b'a', 3044), (b'd', 3045), (b'u', 3046), (b'h', 3047), (b'l', 3048),
+    (b't', 3049), (b';', 3050), (b's', 3051), (b'o', 3052), (b';', 3053), (b'r', 3054), (b';', 3055), (b'v', 3056),
+    (b'h', 3057), (b'g', 3058), (b'i', 3059), (b'r', 3060), (b'a', 3061), (b';', 3062), (b'u', 3063), (b'i', 3064),
+    (b'n', 3065), (b'r', 3066), (b'r', 3067), (b'a', 3068), (b'f', 3069), (b'l', 3070), (b'i', 3071), (b'r', 3072),
+    (b'o', 3073), (b'r', 3074), (b'q', 3075), (b'r', 3076), (b';', 3077), (b'b', 3078), (b'u', 3079), (b'r', 3080),
+    (b'm', 3081), (b'i', 3082), (b'u', 3083), (b'u', 3084), (b'u', 3085), (b';', 3086), (b'p', 3087), (b'r', 3088),
+    (b'u', 3089), (b';', 3090), (b'd', 3091), (b'r', 3092), (b'E', 3093), (b'a', 3094), (b's', 3095), (b'o', 3096),
+    (b'i', 3097), (b';', 3098), (b't', 3099), (b'r', 3100), (b'r', 3101), (b't', 3102), (b'i', 3103), (b'w', 3104),
+    (b'm', 3105), (b't', 3106), (b';', 3107), (b'o', 3108), (b'r', 3109), (b'h', 3110), (b'y', 3111), (b'r', 3112),
+    (b';', 3113), (b'm', 3114), (b';', 3115), (b'd', 3116), (b'e', 3117), (b'g', 3118), (b'l', 3119), (b'n', 3120),
+    (b'p', 3121), (b'r', 3122), (b'r', 3123), (b'l', 3124), (b's', 3125), (b'p', 3126), (b'd', 3127), (b'l', 3128),
+    (b';', 3129), (b'e', 3130), (b't', 3131), (b';', 3132), (b'b', 3133), (b'f', 3134), (b'd', 3135), (b'r', 3136),
+    (b'a', 3137), (b'u', 3138), (b'u', 3139), (b';', 3140), (b'a', 3141), (b'f', 3142), (b'r', 3143), (b'r', 3144),
+    (b't', 3145), (b'i', 3146), (b'a', 3147), (b'r', 3148), (b'a', 3149), (b'n', 3150), (b';', 3151), (b'E', 3152),
+    (b'd', 3153), (b'e', 3154), (b'm', 3155), (b'n', 3156), (b'p', 3157), (b'r', 3158), (b's', 3159), (b'c', 3160),
+    (b';', 3161), (b'g', 3162), (b'1', 3163), (b'2', 3164), (b'3', 3165), (b';', 3166), (b'E', 3167), (b'd', 3168),
+    (b'e', 3169), (b'h', 3170), (b'l', 3171), (b'm', 3172), (b'n', 3173), (b'p', 3174), (b's', 3175), (b'r', 3176),
+    (b'r', 3177), (b'w', 3178), (b'i', 3179), (b'g', 3180), (b';', 3181), (b'k', 3182), (b'r', 3183), (b'd', 3184),
+    (b';', 3185), (b't', 3186), (b'r', 3187), (b';', 3188), (b'r', 3189), (b't', 3190), (b'c', 3191), (b'n', 3192),
+    (b'a', 3193), (b's', 3194), (b'r', 3195), (b'd', 3196), (b'e', 3197), (b't', 3198), (b'a', 3199), (b';', 3200),
+    (b'b', 3201), (b'c', 3202), (b'f', 3203), (b'a', 3204), (b'i', 3205), (b'd', 3206), (b'a', 3207), (b'd', 3208),
+    (b'e', 3209), (b'm', 3210), (b'p', 3211), (b's', 3212), (b't', 3213), (b'e', 3214), (b'r', 3215), (b'y', 3216),
+    (b'c', 3217), (b'r', 3218), (b'x', 3219), (b'h', 3220), (b'r', 3221), (b'r', 3222), (b'u', 3223), (b'r', 3224),
+    (b'c', 3225), (b'e', 3226), (b'r', 3227), (b';', 3228), (b'r', 3229), (b'l', 3230), (b'a', 3231), (b's', 3232),
+    (b';', 3233), (b'a', 3234), (b'r', 3235), (b'l', 3236), (b'o', 3237), (b'r', 3238), (b'r', 3239), (b'c', 3240),
+    (b';', 3241), (b'o', 3242), (b'f', 3243), (b'r', 3244), (b'o', 3245), (b'a', 3246), (b'u', 3247), (b'i', 3248),
+    (b'p', 3249), (b'o', 3250), (b'r', 3251), (b'n', 3252), (b'r', 3253), (b'r', 3254), (b'o', 3255), (b'l', 3256),
+    (b'i', 3257), (b'r', 3258), (b'l', 3259), (b'n', 3260), (b'r', 3261), (b'r', 3262), (b's', 3263), (b'g', 3264),
+    (b'e', 3265), (b'k', 3266), (b'n', 3267), (b'p', 3268), (b'r', 3269), (b's', 3270), (b't', 3271), (b';', 3272),
+    (b's', 3273), (b';', 3274), (b'b', 3275), (b'e', 3276), (b'l', 3277), (b'b', 3278), (b't', 3279), (b';', 3280),
+    (b'r', 3281), (b'u', 3282), (b'f', 3283), (b'o', 3284), (b'r', 3285), (b'r', 3286), (b'b', 3287), (b'p', 3288),
+    (b'g', 3289), (b'r', 3290), (b'b', 3291), (b'g', 3292), (b'e', 3293), (b';', 3294), (b'f', 3295), (b'a', 3296),
+    (b'r', 3297), (b'p', 3298), (b'r', 3299), (b'p', 3300), (b'r', 3301), (b';', 3302), (b'r', 3303), (b'r', 3304),
+    (b'r', 3305), (b'r', 3306), (b'p', 3307), (b's', 3308), (b'o', 3309), (b'f', 3310), (b'l', 3311), (b'i', 3312),
+    (b'r', 3313), (b'r', 3314), (b'r', 3315), (b'c', 3316), (b'l', 3317), (b'r', 3318), (b'e', 3319), (b'd', 3320),
+    (b'u', 3321), (b'y', 3322), (b'r', 3323), (b';', 3324), (b';', 3325), (b';', 3326), (b'y', 3327), (b'f', 3328),
+    (b'r', 3329), (b'y', 3330), (b'l', 3331), (b'u', 3332), (b'r', 3333), (b';', 3334), (b't', 3335), (b't', 3336),
+    (b'a', 3337), (b';', 3338), (b'y', 3339), (b'r', 3340), (b'f', 3341), (b'r', 3342), (b';', 3343), (b'j', 3344),
+    (b'g', 3345), (b't', 3346), (b'v', 3347), (b'c', 3348), (b'v', 3349), (b'a', 3350), (b'r', 3351), (b'n', 3352),
+    (b';', 3353), (b'y', 3354), (b'g', 3355), (b';', 3356), (b'g', 3357), (b'd', 3358), (b';', 3359), (b's', 3360),
+    (b';', 3361), (b'e', 3362), (b'u', 3363), (b'o', 3364), (b';', 3365), (b';', 3366), (b'e', 3367), (b';', 3368),
+    (b'e', 3369), (b';', 3370), (b';', 3371), (b't', 3372), (b't', 3373), (b'e', 3374), (b'o', 3375), (b'i', 3376),
+    (b'c', 3377), (b'i', 3378), (b';', 3379), (b'l', 3380), (b'e', 3381), (b'l', 3382), (b'k', 3383), (b'e', 3384),
+    (b'n', 3385), (b'r', 3386), (b'n', 3387), (b'o', 3388), (b';', 3389), (b'o', 3390), (b't', 3391), (b's', 3392),
+    (b';', 3393), (b'a', 3394), (b'r', 3395), (b';', 3396), (b';', 3397), (b';', 3398), (b'e', 3399), (b';', 3400),
+    (b'v', 3401), (b'o', 3402), (b'a', 3403), (b'r', 3404), (b'o', 3405), (b'e', 3406), (b';', 3407), (b'o', 3408),
+    (b'q', 3409), (b'l', 3410), (b'A', 3411), (b'B', 3412), (b'L', 3413), (b'R', 3414), (b'T', 3415), (b'a', 3416),
+    (b';', 3417), (b'o', 3418), (b't', 3419), (b'o', 3420), (b'c', 3421), (b';', 3422), (b'v', 3423), (b'e', 3424),
+    (b'r', 3425), (b'y', 3426), (b'n', 3427), (b';', 3428), (b'l', 3429), (b'l', 3430), (b'l', 3431), (b';', 3432),
+    (b';', 3433), (b';', 3434), (b't', 3435), (b'n', 3436), (b'e', 3437), (b';', 3438), (b'l', 3439), (b'i', 3440),
+    (b';', 3441), (b';', 3442), (b'a', 3443), (b'v', 3444), (b'i', 3445), (b'c', 3446), (b';', 3447), (b';', 3448),
+    (b't', 3449), (b';', 3450), (b'c', 3451), (b'k', 3452), (b'c', 3453), (b'e', 3454), (b';', 3455), (b'z', 3456),
+    (b';', 3457), (b'o', 3458), (b'D', 3459), (b'E', 3460), (b';', 3461), (b'g', 3462), (b';', 3463), (b't', 3464),
+    (b'c', 3465), (b';', 3466), (b'v', 3467), (b'r', 3468), (b'i', 3469), (b'i', 3470), (b'g', 3471), (b'r', 3472),
+    (b's', 3473), (b'n', 3474), (b';', 3475), (b';', 3476), (b';', 3477), (b'd', 3478), (b'y', 3479), (b';', 3480),
+    (b'c', 3481), (b';', 3482), (b';', 3483), (b'c', 3484), (b'y', 3485), (b';', 3486), (b';', 3487), (b'a', 3488),
+    (b'i', 3489), (b';', 3490), (b';', 3491), (b';', 3492), (b't', 3493), (b'd', 3494), (b';', 3495), (b'a', 3496),
+    (b';', 3497), (b'o', 3498), (b'i', 3499), (b'A', 3500), (b'C', 3501), (b'D', 3502), (b'F', 3503), (b'R', 3504),
+    (b'T', 3505), (b'U', 3506), (b'V', 3507), (b'a', 3508), (b'r', 3509), (b'E', 3510), (b'F', 3511), (b'G', 3512),
+    (b'L', 3513), (b'S', 3514), (b'T', 3515), (b't', 3516), (b'o', 3517), (b'L', 3518), (b'R', 3519), (b'l', 3520),
+    (b'r', 3521), (b';', 3522), (b'r', 3523), (b';', 3524), (b'o', 3525), (b'u', 3526), (b'i', 3527), (b's', 3528),
+    (b';', 3529), (b';', 3530), (b';', 3531), (b't', 3532), (b'o', 3533), (b'i', 3534), (b't', 3535), (b'e', 3536),
+    (b'i', 3537), (b'e', 3538), (b'r', 3539), (b';', 3540), (b'o', 3541), (b'u', 3542), (b'o', 3543), (b'l', 3544),
+    (b'q', 3545), (b'x', 3546), (b'r', 3547), (b'u', 3548), (b'e', 3549), (b'e', 3550), (b'r', 3551), (b'e', 3552),
+    (b'i', 3553), (b'q', 3554), (b'u', 3555), (b'i', 3556), (b'e', 3557), (b';', 3558), (b'd', 3559), (b'g', 3560),
+    (b't', 3561), (b'c', 3562), (b'a', 3563), (b'v', 3564), (b'r', 3565), (b'a', 3566), (b'r', 3567), (b';', 3568),
+    (b'C', 3569), (b';', 3570), (b's', 3571), (b'd', 3572), (b'e', 3573), (b';', 3574), (b'B', 3575), (b'P', 3576),
+    (b'i', 3577), (b'M', 3578), (b'c', 3579), (b';', 3580), (b'e', 3581), (b'e', 3582), (b'u', 3583), (b'o', 3584),
+    (b';', 3585), (b';', 3586), (b';', 3587), (b';', 3588), (b'r', 3589), (b't', 3590), (b';', 3591), (b';', 3592),
+    (b't', 3593), (b'o', 3594), (b'i', 3595), (b'r', 3596), (b't', 3597), (b';', 3598), (b'd', 3599), (b'h', 3600),
+    (b';', 3601), (b'D', 3602), (b'c', 3603), (b';', 3604), (b'c', 3605), (b't', 3606), (b'o', 3607), (b'i', 3608),
+    (b'c', 3609), (b't', 3610), (b'a', 3611), (b'l', 3612), (b';', 3613), (b';', 3614), (b'r', 3615), (b';', 3616),
+    (b';', 3617), (b'e', 3618), (b'e', 3619), (b'T', 3620), (b'r', 3621), (b'e', 3622), (b'N', 3623), (b'E', 3624),
+    (b'y', 3625), (b';', 3626), (b'o', 3627), (b'i', 3628), (b'e', 3629), (b'a', 3630), (b'k', 3631), (b'S', 3632),
+    (b'e', 3633), (b';', 3634), (b'l', 3635), (b';', 3636), (b'o', 3637), (b't', 3638), (b';', 3639), (b'o', 3640),
+    (b'y', 3641), (b'v', 3642), (b'c', 3643), (b'a', 3644), (b'v', 3645), (b'r', 3646), (b'r', 3647), (b'n', 3648),
+    (b'n', 3649), (b';', 3650), (b'r', 3651), (b'w', 3652), (b'u', 3653), (b'e', 3654), (b'r', 3655), (b'w', 3656),
+    (b'r', 3657), (b';', 3658), (b'l', 3659), (b'g', 3660), (b';', 3661), (b'd', 3662), (b';', 3663), (b'h', 3664),
+    (b';', 3665), (b'h', 3666), (b'a', 3667), (b';', 3668), (b'i', 3669), (b'T', 3670), (b';', 3671), (b';', 3672),
+    (b's', 3673), (b'c', 3674), (b'e', 3675), (b';', 3676), (b';', 3677), (b';', 3678), (b';', 3679), (b';', 3680),
+    (b';', 3681), (b';', 3682), (b't', 3683), (b'c', 3684), (b';', 3685), (b';', 3686), (b';', 3687), (b';', 3688),
+    (b't', 3689), (b'o', 3690), (b';', 3691), (b'W', 3692), (b';', 3693), (b';', 3694), (b';', 3695), (b't', 3696),
+    (b'v', 3697), (b'c', 3698), (b'e', 3699), (b'g', 3700), (b'v', 3701), (b's', 3702), (b'h', 3703), (b'a', 3704),
+    (b'r', 3705), (b'g', 3706), (b'n', 3707), (b';', 3708), (b'l', 3709), (b';', 3710), (b';', 3711), (b'e', 3712),
+    (b's', 3713), (b't', 3714), (b'p', 3715), (b't', 3716), (b'a', 3717), (b'n', 3718), (b';', 3719), (b'i', 3720),
+    (b';', 3721), (b';', 3722), (b'o', 3723), (b'g', 3724), (b';', 3725), (b'p', 3726), (b'd', 3727), (b';', 3728),
+    (b'n', 3729), (b't', 3730), (b';', 3731), (b'c', 3732), (b'e', 3733), (b'p', 3734), (b's', 3735), (b'e', 3736),
+    (b'e', 3737), (b';', 3738), (b't', 3739), (b'g', 3740), (b'o', 3741), (b'u', 3742), (b't', 3743), (b'i', 3744),
+    (b'o', 3745), (b';', 3746), (b';', 3747), (b'e', 3748), (b'a', 3749), (b'i', 3750), (b'u', 3751), (b'd', 3752),
+    (b'p', 3753), (b't', 3754), (b'q', 3755), (b't', 3756), (b'r', 3757), (b'p', 3758), (b'e', 3759), (b'e', 3760),
+    (b'o', 3761), (b'k', 3762), (b'k', 3763), (b'2', 3764), (b'4', 3765), (b'4', 3766), (b'k', 3767), (b'u', 3768),
+    (b';', 3769), (b';', 3770), (b'o', 3771), (b'i', 3772), (b'L', 3773), (b'R', 3774), (b'l', 3775), (b'r', 3776),
+    (b';', 3777), (b'D', 3778), (b'U', 3779), (b'd', 3780), (b'u', 3781), (b'L', 3782), (b'R', 3783), (b'l', 3784),
+    (b'r', 3785), (b';', 3786), (b'H', 3787), (b'L', 3788), (b'R', 3789), (b'h', 3790), (b'l', 3791), (b'r', 3792),
+    (b'o', 3793), (b'L', 3794), (b'R', 3795), (b'l', 3796), (b'r', 3797), (b';', 3798), (b'D', 3799), (b'U', 3800),
+    (b'd', 3801), (b'u', 3802), (b'i', 3803), (b'l', 3804), (b'i', 3805), (b'L', 3806), (b'R', 3807), (b'l', 3808),
+    (b'r', 3809), (b';', 3810), (b'H', 3811), (b'L', 3812), (b'R', 3813), (b'h', 3814), (b'l', 3815), (b'r', 3816),
+    (b'm', 3817), (b'e', 3818), (b'a', 3819), (b';', 3820), (b'i', 3821), (b';', 3822), (b'e', 3823), (b';', 3824),
+    (b'b', 3825), (b'h', 3826), (b';', 3827), (b'e', 3828), (b';', 3829), (b'E', 3830), (b'e', 3831), (b't', 3832),
+    (b'n', 3833), (b'r', 3834), (b'a', 3835), (b'u', 3836), (b'o', 3837), (b';', 3838), (b't', 3839), (b'n', 3840),
+    (b's', 3841), (b'o', 3842), (b'i', 3843), (b'c', 3844), (b's', 3845), (b';', 3846), (b'l', 3847), (b't', 3848),
+    (b';', 3849), (b'e', 3850), (b';', 3851), (b'k', 3852), (b';', 3853), (b';', 3854), (b'e', 3855), (b'l', 3856),
+    (b';', 3857), (b'n', 3858), (b'i', 3859), (b'c', 3860), (b's', 3861), (b'n', 3862), (b'a', 3863), (b';', 3864),
+    (b'f', 3865), (b'l', 3866), (b';', 3867), (b'd', 3868), (b'n', 3869), (b';', 3870), (b'o', 3871), (b';', 3872),
+    (b's', 3873), (b'r', 3874), (b's', 3875), (b';', 3876), (b';', 3877), (b'e', 3878), (b';', 3879), (b'e', 3880),
+    (b't', 3881), (b'r', 3882), (b'r', 3883), (b'c', 3884), (b'r', 3885), (b'r', 3886), (b'a', 3887), (b'u', 3888),
+    (b'o', 3889), (b'r', 3890), (b';', 3891), (b'r', 3892), (b'y', 3893), (b'e', 3894), (b'e', 3895), (b'e', 3896),
+    (b'd', 3897), (b'n', 3898), (b't', 3899), (b't', 3900), (b';', 3901), (b';', 3902), (b'e', 3903), (b't', 3904),
+    (b';', 3905), (b';', 3906), (b'v', 3907), (b'r', 3908), (b'c', 3909), (b'o', 3910), (b'g', 3911), (b'r', 3912),
+    (b's', 3913), (b'a', 3914), (b't', 3915), (b'h', 3916), (b'l', 3917), (b'r', 3918), (b';', 3919), (b'o', 3920),
+    (b's', 3921), (b'm', 3922), (b'n', 3923), (b'd', 3924), (b'n', 3925), (b';', 3926), (b'r', 3927), (b'o', 3928),
+    (b'a', 3929), (b';', 3930), (b'q', 3931), (b'i', 3932), (b'l', 3933), (b'q', 3934), (b'l', 3935), (b'a', 3936),
+    (b'd', 3937), (b'h', 3938), (b'a', 3939), (b'r', 3940), (b'o', 3941), (b';', 3942), (b';', 3943), (b';', 3944),
+    (b'o', 3945), (b't', 3946), (b';', 3947), (b'f', 3948), (b'r', 3949), (b'r', 3950), (b'g', 3951), (b';', 3952),
+    (b'r', 3953), (b't', 3954), (b';', 3955), (b't', 3956), (b'e', 3957), (b'o', 3958), (b';', 3959), (b'c', 3960),
+    (b'o', 3961), (b';', 3962), (b't', 3963), (b'v', 3964), (b'o', 3965), (b't', 3966), (b'o', 3967), (b'r', 3968),
+    (b'y', 3969), (b'1', 3970), (b';', 3971), (b';', 3972), (b'n', 3973), (b';', 3974), (b';', 3975), (b's', 3976),
+    (b's', 3977), (b';', 3978), (b'l', 3979), (b'v', 3980), (b'r', 3981), (b'l', 3982), (b'm', 3983), (b'a', 3984),
+    (b'l', 3985), (b's', 3986), (b'v', 3987), (b'a', 3988), (b't', 3989), (b'r', 3990), (b';', 3991), (b't', 3992),
+    (b';', 3993), (b';', 3994), (b';', 3995), (b';', 3996), (b't', 3997), (b'c', 3998), (b'n', 3999), (b'i', 4000),
+    (b'l', 4001), (b'i', 4002), (b'g', 4003), (b'i', 4004), (b'g', 4005), (b'g', 4006), (b';', 4007), (b'g', 4008),
+    (b's', 4009), (b';', 4010), (b';', 4011), (b'l', 4012), (b';', 4013), (b'v', 4014), (b't', 4015), (b'1', 4016),
+    (b'2', 4017), (b'3', 4018), (b'4', 4019), (b'5', 4020), (b'7', 4021), (b'l', 4022), (b'n', 4023), (b';', 4024),
+    (b't', 4025), (b'a', 4026), (b'v', 4027), (b'c', 4028), (b';', 4029), (b';', 4030), (b'l', 4031), (b'c', 4032),
+    (b'o', 4033), (b';', 4034), (b'e', 4035), (b'l', 4036), (b';', 4037), (b';', 4038), (b'p', 4039), (b';', 4040),
+    (b'q', 4041), (b'm', 4042), (b';', 4043), (b'e', 4044), (b';', 4045), (b';', 4046), (b'e', 4047), (b'l', 4048),
+    (b';', 4049), (b'r', 4050), (b't', 4051), (b'a', 4052), (b'e', 4053), (b'p', 4054), (b'r', 4055), (b'o', 4056),
+    (b'q', 4057), (b'e', 4058), (b'i', 4059), (b't', 4060), (b';', 4061), (b';', 4062), (b's', 4063), (b';', 4064),
+    (b'l', 4065), (b'c', 4066), (b';', 4067), (b'c', 4068), (b'w', 4069), (b';', 4070), (b'c', 4071), (b't', 4072),
+    (b'i', 4073), (b'o', 4074), (b'a', 4075), (b'a', 4076), (b'r', 4077), (b'h', 4078), (b'l', 4079), (b'r', 4080),
+    (b';', 4081), (b'a', 4082), (b';', 4083), (b's', 4084), (b'o', 4085), (b'l', 4086), (b'e', 4087), (b't', 4088),
+    (b'c', 4089), (b';', 4090), (b'l', 4091), (b'v', 4092), (b'n', 4093), (b't', 4094), (b'i', 4095), (b'a', 4096),
+    (b'g', 4097), (b'r', 4098), (b'e', 4099), (b'l', 4100), (b'p', 4101), (b'h', 4102), (b';', 4103), (b'd', 4104),
+    (b'r', 4105), (b'n', 4106), (b'o', 4107), (b'a', 4108), (b'g', 4109), (b'r', 4110), (b'a', 4111), (b'r', 4112),
+    (b';', 4113), (b'n', 4114), (b';', 4115), (b';', 4116), (b'd', 4117), (b's', 4118), (b';', 4119), (b';', 4120),
+    (b'E', 4121), (b'd', 4122), (b's', 4123), (b'v', 4124), (b'd', 4125), (b'y', 4126), (b';', 4127), (b'c', 4128),
+    (b'h', 4129), (b';', 4130), (b';', 4131), (b'c', 4132), (b'y', 4133), (b'a', 4134), (b'i', 4135), (b'e', 4136),
+    (b';', 4137), (b';', 4138), (b';', 4139), (b';', 4140), (b'r', 4141), (b';', 4142), (b'i', 4143), (b'r', 4144),
+    (b';', 4145), (b't', 4146), (b'p', 4147), (b'a', 4148), (b'd', 4149), (b';', 4150), (b'd', 4151), (b'l', 4152),
+    (b'o', 4153), (b';', 4154), (b'b', 4155), (b'f', 4156), (b'h', 4157), (b'l', 4158), (b'p', 4159), (b's', 4160),
+    (b't', 4161), (b'i', 4162), (b';', 4163), (b's', 4164), (b'r', 4165), (b'k', 4166), (b'c', 4167), (b'e', 4168),
+    (b's', 4169), (b'o', 4170), (b'i', 4171), (b'l', 4172), (b';', 4173), (b';', 4174), (b'o', 4175), (b'h', 4176),
+    (b's', 4177), (b';', 4178), (b'a', 4179), (b'h', 4180), (b'l', 4181), (b'r', 4182), (b't', 4183), (b';', 4184),
+    (b'l', 4185), (b'c', 4186), (b'o', 4187), (b';', 4188), (b'e', 4189), (b'a', 4190), (b'd', 4191), (b'e', 4192),
+    (b'g', 4193), (b's', 4194), (b'h', 4195), (b'o', 4196), (b'd', 4197), (b'u', 4198), (b'k', 4199), (b';', 4200),
+    (b'r', 4201), (b'r', 4202), (b'r', 4203), (b'i', 4204), (b'o', 4205), (b's', 4206), (b';', 4207), (b'p', 4208),
+    (b';', 4209), (b'q', 4210), (b'm', 4211), (b'g', 4212), (b'r', 4213), (b'k', 4214), (b'l', 4215), (b'm', 4216),
+    (b'r', 4217), (b'a', 4218), (b'r', 4219), (b';', 4220), (b'u', 4221), (b'm', 4222), (b's', 4223), (b'a', 4224),
+    (b'n', 4225), (b';', 4226), (b';', 4227), (b'l', 4228), (b'r', 4229), (b'r', 4230), (b'r', 4231), (b'i', 4232),
+    (b'u', 4233), (b';', 4234), (b';', 4235), (b'e', 4236), (b'g', 4237), (b';', 4238), (b'o', 4239), (b'o', 4240),
+    (b';', 4241), (b'r', 4242), (b't', 4243), (b'e', 4244), (b'e', 4245), (b'r', 4246), (b'e', 4247), (b'a', 4248),
+    (b';', 4249), (b'e', 4250), (b'f', 4251), (b's', 4252), (b'h', 4253), (b't', 4254), (b';', 4255), (b't', 4256),
+    (b';', 4257), (b';', 4258), (b';', 4259), (b'e', 4260), (b't', 4261), (b'e', 4262), (b'm', 4263), (b'h', 4264),
+    (b'u', 4265), (b'o', 4266), (b's', 4267), (b'i', 4268), (b'o', 4269), (b's', 4270), (b';', 4271), (b';', 4272),
+    (b'u', 4273), (b'l', 4274), (b';', 4275), (b';', 4276), (b'o', 4277), (b'i', 4278), (b'p', 4279), (b';', 4280),
+    (b't', 4281), (b';', 4282), (b'h', 4283), (b's', 4284), (b's', 4285), (b'a', 4286), (b't', 4287), (b';', 4288),
+    (b';', 4289), (b'd', 4290), (b's', 4291), (b'r', 4292), (b'r', 4293), (b';', 4294), (b'p', 4295), (b';', 4296),
+    (b'o', 4297), (b'i', 4298), (b'g', 4299), (b';', 4300), (b'h', 4301), (b'r', 4302), (b'h', 4303), (b'r', 4304),
+    (b't', 4305), (b'i', 4306), (b'a', 4307), (b'm', 4308), (b's', 4309), (b';', 4310), (b'q', 4311), (b's', 4312),
+    (b';', 4313), (b'm', 4314), (b';', 4315), (b'r', 4316), (b'r', 4317), (b'r', 4318), (b';', 4319), (b';', 4320),
+    (b'r', 4321), (b'r', 4322), (b';', 4323), (b't', 4324), (b';', 4325), (b'q', 4326), (b's', 4327), (b';', 4328),
+    (b's', 4329), (b'm', 4330), (b'i', 4331), (b';', 4332), (b';', 4333), (b'n', 4334), (b'i', 4335), (b';', 4336),
+    (b'a', 4337), (b's', 4338), (b't', 4339), (b'i', 4340), (b'u', 4341), (b';', 4342), (b'c', 4343), (b'r', 4344),
+    (b'r', 4345), (b'h', 4346), (b'i', 4347), (b'u', 4348), (b';', 4349), (b';', 4350), (b'r', 4351), (b';', 4352),
+    (b'e', 4353), (b'd', 4354), (b'r', 4355), (b'u', 4356), (b';', 4357), (b'E', 4358), (b'e', 4359), (b's', 4360),
+    (b'c', 4361), (b';', 4362), (b'E', 4363), (b'e', 4364), (b's', 4365), (b';', 4366), (b'd', 4367), (b';', 4368),
+    (b'a', 4369), (b'r', 4370), (b'p', 4371), (b's', 4372), (b'r', 4373), (b';', 4374), (b's', 4375), (b';', 4376),
+    (b';', 4377), (b'f', 4378), (b'r', 4379), (b';', 4380), (b';', 4381), (b'r', 4382), (b'r', 4383), (b'r', 4384),
+    (b'm', 4385), (b'r', 4386), (b'h', 4387), (b'r', 4388), (b'a', 4389), (b't', 4390), (b';', 4391), (b';', 4392),
+    (b'c', 4393), (b'h', 4394), (b'a', 4395), (b';', 4396), (b';', 4397), (b'l', 4398), (b'g', 4399), (b'r', 4400),
+    (b';', 4401), (b'v', 4402), (b'r', 4403), (b';', 4404), (b'r', 4405), (b'r', 4406), (b'o', 4407), (b'e', 4408),
+    (b'r', 4409), (b'a', 4410), (b'r', 4411), (b';', 4412), (b'u', 4413), (b';', 4414), (b';', 4415), (b'p', 4416),
+    (b's', 4417), (b'r', 4418), (b'r', 4419), (b';', 4420), (b';', 4421), (b'o', 4422), (b';', 4423), (b'o', 4424),
+    (b';', 4425), (b's', 4426), (b';', 4427), (b'd', 4428), (b'e', 4429), (b';', 4430), (b'r', 4431), (b';', 4432),
+    (b'l', 4433), (b'i', 4434), (b'l', 4435), (b';', 4436), (b'n', 4437), (b'o', 4438), (b'i', 4439), (b';', 4440),
+    (b'e', 4441), (b';', 4442), (b'a', 4443), (b'e', 4444), (b'h', 4445), (b'c', 4446), (b'k', 4447), (b';', 4448),
+    (b'a', 4449), (b'b', 4450), (b'c', 4451), (b'd', 4452), (b'e', 4453), (b'm', 4454), (b's', 4455), (b't', 4456),
+    (b't', 4457), (b';', 4458), (b'd', 4459), (b';', 4460), (b'e', 4461), (b';', 4462), (b'a', 4463), (b'c', 4464),
+    (b'e', 4465), (b'n', 4466), (b's', 4467), (b'e', 4468), (b';', 4469), (b'p', 4470), (b'i', 4471), (b';', 4472),
+    (b'a', 4473), (b'l', 4474), (b's', 4475), (b';', 4476), (b't', 4477), (b'm', 4478), (b'e', 4479), (b';', 4480),
+    (b's', 4481), (b';', 4482), (b';', 4483), (b'm', 4484), (b';', 4485), (b'e', 4486), (b'i', 4487), (b't', 4488),
+    (b';', 4489), (b'r', 4490), (b';', 4491), (b'i', 4492), (b'r', 4493), (b';', 4494), (b';', 4495), (b't', 4496),
+    (b'c', 4497), (b'p', 4498), (b';', 4499), (b'd', 4500), (b'e', 4501), (b'l', 4502), (b'o', 4503), (b';', 4504),
+    (b'a', 4505), (b'b', 4506), (b'c', 4507), (b'f', 4508), (b'h', 4509), (b'l', 4510), (b'p', 4511), (b's', 4512),
+    (b't', 4513), (b'w', 4514), (b'i', 4515), (b'o', 4516), (b'r', 4517), (b'k', 4518), (b'c', 4519), (b'e', 4520),
+    (b's', 4521), (b'o', 4522), (b'i', 4523), (b'l', 4524), (b';', 4525), (b';', 4526), (b'h', 4527), (b'o', 4528),
+    (b';', 4529), (b';', 4530), (b'i', 4531), (b'p', 4532), (b's', 4533), (b';', 4534), (b'h', 4535), (b'o', 4536),
+    (b'd', 4537), (b'u', 4538), (b';', 4539), (b't', 4540), (b';', 4541), (b'n', 4542), (b'r', 4543), (b'r', 4544),
+    (b's', 4545), (b'd', 4546), (b'g', 4547), (b'r', 4548), (b'k', 4549), (b'r', 4550), (b';', 4551), (b'u', 4552),
+    (b'm', 4553), (b';', 4554), (b'g', 4555), (b'l', 4556), (b'r', 4557), (b'u', 4558), (b';', 4559), (b';', 4560),
+    (b'o', 4561), (b'e', 4562), (b'e', 4563), (b';', 4564), (b'e', 4565), (b'f', 4566), (b'l', 4567), (b'h', 4568),
+    (b't', 4569), (b'o', 4570), (b';', 4571), (b'o', 4572), (b'e', 4573), (b'i', 4574), (b'c', 4575), (b';', 4576),
+    (b'p', 4577), (b'i', 4578), (b'l', 4579), (b'm', 4580), (b';', 4581), (b'b', 4582), (b'e', 4583), (b'r', 4584),
+    (b'h', 4585), (b'r', 4586), (b';', 4587), (b';', 4588), (b'a', 4589), (b'i', 4590), (b'n', 4591), (b';', 4592),
+    (b'w', 4593), (b'p', 4594), (b'c', 4595), (b';', 4596), (b't', 4597), (b'a', 4598), (b'o', 4599), (b';', 4600),
+    (b'q', 4601), (b';', 4602), (b'E', 4603), (b';', 4604), (b'E', 4605), (b'e', 4606), (b'l', 4607), (b'a', 4608),
+    (b'r', 4609), (b'l', 4610), (b'h', 4611), (b'a', 4612), (b';', 4613), (b'e', 4614), (b';', 4615), (b's', 4616),
+    (b'c', 4617), (b';', 4618), (b'a', 4619), (b';', 4620), (b'e', 4621), (b';', 4622), (b'p', 4623), (b'p', 4624),
+    (b'b', 4625), (b'p', 4626), (b'r', 4627), (b';', 4628), (b'r', 4629), (b';', 4630), (b'm', 4631), (b'l', 4632),
+    (b'r', 4633), (b';', 4634), (b'f', 4635), (b'i', 4636), (b's', 4637), (b';', 4638), (b'o', 4639), (b';', 4640),
+    (b'd', 4641), (b'u', 4642), (b'E', 4643), (b'e', 4644), (b'l', 4645), (b'a', 4646), (b'e', 4647), (b'i', 4648),
+    (b'u', 4649), (b';', 4650), (b'a', 4651), (b'c', 4652), (b'e', 4653), (b'n', 4654), (b's', 4655), (b';', 4656),
+    (b';', 4657), (b';', 4658), (b';', 4659), (b';', 4660), (b'o', 4661), (b's', 4662), (b';', 4663), (b'd', 4664),
+    (b's', 4665), (b'a', 4666), (b'u', 4667), (b'E', 4668), (b'e', 4669), (b'l', 4670), (b'e', 4671), (b'i', 4672),
+    (b'u', 4673), (b'r', 4674), (b'h', 4675), (b'r', 4676), (b'a', 4677), (b'g', 4678), (b'e', 4679), (b';', 4680),
+    (b'o', 4681), (b'i', 4682), (b';', 4683), (b'e', 4684), (b'e', 4685), (b'a', 4686), (b'k', 4687), (b's', 4688),
+    (b'p', 4689), (b'i', 4690), (b'n', 4691), (b'e', 4692), (b's', 4693), (b';', 4694), (b';', 4695), (b'o', 4696),
+    (b'i', 4697), (b';', 4698), (b'o', 4699), (b';', 4700), (b'm', 4701), (b'e', 4702), (b'n', 4703), (b'o', 4704),
+    (b';', 4705), (b'i', 4706), (b'l', 4707), (b'b', 4708), (b'i', 4709), (b'z', 4710), (b';', 4711), (b';', 4712),
+    (b'y', 4713), (b'o', 4714), (b't', 4715), (b'e', 4716), (b';', 4717), (b';', 4718), (b't', 4719), (b';', 4720),
+    (b'y', 4721), (b'v', 4722), (b'c', 4723), (b'r', 4724), (b'a', 4725), (b'r', 4726), (b'h', 4727), (b'v', 4728),
+    (b'l', 4729), (b'r', 4730), (b'k', 4731), (b'r', 4732), (b'o', 4733), (b'i', 4734), (b'r', 4735), (b'n', 4736),
+    (b';', 4737), (b'r', 4738), (b'w', 4739), (b'r', 4740), (b's', 4741), (b';', 4742), (b'h', 4743), (b'l', 4744),
+    (b'a', 4745), (b'r', 4746), (b'o', 4747), (b'g', 4748), (b'i', 4749), (b';', 4750), (b't', 4751), (b'd', 4752),
+    (b';', 4753), (b'f', 4754), (b'r', 4755), (b';', 4756), (b'g', 4757), (b';', 4758), (b';', 4759), (b'v', 4760),
+    (b'h', 4761), (b'r', 4762), (b'p', 4763), (b'a', 4764), (b'o', 4765), (b'h', 4766), (b'i', 4767), (b'r', 4768),
+    (b';', 4769), (b'h', 4770), (b'i', 4771), (b'u', 4772), (b'h', 4773), (b'r', 4774), (b'h', 4775), (b'a', 4776),
+    (b'q', 4777), (b'i', 4778), (b'a', 4779), (b';', 4780), (b'i', 4781), (b'b', 4782), (b'p', 4783), (b';', 4784),
+    (b'p', 4785), (b'i', 4786), (b';', 4787), (b'n', 4788), (b'n', 4789), (b'z', 4790), (b'c', 4791), (b'a', 4792),
+    (b'e', 4793), (b'r', 4794), (b';', 4795), (b't', 4796), (b';', 4797), (b';', 4798), (b'c', 4799), (b';', 4800),
+    (b'i', 4801), (b'r', 4802), (b'r', 4803), (b'r', 4804), (b'r', 4805), (b';', 4806), (b';', 4807), (b't', 4808),
+    (b';', 4809), (b'u', 4810), (b'm', 4811), (b'r', 4812), (b'r', 4813), (b';', 4814), (b'u', 4815), (b'u', 4816),
+    (b'i', 4817), (b';', 4818), (b'g', 4819), (b't', 4820), (b';', 4821), (b'c', 4822), (b';', 4823), (b';', 4824),
+    (b';', 4825), (b';', 4826), (b';', 4827), (b't', 4828), (b'o', 4829), (b';', 4830), (b'r', 4831), (b';', 4832),
+    (b';', 4833), (b'a', 4834), (b';', 4835), (b';', 4836), (b';', 4837), (b';', 4838), (b'e', 4839), (b'e', 4840),
+    (b';', 4841), (b'e', 4842), (b';', 4843), (b';', 4844), (b';', 4845), (b'F', 4846), (b';', 4847), (b'n', 4848),
+    (b'e', 4849), (b'l', 4850), (b'd', 4851), (b's', 4852), (b'u', 4853), (b';', 4854), (b'q', 4855), (b'e', 4856),
+    (b'a', 4857), (b'y', 4858), (b'n', 4859), (b'l', 4860), (b';', 4861), (b'n', 4862), (b'l', 4863), (b'r', 4864),
+    (b'e', 4865), (b'w', 4866), (b'C', 4867), (b';', 4868), (b'e', 4869), (b'u', 4870), (b't', 4871), (b'u', 4872),
+    (b'd', 4873), (b'e', 4874), (b';', 4875), (b'p', 4876), (b'a', 4877), (b'r', 4878), (b';', 4879), (b'n', 4880),
+    (b';', 4881), (b'i', 4882), (b'n', 4883), (b'r', 4884), (b't', 4885), (b'u', 4886), (b'e', 4887), (b'r', 4888),
+    (b'r', 4889), (b'e', 4890), (b'i', 4891), (b'e', 4892), (b'r', 4893), (b'k', 4894), (b'e', 4895), (b'n', 4896),
+    (b';', 4897), (b'e', 4898), (b'n', 4899), (b';', 4900), (b'S', 4901), (b'V', 4902), (b';', 4903), (b'o', 4904),
+    (b';', 4905), (b'T', 4906), (b'i', 4907), (b's', 4908), (b'e', 4909), (b'd', 4910), (b'l', 4911), (b'e', 4912),
+    (b';', 4913), (b'd', 4914), (b'e', 4915), (b'l', 4916), (b';', 4917), (b'e', 4918), (b'y', 4919), (b';', 4920),
+    (b';', 4921), (b'r', 4922), (b'o', 4923), (b'k', 4924), (b'o', 4925), (b'q', 4926), (b';', 4927), (b'e', 4928),
+    (b';', 4929), (b'e', 4930), (b';', 4931), (b'n', 4932), (b'e', 4933), (b'r', 4934), (b's', 4935), (b'i', 4936),
+    (b';', 4937), (b'e', 4938), (b';', 4939), (b';', 4940), (b'y', 4941), (b';', 4942), (b';', 4943), (b'l', 4944),
+    (b'e', 4945), (b'a', 4946), (b'c', 4947), (b'n', 4948), (b'l', 4949), (b'n', 4950), (b'r', 4951), (b'e', 4952),
+    (b'o', 4953), (b'l', 4954), (b'i', 4955), (b'e', 4956), (b'r', 4957), (b'p', 4958), (b'e', 4959), (b'r', 4960),
+    (b'i', 4961), (b'q', 4962), (b'u', 4963), (b'r', 4964), (b'e', 4965), (b'l', 4966), (b'i', 4967), (b'a', 4968),
+    (b't', 4969), (b'e', 4970), (b'i', 4971), (b'e', 4972), (b'i', 4973), (b'L', 4974), (b'R', 4975), (b'k', 4976),
+    (b'm', 4977), (b'n', 4978), (b'P', 4979), (b'e', 4980), (b'n', 4981), (b'l', 4982), (b'i', 4983), (b'd', 4984),
+    (b'n', 4985), (b'a', 4986), (b'e', 4987), (b'n', 4988), (b'p', 4989), (b'u', 4990), (b'e', 4991), (b'u', 4992),
+    (b'i', 4993), (b'e', 4994), (b'm', 4995), (b'f', 4996), (b's', 4997), (b's', 4998), (b'e', 4999), (b'v', 5000),
+    (b'g', 5001), (b'u', 5002), (b'b', 5003), (b'c', 5004), (b'p', 5005), (b'l', 5006), (b'r', 5007), (b'e', 5008),
+    (b';', 5009), (b'e', 5010), (b';', 5011), (b'c', 5012), (b'e', 5013), (b';', 5014), (b';', 5015), (b'o', 5016),
+    (b'u', 5017), (b'h', 5018), (b'e', 5019), (b's', 5020), (b'a', 5021), (b'r', 5022), (b'a', 5023), (b'a', 5024),
+    (b'i', 5025), (b'a', 5026), (b'd', 5027), (b';', 5028), (b'c', 5029), (b'r', 5030), (b';', 5031), (b'e', 5032),
+    (b'l', 5033), (b'n', 5034), (b'l', 5035), (b's', 5036), (b'A', 5037), (b'C', 5038), (b'D', 5039), (b'F', 5040),
+    (b'T', 5041), (b'U', 5042), (b'V', 5043), (b'a', 5044), (b'I', 5045), (b't', 5046), (b'e', 5047), (b'y', 5048),
+    (b'y', 5049), (b'e', 5050), (b'n', 5051), (b'l', 5052), (b';', 5053), (b'D', 5054), (b'L', 5055), (b'R', 5056),
+    (b'U', 5057), (b';', 5058), (b'C', 5059), (b'e', 5060), (b't', 5061), (b'e', 5062), (b'h', 5063), (b's', 5064),
+    (b't', 5065), (b';', 5066), (b';', 5067), (b';', 5068), (b'n', 5069), (b'l', 5070), (b'f', 5071), (b';', 5072),
+    (b'S', 5073), (b'p', 5074), (b';', 5075), (b'E', 5076), (b'F', 5077), (b'T', 5078), (b'e', 5079), (b'k', 5080),
+    (b'e', 5081), (b'c', 5082), (b';', 5083), (b'e', 5084), (b';', 5085), (b'c', 5086), (b'e', 5087), (b';', 5088),
+    (b'B', 5089), (b'P', 5090), (b';', 5091), (b'P', 5092), (b';', 5093), (b'o', 5094), (b'n', 5095), (b'i', 5096),
+    (b';', 5097), (b'A', 5098), (b'o', 5099), (b'n', 5100), (b'L', 5101), (b'R', 5102), (b'o', 5103), (b';', 5104),
+    (b'e', 5105), (b';', 5106), (b';', 5107), (b'l', 5108), (b'r', 5109), (b'c', 5110), (b'h', 5111), (b'h', 5112),
+    (b';', 5113), (b';', 5114), (b'e', 5115), (b';', 5116), (b'e', 5117), (b'n', 5118), (b'i', 5119), (b'e', 5120),
+    (b'e', 5121), (b';', 5122), (b';', 5123), (b';', 5124), (b'e', 5125), (b'y', 5126), (b';', 5127), (b';', 5128),
+    (b';', 5129), (b';', 5130), (b'd', 5131), (b'o', 5132), (b';', 5133), (b'd', 5134), (b';', 5135), (b'v', 5136),
+    (b'h', 5137), (b';', 5138), (b'r', 5139), (b';', 5140), (b'r', 5141), (b'x', 5142), (b';', 5143), (b';', 5144),
+    (b'e', 5145), (b'e', 5146), (b'i', 5147), (b';', 5148), (b'o', 5149), (b'p', 5150), (b'r', 5151), (b'i', 5152),
+    (b'e', 5153), (b'd', 5154), (b'b', 5155), (b';', 5156), (b';', 5157), (b's', 5158), (b'y', 5159), (b';', 5160),
+    (b'u', 5161), (b'e', 5162), (b'p', 5163), (b'r', 5164), (b'p', 5165), (b'o', 5166), (b'l', 5167), (b'i', 5168),
+    (b'c', 5169), (b'a', 5170), (b'i', 5171), (b'l', 5172), (b'e', 5173), (b'd', 5174), (b'w', 5175), (b'l', 5176),
+    (b's', 5177), (b't', 5178), (b';', 5179), (b';', 5180), (b';', 5181), (b';', 5182), (b';', 5183), (b'i', 5184),
+    (b'm', 5185), (b'e', 5186), (b';', 5187), (b';', 5188), (b';', 5189), (b';', 5190), (b';', 5191), (b';', 5192),
+    (b';', 5193), (b';', 5194), (b';', 5195), (b';', 5196), (b';', 5197), (b';', 5198), (b';', 5199), (b';', 5200),
+    (b';', 5201), (b';', 5202), (b';', 5203), (b';', 5204), (b'x', 5205), (b';', 5206), (b';', 5207), (b';', 5208),
+    (b';', 5209), (b';', 5210), (b';', 5211), (b';', 5212), (b';', 5213), (b'n', 5214), (b'u', 5215), (b'm', 5216),
+    (b';', 5217), (b';', 5218), (b';', 5219), (b';', 5220), (b';', 5221), (b';', 5222), (b';', 5223), (b';', 5224),
+    (b';', 5225), (b';', 5226), (b'e', 5227), (b';', 5228), (b'r', 5229), (b';', 5230), (b';', 5231), (b';', 5232),
+    (b's', 5233), (b't', 5234), (b';', 5235), (b';', 5236), (b'q', 5237), (b'e', 5238), (b'd', 5239), (b'c', 5240),
+    (b'p', 5241), (b'p', 5242), (b't', 5243), (b';', 5244), (b';', 5245), (b';', 5246), (b'n', 5247), (b'l', 5248),
+    (b';', 5249), (b';', 5250), (b's', 5251), (b';', 5252), (b'y', 5253), (b'r', 5254), (b';', 5255), (b'm', 5256),
+    (b'q', 5257), (b'e', 5258), (b'i', 5259), (b'd', 5260), (b'i', 5261), (b';', 5262), (b'u', 5263), (b';', 5264),
+    (b'e', 5265), (b';', 5266), (b't', 5267), (b'n', 5268), (b'e', 5269), (b'o', 5270), (b't', 5271), (b'd', 5272),
+    (b'r', 5273), (b';', 5274), (b';', 5275), (b';', 5276), (b';', 5277), (b';', 5278), (b'r', 5279), (b';', 5280),
+    (b';', 5281), (b'r', 5282), (b'c', 5283), (b'p', 5284), (b'p', 5285), (b't', 5286), (b';', 5287), (b'r', 5288),
+    (b'e', 5289), (b'v', 5290), (b'w', 5291), (b'n', 5292), (b'a', 5293), (b';', 5294), (b';', 5295), (b'i', 5296),
+    (b';', 5297), (b'y', 5298), (b'r', 5299), (b'h', 5300), (b';', 5301), (b'o', 5302), (b';', 5303), (b'n', 5304),
+    (b'e', 5305), (b';', 5306), (b'e', 5307), (b';', 5308), (b'y', 5309), (b't', 5310), (b';', 5311), (b';', 5312),
+    (b'n', 5313), (b';', 5314), (b'm', 5315), (b';', 5316), (b'e', 5317), (b'x', 5318), (b'n', 5319), (b'p', 5320),
+    (b'r', 5321), (b';', 5322), (b'd', 5323), (b'n', 5324), (b'u', 5325), (b'u', 5326), (b'e', 5327), (b'r', 5328),
+    (b'o', 5329), (b'a', 5330), (b'r', 5331), (b'n', 5332), (b'p', 5333), (b'k', 5334), (b';', 5335), (b';', 5336),
+    (b';', 5337), (b';', 5338), (b'l', 5339), (b'a', 5340), (b';', 5341), (b'e', 5342), (b'r', 5343), (b'n', 5344),
+    (b';', 5345), (b'n', 5346), (b';', 5347), (b'e', 5348), (b't', 5349), (b'e', 5350), (b't', 5351), (b';', 5352),
+    (b';', 5353), (b's', 5354), (b'v', 5355), (b'3', 5356), (b'4', 5357), (b';', 5358), (b'l', 5359), (b';', 5360),
+    (b'o', 5361), (b';', 5362), (b'c', 5363), (b'o', 5364), (b';', 5365), (b'n', 5366), (b's', 5367), (b't', 5368),
+    (b';', 5369), (b'D', 5370), (b'r', 5371), (b';', 5372), (b';', 5373), (b';', 5374), (b';', 5375), (b't', 5376),
+    (b'e', 5377), (b'n', 5378), (b'e', 5379), (b'g', 5380), (b';', 5381), (b'g', 5382), (b';', 5383), (b';', 5384),
+    (b';', 5385), (b';', 5386), (b'l', 5387), (b';', 5388), (b'i', 5389), (b'2', 5390), (b'3', 5391), (b'4', 5392),
+    (b'5', 5393), (b'6', 5394), (b'8', 5395), (b'3', 5396), (b'5', 5397), (b'4', 5398), (b'5', 5399), (b'8', 5400),
+    (b'5', 5401), (b'6', 5402), (b'8', 5403), (b'8', 5404), (b';', 5405), (b';', 5406), (b'e', 5407), (b';', 5408),
+    (b'd', 5409), (b'e', 5410), (b';', 5411), (b'a', 5412), (b';', 5413), (b't', 5414), (b's', 5415), (b';', 5416),
+    (b'r', 5417), (b';', 5418), (b';', 5419), (b';', 5420), (b';', 5421), (b';', 5422), (b';', 5423), (b';', 5424),
+    (b'r', 5425), (b's', 5426), (b'p', 5427), (b'r', 5428), (b't', 5429), (b'l', 5430), (b'q', 5431), (b's', 5432),
+    (b'm', 5433), (b'n', 5434), (b'p', 5435), (b't', 5436), (b'y', 5437), (b'i', 5438), (b';', 5439), (b';', 5440),
+    (b's', 5441), (b'p', 5442), (b'n', 5443), (b'r', 5444), (b'r', 5445), (b';', 5446), (b't', 5447), (b'e', 5448),
+    (b'i', 5449), (b'r', 5450), (b'h', 5451), (b'k', 5452), (b'l', 5453), (b'n', 5454), (b'e', 5455), (b';', 5456),
+    (b';', 5457), (b'e', 5458), (b't', 5459), (b';', 5460), (b'n', 5461), (b';', 5462), (b';', 5463), (b';', 5464),
+    (b';', 5465), (b'i', 5466), (b'a', 5467), (b';', 5468), (b';', 5469), (b'e', 5470), (b';', 5471), (b't', 5472),
+    (b't', 5473), (b'l', 5474), (b'e', 5475), (b'c', 5476), (b'r', 5477), (b'o', 5478), (b';', 5479), (b';', 5480),
+    (b't', 5481), (b';', 5482), (b'o', 5483), (b';', 5484), (b'v', 5485), (b';', 5486), (b'e', 5487), (b';', 5488),
+    (b';', 5489), (b';', 5490), (b'y', 5491), (b';', 5492), (b';', 5493), (b'v', 5494), (b'l', 5495), (b'n', 5496),
+    (b';', 5497), (b'l', 5498), (b';', 5499), (b'e', 5500), (b't', 5501), (b'n', 5502), (b'a', 5503), (b';', 5504),
+    (b'e', 5505), (b';', 5506), (b';', 5507), (b'f', 5508), (b's', 5509), (b'k', 5510), (b'p', 5511), (b'l', 5512),
+    (b'i', 5513), (b'l', 5514), (b'l', 5515), (b';', 5516), (b';', 5517), (b';', 5518), (b'e', 5519), (b'k', 5520),
+    (b';', 5521), (b'l', 5522), (b'n', 5523), (b'l', 5524), (b';', 5525), (b';', 5526), (b'r', 5527), (b'a', 5528),
+    (b'h', 5529), (b'r', 5530), (b'a', 5531), (b'e', 5532), (b'i', 5533), (b'h', 5534), (b'a', 5535), (b';', 5536),
+    (b't', 5537), (b's', 5538), (b'p', 5539), (b'o', 5540), (b'q', 5541), (b't', 5542), (b'i', 5543), (b't', 5544),
+    (b'r', 5545), (b';', 5546), (b';', 5547), (b'l', 5548), (b';', 5549), (b';', 5550), (b'n', 5551), (b'd', 5552),
+    (b';', 5553), (b't', 5554), (b't', 5555), (b'r', 5556), (b';', 5557), (b';', 5558), (b';', 5559), (b';', 5560),
+    (b';', 5561), (b'e', 5562), (b'a', 5563), (b'i', 5564), (b'r', 5565), (b';', 5566), (b's', 5567), (b'e', 5568),
+    (b't', 5569), (b'r', 5570), (b'g', 5571), (b't', 5572), (b';', 5573), (b'n', 5574), (b';', 5575), (b'd', 5576),
+    (b';', 5577), (b'o', 5578), (b';', 5579), (b';', 5580), (b';', 5581), (b'r', 5582), (b'k', 5583), (b';', 5584),
+    (b';', 5585), (b'e', 5586), (b's', 5587), (b'r', 5588), (b's', 5589), (b'r', 5590), (b';', 5591), (b';', 5592),
+    (b'h', 5593), (b'a', 5594), (b'n', 5595), (b';', 5596), (b's', 5597), (b'o', 5598), (b'r', 5599), (b'a', 5600),
+    (b';', 5601), (b'r', 5602), (b';', 5603), (b't', 5604), (b'r', 5605), (b't', 5606), (b';', 5607), (b'b', 5608),
+    (b'd', 5609), (b's', 5610), (b's', 5611), (b's', 5612), (b'm', 5613), (b';', 5614), (b'a', 5615), (b'r', 5616),
+    (b't', 5617), (b'h', 5618), (b'h', 5619), (b';', 5620), (b'e', 5621), (b';', 5622), (b';', 5623), (b'o', 5624),
+    (b';', 5625), (b'a', 5626), (b';', 5627), (b'e', 5628), (b'n', 5629), (b'l', 5630), (b';', 5631), (b'd', 5632),
+    (b';', 5633), (b';', 5634), (b'k', 5635), (b';', 5636), (b'o', 5637), (b';', 5638), (b'v', 5639), (b'r', 5640),
+    (b';', 5641), (b't', 5642), (b';', 5643), (b'l', 5644), (b';', 5645), (b';', 5646), (b';', 5647), (b';', 5648),
+    (b';', 5649), (b';', 5650), (b'a', 5651), (b'r', 5652), (b';', 5653), (b'l', 5654), (b';', 5655), (b';', 5656),
+    (b';', 5657), (b'e', 5658), (b';', 5659), (b'E', 5660), (b'd', 5661), (b'v', 5662), (b';', 5663), (b'v', 5664),
+    (b'l', 5665), (b'l', 5666), (b';', 5667), (b'n', 5668), (b'e', 5669), (b';', 5670), (b'e', 5671), (b';', 5672),
+    (b';', 5673), (b'c', 5674), (b'w', 5675), (b't', 5676), (b';', 5677), (b'e', 5678), (b'e', 5679), (b't', 5680),
+    (b';', 5681), (b'q', 5682), (b';', 5683), (b';', 5684), (b'b', 5685), (b'p', 5686), (b';', 5687), (b';', 5688),
+    (b'e', 5689), (b';', 5690), (b'e', 5691), (b';', 5692), (b';', 5693), (b'e', 5694), (b'e', 5695), (b'n', 5696),
+    (b'o', 5697), (b';', 5698), (b'h', 5699), (b'r', 5700), (b'h', 5701), (b'i', 5702), (b'r', 5703), (b'i', 5704),
+    (b'r', 5705), (b'i', 5706), (b';', 5707), (b';', 5708), (b'k', 5709), (b';', 5710), (b'o', 5711), (b'r', 5712),
+    (b'e', 5713), (b';', 5714), (b';', 5715), (b'c', 5716), (b'd', 5717), (b';', 5718), (b';', 5719), (b'e', 5720),
+    (b';', 5721), (b';', 5722), (b';', 5723), (b's', 5724), (b';', 5725), (b';', 5726), (b';', 5727), (b'o', 5728),
+    (b's', 5729), (b';', 5730), (b';', 5731), (b';', 5732), (b';', 5733), (b'o', 5734), (b'f', 5735), (b'p', 5736),
+    (b'h', 5737), (b'e', 5738), (b's', 5739), (b';', 5740), (b'l', 5741), (b'm', 5742), (b';', 5743), (b't', 5744),
+    (b'd', 5745), (b'l', 5746), (b'n', 5747), (b't', 5748), (b';', 5749), (b'f', 5750), (b'k', 5751), (b'v', 5752),
+    (b'c', 5753), (b';', 5754), (b'i', 5755), (b'o', 5756), (b'u', 5757), (b';', 5758), (b'n', 5759), (b'i', 5760),
+    (b'w', 5761), (b'i', 5762), (b';', 5763), (b';', 5764), (b'p', 5765), (b'u', 5766), (b'q', 5767), (b'a', 5768),
+    (b'e', 5769), (b's', 5770), (b'i', 5771), (b';', 5772), (b's', 5773), (b';', 5774), (b'm', 5775), (b'l', 5776),
+    (b'i', 5777), (b'u', 5778), (b'o', 5779), (b';', 5780), (b'l', 5781), (b'p', 5782), (b'e', 5783), (b'r', 5784),
+    (b'n', 5785), (b';', 5786), (b'e', 5787), (b';', 5788), (b'l', 5789), (b';', 5790), (b'e', 5791), (b';', 5792),
+    (b't', 5793), (b';', 5794), (b';', 5795), (b'e', 5796), (b';', 5797), (b'p', 5798), (b';', 5799), (b'f', 5800),
+    (b';', 5801), (b's', 5802), (b'k', 5803), (b'p', 5804), (b'l', 5805), (b'i', 5806), (b'l', 5807), (b';', 5808),
+    (b'l', 5809), (b';', 5810), (b'n', 5811), (b';', 5812), (b';', 5813), (b'e', 5814), (b'k', 5815), (b';', 5816),
+    (b'l', 5817), (b'n', 5818), (b'l', 5819), (b';', 5820), (b'a', 5821), (b';', 5822), (b'r', 5823), (b'n', 5824),
+    (b'a', 5825), (b';', 5826), (b't', 5827), (b'r', 5828), (b';', 5829), (b';', 5830), (b'l', 5831), (b'a', 5832),
+    (b'h', 5833), (b'l', 5834), (b'r', 5835), (b's', 5836), (b't', 5837), (b'g', 5838), (b';', 5839), (b';', 5840),
+    (b't', 5841), (b';', 5842), (b';', 5843), (b';', 5844), (b';', 5845), (b';', 5846), (b's', 5847), (b'e', 5848),
+    (b't', 5849), (b'i', 5850), (b';', 5851), (b'o', 5852), (b';', 5853), (b'r', 5854), (b'e', 5855), (b's', 5856),
+    (b';', 5857), (b';', 5858), (b't', 5859), (b'a', 5860), (b'e', 5861), (b';', 5862), (b'n', 5863), (b';', 5864),
+    (b'l', 5865), (b';', 5866), (b';', 5867), (b'm', 5868), (b'i', 5869), (b';', 5870), (b';', 5871), (b';', 5872),
+    (b';', 5873), (b'k', 5874), (b';', 5875), (b'o', 5876), (b'r', 5877), (b'n', 5878), (b';', 5879), (b'n', 5880),
+    (b';', 5881), (b'y', 5882), (b'm', 5883), (b'p', 5884), (b';', 5885), (b'f', 5886), (b'v', 5887), (b't', 5888),
+    (b';', 5889), (b';', 5890), (b';', 5891), (b';', 5892), (b'u', 5893), (b'r', 5894), (b';', 5895), (b's', 5896),
+    (b'p', 5897), (b'r', 5898), (b';', 5899), (b';', 5900), (b'y', 5901), (b'r', 5902), (b's', 5903), (b';', 5904),
+    (b's', 5905), (b';', 5906), (b's', 5907), (b';', 5908), (b'e', 5909), (b's', 5910), (b';', 5911), (b'e', 5912),
+    (b's', 5913), (b'e', 5914), (b'f', 5915), (b';', 5916), (b'n', 5917), (b'e', 5918), (b'f', 5919), (b';', 5920),
+    (b'g', 5921), (b';', 5922), (b't', 5923), (b'o', 5924), (b'l', 5925), (b';', 5926), (b';', 5927), (b'u', 5928),
+    (b'r', 5929), (b't', 5930), (b'm', 5931), (b'b', 5932), (b'p', 5933), (b'p', 5934), (b'u', 5935), (b'q', 5936),
+    (b'a', 5937), (b'e', 5938), (b's', 5939), (b'i', 5940), (b't', 5941), (b'u', 5942), (b'o', 5943), (b'o', 5944),
+    (b'u', 5945), (b'r', 5946), (b'l', 5947), (b';', 5948), (b';', 5949), (b'u', 5950), (b't', 5951), (b'm', 5952),
+    (b'b', 5953), (b'p', 5954), (b';', 5955), (b'k', 5956), (b';', 5957), (b'o', 5958), (b'r', 5959), (b';', 5960),
+    (b't', 5961), (b'n', 5962), (b'l', 5963), (b'c', 5964), (b'4', 5965), (b'f', 5966), (b';', 5967), (b's', 5968),
+    (b'v', 5969), (b'a', 5970), (b's', 5971), (b'p', 5972), (b';', 5973), (b'm', 5974), (b';', 5975), (b';', 5976),
+    (b';', 5977), (b'b', 5978), (b'd', 5979), (b't', 5980), (b'r', 5981), (b'r', 5982), (b'e', 5983), (b';', 5984),
+    (b'g', 5985), (b't', 5986), (b'n', 5987), (b'u', 5988), (b';', 5989), (b'm', 5990), (b'i', 5991), (b';', 5992),
+    (b'k', 5993), (b';', 5994), (b'a', 5995), (b'e', 5996), (b';', 5997), (b'e', 5998), (b';', 5999), (b';', 6000),
+    (b'c', 6001), (b';', 6002), (b't', 6003), (b'e', 6004), (b';', 6005), (b';', 6006), (b';', 6007), (b'n', 6008),
+    (b'p', 6009), (b';', 6010), (b';', 6011), (b';', 6012), (b'o', 6013), (b'n', 6014), (b'p', 6015), (b';', 6016),
+    (b';', 6017), (b'o', 6018), (b'r', 6019), (b'n', 6020), (b'p', 6021), (b';', 6022), (b';', 6023), (b';', 6024),
+    (b'e', 6025), (b';', 6026), (b';', 6027), (b'l', 6028), (b';', 6029), (b';', 6030), (b't', 6031), (b's', 6032),
+    (b'p', 6033), (b't', 6034), (b'i', 6035), (b';', 6036), (b'o', 6037), (b'o', 6038), (b'g', 6039), (b'b', 6040),
+    (b'p', 6041), (b'e', 6042), (b'i', 6043), (b';', 6044), (b'r', 6045), (b';', 6046), (b'p', 6047), (b'r', 6048),
+    (b';', 6049), (b';', 6050), (b';', 6051), (b';', 6052), (b';', 6053), (b'E', 6054), (b'e', 6055), (b'E', 6056),
+    (b'e', 6057), (b'a', 6058), (b';', 6059), (b'r', 6060), (b';', 6061), (b'q', 6062), (b'p', 6063), (b'h', 6064),
+    (b';', 6065), (b';', 6066), (b';', 6067), (b';', 6068), (b';', 6069), (b';', 6070), (b';', 6071), (b's', 6072),
+    (b'e', 6073), (b';', 6074), (b';', 6075), (b'p', 6076), (b's', 6077), (b';', 6078), (b'e', 6079), (b'e', 6080),
+    (b';', 6081), (b'e', 6082), (b'n', 6083), (b'f', 6084), (b'r', 6085), (b';', 6086), (b';', 6087), (b';', 6088),
+    (b'u', 6089), (b';', 6090), (b';', 6091), (b'a', 6092), (b';', 6093), (b'e', 6094), (b'l', 6095), (b';', 6096),
+    (b';', 6097), (b'l', 6098), (b's', 6099), (b';', 6100), (b';', 6101), (b't', 6102), (b'a', 6103), (b'D', 6104),
+    (b'D', 6105), (b'M', 6106), (b'P', 6107), (b'T', 6108), (b'i', 6109), (b'u', 6110), (b';', 6111), (b'e', 6112),
+    (b';', 6113), (b'r', 6114), (b'u', 6115), (b'r', 6116), (b';', 6117), (b'h', 6118), (b';', 6119), (b';', 6120),
+    (b't', 6121), (b'd', 6122), (b'e', 6123), (b';', 6124), (b'a', 6125), (b'C', 6126), (b'D', 6127), (b'L', 6128),
+    (b'R', 6129), (b'U', 6130), (b'V', 6131), (b'r', 6132), (b'e', 6133), (b'f', 6134), (b'g', 6135), (b'e', 6136),
+    (b'r', 6137), (b';', 6138), (b';', 6139), (b';', 6140), (b';', 6141), (b't', 6142), (b'm', 6143), (b'e', 6144),
+    (b'n', 6145), (b'i', 6146), (b'b', 6147), (b';', 6148), (b'n', 6149), (b'S', 6150), (b'V', 6151), (b';', 6152),
+    (b'r', 6153), (b';', 6154), (b';', 6155), (b';', 6156), (b'r', 6157), (b';', 6158), (b't', 6159), (b'n', 6160),
+    (b';', 6161), (b'w', 6162), (b'u', 6163), (b';', 6164), (b';', 6165), (b'a', 6166), (b's', 6167), (b'a', 6168),
+    (b'e', 6169), (b'b', 6170), (b';', 6171), (b';', 6172), (b';', 6173), (b';', 6174), (b';', 6175), (b'e', 6176),
+    (b';', 6177), (b';', 6178), (b'g', 6179), (b'r', 6180), (b'i', 6181), (b'u', 6182), (b'w', 6183), (b'o', 6184),
+    (b'g', 6185), (b'e', 6186), (b'i', 6187), (b'D', 6188), (b'T', 6189), (b'V', 6190), (b'c', 6191), (b'r', 6192),
+    (b'g', 6193), (b'u', 6194), (b'l', 6195), (b'e', 6196), (b's', 6197), (b'a', 6198), (b'l', 6199), (b'r', 6200),
+    (b';', 6201), (b'f', 6202), (b'g', 6203), (b'f', 6204), (b'g', 6205), (b'e', 6206), (b'i', 6207), (b';', 6208),
+    (b'S', 6209), (b't', 6210), (b'l', 6211), (b';', 6212), (b';', 6213), (b';', 6214), (b'v', 6215), (b'G', 6216),
+    (b'L', 6217), (b'e', 6218), (b'k', 6219), (b'a', 6220), (b'g', 6221), (b'C', 6222), (b'b', 6223), (b'm', 6224),
+    (b'a', 6225), (b's', 6226), (b'a', 6227), (b'p', 6228), (b't', 6229), (b's', 6230), (b't', 6231), (b'c', 6232),
+    (b'e', 6233), (b'h', 6234), (b'a', 6235), (b's', 6236), (b'c', 6237), (b'e', 6238), (b'd', 6239), (b't', 6240),
+    (b';', 6241), (b';', 6242), (b';', 6243), (b';', 6244), (b'n', 6245), (b'r', 6246), (b';', 6247), (b';', 6248),
+    (b';', 6249), (b'r', 6250), (b'a', 6251), (b'r', 6252), (b'l', 6253), (b'n', 6254), (b'r', 6255), (b'e', 6256),
+    (b't', 6257), (b't', 6258), (b';', 6259), (b';', 6260), (b';', 6261), (b';', 6262), (b'e', 6263), (b'n', 6264),
+    (b'r', 6265), (b'e', 6266), (b'o', 6267), (b'l', 6268), (b'e', 6269), (b'r', 6270), (b'p', 6271), (b'e', 6272),
+    (b'r', 6273), (b'm', 6274), (b'a', 6275), (b'l', 6276), (b';', 6277), (b';', 6278), (b';', 6279), (b';', 6280),
+    (b';', 6281), (b'o', 6282), (b'e', 6283), (b'i', 6284), (b'p', 6285), (b'i', 6286), (b';', 6287), (b'I', 6288),
+    (b'S', 6289), (b'U', 6290), (b';', 6291), (b'E', 6292), (b'd', 6293), (b'a', 6294), (b'e', 6295), (b';', 6296),
+    (b';', 6297), (b';', 6298), (b'o', 6299), (b'p', 6300), (b'a', 6301), (b'q', 6302), (b'u', 6303), (b'i', 6304),
+    (b'D', 6305), (b';', 6306), (b';', 6307), (b'i', 6308), (b';', 6309), (b';', 6310), (b';', 6311), (b'a', 6312),
+    (b'r', 6313), (b'a', 6314), (b'l', 6315), (b'w', 6316), (b'A', 6317), (b'l', 6318), (b'r', 6319), (b'w', 6320),
+    (b'a', 6321), (b'e', 6322), (b'i', 6323), (b'n', 6324), (b';', 6325), (b';', 6326), (b';', 6327), (b'a', 6328),
+    (b'i', 6329), (b';', 6330), (b';', 6331), (b';', 6332), (b';', 6333), (b'd', 6334), (b';', 6335), (b';', 6336),
+    (b';', 6337), (b'm', 6338), (b';', 6339), (b'p', 6340), (b';', 6341), (b'a', 6342), (b'b', 6343), (b';', 6344),
+    (b'r', 6345), (b';', 6346), (b';', 6347), (b'e', 6348), (b'q', 6349), (b';', 6350), (b'n', 6351), (b'n', 6352),
+    (b's', 6353), (b'i', 6354), (b'm', 6355), (b';', 6356), (b';', 6357), (b'g', 6358), (b'r', 6359), (b';', 6360),
+    (b'e', 6361), (b'v', 6362), (b';', 6363), (b'n', 6364), (b';', 6365), (b'c', 6366), (b';', 6367), (b't', 6368),
+    (b'u', 6369), (b'm', 6370), (b'u', 6371), (b'r', 6372), (b'a', 6373), (b'u', 6374), (b';', 6375), (b'g', 6376),
+    (b';', 6377), (b'o', 6378), (b'q', 6379), (b'r', 6380), (b'v', 6381), (b';', 6382), (b';', 6383), (b';', 6384),
+    (b'u', 6385), (b's', 6386), (b'e', 6387), (b';', 6388), (b';', 6389), (b'u', 6390), (b';', 6391), (b';', 6392),
+    (b';', 6393), (b';', 6394), (b'u', 6395), (b';', 6396), (b';', 6397), (b';', 6398), (b';', 6399), (b';', 6400),
+    (b'm', 6401), (b'v', 6402), (b'd', 6403), (b'a', 6404), (b';', 6405), (b'a', 6406), (b'd', 6407), (b'n', 6408),
+    (b';', 6409), (b'r', 6410), (b'i', 6411), (b';', 6412), (b'q', 6413), (b';', 6414), (b';', 6415), (b'm', 6416),
+    (b'x', 6417), (b't', 6418), (b';', 6419), (b';', 6420), (b';', 6421), (b'l', 6422), (b'r', 6423), (b';', 6424),
+    (b'p', 6425), (b'a', 6426), (b';', 6427), (b';', 6428), (b';', 6429), (b';', 6430), (b'm', 6431), (b'q', 6432),
+    (b'e', 6433), (b'e', 6434), (b';', 6435), (b'r', 6436), (b'n', 6437), (b';', 6438), (b';', 6439), (b';', 6440),
+    (b'w', 6441), (b';', 6442), (b'r', 6443), (b'q', 6444), (b'v', 6445), (b';', 6446), (b'd', 6447), (b'a', 6448),
+    (b';', 6449), (b'o', 6450), (b';', 6451), (b';', 6452), (b';', 6453), (b';', 6454), (b'o', 6455), (b'u', 6456),
+    (b's', 6457), (b'a', 6458), (b'b', 6459), (b'r', 6460), (b'w', 6461), (b'r', 6462), (b'o', 6463), (b';', 6464),
+    (b';', 6465), (b';', 6466), (b'e', 6467), (b'r', 6468), (b';', 6469), (b';', 6470), (b';', 6471), (b';', 6472),
+    (b';', 6473), (b';', 6474), (b'r', 6475), (b';', 6476), (b'e', 6477), (b';', 6478), (b';', 6479), (b';', 6480),
+    (b';', 6481), (b'n', 6482), (b';', 6483), (b'n', 6484), (b't', 6485), (b';', 6486), (b';', 6487), (b'D', 6488),
+    (b's', 6489), (b'a', 6490), (b'n', 6491), (b'g', 6492), (b';', 6493), (b';', 6494), (b';', 6495), (b';', 6496),
+    (b'n', 6497), (b';', 6498), (b';', 6499), (b';', 6500), (b';', 6501), (b';', 6502), (b';', 6503), (b';', 6504),
+    (b';', 6505), (b';', 6506), (b';', 6507), (b';', 6508), (b';', 6509), (b';', 6510), (b';', 6511), (b';', 6512),
+    (b';', 6513), (b';', 6514), (b';', 6515), (b'n', 6516), (b';', 6517), (b'o', 6518), (b';', 6519), (b'o', 6520),
+    (b';', 6521), (b't', 6522), (b'r', 6523), (b';', 6524), (b';', 6525), (b'e', 6526), (b'l', 6527), (b's', 6528),
+    (b';', 6529), (b'e', 6530), (b';', 6531), (b';', 6532), (b';', 6533), (b'r', 6534), (b';', 6535), (b'u', 6536),
+    (b';', 6537), (b';', 6538), (b'o', 6539), (b'o', 6540), (b';', 6541), (b'f', 6542), (b'g', 6543), (b';', 6544),
+    (b';', 6545), (b';', 6546), (b';', 6547), (b';', 6548), (b';', 6549), (b';', 6550), (b';', 6551), (b';', 6552),
+    (b'n', 6553), (b'r', 6554), (b';', 6555), (b'i', 6556), (b';', 6557), (b';', 6558), (b'r', 6559), (b'a', 6560),
+    (b'h', 6561), (b'd', 6562), (b';', 6563), (b't', 6564), (b';', 6565), (b';', 6566), (b';', 6567), (b';', 6568),
+    (b';', 6569), (b';', 6570), (b';', 6571), (b';', 6572), (b'y', 6573), (b';', 6574), (b';', 6575), (b';', 6576),
+    (b's', 6577), (b';', 6578), (b';', 6579), (b';', 6580), (b';', 6581), (b'm', 6582), (b';', 6583), (b';', 6584),
+    (b';', 6585), (b';', 6586), (b'd', 6587), (b'u', 6588), (b';', 6589), (b';', 6590), (b';', 6591), (b'r', 6592),
+    (b'a', 6593), (b'r', 6594), (b'r', 6595), (b'f', 6596), (b'g', 6597), (b'r', 6598), (b'n', 6599), (b';', 6600),
+    (b'o', 6601), (b';', 6602), (b'p', 6603), (b't', 6604), (b'g', 6605), (b'q', 6606), (b'r', 6607), (b'm', 6608),
+    (b';', 6609), (b';', 6610), (b';', 6611), (b'e', 6612), (b';', 6613), (b';', 6614), (b';', 6615), (b'a', 6616),
+    (b'o', 6617), (b'f', 6618), (b'p', 6619), (b'g', 6620), (b'r', 6621), (b';', 6622), (b's', 6623), (b';', 6624),
+    (b';', 6625), (b'e', 6626), (b';', 6627), (b'e', 6628), (b';', 6629), (b';', 6630), (b';', 6631), (b';', 6632),
+    (b';', 6633), (b';', 6634), (b';', 6635), (b't', 6636), (b';', 6637), (b'a', 6638), (b'r', 6639), (b'e', 6640),
+    (b'e', 6641), (b';', 6642), (b'd', 6643), (b'l', 6644), (b'u', 6645), (b';', 6646), (b';', 6647), (b'e', 6648),
+    (b';', 6649), (b';', 6650), (b';', 6651), (b';', 6652), (b';', 6653), (b'u', 6654), (b';', 6655), (b';', 6656),
+    (b';', 6657), (b'a', 6658), (b'r', 6659), (b'i', 6660), (b'a', 6661), (b';', 6662), (b';', 6663), (b';', 6664),
+    (b'x', 6665), (b'l', 6666), (b';', 6667), (b';', 6668), (b';', 6669), (b'o', 6670), (b';', 6671), (b'w', 6672),
+    (b';', 6673), (b';', 6674), (b';', 6675), (b's', 6676), (b'a', 6677), (b'r', 6678), (b'i', 6679), (b'a', 6680),
+    (b';', 6681), (b';', 6682), (b'o', 6683), (b'a', 6684), (b'b', 6685), (b'c', 6686), (b'a', 6687), (b'b', 6688),
+    (b'c', 6689), (b'l', 6690), (b';', 6691), (b't', 6692), (b';', 6693), (b'q', 6694), (b';', 6695), (b';', 6696),
+    (b'a', 6697), (b';', 6698), (b';', 6699), (b'm', 6700), (b'p', 6701), (b';', 6702), (b'e', 6703), (b'e', 6704),
+    (b't', 6705), (b'q', 6706), (b't', 6707), (b';', 6708), (b'g', 6709), (b';', 6710), (b';', 6711), (b';', 6712),
+    (b';', 6713), (b'n', 6714), (b';', 6715), (b'e', 6716), (b';', 6717), (b'e', 6718), (b';', 6719), (b'w', 6720),
+    (b';', 6721), (b';', 6722), (b';', 6723), (b';', 6724), (b';', 6725), (b's', 6726), (b'n', 6727), (b';', 6728),
+    (b'f', 6729), (b';', 6730), (b'e', 6731), (b';', 6732), (b';', 6733), (b';', 6734), (b'a', 6735), (b'e', 6736),
+    (b';', 6737), (b';', 6738), (b';', 6739), (b';', 6740), (b'k', 6741), (b';', 6742), (b'o', 6743), (b';', 6744),
+    (b'h', 6745), (b';', 6746), (b'i', 6747), (b'r', 6748), (b';', 6749), (b';', 6750), (b';', 6751), (b'm', 6752),
+    (b'o', 6753), (b'n', 6754), (b'p', 6755), (b'r', 6756), (b';', 6757), (b'p', 6758), (b'q', 6759), (b'i', 6760),
+    (b'm', 6761), (b';', 6762), (b';', 6763), (b'a', 6764), (b'n', 6765), (b'r', 6766), (b';', 6767), (b';', 6768),
+    (b';', 6769), (b';', 6770), (b'n', 6771), (b't', 6772), (b'q', 6773), (b';', 6774), (b';', 6775), (b'y', 6776),
+    (b';', 6777), (b';', 6778), (b's', 6779), (b';', 6780), (b';', 6781), (b';', 6782), (b';', 6783), (b'm', 6784),
+    (b';', 6785), (b';', 6786), (b'a', 6787), (b';', 6788), (b';', 6789), (b'd', 6790), (b'u', 6791), (b';', 6792),
+    (b';', 6793), (b'r', 6794), (b';', 6795), (b'e', 6796), (b'r', 6797), (b';', 6798), (b';', 6799), (b';', 6800),
+    (b'r', 6801), (b'a', 6802), (b'e', 6803), (b'i', 6804), (b'q', 6805), (b'h', 6806), (b'd', 6807), (b';', 6808),
+    (b'a', 6809), (b';', 6810), (b's', 6811), (b';', 6812), (b'n', 6813), (b';', 6814), (b';', 6815), (b';', 6816),
+    (b';', 6817), (b'r', 6818), (b'r', 6819), (b';', 6820), (b';', 6821), (b';', 6822), (b';', 6823), (b'n', 6824),
+    (b';', 6825), (b'w', 6826), (b';', 6827), (b'u', 6828), (b';', 6829), (b';', 6830), (b'i', 6831), (b'a', 6832),
+    (b';', 6833), (b';', 6834), (b';', 6835), (b's', 6836), (b'r', 6837), (b'e', 6838), (b';', 6839), (b's', 6840),
+    (b';', 6841), (b';', 6842), (b';', 6843), (b'u', 6844), (b';', 6845), (b';', 6846), (b';', 6847), (b'e', 6848),
+    (b';', 6849), (b'e', 6850), (b';', 6851), (b';', 6852), (b';', 6853), (b';', 6854), (b';', 6855), (b'h', 6856),
+    (b';', 6857), (b't', 6858), (b't', 6859), (b's', 6860), (b'r', 6861), (b';', 6862), (b'e', 6863), (b'n', 6864),
+    (b';', 6865), (b';', 6866), (b';', 6867), (b'p', 6868), (b'r', 6869), (b';', 6870), (b'p', 6871), (b'q', 6872),
+    (b'i', 6873), (b'm', 6874), (b';', 6875), (b'b', 6876), (b't', 6877), (b'l', 6878), (b'b', 6879), (b'r', 6880),
+    (b't', 6881), (b's', 6882), (b';', 6883), (b'e', 6884), (b'n', 6885), (b';', 6886), (b';', 6887), (b';', 6888),
+    (b';', 6889), (b'w', 6890), (b';', 6891), (b';', 6892), (b';', 6893), (b';', 6894), (b';', 6895), (b';', 6896),
+    (b'o', 6897), (b'y', 6898), (b';', 6899), (b'p', 6900), (b'i', 6901), (b';', 6902), (b';', 6903), (b';', 6904),
+    (b'a', 6905), (b';', 6906), (b';', 6907), (b';', 6908), (b'k', 6909), (b';', 6910), (b'l', 6911), (b';', 6912),
+    (b'u', 6913), (b's', 6914), (b'e', 6915), (b'u', 6916), (b';', 6917), (b'd', 6918), (b';', 6919), (b';', 6920),
+    (b';', 6921), (b';', 6922), (b';', 6923), (b';', 6924), (b'e', 6925), (b';', 6926), (b'w', 6927), (b'a', 6928),
+    (b'o', 6929), (b'n', 6930), (b'r', 6931), (b';', 6932), (b'e', 6933), (b';', 6934), (b';', 6935), (b'e', 6936),
+    (b';', 6937), (b'i', 6938), (b'p', 6939), (b'h', 6940), (b';', 6941), (b'p', 6942), (b';', 6943), (b'm', 6944),
+    (b's', 6945), (b's', 6946), (b't', 6947), (b'a', 6948), (b';', 6949), (b';', 6950), (b';', 6951), (b';', 6952),
+    (b';', 6953), (b';', 6954), (b';', 6955), (b'g', 6956), (b';', 6957), (b';', 6958), (b';', 6959), (b';', 6960),
+    (b';', 6961), (b';', 6962), (b';', 6963), (b';', 6964), (b';', 6965), (b';', 6966), (b';', 6967), (b';', 6968),
+    (b';', 6969), (b'r', 6970), (b'n', 6971), (b's', 6972), (b';', 6973), (b'l', 6974), (b'D', 6975), (b';', 6976),
+    (b';', 6977), (b';', 6978), (b'o', 6979), (b'o', 6980), (b'i', 6981), (b'l', 6982), (b'i', 6983), (b's', 6984),
+    (b'r', 6985), (b'n', 6986), (b'I', 6987), (b'c', 6988), (b'C', 6989), (b'd', 6990), (b'i', 6991), (b';', 6992),
+    (b'n', 6993), (b'l', 6994), (b'o', 6995), (b'o', 6996), (b'e', 6997), (b'o', 6998), (b'i', 6999), (b'p', 7000),
+    (b'e', 7001), (b'o', 7002), (b'v', 7003), (b't', 7004), (b'h', 7005), (b';', 7006), (b'A', 7007), (b'o', 7008),
+    (b';', 7009), (b'a', 7010), (b'r', 7011), (b';', 7012), (b'l', 7013), (b'r', 7014), (b't', 7015), (b'm', 7016),
+    (b'e', 7017), (b't', 7018), (b'E', 7019), (b'F', 7020), (b'G', 7021), (b'L', 7022), (b'S', 7023), (b'T', 7024),
+    (b'S', 7025), (b't', 7026), (b'n', 7027), (b'a', 7028), (b'r', 7029), (b';', 7030), (b'l', 7031), (b'c', 7032),
+    (b'l', 7033), (b't', 7034), (b'l', 7035), (b'o', 7036), (b'l', 7037), (b'b', 7038), (b'n', 7039), (b'o', 7040),
+    (b'h', 7041), (b';', 7042), (b'A', 7043), (b'V', 7044), (b'a', 7045), (b'o', 7046), (b'e', 7047), (b'e', 7048),
+    (b't', 7049), (b'o', 7050), (b'h', 7051), (b'a', 7052), (b'l', 7053), (b'a', 7054), (b's', 7055), (b'n', 7056),
+    (b'd', 7057), (b'r', 7058), (b't', 7059), (b'h', 7060), (b't', 7061), (b'h', 7062), (b'f', 7063), (b'g', 7064),
+    (b'p', 7065), (b'r', 7066), (b'u', 7067), (b'e', 7068), (b'r', 7069), (b'e', 7070), (b';', 7071), (b';', 7072),
+    (b'k', 7073), (b'r', 7074), (b'a', 7075), (b'l', 7076), (b'e', 7077), (b'l', 7078), (b't', 7079), (b't', 7080),
+    (b'D', 7081), (b'E', 7082), (b'T', 7083), (b';', 7084), (b'E', 7085), (b'G', 7086), (b'L', 7087), (b'S', 7088),
+    (b'T', 7089), (b'e', 7090), (b'e', 7091), (b'r', 7092), (b't', 7093), (b'r', 7094), (b'e', 7095), (b'e', 7096),
+    (b'r', 7097), (b'e', 7098), (b'i', 7099), (b';', 7100), (b'l', 7101), (b';', 7102), (b'c', 7103), (b'e', 7104),
+    (b'D', 7105), (b'u', 7106), (b'e', 7107), (b's', 7108), (b';', 7109), (b'i', 7110), (b'E', 7111), (b'U', 7112),
+    (b'g', 7113), (b'r', 7114), (b'i', 7115), (b'u', 7116), (b'w', 7117), (b'o', 7118), (b'e', 7119), (b'i', 7120),
+    (b'D', 7121), (b'T', 7122), (b'V', 7123), (b'c', 7124), (b'r', 7125), (b'p', 7126), (b'r', 7127), (b'a', 7128),
+    (b'w', 7129), (b'f', 7130), (b'g', 7131), (b'A', 7132), (b'r', 7133), (b'n', 7134), (b'u', 7135), (b'n', 7136),
+    (b'q', 7137), (b's', 7138), (b't', 7139), (b't', 7140), (b'r', 7141), (b'a', 7142), (b'c', 7143), (b'u', 7144),
+    (b'l', 7145), (b'l', 7146), (b'o', 7147), (b'r', 7148), (b'r', 7149), (b'a', 7150), (b'r', 7151), (b'u', 7152),
+    (b';', 7153), (b'B', 7154), (b'D', 7155), (b'r', 7156), (b'i', 7157), (b'r', 7158), (b';', 7159), (b'r', 7160),
+    (b'f', 7161), (b'g', 7162), (b';', 7163), (b'l', 7164), (b'n', 7165), (b't', 7166), (b';', 7167), (b'e', 7168),
+    (b'a', 7169), (b'b', 7170), (b'c', 7171), (b'd', 7172), (b'e', 7173), (b'f', 7174), (b'g', 7175), (b'h', 7176),
+    (b';', 7177), (b'd', 7178), (b';', 7179), (b'q', 7180), (b';', 7181), (b't', 7182), (b'g', 7183), (b'i', 7184),
+    (b'm', 7185), (b';', 7186), (b'e', 7187), (b'e', 7188), (b'k', 7189), (b';', 7190), (b';', 7191), (b';', 7192),
+    (b';', 7193), (b';', 7194), (b's', 7195), (b'e', 7196), (b'p', 7197), (b';', 7198), (b'n', 7199), (b's', 7200),
+    (b'e', 7201), (b'z', 7202), (b'u', 7203), (b'i', 7204), (b';', 7205), (b's', 7206), (b';', 7207), (b's', 7208),
+    (b'b', 7209), (b'p', 7210), (b';', 7211), (b';', 7212), (b'o', 7213), (b'r', 7214), (b'r', 7215), (b'R', 7216),
+    (b'S', 7217), (b'a', 7218), (b'c', 7219), (b'd', 7220), (b't', 7221), (b';', 7222), (b't', 7223), (b';', 7224),
+    (b'e', 7225), (b'e', 7226), (b';', 7227), (b';', 7228), (b';', 7229), (b';', 7230), (b'p', 7231), (b';', 7232),
+    (b'p', 7233), (b's', 7234), (b'e', 7235), (b'd', 7236), (b'r', 7237), (b't', 7238), (b';', 7239), (b';', 7240),
+    (b';', 7241), (b';', 7242), (b';', 7243), (b's', 7244), (b';', 7245), (b'n', 7246), (b't', 7247), (b's', 7248),
+    (b';', 7249), (b'r', 7250), (b'a', 7251), (b'o', 7252), (b'n', 7253), (b'p', 7254), (b'w', 7255), (b';', 7256),
+    (b'r', 7257), (b's', 7258), (b't', 7259), (b';', 7260), (b';', 7261), (b'g', 7262), (b'l', 7263), (b';', 7264),
+    (b'l', 7265), (b't', 7266), (b't', 7267), (b'd', 7268), (b't', 7269), (b't', 7270), (b';', 7271), (b'l', 7272),
+    (b'x', 7273), (b';', 7274), (b'o', 7275), (b's', 7276), (b'e', 7277), (b';', 7278), (b'q', 7279), (b';', 7280),
+    (b'i', 7281), (b'w', 7282), (b'w', 7283), (b't', 7284), (b'h', 7285), (b'e', 7286), (b't', 7287), (b'e', 7288),
+    (b's', 7289), (b'l', 7290), (b'k', 7291), (b';', 7292), (b';', 7293), (b'v', 7294), (b';', 7295), (b';', 7296),
+    (b';', 7297), (b';', 7298), (b';', 7299), (b'r', 7300), (b'o', 7301), (b'p', 7302), (b't', 7303), (b'h', 7304),
+    (b'e', 7305), (b't', 7306), (b';', 7307), (b'r', 7308), (b'r', 7309), (b';', 7310), (b't', 7311), (b'g', 7312),
+    (b';', 7313), (b';', 7314), (b'r', 7315), (b'c', 7316), (b'x', 7317), (b't', 7318), (b's', 7319), (b'h', 7320),
+    (b'o', 7321), (b';', 7322), (b';', 7323), (b'r', 7324), (b';', 7325), (b'r', 7326), (b';', 7327), (b'q', 7328),
+    (b';', 7329), (b'o', 7330), (b'e', 7331), (b'p', 7332), (b'd', 7333), (b';', 7334), (b'p', 7335), (b'r', 7336),
+    (b'g', 7337), (b'r', 7338), (b';', 7339), (b';', 7340), (b's', 7341), (b't', 7342), (b';', 7343), (b';', 7344),
+    (b'n', 7345), (b'r', 7346), (b'g', 7347), (b'n', 7348), (b't', 7349), (b';', 7350), (b';', 7351), (b';', 7352),
+    (b';', 7353), (b';', 7354), (b';', 7355), (b'e', 7356), (b';', 7357), (b';', 7358), (b'r', 7359), (b'i', 7360),
+    (b'a', 7361), (b';', 7362), (b';', 7363), (b';', 7364), (b'e', 7365), (b';', 7366), (b';', 7367), (b'e', 7368),
+    (b'l', 7369), (b';', 7370), (b';', 7371), (b';', 7372), (b';', 7373), (b';', 7374), (b';', 7375), (b';', 7376),
+    (b';', 7377), (b's', 7378), (b'l', 7379), (b';', 7380), (b'r', 7381), (b';', 7382), (b'r', 7383), (b';', 7384),
+    (b';', 7385), (b';', 7386), (b't', 7387), (b'r', 7388), (b'l', 7389), (b'p', 7390), (b'q', 7391), (b'm', 7392),
+    (b';', 7393), (b'r', 7394), (b'e', 7395), (b'f', 7396), (b'i', 7397), (b';', 7398), (b';', 7399), (b'v', 7400),
+    (b';', 7401), (b';', 7402), (b'l', 7403), (b';', 7404), (b';', 7405), (b';', 7406), (b';', 7407), (b't', 7408),
+    (b'r', 7409), (b'r', 7410), (b'f', 7411), (b'g', 7412), (b'u', 7413), (b'r', 7414), (b'o', 7415), (b'c', 7416),
+    (b';', 7417), (b't', 7418), (b'i', 7419), (b';', 7420), (b't', 7421), (b';', 7422), (b's', 7423), (b'd', 7424),
+    (b'r', 7425), (b';', 7426), (b';', 7427), (b't', 7428), (b'l', 7429), (b'i', 7430), (b't', 7431), (b't', 7432),
+    (b't', 7433), (b';', 7434), (b';', 7435), (b';', 7436), (b';', 7437), (b'q', 7438), (b'e', 7439), (b'r', 7440),
+    (b'l', 7441), (b'p', 7442), (b'q', 7443), (b'm', 7444), (b';', 7445), (b';', 7446), (b';', 7447), (b';', 7448),
+    (b';', 7449), (b';', 7450), (b';', 7451), (b';', 7452), (b'q', 7453), (b'e', 7454), (b';', 7455), (b'r', 7456),
+    (b'm', 7457), (b'p', 7458), (b'm', 7459), (b'r', 7460), (b';', 7461), (b'e', 7462), (b's', 7463), (b';', 7464),
+    (b';', 7465), (b'm', 7466), (b'l', 7467), (b'r', 7468), (b'r', 7469), (b';', 7470), (b'r', 7471), (b'o', 7472),
+    (b';', 7473), (b'o', 7474), (b'r', 7475), (b';', 7476), (b'l', 7477), (b'a', 7478), (b'i', 7479), (b't', 7480),
+    (b'a', 7481), (b'e', 7482), (b'e', 7483), (b'a', 7484), (b'n', 7485), (b';', 7486), (b';', 7487), (b'c', 7488),
+    (b'h', 7489), (b'i', 7490), (b'i', 7491), (b't', 7492), (b't', 7493), (b'n', 7494), (b'u', 7495), (b'm', 7496),
+    (b'e', 7497), (b'l', 7498), (b't', 7499), (b'n', 7500), (b't', 7501), (b'l', 7502), (b';', 7503), (b'c', 7504),
+    (b't', 7505), (b';', 7506), (b'n', 7507), (b't', 7508), (b'w', 7509), (b'f', 7510), (b'n', 7511), (b'g', 7512),
+    (b'A', 7513), (b'D', 7514), (b'r', 7515), (b'w', 7516), (b'e', 7517), (b'R', 7518), (b'T', 7519), (b'V', 7520),
+    (b't', 7521), (b'r', 7522), (b'w', 7523), (b'l', 7524), (b'y', 7525), (b'd', 7526), (b'i', 7527), (b'i', 7528),
+    (b'a', 7529), (b'r', 7530), (b'r', 7531), (b'q', 7532), (b'u', 7533), (b'r', 7534), (b'e', 7535), (b'l', 7536),
+    (b'i', 7537), (b'p', 7538), (b'a', 7539), (b'H', 7540), (b'l', 7541), (b'y', 7542), (b';', 7543), (b't', 7544),
+    (b'e', 7545), (b'r', 7546), (b'e', 7547), (b'w', 7548), (b'i', 7549), (b'l', 7550), (b'T', 7551), (b'V', 7552),
+    (b'r', 7553), (b't', 7554), (b'r', 7555), (b'e', 7556), (b'n', 7557), (b'w', 7558), (b'e', 7559), (b'c', 7560),
+    (b'o', 7561), (b'w', 7562), (b't', 7563), (b'l', 7564), (b'E', 7565), (b't', 7566), (b';', 7567), (b't', 7568),
+    (b'e', 7569), (b'o', 7570), (b'A', 7571), (b'R', 7572), (b't', 7573), (b'a', 7574), (b'r', 7575), (b't', 7576),
+    (b't', 7577), (b'h', 7578), (b'a', 7579), (b'f', 7580), (b's', 7581), (b'M', 7582), (b'T', 7583), (b'V', 7584),
+    (b'e', 7585), (b's', 7586), (b'i', 7587), (b'u', 7588), (b'p', 7589), (b'e', 7590), (b'n', 7591), (b';', 7592),
+    (b'T', 7593), (b's', 7594), (b'e', 7595), (b'o', 7596), (b'q', 7597), (b'r', 7598), (b'q', 7599), (b'r', 7600),
+    (b'e', 7601), (b'l', 7602), (b'i', 7603), (b'd', 7604), (b'd', 7605), (b's', 7606), (b'T', 7607), (b'e', 7608),
+    (b't', 7609), (b'e', 7610), (b's', 7611), (b';', 7612), (b'E', 7613), (b'F', 7614), (b'T', 7615), (b'c', 7616),
+    (b'y', 7617), (b'e', 7618), (b'k', 7619), (b'n', 7620), (b';', 7621), (b's', 7622), (b'p', 7623), (b';', 7624),
+    (b'E', 7625), (b'S', 7626), (b'T', 7627), (b'o', 7628), (b'l', 7629), (b'q', 7630), (b'p', 7631), (b'l', 7632),
+    (b'o', 7633), (b'l', 7634), (b'b', 7635), (b'n', 7636), (b'o', 7637), (b';', 7638), (b'A', 7639), (b'V', 7640),
+    (b'a', 7641), (b'o', 7642), (b'e', 7643), (b'e', 7644), (b't', 7645), (b'o', 7646), (b'l', 7647), (b'r', 7648),
+    (b'y', 7649), (b'n', 7650), (b't', 7651), (b'h', 7652), (b'r', 7653), (b'c', 7654), (b't', 7655), (b'b', 7656),
+    (b'p', 7657), (b'i', 7658), (b'u', 7659), (b';', 7660), (b'E', 7661), (b'S', 7662), (b'T', 7663), (b';', 7664),
+    (b';', 7665), (b'E', 7666), (b'e', 7667), (b'c', 7668), (b'e', 7669), (b'a', 7670), (b'l', 7671), (b'd', 7672),
+    (b't', 7673), (b';', 7674), (b';', 7675), (b'c', 7676), (b'e', 7677), (b's', 7678), (b'a', 7679), (b'o', 7680),
+    (b'r', 7681), (b'b', 7682), (b'o', 7683), (b'r', 7684), (b't', 7685), (b'h', 7686), (b'B', 7687), (b'L', 7688),
+    (b'S', 7689), (b'T', 7690), (b'S', 7691), (b'h', 7692), (b';', 7693), (b';', 7694), (b';', 7695), (b';', 7696),
+    (b';', 7697), (b';', 7698), (b';', 7699), (b';', 7700), (b';', 7701), (b';', 7702), (b';', 7703), (b';', 7704),
+    (b';', 7705), (b'l', 7706), (b'e', 7707), (b'q', 7708), (b';', 7709), (b';', 7710), (b';', 7711), (b's', 7712),
+    (b';', 7713), (b'g', 7714), (b';', 7715), (b';', 7716), (b'e', 7717), (b'a', 7718), (b'a', 7719), (b';', 7720),
+    (b';', 7721), (b';', 7722), (b';', 7723), (b't', 7724), (b'k', 7725), (b'r', 7726), (b';', 7727), (b';', 7728),
+    (b's', 7729), (b'i', 7730), (b'a', 7731), (b';', 7732), (b';', 7733), (b'n', 7734), (b's', 7735), (b';', 7736),
+    (b'r', 7737), (b'u', 7738), (b';', 7739), (b'g', 7740), (b'o', 7741), (b';', 7742), (b'u', 7743), (b't', 7744),
+    (b';', 7745), (b';', 7746), (b'e', 7747), (b'r', 7748), (b'w', 7749), (b'a', 7750), (b'o', 7751), (b';', 7752),
+    (b';', 7753), (b';', 7754), (b';', 7755), (b't', 7756), (b'e', 7757), (b';', 7758), (b'i', 7759), (b'i', 7760),
+    (b'o', 7761), (b';', 7762), (b';', 7763), (b';', 7764), (b';', 7765), (b'x', 7766), (b's', 7767), (b's', 7768),
+    (b'q', 7769), (b't', 7770), (b';', 7771), (b';', 7772), (b'a', 7773), (b't', 7774), (b';', 7775), (b';', 7776),
+    (b';', 7777), (b';', 7778), (b';', 7779), (b';', 7780), (b';', 7781), (b';', 7782), (b'w', 7783), (b'o', 7784),
+    (b'a', 7785), (b't', 7786), (b'e', 7787), (b';', 7788), (b';', 7789), (b'o', 7790), (b'r', 7791), (b't', 7792),
+    (b';', 7793), (b'h', 7794), (b';', 7795), (b'a', 7796), (b'r', 7797), (b't', 7798), (b't', 7799), (b'w', 7800),
+    (b';', 7801), (b';', 7802), (b'q', 7803), (b'w', 7804), (b'f', 7805), (b';', 7806), (b'a', 7807), (b';', 7808),
+    (b'o', 7809), (b'h', 7810), (b'r', 7811), (b';', 7812), (b';', 7813), (b't', 7814), (b'o', 7815), (b'h', 7816),
+    (b't', 7817), (b';', 7818), (b'l', 7819), (b'r', 7820), (b'd', 7821), (b'r', 7822), (b'q', 7823), (b'q', 7824),
+    (b'e', 7825), (b';', 7826), (b';', 7827), (b'k', 7828), (b';', 7829), (b';', 7830), (b'o', 7831), (b'y', 7832),
+    (b'r', 7833), (b';', 7834), (b';', 7835), (b';', 7836), (b';', 7837), (b';', 7838), (b'o', 7839), (b';', 7840),
+    (b's', 7841), (b';', 7842), (b'o', 7843), (b'p', 7844), (b't', 7845), (b'h', 7846), (b'i', 7847), (b'e', 7848),
+    (b't', 7849), (b'h', 7850), (b';', 7851), (b';', 7852), (b';', 7853), (b';', 7854), (b';', 7855), (b'a', 7856),
+    (b'm', 7857), (b';', 7858), (b't', 7859), (b';', 7860), (b'e', 7861), (b';', 7862), (b'e', 7863), (b'e', 7864),
+    (b'p', 7865), (b';', 7866), (b'q', 7867), (b'q', 7868), (b'o', 7869), (b'y', 7870), (b'r', 7871), (b';', 7872),
+    (b';', 7873), (b';', 7874), (b'q', 7875), (b'q', 7876), (b'e', 7877), (b';', 7878), (b'r', 7879), (b';', 7880),
+    (b';', 7881), (b';', 7882), (b'd', 7883), (b'l', 7884), (b'q', 7885), (b'r', 7886), (b';', 7887), (b';', 7888),
+    (b'e', 7889), (b'i', 7890), (b';', 7891), (b'r', 7892), (b'n', 7893), (b'w', 7894), (b';', 7895), (b'o', 7896),
+    (b';', 7897), (b'n', 7898), (b'o', 7899), (b';', 7900), (b't', 7901), (b't', 7902), (b';', 7903), (b'g', 7904),
+    (b't', 7905), (b';', 7906), (b's', 7907), (b'f', 7908), (b';', 7909), (b';', 7910), (b'u', 7911), (b's', 7912),
+    (b'e', 7913), (b'C', 7914), (b'y', 7915), (b';', 7916), (b't', 7917), (b';', 7918), (b'o', 7919), (b'a', 7920),
+    (b'i', 7921), (b't', 7922), (b';', 7923), (b'n', 7924), (b't', 7925), (b'g', 7926), (b'h', 7927), (b'r', 7928),
+    (b'o', 7929), (b't', 7930), (b';', 7931), (b'B', 7932), (b'U', 7933), (b';', 7934), (b'i', 7935), (b'e', 7936),
+    (b'e', 7937), (b'T', 7938), (b'V', 7939), (b'r', 7940), (b';', 7941), (b'l', 7942), (b'S', 7943), (b'e', 7944),
+    (b'u', 7945), (b'a', 7946), (b'l', 7947), (b'y', 7948), (b'f', 7949), (b'u', 7950), (b'l', 7951), (b'e', 7952),
+    (b's', 7953), (b'a', 7954), (b'l', 7955), (b'a', 7956), (b'l', 7957), (b'u', 7958), (b';', 7959), (b'I', 7960),
+    (b'i', 7961), (b'C', 7962), (b'T', 7963), (b'f', 7964), (b'B', 7965), (b';', 7966), (b'B', 7967), (b'R', 7968),
+    (b'n', 7969), (b'e', 7970), (b'e', 7971), (b'e', 7972), (b';', 7973), (b'A', 7974), (b'V', 7975), (b'r', 7976),
+    (b'c', 7977), (b'g', 7978), (b'n', 7979), (b'V', 7980), (b't', 7981), (b'r', 7982), (b';', 7983), (b'a', 7984),
+    (b'G', 7985), (b'q', 7986), (b'e', 7987), (b'E', 7988), (b';', 7989), (b'w', 7990), (b'r', 7991), (b'i', 7992),
+    (b'A', 7993), (b'r', 7994), (b'i', 7995), (b'a', 7996), (b'A', 7997), (b't', 7998), (b'c', 7999), (b';', 8000),
+    (b';', 8001), (b'e', 8002), (b'h', 8003), (b'e', 8004), (b'a', 8005), (b's', 8006), (b'n', 8007), (b'e', 8008),
+    (b';', 8009), (b'V', 8010), (b't', 8011), (b'i', 8012), (b';', 8013), (b'r', 8014), (b'w', 8015), (b'u', 8016),
+    (b'i', 8017), (b'u', 8018), (b'e', 8019), (b's', 8020), (b'a', 8021), (b'l', 8022), (b'G', 8023), (b'L', 8024),
+    (b'e', 8025), (b'e', 8026), (b'r', 8027), (b'S', 8028), (b';', 8029), (b'E', 8030), (b'd', 8031), (b'e', 8032),
+    (b'q', 8033), (b'u', 8034), (b'i', 8035), (b'a', 8036), (b'D', 8037), (b'Q', 8038), (b';', 8039), (b'e', 8040),
+    (b't', 8041), (b';', 8042), (b'l', 8043), (b'q', 8044), (b'l', 8045), (b'i', 8046), (b'n', 8047), (b'e', 8048),
+    (b'u', 8049), (b'E', 8050), (b'e', 8051), (b'w', 8052), (b'i', 8053), (b'l', 8054), (b'T', 8055), (b'V', 8056),
+    (b'r', 8057), (b'r', 8058), (b'e', 8059), (b'n', 8060), (b'w', 8061), (b'e', 8062), (b'c', 8063), (b'o', 8064),
+    (b'w', 8065), (b'i', 8066), (b'o', 8067), (b'e', 8068), (b'A', 8069), (b'A', 8070), (b't', 8071), (b'r', 8072),
+    (b'l', 8073), (b'e', 8074), (b's', 8075), (b'e', 8076), (b'o', 8077), (b'a', 8078), (b'q', 8079), (b'l', 8080),
+    (b'i', 8081), (b'q', 8082), (b';', 8083), (b'e', 8084), (b';', 8085), (b'l', 8086), (b'E', 8087), (b'e', 8088),
+    (b';', 8089), (b'e', 8090), (b'k', 8091), (b'n', 8092), (b';', 8093), (b'r', 8094), (b'w', 8095), (b'o', 8096),
+    (b'r', 8097), (b'w', 8098), (b'o', 8099), (b'A', 8100), (b't', 8101), (b'a', 8102), (b'i', 8103), (b'e', 8104),
+    (b'i', 8105), (b'p', 8106), (b'S', 8107), (b'o', 8108), (b';', 8109), (b';', 8110), (b';', 8111), (b'l', 8112),
+    (b'n', 8113), (b'r', 8114), (b'n', 8115), (b';', 8116), (b';', 8117), (b'o', 8118), (b't', 8119), (b'r', 8120),
+    (b's', 8121), (b't', 8122), (b';', 8123), (b'e', 8124), (b'c', 8125), (b'e', 8126), (b'w', 8127), (b'i', 8128),
+    (b'i', 8129), (b';', 8130), (b'w', 8131), (b';', 8132), (b'r', 8133), (b'o', 8134), (b'r', 8135), (b's', 8136),
+    (b'o', 8137), (b'a', 8138), (b't', 8139), (b';', 8140), (b';', 8141), (b's', 8142), (b';', 8143), (b';', 8144),
+    (b'r', 8145), (b'a', 8146), (b';', 8147), (b't', 8148), (b'o', 8149), (b'r', 8150), (b'a', 8151), (b'h', 8152),
+    (b's', 8153), (b't', 8154), (b'x', 8155), (b';', 8156), (b'r', 8157), (b'e', 8158), (b'r', 8159), (b'i', 8160),
+    (b'o', 8161), (b'a', 8162), (b'l', 8163), (b'r', 8164), (b';', 8165), (b'n', 8166), (b't', 8167), (b'n', 8168),
+    (b'w', 8169), (b't', 8170), (b'o', 8171), (b';', 8172), (b'w', 8173), (b't', 8174), (b';', 8175), (b';', 8176),
+    (b'o', 8177), (b';', 8178), (b'a', 8179), (b';', 8180), (b'q', 8181), (b';', 8182), (b'q', 8183), (b'l', 8184),
+    (b'r', 8185), (b';', 8186), (b'x', 8187), (b'e', 8188), (b'o', 8189), (b'n', 8190), (b';', 8191), (b'w', 8192),
+    (b'o', 8193), (b'a', 8194), (b'h', 8195), (b't', 8196), (b'g', 8197), (b'e', 8198), (b's', 8199), (b'e', 8200),
+    (b'l', 8201), (b'i', 8202), (b';', 8203), (b'q', 8204), (b'q', 8205), (b'p', 8206), (b'h', 8207), (b';', 8208),
+    (b';', 8209), (b'q', 8210), (b'x', 8211), (b'e', 8212), (b'o', 8213), (b';', 8214), (b';', 8215), (b'q', 8216),
+    (b';', 8217), (b'o', 8218), (b'o', 8219), (b'e', 8220), (b';', 8221), (b'i', 8222), (b'f', 8223), (b'g', 8224),
+    (b'o', 8225), (b'l', 8226), (b'r', 8227), (b's', 8228), (b'n', 8229), (b'g', 8230), (b';', 8231), (b'n', 8232),
+    (b'n', 8233), (b'l', 8234), (b'i', 8235), (b';', 8236), (b'f', 8237), (b's', 8238), (b';', 8239), (b's', 8240),
+    (b'o', 8241), (b'D', 8242), (b'Q', 8243), (b'e', 8244), (b'c', 8245), (b'l', 8246), (b'a', 8247), (b'o', 8248),
+    (b'A', 8249), (b'A', 8250), (b'R', 8251), (b'T', 8252), (b'L', 8253), (b'R', 8254), (b't', 8255), (b'r', 8256),
+    (b'w', 8257), (b'i', 8258), (b'a', 8259), (b'p', 8260), (b'g', 8261), (b'e', 8262), (b'c', 8263), (b'e', 8264),
+    (b'e', 8265), (b'o', 8266), (b'S', 8267), (b'm', 8268), (b';', 8269), (b'm', 8270), (b'l', 8271), (b'l', 8272),
+    (b'S', 8273), (b';', 8274), (b'a', 8275), (b'l', 8276), (b'a', 8277), (b's', 8278), (b'n', 8279), (b'd', 8280),
+    (b'c', 8281), (b'L', 8282), (b'm', 8283), (b';', 8284), (b'o', 8285), (b'o', 8286), (b'i', 8287), (b';', 8288),
+    (b'r', 8289), (b'a', 8290), (b'i', 8291), (b'g', 8292), (b'B', 8293), (b'e', 8294), (b'c', 8295), (b'r', 8296),
+    (b'e', 8297), (b'o', 8298), (b't', 8299), (b'l', 8300), (b'V', 8301), (b'e', 8302), (b'o', 8303), (b';', 8304),
+    (b'B', 8305), (b'r', 8306), (b'r', 8307), (b'u', 8308), (b'r', 8309), (b'q', 8310), (b';', 8311), (b'r', 8312),
+    (b'g', 8313), (b'r', 8314), (b'r', 8315), (b'g', 8316), (b'r', 8317), (b'r', 8318), (b'A', 8319), (b'e', 8320),
+    (b'd', 8321), (b'i', 8322), (b'r', 8323), (b't', 8324), (b'L', 8325), (b'g', 8326), (b'n', 8327), (b'e', 8328),
+    (b';', 8329), (b'l', 8330), (b';', 8331), (b'E', 8332), (b'F', 8333), (b'G', 8334), (b'L', 8335), (b'S', 8336),
+    (b'T', 8337), (b'n', 8338), (b'a', 8339), (b'a', 8340), (b'a', 8341), (b'a', 8342), (b's', 8343), (b'n', 8344),
+    (b'd', 8345), (b'r', 8346), (b'e', 8347), (b's', 8348), (b'E', 8349), (b'i', 8350), (b'u', 8351), (b'q', 8352),
+    (b's', 8353), (b't', 8354), (b'u', 8355), (b'l', 8356), (b'l', 8357), (b'l', 8358), (b'o', 8359), (b'u', 8360),
+    (b't', 8361), (b'h', 8362), (b'a', 8363), (b'u', 8364), (b'a', 8365), (b'l', 8366), (b';', 8367), (b'a', 8368),
+    (b'm', 8369), (b'i', 8370), (b'q', 8371), (b'B', 8372), (b';', 8373), (b'B', 8374), (b'L', 8375), (b'n', 8376),
+    (b'e', 8377), (b'e', 8378), (b'e', 8379), (b';', 8380), (b'r', 8381), (b'c', 8382), (b'g', 8383), (b'n', 8384),
+    (b'V', 8385), (b't', 8386), (b'r', 8387), (b';', 8388), (b'e', 8389), (b'w', 8390), (b'd', 8391), (b'r', 8392),
+    (b'r', 8393), (b'A', 8394), (b'o', 8395), (b'e', 8396), (b'r', 8397), (b'e', 8398), (b'r', 8399), (b'n', 8400),
+    (b'l', 8401), (b'u', 8402), (b'a', 8403), (b'l', 8404), (b'u', 8405), (b';', 8406), (b';', 8407), (b'q', 8408),
+    (b';', 8409), (b';', 8410), (b'e', 8411), (b't', 8412), (b';', 8413), (b'n', 8414), (b'w', 8415), (b'i', 8416),
+    (b';', 8417), (b'w', 8418), (b'r', 8419), (b'A', 8420), (b'r', 8421), (b'n', 8422), (b'p', 8423), (b'l', 8424),
+    (b'a', 8425), (b'p', 8426), (b'n', 8427), (b'e', 8428), (b'g', 8429), (b'e', 8430), (b'g', 8431), (b'w', 8432),
+    (b';', 8433), (b'c', 8434), (b'h', 8435), (b';', 8436), (b'c', 8437), (b'c', 8438), (b';', 8439), (b'l', 8440),
+    (b'r', 8441), (b't', 8442), (b'm', 8443), (b'e', 8444), (b'r', 8445), (b'n', 8446), (b';', 8447), (b's', 8448),
+    (b'n', 8449), (b'l', 8450), (b's', 8451), (b';', 8452), (b'r', 8453), (b'r', 8454), (b'a', 8455), (b'n', 8456),
+    (b'r', 8457), (b'r', 8458), (b'a', 8459), (b'q', 8460), (b'i', 8461), (b';', 8462), (b';', 8463), (b';', 8464),
+    (b'r', 8465), (b'g', 8466), (b';', 8467), (b'r', 8468), (b'e', 8469), (b'i', 8470), (b';', 8471), (b';', 8472),
+    (b'g', 8473), (b';', 8474), (b'a', 8475), (b'w', 8476), (b';', 8477), (b'a', 8478), (b'w', 8479), (b'l', 8480),
+    (b';', 8481), (b';', 8482), (b'e', 8483), (b'i', 8484), (b';', 8485), (b'q', 8486), (b'x', 8487), (b's', 8488),
+    (b';', 8489), (b't', 8490), (b'o', 8491), (b'r', 8492), (b'a', 8493), (b'a', 8494), (b'a', 8495), (b't', 8496),
+    (b'e', 8497), (b';', 8498), (b'l', 8499), (b'n', 8500), (b';', 8501), (b';', 8502), (b's', 8503), (b'i', 8504),
+    (b';', 8505), (b';', 8506), (b'q', 8507), (b'x', 8508), (b';', 8509), (b'x', 8510), (b'w', 8511), (b'f', 8512),
+    (b'g', 8513), (b't', 8514), (b'h', 8515), (b'w', 8516), (b'e', 8517), (b'i', 8518), (b';', 8519), (b';', 8520),
+    (b';', 8521), (b'e', 8522), (b'e', 8523), (b'e', 8524), (b'o', 8525), (b'e', 8526), (b';', 8527), (b';', 8528),
+    (b'n', 8529), (b'o', 8530), (b'u', 8531), (b'g', 8532), (b'k', 8533), (b'A', 8534), (b'D', 8535), (b'G', 8536),
+    (b'T', 8537), (b'l', 8538), (b'u', 8539), (b'r', 8540), (b'r', 8541), (b'i', 8542), (b'e', 8543), (b'e', 8544),
+    (b'i', 8545), (b'A', 8546), (b'T', 8547), (b'o', 8548), (b'n', 8549), (b'c', 8550), (b'r', 8551), (b'A', 8552),
+    (b'h', 8553), (b'V', 8554), (b't', 8555), (b'e', 8556), (b'c', 8557), (b'w', 8558), (b'q', 8559), (b'a', 8560),
+    (b';', 8561), (b'E', 8562), (b'S', 8563), (b'm', 8564), (b'l', 8565), (b'E', 8566), (b't', 8567), (b';', 8568),
+    (b't', 8569), (b'e', 8570), (b'e', 8571), (b'i', 8572), (b'p', 8573), (b'n', 8574), (b'm', 8575), (b'm', 8576),
+    (b'a', 8577), (b'r', 8578), (b'g', 8579), (b';', 8580), (b'r', 8581), (b'V', 8582), (b't', 8583), (b'r', 8584),
+    (b'c', 8585), (b'w', 8586), (b'o', 8587), (b'e', 8588), (b'e', 8589), (b'c', 8590), (b'r', 8591), (b'a', 8592),
+    (b'r', 8593), (b'e', 8594), (b'a', 8595), (b';', 8596), (b'u', 8597), (b'o', 8598), (b'h', 8599), (b'r', 8600),
+    (b'o', 8601), (b'h', 8602), (b'r', 8603), (b'r', 8604), (b'r', 8605), (b';', 8606), (b'i', 8607), (b'c', 8608),
+    (b'n', 8609), (b'y', 8610), (b'e', 8611), (b'e', 8612), (b'S', 8613), (b't', 8614), (b'r', 8615), (b'd', 8616),
+    (b'q', 8617), (b'u', 8618), (b'r', 8619), (b'e', 8620), (b'l', 8621), (b'i', 8622), (b'H', 8623), (b'l', 8624),
+    (b'n', 8625), (b'l', 8626), (b't', 8627), (b';', 8628), (b't', 8629), (b'e', 8630), (b'e', 8631), (b's', 8632),
+    (b';', 8633), (b'E', 8634), (b'S', 8635), (b'l', 8636), (b'a', 8637), (b'b', 8638), (b'p', 8639), (b'u', 8640),
+    (b';', 8641), (b'E', 8642), (b'S', 8643), (b'T', 8644), (b';', 8645), (b'E', 8646), (b'a', 8647), (b'l', 8648),
+    (b'd', 8649), (b'B', 8650), (b'u', 8651), (b'o', 8652), (b';', 8653), (b'e', 8654), (b'n', 8655), (b'a', 8656),
+    (b'n', 8657), (b'd', 8658), (b'l', 8659), (b'e', 8660), (b'l', 8661), (b'u', 8662), (b'r', 8663), (b'a', 8664),
+    (b'e', 8665), (b'g', 8666), (b'B', 8667), (b'e', 8668), (b'c', 8669), (b'o', 8670), (b't', 8671), (b'l', 8672),
+    (b'V', 8673), (b'e', 8674), (b'o', 8675), (b';', 8676), (b'B', 8677), (b's', 8678), (b';', 8679), (b';', 8680),
+    (b'r', 8681), (b'r', 8682), (b'r', 8683), (b'w', 8684), (b';', 8685), (b's', 8686), (b't', 8687), (b's', 8688),
+    (b';', 8689), (b';', 8690), (b'a', 8691), (b'n', 8692), (b'd', 8693), (b'a', 8694), (b'u', 8695), (b't', 8696),
+    (b'h', 8697), (b'A', 8698), (b';', 8699), (b'u', 8700), (b';', 8701), (b'r', 8702), (b'r', 8703), (b';', 8704),
+    (b'e', 8705), (b'a', 8706), (b'd', 8707), (b'c', 8708), (b'a', 8709), (b';', 8710), (b'd', 8711), (b'u', 8712),
+    (b'e', 8713), (b';', 8714), (b'l', 8715), (b'l', 8716), (b'r', 8717), (b';', 8718), (b';', 8719), (b';', 8720),
+    (b';', 8721), (b'e', 8722), (b'i', 8723), (b';', 8724), (b'e', 8725), (b'd', 8726), (b'o', 8727), (b'l', 8728),
+    (b'r', 8729), (b';', 8730), (b';', 8731), (b'e', 8732), (b'e', 8733), (b'o', 8734), (b'r', 8735), (b'i', 8736),
+    (b'd', 8737), (b'u', 8738), (b'o', 8739), (b'r', 8740), (b'r', 8741), (b'u', 8742), (b'm', 8743), (b'o', 8744),
+    (b'h', 8745), (b'r', 8746), (b'f', 8747), (b'g', 8748), (b'l', 8749), (b'r', 8750), (b';', 8751), (b'r', 8752),
+    (b';', 8753), (b'l', 8754), (b'f', 8755), (b'g', 8756), (b';', 8757), (b';', 8758), (b';', 8759), (b'a', 8760),
+    (b'n', 8761), (b'r', 8762), (b'r', 8763), (b'r', 8764), (b'r', 8765), (b'i', 8766), (b'q', 8767), (b'e', 8768),
+    (b'u', 8769), (b'i', 8770), (b';', 8771), (b';', 8772), (b';', 8773), (b';', 8774), (b'n', 8775), (b't', 8776),
+    (b'h', 8777), (b'a', 8778), (b't', 8779), (b';', 8780), (b'f', 8781), (b'g', 8782), (b'q', 8783), (b'q', 8784),
+    (b'l', 8785), (b'r', 8786), (b'n', 8787), (b'r', 8788), (b't', 8789), (b'u', 8790), (b'o', 8791), (b'r', 8792),
+    (b'w', 8793), (b'c', 8794), (b'o', 8795), (b'r', 8796), (b'i', 8797), (b'D', 8798), (b'r', 8799), (b'r', 8800),
+    (b'r', 8801), (b'g', 8802), (b'e', 8803), (b'f', 8804), (b'g', 8805), (b'r', 8806), (b'e', 8807), (b'w', 8808),
+    (b'A', 8809), (b'a', 8810), (b';', 8811), (b'r', 8812), (b't', 8813), (b'e', 8814), (b'o', 8815), (b'V', 8816),
+    (b't', 8817), (b';', 8818), (b'u', 8819), (b'l', 8820), (b';', 8821), (b'q', 8822), (b'a', 8823), (b';', 8824),
+    (b'L', 8825), (b'q', 8826), (b'e', 8827), (b'E', 8828), (b';', 8829), (b';', 8830), (b'n', 8831), (b';', 8832),
+    (b';', 8833), (b'm', 8834), (b'e', 8835), (b'c', 8836), (b';', 8837), (b'h', 8838), (b'a', 8839), (b'e', 8840),
+    (b'o', 8841), (b'o', 8842), (b't', 8843), (b';', 8844), (b'r', 8845), (b';', 8846), (b'B', 8847), (b'E', 8848),
+    (b'c', 8849), (b't', 8850), (b';', 8851), (b'B', 8852), (b'r', 8853), (b'o', 8854), (b'a', 8855), (b'l', 8856),
+    (b'a', 8857), (b'w', 8858), (b't', 8859), (b'o', 8860), (b'w', 8861), (b't', 8862), (b'o', 8863), (b'o', 8864),
+    (b'r', 8865), (b'u', 8866), (b'k', 8867), (b'S', 8868), (b'T', 8869), (b'r', 8870), (b's', 8871), (b'p', 8872),
+    (b';', 8873), (b't', 8874), (b'e', 8875), (b'u', 8876), (b'l', 8877), (b'e', 8878), (b's', 8879), (b'a', 8880),
+    (b'l', 8881), (b'u', 8882), (b';', 8883), (b'g', 8884), (b';', 8885), (b'e', 8886), (b'E', 8887), (b';', 8888),
+    (b'a', 8889), (b's', 8890), (b'q', 8891), (b'l', 8892), (b'e', 8893), (b'n', 8894), (b's', 8895), (b'e', 8896),
+    (b'a', 8897), (b'q', 8898), (b'l', 8899), (b'i', 8900), (b'q', 8901), (b'l', 8902), (b'E', 8903), (b'e', 8904),
+    (b'a', 8905), (b'b', 8906), (b't', 8907), (b's', 8908), (b'e', 8909), (b'l', 8910), (b't', 8911), (b'e', 8912),
+    (b';', 8913), (b'n', 8914), (b'i', 8915), (b'i', 8916), (b'a', 8917), (b'r', 8918), (b'f', 8919), (b';', 8920),
+    (b'r', 8921), (b'V', 8922), (b't', 8923), (b'w', 8924), (b'o', 8925), (b'e', 8926), (b'e', 8927), (b'c', 8928),
+    (b'r', 8929), (b'a', 8930), (b';', 8931), (b'o', 8932), (b'o', 8933), (b'r', 8934), (b';', 8935), (b'e', 8936),
+    (b';', 8937), (b'E', 8938), (b'e', 8939), (b'l', 8940), (b't', 8941), (b'e', 8942), (b'l', 8943), (b'a', 8944),
+    (b';', 8945), (b'e', 8946), (b'r', 8947), (b'm', 8948), (b'o', 8949), (b'r', 8950), (b';', 8951), (b'r', 8952),
+    (b'e', 8953), (b'e', 8954), (b'c', 8955), (b'o', 8956), (b'p', 8957), (b';', 8958), (b'e', 8959), (b'e', 8960),
+    (b'i', 8961), (b'f', 8962), (b'g', 8963), (b's', 8964), (b'g', 8965), (b'w', 8966), (b'e', 8967), (b'i', 8968),
+    (b';', 8969), (b'q', 8970), (b'w', 8971), (b'o', 8972), (b'l', 8973), (b'o', 8974), (b'p', 8975), (b'w', 8976),
+    (b'o', 8977), (b'p', 8978), (b'i', 8979), (b'e', 8980), (b'w', 8981), (b't', 8982), (b'o', 8983), (b't', 8984),
+    (b'h', 8985), (b'e', 8986), (b'r', 8987), (b'r', 8988), (b'e', 8989), (b't', 8990), (b'h', 8991), (b'i', 8992),
+    (b'd', 8993), (b'u', 8994), (b'o', 8995), (b'p', 8996), (b'r', 8997), (b'r', 8998), (b'm', 8999), (b';', 9000),
+    (b'l', 9001), (b's', 9002), (b'l', 9003), (b';', 9004), (b';', 9005), (b'e', 9006), (b't', 9007), (b'r', 9008),
+    (b'a', 9009), (b't', 9010), (b'h', 9011), (b';', 9012), (b'q', 9013), (b';', 9014), (b'q', 9015), (b'e', 9016),
+    (b'i', 9017), (b';', 9018), (b'e', 9019), (b'o', 9020), (b'b', 9021), (b't', 9022), (b'a', 9023), (b'i', 9024),
+    (b'u', 9025), (b't', 9026), (b'u', 9027), (b'a', 9028), (b'l', 9029), (b';', 9030), (b'I', 9031), (b'o', 9032),
+    (b'o', 9033), (b'h', 9034), (b';', 9035), (b't', 9036), (b'h', 9037), (b'r', 9038), (b'e', 9039), (b';', 9040),
+    (b'r', 9041), (b'l', 9042), (b'r', 9043), (b'V', 9044), (b'c', 9045), (b'r', 9046), (b'e', 9047), (b'o', 9048),
+    (b'a', 9049), (b'l', 9050), (b'u', 9051), (b'l', 9052), (b'e', 9053), (b'u', 9054), (b'r', 9055), (b'q', 9056),
+    (b'e', 9057), (b'a', 9058), (b's', 9059), (b'k', 9060), (b't', 9061), (b'c', 9062), (b'c', 9063), (b'r', 9064),
+    (b'w', 9065), (b'o', 9066), (b';', 9067), (b'a', 9068), (b'q', 9069), (b't', 9070), (b'o', 9071), (b'a', 9072),
+    (b';', 9073), (b'w', 9074), (b't', 9075), (b';', 9076), (b'l', 9077), (b';', 9078), (b'A', 9079), (b'w', 9080),
+    (b';', 9081), (b'a', 9082), (b'w', 9083), (b'w', 9084), (b'o', 9085), (b'm', 9086), (b'S', 9087), (b'p', 9088),
+    (b'h', 9089), (b'G', 9090), (b's', 9091), (b'a', 9092), (b'i', 9093), (b';', 9094), (b'a', 9095), (b'l', 9096),
+    (b'a', 9097), (b's', 9098), (b'n', 9099), (b'd', 9100), (b'm', 9101), (b'l', 9102), (b'r', 9103), (b'q', 9104),
+    (b't', 9105), (b'L', 9106), (b'u', 9107), (b'a', 9108), (b'm', 9109), (b'g', 9110), (b'e', 9111), (b'r', 9112),
+    (b'l', 9113), (b'u', 9114), (b'a', 9115), (b'l', 9116), (b'u', 9117), (b';', 9118), (b'q', 9119), (b';', 9120),
+    (b'r', 9121), (b'l', 9122), (b'e', 9123), (b'i', 9124), (b';', 9125), (b';', 9126), (b'E', 9127), (b';', 9128),
+    (b't', 9129), (b'b', 9130), (b'l', 9131), (b'c', 9132), (b';', 9133), (b't', 9134), (b'a', 9135), (b'e', 9136),
+    (b'o', 9137), (b';', 9138), (b'r', 9139), (b';', 9140), (b'B', 9141), (b'E', 9142), (b'c', 9143), (b't', 9144),
+    (b';', 9145), (b'B', 9146), (b'r', 9147), (b'w', 9148), (b'w', 9149), (b'o', 9150), (b'c', 9151), (b'q', 9152),
+    (b't', 9153), (b';', 9154), (b'E', 9155), (b';', 9156), (b';', 9157), (b'l', 9158), (b's', 9159), (b'r', 9160),
+    (b';', 9161), (b'w', 9162), (b'o', 9163), (b'a', 9164), (b';', 9165), (b';', 9166), (b'e', 9167), (b'w', 9168),
+    (b';', 9169), (b';', 9170), (b'd', 9171), (b'l', 9172), (b'r', 9173), (b'f', 9174), (b'g', 9175), (b't', 9176),
+    (b'h', 9177), (b';', 9178), (b'e', 9179), (b's', 9180), (b'f', 9181), (b'g', 9182), (b';', 9183), (b';', 9184),
+    (b'w', 9185), (b';', 9186), (b'w', 9187), (b';', 9188), (b's', 9189), (b'w', 9190), (b'o', 9191), (b'g', 9192),
+    (b's', 9193), (b';', 9194), (b'a', 9195), (b'w', 9196), (b';', 9197), (b't', 9198), (b';', 9199), (b'o', 9200),
+    (b'o', 9201), (b'l', 9202), (b';', 9203), (b'e', 9204), (b't', 9205), (b'l', 9206), (b'o', 9207), (b'p', 9208),
+    (b'w', 9209), (b'o', 9210), (b'o', 9211), (b'o', 9212), (b'e', 9213), (b';', 9214), (b';', 9215), (b'o', 9216),
+    (b'q', 9217), (b';', 9218), (b'e', 9219), (b'r', 9220), (b'r', 9221), (b';', 9222), (b't', 9223), (b';', 9224),
+    (b';', 9225), (b'f', 9226), (b'g', 9227), (b'n', 9228), (b'u', 9229), (b'l', 9230), (b'e', 9231), (b'l', 9232),
+    (b's', 9233), (b't', 9234), (b';', 9235), (b'b', 9236), (b'v', 9237), (b'd', 9238), (b'n', 9239), (b'w', 9240),
+    (b'w', 9241), (b't', 9242), (b'A', 9243), (b'R', 9244), (b't', 9245), (b'o', 9246), (b';', 9247), (b'r', 9248),
+    (b'B', 9249), (b'o', 9250), (b'e', 9251), (b't', 9252), (b';', 9253), (b'B', 9254), (b'c', 9255), (b'r', 9256),
+    (b'r', 9257), (b'S', 9258), (b'a', 9259), (b'l', 9260), (b's', 9261), (b'a', 9262), (b';', 9263), (b'u', 9264),
+    (b';', 9265), (b';', 9266), (b';', 9267), (b'e', 9268), (b'A', 9269), (b'k', 9270), (b't', 9271), (b';', 9272),
+    (b'B', 9273), (b';', 9274), (b'r', 9275), (b'r', 9276), (b'u', 9277), (b'o', 9278), (b'r', 9279), (b'r', 9280),
+    (b';', 9281), (b'e', 9282), (b';', 9283), (b'r', 9284), (b';', 9285), (b'r', 9286), (b';', 9287), (b';', 9288),
+    (b'w', 9289), (b'S', 9290), (b'p', 9291), (b'a', 9292), (b'i', 9293), (b'r', 9294), (b';', 9295), (b'c', 9296),
+    (b'c', 9297), (b'l', 9298), (b'E', 9299), (b't', 9300), (b';', 9301), (b't', 9302), (b'e', 9303), (b'p', 9304),
+    (b'e', 9305), (b';', 9306), (b'u', 9307), (b'e', 9308), (b'e', 9309), (b'a', 9310), (b'n', 9311), (b'e', 9312),
+    (b'l', 9313), (b't', 9314), (b's', 9315), (b';', 9316), (b'a', 9317), (b'n', 9318), (b'd', 9319), (b'a', 9320),
+    (b'u', 9321), (b';', 9322), (b'e', 9323), (b';', 9324), (b's', 9325), (b'q', 9326), (b';', 9327), (b'r', 9328),
+    (b'i', 9329), (b'k', 9330), (b'A', 9331), (b'c', 9332), (b'c', 9333), (b'r', 9334), (b';', 9335), (b'a', 9336),
+    (b'q', 9337), (b't', 9338), (b'o', 9339), (b'a', 9340), (b';', 9341), (b';', 9342), (b';', 9343), (b'w', 9344),
+    (b't', 9345), (b'u', 9346), (b';', 9347), (b'E', 9348), (b'q', 9349), (b';', 9350), (b'i', 9351), (b'o', 9352),
+    (b';', 9353), (b'w', 9354), (b't', 9355), (b';', 9356), (b'n', 9357), (b'o', 9358), (b'e', 9359), (b'i', 9360),
+    (b't', 9361), (b'h', 9362), (b';', 9363), (b't', 9364), (b';', 9365), (b';', 9366), (b't', 9367), (b'h', 9368),
+    (b';', 9369), (b'n', 9370), (b';', 9371), (b';', 9372), (b's', 9373), (b'o', 9374), (b'a', 9375), (b';', 9376),
+    (b'r', 9377), (b';', 9378), (b';', 9379), (b'w', 9380), (b'w', 9381), (b';', 9382), (b'q', 9383), (b';', 9384),
+    (b'e', 9385), (b';', 9386), (b'w', 9387), (b';', 9388), (b's', 9389), (b'o', 9390), (b'w', 9391), (b'w', 9392),
+    (b's', 9393), (b'n', 9394), (b';', 9395), (b'q', 9396), (b'o', 9397), (b'r', 9398), (b';', 9399), (b't', 9400),
+    (b'h', 9401), (b't', 9402), (b'r', 9403), (b'e', 9404), (b';', 9405), (b';', 9406), (b'e', 9407), (b'e', 9408),
+    (b'l', 9409), (b'e', 9410), (b'e', 9411), (b't', 9412), (b';', 9413), (b';', 9414), (b'A', 9415), (b'r', 9416),
+    (b'i', 9417), (b'A', 9418), (b'w', 9419), (b'o', 9420), (b'a', 9421), (b'w', 9422), (b'c', 9423), (b'o', 9424),
+    (b'a', 9425), (b't', 9426), (b';', 9427), (b'B', 9428), (b'e', 9429), (b'q', 9430), (b'r', 9431), (b'S', 9432),
+    (b's', 9433), (b'l', 9434), (b'a', 9435), (b't', 9436), (b'r', 9437), (b'e', 9438), (b'o', 9439), (b'a', 9440),
+    (b';', 9441), (b';', 9442), (b'a', 9443), (b'r', 9444), (b';', 9445), (b';', 9446), (b'r', 9447), (b'r', 9448),
+    (b'r', 9449), (b';', 9450), (b'p', 9451), (b'a', 9452), (b'c', 9453), (b'n', 9454), (b'e', 9455), (b'e', 9456),
+    (b'a', 9457), (b';', 9458), (b'q', 9459), (b'e', 9460), (b'E', 9461), (b';', 9462), (b';', 9463), (b';', 9464),
+    (b'B', 9465), (b'E', 9466), (b'a', 9467), (b'r', 9468), (b's', 9469), (b'l', 9470), (b't', 9471), (b'n', 9472),
+    (b'e', 9473), (b';', 9474), (b'E', 9475), (b'e', 9476), (b'l', 9477), (b't', 9478), (b'e', 9479), (b'l', 9480),
+    (b'a', 9481), (b'Q', 9482), (b';', 9483), (b'u', 9484), (b'i', 9485), (b'b', 9486), (b'e', 9487), (b'r', 9488),
+    (b'k', 9489), (b't', 9490), (b';', 9491), (b'B', 9492), (b'r', 9493), (b'u', 9494), (b'o', 9495), (b'r', 9496),
+    (b'r', 9497), (b';', 9498), (b'i', 9499), (b'a', 9500), (b'q', 9501), (b'u', 9502), (b's', 9503), (b'w', 9504),
+    (b';', 9505), (b'o', 9506), (b';', 9507), (b'w', 9508), (b'f', 9509), (b'g', 9510), (b';', 9511), (b't', 9512),
+    (b';', 9513), (b';', 9514), (b't', 9515), (b';', 9516), (b';', 9517), (b'n', 9518), (b'r', 9519), (b'r', 9520),
+    (b';', 9521), (b';', 9522), (b';', 9523), (b'q', 9524), (b'n', 9525), (b';', 9526), (b'n', 9527), (b's', 9528),
+    (b';', 9529), (b';', 9530), (b';', 9531), (b';', 9532), (b'w', 9533), (b'o', 9534), (b';', 9535), (b't', 9536),
+    (b'i', 9537), (b'I', 9538), (b'Q', 9539), (b'C', 9540), (b';', 9541), (b'e', 9542), (b';', 9543), (b';', 9544),
+    (b'e', 9545), (b'r', 9546), (b'r', 9547), (b'g', 9548), (b'r', 9549), (b';', 9550), (b'w', 9551), (b'r', 9552),
+    (b';', 9553), (b't', 9554), (b'r', 9555), (b'r', 9556), (b'o', 9557), (b'a', 9558), (b';', 9559), (b'u', 9560),
+    (b'e', 9561), (b'q', 9562), (b';', 9563), (b';', 9564), (b'l', 9565), (b';', 9566), (b'r', 9567), (b't', 9568),
+    (b'r', 9569), (b'r', 9570), (b'l', 9571), (b';', 9572), (b';', 9573), (b'o', 9574), (b'o', 9575), (b'a', 9576),
+    (b'c', 9577), (b'e', 9578), (b'S', 9579), (b'a', 9580), (b';', 9581), (b'l', 9582), (b'u', 9583), (b'r', 9584),
+    (b'q', 9585), (b'a', 9586), (b'q', 9587), (b'l', 9588), (b'G', 9589), (b's', 9590), (b';', 9591), (b'E', 9592),
+    (b't', 9593), (b';', 9594), (b'B', 9595), (b'E', 9596), (b'q', 9597), (b't', 9598), (b';', 9599), (b'E', 9600),
+    (b';', 9601), (b';', 9602), (b'l', 9603), (b'u', 9604), (b'a', 9605), (b'u', 9606), (b'r', 9607), (b't', 9608),
+    (b'r', 9609), (b'e', 9610), (b'o', 9611), (b'a', 9612), (b';', 9613), (b'a', 9614), (b'r', 9615), (b';', 9616),
+    (b';', 9617), (b'o', 9618), (b'l', 9619), (b'u', 9620), (b'a', 9621), (b';', 9622), (b';', 9623), (b'r', 9624),
+    (b'n', 9625), (b't', 9626), (b'h', 9627), (b';', 9628), (b';', 9629), (b's', 9630), (b'r', 9631), (b'o', 9632),
+    (b';', 9633), (b';', 9634), (b's', 9635), (b';', 9636), (b';', 9637), (b'w', 9638), (b';', 9639), (b'a', 9640),
+    (b'n', 9641), (b'u', 9642), (b'o', 9643), (b'A', 9644), (b'g', 9645), (b'r', 9646), (b'o', 9647), (b'h', 9648),
+    (b'r', 9649), (b';', 9650), (b';', 9651), (b'o', 9652), (b';', 9653), (b';', 9654), (b'r', 9655), (b'r', 9656),
+    (b'a', 9657), (b';', 9658), (b'u', 9659), (b';', 9660), (b'o', 9661), (b';', 9662), (b';', 9663), (b';', 9664),
+    (b';', 9665), (b'w', 9666), (b'w', 9667), (b'c', 9668), (b'e', 9669), (b';', 9670), (b'p', 9671), (b't', 9672),
+    (b'B', 9673), (b'a', 9674), (b';', 9675), (b'u', 9676), (b'r', 9677), (b'u', 9678), (b';', 9679), (b'r', 9680),
+    (b';', 9681), (b'q', 9682), (b';', 9683), (b'a', 9684), (b'q', 9685), (b'u', 9686), (b';', 9687), (b'E', 9688),
+    (b'q', 9689), (b';', 9690), (b'o', 9691), (b'l', 9692), (b'm', 9693), (b'i', 9694), (b';', 9695), (b'o', 9696),
+    (b't', 9697), (b'r', 9698), (b'r', 9699), (b'l', 9700), (b';', 9701), (b'n', 9702), (b';', 9703), (b'a', 9704),
+    (b'l', 9705), (b';', 9706), (b';', 9707), (b';', 9708), (b't', 9709), (b';', 9710), (b'o', 9711), (b'w', 9712),
+    (b';', 9713), (b';', 9714), (b'l', 9715), (b't', 9716), (b'o', 9717), (b'n', 9718), (b'c', 9719), (b'r', 9720),
+    (b'o', 9721), (b'w', 9722), (b't', 9723), (b'o', 9724), (b'r', 9725), (b';', 9726), (b';', 9727), (b'r', 9728),
+    (b'a', 9729), (b'w', 9730), (b';', 9731), (b';', 9732), (b'e', 9733), (b';', 9734), (b'a', 9735), (b'e', 9736),
+    (b'a', 9737), (b'l', 9738), (b'a', 9739), (b';', 9740), (b'a', 9741), (b'e', 9742), (b'u', 9743), (b'r', 9744),
+    (b'u', 9745), (b'a', 9746), (b'q', 9747), (b'u', 9748), (b't', 9749), (b';', 9750), (b';', 9751), (b'u', 9752),
+    (b'w', 9753), (b';', 9754), (b';', 9755), (b';', 9756), (b';', 9757), (b';', 9758), (b'l', 9759), (b';', 9760),
+    (b';', 9761), (b'w', 9762), (b';', 9763), (b'D', 9764), (b'e', 9765), (b't', 9766), (b't', 9767), (b'u', 9768),
+    (b'a', 9769), (b'w', 9770), (b';', 9771), (b'A', 9772), (b'w', 9773), (b';', 9774), (b'e', 9775), (b'r', 9776),
+    (b';', 9777), (b';', 9778), (b'c', 9779), (b'r', 9780), (b'r', 9781), (b';', 9782), (b'l', 9783), (b'l', 9784),
+    (b'a', 9785), (b'a', 9786), (b';', 9787), (b'a', 9788), (b'l', 9789), (b'u', 9790), (b'a', 9791), (b'e', 9792),
+    (b'm', 9793), (b';', 9794), (b';', 9795), (b';', 9796), (b';', 9797), (b'g', 9798), (b'e', 9799), (b'o', 9800),
+    (b't', 9801), (b'l', 9802), (b';', 9803), (b'r', 9804), (b';', 9805), (b';', 9806), (b'e', 9807), (b'e', 9808),
+    (b';', 9809), (b';', 9810), (b';', 9811), (b';', 9812), (b't', 9813), (b'l', 9814), (b'l', 9815), (b';', 9816),
+    (b'a', 9817), (b'l', 9818), (b';', 9819), (b';', 9820), (b'r', 9821), (b';', 9822), (b'u', 9823), (b'e', 9824),
+    (b';', 9825), (b'r', 9826), (b';', 9827), (b';', 9828), (b'e', 9829), (b';', 9830), (b';', 9831), (b'l', 9832),
+    (b';', 9833), (b'a', 9834), (b'r', 9835), (b';', 9836), (b'o', 9837), (b'r', 9838), (b';', 9839), (b'l', 9840),
+    (b'I', 9841), (b'w', 9842), (b';', 9843), (b';', 9844), (b'n', 9845), (b';', 9846), (b't', 9847), (b'e', 9848),
+    (b'g', 9849), (b'r', 9850), (b'a', 9851), (b'l', 9852), (b';', 9853),
+];
+
+/** The code points of the names, the second one is 0 for names of a single code point. */
+pub(crate) static TRIE_VALUES: [(u32, u32); 1511] = [
+    (0xc6, 0x0), (0x26, 0x0), (0xc1, 0x0), (0x102, 0x0), (0xc2, 0x0), (0x410, 0x0),
+    (0x1d504, 0x0), (0xc0, 0x0), (0x391, 0x0), (0x100, 0x0), (0x2a53, 0x0), (0x104, 0x0),
+    (0x1d538, 0x0), (0x2061, 0x0), (0xc5, 0x0), (0x1d49c, 0x0), (0x2254, 0x0), (0xc3, 0x0),
+    (0xc4, 0x0), (0x2216, 0x0), (0x2ae7, 0x0), (0x2306, 0x0), (0x411, 0x0), (0x2235, 0x0),
+    (0x212c, 0x0), (0x392, 0x0), (0x1d505, 0x0), (0x1d539, 0x0), (0x2d8, 0x0), (0x224e, 0x0),
+    (0x427, 0x0), (0xa9, 0x0), (0x106, 0x0), (0x22d2, 0x0), (0x2145, 0x0), (0x212d, 0x0),
+    (0x10c, 0x0), (0xc7, 0x0), (0x108, 0x0), (0x2230, 0x0), (0x10a, 0x0), (0xb8, 0x0),
+    (0xb7, 0x0), (0x3a7, 0x0), (0x2299, 0x0), (0x2296, 0x0), (0x2295, 0x0), (0x2297, 0x0),
+    (0x2232, 0x0), (0x201d, 0x0), (0x2019, 0x0), (0x2237, 0x0), (0x2a74, 0x0), (0x2261, 0x0),
+    (0x222f, 0x0), (0x222e, 0x0), (0x2102, 0x0), (0x2210, 0x0), (0x2233, 0x0), (0x2a2f, 0x0),
+    (0x1d49e, 0x0), (0x22d3, 0x0), (0x224d, 0x0), (0x2911, 0x0), (0x402, 0x0), (0x405, 0x0),
+    (0x40f, 0x0), (0x2021, 0x0), (0x21a1, 0x0), (0x2ae4, 0x0), (0x10e, 0x0), (0x414, 0x0),
+    (0x2207, 0x0), (0x394, 0x0), (0x1d507, 0x0), (0xb4, 0x0), (0x2d9, 0x0), (0x2dd, 0x0),
+    (0x60, 0x0), (0x2dc, 0x0), (0x22c4, 0x0), (0x2146, 0x0), (0x1d53b, 0x0), (0xa8, 0x0),
+    (0x20dc, 0x0), (0x2250, 0x0), (0x21d3, 0x0), (0x21d0, 0x0), (0x21d4, 0x0), (0x27f8, 0x0),
+    (0x27fa, 0x0), (0x27f9, 0x0), (0x21d2, 0x0), (0x22a8, 0x0), (0x21d1, 0x0), (0x21d5, 0x0),
+    (0x2225, 0x0), (0x2193, 0x0), (0x2913, 0x0), (0x21f5, 0x0), (0x311, 0x0), (0x2950, 0x0),
+    (0x295e, 0x0), (0x21bd, 0x0), (0x2956, 0x0), (0x295f, 0x0), (0x21c1, 0x0), (0x2957, 0x0),
+    (0x22a4, 0x0), (0x21a7, 0x0), (0x1d49f, 0x0), (0x110, 0x0), (0x14a, 0x0), (0xd0, 0x0),
+    (0xc9, 0x0), (0x11a, 0x0), (0xca, 0x0), (0x42d, 0x0), (0x116, 0x0), (0x1d508, 0x0),
+    (0xc8, 0x0), (0x2208, 0x0), (0x112, 0x0), (0x25fb, 0x0), (0x25ab, 0x0), (0x118, 0x0),
+    (0x1d53c, 0x0), (0x395, 0x0), (0x2a75, 0x0), (0x2242, 0x0), (0x21cc, 0x0), (0x2130, 0x0),
+    (0x2a73, 0x0), (0x397, 0x0), (0xcb, 0x0), (0x2203, 0x0), (0x2147, 0x0), (0x424, 0x0),
+    (0x1d509, 0x0), (0x25fc, 0x0), (0x25aa, 0x0), (0x1d53d, 0x0), (0x2200, 0x0), (0x2131, 0x0),
+    (0x403, 0x0), (0x3e, 0x0), (0x393, 0x0), (0x3dc, 0x0), (0x11e, 0x0), (0x122, 0x0),
+    (0x11c, 0x0), (0x413, 0x0), (0x120, 0x0), (0x1d50a, 0x0), (0x22d9, 0x0), (0x1d53e, 0x0),
+    (0x2265, 0x0), (0x22db, 0x0), (0x2267, 0x0), (0x2aa2, 0x0), (0x2277, 0x0), (0x2a7e, 0x0),
+    (0x2273, 0x0), (0x1d4a2, 0x0), (0x226b, 0x0), (0x42a, 0x0), (0x2c7, 0x0), (0x5e, 0x0),
+    (0x124, 0x0), (0x210c, 0x0), (0x210b, 0x0), (0x210d, 0x0), (0x2500, 0x0), (0x126, 0x0),
+    (0x224f, 0x0), (0x415, 0x0), (0x132, 0x0), (0x401, 0x0), (0xcd, 0x0), (0xce, 0x0),
+    (0x418, 0x0), (0x130, 0x0), (0x2111, 0x0), (0xcc, 0x0), (0x12a, 0x0), (0x2148, 0x0),
+    (0x222c, 0x0), (0x222b, 0x0), (0x22c2, 0x0), (0x2063, 0x0), (0x2062, 0x0), (0x12e, 0x0),
+    (0x1d540, 0x0), (0x399, 0x0), (0x2110, 0x0), (0x128, 0x0), (0x406, 0x0), (0xcf, 0x0),
+    (0x134, 0x0), (0x419, 0x0), (0x1d50d, 0x0), (0x1d541, 0x0), (0x1d4a5, 0x0), (0x408, 0x0),
+    (0x404, 0x0), (0x425, 0x0), (0x40c, 0x0), (0x39a, 0x0), (0x136, 0x0), (0x41a, 0x0),
+    (0x1d50e, 0x0), (0x1d542, 0x0), (0x1d4a6, 0x0), (0x409, 0x0), (0x3c, 0x0), (0x139, 0x0),
+    (0x39b, 0x0), (0x27ea, 0x0), (0x2112, 0x0), (0x219e, 0x0), (0x13d, 0x0), (0x13b, 0x0),
+    (0x41b, 0x0), (0x27e8, 0x0), (0x2190, 0x0), (0x21e4, 0x0), (0x21c6, 0x0), (0x2308, 0x0),
+    (0x27e6, 0x0), (0x2961, 0x0), (0x21c3, 0x0), (0x2959, 0x0), (0x230a, 0x0), (0x2194, 0x0),
+    (0x294e, 0x0), (0x22a3, 0x0), (0x21a4, 0x0), (0x295a, 0x0), (0x22b2, 0x0), (0x29cf, 0x0),
+    (0x22b4, 0x0), (0x2951, 0x0), (0x2960, 0x0), (0x21bf, 0x0), (0x2958, 0x0), (0x21bc, 0x0),
+    (0x2952, 0x0), (0x22da, 0x0), (0x2266, 0x0), (0x2276, 0x0), (0x2aa1, 0x0), (0x2a7d, 0x0),
+    (0x2272, 0x0), (0x1d50f, 0x0), (0x22d8, 0x0), (0x21da, 0x0), (0x13f, 0x0), (0x27f5, 0x0),
+    (0x27f7, 0x0), (0x27f6, 0x0), (0x1d543, 0x0), (0x2199, 0x0), (0x2198, 0x0), (0x21b0, 0x0),
+    (0x141, 0x0), (0x226a, 0x0), (0x2905, 0x0), (0x41c, 0x0), (0x205f, 0x0), (0x2133, 0x0),
+    (0x1d510, 0x0), (0x2213, 0x0), (0x1d544, 0x0), (0x39c, 0x0), (0x40a, 0x0), (0x143, 0x0),
+    (0x147, 0x0), (0x145, 0x0), (0x41d, 0x0), (0x200b, 0x0), (0xa, 0x0), (0x1d511, 0x0),
+    (0x2060, 0x0), (0xa0, 0x0), (0x2115, 0x0), (0x2aec, 0x0), (0x2262, 0x0), (0x226d, 0x0),
+    (0x2226, 0x0), (0x2209, 0x0), (0x2260, 0x0), (0x2242, 0x338), (0x2204, 0x0), (0x226f, 0x0),
+    (0x2271, 0x0), (0x2267, 0x338), (0x226b, 0x338), (0x2279, 0x0), (0x2a7e, 0x338), (0x2275, 0x0),
+    (0x224e, 0x338), (0x224f, 0x338), (0x22ea, 0x0), (0x29cf, 0x338), (0x22ec, 0x0), (0x226e, 0x0),
+    (0x2270, 0x0), (0x2278, 0x0), (0x226a, 0x338), (0x2a7d, 0x338), (0x2274, 0x0), (0x2aa2, 0x338),
+    (0x2aa1, 0x338), (0x2280, 0x0), (0x2aaf, 0x338), (0x22e0, 0x0), (0x220c, 0x0), (0x22eb, 0x0),
+    (0x29d0, 0x338), (0x22ed, 0x0), (0x228f, 0x338), (0x22e2, 0x0), (0x2290, 0x338), (0x22e3, 0x0),
+    (0x2282, 0x20d2), (0x2288, 0x0), (0x2281, 0x0), (0x2ab0, 0x338), (0x22e1, 0x0), (0x227f, 0x338),
+    (0x2283, 0x20d2), (0x2289, 0x0), (0x2241, 0x0), (0x2244, 0x0), (0x2247, 0x0), (0x2249, 0x0),
+    (0x2224, 0x0), (0x1d4a9, 0x0), (0xd1, 0x0), (0x39d, 0x0), (0x152, 0x0), (0xd3, 0x0),
+    (0xd4, 0x0), (0x41e, 0x0), (0x150, 0x0), (0x1d512, 0x0), (0xd2, 0x0), (0x14c, 0x0),
+    (0x3a9, 0x0), (0x39f, 0x0), (0x1d546, 0x0), (0x201c, 0x0), (0x2018, 0x0), (0x2a54, 0x0),
+    (0x1d4aa, 0x0), (0xd8, 0x0), (0xd5, 0x0), (0x2a37, 0x0), (0xd6, 0x0), (0x203e, 0x0),
+    (0x23de, 0x0), (0x23b4, 0x0), (0x23dc, 0x0), (0x2202, 0x0), (0x41f, 0x0), (0x1d513, 0x0),
+    (0x3a6, 0x0), (0x3a0, 0x0), (0xb1, 0x0), (0x2119, 0x0), (0x2abb, 0x0), (0x227a, 0x0),
+    (0x2aaf, 0x0), (0x227c, 0x0), (0x227e, 0x0), (0x2033, 0x0), (0x220f, 0x0), (0x221d, 0x0),
+    (0x1d4ab, 0x0), (0x3a8, 0x0), (0x22, 0x0), (0x1d514, 0x0), (0x211a, 0x0), (0x1d4ac, 0x0),
+    (0x2910, 0x0), (0xae, 0x0), (0x154, 0x0), (0x27eb, 0x0), (0x21a0, 0x0), (0x2916, 0x0),
+    (0x158, 0x0), (0x156, 0x0), (0x420, 0x0), (0x211c, 0x0), (0x220b, 0x0), (0x21cb, 0x0),
+    (0x296f, 0x0), (0x3a1, 0x0), (0x27e9, 0x0), (0x2192, 0x0), (0x21e5, 0x0), (0x21c4, 0x0),
+    (0x2309, 0x0), (0x27e7, 0x0), (0x295d, 0x0), (0x21c2, 0x0), (0x2955, 0x0), (0x230b, 0x0),
+    (0x22a2, 0x0), (0x21a6, 0x0), (0x295b, 0x0), (0x22b3, 0x0), (0x29d0, 0x0), (0x22b5, 0x0),
+    (0x294f, 0x0), (0x295c, 0x0), (0x21be, 0x0), (0x2954, 0x0), (0x21c0, 0x0), (0x2953, 0x0),
+    (0x211d, 0x0), (0x2970, 0x0), (0x21db, 0x0), (0x211b, 0x0), (0x21b1, 0x0), (0x29f4, 0x0),
+    (0x429, 0x0), (0x428, 0x0), (0x42c, 0x0), (0x15a, 0x0), (0x2abc, 0x0), (0x160, 0x0),
+    (0x15e, 0x0), (0x15c, 0x0), (0x421, 0x0), (0x1d516, 0x0), (0x2191, 0x0), (0x3a3, 0x0),
+    (0x2218, 0x0), (0x1d54a, 0x0), (0x221a, 0x0), (0x25a1, 0x0), (0x2293, 0x0), (0x228f, 0x0),
+    (0x2291, 0x0), (0x2290, 0x0), (0x2292, 0x0), (0x2294, 0x0), (0x1d4ae, 0x0), (0x22c6, 0x0),
+    (0x22d0, 0x0), (0x2286, 0x0), (0x227b, 0x0), (0x2ab0, 0x0), (0x227d, 0x0), (0x227f, 0x0),
+    (0x2211, 0x0), (0x22d1, 0x0), (0x2283, 0x0), (0x2287, 0x0), (0xde, 0x0), (0x2122, 0x0),
+    (0x40b, 0x0), (0x426, 0x0), (0x9, 0x0), (0x3a4, 0x0), (0x164, 0x0), (0x162, 0x0),
+    (0x422, 0x0), (0x1d517, 0x0), (0x2234, 0x0), (0x398, 0x0), (0x205f, 0x200a), (0x2009, 0x0),
+    (0x223c, 0x0), (0x2243, 0x0), (0x2245, 0x0), (0x2248, 0x0), (0x1d54b, 0x0), (0x20db, 0x0),
+    (0x1d4af, 0x0), (0x166, 0x0), (0xda, 0x0), (0x219f, 0x0), (0x2949, 0x0), (0x40e, 0x0),
+    (0x16c, 0x0), (0xdb, 0x0), (0x423, 0x0), (0x170, 0x0), (0x1d518, 0x0), (0xd9, 0x0),
+    (0x16a, 0x0), (0x5f, 0x0), (0x23df, 0x0), (0x23b5, 0x0), (0x23dd, 0x0), (0x22c3, 0x0),
+    (0x228e, 0x0), (0x172, 0x0), (0x1d54c, 0x0), (0x2912, 0x0), (0x21c5, 0x0), (0x2195, 0x0),
+    (0x296e, 0x0), (0x22a5, 0x0), (0x21a5, 0x0), (0x2196, 0x0), (0x2197, 0x0), (0x3d2, 0x0),
+    (0x3a5, 0x0), (0x16e, 0x0), (0x1d4b0, 0x0), (0x168, 0x0), (0xdc, 0x0), (0x22ab, 0x0),
+    (0x2aeb, 0x0), (0x412, 0x0), (0x22a9, 0x0), (0x2ae6, 0x0), (0x22c1, 0x0), (0x2016, 0x0),
+    (0x2223, 0x0), (0x7c, 0x0), (0x2758, 0x0), (0x2240, 0x0), (0x200a, 0x0), (0x1d519, 0x0),
+    (0x1d54d, 0x0), (0x1d4b1, 0x0), (0x22aa, 0x0), (0x174, 0x0), (0x22c0, 0x0), (0x1d51a, 0x0),
+    (0x1d54e, 0x0), (0x1d4b2, 0x0), (0x1d51b, 0x0), (0x39e, 0x0), (0x1d54f, 0x0), (0x1d4b3, 0x0),
+    (0x42f, 0x0), (0x407, 0x0), (0x42e, 0x0), (0xdd, 0x0), (0x176, 0x0), (0x42b, 0x0),
+    (0x1d51c, 0x0), (0x1d550, 0x0), (0x1d4b4, 0x0), (0x178, 0x0), (0x416, 0x0), (0x179, 0x0),
+    (0x17d, 0x0), (0x417, 0x0), (0x17b, 0x0), (0x396, 0x0), (0x2128, 0x0), (0x2124, 0x0),
+    (0x1d4b5, 0x0), (0xe1, 0x0), (0x103, 0x0), (0x223e, 0x0), (0x223e, 0x333), (0x223f, 0x0),
+    (0xe2, 0x0), (0x430, 0x0), (0xe6, 0x0), (0x1d51e, 0x0), (0xe0, 0x0), (0x2135, 0x0),
+    (0x3b1, 0x0), (0x101, 0x0), (0x2a3f, 0x0), (0x2227, 0x0), (0x2a55, 0x0), (0x2a5c, 0x0),
+    (0x2a58, 0x0), (0x2a5a, 0x0), (0x2220, 0x0), (0x29a4, 0x0), (0x2221, 0x0), (0x29a8, 0x0),
+    (0x29a9, 0x0), (0x29aa, 0x0), (0x29ab, 0x0), (0x29ac, 0x0), (0x29ad, 0x0), (0x29ae, 0x0),
+    (0x29af, 0x0), (0x221f, 0x0), (0x22be, 0x0), (0x299d, 0x0), (0x2222, 0x0), (0x237c, 0x0),
+    (0x105, 0x0), (0x1d552, 0x0), (0x2a70, 0x0), (0x2a6f, 0x0), (0x224a, 0x0), (0x224b, 0x0),
+    (0x27, 0x0), (0xe5, 0x0), (0x1d4b6, 0x0), (0x2a, 0x0), (0xe3, 0x0), (0xe4, 0x0),
+    (0x2a11, 0x0), (0x2aed, 0x0), (0x224c, 0x0), (0x3f6, 0x0), (0x2035, 0x0), (0x223d, 0x0),
+    (0x22cd, 0x0), (0x22bd, 0x0), (0x2305, 0x0), (0x23b6, 0x0), (0x431, 0x0), (0x201e, 0x0),
+    (0x29b0, 0x0), (0x3b2, 0x0), (0x2136, 0x0), (0x226c, 0x0), (0x1d51f, 0x0), (0x25ef, 0x0),
+    (0x2a00, 0x0), (0x2a01, 0x0), (0x2a02, 0x0), (0x2a06, 0x0), (0x2605, 0x0), (0x25bd, 0x0),
+    (0x25b3, 0x0), (0x2a04, 0x0), (0x290d, 0x0), (0x29eb, 0x0), (0x25b4, 0x0), (0x25be, 0x0),
+    (0x25c2, 0x0), (0x25b8, 0x0), (0x2423, 0x0), (0x2592, 0x0), (0x2591, 0x0), (0x2593, 0x0),
+    (0x2588, 0x0), (0x3d, 0x20e5), (0x2261, 0x20e5), (0x2310, 0x0), (0x1d553, 0x0), (0x22c8, 0x0),
+    (0x2557, 0x0), (0x2554, 0x0), (0x2556, 0x0), (0x2553, 0x0), (0x2550, 0x0), (0x2566, 0x0),
+    (0x2569, 0x0), (0x2564, 0x0), (0x2567, 0x0), (0x255d, 0x0), (0x255a, 0x0), (0x255c, 0x0),
+    (0x2559, 0x0), (0x2551, 0x0), (0x256c, 0x0), (0x2563, 0x0), (0x2560, 0x0), (0x256b, 0x0),
+    (0x2562, 0x0), (0x255f, 0x0), (0x29c9, 0x0), (0x2555, 0x0), (0x2552, 0x0), (0x2510, 0x0),
+    (0x250c, 0x0), (0x2565, 0x0), (0x2568, 0x0), (0x252c, 0x0), (0x2534, 0x0), (0x229f, 0x0),
+    (0x229e, 0x0), (0x22a0, 0x0), (0x255b, 0x0), (0x2558, 0x0), (0x2518, 0x0), (0x2514, 0x0),
+    (0x2502, 0x0), (0x256a, 0x0), (0x2561, 0x0), (0x255e, 0x0), (0x253c, 0x0), (0x2524, 0x0),
+    (0x251c, 0x0), (0xa6, 0x0), (0x1d4b7, 0x0), (0x204f, 0x0), (0x5c, 0x0), (0x29c5, 0x0),
+    (0x27c8, 0x0), (0x2022, 0x0), (0x2aae, 0x0), (0x107, 0x0), (0x2229, 0x0), (0x2a44, 0x0),
+    (0x2a49, 0x0), (0x2a4b, 0x0), (0x2a47, 0x0), (0x2a40, 0x0), (0x2229, 0xfe00), (0x2041, 0x0),
+    (0x2a4d, 0x0), (0x10d, 0x0), (0xe7, 0x0), (0x109, 0x0), (0x2a4c, 0x0), (0x2a50, 0x0),
+    (0x10b, 0x0), (0x29b2, 0x0), (0xa2, 0x0), (0x1d520, 0x0), (0x447, 0x0), (0x2713, 0x0),
+    (0x3c7, 0x0), (0x25cb, 0x0), (0x29c3, 0x0), (0x2c6, 0x0), (0x2257, 0x0), (0x21ba, 0x0),
+    (0x21bb, 0x0), (0x24c8, 0x0), (0x229b, 0x0), (0x229a, 0x0), (0x229d, 0x0), (0x2a10, 0x0),
+    (0x2aef, 0x0), (0x29c2, 0x0), (0x2663, 0x0), (0x3a, 0x0), (0x2c, 0x0), (0x40, 0x0),
+    (0x2201, 0x0), (0x2a6d, 0x0), (0x1d554, 0x0), (0x2117, 0x0), (0x21b5, 0x0), (0x2717, 0x0),
+    (0x1d4b8, 0x0), (0x2acf, 0x0), (0x2ad1, 0x0), (0x2ad0, 0x0), (0x2ad2, 0x0), (0x22ef, 0x0),
+    (0x2938, 0x0), (0x2935, 0x0), (0x22de, 0x0), (0x22df, 0x0), (0x21b6, 0x0), (0x293d, 0x0),
+    (0x222a, 0x0), (0x2a48, 0x0), (0x2a46, 0x0), (0x2a4a, 0x0), (0x228d, 0x0), (0x2a45, 0x0),
+    (0x222a, 0xfe00), (0x21b7, 0x0), (0x293c, 0x0), (0x22ce, 0x0), (0x22cf, 0x0), (0xa4, 0x0),
+    (0x2231, 0x0), (0x232d, 0x0), (0x2965, 0x0), (0x2020, 0x0), (0x2138, 0x0), (0x2010, 0x0),
+    (0x290f, 0x0), (0x10f, 0x0), (0x434, 0x0), (0x21ca, 0x0), (0x2a77, 0x0), (0xb0, 0x0),
+    (0x3b4, 0x0), (0x29b1, 0x0), (0x297f, 0x0), (0x1d521, 0x0), (0x2666, 0x0), (0x3dd, 0x0),
+    (0x22f2, 0x0), (0xf7, 0x0), (0x22c7, 0x0), (0x452, 0x0), (0x231e, 0x0), (0x230d, 0x0),
+    (0x24, 0x0), (0x1d555, 0x0), (0x2251, 0x0), (0x2238, 0x0), (0x2214, 0x0), (0x22a1, 0x0),
+    (0x231f, 0x0), (0x230c, 0x0), (0x1d4b9, 0x0), (0x455, 0x0), (0x29f6, 0x0), (0x111, 0x0),
+    (0x22f1, 0x0), (0x25bf, 0x0), (0x29a6, 0x0), (0x45f, 0x0), (0x27ff, 0x0), (0xe9, 0x0),
+    (0x2a6e, 0x0), (0x11b, 0x0), (0x2256, 0x0), (0xea, 0x0), (0x2255, 0x0), (0x44d, 0x0),
+    (0x117, 0x0), (0x2252, 0x0), (0x1d522, 0x0), (0x2a9a, 0x0), (0xe8, 0x0), (0x2a96, 0x0),
+    (0x2a98, 0x0), (0x2a99, 0x0), (0x23e7, 0x0), (0x2113, 0x0), (0x2a95, 0x0), (0x2a97, 0x0),
+    (0x113, 0x0), (0x2205, 0x0), (0x2004, 0x0), (0x2005, 0x0), (0x2003, 0x0), (0x14b, 0x0),
+    (0x2002, 0x0), (0x119, 0x0), (0x1d556, 0x0), (0x22d5, 0x0), (0x29e3, 0x0), (0x2a71, 0x0),
+    (0x3b5, 0x0), (0x3f5, 0x0), (0x3d, 0x0), (0x225f, 0x0), (0x2a78, 0x0), (0x29e5, 0x0),
+    (0x2253, 0x0), (0x2971, 0x0), (0x212f, 0x0), (0x3b7, 0x0), (0xf0, 0x0), (0xeb, 0x0),
+    (0x20ac, 0x0), (0x21, 0x0), (0x444, 0x0), (0x2640, 0x0), (0xfb03, 0x0), (0xfb00, 0x0),
+    (0xfb04, 0x0), (0x1d523, 0x0), (0xfb01, 0x0), (0x66, 0x6a), (0x266d, 0x0), (0xfb02, 0x0),
+    (0x25b1, 0x0), (0x192, 0x0), (0x1d557, 0x0), (0x22d4, 0x0), (0x2ad9, 0x0), (0x2a0d, 0x0),
+    (0xbd, 0x0), (0x2153, 0x0), (0xbc, 0x0), (0x2155, 0x0), (0x2159, 0x0), (0x215b, 0x0),
+    (0x2154, 0x0), (0x2156, 0x0), (0xbe, 0x0), (0x2157, 0x0), (0x215c, 0x0), (0x2158, 0x0),
+    (0x215a, 0x0), (0x215d, 0x0), (0x215e, 0x0), (0x2044, 0x0), (0x2322, 0x0), (0x1d4bb, 0x0),
+    (0x2a8c, 0x0), (0x1f5, 0x0), (0x3b3, 0x0), (0x2a86, 0x0), (0x11f, 0x0), (0x11d, 0x0),
+    (0x433, 0x0), (0x121, 0x0), (0x2aa9, 0x0), (0x2a80, 0x0), (0x2a82, 0x0), (0x2a84, 0x0),
+    (0x22db, 0xfe00), (0x2a94, 0x0), (0x1d524, 0x0), (0x2137, 0x0), (0x453, 0x0), (0x2a92, 0x0),
+    (0x2aa5, 0x0), (0x2aa4, 0x0), (0x2269, 0x0), (0x2a8a, 0x0), (0x2a88, 0x0), (0x22e7, 0x0),
+    (0x1d558, 0x0), (0x210a, 0x0), (0x2a8e, 0x0), (0x2a90, 0x0), (0x2aa7, 0x0), (0x2a7a, 0x0),
+    (0x22d7, 0x0), (0x2995, 0x0), (0x2a7c, 0x0), (0x2978, 0x0), (0x2269, 0xfe00), (0x44a, 0x0),
+    (0x2948, 0x0), (0x21ad, 0x0), (0x210f, 0x0), (0x125, 0x0), (0x2665, 0x0), (0x2026, 0x0),
+    (0x22b9, 0x0), (0x1d525, 0x0), (0x2925, 0x0), (0x2926, 0x0), (0x21ff, 0x0), (0x223b, 0x0),
+    (0x21a9, 0x0), (0x21aa, 0x0), (0x1d559, 0x0), (0x2015, 0x0), (0x1d4bd, 0x0), (0x127, 0x0),
+    (0x2043, 0x0), (0xed, 0x0), (0xee, 0x0), (0x438, 0x0), (0x435, 0x0), (0xa1, 0x0),
+    (0x1d526, 0x0), (0xec, 0x0), (0x2a0c, 0x0), (0x222d, 0x0), (0x29dc, 0x0), (0x2129, 0x0),
+    (0x133, 0x0), (0x12b, 0x0), (0x131, 0x0), (0x22b7, 0x0), (0x1b5, 0x0), (0x2105, 0x0),
+    (0x221e, 0x0), (0x29dd, 0x0), (0x22ba, 0x0), (0x2a17, 0x0), (0x2a3c, 0x0), (0x451, 0x0),
+    (0x12f, 0x0), (0x1d55a, 0x0), (0x3b9, 0x0), (0xbf, 0x0), (0x1d4be, 0x0), (0x22f9, 0x0),
+    (0x22f5, 0x0), (0x22f4, 0x0), (0x22f3, 0x0), (0x129, 0x0), (0x456, 0x0), (0xef, 0x0),
+    (0x135, 0x0), (0x439, 0x0), (0x1d527, 0x0), (0x237, 0x0), (0x1d55b, 0x0), (0x1d4bf, 0x0),
+    (0x458, 0x0), (0x454, 0x0), (0x3ba, 0x0), (0x3f0, 0x0), (0x137, 0x0), (0x43a, 0x0),
+    (0x1d528, 0x0), (0x138, 0x0), (0x445, 0x0), (0x45c, 0x0), (0x1d55c, 0x0), (0x1d4c0, 0x0),
+    (0x291b, 0x0), (0x290e, 0x0), (0x2a8b, 0x0), (0x2962, 0x0), (0x13a, 0x0), (0x29b4, 0x0),
+    (0x3bb, 0x0), (0x2991, 0x0), (0x2a85, 0x0), (0xab, 0x0), (0x291f, 0x0), (0x291d, 0x0),
+    (0x21ab, 0x0), (0x2939, 0x0), (0x2973, 0x0), (0x21a2, 0x0), (0x2aab, 0x0), (0x2919, 0x0),
+    (0x2aad, 0x0), (0x2aad, 0xfe00), (0x290c, 0x0), (0x2772, 0x0), (0x7b, 0x0), (0x5b, 0x0),
+    (0x298b, 0x0), (0x298f, 0x0), (0x298d, 0x0), (0x13e, 0x0), (0x13c, 0x0), (0x43b, 0x0),
+    (0x2936, 0x0), (0x2967, 0x0), (0x294b, 0x0), (0x21b2, 0x0), (0x2264, 0x0), (0x21c7, 0x0),
+    (0x22cb, 0x0), (0x2aa8, 0x0), (0x2a7f, 0x0), (0x2a81, 0x0), (0x2a83, 0x0), (0x22da, 0xfe00),
+    (0x2a93, 0x0), (0x22d6, 0x0), (0x297c, 0x0), (0x1d529, 0x0), (0x2a91, 0x0), (0x296a, 0x0),
+    (0x2584, 0x0), (0x459, 0x0), (0x296b, 0x0), (0x25fa, 0x0), (0x140, 0x0), (0x23b0, 0x0),
+    (0x2268, 0x0), (0x2a89, 0x0), (0x2a87, 0x0), (0x22e6, 0x0), (0x27ec, 0x0), (0x21fd, 0x0),
+    (0x27fc, 0x0), (0x21ac, 0x0), (0x2985, 0x0), (0x1d55d, 0x0), (0x2a2d, 0x0), (0x2a34, 0x0),
+    (0x2217, 0x0), (0x25ca, 0x0), (0x28, 0x0), (0x2993, 0x0), (0x296d, 0x0), (0x200e, 0x0),
+    (0x22bf, 0x0), (0x2039, 0x0), (0x1d4c1, 0x0), (0x2a8d, 0x0), (0x2a8f, 0x0), (0x201a, 0x0),
+    (0x142, 0x0), (0x2aa6, 0x0), (0x2a79, 0x0), (0x22c9, 0x0), (0x2976, 0x0), (0x2a7b, 0x0),
+    (0x2996, 0x0), (0x25c3, 0x0), (0x294a, 0x0), (0x2966, 0x0), (0x2268, 0xfe00), (0x223a, 0x0),
+    (0xaf, 0x0), (0x2642, 0x0), (0x2720, 0x0), (0x25ae, 0x0), (0x2a29, 0x0), (0x43c, 0x0),
+    (0x2014, 0x0), (0x1d52a, 0x0), (0x2127, 0x0), (0xb5, 0x0), (0x2af0, 0x0), (0x2212, 0x0),
+    (0x2a2a, 0x0), (0x2adb, 0x0), (0x22a7, 0x0), (0x1d55e, 0x0), (0x1d4c2, 0x0), (0x3bc, 0x0),
+    (0x22b8, 0x0), (0x22d9, 0x338), (0x226b, 0x20d2), (0x21cd, 0x0), (0x21ce, 0x0), (0x22d8, 0x338),
+    (0x226a, 0x20d2), (0x21cf, 0x0), (0x22af, 0x0), (0x22ae, 0x0), (0x144, 0x0), (0x2220, 0x20d2),
+    (0x2a70, 0x338), (0x224b, 0x338), (0x149, 0x0), (0x266e, 0x0), (0x2a43, 0x0), (0x148, 0x0),
+    (0x146, 0x0), (0x2a6d, 0x338), (0x2a42, 0x0), (0x43d, 0x0), (0x2013, 0x0), (0x21d7, 0x0),
+    (0x2924, 0x0), (0x2250, 0x338), (0x2928, 0x0), (0x1d52b, 0x0), (0x21ae, 0x0), (0x2af2, 0x0),
+    (0x22fc, 0x0), (0x22fa, 0x0), (0x45a, 0x0), (0x2266, 0x338), (0x219a, 0x0), (0x2025, 0x0),
+    (0x1d55f, 0x0), (0xac, 0x0), (0x22f9, 0x338), (0x22f5, 0x338), (0x22f7, 0x0), (0x22f6, 0x0),
+    (0x22fe, 0x0), (0x22fd, 0x0), (0x2afd, 0x20e5), (0x2202, 0x338), (0x2a14, 0x0), (0x219b, 0x0),
+    (0x2933, 0x338), (0x219d, 0x338), (0x1d4c3, 0x0), (0x2284, 0x0), (0x2ac5, 0x338), (0x2285, 0x0),
+    (0x2ac6, 0x338), (0xf1, 0x0), (0x3bd, 0x0), (0x23, 0x0), (0x2116, 0x0), (0x2007, 0x0),
+    (0x22ad, 0x0), (0x2904, 0x0), (0x224d, 0x20d2), (0x22ac, 0x0), (0x2265, 0x20d2), (0x3e, 0x20d2),
+    (0x29de, 0x0), (0x2902, 0x0), (0x2264, 0x20d2), (0x3c, 0x20d2), (0x22b4, 0x20d2), (0x2903, 0x0),
+    (0x22b5, 0x20d2), (0x223c, 0x20d2), (0x21d6, 0x0), (0x2923, 0x0), (0x2927, 0x0), (0xf3, 0x0),
+    (0xf4, 0x0), (0x43e, 0x0), (0x151, 0x0), (0x2a38, 0x0), (0x29bc, 0x0), (0x153, 0x0),
+    (0x29bf, 0x0), (0x1d52c, 0x0), (0x2db, 0x0), (0xf2, 0x0), (0x29c1, 0x0), (0x29b5, 0x0),
+    (0x29be, 0x0), (0x29bb, 0x0), (0x29c0, 0x0), (0x14d, 0x0), (0x3c9, 0x0), (0x3bf, 0x0),
+    (0x29b6, 0x0), (0x1d560, 0x0), (0x29b7, 0x0), (0x29b9, 0x0), (0x2228, 0x0), (0x2a5d, 0x0),
+    (0x2134, 0x0), (0xaa, 0x0), (0xba, 0x0), (0x22b6, 0x0), (0x2a56, 0x0), (0x2a57, 0x0),
+    (0x2a5b, 0x0), (0xf8, 0x0), (0x2298, 0x0), (0xf5, 0x0), (0x2a36, 0x0), (0xf6, 0x0),
+    (0x233d, 0x0), (0xb6, 0x0), (0x2af3, 0x0), (0x2afd, 0x0), (0x43f, 0x0), (0x25, 0x0),
+    (0x2e, 0x0), (0x2030, 0x0), (0x2031, 0x0), (0x1d52d, 0x0), (0x3c6, 0x0), (0x3d5, 0x0),
+    (0x260e, 0x0), (0x3c0, 0x0), (0x3d6, 0x0), (0x210e, 0x0), (0x2b, 0x0), (0x2a23, 0x0),
+    (0x2a22, 0x0), (0x2a25, 0x0), (0x2a72, 0x0), (0x2a26, 0x0), (0x2a27, 0x0), (0x2a15, 0x0),
+    (0x1d561, 0x0), (0xa3, 0x0), (0x2ab3, 0x0), (0x2ab7, 0x0), (0x2ab9, 0x0), (0x2ab5, 0x0),
+    (0x22e8, 0x0), (0x2032, 0x0), (0x232e, 0x0), (0x2312, 0x0), (0x2313, 0x0), (0x22b0, 0x0),
+    (0x1d4c5, 0x0), (0x3c8, 0x0), (0x2008, 0x0), (0x1d52e, 0x0), (0x1d562, 0x0), (0x2057, 0x0),
+    (0x1d4c6, 0x0), (0x2a16, 0x0), (0x3f, 0x0), (0x291c, 0x0), (0x2964, 0x0), (0x223d, 0x331),
+    (0x155, 0x0), (0x29b3, 0x0), (0x2992, 0x0), (0x29a5, 0x0), (0xbb, 0x0), (0x2975, 0x0),
+    (0x2920, 0x0), (0x2933, 0x0), (0x291e, 0x0), (0x2945, 0x0), (0x2974, 0x0), (0x21a3, 0x0),
+    (0x219d, 0x0), (0x291a, 0x0), (0x2236, 0x0), (0x2773, 0x0), (0x7d, 0x0), (0x5d, 0x0),
+    (0x298c, 0x0), (0x298e, 0x0), (0x2990, 0x0), (0x159, 0x0), (0x157, 0x0), (0x440, 0x0),
+    (0x2937, 0x0), (0x2969, 0x0), (0x21b3, 0x0), (0x25ad, 0x0), (0x297d, 0x0), (0x1d52f, 0x0),
+    (0x296c, 0x0), (0x3c1, 0x0), (0x3f1, 0x0), (0x21c9, 0x0), (0x22cc, 0x0), (0x2da, 0x0),
+    (0x200f, 0x0), (0x23b1, 0x0), (0x2aee, 0x0), (0x27ed, 0x0), (0x21fe, 0x0), (0x2986, 0x0),
+    (0x1d563, 0x0), (0x2a2e, 0x0), (0x2a35, 0x0), (0x29, 0x0), (0x2994, 0x0), (0x2a12, 0x0),
+    (0x203a, 0x0), (0x1d4c7, 0x0), (0x22ca, 0x0), (0x25b9, 0x0), (0x29ce, 0x0), (0x2968, 0x0),
+    (0x211e, 0x0), (0x15b, 0x0), (0x2ab4, 0x0), (0x2ab8, 0x0), (0x161, 0x0), (0x15f, 0x0),
+    (0x15d, 0x0), (0x2ab6, 0x0), (0x2aba, 0x0), (0x22e9, 0x0), (0x2a13, 0x0), (0x441, 0x0),
+    (0x22c5, 0x0), (0x2a66, 0x0), (0x21d8, 0x0), (0xa7, 0x0), (0x3b, 0x0), (0x2929, 0x0),
+    (0x2736, 0x0), (0x1d530, 0x0), (0x266f, 0x0), (0x449, 0x0), (0x448, 0x0), (0xad, 0x0),
+    (0x3c3, 0x0), (0x3c2, 0x0), (0x2a6a, 0x0), (0x2a9e, 0x0), (0x2aa0, 0x0), (0x2a9d, 0x0),
+    (0x2a9f, 0x0), (0x2246, 0x0), (0x2a24, 0x0), (0x2972, 0x0), (0x2a33, 0x0), (0x29e4, 0x0),
+    (0x2323, 0x0), (0x2aaa, 0x0), (0x2aac, 0x0), (0x2aac, 0xfe00), (0x44c, 0x0), (0x2f, 0x0),
+    (0x29c4, 0x0), (0x233f, 0x0), (0x1d564, 0x0), (0x2660, 0x0), (0x2293, 0xfe00), (0x2294, 0xfe00),
+    (0x1d4c8, 0x0), (0x2606, 0x0), (0x2282, 0x0), (0x2ac5, 0x0), (0x2abd, 0x0), (0x2ac3, 0x0),
+    (0x2ac1, 0x0), (0x2acb, 0x0), (0x228a, 0x0), (0x2abf, 0x0), (0x2979, 0x0), (0x2ac7, 0x0),
+    (0x2ad5, 0x0), (0x2ad3, 0x0), (0x266a, 0x0), (0xb9, 0x0), (0xb2, 0x0), (0xb3, 0x0),
+    (0x2ac6, 0x0), (0x2abe, 0x0), (0x2ad8, 0x0), (0x2ac4, 0x0), (0x27c9, 0x0), (0x2ad7, 0x0),
+    (0x297b, 0x0), (0x2ac2, 0x0), (0x2acc, 0x0), (0x228b, 0x0), (0x2ac0, 0x0), (0x2ac8, 0x0),
+    (0x2ad4, 0x0), (0x2ad6, 0x0), (0x21d9, 0x0), (0x292a, 0x0), (0xdf, 0x0), (0x2316, 0x0),
+    (0x3c4, 0x0), (0x165, 0x0), (0x163, 0x0), (0x442, 0x0), (0x2315, 0x0), (0x1d531, 0x0),
+    (0x3b8, 0x0), (0x3d1, 0x0), (0xfe, 0x0), (0xd7, 0x0), (0x2a31, 0x0), (0x2a30, 0x0),
+    (0x2336, 0x0), (0x2af1, 0x0), (0x1d565, 0x0), (0x2ada, 0x0), (0x2034, 0x0), (0x25b5, 0x0),
+    (0x225c, 0x0), (0x25ec, 0x0), (0x2a3a, 0x0), (0x2a39, 0x0), (0x29cd, 0x0), (0x2a3b, 0x0),
+    (0x23e2, 0x0), (0x1d4c9, 0x0), (0x446, 0x0), (0x45b, 0x0), (0x167, 0x0), (0x2963, 0x0),
+    (0xfa, 0x0), (0x45e, 0x0), (0x16d, 0x0), (0xfb, 0x0), (0x443, 0x0), (0x171, 0x0),
+    (0x297e, 0x0), (0x1d532, 0x0), (0xf9, 0x0), (0x2580, 0x0), (0x231c, 0x0), (0x230f, 0x0),
+    (0x25f8, 0x0), (0x16b, 0x0), (0x173, 0x0), (0x1d566, 0x0), (0x3c5, 0x0), (0x21c8, 0x0),
+    (0x231d, 0x0), (0x230e, 0x0), (0x16f, 0x0), (0x25f9, 0x0), (0x1d4ca, 0x0), (0x22f0, 0x0),
+    (0x169, 0x0), (0xfc, 0x0), (0x29a7, 0x0), (0x2ae8, 0x0), (0x2ae9, 0x0), (0x299c, 0x0),
+    (0x228a, 0xfe00), (0x2acb, 0xfe00), (0x228b, 0xfe00), (0x2acc, 0xfe00), (0x432, 0x0), (0x22bb, 0x0),
+    (0x225a, 0x0), (0x22ee, 0x0), (0x1d533, 0x0), (0x1d567, 0x0), (0x1d4cb, 0x0), (0x299a, 0x0),
+    (0x175, 0x0), (0x2a5f, 0x0), (0x2259, 0x0), (0x2118, 0x0), (0x1d534, 0x0), (0x1d568, 0x0),
+    (0x1d4cc, 0x0), (0x1d535, 0x0), (0x3be, 0x0), (0x22fb, 0x0), (0x1d569, 0x0), (0x1d4cd, 0x0),
+    (0xfd, 0x0), (0x44f, 0x0), (0x177, 0x0), (0x44b, 0x0), (0xa5, 0x0), (0x1d536, 0x0),
+    (0x457, 0x0), (0x1d56a, 0x0), (0x1d4ce, 0x0), (0x44e, 0x0), (0xff, 0x0), (0x17a, 0x0),
+    (0x17e, 0x0), (0x437, 0x0), (0x17c, 0x0), (0x3b6, 0x0), (0x1d537, 0x0), (0x436, 0x0),
+    (0x21dd, 0x0), (0x1d56b, 0x0), (0x1d4cf, 0x0), (0x200d, 0x0), (0x200c, 0x0),
+];
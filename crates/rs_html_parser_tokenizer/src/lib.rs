@@ -1,8 +1,14 @@
-use htmlize::Context;
+#![no_std]
+#![forbid(unsafe_code)]
+
+pub mod entities;
+mod entity_table;
+
+use core::iter::Iterator;
+use core::mem::take;
+use crate::entities::{numeric_code_point, EntityMatcher, MAX_CODE_POINT};
+use memchr::{memchr, memchr2};
 use rs_html_parser_tokenizer_tokens::{QuoteType, TokenizerToken, TokenizerTokenLocation};
-use std::iter::Iterator;
-use std::ops::Range;
-use std::str;
 
 struct CharCodes {}
 
@@ -27,7 +33,7 @@ impl CharCodes {
     const GT: u8 = 62; // ">"
     const QUESTION_MARK: u8 = 63; // "?"
                                   // const UPPER_A: u8 = 65; // "A"
-    const UNDERSCORE: u8 = 95; // "_"
+    // const UNDERSCORE: u8 = 95; // "_"
                                // const LOWER_A: u8 = 97; // "a"
                                // const UPPER_F: u8 = 70; // "F"
                                // const LOWER_F: u8 = 102; // "f"
@@ -40,14 +46,49 @@ impl CharCodes {
 struct Sequences {}
 
 impl Sequences {
-    const EMPTY: [u8; 0] = [];
     const CDATA: [u8; 6] = [0x43, 0x44, 0x41, 0x54, 0x41, 0x5b]; // CDATA[
     const CDATA_END: [u8; 3] = [0x5d, 0x5d, 0x3e]; // ]]>
     const COMMENT_END: [u8; 3] = [0x2d, 0x2d, 0x3e]; // `-->`
-    const SCRIPT_END: [u8; 8] = [0x3c, 0x2f, 0x73, 0x63, 0x72, 0x69, 0x70, 0x74]; // `</script`
-    const STYLE_END: [u8; 7] = [0x3c, 0x2f, 0x73, 0x74, 0x79, 0x6c, 0x65]; // `</style`
-    const TITLE_END: [u8; 7] = [0x3c, 0x2f, 0x74, 0x69, 0x74, 0x6c, 0x65]; // `</title`
-    const TEXTAREA_END: [u8; 10] = [0x3c, 0x2f, 0x74, 0x65, 0x78, 0x74, 0x61, 0x72, 0x65, 0x61]; // `</textarea`
+    const SCRIPT: [u8; 6] = [0x73, 0x63, 0x72, 0x69, 0x70, 0x74]; // `script`
+    const STYLE: [u8; 5] = [0x73, 0x74, 0x79, 0x6c, 0x65]; // `style`
+    const TITLE: [u8; 5] = [0x74, 0x69, 0x74, 0x6c, 0x65]; // `title`
+    const TEXTAREA: [u8; 8] = [0x74, 0x65, 0x78, 0x74, 0x61, 0x72, 0x65, 0x61]; // `textarea`
+}
+
+/**
+ * The content models a tree builder can switch the tokenizer into.
+ *
+ * The selected content model applies to the text following the start tag that is currently being
+ * tokenized, until the matching end tag. See `Tokenizer::set_content_model`.
+ */
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ContentModel {
+    /// Regular markup.
+    Data,
+    /// Text with character references, eg. the contents of `<title>` and `<textarea>`.
+    RcData,
+    /// Text without character references, eg. the contents of `<style>`.
+    RawText,
+    /// The contents of `<script>`. Script escapes are not tracked, so this behaves like `RawText`.
+    ScriptData,
+    /// Everything until the end of the input is text, eg. after `<plaintext>`.
+    PlainText,
+}
+
+/**
+ * A point where the input can be split, to tokenize the parts of it separately: right after the
+ * `<` of a tag that starts in text. The tokenizer continues from it the same way, whatever came
+ * before it, see `Tokenizer::boundary` and `Tokenizer::resume`.
+ */
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct TagBoundary {
+    /** The index of the `<`. */
+    pub index: usize,
+    /**
+     * Whether text was emitted right before the tag. Whitespace-only text after the tag is then
+     * dropped. Always `false` when whitespace between tags is not ignored.
+     */
+    pub follows_text: bool,
 }
 
 /** All the states the tokenizer can be in. */
@@ -87,12 +128,15 @@ enum State {
     InCommentLike,
 
     // Special tags
-    BeforeSpecialS, // Decide if we deal with `<script` or `<style`
-    BeforeSpecialT, // Decide if we deal with `<title` or `<textarea`
-    SpecialStartSequence,
     InSpecialTag,
-
-    InEntity,
+    InPlainText,
+
+    // Character references
+    BeforeEntity, // &
+    InNamedEntity,
+    BeforeNumericEntity, // &#
+    InNumericEntity,
+    InHexEntity,
     AfterReadEntityText,
     AfterReadEntityAttr,
 
@@ -103,11 +147,17 @@ pub struct Tokenizer<'a> {
     state: State,
     buffer: &'a [u8],
     section_start: usize,
-    index: i32,
+    index: usize,
+    // Where the tokenizer stops, the end of the buffer unless `set_end` moved it
+    end: usize,
     entity_start: usize,
     base_state: State,
-    is_special: bool,
+    content_model: ContentModel,
+    cdata_allowed: bool,
+    special_tag_name: &'a [u8],
     code: u32,
+    second_code: u32,
+    entity_matcher: EntityMatcher,
     prev_quote_type: QuoteType,
 
     ignore_whitespace_between_tags: bool,
@@ -117,6 +167,8 @@ pub struct Tokenizer<'a> {
 
     current_sequence: &'a [u8],
     sequence_index: usize,
+    // Whether the current byte is processed again in the new state, instead of moving to the next
+    reconsume: bool,
     has_ended: bool,
 }
 
@@ -151,8 +203,8 @@ fn is_end_of_tag_section(c: u8) -> bool {
     )
 }
 
-impl Tokenizer<'_> {
-    pub fn new<'a>(buffer: &'a [u8], options: &'a TokenizerOptions) -> Tokenizer<'a> {
+impl<'a> Tokenizer<'a> {
+    pub fn new(buffer: &'a [u8], options: &'a TokenizerOptions) -> Tokenizer<'a> {
         let ignore_whitespace = options.ignore_whitespace_between_tags.unwrap_or(true);
 
         Tokenizer {
@@ -160,14 +212,20 @@ impl Tokenizer<'_> {
             buffer,
             section_start: 0,
             index: 0,
+            end: buffer.len(),
             code: 0,
+            second_code: 0,
+            entity_matcher: EntityMatcher::new(),
             entity_start: 0,
             base_state: State::Text,
-            is_special: false,
+            content_model: ContentModel::Data,
+            cdata_allowed: true,
+            special_tag_name: Default::default(),
             xml_mode: options.xml_mode.unwrap_or(false),
             decode_entities: options.decode_entities.unwrap_or(true),
             current_sequence: Default::default(),
             sequence_index: 0,
+            reconsume: false,
             has_ended: false,
             prev_quote_type: QuoteType::NoValue,
             ignore_whitespace_between_tags: ignore_whitespace,
@@ -175,39 +233,167 @@ impl Tokenizer<'_> {
         }
     }
 
-    pub fn reset(&mut self) {
+    /**
+     * Starts over with a new input, as if the tokenizer was just created. The options and the
+     * whitespace setting are kept, the content model and `set_cdata_allowed` are not.
+     */
+    pub fn reset(&mut self, buffer: &'a [u8]) {
         self.state = State::Text;
-        self.buffer = &Sequences::EMPTY;
+        self.buffer = buffer;
         self.section_start = 0;
         self.index = 0;
+        self.end = buffer.len();
+        self.code = 0;
+        self.second_code = 0;
+        self.entity_matcher = EntityMatcher::new();
+        self.entity_start = 0;
         self.base_state = State::Text;
+        self.content_model = ContentModel::Data;
+        self.cdata_allowed = true;
+        self.special_tag_name = Default::default();
         self.current_sequence = Default::default();
+        self.sequence_index = 0;
+        self.reconsume = false;
+        self.has_ended = false;
+        self.prev_quote_type = QuoteType::NoValue;
+        self.seen_non_whitespace_text = !self.ignore_whitespace_between_tags;
+    }
+
+    /**
+     * Sets the content model of the text following the start tag that is currently being
+     * tokenized. It has to be called after the `OpenTagName` token and before the tag ends.
+     *
+     * By default the tokenizer picks the content model from the tag name, treating `<script>`,
+     * `<style>`, `<title>` and `<textarea>` specially outside of XML mode. A tree builder that
+     * knows better (eg. `<style>` inside `<svg>` is a regular element) overrides it here.
+     * Self-closing tags always continue with `ContentModel::Data`.
+     */
+    pub fn set_content_model(&mut self, content_model: ContentModel) {
+        self.content_model = content_model;
+    }
+
+    /**
+     * Sets whether `<![CDATA[` starts a CDATA section. Otherwise it starts a bogus comment, as
+     * in HTML content. Defaults to `true`.
+     */
+    pub fn set_cdata_allowed(&mut self, cdata_allowed: bool) {
+        self.cdata_allowed = cdata_allowed;
+    }
+
+    /**
+     * Sets whether whitespace-only text between tags is dropped, overriding
+     * `TokenizerOptions::ignore_whitespace_between_tags`.
+     */
+    pub fn set_ignore_whitespace_between_tags(&mut self, ignore: bool) {
+        self.ignore_whitespace_between_tags = ignore;
+        self.seen_non_whitespace_text = !ignore;
+    }
+
+    /**
+     * Stops the tokenizer at `end`, `next` returns `None` once the tokens before it are emitted.
+     * Nothing is flushed there, the tokenizer continues from where it stopped when the end is
+     * moved further.
+     */
+    pub fn set_end(&mut self, end: usize) {
+        self.end = end.min(self.buffer.len());
+    }
+
+    /**
+     * The boundary the tokenizer is at, if it has just read the `<` of a tag in text. A tokenizer
+     * stopped by `set_end` right after the `<` is at one.
+     */
+    pub fn boundary(&self) -> Option<TagBoundary> {
+        if self.state == State::BeforeTagName && self.index == self.section_start + 1 {
+            Some(TagBoundary {
+                index: self.section_start,
+                follows_text: !self.seen_non_whitespace_text,
+            })
+        } else {
+            None
+        }
+    }
+
+    /**
+     * Continues tokenizing from `boundary`, as if the input before it was just tokenized. It has to
+     * be called on a new or reset tokenizer.
+     */
+    pub fn resume(&mut self, boundary: TagBoundary) {
+        self.state = State::BeforeTagName;
+        self.section_start = boundary.index;
+        self.index = boundary.index + 1;
+        self.seen_non_whitespace_text = !boundary.follows_text;
+    }
+
+    fn default_content_model(&self, name: &[u8]) -> ContentModel {
+        if self.xml_mode {
+            ContentModel::Data
+        } else if name.eq_ignore_ascii_case(&Sequences::SCRIPT) {
+            ContentModel::ScriptData
+        } else if name.eq_ignore_ascii_case(&Sequences::STYLE) {
+            ContentModel::RawText
+        } else if name.eq_ignore_ascii_case(&Sequences::TITLE)
+            || name.eq_ignore_ascii_case(&Sequences::TEXTAREA)
+        {
+            ContentModel::RcData
+        } else {
+            ContentModel::Data
+        }
+    }
+
+    /** The end tag sequence of the current special tag, eg. `</script` */
+    fn special_end_sequence_at(&self, index: usize) -> u8 {
+        match index {
+            0 => CharCodes::LT,
+            1 => CharCodes::SLASH,
+            _ => self.special_tag_name[index - 2],
+        }
+    }
+
+    fn special_end_sequence_len(&self) -> usize {
+        self.special_tag_name.len() + 2
     }
 
     fn fast_forward_to(&mut self, c: u8) -> bool {
-        for n in self.index..self.buffer.len() as i32 {
-            if self.buffer[n as usize] == c {
-                self.index = n;
+        let start = self.index;
 
-                return true;
-            }
+        if let Some(offset) = memchr(c, &self.buffer[start..]) {
+            self.index = start + offset;
+
+            return true;
         }
 
-        self.index = (self.buffer.len() - 1) as i32;
+        self.index = self.buffer.len() - 1;
 
         false
     }
 
+    /**
+     * Skips the bytes after the current one up to the next `a` or `b`, so the main loop continues
+     * with that byte. Returns the skipped bytes, including the current one.
+     */
+    fn skip_to_either(&mut self, a: u8, b: u8) -> &[u8] {
+        let start = self.index;
+        let buffer = self.buffer;
+        let end = match memchr2(a, b, &buffer[start + 1..]) {
+            Some(offset) => start + 1 + offset,
+            None => buffer.len(),
+        };
+
+        self.index = end - 1;
+
+        &buffer[start..end]
+    }
+
     fn state_text(&mut self, c: u8) -> Option<TokenizerToken> {
         if c == CharCodes::LT || (!self.decode_entities && self.fast_forward_to(CharCodes::LT)) {
-            let token = if self.seen_non_whitespace_text && self.index > self.section_start as i32 {
+            let token = if self.seen_non_whitespace_text && self.index > self.section_start {
                 if self.ignore_whitespace_between_tags {
                     self.seen_non_whitespace_text = false;
                 }
 
                 Some(TokenizerToken {
                     start: self.section_start,
-                    end: self.index as usize,
+                    end: self.index,
                     location: TokenizerTokenLocation::Text,
                     code: 0,
                     quote: QuoteType::NoValue,
@@ -221,15 +407,25 @@ impl Tokenizer<'_> {
             };
 
             self.state = State::BeforeTagName;
-            self.section_start = self.index as usize;
+            self.section_start = self.index;
 
             return token;
         }
-        if self.ignore_whitespace_between_tags && !is_whitespace(c) {
-            self.seen_non_whitespace_text = true;
-        }
         if self.decode_entities && c == CharCodes::AMP {
+            if self.ignore_whitespace_between_tags {
+                self.seen_non_whitespace_text = true;
+            }
             self.start_entity();
+
+            return None;
+        }
+
+        // Skip the rest of the text in bulk
+        let check_whitespace = self.ignore_whitespace_between_tags && !self.seen_non_whitespace_text;
+        let skipped = self.skip_to_either(CharCodes::LT, CharCodes::AMP);
+
+        if check_whitespace && !skipped.iter().all(|&c| is_whitespace(c)) {
+            self.seen_non_whitespace_text = true;
         }
 
         None
@@ -248,19 +444,12 @@ impl Tokenizer<'_> {
             self.sequence_index += 1;
 
             if self.sequence_index == self.current_sequence.len() {
-                let end_index = if self.index - 2 > self.buffer.len() as i32 {
-                    self.section_start
-                } else {
-                    self.index as usize - 2
-                };
+                // The end sequence is not part of the content, short comments (eg. `<!-->`) are empty
+                let end_index = (self.index + 1 - self.current_sequence.len()).max(self.section_start);
 
                 let token = TokenizerToken {
                     start: self.section_start,
-                    end: if self.section_start > end_index {
-                        self.section_start
-                    } else {
-                        end_index
-                    },
+                    end: end_index,
                     location: if self.current_sequence == Sequences::CDATA_END {
                         TokenizerTokenLocation::CData
                     } else {
@@ -271,7 +460,7 @@ impl Tokenizer<'_> {
                 };
 
                 self.sequence_index = 0;
-                self.section_start = (self.index + 1) as usize;
+                self.section_start = self.index + 1;
                 self.state = State::Text;
 
                 return Some(token);
@@ -297,35 +486,19 @@ impl Tokenizer<'_> {
         }
     }
 
-    fn start_special(&mut self, sequence: &'static [u8], offset: i32) {
-        self.is_special = true;
-        self.current_sequence = sequence;
-        self.sequence_index = offset as usize;
-        self.state = State::SpecialStartSequence;
-    }
-
     fn state_before_tag_name(&mut self, c: u8) -> Option<TokenizerToken> {
         match c {
             CharCodes::EXCLAMATION_MARK => {
                 self.state = State::BeforeDeclaration;
-                self.section_start = (self.index + 1) as usize;
+                self.section_start = self.index + 1;
             }
             CharCodes::QUESTION_MARK => {
                 self.state = State::InProcessingInstruction;
-                self.section_start = (self.index + 1) as usize;
+                self.section_start = self.index + 1;
             }
             _ if self.is_tag_start_char(c) => {
-                self.section_start = self.index as usize;
-                if !self.xml_mode && c.eq_ignore_ascii_case(&Sequences::TITLE_END[2]) {
-                    self.state = State::BeforeSpecialT;
-                } else {
-                    self.state =
-                        if !self.xml_mode && c.eq_ignore_ascii_case(&Sequences::SCRIPT_END[2]) {
-                            State::BeforeSpecialS
-                        } else {
-                            State::InTagName
-                        };
-                }
+                self.section_start = self.index;
+                self.state = State::InTagName;
             }
             CharCodes::SLASH => {
                 self.state = State::BeforeClosingTagName;
@@ -342,9 +515,13 @@ impl Tokenizer<'_> {
     }
     fn state_in_tag_name(&mut self, c: u8) -> Option<TokenizerToken> {
         if is_end_of_tag_section(c) {
+            let buffer = self.buffer;
+            self.special_tag_name = &buffer[self.section_start..self.index];
+            self.content_model = self.default_content_model(self.special_tag_name);
+
             let token = Some(TokenizerToken {
                 start: self.section_start,
-                end: self.index as usize,
+                end: self.index,
                 location: TokenizerTokenLocation::OpenTagName,
                 code: 0,
                 quote: QuoteType::NoValue,
@@ -352,7 +529,7 @@ impl Tokenizer<'_> {
 
             self.section_start = 0;
             self.state = State::BeforeAttributeName;
-            self.index -= 1; // continue
+            self.reconsume = true;
 
             return token;
         }
@@ -371,7 +548,7 @@ impl Tokenizer<'_> {
                 State::InSpecialComment
             };
 
-            self.section_start = self.index as usize;
+            self.section_start = self.index;
         }
 
         None
@@ -380,7 +557,7 @@ impl Tokenizer<'_> {
         if c == CharCodes::GT || is_whitespace(c) {
             let token = Some(TokenizerToken {
                 start: self.section_start,
-                end: self.index as usize,
+                end: self.index,
                 location: TokenizerTokenLocation::CloseTag,
                 code: 0,
                 quote: QuoteType::NoValue,
@@ -398,7 +575,7 @@ impl Tokenizer<'_> {
     fn state_after_closing_tag_name(&mut self, c: u8) -> Option<TokenizerToken> {
         if c == CharCodes::GT || self.fast_forward_to(CharCodes::GT) {
             self.state = State::Text;
-            self.section_start = (self.index + 1) as usize;
+            self.section_start = self.index + 1;
         }
 
         None
@@ -406,27 +583,29 @@ impl Tokenizer<'_> {
     fn state_before_attribute_name(&mut self, c: u8) -> Option<TokenizerToken> {
         if c == CharCodes::GT {
             let token = Some(TokenizerToken {
-                start: self.index as usize,
-                end: self.index as usize,
+                start: self.index,
+                end: self.index,
                 location: TokenizerTokenLocation::OpenTagEnd,
                 code: 0,
                 quote: QuoteType::NoValue,
             });
 
-            if self.is_special {
-                self.state = State::InSpecialTag;
-                self.sequence_index = 0;
-            } else {
-                self.state = State::Text;
-            }
-            self.section_start = (self.index + 1) as usize;
+            self.state = match self.content_model {
+                ContentModel::Data => State::Text,
+                ContentModel::RcData | ContentModel::RawText | ContentModel::ScriptData => {
+                    self.sequence_index = 0;
+                    State::InSpecialTag
+                }
+                ContentModel::PlainText => State::InPlainText,
+            };
+            self.section_start = self.index + 1;
 
             return token;
         } else if c == CharCodes::SLASH {
             self.state = State::InSelfClosingTag;
         } else if !is_whitespace(c) {
             self.state = State::InAttributeName;
-            self.section_start = self.index as usize;
+            self.section_start = self.index;
         }
 
         None
@@ -434,21 +613,21 @@ impl Tokenizer<'_> {
     fn state_in_self_closing_tag(&mut self, c: u8) -> Option<TokenizerToken> {
         if c == CharCodes::GT {
             let token = Some(TokenizerToken {
-                start: self.index as usize,
-                end: self.index as usize,
+                start: self.index,
+                end: self.index,
                 location: TokenizerTokenLocation::SelfClosingTag,
                 code: 0,
                 quote: QuoteType::NoValue,
             });
 
             self.state = State::Text;
-            self.section_start = (self.index + 1) as usize;
-            self.is_special = false;
+            self.section_start = self.index + 1;
+            self.content_model = ContentModel::Data;
 
             return token;
         } else if !is_whitespace(c) {
             self.state = State::BeforeAttributeName;
-            self.index -= 1; // continue
+            self.reconsume = true;
         }
 
         None
@@ -458,14 +637,14 @@ impl Tokenizer<'_> {
         if c == CharCodes::EQ || is_end_of_tag_section(c) {
             token = Some(TokenizerToken {
                 start: self.section_start,
-                end: self.index as usize,
+                end: self.index,
                 location: TokenizerTokenLocation::AttrName,
                 code: 0,
                 quote: QuoteType::NoValue,
             });
-            self.section_start = self.index as usize;
+            self.section_start = self.index;
             self.state = State::AfterAttributeName;
-            self.index -= 1; // continue
+            self.reconsume = true;
         } else {
             token = None;
         }
@@ -488,7 +667,7 @@ impl Tokenizer<'_> {
             });
             self.section_start = 0;
             self.state = State::BeforeAttributeName;
-            self.index -= 1; // continue
+            self.reconsume = true;
         } else if !is_whitespace(c) {
             token = Some(TokenizerToken {
                 start: self.section_start,
@@ -498,7 +677,7 @@ impl Tokenizer<'_> {
                 quote: QuoteType::NoValue,
             });
             self.state = State::InAttributeName;
-            self.section_start = self.index as usize;
+            self.section_start = self.index;
         } else {
             token = None;
         };
@@ -509,14 +688,14 @@ impl Tokenizer<'_> {
         if c == CharCodes::DOUBLE_QUOTE {
             self.state = State::InAttributeValueDq;
             self.prev_quote_type = QuoteType::Double;
-            self.section_start = (self.index + 1) as usize;
+            self.section_start = self.index + 1;
         } else if c == CharCodes::SINGLE_QUOTE {
             self.state = State::InAttributeValueSq;
             self.prev_quote_type = QuoteType::Single;
-            self.section_start = (self.index + 1) as usize;
+            self.section_start = self.index + 1;
         } else if !is_whitespace(c) {
             self.prev_quote_type = QuoteType::Unquoted;
-            self.section_start = self.index as usize;
+            self.section_start = self.index;
             self.state = State::InAttributeValueNq;
 
             return self.state_in_attribute_value_no_quotes(c);
@@ -534,17 +713,19 @@ impl Tokenizer<'_> {
 
             let token = Some(TokenizerToken {
                 start: self.section_start,
-                end: self.index as usize,
+                end: self.index,
                 location: TokenizerTokenLocation::AttrData,
                 code: 0,
                 quote: self.prev_quote_type,
             });
 
-            self.index -= 1; // Continue
+            self.reconsume = true;
 
             return token;
         } else if self.decode_entities && c == CharCodes::AMP {
             self.start_entity();
+        } else {
+            self.skip_to_either(quote, CharCodes::AMP);
         }
 
         None
@@ -557,10 +738,10 @@ impl Tokenizer<'_> {
     }
     fn state_in_attribute_value_no_quotes(&mut self, c: u8) -> Option<TokenizerToken> {
         if is_whitespace(c) || c == CharCodes::GT {
-            let token = if self.section_start < self.index as usize {
+            let token = if self.section_start < self.index {
                 Some(TokenizerToken {
                     start: self.section_start,
-                    end: self.index as usize,
+                    end: self.index,
                     location: TokenizerTokenLocation::AttrData,
                     code: 0,
                     quote: QuoteType::Unquoted,
@@ -569,7 +750,7 @@ impl Tokenizer<'_> {
                 None
             };
 
-            self.index -= 1; // continue
+            self.reconsume = true;
             self.state = State::AfterAttributeData;
             self.section_start = 0;
 
@@ -582,8 +763,13 @@ impl Tokenizer<'_> {
     }
     fn state_before_declaration(&mut self, c: u8) -> Option<TokenizerToken> {
         if c == CharCodes::OPENING_SQUARE_BRACKET {
-            self.state = State::CDATASequence;
-            self.sequence_index = 0;
+            if self.cdata_allowed {
+                self.state = State::CDATASequence;
+                self.sequence_index = 0;
+            } else {
+                self.state = State::InSpecialComment;
+                self.section_start = self.index;
+            }
         } else {
             self.state = if c == CharCodes::DASH {
                 State::BeforeComment
@@ -603,14 +789,14 @@ impl Tokenizer<'_> {
         if c == CharCodes::GT || self.fast_forward_to(CharCodes::GT) {
             let token = Some(TokenizerToken {
                 start: self.section_start,
-                end: self.index as usize,
+                end: self.index,
                 location,
                 code: 0,
                 quote: QuoteType::NoValue,
             });
 
             self.state = State::Text;
-            self.section_start = (self.index + 1) as usize;
+            self.section_start = self.index + 1;
 
             return token;
         }
@@ -624,7 +810,7 @@ impl Tokenizer<'_> {
             self.current_sequence = &Sequences::COMMENT_END;
             // Allow short comments (eg. <!-->)
             self.sequence_index = 2;
-            self.section_start = (self.index + 1) as usize;
+            self.section_start = self.index + 1;
         } else {
             self.state = State::InDeclaration;
         }
@@ -632,163 +818,164 @@ impl Tokenizer<'_> {
         None
     }
 
-    fn state_before_special_s(&mut self, c: u8) -> Option<TokenizerToken> {
-        if c.eq_ignore_ascii_case(&Sequences::SCRIPT_END[3]) {
-            self.start_special(&Sequences::SCRIPT_END, 4);
-
-            return None;
-        }
-        if c.eq_ignore_ascii_case(&Sequences::STYLE_END[3]) {
-            self.start_special(&Sequences::STYLE_END, 4);
+    fn start_entity(&mut self) {
+        self.base_state = self.state;
+        self.state = State::BeforeEntity;
+        self.entity_start = self.index;
+    }
 
-            return None;
-        }
+    fn is_in_attribute_entity(&self) -> bool {
+        self.base_state != State::Text && self.base_state != State::InSpecialTag
+    }
 
-        self.state = State::InTagName;
+    /** Leaves a character reference that is not one, the `&` and what followed it are text. */
+    fn leave_entity(&mut self) -> Option<TokenizerToken> {
+        self.state = self.base_state;
+        self.code = 0;
+        self.reconsume = true;
 
-        self.state_in_tag_name(c)
+        None
     }
 
-    fn state_before_special_t(&mut self, c: u8) -> Option<TokenizerToken> {
-        if c.eq_ignore_ascii_case(&Sequences::TITLE_END[3]) {
-            self.start_special(&Sequences::TITLE_END, 4);
+    fn state_before_entity(&mut self, c: u8) -> Option<TokenizerToken> {
+        if c == CharCodes::HASH {
+            self.state = State::BeforeNumericEntity;
+            self.code = 0;
 
-            return None;
+            None
+        } else if c.is_ascii_alphanumeric() {
+            self.state = State::InNamedEntity;
+            self.entity_matcher = EntityMatcher::new();
+            self.reconsume = true;
+
+            None
+        } else {
+            self.leave_entity()
         }
-        if c.eq_ignore_ascii_case(&Sequences::TEXTAREA_END[3]) {
-            self.start_special(&Sequences::TEXTAREA_END, 4);
+    }
 
+    fn state_in_named_entity(&mut self, c: u8) -> Option<TokenizerToken> {
+        if self.entity_matcher.push(c) {
             return None;
         }
 
-        self.state = State::InTagName;
-
-        self.state_in_tag_name(c)
+        self.end_named_entity()
     }
 
+    /** Ends the name at the current byte, the reference is the longest name before it. */
+    fn end_named_entity(&mut self) -> Option<TokenizerToken> {
+        let name_start = self.entity_start + 1;
 
-    fn start_entity(&mut self) {
-        self.base_state = self.state;
-        self.state = State::InEntity;
-        self.entity_start = self.index as usize;
-        self.index -= 1; // continue
-    }
-
-    fn find_end_of_html_entity(&mut self) -> i32 {
-        let start_pos = self.index as usize;
-        let loop_until = self.buffer.len();
-        let mut count: usize = start_pos + 1; // the first is always &
-
-        while count < loop_until {
-            let char = self.buffer[count];
-
-            if (char > 47 && char < 58) || // number
-                (char > 96 && char < 123) || // lower case letter
-                (char > 64 && char < 91) || // upper case letter
-                char == CharCodes::UNDERSCORE ||
-                char == CharCodes::HASH
-            {
-                count += 1;
-                continue;
-            }
-            if char == CharCodes::SEMI {
-                count += 1;
-                break;
-            }
+        let Some(entity) = self.entity_matcher.longest_match() else {
+            return self.leave_entity();
+        };
 
-            break;
+        // In an attribute a legacy name followed by `=` or an alphanumeric is not a reference
+        let end = name_start + entity.length;
+        if self.is_in_attribute_entity()
+            && !entity.is_closed
+            && self
+                .buffer
+                .get(end)
+                .is_some_and(|c| *c == CharCodes::EQ || c.is_ascii_alphanumeric())
+        {
+            return self.leave_entity();
         }
 
-        if count <= loop_until {
-            return count as i32;
+        self.end_entity(end, entity.code_points)
+    }
+
+    fn state_before_numeric_entity(&mut self, c: u8) -> Option<TokenizerToken> {
+        if c == b'x' || c == b'X' {
+            self.state = State::InHexEntity;
+        } else {
+            self.state = State::InNumericEntity;
+            self.reconsume = true;
         }
 
-        -1
+        None
     }
 
-    fn state_in_entity(&mut self) -> Option<TokenizerToken> {
-        let index = self.find_end_of_html_entity();
+    fn state_in_numeric_entity(&mut self, c: u8, radix: u32) -> Option<TokenizerToken> {
+        if let Some(digit) = char::from(c).to_digit(radix) {
+            self.code = (self.code * radix + digit).min(MAX_CODE_POINT + 1);
 
-        if index >= 0 {
-            let range: Range<usize> = Range {
-                start: self.index as usize,
-                end: index as usize,
-            };
-            let is_attr = self.base_state != State::Text && self.base_state != State::InSpecialTag;
-            let code = htmlize::unescape_bytes_in(
-                &self.buffer[range],
-                if is_attr {
-                    Context::Attribute
-                } else {
-                    Context::General
-                },
-            );
+            return None;
+        }
 
-            if code.len() > 0 {
-                if code.len() > 1 && code[0] == CharCodes::AMP {
-                    self.state = self.base_state;
-                    return None;
-                }
+        self.end_numeric_entity(c == CharCodes::SEMI)
+    }
 
-                let text = unsafe { str::from_utf8_unchecked(&code) };
+    /** Ends the number at the current byte, which is part of the reference if it is a `;`. */
+    fn end_numeric_entity(&mut self, is_semicolon: bool) -> Option<TokenizerToken> {
+        let digits_start = self.entity_start
+            + if self.state == State::InHexEntity {
+                3
+            } else {
+                2
+            };
 
-                if let Some(character) = text.chars().next() {
-                    self.code = character as u32
-                } else {
-                    self.state = self.base_state;
-                    return None;
-                }
+        if self.index == digits_start {
+            return self.leave_entity();
+        }
 
-                let token: Option<TokenizerToken>;
+        let end = if is_semicolon {
+            self.index + 1
+        } else {
+            self.index
+        };
 
-                if self.section_start < self.entity_start {
-                    token = Some(TokenizerToken {
-                        start: self.section_start,
-                        end: self.entity_start,
-                        location: if is_attr {
-                            TokenizerTokenLocation::AttrData
-                        } else {
-                            TokenizerTokenLocation::Text
-                        },
-                        code: 0,
-                        quote: if is_attr {
-                            self.prev_quote_type
-                        } else {
-                            QuoteType::NoValue
-                        },
-                    });
-                    self.section_start = self.index as usize;
-                } else {
-                    token = None;
-                }
+        self.end_entity(end, (numeric_code_point(self.code), 0))
+    }
 
-                self.index = index - 1;
+    /**
+     * Ends the reference at `entity_start`, the tokenizing continues at `end`. The text before
+     * the reference is emitted first, then the code points in the `AfterReadEntity` states.
+     */
+    fn end_entity(&mut self, end: usize, code_points: (u32, u32)) -> Option<TokenizerToken> {
+        let is_attr = self.is_in_attribute_entity();
 
-                self.state = if is_attr {
-                    State::AfterReadEntityAttr
+        let token = if self.section_start < self.entity_start {
+            Some(TokenizerToken {
+                start: self.section_start,
+                end: self.entity_start,
+                location: if is_attr {
+                    TokenizerTokenLocation::AttrData
                 } else {
-                    State::AfterReadEntityText
-                };
+                    TokenizerTokenLocation::Text
+                },
+                code: 0,
+                quote: if is_attr {
+                    self.prev_quote_type
+                } else {
+                    QuoteType::NoValue
+                },
+            })
+        } else {
+            None
+        };
 
-                return token;
-            } else {
-                self.index = (self.buffer.len() - 1) as i32;
-            }
+        (self.code, self.second_code) = code_points;
+        self.section_start = self.entity_start;
+        self.index = end;
+        self.reconsume = true;
+        self.state = if is_attr {
+            State::AfterReadEntityAttr
         } else {
-            self.index = (self.buffer.len() - 1) as i32;
-        }
+            State::AfterReadEntityText
+        };
 
-        None
+        token
     }
 
     fn tokenizer_next(&mut self) -> Option<TokenizerToken> {
-        while self.index < self.buffer.len() as i32 {
-            let c = self.buffer[self.index as usize];
+        while self.index < self.end {
+            let c = self.buffer[self.index];
 
             let token_or_empty: Option<TokenizerToken> = match self.state {
                 State::Text => self.state_text(c),
-                State::SpecialStartSequence => self.state_special_start_sequence(c),
                 State::InSpecialTag => self.state_in_special_tag(c),
+                State::InPlainText => self.state_in_plain_text(),
                 State::CDATASequence => self.state_cdata_sequence(c),
                 State::InAttributeValueDq => self.state_in_attribute_value_double_quotes(c),
                 State::InAttributeName => self.state_in_attribute_name(c),
@@ -811,8 +998,6 @@ impl Tokenizer<'_> {
                 State::BeforeAttributeValue => self.state_before_attribute_value(c),
                 State::BeforeClosingTagName => self.state_before_closing_tag_name(c),
                 State::AfterClosingTagName => self.state_after_closing_tag_name(c),
-                State::BeforeSpecialS => self.state_before_special_s(c),
-                State::BeforeSpecialT => self.state_before_special_t(c),
                 State::InAttributeValueNq => self.state_in_attribute_value_no_quotes(c),
                 State::AfterAttributeData => self.state_after_attribute_data(),
                 State::InSelfClosingTag => self.state_in_self_closing_tag(c),
@@ -824,7 +1009,11 @@ impl Tokenizer<'_> {
                 State::InProcessingInstruction => {
                     self.fast_get_until_gt(c, TokenizerTokenLocation::ProcessingInstruction)
                 }
-                State::InEntity => self.state_in_entity(),
+                State::BeforeEntity => self.state_before_entity(c),
+                State::InNamedEntity => self.state_in_named_entity(c),
+                State::BeforeNumericEntity => self.state_before_numeric_entity(c),
+                State::InNumericEntity => self.state_in_numeric_entity(c, 10),
+                State::InHexEntity => self.state_in_numeric_entity(c, 16),
                 State::AfterReadEntityText => {
                     self.state_after_entity(TokenizerTokenLocation::TextEntity)
                 }
@@ -834,19 +1023,43 @@ impl Tokenizer<'_> {
                 State::End => self.state_end(),
             };
 
-            self.index += 1;
+            if !take(&mut self.reconsume) {
+                self.index += 1;
+            }
 
             if token_or_empty.is_some() {
                 return token_or_empty;
             }
         }
 
-        if self.state == State::InEntity {
-            self.state = self.base_state;
+        if self.end < self.buffer.len() {
+            return None;
+        }
+
+        // The input ended in a character reference. A name can end before the input does, the
+        // rest of the input is tokenized again then.
+        let entity_token = match self.state {
+            State::BeforeEntity | State::BeforeNumericEntity => self.leave_entity(),
+            State::InNamedEntity => self.end_named_entity(),
+            State::InNumericEntity | State::InHexEntity => self.end_numeric_entity(false),
+            _ => None,
+        };
+        self.reconsume = false;
+        if entity_token.is_some() {
+            return entity_token;
+        }
+        if self.index < self.buffer.len() {
+            return self.tokenizer_next();
         }
 
         let option_trailing_token: Option<TokenizerToken> = self.handle_trailing_data();
-        self.state = State::End;
+        // The second code point of a reference is emitted on the next call
+        if !matches!(
+            self.state,
+            State::AfterReadEntityText | State::AfterReadEntityAttr
+        ) {
+            self.state = State::End;
+        }
         if option_trailing_token.is_some() {
             return option_trailing_token;
         }
@@ -873,8 +1086,15 @@ impl Tokenizer<'_> {
     }
 
     fn handle_trailing_data(&mut self) -> Option<TokenizerToken> {
-        if self.state == State::End {
-            return None;
+        match self.state {
+            State::End => return None,
+            State::AfterReadEntityText => {
+                return self.state_after_entity(TokenizerTokenLocation::TextEntity)
+            }
+            State::AfterReadEntityAttr => {
+                return self.state_after_entity(TokenizerTokenLocation::AttrEntity)
+            }
+            _ => {}
         }
 
         let end_index = self.buffer.len();
@@ -905,12 +1125,6 @@ impl Tokenizer<'_> {
                 | State::InAttributeValueDq
                 | State::InAttributeValueNq
                 | State::InClosingTagName => None,
-                State::AfterReadEntityText => {
-                    self.state_after_entity(TokenizerTokenLocation::TextEntity)
-                }
-                State::AfterReadEntityAttr => {
-                    self.state_after_entity(TokenizerTokenLocation::AttrData)
-                }
                 _ => Some(TokenizerToken {
                     start: self.section_start,
                     end: end_index,
@@ -922,28 +1136,11 @@ impl Tokenizer<'_> {
         }
     }
 
-    fn state_special_start_sequence(&mut self, c: u8) -> Option<TokenizerToken> {
-        let is_end = self.sequence_index == self.current_sequence.len();
-        let is_match = if is_end {
-            // If we are at the end of the sequence, make sure the tag name has ended
-            is_end_of_tag_section(c)
-        } else {
-            // Otherwise, do a case-insensitive comparison
-            c.eq_ignore_ascii_case(&self.current_sequence[self.sequence_index])
-        };
-
-        if !is_match {
-            self.is_special = false;
-        } else if !is_end {
-            self.sequence_index += 1;
-
-            return None;
-        }
-
-        self.sequence_index = 0;
-        self.state = State::InTagName;
+    fn state_in_plain_text(&mut self) -> Option<TokenizerToken> {
+        // Everything until the end is text, it is emitted as trailing data.
+        self.index = self.buffer.len() - 1;
 
-        self.state_in_tag_name(c)
+        None
     }
     fn state_cdata_sequence(&mut self, c: u8) -> Option<TokenizerToken> {
         if c == Sequences::CDATA[self.sequence_index] {
@@ -952,7 +1149,7 @@ impl Tokenizer<'_> {
                 self.state = State::InCommentLike;
                 self.current_sequence = &Sequences::CDATA_END;
                 self.sequence_index = 0;
-                self.section_start = (self.index + 1) as usize;
+                self.section_start = self.index + 1;
             }
             None
         } else {
@@ -963,13 +1160,15 @@ impl Tokenizer<'_> {
         }
     }
     fn state_in_special_tag(&mut self, c: u8) -> Option<TokenizerToken> {
-        if self.sequence_index == self.current_sequence.len() {
+        let sequence_len = self.special_end_sequence_len();
+
+        if self.sequence_index == sequence_len {
             if c == CharCodes::GT || is_whitespace(c) {
-                let end_of_text = self.index - self.current_sequence.len() as i32;
-                let token: Option<TokenizerToken> = if self.section_start < end_of_text as usize {
+                let end_of_text = self.index - sequence_len;
+                let token: Option<TokenizerToken> = if self.section_start < end_of_text {
                     Some(TokenizerToken {
                         start: self.section_start,
-                        end: end_of_text as usize,
+                        end: end_of_text,
                         location: TokenizerTokenLocation::Text,
                         code: 0,
                         quote: QuoteType::NoValue,
@@ -978,10 +1177,9 @@ impl Tokenizer<'_> {
                     None
                 };
 
-                self.is_special = false;
-                self.section_start = (end_of_text + 2) as usize; // Skip over the `</`
+                self.section_start = end_of_text + 2; // Skip over the `</`
                 self.state = State::InClosingTagName;
-                self.index -= 1; // continue
+                self.reconsume = true;
 
                 return token;
             }
@@ -989,16 +1187,18 @@ impl Tokenizer<'_> {
             self.sequence_index = 0;
         }
 
-        if c.eq_ignore_ascii_case(&self.current_sequence[self.sequence_index]) {
+        if c.eq_ignore_ascii_case(&self.special_end_sequence_at(self.sequence_index)) {
             self.sequence_index += 1;
         } else if self.sequence_index == 0 {
-            if self.current_sequence == Sequences::TITLE_END {
-                // We have to parse entities in <title> tags.
+            if self.content_model == ContentModel::RcData {
+                // We have to parse entities in <title> and <textarea> tags.
                 if self.decode_entities && c == CharCodes::AMP {
                     self.start_entity();
+                } else {
+                    self.skip_to_either(CharCodes::LT, CharCodes::AMP);
                 }
             } else if self.fast_forward_to(CharCodes::LT) {
-                // Outside of <title> tags, we can fast-forward.
+                // Outside of RCDATA, we can fast-forward.
                 self.sequence_index = 1;
                 // self.index -= 1; // continue
             }
@@ -1017,14 +1217,14 @@ impl Tokenizer<'_> {
     #[inline]
     fn state_after_attribute_data(&mut self) -> Option<TokenizerToken> {
         let token = Some(TokenizerToken {
-            start: self.index as usize,
-            end: self.index as usize,
+            start: self.index,
+            end: self.index,
             location: TokenizerTokenLocation::AttrEnd,
             code: 0,
             quote: QuoteType::Unquoted,
         });
         self.state = State::BeforeAttributeName;
-        self.index -= 1; // continue
+        self.reconsume = true;
 
         token
     }
@@ -1035,8 +1235,8 @@ impl Tokenizer<'_> {
         self.state = State::BeforeAttributeName;
 
         Some(TokenizerToken {
-            start: (self.index + 1) as usize,
-            end: (self.index + 1) as usize,
+            start: self.index + 1,
+            end: self.index + 1,
             location: TokenizerTokenLocation::AttrEnd,
             code: 0,
             quote: quote_type,
@@ -1045,11 +1245,9 @@ impl Tokenizer<'_> {
 
     #[inline]
     fn state_after_entity(&mut self, location: TokenizerTokenLocation) -> Option<TokenizerToken> {
-        self.state = self.base_state;
-
         let token = Some(TokenizerToken {
             start: self.section_start,
-            end: self.index as usize,
+            end: self.index,
             location,
             code: self.code,
             quote: if location == TokenizerTokenLocation::AttrEntity {
@@ -1059,9 +1257,15 @@ impl Tokenizer<'_> {
             },
         });
 
-        self.section_start = self.index as usize;
-        self.index -= 1;
-        self.code = 0;
+        self.section_start = self.index;
+        self.reconsume = true;
+
+        // A few references are two code points, the second one is emitted next with an empty
+        // range
+        self.code = take(&mut self.second_code);
+        if self.code == 0 {
+            self.state = self.base_state;
+        }
 
         token
     }
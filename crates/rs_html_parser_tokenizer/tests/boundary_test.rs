@@ -0,0 +1,89 @@
+#[cfg(test)]
+mod tests {
+    use rs_html_parser_tokenizer::{TagBoundary, Tokenizer, TokenizerOptions};
+
+    const DATA: &str = "<ul><li>one</li> <li>two &amp; three</li></ul>\n<p>four</p><br/>";
+
+    #[test]
+    fn stops_at_the_end_and_continues() {
+        let options = TokenizerOptions::default();
+        let expected = format!(
+            "{:?}",
+            Tokenizer::new(DATA.as_bytes(), &options).collect::<Vec<_>>()
+        );
+
+        for end in 0..=DATA.len() {
+            let mut tokenizer = Tokenizer::new(DATA.as_bytes(), &options);
+
+            tokenizer.set_end(end);
+            let mut tokens: Vec<_> = tokenizer.by_ref().collect();
+            tokenizer.set_end(DATA.len());
+            tokens.extend(tokenizer);
+
+            assert_eq!(format!("{:?}", tokens), expected, "{end}");
+        }
+    }
+
+    #[test]
+    fn resumes_from_boundaries() {
+        for ignore_whitespace in [true, false] {
+            let options = TokenizerOptions {
+                ignore_whitespace_between_tags: Some(ignore_whitespace),
+                ..Default::default()
+            };
+            let expected: Vec<_> = Tokenizer::new(DATA.as_bytes(), &options).collect();
+
+            for (index, _) in DATA.match_indices('<') {
+                let mut tokenizer = Tokenizer::new(DATA.as_bytes(), &options);
+
+                tokenizer.set_end(index + 1);
+                let before = tokenizer.by_ref().count();
+                let boundary = tokenizer.boundary().unwrap();
+
+                let mut resumed = Tokenizer::new(DATA.as_bytes(), &options);
+
+                resumed.resume(boundary);
+
+                assert_eq!(boundary.index, index);
+                assert_eq!(
+                    format!("{:?}", resumed.collect::<Vec<_>>()),
+                    format!("{:?}", &expected[before..]),
+                    "{index}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn boundary_tells_whether_text_was_emitted() {
+        let options = TokenizerOptions::default();
+        let mut tokenizer = Tokenizer::new(DATA.as_bytes(), &options);
+
+        // No text between `<ul>` and `<li>`
+        tokenizer.set_end(5);
+        tokenizer.by_ref().for_each(drop);
+        assert_eq!(
+            tokenizer.boundary(),
+            Some(TagBoundary {
+                index: 4,
+                follows_text: false
+            })
+        );
+
+        // `one` is emitted at the `<` of `</li>`
+        tokenizer.set_end(12);
+        tokenizer.by_ref().for_each(drop);
+        assert_eq!(
+            tokenizer.boundary(),
+            Some(TagBoundary {
+                index: 11,
+                follows_text: true
+            })
+        );
+
+        // Inside of a tag
+        tokenizer.set_end(14);
+        tokenizer.by_ref().for_each(drop);
+        assert_eq!(tokenizer.boundary(), None);
+    }
+}
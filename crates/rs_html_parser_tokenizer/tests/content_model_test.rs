@@ -0,0 +1,53 @@
+#[cfg(test)]
+mod tests {
+    use insta::assert_debug_snapshot;
+    use rs_html_parser_tokenizer::{ContentModel, Tokenizer, TokenizerOptions};
+    use rs_html_parser_tokenizer_tokens::{TokenizerToken, TokenizerTokenLocation};
+
+    fn tokenize(data: &str, content_model: ContentModel) -> Vec<TokenizerToken> {
+        let mut log: Vec<TokenizerToken> = Vec::new();
+
+        let options = TokenizerOptions {
+            xml_mode: Some(false),
+            decode_entities: Some(true),
+            ignore_whitespace_between_tags: Some(true),
+        };
+
+        let mut tokenizer = Tokenizer::new(data.as_bytes(), &options);
+
+        while let Some(token) = tokenizer.next() {
+            if token.location == TokenizerTokenLocation::OpenTagName {
+                tokenizer.set_content_model(content_model);
+            }
+
+            log.push(token);
+        }
+
+        log
+    }
+
+    #[test]
+    fn style_as_data() {
+        assert_debug_snapshot!(tokenize("<style><b></b></style>", ContentModel::Data))
+    }
+
+    #[test]
+    fn div_as_raw_text() {
+        assert_debug_snapshot!(tokenize("<div><b>&amp;</b></div>", ContentModel::RawText))
+    }
+
+    #[test]
+    fn div_as_rcdata() {
+        assert_debug_snapshot!(tokenize("<div><b>&amp;</b></DIV >", ContentModel::RcData))
+    }
+
+    #[test]
+    fn plaintext() {
+        assert_debug_snapshot!(tokenize("<plaintext></plaintext>&amp;", ContentModel::PlainText))
+    }
+
+    #[test]
+    fn self_closing_tag_continues_as_data() {
+        assert_debug_snapshot!(tokenize("<div/><b></b>", ContentModel::RawText))
+    }
+}
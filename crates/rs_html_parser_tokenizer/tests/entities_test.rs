@@ -0,0 +1,168 @@
+#[cfg(test)]
+mod tests {
+    use rs_html_parser_tokenizer::entities::{
+        longest_match, lookup, EntityMatcher, ENTITIES, ENTITY_MAX_LENGTH, ENTITY_MIN_LENGTH,
+    };
+    use rs_html_parser_tokenizer::{Tokenizer, TokenizerOptions};
+    use rs_html_parser_tokenizer_tokens::TokenizerTokenLocation;
+
+    fn code_points(characters: &str) -> (u32, u32) {
+        let mut chars = characters.chars().map(u32::from);
+
+        (chars.next().unwrap(), chars.next().unwrap_or(0))
+    }
+
+    // The text and the attribute values, with the references decoded
+    fn decode(html: &str) -> String {
+        let options = TokenizerOptions::default();
+        let mut decoded = String::new();
+
+        for token in Tokenizer::new(html.as_bytes(), &options) {
+            match token.location {
+                TokenizerTokenLocation::Text | TokenizerTokenLocation::AttrData => {
+                    decoded.push_str(&html[token.start..token.end]);
+                }
+                TokenizerTokenLocation::TextEntity | TokenizerTokenLocation::AttrEntity => {
+                    decoded.push(char::from_u32(token.code).unwrap());
+                }
+                TokenizerTokenLocation::AttrEnd => decoded.push('|'),
+                _ => {}
+            }
+        }
+
+        decoded
+    }
+
+    #[test]
+    fn table_is_sorted() {
+        assert!(ENTITIES.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        assert_eq!(
+            ENTITIES.iter().map(|(name, _)| name.len()).max(),
+            Some(ENTITY_MAX_LENGTH)
+        );
+        assert_eq!(
+            ENTITIES.iter().map(|(name, _)| name.len()).min(),
+            Some(ENTITY_MIN_LENGTH)
+        );
+    }
+
+    #[test]
+    fn lookup_names() {
+        assert_eq!(lookup(b"&amp;"), Some("&"));
+        assert_eq!(lookup(b"&amp"), Some("&"));
+        assert_eq!(lookup(b"&nGg;"), Some("\u{22D9}\u{338}"));
+        assert_eq!(lookup(b"&Amp;"), None);
+        assert_eq!(lookup(b"&hellip"), None);
+    }
+
+    #[test]
+    fn trie_matches_every_name() {
+        for (name, characters) in ENTITIES.iter() {
+            let entity = longest_match(&name.as_bytes()[1..]).unwrap();
+
+            assert_eq!(entity.length, name.len() - 1, "{name}");
+            assert_eq!(entity.is_closed, name.ends_with(';'), "{name}");
+            assert_eq!(entity.code_points, code_points(characters), "{name}");
+        }
+    }
+
+    #[test]
+    fn trie_matches_longest_prefix() {
+        let not = longest_match(b"notit;").unwrap();
+        assert_eq!((not.length, not.is_closed), (3, false));
+        assert_eq!(not.code_points, (0xAC, 0));
+
+        let notin = longest_match(b"notin;x").unwrap();
+        assert_eq!((notin.length, notin.is_closed), (6, true));
+        assert_eq!(notin.code_points, (0x2209, 0));
+
+        assert_eq!(longest_match(b"amp").unwrap().length, 3);
+        assert_eq!(longest_match(b"hellip;").unwrap().length, 7);
+        assert_eq!(longest_match(b"hellip"), None);
+        assert_eq!(longest_match(b"helli"), None);
+        assert_eq!(longest_match(b"x"), None);
+        assert_eq!(longest_match(b""), None);
+    }
+
+    #[test]
+    fn matcher_resumes_across_chunks() {
+        let name = b"NotGreaterFullEqual;";
+
+        for split in 0..=name.len() {
+            let mut matcher = EntityMatcher::new();
+
+            for chunk in [&name[..split], &name[split..]] {
+                for c in chunk {
+                    assert!(matcher.push(*c));
+                }
+            }
+
+            assert_eq!(matcher.len(), name.len());
+            assert_eq!(matcher.longest_match(), longest_match(name));
+        }
+    }
+
+    #[test]
+    fn matcher_stops_after_the_longest_name() {
+        let mut matcher = EntityMatcher::new();
+
+        assert!(b"timesbar".iter().all(|c| matcher.push(*c)));
+        assert!(!matcher.push(b'x'));
+        assert_eq!(matcher.len(), 8);
+        assert_eq!(matcher.longest_match().unwrap().length, 5);
+    }
+
+    #[test]
+    fn named_references() {
+        assert_eq!(decode("a &lt; b &gt c"), "a < b > c");
+        assert_eq!(decode("I'm &notit; I tell you"), "I'm ¬it; I tell you");
+        assert_eq!(decode("I'm &notin; I tell you"), "I'm ∉ I tell you");
+        assert_eq!(decode("&ampx &amp_ &lt_"), "&x &_ <_");
+        assert_eq!(
+            decode("&nGg; &NotGreaterFullEqual;x"),
+            "\u{22D9}\u{338} ≧\u{338}x"
+        );
+        assert_eq!(decode("& &x; &Amp; &#"), "& &x; &Amp; &#");
+        assert_eq!(decode("&timesbar"), "×bar");
+        assert_eq!(decode("&nGg;"), "\u{22D9}\u{338}");
+    }
+
+    #[test]
+    fn numeric_references() {
+        assert_eq!(decode("&#65;&#x42;&#X43&#68x"), "ABCDx");
+        assert_eq!(
+            decode("&#0; &#xD800; &#x110000; &#99999999999;"),
+            "\u{FFFD} \u{FFFD} \u{FFFD} \u{FFFD}"
+        );
+        assert_eq!(decode("&#128; &#x9f; &#x81;"), "€ Ÿ \u{81}");
+        assert_eq!(decode("&#; &#x; &#xg; &#a"), "&#; &#x; &#xg; &#a");
+        assert_eq!(decode("&#x0000000041"), "A");
+    }
+
+    #[test]
+    fn references_in_attributes() {
+        assert_eq!(
+            decode(r#"<a href="?a=1&amp;b=2&not=3&notx&not;">"#),
+            "?a=1&b=2&not=3&notx¬|"
+        );
+        assert_eq!(
+            decode(r#"<a title='&lt;&nGg;&#x26;'>"#),
+            "<\u{22D9}\u{338}&|"
+        );
+        assert_eq!(decode("<a title=&not.&gt>"), "¬.>|");
+    }
+
+    #[test]
+    fn references_in_attributes_at_the_end_of_the_input() {
+        let html = r#"<a b="&amp;"#;
+        let options = TokenizerOptions::default();
+        let entity = Tokenizer::new(html.as_bytes(), &options)
+            .find(|token| token.start == 6)
+            .unwrap();
+
+        assert_eq!(entity.location, TokenizerTokenLocation::AttrEntity);
+        assert_eq!((entity.end, entity.code), (11, 38));
+        assert_eq!(decode(r#"<a b="x&nGg;"#), "x\u{22D9}\u{338}");
+        assert_eq!(decode("<a b=&lt"), "<");
+    }
+}
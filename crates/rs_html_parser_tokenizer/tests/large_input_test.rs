@@ -0,0 +1,47 @@
+#[cfg(test)]
+mod tests {
+    use rs_html_parser_tokenizer::{Tokenizer, TokenizerOptions};
+    use rs_html_parser_tokenizer_tokens::TokenizerTokenLocation;
+
+    /**
+     * Needs more than 4 GiB of address space, which a runner with limited memory or without
+     * overcommit can't allocate. Run it with
+     * `cargo test -p rs_html_parser_tokenizer --test large_input_test -- --ignored`.
+     */
+    #[test]
+    #[ignore = "allocates more than 4 GiB"]
+    #[cfg(target_pointer_width = "64")]
+    fn offsets_past_4_gib() {
+        let tail = b"<p title=\"a&amp;b\">x</p>";
+        let start = (4 << 30) + 7;
+        // A zeroed allocation is mapped lazily, the gap does not take memory unless it is written
+        let mut buffer = vec![0; start + tail.len()];
+        buffer[start..].copy_from_slice(tail);
+
+        let options = TokenizerOptions::default();
+        let mut tokenizer = Tokenizer::new(&buffer, &options);
+        let text = tokenizer.next().unwrap();
+
+        assert_eq!(
+            (text.location, text.start, text.end),
+            (TokenizerTokenLocation::Text, 0, start)
+        );
+        assert_eq!(
+            tokenizer
+                .map(|token| (token.location, token.start - start, token.end - start))
+                .collect::<Vec<_>>(),
+            vec![
+                (TokenizerTokenLocation::OpenTagName, 1, 2),
+                (TokenizerTokenLocation::AttrName, 3, 8),
+                (TokenizerTokenLocation::AttrData, 10, 11),
+                (TokenizerTokenLocation::AttrEntity, 11, 16),
+                (TokenizerTokenLocation::AttrData, 16, 17),
+                (TokenizerTokenLocation::AttrEnd, 18, 18),
+                (TokenizerTokenLocation::OpenTagEnd, 18, 18),
+                (TokenizerTokenLocation::Text, 19, 20),
+                (TokenizerTokenLocation::CloseTag, 22, 23),
+                (TokenizerTokenLocation::End, 24, 24),
+            ]
+        );
+    }
+}
@@ -0,0 +1,49 @@
+#[cfg(test)]
+mod tests {
+    use rs_html_parser_tokenizer::{Tokenizer, TokenizerOptions};
+
+    const FIRST: &str = "<script>if (a < b) { c = '&amp;' }";
+    const SECOND: &str = "  <p title=\"a &amp; b\">text</p><!-- end -->  ";
+
+    #[test]
+    fn reset_matches_new_tokenizer() {
+        let options = TokenizerOptions::default();
+        let mut tokenizer = Tokenizer::new(FIRST.as_bytes(), &options);
+
+        // Leave the tokenizer inside the script and at the end of the input
+        tokenizer.by_ref().take(3).for_each(drop);
+        tokenizer.by_ref().for_each(drop);
+        tokenizer.reset(SECOND.as_bytes());
+
+        assert_eq!(
+            format!("{:?}", tokenizer.collect::<Vec<_>>()),
+            format!(
+                "{:?}",
+                Tokenizer::new(SECOND.as_bytes(), &options).collect::<Vec<_>>()
+            )
+        );
+    }
+
+    #[test]
+    fn reset_keeps_whitespace_setting() {
+        let options = TokenizerOptions::default();
+        let mut tokenizer = Tokenizer::new(FIRST.as_bytes(), &options);
+
+        tokenizer.set_ignore_whitespace_between_tags(false);
+        tokenizer.by_ref().for_each(drop);
+        tokenizer.reset(SECOND.as_bytes());
+
+        let options = TokenizerOptions {
+            ignore_whitespace_between_tags: Some(false),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            format!("{:?}", tokenizer.collect::<Vec<_>>()),
+            format!(
+                "{:?}",
+                Tokenizer::new(SECOND.as_bytes(), &options).collect::<Vec<_>>()
+            )
+        );
+    }
+}
@@ -31,4 +31,14 @@ mod tests {
             </template>"#)
         )
     }
+
+    #[test]
+    fn text_after_leading_whitespace_is_kept() {
+        assert_debug_snapshot!(tokenize("<p>\n    text after whitespace   </p>\n  <p> </p>"))
+    }
+
+    #[test]
+    fn whitespace_around_entities_is_kept() {
+        assert_debug_snapshot!(tokenize("<p>   &amp;   </p>\n  <p title=\"a &amp; b\">   </p>"))
+    }
 }
@@ -1,3 +1,5 @@
+#![no_std]
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum QuoteType {
     NoValue = 0,
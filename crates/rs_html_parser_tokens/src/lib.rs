@@ -1,6 +1,25 @@
-use unicase_collections::unicase_btree_map::UniCaseBTreeMap;
+#![no_std]
+
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+mod local_name;
+mod raw_attributes;
+pub mod unicase_btree_map;
+
+use crate::unicase_btree_map::UniCaseBTreeMap;
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::string::String;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::Deref;
 use rs_html_parser_tokenizer_tokens::QuoteType;
 
+pub use crate::local_name::{Atom, LocalName};
+pub use crate::raw_attributes::{AttributeDecoder, RawAttributes};
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum TokenKind {
     Text = 1,
@@ -14,10 +33,182 @@ pub enum TokenKind {
     CommentEnd, // Attribute,
 }
 
-#[derive(Debug)]
+pub type Attributes = UniCaseBTreeMap<Option<(Box<str>, QuoteType)>>;
+
+/**
+ * The data of a token. Tag names are interned element names, so emitting them does not
+ * allocate. It dereferences to `str` and compares like one.
+ */
+#[derive(Clone)]
+pub enum TokenData {
+    Name(LocalName),
+    Owned(Box<str>),
+}
+
+impl Deref for TokenData {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        match self {
+            TokenData::Name(name) => name.as_str(),
+            TokenData::Owned(data) => data,
+        }
+    }
+}
+
+impl fmt::Debug for TokenData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+impl fmt::Display for TokenData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self)
+    }
+}
+
+impl PartialEq for TokenData {
+    fn eq(&self, other: &TokenData) -> bool {
+        match (self, other) {
+            (TokenData::Name(a), TokenData::Name(b)) => a == b,
+            _ => **self == **other,
+        }
+    }
+}
+
+impl Eq for TokenData {}
+
+impl PartialEq<str> for TokenData {
+    fn eq(&self, other: &str) -> bool {
+        &**self == other
+    }
+}
+
+impl PartialEq<&str> for TokenData {
+    fn eq(&self, other: &&str) -> bool {
+        &**self == *other
+    }
+}
+
+impl Hash for TokenData {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (**self).hash(state)
+    }
+}
+
+impl From<LocalName> for TokenData {
+    fn from(name: LocalName) -> TokenData {
+        TokenData::Name(name)
+    }
+}
+
+impl From<Box<str>> for TokenData {
+    fn from(data: Box<str>) -> TokenData {
+        TokenData::Owned(data)
+    }
+}
+
+impl From<String> for TokenData {
+    fn from(data: String) -> TokenData {
+        TokenData::Owned(data.into_boxed_str())
+    }
+}
+
+impl From<&str> for TokenData {
+    fn from(data: &str) -> TokenData {
+        TokenData::Owned(data.into())
+    }
+}
+
+impl From<Cow<'_, str>> for TokenData {
+    fn from(data: Cow<'_, str>) -> TokenData {
+        TokenData::Owned(data.into())
+    }
+}
+
 pub struct Token {
-    pub data: Box<str>,
-    pub attrs: Option<UniCaseBTreeMap<Option<(Box<str>, QuoteType)>>>,
+    pub data: TokenData,
+    pub attrs: Option<Attributes>,
+    /** The undecoded attributes of an `OpenTag` when the parser decodes attributes lazily. */
+    pub raw_attrs: Option<RawAttributes>,
     pub kind: TokenKind,
     pub is_implied: bool,
+    /**
+     * Whether a `Comment` token, and its `CommentEnd`, is a CDATA section (`<![CDATA[...]]>`),
+     * which is emitted as a comment.
+     */
+    pub is_cdata: bool,
+    /**
+     * The byte offset in the input of the `<` of an `OpenTag` that is read from the input.
+     * Implied tags and the other tokens have none.
+     */
+    pub start: Option<usize>,
+}
+
+impl fmt::Debug for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_struct("Token");
+
+        debug.field("data", &self.data).field("attrs", &self.attrs);
+        if let Some(raw_attrs) = &self.raw_attrs {
+            debug.field("raw_attrs", raw_attrs);
+        }
+        debug
+            .field("kind", &self.kind)
+            .field("is_implied", &self.is_implied);
+        if self.is_cdata {
+            debug.field("is_cdata", &self.is_cdata);
+        }
+        debug.finish()
+    }
+}
+
+impl Token {
+    /** The element name of an `OpenTag` or `CloseTag` token. */
+    pub fn name(&self) -> Option<LocalName> {
+        match (&self.data, self.kind) {
+            (TokenData::Name(name), _) => Some(name.clone()),
+            (data, TokenKind::OpenTag | TokenKind::CloseTag) => Some(LocalName::new(data)),
+            _ => None,
+        }
+    }
+
+    /**
+     * The attributes of an `OpenTag` token, whether they were decoded while parsing or are
+     * decoded now from `raw_attrs`.
+     */
+    pub fn attributes(&self) -> Option<Cow<'_, Attributes>> {
+        match (&self.attrs, &self.raw_attrs) {
+            (Some(attrs), _) => Some(Cow::Borrowed(attrs)),
+            (None, Some(raw_attrs)) => raw_attrs.decode().map(Cow::Owned),
+            (None, None) => None,
+        }
+    }
+
+    /** The value of an attribute, ignoring ASCII case. An attribute without a value is empty. */
+    pub fn attribute(&self, name: &str) -> Option<Cow<'_, str>> {
+        match (&self.attrs, &self.raw_attrs) {
+            (Some(attrs), _) => {
+                let value = attrs.get(name)?;
+
+                Some(Cow::Borrowed(value.as_ref().map_or("", |(value, _)| value)))
+            }
+            (None, Some(raw_attrs)) => {
+                let value = raw_attrs.decode_attribute(name)?;
+
+                Some(Cow::Owned(value.map_or_else(String::new, |(value, _)| value.into())))
+            }
+            (None, None) => None,
+        }
+    }
+
+    /** Whether this is a `Text` token that consists only of whitespace. */
+    pub fn is_whitespace(&self) -> bool {
+        self.kind == TokenKind::Text
+            && self
+                .data
+                .bytes()
+                .all(|c| matches!(c, b' ' | b'\t' | b'\n' | b'\x0C' | b'\r'))
+    }
 }
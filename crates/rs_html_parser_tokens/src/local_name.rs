@@ -0,0 +1,609 @@
+use alloc::boxed::Box;
+use alloc::sync::Arc;
+#[cfg(feature = "std")]
+use core::borrow::Borrow;
+#[cfg(feature = "std")]
+use core::cell::RefCell;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+#[cfg(feature = "std")]
+use std::collections::hash_map::DefaultHasher;
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
+#[cfg(feature = "std")]
+use std::string::String;
+#[cfg(feature = "std")]
+use std::sync::{Mutex, OnceLock};
+
+/**
+ * An interned name of an element that is not one of the known elements, or is not spelled like
+ * one. Atoms with the same name share the same allocation, so comparing them compares pointers.
+ * The known element of a name spelled differently, eg. `Div` for `DIV`, is found once when the
+ * name is interned.
+ *
+ * The atoms are shared across documents and threads. Each thread looks up the names that it has
+ * seen recently without locking, the other names in one of the shards of the interner. Names that
+ * are not used anymore are released when a shard grows, so hostile input can't make it grow
+ * without bounds.
+ *
+ * Without `std` there is no interner. Every atom has its own allocation and comparing atoms
+ * compares their names.
+ */
+#[derive(Clone)]
+pub struct Atom(Arc<AtomData>);
+
+struct AtomData {
+    name: Box<str>,
+    /** The known element with this name, ignoring ASCII case. */
+    element: Option<LocalName>,
+}
+
+impl AtomData {
+    fn new(name: &str) -> AtomData {
+        AtomData {
+            name: name.into(),
+            element: LocalName::known(name),
+        }
+    }
+}
+
+/** An atom in a set of atoms, looked up by its name. */
+#[cfg(feature = "std")]
+#[derive(Clone)]
+struct Entry(Arc<AtomData>);
+
+#[cfg(feature = "std")]
+impl Borrow<str> for Entry {
+    fn borrow(&self) -> &str {
+        &self.0.name
+    }
+}
+
+#[cfg(feature = "std")]
+impl PartialEq for Entry {
+    fn eq(&self, other: &Entry) -> bool {
+        self.0.name == other.0.name
+    }
+}
+
+#[cfg(feature = "std")]
+impl Eq for Entry {}
+
+#[cfg(feature = "std")]
+impl Hash for Entry {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.name.hash(state)
+    }
+}
+
+#[cfg(feature = "std")]
+struct Shard {
+    atoms: HashSet<Entry>,
+    prune_at: usize,
+}
+
+#[cfg(feature = "std")]
+const SHARDS: usize = 16;
+
+#[cfg(feature = "std")]
+const MIN_PRUNE_AT: usize = 256;
+
+/** The most atoms that a thread keeps for lookups without locking. */
+#[cfg(feature = "std")]
+const MAX_CACHED: usize = 256;
+
+#[cfg(feature = "std")]
+fn shard(name: &str) -> &'static Mutex<Shard> {
+    static INTERNER: OnceLock<[Mutex<Shard>; SHARDS]> = OnceLock::new();
+
+    let shards = INTERNER.get_or_init(|| {
+        core::array::from_fn(|_| {
+            Mutex::new(Shard {
+                atoms: HashSet::new(),
+                prune_at: MIN_PRUNE_AT,
+            })
+        })
+    });
+    let mut hasher = DefaultHasher::new();
+
+    name.hash(&mut hasher);
+    &shards[hasher.finish() as usize % SHARDS]
+}
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    /**
+     * The atoms that the thread has looked up. The cache holds them, so they are not released
+     * while they are cached and each name still has one atom.
+     */
+    static CACHE: RefCell<HashSet<Entry>> = RefCell::new(HashSet::new());
+}
+
+#[cfg(feature = "std")]
+fn cached(name: &str) -> Option<Atom> {
+    CACHE
+        .try_with(|cache| cache.borrow().get(name).map(|entry| Atom(entry.0.clone())))
+        .ok()
+        .flatten()
+}
+
+#[cfg(feature = "std")]
+fn cache(atom: &Atom) {
+    let _ = CACHE.try_with(|cache| {
+        let mut cache = cache.borrow_mut();
+
+        if cache.len() >= MAX_CACHED {
+            cache.clear();
+        }
+        cache.insert(Entry(atom.0.clone()));
+    });
+}
+
+impl Atom {
+    #[cfg(feature = "std")]
+    pub fn new(name: &str) -> Atom {
+        if let Some(atom) = cached(name) {
+            return atom;
+        }
+
+        let atom = {
+            let mut shard = shard(name)
+                .lock()
+                .unwrap_or_else(|error| error.into_inner());
+
+            match shard.atoms.get(name) {
+                Some(entry) => Atom(entry.0.clone()),
+                None => {
+                    if shard.atoms.len() >= shard.prune_at {
+                        // Only the shard holds these
+                        shard.atoms.retain(|entry| Arc::strong_count(&entry.0) > 1);
+                        shard.prune_at = MIN_PRUNE_AT.max(shard.atoms.len() * 2);
+                    }
+
+                    let atom = Arc::new(AtomData::new(name));
+                    shard.atoms.insert(Entry(atom.clone()));
+
+                    Atom(atom)
+                }
+            }
+        };
+
+        cache(&atom);
+        atom
+    }
+
+    #[cfg(not(feature = "std"))]
+    pub fn new(name: &str) -> Atom {
+        Atom(Arc::new(AtomData::new(name)))
+    }
+
+    /** The atom with this name, if it exists. */
+    #[cfg(feature = "std")]
+    pub fn get(name: &str) -> Option<Atom> {
+        if let Some(atom) = cached(name) {
+            return Some(atom);
+        }
+
+        let atom = {
+            let shard = shard(name)
+                .lock()
+                .unwrap_or_else(|error| error.into_inner());
+
+            shard.atoms.get(name).map(|entry| Atom(entry.0.clone()))
+        }?;
+
+        cache(&atom);
+        Some(atom)
+    }
+
+    /** Without an interner any atom may exist. */
+    #[cfg(not(feature = "std"))]
+    pub fn get(name: &str) -> Option<Atom> {
+        Some(Atom::new(name))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0.name
+    }
+}
+
+impl PartialEq for Atom {
+    fn eq(&self, other: &Atom) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+            || (cfg!(not(feature = "std")) && self.0.name == other.0.name)
+    }
+}
+
+impl Eq for Atom {}
+
+impl Hash for Atom {
+    #[cfg(feature = "std")]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Arc::as_ptr(&self.0).cast::<u8>().hash(state)
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.name.hash(state)
+    }
+}
+
+impl fmt::Debug for Atom {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Atom").field(&self.as_str()).finish()
+    }
+}
+
+macro_rules! local_names {
+    ($($variant:ident => $name:expr),* $(,)?) => {
+        /**
+         * The name of an element. The HTML, SVG and MathML elements are variants, spelled as in the
+         * spec (eg. `clipPath`). Other names, and known names spelled differently (eg. `DIV`), are
+         * interned atoms, so comparing two names never compares strings.
+         *
+         * Equality is exact, `DIV` and `div` are different names. `element` folds the ASCII case
+         * to find the known element.
+         */
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum LocalName {
+            $($variant,)*
+            Other(Atom),
+        }
+
+        const KNOWN_NAMES: &[(&str, LocalName)] = &[$(($name, LocalName::$variant),)*];
+
+        impl LocalName {
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $(LocalName::$variant => $name,)*
+                    LocalName::Other(atom) => atom.as_str(),
+                }
+            }
+
+            fn from_known_name(name: &str) -> Option<LocalName> {
+                match name {
+                    $($name => Some(LocalName::$variant),)*
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+local_names! {
+    // HTML
+    A => "a",
+    Abbr => "abbr",
+    Acronym => "acronym",
+    Address => "address",
+    Applet => "applet",
+    Area => "area",
+    Article => "article",
+    Aside => "aside",
+    Audio => "audio",
+    B => "b",
+    Base => "base",
+    Basefont => "basefont",
+    Bdi => "bdi",
+    Bdo => "bdo",
+    Bgsound => "bgsound",
+    Big => "big",
+    Blink => "blink",
+    Blockquote => "blockquote",
+    Body => "body",
+    Br => "br",
+    Button => "button",
+    Canvas => "canvas",
+    Caption => "caption",
+    Center => "center",
+    Cite => "cite",
+    Code => "code",
+    Col => "col",
+    Colgroup => "colgroup",
+    Command => "command",
+    Data => "data",
+    Datalist => "datalist",
+    Dd => "dd",
+    Del => "del",
+    Details => "details",
+    Dfn => "dfn",
+    Dialog => "dialog",
+    Dir => "dir",
+    Div => "div",
+    Dl => "dl",
+    Dt => "dt",
+    Em => "em",
+    Embed => "embed",
+    Fieldset => "fieldset",
+    Figcaption => "figcaption",
+    Figure => "figure",
+    Font => "font",
+    Footer => "footer",
+    Form => "form",
+    Frame => "frame",
+    Frameset => "frameset",
+    H1 => "h1",
+    H2 => "h2",
+    H3 => "h3",
+    H4 => "h4",
+    H5 => "h5",
+    H6 => "h6",
+    Head => "head",
+    Header => "header",
+    Hgroup => "hgroup",
+    Hr => "hr",
+    Html => "html",
+    I => "i",
+    Iframe => "iframe",
+    Image => "image",
+    Img => "img",
+    Input => "input",
+    Ins => "ins",
+    Isindex => "isindex",
+    Kbd => "kbd",
+    Keygen => "keygen",
+    Label => "label",
+    Legend => "legend",
+    Li => "li",
+    Link => "link",
+    Listing => "listing",
+    Main => "main",
+    Map => "map",
+    Mark => "mark",
+    Marquee => "marquee",
+    Menu => "menu",
+    Menuitem => "menuitem",
+    Meta => "meta",
+    Meter => "meter",
+    Multicol => "multicol",
+    Nav => "nav",
+    Nextid => "nextid",
+    Nobr => "nobr",
+    Noembed => "noembed",
+    Noframes => "noframes",
+    Noscript => "noscript",
+    Object => "object",
+    Ol => "ol",
+    Optgroup => "optgroup",
+    Option => "option",
+    Output => "output",
+    P => "p",
+    Param => "param",
+    Picture => "picture",
+    Plaintext => "plaintext",
+    Pre => "pre",
+    Progress => "progress",
+    Q => "q",
+    Rb => "rb",
+    Rp => "rp",
+    Rt => "rt",
+    Rtc => "rtc",
+    Ruby => "ruby",
+    S => "s",
+    Samp => "samp",
+    Script => "script",
+    Search => "search",
+    Section => "section",
+    Select => "select",
+    Slot => "slot",
+    Small => "small",
+    Source => "source",
+    Spacer => "spacer",
+    Span => "span",
+    Strike => "strike",
+    Strong => "strong",
+    Style => "style",
+    Sub => "sub",
+    Summary => "summary",
+    Sup => "sup",
+    Table => "table",
+    Tbody => "tbody",
+    Td => "td",
+    Template => "template",
+    Textarea => "textarea",
+    Tfoot => "tfoot",
+    Th => "th",
+    Thead => "thead",
+    Time => "time",
+    Title => "title",
+    Tr => "tr",
+    Track => "track",
+    Tt => "tt",
+    U => "u",
+    Ul => "ul",
+    Var => "var",
+    Video => "video",
+    Wbr => "wbr",
+    Xmp => "xmp",
+
+    // SVG
+    Svg => "svg",
+    AltGlyph => "altGlyph",
+    AltGlyphDef => "altGlyphDef",
+    AltGlyphItem => "altGlyphItem",
+    Animate => "animate",
+    AnimateColor => "animateColor",
+    AnimateMotion => "animateMotion",
+    AnimateTransform => "animateTransform",
+    Circle => "circle",
+    ClipPath => "clipPath",
+    ColorProfile => "color-profile",
+    Cursor => "cursor",
+    Defs => "defs",
+    Desc => "desc",
+    Ellipse => "ellipse",
+    FeBlend => "feBlend",
+    FeColorMatrix => "feColorMatrix",
+    FeComponentTransfer => "feComponentTransfer",
+    FeComposite => "feComposite",
+    FeConvolveMatrix => "feConvolveMatrix",
+    FeDiffuseLighting => "feDiffuseLighting",
+    FeDisplacementMap => "feDisplacementMap",
+    FeDistantLight => "feDistantLight",
+    FeDropShadow => "feDropShadow",
+    FeFlood => "feFlood",
+    FeFuncA => "feFuncA",
+    FeFuncB => "feFuncB",
+    FeFuncG => "feFuncG",
+    FeFuncR => "feFuncR",
+    FeGaussianBlur => "feGaussianBlur",
+    FeImage => "feImage",
+    FeMerge => "feMerge",
+    FeMergeNode => "feMergeNode",
+    FeMorphology => "feMorphology",
+    FeOffset => "feOffset",
+    FePointLight => "fePointLight",
+    FeSpecularLighting => "feSpecularLighting",
+    FeSpotLight => "feSpotLight",
+    FeTile => "feTile",
+    FeTurbulence => "feTurbulence",
+    Filter => "filter",
+    FontFace => "font-face",
+    FontFaceFormat => "font-face-format",
+    FontFaceName => "font-face-name",
+    FontFaceSrc => "font-face-src",
+    FontFaceUri => "font-face-uri",
+    ForeignObject => "foreignObject",
+    G => "g",
+    Glyph => "glyph",
+    GlyphRef => "glyphRef",
+    Hkern => "hkern",
+    Line => "line",
+    LinearGradient => "linearGradient",
+    Marker => "marker",
+    Mask => "mask",
+    Metadata => "metadata",
+    MissingGlyph => "missing-glyph",
+    Mpath => "mpath",
+    Path => "path",
+    Pattern => "pattern",
+    Polygon => "polygon",
+    Polyline => "polyline",
+    RadialGradient => "radialGradient",
+    Rect => "rect",
+    Set => "set",
+    Stop => "stop",
+    Switch => "switch",
+    Symbol => "symbol",
+    Text => "text",
+    TextPath => "textPath",
+    Tref => "tref",
+    Tspan => "tspan",
+    Use => "use",
+    View => "view",
+    Vkern => "vkern",
+
+    // MathML
+    Math => "math",
+    Maction => "maction",
+    Annotation => "annotation",
+    AnnotationXml => "annotation-xml",
+    Menclose => "menclose",
+    Merror => "merror",
+    Mfenced => "mfenced",
+    Mfrac => "mfrac",
+    Mglyph => "mglyph",
+    Mi => "mi",
+    Mlabeledtr => "mlabeledtr",
+    Mmultiscripts => "mmultiscripts",
+    Mn => "mn",
+    Mo => "mo",
+    Mover => "mover",
+    Mpadded => "mpadded",
+    Mphantom => "mphantom",
+    Mprescripts => "mprescripts",
+    Mroot => "mroot",
+    Mrow => "mrow",
+    Ms => "ms",
+    Mspace => "mspace",
+    Msqrt => "msqrt",
+    Mstyle => "mstyle",
+    Msub => "msub",
+    Msubsup => "msubsup",
+    Msup => "msup",
+    Mtable => "mtable",
+    Mtd => "mtd",
+    Mtext => "mtext",
+    Mtr => "mtr",
+    Munder => "munder",
+    Munderover => "munderover",
+    MathNone => "none",
+    Semantics => "semantics",
+}
+
+/** The longest known name, longer names can't be known elements. */
+#[cfg(feature = "std")]
+const MAX_KNOWN_LENGTH: usize = 32;
+
+#[cfg(feature = "std")]
+fn folded_names() -> &'static HashMap<String, LocalName> {
+    static FOLDED: OnceLock<HashMap<String, LocalName>> = OnceLock::new();
+
+    FOLDED.get_or_init(|| {
+        KNOWN_NAMES
+            .iter()
+            .map(|(name, local_name)| (name.to_ascii_lowercase(), local_name.clone()))
+            .collect()
+    })
+}
+
+impl LocalName {
+    /** The name of an element, interning it when it is not a known element. */
+    pub fn new(name: &str) -> LocalName {
+        LocalName::from_known_name(name).unwrap_or_else(|| LocalName::Other(Atom::new(name)))
+    }
+
+    /**
+     * The name, if it is a known element or has been interned before. An element with a name
+     * that does not exist can't be open, so eg. end tags are looked up without interning.
+     */
+    pub fn get(name: &str) -> Option<LocalName> {
+        LocalName::from_known_name(name).or_else(|| Atom::get(name).map(LocalName::Other))
+    }
+
+    /** The known element with this name, ignoring ASCII case. */
+    #[cfg(feature = "std")]
+    pub fn known(name: &str) -> Option<LocalName> {
+        if name.len() > MAX_KNOWN_LENGTH {
+            return None;
+        }
+
+        let mut buffer = [0; MAX_KNOWN_LENGTH];
+        let folded = &mut buffer[..name.len()];
+
+        folded.copy_from_slice(name.as_bytes());
+        folded.make_ascii_lowercase();
+
+        let folded = core::str::from_utf8(folded).ok()?;
+
+        folded_names().get(folded).cloned()
+    }
+
+    /** The known element with this name, ignoring ASCII case. */
+    #[cfg(not(feature = "std"))]
+    pub fn known(name: &str) -> Option<LocalName> {
+        KNOWN_NAMES
+            .iter()
+            .find(|(known, _)| known.eq_ignore_ascii_case(name))
+            .map(|(_, local_name)| local_name.clone())
+    }
+
+    /** The known element, ignoring ASCII case, eg. `Div` for `DIV`. Otherwise the name itself. */
+    pub fn element(&self) -> LocalName {
+        self.as_element().clone()
+    }
+
+    /** Like `element`, without cloning. The known element of an atom is found when interning. */
+    pub fn as_element(&self) -> &LocalName {
+        match self {
+            LocalName::Other(atom) => atom.0.element.as_ref().unwrap_or(self),
+            _ => self,
+        }
+    }
+}
+
+impl fmt::Display for LocalName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
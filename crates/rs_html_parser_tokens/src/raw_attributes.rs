@@ -0,0 +1,54 @@
+use crate::Attributes;
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::sync::Arc;
+use core::fmt;
+use rs_html_parser_tokenizer_tokens::QuoteType;
+
+/** Decodes the attributes of a tag from its raw source, see `RawAttributes`. */
+pub trait AttributeDecoder: Send + Sync {
+    /** Returns the attributes in `source`, or `None` when there are none. */
+    fn decode(&self, source: &[u8]) -> Option<Attributes>;
+
+    /** Returns the value of the attribute `name` in `source`, like `decode(source)?.remove(name)`. */
+    fn decode_attribute(&self, source: &[u8], name: &str) -> Option<Option<(Box<str>, QuoteType)>> {
+        self.decode(source)?.remove(name)
+    }
+}
+
+/**
+ * The undecoded attributes of a tag: the source between the tag name and the end of the tag.
+ * Names and values are decoded only when they are asked for, by the decoder of the parser that
+ * emitted the tag, so they are the same as if they had been decoded while parsing.
+ */
+#[derive(Clone)]
+pub struct RawAttributes {
+    source: Box<[u8]>,
+    decoder: Arc<dyn AttributeDecoder>,
+}
+
+impl RawAttributes {
+    pub fn new(source: Box<[u8]>, decoder: Arc<dyn AttributeDecoder>) -> RawAttributes {
+        RawAttributes { source, decoder }
+    }
+
+    pub fn source(&self) -> &[u8] {
+        &self.source
+    }
+
+    pub fn decode(&self) -> Option<Attributes> {
+        self.decoder.decode(&self.source)
+    }
+
+    pub fn decode_attribute(&self, name: &str) -> Option<Option<(Box<str>, QuoteType)>> {
+        self.decoder.decode_attribute(&self.source, name)
+    }
+}
+
+impl fmt::Debug for RawAttributes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("RawAttributes")
+            .field(&String::from_utf8_lossy(&self.source))
+            .finish()
+    }
+}
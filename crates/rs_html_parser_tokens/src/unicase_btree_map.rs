@@ -0,0 +1,247 @@
+use alloc::collections::btree_map::{Entry, IntoIter, Iter, IterMut, Keys, Values, ValuesMut};
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::ops::Index;
+use unicase::UniCase;
+
+/** A key of `UniCaseBTreeMap`, compared with Unicode case folding, see `UniCase`. */
+pub type Key = UniCase<String>;
+
+/** Types that can be inserted as a key of a `UniCaseBTreeMap`, see `KeyRef` for lookups. */
+pub trait ToKey {
+    fn to_key(self) -> Key;
+}
+
+impl ToKey for String {
+    fn to_key(self) -> Key {
+        UniCase::new(self)
+    }
+}
+
+impl ToKey for &str {
+    fn to_key(self) -> Key {
+        UniCase::new(self.to_string())
+    }
+}
+
+impl ToKey for Key {
+    fn to_key(self) -> Key {
+        self
+    }
+}
+
+impl ToKey for &Key {
+    fn to_key(self) -> Key {
+        self.clone()
+    }
+}
+
+/**
+ * Types that a `UniCaseBTreeMap` can be looked up with, without allocating a `Key`. They borrow as
+ * a `UniCase<&str>`, which compares like the `Key` with the same text.
+ */
+pub trait KeyRef {
+    fn key(&self) -> UniCase<&str>;
+}
+
+impl KeyRef for str {
+    fn key(&self) -> UniCase<&str> {
+        UniCase::new(self)
+    }
+}
+
+impl KeyRef for String {
+    fn key(&self) -> UniCase<&str> {
+        UniCase::new(self)
+    }
+}
+
+impl KeyRef for Key {
+    fn key(&self) -> UniCase<&str> {
+        // The key was already checked for non-ASCII characters when it was created
+        if self.is_ascii() {
+            UniCase::ascii(self.as_str())
+        } else {
+            UniCase::unicode(self.as_str())
+        }
+    }
+}
+
+impl KeyRef for UniCase<&str> {
+    fn key(&self) -> UniCase<&str> {
+        *self
+    }
+}
+
+impl PartialEq for dyn KeyRef + '_ {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl Eq for dyn KeyRef + '_ {}
+
+impl PartialOrd for dyn KeyRef + '_ {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for dyn KeyRef + '_ {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key().cmp(&other.key())
+    }
+}
+
+impl<'a> Borrow<dyn KeyRef + 'a> for Key {
+    fn borrow(&self) -> &(dyn KeyRef + 'a) {
+        self
+    }
+}
+
+/**
+ * A `BTreeMap` whose keys are compared ignoring case. ASCII keys ignore ASCII case, other keys are
+ * compared with Unicode case folding, as `UniCase` does. It only needs `alloc`, and has the API of
+ * the `unicase_collections` map that the attributes used before.
+ */
+#[derive(Debug, Default, Clone)]
+pub struct UniCaseBTreeMap<V> {
+    inner: BTreeMap<Key, V>,
+}
+
+impl<V: PartialEq> PartialEq for UniCaseBTreeMap<V> {
+    fn eq(&self, other: &UniCaseBTreeMap<V>) -> bool {
+        self.inner == other.inner
+    }
+}
+
+impl<V: Eq> Eq for UniCaseBTreeMap<V> {}
+
+impl<K: ToKey, V> Extend<(K, V)> for UniCaseBTreeMap<V> {
+    fn extend<T: IntoIterator<Item = (K, V)>>(&mut self, iter: T) {
+        self.inner
+            .extend(iter.into_iter().map(|(k, v)| (k.to_key(), v)));
+    }
+}
+
+impl<K: ToKey, V> FromIterator<(K, V)> for UniCaseBTreeMap<V> {
+    fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> Self {
+        let mut map = UniCaseBTreeMap::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<'a, V> IntoIterator for &'a UniCaseBTreeMap<V> {
+    type Item = (&'a Key, &'a V);
+    type IntoIter = Iter<'a, Key, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, V> IntoIterator for &'a mut UniCaseBTreeMap<V> {
+    type Item = (&'a Key, &'a mut V);
+    type IntoIter = IterMut<'a, Key, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<V> IntoIterator for UniCaseBTreeMap<V> {
+    type Item = (Key, V);
+    type IntoIter = IntoIter<Key, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.into_iter()
+    }
+}
+
+impl<K: KeyRef + ?Sized, V> Index<&K> for UniCaseBTreeMap<V> {
+    type Output = V;
+
+    fn index(&self, index: &K) -> &V {
+        self.get(index).expect("no entry found for key")
+    }
+}
+
+impl<V> UniCaseBTreeMap<V> {
+    pub fn new() -> Self {
+        UniCaseBTreeMap {
+            inner: BTreeMap::new(),
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.inner.clear();
+    }
+
+    pub fn contains_key<K: KeyRef + ?Sized>(&self, k: &K) -> bool {
+        self.inner.contains_key(&k.key() as &dyn KeyRef)
+    }
+
+    pub fn entry<K: ToKey>(&mut self, k: K) -> Entry<'_, Key, V> {
+        self.inner.entry(k.to_key())
+    }
+
+    pub fn get<K: KeyRef + ?Sized>(&self, k: &K) -> Option<&V> {
+        self.inner.get(&k.key() as &dyn KeyRef)
+    }
+
+    pub fn get_key_value<K: KeyRef + ?Sized>(&self, k: &K) -> Option<(&Key, &V)> {
+        self.inner.get_key_value(&k.key() as &dyn KeyRef)
+    }
+
+    pub fn get_mut<K: KeyRef + ?Sized>(&mut self, k: &K) -> Option<&mut V> {
+        self.inner.get_mut(&k.key() as &dyn KeyRef)
+    }
+
+    /** Inserts a value, returning the old one. The key keeps the case it was inserted with. */
+    pub fn insert<K: ToKey>(&mut self, k: K, v: V) -> Option<V> {
+        self.inner.insert(k.to_key(), v)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    pub fn iter(&self) -> Iter<'_, Key, V> {
+        self.inner.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, Key, V> {
+        self.inner.iter_mut()
+    }
+
+    pub fn keys(&self) -> Keys<'_, Key, V> {
+        self.inner.keys()
+    }
+
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    pub fn remove<K: KeyRef + ?Sized>(&mut self, k: &K) -> Option<V> {
+        self.inner.remove(&k.key() as &dyn KeyRef)
+    }
+
+    pub fn remove_entry<K: KeyRef + ?Sized>(&mut self, k: &K) -> Option<(Key, V)> {
+        self.inner.remove_entry(&k.key() as &dyn KeyRef)
+    }
+
+    pub fn retain<F: FnMut(&Key, &mut V) -> bool>(&mut self, f: F) {
+        self.inner.retain(f);
+    }
+
+    pub fn values(&self) -> Values<'_, Key, V> {
+        self.inner.values()
+    }
+
+    pub fn values_mut(&mut self) -> ValuesMut<'_, Key, V> {
+        self.inner.values_mut()
+    }
+}
@@ -0,0 +1,79 @@
+mod tests {
+    use rs_html_parser_tokens::{Atom, LocalName, Token, TokenData, TokenKind};
+
+    #[test]
+    fn known_names_are_variants() {
+        assert_eq!(LocalName::new("div"), LocalName::Div);
+        assert_eq!(LocalName::new("clipPath"), LocalName::ClipPath);
+        assert_eq!(LocalName::new("annotation-xml"), LocalName::AnnotationXml);
+        assert_eq!(LocalName::ClipPath.as_str(), "clipPath");
+    }
+
+    #[test]
+    fn other_names_are_interned() {
+        let name = LocalName::new("my-element");
+
+        assert_eq!(name, LocalName::new("my-element"));
+        assert_eq!(name, LocalName::Other(Atom::new("my-element")));
+        assert_ne!(name, LocalName::new("my-other-element"));
+        assert_eq!(name.as_str(), "my-element");
+        assert_eq!(LocalName::get("my-element"), Some(name));
+        assert_eq!(LocalName::get("never-interned-element"), None);
+    }
+
+    #[test]
+    fn case_is_folded_for_the_element() {
+        let name = LocalName::new("DIV");
+
+        assert_ne!(name, LocalName::Div);
+        assert_eq!(name.as_str(), "DIV");
+        assert_eq!(name.element(), LocalName::Div);
+        assert_eq!(LocalName::new("foreignobject").element(), LocalName::ForeignObject);
+        assert_eq!(LocalName::known("TEXTAREA"), Some(LocalName::Textarea));
+        assert_eq!(LocalName::known("unknown"), None);
+        assert_eq!(name.as_element(), &LocalName::Div);
+        assert_eq!(LocalName::new("x-unknown").as_element(), &LocalName::new("x-unknown"));
+    }
+
+    #[test]
+    fn atoms_are_shared_across_threads() {
+        let name = LocalName::new("x-shared-element");
+        let names: Vec<LocalName> = (0..4)
+            .map(|_| std::thread::spawn(|| LocalName::new("x-shared-element")))
+            .map(|thread| thread.join().unwrap())
+            .collect();
+
+        assert!(names.iter().all(|other| *other == name));
+
+        // More names than a thread caches
+        let many: Vec<LocalName> = (0..1000)
+            .map(|index| LocalName::new(&format!("x-many-{}", index)))
+            .collect();
+
+        for (index, name) in many.iter().enumerate() {
+            assert_eq!(LocalName::get(&format!("x-many-{}", index)).as_ref(), Some(name));
+        }
+    }
+
+    #[test]
+    fn token_data_behaves_like_str() {
+        let name: TokenData = LocalName::Div.into();
+        let owned: TokenData = "div".into();
+
+        assert_eq!(name, owned);
+        assert_eq!(name, "div");
+        assert_eq!(format!("{:?}", name), "\"div\"");
+
+        let token = Token {
+            data: owned,
+            attrs: None,
+            raw_attrs: None,
+            kind: TokenKind::OpenTag,
+            is_implied: false,
+            is_cdata: false,
+            start: None,
+        };
+
+        assert_eq!(token.name(), Some(LocalName::Div));
+    }
+}
@@ -0,0 +1,29 @@
+mod tests {
+    use rs_html_parser_tokens::unicase_btree_map::{Key, UniCaseBTreeMap};
+    use unicase::UniCase;
+
+    #[test]
+    fn looks_up_borrowed_keys_ignoring_case() {
+        let mut map = UniCaseBTreeMap::new();
+
+        map.insert("Href", 1);
+        map.insert("ΣΊΣΥΦΟΣ", 2);
+        map.insert(String::from("data-X"), 3);
+
+        assert_eq!(map.get("href"), Some(&1));
+        assert_eq!(map.get("HREF"), Some(&1));
+        assert_eq!(map.get(&String::from("DATA-x")), Some(&3));
+        assert_eq!(map.get(&Key::new("data-x".to_string())), Some(&3));
+        assert_eq!(map.get(&UniCase::new("hReF")), Some(&1));
+        assert_eq!(map.get("σίσυφος"), Some(&2));
+        assert_eq!(map.get("src"), None);
+        assert!(map.contains_key("ΣΊΣΥΦΟΣ"));
+        assert_eq!(map["data-x"], 3);
+        assert_eq!(map.remove("HREF"), Some(1));
+        assert!(!map.contains_key("href"));
+        assert_eq!(
+            map.keys().map(|key| key.as_str()).collect::<Vec<_>>(),
+            ["data-X", "ΣΊΣΥΦΟΣ"]
+        );
+    }
+}
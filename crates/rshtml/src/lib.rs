@@ -0,0 +1,927 @@
+use clap::{Args, Parser as ClapParser, Subcommand, ValueEnum};
+use rs_html_parser::{
+    apply_whitespace_mode, extract_links, format, minify, ArticleOptions, AttributeWrapping,
+    Document, FormatOptions, LinkOptions, MarkdownOptions, MinifyOptions, NodeData, NodeId, Parser,
+    ParserOptions, ProcessingInstruction, QuoteStyle, Selector, UnsupportedElements,
+    WhitespaceMode,
+};
+use rs_html_parser_tokenizer::{Tokenizer, TokenizerOptions};
+use rs_html_parser_tokenizer_tokens::{QuoteType, TokenizerToken, TokenizerTokenLocation};
+use rs_html_parser_tokens::{Token, TokenKind};
+use serde_json::{json, Map, Value};
+use std::error::Error;
+use std::io::Write;
+
+#[derive(Debug, ClapParser)]
+#[command(name = "rshtml", version, about = "Tokenize, parse and query HTML")]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Command,
+
+    #[command(flatten)]
+    pub options: Options,
+}
+
+/** The parser and tokenizer options, see `ParserOptions` and `TokenizerOptions`. */
+#[derive(Debug, Args)]
+pub struct Options {
+    /** Parse as XML: no special tags, void elements can have children (`xml_mode`) */
+    #[arg(long, global = true)]
+    pub xml_mode: bool,
+
+    /** Keep character references like `&amp;` as they are (`decode_entities = false`) */
+    #[arg(long, global = true)]
+    pub no_decode_entities: bool,
+
+    /** Keep whitespace-only text between tags (`ignore_whitespace_between_tags = false`) */
+    #[arg(long, global = true)]
+    pub keep_whitespace_between_tags: bool,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /** Print the tokens of the parser, or of the tokenizer */
+    Tokens {
+        /** Print the tokens of the tokenizer, with their byte ranges */
+        #[arg(long)]
+        tokenizer: bool,
+
+        /** Print JSON */
+        #[arg(long)]
+        json: bool,
+
+        /** The file to read, `-` for stdin */
+        #[arg(default_value = "-")]
+        file: String,
+    },
+    /** Print the element tree */
+    Tree {
+        /** Print JSON */
+        #[arg(long)]
+        json: bool,
+
+        /** The file to read, `-` for stdin */
+        #[arg(default_value = "-")]
+        file: String,
+    },
+    /** Print the elements that match a CSS selector, one per line */
+    Select {
+        /** The selector, eg. `article > h1, a[href]` */
+        selector: String,
+
+        /** Print the text of the elements instead of their markup */
+        #[arg(long, conflicts_with_all = ["attr", "json"])]
+        text: bool,
+
+        /** Print the value of an attribute of the elements instead of their markup */
+        #[arg(long, value_name = "NAME", conflicts_with = "json")]
+        attr: Option<String>,
+
+        /** Print JSON */
+        #[arg(long)]
+        json: bool,
+
+        /** The file to read, `-` for stdin */
+        #[arg(default_value = "-")]
+        file: String,
+    },
+    /**
+     * Print the text of the document as it is rendered, like `innerText`: blocks on their own
+     * lines, table cells separated by tabs and without scripts, styles or hidden elements
+     */
+    Text {
+        /** The file to read, `-` for stdin */
+        #[arg(default_value = "-")]
+        file: String,
+    },
+    /** Report the parse errors, the exit status is 1 if there are any */
+    Validate {
+        /** Print JSON */
+        #[arg(long)]
+        json: bool,
+
+        /** The file to read, `-` for stdin */
+        #[arg(default_value = "-")]
+        file: String,
+    },
+    /**
+     * Print the document formatted, block-level elements are re-indented. Entities and whitespace
+     * between tags are always kept.
+     */
+    Format {
+        /** Only report whether the document is formatted, the exit status is 1 if it is not */
+        #[arg(long)]
+        check: bool,
+
+        /** Number of spaces per level of nesting */
+        #[arg(long, value_name = "WIDTH", default_value_t = 2)]
+        indent: usize,
+
+        /** Lines are wrapped at whitespace to fit this width */
+        #[arg(long, value_name = "WIDTH", default_value_t = 80)]
+        max_line_width: usize,
+
+        /** When the attributes of a start tag are put on lines of their own */
+        #[arg(long, value_enum, default_value_t = WrapAttributes::Auto)]
+        wrap_attributes: WrapAttributes,
+
+        /** How attribute values are quoted, `keep` keeps the quotes of every value */
+        #[arg(long, value_enum, default_value_t = Quotes::Double)]
+        quotes: Quotes,
+
+        /** The file to read, `-` for stdin */
+        #[arg(default_value = "-")]
+        file: String,
+    },
+    /** Print the document minified, without comments and whitespace that is not rendered */
+    Minify {
+        /** Keep all comments, not only conditional comments */
+        #[arg(long)]
+        keep_comments: bool,
+
+        /** Keep the end tags that can be omitted */
+        #[arg(long)]
+        keep_optional_tags: bool,
+
+        /** The file to read, `-` for stdin */
+        #[arg(default_value = "-")]
+        file: String,
+    },
+    /** Print the main content of the document, with its title, byline and lead image */
+    Article {
+        /** Print JSON with the content as HTML and as text */
+        #[arg(long)]
+        json: bool,
+
+        /** The file to read, `-` for stdin */
+        #[arg(default_value = "-")]
+        file: String,
+    },
+    /** Print the tables of the document as CSV, separated by empty lines */
+    Tables {
+        /** Print JSON with the caption, the number of header rows and the text of the cells */
+        #[arg(long)]
+        json: bool,
+
+        /** The file to read, `-` for stdin */
+        #[arg(default_value = "-")]
+        file: String,
+    },
+    /** Print the URLs in the attributes of the elements, with the byte offset of the element */
+    Links {
+        /** The URL of the document, to resolve relative URLs against */
+        #[arg(long, value_name = "URL")]
+        url: Option<String>,
+
+        /** Print JSON with the element, the attribute, the URL as written and resolved */
+        #[arg(long)]
+        json: bool,
+
+        /** The file to read, `-` for stdin */
+        #[arg(default_value = "-")]
+        file: String,
+    },
+    /** Convert the document to Markdown */
+    Markdown {
+        /** Drop the elements without a Markdown syntax instead of writing them as HTML */
+        #[arg(long)]
+        drop_unsupported: bool,
+
+        /** Write CommonMark, without the tables and strikethrough of GitHub Flavored Markdown */
+        #[arg(long)]
+        commonmark: bool,
+
+        /** The file to read, `-` for stdin */
+        #[arg(default_value = "-")]
+        file: String,
+    },
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, ValueEnum)]
+pub enum WrapAttributes {
+    /** When the start tag does not fit on its line */
+    Auto,
+    /** When the tag has more than one attribute */
+    Always,
+    Never,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, ValueEnum)]
+pub enum Quotes {
+    Double,
+    Single,
+    Keep,
+}
+
+impl Command {
+    /** The file to read, `-` for stdin. */
+    pub fn file(&self) -> &str {
+        match self {
+            Command::Tokens { file, .. }
+            | Command::Tree { file, .. }
+            | Command::Select { file, .. }
+            | Command::Text { file }
+            | Command::Validate { file, .. }
+            | Command::Format { file, .. }
+            | Command::Minify { file, .. }
+            | Command::Article { file, .. }
+            | Command::Tables { file, .. }
+            | Command::Links { file, .. }
+            | Command::Markdown { file, .. } => file,
+        }
+    }
+}
+
+impl Options {
+    pub fn parser_options(&self) -> ParserOptions {
+        ParserOptions {
+            xml_mode: self.xml_mode,
+            tokenizer_options: self.tokenizer_options(),
+            ..Default::default()
+        }
+    }
+
+    pub fn tokenizer_options(&self) -> TokenizerOptions {
+        TokenizerOptions {
+            decode_entities: Some(!self.no_decode_entities),
+            ignore_whitespace_between_tags: Some(!self.keep_whitespace_between_tags),
+            ..Default::default()
+        }
+    }
+}
+
+/**
+ * Runs the command on the input, the name of the input is used in messages. Returns whether the
+ * command succeeded: `select` fails when nothing matches, `validate` when there are errors,
+ * `format --check` when the input is not formatted, `article` when the document has no text and
+ * `tables` when it has no tables.
+ */
+pub fn run(
+    cli: &Cli,
+    name: &str,
+    input: &[u8],
+    output: &mut dyn Write,
+) -> Result<bool, Box<dyn Error>> {
+    let options = cli.options.parser_options();
+
+    match &cli.command {
+        Command::Tokens {
+            tokenizer: true,
+            json,
+            ..
+        } => {
+            let tokenizer = Tokenizer::new(input, &options.tokenizer_options);
+
+            print_tokenizer_tokens(tokenizer, input, *json, output)?;
+        }
+        Command::Tokens { json, .. } => {
+            print_tokens(Parser::from_bytes(input, &options), *json, output)?;
+        }
+        Command::Tree { json, .. } => {
+            let document = Document::from_tokens(Parser::from_bytes(input, &options));
+
+            if *json {
+                write_nodes_json(&document, &document[document.root()].children, output)?;
+            } else {
+                print_tree(&document, document.root(), output)?;
+            }
+        }
+        Command::Select {
+            selector,
+            text,
+            attr,
+            json,
+            ..
+        } => {
+            let selector = Selector::parse(selector)?;
+            let document = Document::from_tokens(Parser::from_bytes(input, &options));
+            let matches: Vec<_> = document.select(document.root(), &selector).collect();
+
+            if *json {
+                write_nodes_json(&document, &matches, output)?;
+            } else {
+                for id in &matches {
+                    if *text {
+                        writeln!(
+                            output,
+                            "{}",
+                            apply_whitespace_mode(
+                                WhitespaceMode::Trim,
+                                &document.text_content(*id)
+                            )
+                            .unwrap_or_default()
+                        )?;
+                    } else if let Some(attr) = attr {
+                        if let Some(value) = document.attribute(*id, attr) {
+                            writeln!(output, "{}", value)?;
+                        }
+                    } else {
+                        writeln!(output, "{}", document.outer_html(*id))?;
+                    }
+                }
+            }
+
+            return Ok(!matches.is_empty());
+        }
+        Command::Text { .. } => {
+            let document = Document::from_tokens(Parser::from_bytes(input, &options));
+
+            writeln!(output, "{}", document.inner_text(document.root()))?;
+        }
+        Command::Validate { json, .. } => {
+            let mut parser = Parser::from_bytes(input, &options);
+
+            parser.by_ref().for_each(drop);
+
+            let errors = parser.parse_errors();
+            let mut lines = LineIndex::new(input);
+
+            if *json {
+                let errors: Vec<_> = errors
+                    .iter()
+                    .map(|error| {
+                        let (line, column) = lines.line_and_column(error.position);
+
+                        json!({
+                            "line": line,
+                            "column": column,
+                            "offset": error.position,
+                            "message": error.to_string(),
+                        })
+                    })
+                    .collect();
+
+                writeln!(output, "{}", serde_json::to_string_pretty(&errors)?)?;
+            } else {
+                for error in errors {
+                    let (line, column) = lines.line_and_column(error.position);
+
+                    writeln!(output, "{}:{}:{}: {}", name, line, column, error)?;
+                }
+            }
+
+            return Ok(errors.is_empty());
+        }
+        Command::Format {
+            check,
+            indent,
+            max_line_width,
+            wrap_attributes,
+            quotes,
+            ..
+        } => {
+            let html = String::from_utf8_lossy(input);
+            let formatted = format(
+                &html,
+                &FormatOptions {
+                    xml_mode: cli.options.xml_mode,
+                    indent_width: Some(*indent),
+                    max_line_width: Some(*max_line_width),
+                    attribute_wrapping: Some(match wrap_attributes {
+                        WrapAttributes::Auto => AttributeWrapping::Auto,
+                        WrapAttributes::Always => AttributeWrapping::Always,
+                        WrapAttributes::Never => AttributeWrapping::Never,
+                    }),
+                    quote_style: Some(match quotes {
+                        Quotes::Double => QuoteStyle::Double,
+                        Quotes::Single => QuoteStyle::Single,
+                        Quotes::Keep => QuoteStyle::Keep,
+                    }),
+                },
+            );
+
+            if *check {
+                if formatted != html {
+                    writeln!(output, "{}: not formatted", name)?;
+                }
+
+                return Ok(formatted == html);
+            }
+
+            output.write_all(formatted.as_bytes())?;
+        }
+        Command::Minify {
+            keep_comments,
+            keep_optional_tags,
+            ..
+        } => {
+            let minified = minify(
+                &String::from_utf8_lossy(input),
+                &MinifyOptions {
+                    remove_comments: Some(!keep_comments),
+                    remove_optional_tags: Some(!keep_optional_tags),
+                    ..Default::default()
+                },
+            );
+
+            output.write_all(minified.as_bytes())?;
+        }
+        Command::Article { json, .. } => {
+            let document = Document::from_tokens(Parser::from_bytes(input, &options));
+            let Some(article) = document.article(&ArticleOptions::default()) else {
+                return Ok(false);
+            };
+            let content = &article.content;
+            let text = content.inner_text(content.root());
+
+            if *json {
+                let article = json!({
+                    "title": article.title,
+                    "byline": article.byline,
+                    "lead_image": article.lead_image,
+                    "html": content.inner_html(content.root()),
+                    "text": text,
+                });
+
+                writeln!(output, "{}", serde_json::to_string_pretty(&article)?)?;
+            } else {
+                for (name, value) in [
+                    ("Title", &article.title),
+                    ("Byline", &article.byline),
+                    ("Lead image", &article.lead_image),
+                ] {
+                    if let Some(value) = value {
+                        writeln!(output, "{}: {}", name, value)?;
+                    }
+                }
+                writeln!(output, "\n{}", text)?;
+            }
+        }
+        Command::Tables { json, .. } => {
+            let document = Document::from_tokens(Parser::from_bytes(input, &options));
+            let tables = document.tables(document.root());
+
+            if *json {
+                let tables: Vec<_> = tables
+                    .iter()
+                    .map(|table| {
+                        let rows: Vec<Vec<_>> = table
+                            .rows
+                            .iter()
+                            .map(|row| row.iter().map(|cell| &cell.text).collect())
+                            .collect();
+
+                        json!({
+                            "caption": table.caption,
+                            "header_rows": table.header_rows,
+                            "rows": rows,
+                        })
+                    })
+                    .collect();
+
+                writeln!(output, "{}", serde_json::to_string_pretty(&tables)?)?;
+            } else {
+                let csv: Vec<_> = tables.iter().map(|table| table.to_csv()).collect();
+
+                output.write_all(csv.join("\n").as_bytes())?;
+            }
+            return Ok(!tables.is_empty());
+        }
+        Command::Links { url, json, .. } => {
+            let links = extract_links(
+                &String::from_utf8_lossy(input),
+                &LinkOptions {
+                    document_url: url.clone(),
+                },
+            );
+
+            if *json {
+                let links: Vec<_> = links
+                    .iter()
+                    .map(|link| {
+                        json!({
+                            "position": link.position,
+                            "element": link.element.as_str(),
+                            "attribute": link.attribute,
+                            "url": link.url,
+                            "resolved": link.resolved,
+                            "rel": link.rel,
+                        })
+                    })
+                    .collect();
+
+                writeln!(output, "{}", serde_json::to_string_pretty(&links)?)?;
+            } else {
+                for link in &links {
+                    writeln!(
+                        output,
+                        "{}\t{}[{}]\t{}",
+                        link.position,
+                        link.element,
+                        link.attribute,
+                        link.resolved.as_ref().unwrap_or(&link.url)
+                    )?;
+                }
+            }
+        }
+        Command::Markdown {
+            drop_unsupported,
+            commonmark,
+            ..
+        } => {
+            let document = Document::from_tokens(Parser::from_bytes(input, &options));
+            let markdown = document.to_markdown(
+                document.root(),
+                &MarkdownOptions {
+                    unsupported_elements: Some(if *drop_unsupported {
+                        UnsupportedElements::Drop
+                    } else {
+                        UnsupportedElements::Html
+                    }),
+                    gfm: Some(!commonmark),
+                },
+            );
+
+            output.write_all(markdown.as_bytes())?;
+        }
+    }
+
+    Ok(true)
+}
+
+/** The line and the column of the byte offset, both starting at 1. Columns count characters. */
+pub fn line_and_column(input: &[u8], offset: usize) -> (usize, usize) {
+    LineIndex::new(input).line_and_column(offset)
+}
+
+/**
+ * Finds the lines and the columns of many offsets in the input, see `line_and_column`. The starts
+ * of the lines are found once, and the columns are counted from the previous offset when it is on
+ * the same line, so offsets in increasing order take time proportional to the input.
+ */
+pub struct LineIndex<'a> {
+    input: &'a [u8],
+    line_starts: Vec<usize>,
+    // The offset that was looked up last, with its line and column
+    last: (usize, usize, usize),
+}
+
+impl<'a> LineIndex<'a> {
+    pub fn new(input: &'a [u8]) -> LineIndex<'a> {
+        let line_starts = std::iter::once(0)
+            .chain(
+                input
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, c)| **c == b'\n')
+                    .map(|(index, _)| index + 1),
+            )
+            .collect();
+
+        LineIndex {
+            input,
+            line_starts,
+            last: (0, 1, 1),
+        }
+    }
+
+    pub fn line_and_column(&mut self, offset: usize) -> (usize, usize) {
+        let offset = offset.min(self.input.len());
+        let line = self.line_starts.partition_point(|start| *start <= offset);
+        let (from, column) = match self.last {
+            (last, last_line, column) if last_line == line && last <= offset => (last, column),
+            _ => (self.line_starts[line - 1], 1),
+        };
+        let column = column
+            + String::from_utf8_lossy(&self.input[from..offset])
+                .chars()
+                .count();
+
+        self.last = (offset, line, column);
+        (line, column)
+    }
+}
+
+fn kind_name(kind: TokenKind) -> &'static str {
+    match kind {
+        TokenKind::Text => "Text",
+        TokenKind::OpenTag => "OpenTag",
+        TokenKind::CloseTag => "CloseTag",
+        TokenKind::ProcessingInstruction => "ProcessingInstruction",
+        TokenKind::Comment => "Comment",
+        TokenKind::CommentEnd => "CommentEnd",
+    }
+}
+
+fn attributes_json(token: &Token) -> Map<String, Value> {
+    let mut attributes = Map::new();
+
+    for (name, value) in token.attributes().iter().flat_map(|attrs| attrs.iter()) {
+        let value = value.as_ref().map(|(value, _)| Value::from(&**value));
+
+        attributes.insert(name.to_string(), value.unwrap_or(Value::Null));
+    }
+
+    attributes
+}
+
+fn print_tokens(parser: Parser, json: bool, output: &mut dyn Write) -> Result<(), Box<dyn Error>> {
+    if json {
+        let tokens: Vec<_> = parser
+            .map(|token| {
+                let mut value = json!({
+                    "kind": kind_name(token.kind),
+                    "data": &*token.data,
+                });
+
+                if token.kind == TokenKind::OpenTag {
+                    value["attrs"] = Value::Object(attributes_json(&token));
+                }
+                if token.is_implied {
+                    value["implied"] = Value::Bool(true);
+                }
+
+                value
+            })
+            .collect();
+
+        writeln!(output, "{}", serde_json::to_string_pretty(&tokens)?)?;
+
+        return Ok(());
+    }
+
+    for token in parser {
+        write!(output, "{} {:?}", kind_name(token.kind), &*token.data)?;
+        if let Some(attrs) = token.attributes() {
+            for (name, value) in attrs.iter() {
+                match value {
+                    Some((value, _)) => write!(output, " {}={:?}", name, value)?,
+                    None => write!(output, " {}", name)?,
+                }
+            }
+        }
+        if token.is_implied {
+            write!(output, " (implied)")?;
+        }
+        writeln!(output)?;
+    }
+
+    Ok(())
+}
+
+fn location_name(location: TokenizerTokenLocation) -> &'static str {
+    match location {
+        TokenizerTokenLocation::AttrData => "AttrData",
+        TokenizerTokenLocation::AttrEntity => "AttrEntity",
+        TokenizerTokenLocation::AttrEnd => "AttrEnd",
+        TokenizerTokenLocation::AttrName => "AttrName",
+        TokenizerTokenLocation::CData => "CData",
+        TokenizerTokenLocation::CloseTag => "CloseTag",
+        TokenizerTokenLocation::Comment => "Comment",
+        TokenizerTokenLocation::Declaration => "Declaration",
+        TokenizerTokenLocation::OpenTagEnd => "OpenTagEnd",
+        TokenizerTokenLocation::OpenTagName => "OpenTagName",
+        TokenizerTokenLocation::ProcessingInstruction => "ProcessingInstruction",
+        TokenizerTokenLocation::SelfClosingTag => "SelfClosingTag",
+        TokenizerTokenLocation::Text => "Text",
+        TokenizerTokenLocation::TextEntity => "TextEntity",
+        TokenizerTokenLocation::End => "End",
+    }
+}
+
+fn quote_name(quote: QuoteType) -> &'static str {
+    match quote {
+        QuoteType::NoValue => "none",
+        QuoteType::Unquoted => "unquoted",
+        QuoteType::Single => "single",
+        QuoteType::Double => "double",
+    }
+}
+
+/** The decoded character of an entity token, or the source of any other token. */
+fn tokenizer_token_text(token: &TokenizerToken, input: &[u8]) -> String {
+    match token.location {
+        TokenizerTokenLocation::TextEntity | TokenizerTokenLocation::AttrEntity => {
+            char::from_u32(token.code).map_or_else(String::new, String::from)
+        }
+        _ => {
+            let end = token.end.min(input.len());
+            let start = token.start.min(end);
+
+            String::from_utf8_lossy(&input[start..end]).into_owned()
+        }
+    }
+}
+
+fn print_tokenizer_tokens(
+    tokenizer: Tokenizer,
+    input: &[u8],
+    json: bool,
+    output: &mut dyn Write,
+) -> Result<(), Box<dyn Error>> {
+    if json {
+        let tokens: Vec<_> = tokenizer
+            .map(|token| {
+                let mut value = json!({
+                    "location": location_name(token.location),
+                    "start": token.start,
+                    "end": token.end,
+                    "text": tokenizer_token_text(&token, input),
+                });
+
+                if token.location == TokenizerTokenLocation::AttrEnd {
+                    value["quote"] = Value::from(quote_name(token.quote));
+                }
+
+                value
+            })
+            .collect();
+
+        writeln!(output, "{}", serde_json::to_string_pretty(&tokens)?)?;
+
+        return Ok(());
+    }
+
+    for token in tokenizer {
+        write!(
+            output,
+            "{} {}..{} {:?}",
+            location_name(token.location),
+            token.start,
+            token.end,
+            tokenizer_token_text(&token, input)
+        )?;
+        if token.location == TokenizerTokenLocation::AttrEnd {
+            write!(output, " {}", quote_name(token.quote))?;
+        }
+        writeln!(output)?;
+    }
+
+    Ok(())
+}
+
+/** A step of writing nodes as JSON, see `write_nodes_json`. */
+enum JsonStep {
+    /** A node at an indentation depth, and whether it is the last in its array. */
+    Node {
+        id: NodeId,
+        depth: usize,
+        is_last: bool,
+    },
+    /** The fields of a node after its children. */
+    End {
+        id: NodeId,
+        depth: usize,
+        is_last: bool,
+    },
+}
+
+/** The steps of writing the nodes, in reverse order to be popped from a stack. */
+fn json_steps(ids: &[NodeId], depth: usize) -> impl Iterator<Item = JsonStep> + '_ {
+    ids.iter()
+        .enumerate()
+        .rev()
+        .map(move |(index, id)| JsonStep::Node {
+            id: *id,
+            depth,
+            is_last: index + 1 == ids.len(),
+        })
+}
+
+/** Writes the fields of a node after its children and ends the object. */
+fn write_json_end(
+    document: &Document,
+    id: NodeId,
+    depth: usize,
+    is_last: bool,
+    output: &mut dyn Write,
+) -> Result<(), Box<dyn Error>> {
+    let indent = "  ".repeat(depth);
+    let comma = if is_last { "" } else { "," };
+
+    if let Some(name) = document.name(id) {
+        writeln!(
+            output,
+            "{}  \"name\": {},",
+            indent,
+            Value::from(name.as_str())
+        )?;
+        writeln!(output, "{}  \"type\": \"element\"", indent)?;
+    } else {
+        writeln!(output, "{}  \"type\": \"document\"", indent)?;
+    }
+    writeln!(output, "{}}}{}", indent, comma)?;
+    Ok(())
+}
+
+/**
+ * Writes the nodes with their descendants as a JSON array, pretty-printed as by `serde_json`. The
+ * nodes are written one at a time, so deeply nested documents don't overflow the stack.
+ */
+fn write_nodes_json(
+    document: &Document,
+    ids: &[NodeId],
+    output: &mut dyn Write,
+) -> Result<(), Box<dyn Error>> {
+    if ids.is_empty() {
+        writeln!(output, "[]")?;
+        return Ok(());
+    }
+
+    writeln!(output, "[")?;
+
+    let mut stack: Vec<JsonStep> = json_steps(ids, 1).collect();
+
+    while let Some(step) = stack.pop() {
+        let (id, depth, is_last) = match step {
+            JsonStep::Node { id, depth, is_last } => (id, depth, is_last),
+            JsonStep::End { id, depth, is_last } => {
+                writeln!(output, "{}  ],", "  ".repeat(depth))?;
+                write_json_end(document, id, depth, is_last, output)?;
+                continue;
+            }
+        };
+        let indent = "  ".repeat(depth);
+        let comma = if is_last { "" } else { "," };
+        let (field, data, kind) = match &document[id].data {
+            NodeData::Document | NodeData::Element { .. } => {
+                writeln!(output, "{}{{", indent)?;
+                if let Some(attrs) = document.attributes(id) {
+                    let mut attributes = Map::new();
+
+                    for (key, value) in attrs.iter() {
+                        let value = value.as_ref().map(|(value, _)| Value::from(&**value));
+
+                        attributes.insert(key.to_string(), value.unwrap_or(Value::Null));
+                    }
+
+                    let attributes = serde_json::to_string_pretty(&attributes)?
+                        .replace('\n', &format!("\n{}  ", indent));
+
+                    writeln!(output, "{}  \"attrs\": {},", indent, attributes)?;
+                }
+
+                let children = &document[id].children;
+
+                if children.is_empty() {
+                    writeln!(output, "{}  \"children\": [],", indent)?;
+                    write_json_end(document, id, depth, is_last, output)?;
+                } else {
+                    writeln!(output, "{}  \"children\": [", indent)?;
+                    stack.push(JsonStep::End { id, depth, is_last });
+                    stack.extend(json_steps(children, depth + 2));
+                }
+                continue;
+            }
+            NodeData::Text(data) => ("data", data, "text"),
+            NodeData::Comment(data) => ("data", data, "comment"),
+            NodeData::ProcessingInstruction(data) => ("data", data, "processing-instruction"),
+        };
+
+        writeln!(output, "{}{{", indent)?;
+        writeln!(
+            output,
+            "{}  \"{}\": {},",
+            indent,
+            field,
+            Value::from(&**data)
+        )?;
+        writeln!(output, "{}  \"type\": \"{}\"", indent, kind)?;
+        writeln!(output, "{}}}{}", indent, comma)?;
+    }
+
+    writeln!(output, "]")?;
+    Ok(())
+}
+
+/** Prints the nodes below the node, one per line and indented by their depth. */
+fn print_tree(
+    document: &Document,
+    id: NodeId,
+    output: &mut dyn Write,
+) -> Result<(), Box<dyn Error>> {
+    let mut stack = vec![(id, 0_usize)];
+
+    while let Some((id, depth)) = stack.pop() {
+        let indent = "  ".repeat(depth.saturating_sub(1));
+
+        match &document[id].data {
+            NodeData::Document => {}
+            NodeData::Element { name, attrs } => {
+                write!(output, "{}<{}", indent, name)?;
+                for (key, value) in attrs.iter() {
+                    match value {
+                        Some((value, _)) => write!(output, " {}={:?}", key, value)?,
+                        None => write!(output, " {}", key)?,
+                    }
+                }
+                writeln!(output, ">")?;
+            }
+            NodeData::Text(data) => writeln!(output, "{}{:?}", indent, data)?,
+            NodeData::Comment(data) => writeln!(output, "{}<!--{}-->", indent, data)?,
+            NodeData::ProcessingInstruction(data) => {
+                writeln!(output, "{}{}", indent, ProcessingInstruction(data))?
+            }
+        }
+
+        stack.extend(
+            document[id]
+                .children
+                .iter()
+                .rev()
+                .map(|child| (*child, depth + 1)),
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use clap::Parser;
+use rshtml::{run, Cli};
+use std::io::{stdin, stdout, Read};
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let file = cli.command.file();
+    let (name, input) = if file == "-" {
+        let mut input = Vec::new();
+
+        ("<stdin>", stdin().read_to_end(&mut input).map(|_| input))
+    } else {
+        (file, std::fs::read(file))
+    };
+
+    let input = match input {
+        Ok(input) => input,
+        Err(error) => {
+            eprintln!("rshtml: {}: {}", name, error);
+            return ExitCode::from(2);
+        }
+    };
+
+    match run(&cli, name, &input, &mut stdout().lock()) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(error) => {
+            eprintln!("rshtml: {}", error);
+            ExitCode::from(2)
+        }
+    }
+}
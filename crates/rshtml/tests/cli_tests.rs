@@ -0,0 +1,221 @@
+mod tests {
+    use clap::Parser;
+    use rshtml::{line_and_column, run, Cli, LineIndex};
+
+    const HTML: &str = concat!(
+        "<!DOCTYPE html>\n<html><head><title>A &amp; B</title><style>p > a {}</style></head>\n",
+        "<body><div id=main class=\"a b\"><p>Hello <b>world</b><p>Two</span></div>\n",
+        "<ul><li>x<li>y</ul>\n<section><em>open</section>\n</body></html>\n"
+    );
+
+    fn rshtml(args: &[&str], input: &str) -> (bool, String) {
+        let cli = Cli::try_parse_from([&["rshtml"], args].concat()).unwrap();
+        let mut output = Vec::new();
+        let success = run(&cli, "test.html", input.as_bytes(), &mut output).unwrap();
+
+        (success, String::from_utf8(output).unwrap())
+    }
+
+    #[test]
+    fn tokens() {
+        insta::assert_snapshot!(rshtml(&["tokens"], "<a href=x id='y'>a &amp; b</a><!--c-->").1)
+    }
+
+    #[test]
+    fn tokens_json() {
+        insta::assert_snapshot!(rshtml(&["tokens", "--json"], "<input disabled value=\"x\">").1)
+    }
+
+    #[test]
+    fn tokenizer_tokens() {
+        insta::assert_snapshot!(rshtml(&["tokens", "--tokenizer"], "<a href='x'>a &amp; b</a>").1)
+    }
+
+    #[test]
+    fn tree() {
+        insta::assert_snapshot!(rshtml(&["tree"], HTML).1)
+    }
+
+    #[test]
+    fn tree_json() {
+        insta::assert_snapshot!(rshtml(&["tree", "--json"], "<p class=x>a<br>b</p><!--c-->").1)
+    }
+
+    #[test]
+    fn select() {
+        assert_eq!(
+            rshtml(&["select", "#main > p"], HTML),
+            (true, "<p>Hello <b>world</b></p>\n<p>Two</p>\n".to_string())
+        );
+        assert_eq!(
+            rshtml(&["select", "--text", "div.b p:first-child"], HTML),
+            (true, "Hello world\n".to_string())
+        );
+        assert_eq!(
+            rshtml(&["select", "--attr", "class", "[id]"], HTML),
+            (true, "a b\n".to_string())
+        );
+        assert_eq!(rshtml(&["select", "table"], HTML), (false, String::new()));
+    }
+
+    #[test]
+    fn invalid_selector() {
+        let cli = Cli::try_parse_from(["rshtml", "select", "a["]).unwrap();
+        let error = run(&cli, "test.html", b"", &mut Vec::new()).unwrap_err();
+
+        assert_eq!(error.to_string(), "invalid selector at 2: expected a name");
+    }
+
+    #[test]
+    fn text() {
+        assert_eq!(
+            rshtml(&["text"], HTML),
+            (true, "Hello world\n\nTwo\n\nx\ny\nopen\n".to_string())
+        );
+    }
+
+    #[test]
+    fn validate() {
+        insta::assert_snapshot!(rshtml(&["validate"], HTML).1);
+        assert!(!rshtml(&["validate"], HTML).0);
+        assert_eq!(
+            rshtml(&["validate"], "<ul><li>a<li>b</ul>"),
+            (true, String::new())
+        );
+    }
+
+    #[test]
+    fn validate_json() {
+        insta::assert_snapshot!(rshtml(&["validate", "--json"], "<div>\n  <span>é</div>").1)
+    }
+
+    #[test]
+    fn format() {
+        let formatted = rshtml(&["format"], HTML).1;
+
+        insta::assert_snapshot!(formatted);
+        assert_eq!(
+            rshtml(
+                &["format", "--indent", "4", "--quotes", "single"],
+                "<div id=a><p>x</div>"
+            ),
+            (true, "<div id='a'>\n    <p>x\n</div>\n".to_string())
+        );
+        assert_eq!(
+            rshtml(&["format", "--check"], &formatted),
+            (true, String::new())
+        );
+        assert_eq!(
+            rshtml(&["format", "--check"], HTML),
+            (false, "test.html: not formatted\n".to_string())
+        );
+    }
+
+    #[test]
+    fn minify() {
+        insta::assert_snapshot!(rshtml(&["minify"], HTML).1);
+        assert_eq!(
+            rshtml(
+                &["minify", "--keep-comments", "--keep-optional-tags"],
+                "<ul>\n  <li>a</li><!-- b -->\n</ul>"
+            ),
+            (true, "<ul><li>a</li><!-- b --></ul>".to_string())
+        );
+    }
+
+    #[test]
+    fn article() {
+        let paragraph =
+            "A paragraph, with commas, that is long enough to be the content of an article.";
+        let html = format!(
+            "<title>A title</title><div class=menu><a href=/>Home</a></div><div><p>{0}</p><p>{0}</p></div>",
+            paragraph
+        );
+
+        assert_eq!(
+            rshtml(&["article"], &html),
+            (true, format!("Title: A title\n\n{0}\n\n{0}\n", paragraph))
+        );
+        insta::assert_snapshot!(rshtml(&["article", "--json"], &html).1);
+        assert!(!rshtml(&["article"], "<img src=a.png>").0);
+    }
+
+    #[test]
+    fn tables() {
+        let html = concat!(
+            "<table><caption>Fruit</caption><tr><th>Name</th><th>Note</th>",
+            "<tr><td>Apple</td><td>red, sweet</td></table><table><tr><td>x</td></table>"
+        );
+
+        assert_eq!(
+            rshtml(&["tables"], html),
+            (true, "Name,Note\nApple,\"red, sweet\"\n\nx\n".to_string())
+        );
+        insta::assert_snapshot!(rshtml(&["tables", "--json"], html).1);
+        assert!(!rshtml(&["tables"], "<p>a</p>").0);
+    }
+
+    #[test]
+    fn links() {
+        let html = "<base href=/docs/><a href=intro.html rel=next>Intro</a><img srcset='a.png 1x'>";
+
+        assert_eq!(
+            rshtml(&["links", "--url", "https://example.com/"], html),
+            (
+                true,
+                concat!(
+                    "0\tbase[href]\thttps://example.com/docs/\n",
+                    "18\ta[href]\thttps://example.com/docs/intro.html\n",
+                    "55\timg[srcset]\thttps://example.com/docs/a.png\n"
+                )
+                .to_string()
+            )
+        );
+        insta::assert_snapshot!(rshtml(&["links", "--json"], html).1);
+    }
+
+    #[test]
+    fn markdown() {
+        insta::assert_snapshot!(rshtml(&["markdown"], HTML).1);
+        let html = "<p><b>a</b><sup>1</sup> <del>b</del></p>";
+
+        assert_eq!(
+            rshtml(&["markdown", "--drop-unsupported"], html),
+            (true, "**a** ~~b~~\n".to_string())
+        );
+        assert_eq!(
+            rshtml(&["markdown", "--commonmark"], html),
+            (true, "**a**<sup>1</sup> <del>b</del>\n".to_string())
+        );
+    }
+
+    #[test]
+    fn options() {
+        let html = "<p>a &amp; b</p>\n<p><br>x</br></p>";
+
+        insta::assert_snapshot!([
+            rshtml(&["tokens", "--no-decode-entities"], html).1,
+            rshtml(&["tokens", "--keep-whitespace-between-tags"], html).1,
+            rshtml(&["tokens", "--xml-mode"], html).1,
+        ]
+        .join("\n"))
+    }
+
+    #[test]
+    fn line_and_column_count_characters() {
+        let input = "ab\nçd\n".as_bytes();
+
+        assert_eq!(line_and_column(input, 0), (1, 1));
+        assert_eq!(line_and_column(input, 3), (2, 1));
+        assert_eq!(line_and_column(input, 5), (2, 2));
+        assert_eq!(line_and_column(input, 100), (3, 1));
+
+        let mut lines = LineIndex::new(input);
+
+        assert_eq!(lines.line_and_column(3), (2, 1));
+        assert_eq!(lines.line_and_column(5), (2, 2));
+        assert_eq!(lines.line_and_column(7), (3, 1));
+        assert_eq!(lines.line_and_column(1), (1, 2));
+        assert_eq!(lines.line_and_column(5), (2, 2));
+    }
+}
@@ -0,0 +1,114 @@
+import {readFileSync, writeFileSync} from 'node:fs';
+
+// Generates the named character reference table of the tokenizer from the WHATWG entities.json
+// (https://html.spec.whatwg.org/entities.json). Usage: node entities_generator.js entities.json
+const entitiesPath = process.argv[2];
+
+if (!entitiesPath) {
+    console.error('Usage: node entities_generator.js <entities.json>');
+    process.exit(1);
+}
+
+const entities = JSON.parse(readFileSync(entitiesPath, 'utf8'));
+
+function escapeRustString(text) {
+    let escaped = '';
+
+    for (const char of text) {
+        const code = char.codePointAt(0);
+
+        if (char === '"' || char === '\\') {
+            escaped += '\\' + char;
+        } else if (code >= 0x20 && code < 0x7f) {
+            escaped += char;
+        } else {
+            escaped += '\\u{' + code.toString(16) + '}';
+        }
+    }
+
+    return escaped;
+}
+
+const names = Object.keys(entities).sort();
+
+// The trie of the names after the `&`. The nodes are numbered breadth first, so the edges of a
+// node are next to each other, sorted by byte.
+const root = {children: new Map(), value: 0};
+const values = [];
+const valueIndexes = new Map();
+
+for (const name of names) {
+    let node = root;
+
+    for (const byte of Buffer.from(name.slice(1))) {
+        if (!node.children.has(byte)) {
+            node.children.set(byte, {children: new Map(), value: 0});
+        }
+        node = node.children.get(byte);
+    }
+
+    const codePoints = entities[name].codepoints;
+    const key = codePoints.join(',');
+
+    if (!valueIndexes.has(key)) {
+        values.push(codePoints);
+        valueIndexes.set(key, values.length);
+    }
+    node.value = valueIndexes.get(key);
+}
+
+const nodes = [root];
+const edges = [];
+
+for (const node of nodes) {
+    node.edgesStart = edges.length;
+
+    for (const byte of [...node.children.keys()].sort((a, b) => a - b)) {
+        edges.push([byte, nodes.length]);
+        nodes.push(node.children.get(byte));
+    }
+}
+
+function rows(items, perRow) {
+    let text = '';
+
+    for (let i = 0; i < items.length; i += perRow) {
+        text += '    ' + items.slice(i, i + perRow).join(', ') + ',\n';
+    }
+
+    return text;
+}
+
+let rsFile = `// Generated by scripts/entities_generator.js from the WHATWG entities.json, do not edit.
+
+/**
+ * The named character references of HTML and their characters, sorted by name. The names
+ * include the \`&\`, the legacy ones are listed both with and without the \`;\`.
+ */
+pub static ENTITIES: [(&str, &str); ${names.length}] = [
+`;
+
+for (const name of names) {
+    rsFile += `    ("${escapeRustString(name)}", "${escapeRustString(entities[name].characters)}"),\n`;
+}
+
+rsFile += `];
+
+/**
+ * The nodes of the trie of the names after the \`&\`, the first one is the root. A node is the
+ * start of its edges in \`TRIE_EDGES\`, the number of them, and the index of its code points in
+ * \`TRIE_VALUES\` plus one when a name ends at the node, otherwise 0.
+ */
+pub(crate) static TRIE_NODES: [(u16, u8, u16); ${nodes.length}] = [
+${rows(nodes.map((node) => `(${node.edgesStart}, ${node.children.size}, ${node.value})`), 6)}];
+
+/** The edges of the trie: a byte of a name and the node it leads to. */
+pub(crate) static TRIE_EDGES: [(u8, u16); ${edges.length}] = [
+${rows(edges.map(([byte, node]) => `(b'${String.fromCharCode(byte)}', ${node})`), 8)}];
+
+/** The code points of the names, the second one is 0 for names of a single code point. */
+pub(crate) static TRIE_VALUES: [(u32, u32); ${values.length}] = [
+${rows(values.map(([first, second]) => `(0x${first.toString(16)}, 0x${(second ?? 0).toString(16)})`), 6)}];
+`;
+
+writeFileSync('../crates/rs_html_parser_tokenizer/src/entity_table.rs', rsFile);